@@ -24,6 +24,7 @@ use barter_data::{
 };
 use barter_integration::model::{instrument::kind::InstrumentKind, Market};
 use parking_lot::Mutex;
+use rust_decimal::Decimal;
 use std::{collections::HashMap, sync::Arc, time::Duration};
 use tokio::sync::mpsc;
 use uuid::Uuid;
@@ -50,10 +51,10 @@ async fn main() {
         MetaPortfolio::builder()
             .engine_id(engine_id)
             .markets(vec![market.clone()])
-            .starting_cash(10_000.0)
+            .starting_cash(Decimal::from(10_000))
             .repository(InMemoryRepository::new())
             .allocation_manager(DefaultAllocator {
-                default_order_value: 100.0,
+                default_order_value: Decimal::ONE_HUNDRED,
             })
             .risk_manager(DefaultRisk {})
             .statistic_config(StatisticConfig {
@@ -82,9 +83,9 @@ async fn main() {
             .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
             .execution(SimulatedExecution::new(ExecutionConfig {
                 simulated_fees_pct: Fees {
-                    exchange: 0.1,
-                    slippage: 0.05,
-                    network: 0.0,
+                    exchange: Decimal::new(1, 1),
+                    slippage: Decimal::new(5, 2),
+                    network: Decimal::ZERO,
                 },
             }))
             .build()
@@ -161,6 +162,9 @@ async fn stream_market_event_trades() -> mpsc::UnboundedReceiver<MarketEvent<Dat
 async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
     while let Some(event) = event_rx.recv().await {
         match event {
+            Event::TraderStarted(_) => {
+                // Trader started trading a Market
+            }
             Event::Market(market) => {
                 // Market Event occurred in Engine
                 println!("{market:?}");
@@ -176,9 +180,45 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // OrderNew Event occurred in Engine
                 println!("{new_order:?}");
             }
+            Event::OrderTriggered(triggered_order) => {
+                // Conditional OrderEvent triggered & sent for execution
+                println!("{triggered_order:?}");
+            }
             Event::OrderUpdate => {
                 // OrderUpdate Event occurred in Engine
             }
+            Event::OrderCancelled(cancelled_order) => {
+                // OrderCancelled Event occurred in Engine
+                println!("{cancelled_order:?}");
+            }
+            Event::OrderAmended(amended_order) => {
+                // Open OrderEvent amended in place with a new price and/or quantity
+                println!("{amended_order:?}");
+            }
+            Event::OrderRejected(rejection) => {
+                // OrderEvent rejected by the exchange & no longer open
+                println!("{rejection:?}");
+            }
+            Event::PositionCapBreach(breach) => {
+                // OrderEvent refused for breaching the Trader hard position cap
+                println!("{breach:?}");
+            }
+            Event::PositionDiscrepancy(discrepancy) => {
+                // Internal Position drifted from the exchange & was corrected to match it
+                println!("{discrepancy:?}");
+            }
+            Event::KillSwitchArmed(armed) => {
+                // Trader kill switch armed, flattening the open Position
+                println!("{armed:?}");
+            }
+            Event::CircuitTripped(tripped) => {
+                // Trader order or fill rate exceeded it's ceiling, halting new orders
+                println!("{tripped:?}");
+            }
+            Event::Heartbeat(heartbeat) => {
+                // Heartbeat Event occurred in Engine
+                println!("{heartbeat:?}");
+            }
             Event::Fill(fill_event) => {
                 // Fill Event occurred in Engine
                 println!("{fill_event:?}");
@@ -195,10 +235,21 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // PositionExit Event occurred in Engine
                 println!("{exited_position:?}");
             }
+            Event::FundingPayment(payment) => {
+                // Funding paid or received on an open perpetual Position
+                println!("{payment:?}");
+            }
             Event::Balance(balance_update) => {
                 // Balance update Event occurred in Engine
                 println!("{balance_update:?}");
             }
+            Event::CommandOutcome(outcome) => {
+                // Outcome of a correlated Command actioned by the Engine
+                println!("{outcome:?}");
+            }
+            Event::TraderStopped(_) => {
+                // Trader stopped trading a Market
+            }
         }
     }
 }
@@ -0,0 +1,30 @@
+use crate::{engine::error::EngineError, portfolio::error::PortfolioError};
+use thiserror::Error;
+
+/// All errors generated in the barter::backtest module.
+#[derive(Error, Debug)]
+pub enum BacktestError {
+    #[error("Failed to build struct due to missing attributes: {0}")]
+    BuilderIncomplete(&'static str),
+
+    #[error("Failed to read historical data file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Unsupported historical data file format, expected .csv or .jsonl: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("Failed to parse historical data at line {line}: {reason}")]
+    Parse { line: u64, reason: String },
+
+    #[error("Historical data file contains no MarketEvents")]
+    NoMarketEvents,
+
+    #[error("Historical data file contains no MarketEvents within the replay window")]
+    NoMarketEventsInWindow,
+
+    #[error("Failed to interact with portfolio: {0}")]
+    Portfolio(#[from] PortfolioError),
+
+    #[error("Engine failed to run backtest: {0}")]
+    Engine(#[from] EngineError),
+}
@@ -0,0 +1,610 @@
+use crate::{
+    backtest::error::BacktestError,
+    clock::SimulatedClock,
+    data::historical::{self, ReplayFeed, ReplaySpeed},
+    engine::{
+        trader::{SessionSummary, Trader},
+        Engine,
+    },
+    event::EventTx,
+    execution::{
+        simulated::{Config as ExecutionConfig, SimulatedExecution},
+        Fees,
+    },
+    portfolio::{
+        allocator::DefaultAllocator, decimal_to_f64, equity::EquityRecorder,
+        portfolio::MetaPortfolio, repository::in_memory::InMemoryRepository, risk::DefaultRisk,
+        ProfitLossReporter,
+    },
+    statistic::summary::{
+        trading::{Config as StatisticConfig, TradingSummary},
+        Initialiser,
+    },
+    strategy::SignalGenerator,
+};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::candle::Candle,
+};
+use barter_integration::model::Market;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+/// Barter backtest module specific errors.
+pub mod error;
+
+/// Results of a [`Backtest`], including the [`SessionSummary`] of the backtested [`Market`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BacktestSummary {
+    /// [`SessionSummary`] of the [`Trader`], including the final [`TradingSummary`] statistics.
+    pub session: SessionSummary<TradingSummary>,
+    /// Starting cash plus the realised & unrealised P&L of every Position at the end of the
+    /// backtest.
+    pub final_equity: Decimal,
+}
+
+/// One-call backtest of a [`SignalGenerator`] strategy over a historical data file for a single
+/// [`Market`]. Orders are sized by a [`DefaultAllocator`] & executed by a [`SimulatedExecution`].
+///
+/// Supported historical data file formats:
+/// - `.csv`: OHLCV [`Candle`] rows with the header
+///   `close_time,open,high,low,close,volume,trade_count`, where `close_time` is RFC3339.
+/// - `.jsonl`: one JSON [`MarketEvent<DataKind>`] per line.
+///
+/// Optionally only a time window of the historical data is backtested (see
+/// [`BacktestBuilder::from`] & [`BacktestBuilder::to`]).
+#[derive(Debug)]
+pub struct Backtest<Strategy>
+where
+    Strategy: SignalGenerator + Send + 'static,
+{
+    market: Market,
+    market_events: Vec<MarketEvent<DataKind>>,
+    strategy: Strategy,
+    starting_cash: Decimal,
+    order_value: Decimal,
+    fees: Fees,
+    warm_up: usize,
+    equity_recorder: Option<EquityRecorder>,
+    replay_speed: ReplaySpeed,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    /// Exchange timestamp of the first [`MarketEvent`] within the replay window.
+    start_time: DateTime<Utc>,
+}
+
+impl<Strategy> Backtest<Strategy>
+where
+    Strategy: SignalGenerator + Send + 'static,
+{
+    /// Builder to construct [`Backtest`] instances.
+    pub fn builder() -> BacktestBuilder<Strategy> {
+        BacktestBuilder::new()
+    }
+
+    /// Run the [`Backtest`] by replaying every historical [`MarketEvent`] through an [`Engine`],
+    /// returning the [`BacktestSummary`] once the historical data is exhausted.
+    pub async fn run(self) -> Result<BacktestSummary, BacktestError> {
+        let engine_id = Uuid::new_v4();
+        let statistic_config = StatisticConfig {
+            starting_equity: decimal_to_f64(self.starting_cash),
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        };
+
+        let portfolio = Arc::new(Mutex::new(
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![self.market.clone()])
+                .starting_cash(self.starting_cash)
+                .repository(InMemoryRepository::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: self.order_value,
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(statistic_config)
+                .build_and_init()?,
+        ));
+
+        // Backtest Events are summarised rather than inspected, so drop the Event receiver
+        let (event_tx, _) = mpsc::unbounded_channel();
+
+        // Command transmitters must outlive the Engine, else the Traders terminate immediately
+        let (_command_tx, command_rx) = mpsc::channel(1);
+        let (trader_command_tx, trader_command_rx) = mpsc::channel(1);
+
+        // Start the SimulatedClock at the first MarketEvent so the backtest is deterministic
+        let clock = SimulatedClock::new(self.start_time);
+
+        let mut data = ReplayFeed::new(
+            historical::MarketFeed::from_recorded(self.market_events),
+            self.replay_speed,
+        );
+        if let Some(from) = self.from {
+            data = data.with_from(from);
+        }
+        if let Some(to) = self.to {
+            data = data.with_to(to);
+        }
+
+        let mut trader = Trader::builder()
+            .engine_id(engine_id)
+            .market(self.market.clone())
+            .command_rx(trader_command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(&portfolio))
+            .data(data)
+            .strategy(self.strategy)
+            .execution(SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: self.fees,
+            }))
+            .clock(Arc::new(clock))
+            .warm_up(self.warm_up);
+        if let Some(equity_recorder) = self.equity_recorder {
+            trader = trader.equity_recorder(equity_recorder);
+        }
+        let trader = trader.build()?;
+
+        let session = Engine::builder()
+            .engine_id(engine_id)
+            .command_rx(command_rx)
+            .portfolio(Arc::clone(&portfolio))
+            .traders(vec![trader])
+            .trader_command_txs(HashMap::from([(self.market.clone(), trader_command_tx)]))
+            .statistics_summary(TradingSummary::init(statistic_config))
+            .build()?
+            .run()
+            .await?
+            .pop()
+            .expect("Engine returns a SessionSummary for every Trader that stops cleanly");
+
+        let unrealised_profit_loss = portfolio
+            .lock()
+            .unrealised_profit_loss(std::iter::once(&self.market))?
+            .values()
+            .sum::<Decimal>();
+
+        Ok(BacktestSummary {
+            final_equity: self.starting_cash
+                + session.realised_profit_loss
+                + unrealised_profit_loss,
+            session,
+        })
+    }
+}
+
+/// Builder to construct [`Backtest`] instances.
+#[derive(Debug, Default)]
+pub struct BacktestBuilder<Strategy>
+where
+    Strategy: SignalGenerator + Send + 'static,
+{
+    market: Option<Market>,
+    data: Option<PathBuf>,
+    strategy: Option<Strategy>,
+    starting_cash: Option<Decimal>,
+    order_value: Option<Decimal>,
+    fees: Option<Fees>,
+    warm_up: Option<usize>,
+    equity_recorder: Option<EquityRecorder>,
+    replay_speed: Option<ReplaySpeed>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+}
+
+impl<Strategy> BacktestBuilder<Strategy>
+where
+    Strategy: SignalGenerator + Send + 'static,
+{
+    fn new() -> Self {
+        Self {
+            market: None,
+            data: None,
+            strategy: None,
+            starting_cash: None,
+            order_value: None,
+            fees: None,
+            warm_up: None,
+            equity_recorder: None,
+            replay_speed: None,
+            from: None,
+            to: None,
+        }
+    }
+
+    pub fn market(self, value: Market) -> Self {
+        Self {
+            market: Some(value),
+            ..self
+        }
+    }
+
+    /// Path to the historical data file (`.csv` or `.jsonl`) to backtest over.
+    pub fn data<P: AsRef<Path>>(self, value: P) -> Self {
+        Self {
+            data: Some(value.as_ref().to_path_buf()),
+            ..self
+        }
+    }
+
+    pub fn strategy(self, value: Strategy) -> Self {
+        Self {
+            strategy: Some(value),
+            ..self
+        }
+    }
+
+    pub fn starting_cash(self, value: Decimal) -> Self {
+        Self {
+            starting_cash: Some(value),
+            ..self
+        }
+    }
+
+    /// Value of each entry order generated by the [`DefaultAllocator`].
+    pub fn order_value(self, value: Decimal) -> Self {
+        Self {
+            order_value: Some(value),
+            ..self
+        }
+    }
+
+    /// Simulated fee percentages (in decimal form) applied to every fill.
+    pub fn fees(self, value: Fees) -> Self {
+        Self {
+            fees: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional number of historical [`MarketEvent`]s replayed to warm up the Strategy before it
+    /// may trade. Defaults to no warm up.
+    pub fn warm_up(self, value: usize) -> Self {
+        Self {
+            warm_up: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`EquityRecorder`] the equity curve of the backtest is recorded to, with one
+    /// [`EquitySample`](crate::portfolio::equity::EquitySample) per historical [`MarketEvent`]
+    /// & fill.
+    pub fn equity_recorder(self, value: EquityRecorder) -> Self {
+        Self {
+            equity_recorder: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`ReplaySpeed`] the historical [`MarketEvent`]s are replayed at, eg/ to watch
+    /// the backtest unfold at 10x real time. Defaults to [`ReplaySpeed::Unlimited`].
+    pub fn replay_speed(self, value: ReplaySpeed) -> Self {
+        Self {
+            replay_speed: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional inclusive start of the time window backtested, skipping every historical
+    /// [`MarketEvent`] before it. Any warm up is consumed by the first [`MarketEvent`]s within
+    /// the window. Defaults to the start of the historical data.
+    pub fn from(self, value: DateTime<Utc>) -> Self {
+        Self {
+            from: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional exclusive end of the time window backtested, finishing the backtest at the first
+    /// historical [`MarketEvent`] at or after it. Defaults to the end of the historical data.
+    pub fn to(self, value: DateTime<Utc>) -> Self {
+        Self {
+            to: Some(value),
+            ..self
+        }
+    }
+
+    /// Builds the [`Backtest`], loading the historical data file into memory.
+    pub fn build(self) -> Result<Backtest<Strategy>, BacktestError> {
+        let market = self
+            .market
+            .ok_or(BacktestError::BuilderIncomplete("market"))?;
+        let data = self.data.ok_or(BacktestError::BuilderIncomplete("data"))?;
+        let market_events = load_market_events(&data, &market)?;
+        let start_time = market_events
+            .iter()
+            .map(|event| event.exchange_time)
+            .filter(|time| self.from.is_none_or(|from| *time >= from))
+            .filter(|time| self.to.is_none_or(|to| *time < to))
+            .min()
+            .ok_or(BacktestError::NoMarketEventsInWindow)?;
+
+        Ok(Backtest {
+            market,
+            market_events,
+            strategy: self
+                .strategy
+                .ok_or(BacktestError::BuilderIncomplete("strategy"))?,
+            starting_cash: self
+                .starting_cash
+                .ok_or(BacktestError::BuilderIncomplete("starting_cash"))?,
+            order_value: self
+                .order_value
+                .ok_or(BacktestError::BuilderIncomplete("order_value"))?,
+            fees: self.fees.ok_or(BacktestError::BuilderIncomplete("fees"))?,
+            warm_up: self.warm_up.unwrap_or_default(),
+            equity_recorder: self.equity_recorder,
+            replay_speed: self.replay_speed.unwrap_or_default(),
+            from: self.from,
+            to: self.to,
+            start_time,
+        })
+    }
+}
+
+/// Loads every historical [`MarketEvent`] from the data file at the provided path, using the
+/// file extension to determine the format.
+fn load_market_events(
+    path: &Path,
+    market: &Market,
+) -> Result<Vec<MarketEvent<DataKind>>, BacktestError> {
+    let contents = fs::read_to_string(path)?;
+
+    let market_events = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("csv") => parse_candles_csv(&contents, market)?,
+        Some("jsonl") => parse_market_events_jsonl(&contents)?,
+        _ => return Err(BacktestError::UnsupportedFormat(path.display().to_string())),
+    };
+
+    if market_events.is_empty() {
+        return Err(BacktestError::NoMarketEvents);
+    }
+
+    Ok(market_events)
+}
+
+/// Parses OHLCV [`Candle`] CSV rows into [`MarketEvent`]s for the provided [`Market`].
+fn parse_candles_csv(
+    contents: &str,
+    market: &Market,
+) -> Result<Vec<MarketEvent<DataKind>>, BacktestError> {
+    csv::Reader::from_reader(contents.as_bytes())
+        .deserialize::<Candle>()
+        .map(|candle| {
+            let candle = candle.map_err(|error| BacktestError::Parse {
+                line: error.position().map_or(0, |position| position.line()),
+                reason: error.to_string(),
+            })?;
+
+            Ok(MarketEvent {
+                exchange_time: candle.close_time,
+                received_time: candle.close_time,
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                kind: DataKind::Candle(candle),
+            })
+        })
+        .collect()
+}
+
+/// Parses JSON lines of [`MarketEvent`]s, skipping blank lines.
+fn parse_market_events_jsonl(contents: &str) -> Result<Vec<MarketEvent<DataKind>>, BacktestError> {
+    contents
+        .lines()
+        .zip(1..)
+        .filter(|(line, _)| !line.trim().is_empty())
+        .map(|(line, line_number)| {
+            serde_json::from_str(line).map_err(|error| BacktestError::Parse {
+                line: line_number,
+                reason: error.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::buy_and_hold::BuyAndHold;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    const CANDLES_CSV: &str = "\
+close_time,open,high,low,close,volume,trade_count
+2022-04-05T21:00:00Z,1000.0,1100.0,900.0,1000.0,1000.0,10
+2022-04-05T22:00:00Z,1000.0,1300.0,1000.0,1200.0,1000.0,10
+2022-04-05T23:00:00Z,1200.0,1600.0,1100.0,1500.0,1000.0,10
+";
+
+    fn market() -> Market {
+        Market::new("binance", ("btc", "usdt", InstrumentKind::Spot))
+    }
+
+    /// Writes the provided contents to a uniquely named temporary file with the extension
+    /// provided.
+    fn temp_file(contents: &str, extension: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("barter_{}.{}", Uuid::new_v4(), extension));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn backtest_over_csv_candles_should_return_deterministic_final_equity() {
+        let path = temp_file(CANDLES_CSV, "csv");
+
+        let summary = Backtest::builder()
+            .market(market())
+            .data(&path)
+            .strategy(BuyAndHold::new())
+            .starting_cash(Decimal::from(10_000))
+            .order_value(Decimal::ONE_THOUSAND)
+            .fees(Fees::default())
+            .build()
+            .unwrap()
+            .run()
+            .await
+            .unwrap();
+        fs::remove_file(path).unwrap();
+
+        // Buys 1.0 contract at the 1000.0 close, which is held until the final 1500.0 close
+        assert_eq!(summary.session.market_events, 3);
+        assert_eq!(summary.session.orders, 1);
+        assert!(summary.session.statistics.is_some());
+        assert_eq!(summary.final_equity, Decimal::from(10_500));
+    }
+
+    #[tokio::test]
+    async fn backtest_with_warm_up_should_ignore_signals_of_warm_up_candles() {
+        let path = temp_file(CANDLES_CSV, "csv");
+
+        let summary = Backtest::builder()
+            .market(market())
+            .data(&path)
+            .strategy(BuyAndHold::new())
+            .starting_cash(Decimal::from(10_000))
+            .order_value(Decimal::ONE_THOUSAND)
+            .fees(Fees::default())
+            .warm_up(1)
+            .build()
+            .unwrap()
+            .run()
+            .await
+            .unwrap();
+        fs::remove_file(path).unwrap();
+
+        // Strategy consumes it's only Signal during the warm up, so never trades
+        assert_eq!(summary.session.market_events, 3);
+        assert_eq!(summary.session.orders, 0);
+        assert_eq!(summary.final_equity, Decimal::from(10_000));
+    }
+
+    #[tokio::test]
+    async fn backtest_with_window_should_only_process_market_events_within_window() {
+        let multi_day_csv = "\
+close_time,open,high,low,close,volume,trade_count
+2022-04-04T12:00:00Z,900.0,900.0,900.0,900.0,1000.0,10
+2022-04-04T18:00:00Z,950.0,950.0,950.0,950.0,1000.0,10
+2022-04-05T00:00:00Z,1000.0,1000.0,1000.0,1000.0,1000.0,10
+2022-04-05T12:00:00Z,1200.0,1200.0,1200.0,1200.0,1000.0,10
+2022-04-06T00:00:00Z,1500.0,1500.0,1500.0,1500.0,1000.0,10
+2022-04-06T12:00:00Z,2000.0,2000.0,2000.0,2000.0,1000.0,10
+";
+        let path = temp_file(multi_day_csv, "csv");
+        let day = |day| format!("2022-04-{day:02}T00:00:00Z").parse().unwrap();
+
+        let summary = Backtest::builder()
+            .market(market())
+            .data(&path)
+            .strategy(BuyAndHold::new())
+            .starting_cash(Decimal::from(10_000))
+            .order_value(Decimal::ONE_THOUSAND)
+            .fees(Fees::default())
+            .from(day(5))
+            .to(day(6))
+            .build()
+            .unwrap()
+            .run()
+            .await
+            .unwrap();
+
+        // Window includes the candle closing on it's start, but not the candle closing on it's end
+        assert_eq!(summary.session.market_events, 2);
+
+        // Buys 1.0 contract at the first in-window 1000.0 close, valued at the last 1200.0 close
+        assert_eq!(summary.session.orders, 1);
+        assert_eq!(summary.final_equity, Decimal::from(10_200));
+
+        // Windows without any MarketEvents cannot be backtested
+        let empty_window = Backtest::builder()
+            .market(market())
+            .data(&path)
+            .strategy(BuyAndHold::new())
+            .starting_cash(Decimal::from(10_000))
+            .order_value(Decimal::ONE_THOUSAND)
+            .fees(Fees::default())
+            .from(day(7))
+            .build();
+        fs::remove_file(path).unwrap();
+        assert!(matches!(
+            empty_window,
+            Err(BacktestError::NoMarketEventsInWindow)
+        ));
+    }
+
+    #[tokio::test]
+    async fn backtest_with_equity_recorder_should_record_equity_curve() {
+        let path = temp_file(CANDLES_CSV, "csv");
+        let equity_recorder = EquityRecorder::in_memory();
+
+        let summary = Backtest::builder()
+            .market(market())
+            .data(&path)
+            .strategy(BuyAndHold::new())
+            .starting_cash(Decimal::from(10_000))
+            .order_value(Decimal::ONE_THOUSAND)
+            .fees(Fees::default())
+            .equity_recorder(equity_recorder.clone())
+            .build()
+            .unwrap()
+            .run()
+            .await
+            .unwrap();
+        fs::remove_file(path).unwrap();
+
+        // One EquitySample per candle, plus one for the fill of the 1000.0 entry
+        let curve = equity_recorder.samples();
+        assert_eq!(curve.len(), 4);
+        assert_eq!(
+            curve.iter().map(|sample| sample.equity).collect::<Vec<_>>(),
+            vec![
+                Decimal::from(10_000),
+                Decimal::from(10_000),
+                Decimal::from(10_200),
+                Decimal::from(10_500)
+            ]
+        );
+        assert_eq!(curve[3].cash, Decimal::from(9000));
+        assert_eq!(curve[3].unrealised_profit_loss, Decimal::from(500));
+        assert_eq!(curve[3].equity, summary.final_equity);
+    }
+
+    #[test]
+    fn backtest_builder_should_report_line_number_of_malformed_rows() {
+        let malformed_csv = "\
+close_time,open,high,low,close,volume,trade_count
+2022-04-05T21:00:00Z,1000.0,1100.0,900.0,1000.0,1000.0,10
+2022-04-05T22:00:00Z,1000.0,1300.0,1000.0,not_a_price,1000.0,10
+";
+        let malformed_jsonl = format!(
+            "{}\n\n{{\"not\": \"a MarketEvent\"}}\n",
+            serde_json::to_string(&crate::test_util::market_event_candle()).unwrap()
+        );
+
+        for (contents, extension) in [(malformed_csv, "csv"), (&malformed_jsonl, "jsonl")] {
+            let path = temp_file(contents, extension);
+            let result = Backtest::builder()
+                .market(market())
+                .data(&path)
+                .strategy(BuyAndHold::new())
+                .starting_cash(Decimal::from(10_000))
+                .order_value(Decimal::ONE_THOUSAND)
+                .fees(Fees::default())
+                .build();
+            fs::remove_file(path).unwrap();
+
+            match result {
+                Err(BacktestError::Parse { line, .. }) => assert_eq!(line, 3, "{extension}"),
+                other => panic!("expected BacktestError::Parse for {extension}, got {other:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use std::{fmt::Debug, sync::Arc};
+
+/// Source of the current time used by the trading event loop. Enables backtests to run against
+/// the timestamps of the market events being replayed, rather than wall-clock time.
+pub trait Clock: Debug {
+    /// Returns the current time according to this [`Clock`].
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Advances this [`Clock`] to the provided time, usually the exchange timestamp of the latest
+    /// market event consumed. Has no effect on real-time clocks.
+    fn advance(&self, _time: DateTime<Utc>) {}
+
+    /// Returns true if this [`Clock`] tells the wall-clock time, or false if it's time is
+    /// simulated (eg/ when replaying market events in a backtest).
+    fn is_realtime(&self) -> bool {
+        true
+    }
+}
+
+/// Real-time [`Clock`] that returns the wall-clock time via [`Utc::now`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct LiveClock;
+
+impl Clock for LiveClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Deterministic [`Clock`] that only moves when it is advanced (eg/ to the exchange timestamp of
+/// each market event consumed in a backtest). Cloning a [`SimulatedClock`] returns a handle to
+/// the same shared time, so a strategy can observe the time advanced by the
+/// [`Trader`](crate::engine::trader::Trader).
+#[derive(Clone, Debug)]
+pub struct SimulatedClock {
+    time: Arc<RwLock<DateTime<Utc>>>,
+}
+
+impl Clock for SimulatedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.time.read()
+    }
+
+    fn advance(&self, time: DateTime<Utc>) {
+        // Never move backwards, eg/ due to an out of order market event
+        let mut current = self.time.write();
+        if time > *current {
+            *current = time;
+        }
+    }
+
+    fn is_realtime(&self) -> bool {
+        false
+    }
+}
+
+impl SimulatedClock {
+    /// Constructs a new [`SimulatedClock`] starting at the provided time.
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            time: Arc::new(RwLock::new(start)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn simulated_clock_should_only_advance_forwards() {
+        let start = Utc::now();
+        let clock = SimulatedClock::new(start);
+        assert_eq!(clock.now(), start);
+
+        let later = start + Duration::minutes(1);
+        clock.advance(later);
+        assert_eq!(clock.now(), later);
+
+        clock.advance(start);
+        assert_eq!(clock.now(), later);
+    }
+
+    #[test]
+    fn simulated_clock_handles_should_share_time() {
+        let start = Utc::now();
+        let clock = SimulatedClock::new(start);
+        let handle = clock.clone();
+
+        let later = start + Duration::hours(1);
+        clock.advance(later);
+
+        assert_eq!(handle.now(), later);
+    }
+}
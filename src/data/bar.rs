@@ -0,0 +1,253 @@
+use crate::{data::MarketMeta, portfolio::decimal_to_f64};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::candle::Candle,
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Interval after which a bar built by a [`BarAggregator`] completes.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum BarInterval {
+    /// Bars span fixed periods of exchange time aligned to the unix epoch (eg/ 1m bars close
+    /// on every minute). A bar completes once a [`MarketEvent`] of a later period arrives.
+    Time(
+        #[serde(
+            deserialize_with = "crate::statistic::de_duration_from_secs",
+            serialize_with = "crate::statistic::se_duration_as_secs"
+        )]
+        Duration,
+    ),
+    /// Bars complete once they have aggregated the provided number of [`MarketEvent`]s.
+    Ticks(u64),
+}
+
+/// Bar of an [`Instrument`] that is yet to complete.
+#[derive(Clone, PartialEq, Debug)]
+struct PartialBar {
+    /// Start of the [`BarInterval::Time`] period, or the exchange time of the first
+    /// [`MarketEvent`] for [`BarInterval::Ticks`].
+    opened_at: DateTime<Utc>,
+    latest: MarketEvent<DataKind>,
+    candle: Candle,
+    ticks: u64,
+}
+
+/// Aggregates the [`MarketEvent`]s of each [`Instrument`] into bars, yielding a
+/// [`DataKind::Candle`] [`MarketEvent`] every time a bar completes. Used by the
+/// [`Trader`](crate::engine::trader::Trader) to only invoke tick-driven strategies that trade
+/// candles with completed bars.
+///
+/// [`MarketEvent`]s that do not communicate a price (eg/ [`DataKind::Liquidation`]) are
+/// ignored. Trade amounts & candle volumes are summed into the bar volume.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BarAggregator {
+    interval: BarInterval,
+    flush_partial: bool,
+    bars: HashMap<(Exchange, Instrument), PartialBar>,
+}
+
+impl BarAggregator {
+    /// Constructs a new [`BarAggregator`] that builds bars of the provided [`BarInterval`]. If
+    /// `flush_partial` is set, incomplete bars are yielded by [`BarAggregator::flush`] once the
+    /// market data feed finishes, rather than discarded.
+    pub fn new(interval: BarInterval, flush_partial: bool) -> Self {
+        let interval = match interval {
+            BarInterval::Time(period) => BarInterval::Time(period.max(Duration::milliseconds(1))),
+            BarInterval::Ticks(ticks) => BarInterval::Ticks(ticks.max(1)),
+        };
+
+        Self {
+            interval,
+            flush_partial,
+            bars: HashMap::new(),
+        }
+    }
+
+    /// Aggregates the [`MarketEvent`] into the bar of it's [`Instrument`], returning the
+    /// [`DataKind::Candle`] [`MarketEvent`] of a bar if it completed.
+    pub fn update(&mut self, market: &MarketEvent<DataKind>) -> Option<MarketEvent<DataKind>> {
+        let MarketMeta { close, time } = MarketMeta::from_market(market)?;
+        let close = decimal_to_f64(close);
+        let (volume, trade_count) = match &market.kind {
+            DataKind::Trade(trade) => (trade.amount, 1),
+            DataKind::Candle(candle) => (candle.volume, candle.trade_count),
+            _ => (0.0, 0),
+        };
+
+        let key = (market.exchange.clone(), market.instrument.clone());
+        let opened_at = match self.interval {
+            BarInterval::Time(period) => period_start(time, period),
+            BarInterval::Ticks(_) => time,
+        };
+
+        // Time bars complete once a MarketEvent of a later period arrives
+        let completed = match (self.interval, self.bars.get(&key)) {
+            (BarInterval::Time(_), Some(bar)) if bar.opened_at != opened_at => {
+                self.bars.remove(&key).map(|bar| self.complete(bar))
+            }
+            _ => None,
+        };
+
+        let bar = self.bars.entry(key.clone()).or_insert_with(|| PartialBar {
+            opened_at,
+            latest: market.clone(),
+            candle: Candle {
+                close_time: time,
+                open: close,
+                high: close,
+                low: close,
+                close,
+                volume: 0.0,
+                trade_count: 0,
+            },
+            ticks: 0,
+        });
+        bar.latest = market.clone();
+        bar.candle.close_time = time;
+        bar.candle.high = bar.candle.high.max(close);
+        bar.candle.low = bar.candle.low.min(close);
+        bar.candle.close = close;
+        bar.candle.volume += volume;
+        bar.candle.trade_count += trade_count;
+        bar.ticks += 1;
+
+        match self.interval {
+            BarInterval::Ticks(ticks) if bar.ticks >= ticks => {
+                self.bars.remove(&key).map(|bar| self.complete(bar))
+            }
+            _ => completed,
+        }
+    }
+
+    /// Returns the [`DataKind::Candle`] [`MarketEvent`]s of every incomplete bar if configured
+    /// to flush partial bars, otherwise discards them.
+    pub fn flush(&mut self) -> Vec<MarketEvent<DataKind>> {
+        let bars = std::mem::take(&mut self.bars);
+        if !self.flush_partial {
+            return Vec::new();
+        }
+
+        let mut bars = bars
+            .into_values()
+            .map(|bar| self.complete(bar))
+            .collect::<Vec<_>>();
+        bars.sort_by_key(|bar| bar.exchange_time);
+        bars
+    }
+
+    /// Converts the [`PartialBar`] into a [`DataKind::Candle`] [`MarketEvent`]. Time bars close
+    /// at the end of their period, tick bars at the exchange time of their latest
+    /// [`MarketEvent`].
+    fn complete(&self, bar: PartialBar) -> MarketEvent<DataKind> {
+        let close_time = match self.interval {
+            BarInterval::Time(period) => bar.opened_at + period,
+            BarInterval::Ticks(_) => bar.candle.close_time,
+        };
+
+        MarketEvent {
+            exchange_time: close_time,
+            received_time: bar.latest.received_time,
+            exchange: bar.latest.exchange,
+            instrument: bar.latest.instrument,
+            kind: DataKind::Candle(Candle {
+                close_time,
+                ..bar.candle
+            }),
+        }
+    }
+}
+
+/// Returns the start of the period of the provided length, aligned to the unix epoch, that the
+/// time falls within.
+fn period_start(time: DateTime<Utc>, period: Duration) -> DateTime<Utc> {
+    let period = period.num_milliseconds();
+    let start = time.timestamp_millis().div_euclid(period) * period;
+    Utc.timestamp_millis_opt(start).single().unwrap_or(time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::model::Side;
+
+    fn tick(time: DateTime<Utc>, price: f64) -> MarketEvent<DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange_time = time;
+        if let DataKind::Trade(trade) = &mut market.kind {
+            trade.price = price;
+        }
+        market
+    }
+
+    fn candle(market: &MarketEvent<DataKind>) -> Candle {
+        match &market.kind {
+            DataKind::Candle(candle) => *candle,
+            kind => panic!("expected DataKind::Candle, got {kind:?}"),
+        }
+    }
+
+    #[test]
+    fn time_bar_aggregator_should_build_one_minute_candle_from_sub_minute_ticks() {
+        let minute = Utc.with_ymd_and_hms(2023, 1, 2, 9, 30, 0).unwrap();
+        let mut aggregator = BarAggregator::new(BarInterval::Time(Duration::minutes(1)), false);
+
+        // Ticks within the 09:30 minute do not complete a bar
+        for (seconds, price) in [(5, 100.0), (20, 104.0), (35, 98.0), (50, 101.0)] {
+            let tick = tick(minute + Duration::seconds(seconds), price);
+            assert_eq!(aggregator.update(&tick), None);
+        }
+
+        // First tick of the 09:31 minute completes the 09:30 bar
+        let bar = aggregator
+            .update(&tick(minute + Duration::seconds(65), 110.0))
+            .unwrap();
+        assert_eq!(bar.exchange_time, minute + Duration::minutes(1));
+        assert_eq!(
+            candle(&bar),
+            Candle {
+                close_time: minute + Duration::minutes(1),
+                open: 100.0,
+                high: 104.0,
+                low: 98.0,
+                close: 101.0,
+                volume: 4.0,
+                trade_count: 4,
+            }
+        );
+
+        // Partial 09:31 bar is discarded unless configured to flush
+        assert!(aggregator.flush().is_empty());
+    }
+
+    #[test]
+    fn tick_bar_aggregator_should_complete_candle_every_10_ticks() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 2, 9, 30, 0).unwrap();
+        let mut aggregator = BarAggregator::new(BarInterval::Ticks(10), true);
+
+        let bars = (0..25)
+            .filter_map(|index| {
+                let price = 100.0 + index as f64;
+                aggregator.update(&tick(start + Duration::seconds(index), price))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(bars.len(), 2);
+
+        let first = candle(&bars[0]);
+        assert_eq!(first.close_time, start + Duration::seconds(9));
+        assert_eq!((first.open, first.high, first.low), (100.0, 109.0, 100.0));
+        assert_eq!((first.close, first.trade_count), (109.0, 10));
+        assert_eq!(candle(&bars[1]).open, 110.0);
+
+        // Remaining 5 ticks are flushed as a partial bar
+        let flushed = aggregator.flush();
+        assert_eq!(flushed.len(), 1);
+        let partial = candle(&flushed[0]);
+        assert_eq!((partial.open, partial.close), (120.0, 124.0));
+        assert_eq!(partial.trade_count, 5);
+        assert!(aggregator.flush().is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Best bid or ask of an [`Instrument`] quoted by a single venue.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct VenueQuote {
+    /// Venue quoting the price.
+    pub exchange: Exchange,
+    pub price: f64,
+    pub amount: f64,
+    /// Exchange time of the [`MarketEvent`] the quote was received with.
+    pub time: DateTime<Utc>,
+}
+
+/// Consolidated top-of-book of each logical [`Instrument`] (eg/ btc_usdt spot) across every
+/// venue quoting it, so a strategy trading the same [`Instrument`] on several exchanges can route
+/// to the venue with the best price.
+///
+/// Maintained from the [`DataKind::OrderBookL1`] [`MarketEvent`]s of each venue, every other
+/// [`MarketEvent`] is ignored. Quotes older than the time-to-live are stale & excluded from the
+/// consolidation, so a venue that stops quoting cannot hold the best price.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ConsolidatedBook {
+    ttl: Duration,
+    /// Latest best bid & ask quoted by each venue of each [`Instrument`].
+    quotes: HashMap<Instrument, BTreeMap<Exchange, (VenueQuote, VenueQuote)>>,
+}
+
+impl ConsolidatedBook {
+    /// Constructs a new empty [`ConsolidatedBook`] excluding quotes older than the provided
+    /// time-to-live.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            quotes: HashMap::new(),
+        }
+    }
+
+    /// Updates the best bid & ask quoted by the venue of the [`DataKind::OrderBookL1`]
+    /// [`MarketEvent`]. Quotes older than the venue's latest quote are ignored.
+    pub fn update_from_market(&mut self, market: &MarketEvent<DataKind>) {
+        let DataKind::OrderBookL1(book) = &market.kind else {
+            return;
+        };
+
+        let quote = |price, amount| VenueQuote {
+            exchange: market.exchange.clone(),
+            price,
+            amount,
+            time: market.exchange_time,
+        };
+        let venues = self.quotes.entry(market.instrument.clone()).or_default();
+        match venues.get(&market.exchange) {
+            Some((bid, _)) if bid.time > market.exchange_time => {}
+            _ => {
+                venues.insert(
+                    market.exchange.clone(),
+                    (
+                        quote(book.best_bid.price, book.best_bid.amount),
+                        quote(book.best_ask.price, book.best_ask.amount),
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Returns the highest bid of the [`Instrument`] quoted by any venue with a quote that is
+    /// not stale at the provided time, or `None` if no venue is quoting it. Ties are awarded to
+    /// the first venue in [`Exchange`] order.
+    pub fn best_bid(&self, instrument: &Instrument, time: DateTime<Utc>) -> Option<&VenueQuote> {
+        self.fresh_quotes(instrument, time)
+            .map(|(bid, _)| bid)
+            .filter(|bid| bid.price > 0.0)
+            .fold(None, |best, bid| match best {
+                Some(best) if best.price >= bid.price => Some(best),
+                _ => Some(bid),
+            })
+    }
+
+    /// Returns the lowest ask of the [`Instrument`] quoted by any venue with a quote that is
+    /// not stale at the provided time, or `None` if no venue is quoting it. Ties are awarded to
+    /// the first venue in [`Exchange`] order.
+    pub fn best_ask(&self, instrument: &Instrument, time: DateTime<Utc>) -> Option<&VenueQuote> {
+        self.fresh_quotes(instrument, time)
+            .map(|(_, ask)| ask)
+            .filter(|ask| ask.price > 0.0)
+            .fold(None, |best, ask| match best {
+                Some(best) if best.price <= ask.price => Some(best),
+                _ => Some(ask),
+            })
+    }
+
+    /// Returns the best bid & ask quoted by every venue of the [`Instrument`] whose quote is not
+    /// stale at the provided time, in [`Exchange`] order.
+    fn fresh_quotes<'a>(
+        &'a self,
+        instrument: &Instrument,
+        time: DateTime<Utc>,
+    ) -> impl Iterator<Item = &'a (VenueQuote, VenueQuote)> {
+        self.quotes
+            .get(instrument)
+            .into_iter()
+            .flat_map(BTreeMap::values)
+            .filter(move |(bid, _)| time - bid.time <= self.ttl)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_data::subscription::book::{Level, OrderBookL1};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::TimeZone;
+
+    fn quote(
+        exchange: &'static str,
+        time: DateTime<Utc>,
+        bid: f64,
+        ask: f64,
+    ) -> MarketEvent<DataKind> {
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(exchange),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: time,
+                best_bid: Level::new(bid, 1.0),
+                best_ask: Level::new(ask, 1.0),
+            }),
+        }
+    }
+
+    #[test]
+    fn consolidated_book_should_reflect_best_venue_and_exclude_stale_quotes() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 2, 9, 30, 0).unwrap();
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let mut book = ConsolidatedBook::new(Duration::seconds(5));
+
+        book.update_from_market(&quote("binance", start, 100.0, 101.0));
+        book.update_from_market(&quote("kraken", start + Duration::seconds(1), 100.5, 101.5));
+
+        // Kraken bids higher, whereas Binance offers lower
+        let now = start + Duration::seconds(2);
+        let best_bid = book.best_bid(&instrument, now).unwrap();
+        assert_eq!(best_bid.exchange, Exchange::from("kraken"));
+        assert_eq!(best_bid.price, 100.5);
+        let best_ask = book.best_ask(&instrument, now).unwrap();
+        assert_eq!(best_ask.exchange, Exchange::from("binance"));
+        assert_eq!(best_ask.price, 101.0);
+
+        // Binance quote expires, so consolidation falls back to the fresh Kraken quote
+        book.update_from_market(&quote("kraken", start + Duration::seconds(6), 99.5, 100.5));
+        let now = start + Duration::seconds(6);
+        let best_bid = book.best_bid(&instrument, now).unwrap();
+        assert_eq!(best_bid.exchange, Exchange::from("kraken"));
+        assert_eq!(best_bid.price, 99.5);
+        let best_ask = book.best_ask(&instrument, now).unwrap();
+        assert_eq!(best_ask.exchange, Exchange::from("kraken"));
+        assert_eq!(best_ask.price, 100.5);
+
+        // Every quote is stale once both venues stop quoting
+        assert_eq!(
+            book.best_bid(&instrument, now + Duration::seconds(10)),
+            None
+        );
+    }
+}
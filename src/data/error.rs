@@ -15,4 +15,15 @@ pub enum DataError {
 
     #[error("Barter-Data: {0}")]
     Data(#[from] barter_data::error::DataError),
+
+    #[error("IO: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[cfg(feature = "parquet")]
+    #[error("Parquet: {0}")]
+    Parquet(#[from] ::parquet::errors::ParquetError),
+
+    #[cfg(feature = "parquet")]
+    #[error("Parquet file does not match the OHLCV candle schema: {0}")]
+    ParquetSchema(String),
 }
@@ -1,4 +1,8 @@
 use crate::data::{Feed, MarketGenerator};
+use barter_data::event::MarketEvent;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
 
 /// Historical [`Feed`] of market events.
 #[derive(Debug)]
@@ -35,3 +39,249 @@ where
         }
     }
 }
+
+impl<T> MarketFeed<std::vec::IntoIter<MarketEvent<T>>, MarketEvent<T>> {
+    /// Construct a historical [`MarketFeed`] that replays the provided recorded [`MarketEvent`]s
+    /// in exchange timestamp order. Events sharing a timestamp are yielded in insertion order.
+    /// Once every recorded event has been yielded the [`MarketFeed`] yields [`Feed::Finished`].
+    pub fn from_recorded(mut events: Vec<MarketEvent<T>>) -> Self {
+        // Stable sort preserves insertion order of events with an identical timestamp
+        events.sort_by_key(|event| event.exchange_time);
+        Self::new(events)
+    }
+}
+
+/// Speed a [`ReplayFeed`] replays historical [`MarketEvent`]s at, relative to the gaps between
+/// their exchange timestamps.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub enum ReplaySpeed {
+    /// Replays every [`MarketEvent`] as fast as possible.
+    #[default]
+    Unlimited,
+    /// Replays at a multiple of real time (eg/ 10.0 for 10x), sleeping between [`MarketEvent`]s
+    /// for the gap between their exchange timestamps divided by the factor. A factor that is
+    /// not positive is treated as [`ReplaySpeed::Unlimited`].
+    Factor(f64),
+}
+
+/// Historical [`Feed`] that paces the [`MarketEvent`]s of a [`MarketFeed`] according to a
+/// [`ReplaySpeed`], eg/ to watch a backtest unfold at 10x real time.
+///
+/// Optionally only replays a time window of the [`MarketFeed`] (eg/ a single trading day of a
+/// large recording), skipping [`MarketEvent`]s before the `from` bound & finishing at the first
+/// [`MarketEvent`] at or after the `to` bound, so the window includes `from` & excludes `to`.
+/// Skipped [`MarketEvent`]s never reach the [`Trader`](crate::engine::trader::Trader), so any
+/// warm up is consumed by the first [`MarketEvent`]s within the window.
+///
+/// Only wall-clock time is paced: a [`SimulatedClock`](crate::clock::SimulatedClock) is still
+/// advanced by the exchange timestamp of each [`MarketEvent`]. Each [`MarketEvent`] is paced
+/// relative to the first, so time spent processing an event does not accumulate as drift.
+#[derive(Debug)]
+pub struct ReplayFeed<Iter, T>
+where
+    Iter: Iterator<Item = MarketEvent<T>>,
+{
+    feed: MarketFeed<Iter, MarketEvent<T>>,
+    speed: ReplaySpeed,
+    /// Inclusive exchange timestamp before which [`MarketEvent`]s are skipped.
+    from: Option<DateTime<Utc>>,
+    /// Exclusive exchange timestamp at which the replay finishes.
+    to: Option<DateTime<Utc>>,
+    /// Whether a [`MarketEvent`] at or after the `to` bound has been reached.
+    ended: bool,
+    /// Exchange timestamp of the first [`MarketEvent`] replayed, and when it was replayed.
+    start: Option<(DateTime<Utc>, Instant)>,
+}
+
+impl<Iter, T> MarketGenerator<MarketEvent<T>> for ReplayFeed<Iter, T>
+where
+    Iter: Iterator<Item = MarketEvent<T>>,
+{
+    fn next(&mut self) -> Feed<MarketEvent<T>> {
+        if self.ended {
+            return Feed::Finished;
+        }
+
+        let event = loop {
+            match self.feed.next() {
+                Feed::Next(event) if self.from.is_some_and(|from| event.exchange_time < from) => {
+                    continue
+                }
+                Feed::Next(event) => break event,
+                feed => return feed,
+            }
+        };
+
+        if self.to.is_some_and(|to| event.exchange_time >= to) {
+            self.ended = true;
+            return Feed::Finished;
+        }
+
+        let ReplaySpeed::Factor(factor) = self.speed else {
+            return Feed::Next(event);
+        };
+        if factor <= 0.0 {
+            return Feed::Next(event);
+        }
+
+        match self.start {
+            None => self.start = Some((event.exchange_time, Instant::now())),
+            Some((start_time, started_at)) => {
+                let gap = (event.exchange_time - start_time)
+                    .to_std()
+                    .unwrap_or_default();
+                let due_at = started_at + gap.div_f64(factor);
+                std::thread::sleep(due_at.saturating_duration_since(Instant::now()));
+            }
+        }
+
+        Feed::Next(event)
+    }
+}
+
+impl<Iter, T> ReplayFeed<Iter, T>
+where
+    Iter: Iterator<Item = MarketEvent<T>>,
+{
+    /// Construct a [`ReplayFeed`] that replays the [`MarketEvent`]s of the provided
+    /// [`MarketFeed`] at the provided [`ReplaySpeed`].
+    pub fn new(feed: MarketFeed<Iter, MarketEvent<T>>, speed: ReplaySpeed) -> Self {
+        Self {
+            feed,
+            speed,
+            from: None,
+            to: None,
+            ended: false,
+            start: None,
+        }
+    }
+
+    /// Skips every [`MarketEvent`] with an exchange timestamp before the provided time.
+    pub fn with_from(mut self, value: DateTime<Utc>) -> Self {
+        self.from = Some(value);
+        self
+    }
+
+    /// Finishes the replay at the first [`MarketEvent`] with an exchange timestamp at or after
+    /// the provided time, without replaying it.
+    pub fn with_to(mut self, value: DateTime<Utc>) -> Self {
+        self.to = Some(value);
+        self
+    }
+
+    /// Returns the [`ReplaySpeed`] of this [`ReplayFeed`].
+    pub fn speed(&self) -> ReplaySpeed {
+        self.speed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::model::Side;
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn recorded_market_feed_should_replay_events_in_timestamp_order_until_finished() {
+        let base_time = Utc::now();
+
+        let mut first = market_event_trade(Side::Buy);
+        first.exchange_time = base_time;
+        let mut second = market_event_trade(Side::Sell);
+        second.exchange_time = base_time + Duration::seconds(1);
+        let mut third = market_event_trade(Side::Buy);
+        third.exchange_time = base_time + Duration::seconds(2);
+
+        let mut feed =
+            MarketFeed::from_recorded(vec![third.clone(), first.clone(), second.clone()]);
+
+        assert_eq!(feed.next(), Feed::Next(first));
+        assert_eq!(feed.next(), Feed::Next(second));
+        assert_eq!(feed.next(), Feed::Next(third));
+        assert_eq!(feed.next(), Feed::Finished);
+    }
+
+    #[test]
+    fn recorded_market_feed_should_preserve_insertion_order_of_events_with_equal_timestamps() {
+        let time = Utc::now();
+
+        let mut buy = market_event_trade(Side::Buy);
+        buy.exchange_time = time;
+        let mut sell = market_event_trade(Side::Sell);
+        sell.exchange_time = time;
+
+        let mut feed = MarketFeed::from_recorded(vec![sell.clone(), buy.clone()]);
+
+        assert_eq!(feed.next(), Feed::Next(sell));
+        assert_eq!(feed.next(), Feed::Next(buy));
+        assert_eq!(feed.next(), Feed::Finished);
+    }
+
+    /// Returns a pair of [`MarketEvent`]s one simulated minute apart.
+    fn events_one_minute_apart() -> Vec<MarketEvent<barter_data::event::DataKind>> {
+        let first = market_event_trade(Side::Buy);
+        let mut second = market_event_trade(Side::Sell);
+        second.exchange_time = first.exchange_time + Duration::minutes(1);
+        vec![first, second]
+    }
+
+    #[test]
+    fn replay_feed_at_10x_should_deliver_events_a_tenth_of_their_gap_apart() {
+        let events = events_one_minute_apart();
+        let mut feed = ReplayFeed::new(
+            MarketFeed::from_recorded(events.clone()),
+            ReplaySpeed::Factor(10.0),
+        );
+
+        assert_eq!(feed.next(), Feed::Next(events[0].clone()));
+        let first_delivered_at = Instant::now();
+        assert_eq!(feed.next(), Feed::Next(events[1].clone()));
+        let elapsed = first_delivered_at.elapsed();
+        assert_eq!(feed.next(), Feed::Finished);
+
+        // Delivered roughly six wall-clock seconds apart
+        assert!(
+            (5.9..6.5).contains(&elapsed.as_secs_f64()),
+            "elapsed {elapsed:?}"
+        );
+    }
+
+    #[test]
+    fn replay_feed_with_window_should_only_replay_events_from_inclusive_to_exclusive() {
+        let from = Utc::now();
+        let to = from + Duration::days(1);
+        let events = [-1, 0, 12, 24, 36]
+            .map(|hours| {
+                let mut event = market_event_trade(Side::Buy);
+                event.exchange_time = from + Duration::hours(hours);
+                event
+            })
+            .to_vec();
+
+        let mut feed = ReplayFeed::new(
+            MarketFeed::from_recorded(events.clone()),
+            ReplaySpeed::Unlimited,
+        )
+        .with_from(from)
+        .with_to(to);
+
+        assert_eq!(feed.next(), Feed::Next(events[1].clone()));
+        assert_eq!(feed.next(), Feed::Next(events[2].clone()));
+        assert_eq!(feed.next(), Feed::Finished);
+        assert_eq!(feed.next(), Feed::Finished);
+    }
+
+    #[test]
+    fn replay_feed_at_unlimited_speed_should_deliver_events_without_sleeping() {
+        let started_at = Instant::now();
+        let mut feed = ReplayFeed::new(
+            MarketFeed::from_recorded(events_one_minute_apart()),
+            ReplaySpeed::Unlimited,
+        );
+
+        while feed.next() != Feed::Finished {}
+
+        assert!(started_at.elapsed() < std::time::Duration::from_secs(1));
+    }
+}
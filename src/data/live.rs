@@ -1,7 +1,28 @@
-use super::{Feed, MarketGenerator};
-use tokio::sync::mpsc;
+use super::{error::DataError, Feed, MarketGenerator};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    exchange::StreamSelector,
+    streams::Streams,
+    subscription::{SubKind, Subscription},
+    Identifier,
+};
+use barter_integration::model::Market;
+use parking_lot::Mutex;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::sync::{mpsc, Notify};
+use tracing::{debug, error, warn};
 
-/// Live [`Feed`] of market events.
+/// Live [`Feed`] of market events. Yields [`Feed::Idle`] whenever no market event is waiting,
+/// rather than blocking, so the consumer can action other work during quiet markets.
 #[derive(Debug)]
 pub struct MarketFeed<Event> {
     pub market_rx: mpsc::UnboundedReceiver<Event>,
@@ -9,12 +30,10 @@ pub struct MarketFeed<Event> {
 
 impl<Event> MarketGenerator<Event> for MarketFeed<Event> {
     fn next(&mut self) -> Feed<Event> {
-        loop {
-            match self.market_rx.try_recv() {
-                Ok(event) => break Feed::Next(event),
-                Err(mpsc::error::TryRecvError::Empty) => continue,
-                Err(mpsc::error::TryRecvError::Disconnected) => break Feed::Finished,
-            }
+        match self.market_rx.try_recv() {
+            Ok(event) => Feed::Next(event),
+            Err(mpsc::error::TryRecvError::Empty) => Feed::Idle,
+            Err(mpsc::error::TryRecvError::Disconnected) => Feed::Finished,
         }
     }
 }
@@ -34,3 +53,617 @@ impl<Event> MarketFeed<Event> {
         Self { market_rx }
     }
 }
+
+impl<Event> Default for MarketFeed<Event> {
+    /// Constructs a [`MarketFeed`] that is not yet subscribed to a market event stream, and so is
+    /// immediately [`Feed::Finished`]. Used as a placeholder for the
+    /// [`Trader`](crate::engine::trader::Trader)s of an
+    /// [`EngineBuilder::with_market_streams`](crate::engine::EngineBuilder::with_market_streams),
+    /// which replaces it with a subscribed [`MarketFeed`].
+    fn default() -> Self {
+        Self::new(mpsc::unbounded_channel().1)
+    }
+}
+
+/// Policy a [`BoundedMarketFeed`] applies to a market event sent whilst it is at capacity.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum OverflowPolicy {
+    /// Wait until the consumer makes room, applying backpressure to the producer.
+    #[default]
+    Block,
+    /// Drop the oldest buffered market event to make room, so the freshest prices are retained.
+    /// Recommended for live trading, where stale prices are worth little.
+    DropOldest,
+    /// Drop the market event being sent, retaining every buffered market event.
+    DropNewest,
+}
+
+/// Live [`Feed`] of market events buffered in a queue of bounded capacity, so memory cannot
+/// balloon if the consumer falls behind (eg/ during a market storm). Market events sent by a
+/// [`BoundedMarketTx`] whilst the queue is at capacity are handled by the [`OverflowPolicy`],
+/// and the number dropped is reported via [`MarketGenerator::dropped_events`].
+///
+/// Like a [`MarketFeed`], it yields [`Feed::Idle`] whenever no market event is waiting, and is
+/// [`Feed::Finished`] once every [`BoundedMarketTx`] is dropped & the queue is drained.
+#[derive(Debug)]
+pub struct BoundedMarketFeed<Event> {
+    shared: Arc<BoundedShared<Event>>,
+}
+
+/// Sender half of a [`BoundedMarketFeed`]. Cloning a [`BoundedMarketTx`] returns another sender
+/// to the same queue.
+#[derive(Debug)]
+pub struct BoundedMarketTx<Event> {
+    shared: Arc<BoundedShared<Event>>,
+}
+
+#[derive(Debug)]
+struct BoundedShared<Event> {
+    queue: Mutex<BoundedQueue<Event>>,
+    /// Notified whenever the [`BoundedMarketFeed`] takes a market event from the queue.
+    space: Notify,
+}
+
+#[derive(Debug)]
+struct BoundedQueue<Event> {
+    events: VecDeque<Event>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    dropped: u64,
+    senders: usize,
+    receiver_dropped: bool,
+}
+
+impl<Event> BoundedMarketFeed<Event> {
+    /// Constructs a [`BoundedMarketFeed`] buffering up to `capacity` market events (at least
+    /// one), & the [`BoundedMarketTx`] used to send market events to it.
+    pub fn channel(capacity: usize, policy: OverflowPolicy) -> (BoundedMarketTx<Event>, Self) {
+        let capacity = capacity.max(1);
+        let shared = Arc::new(BoundedShared {
+            queue: Mutex::new(BoundedQueue {
+                events: VecDeque::with_capacity(capacity),
+                capacity,
+                policy,
+                dropped: 0,
+                senders: 1,
+                receiver_dropped: false,
+            }),
+            space: Notify::new(),
+        });
+
+        (
+            BoundedMarketTx {
+                shared: Arc::clone(&shared),
+            },
+            Self { shared },
+        )
+    }
+}
+
+impl<Event> MarketGenerator<Event> for BoundedMarketFeed<Event> {
+    fn next(&mut self) -> Feed<Event> {
+        let mut queue = self.shared.queue.lock();
+        match queue.events.pop_front() {
+            Some(event) => {
+                self.shared.space.notify_one();
+                Feed::Next(event)
+            }
+            None if queue.senders == 0 => Feed::Finished,
+            None => Feed::Idle,
+        }
+    }
+
+    fn dropped_events(&self) -> u64 {
+        self.shared.queue.lock().dropped
+    }
+}
+
+impl<Event> Drop for BoundedMarketFeed<Event> {
+    fn drop(&mut self) {
+        self.shared.queue.lock().receiver_dropped = true;
+        self.shared.space.notify_waiters();
+    }
+}
+
+impl<Event> BoundedMarketTx<Event> {
+    /// Sends a market event to the [`BoundedMarketFeed`], applying the [`OverflowPolicy`] if it
+    /// is at capacity. With [`OverflowPolicy::Block`] this waits until the [`BoundedMarketFeed`]
+    /// makes room. Returns the market event if the [`BoundedMarketFeed`] has been dropped.
+    pub async fn send(&self, event: Event) -> Result<(), Event> {
+        loop {
+            let space = self.shared.space.notified();
+            {
+                let mut queue = self.shared.queue.lock();
+                if queue.receiver_dropped {
+                    return Err(event);
+                }
+
+                if queue.events.len() < queue.capacity {
+                    queue.events.push_back(event);
+                    return Ok(());
+                }
+
+                match queue.policy {
+                    OverflowPolicy::Block => {}
+                    OverflowPolicy::DropOldest => {
+                        queue.events.pop_front();
+                        queue.events.push_back(event);
+                        queue.dropped += 1;
+                        return Ok(());
+                    }
+                    OverflowPolicy::DropNewest => {
+                        queue.dropped += 1;
+                        return Ok(());
+                    }
+                }
+            }
+            space.await;
+        }
+    }
+}
+
+impl<Event> Clone for BoundedMarketTx<Event> {
+    fn clone(&self) -> Self {
+        self.shared.queue.lock().senders += 1;
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<Event> Drop for BoundedMarketTx<Event> {
+    fn drop(&mut self) {
+        self.shared.queue.lock().senders -= 1;
+    }
+}
+
+/// Communicative type alias for the [`Future`] that initialises a market event stream of
+/// [`MarketSubscriptions`].
+type StreamFuture = Pin<
+    Box<dyn Future<Output = Result<mpsc::UnboundedReceiver<MarketEvent<DataKind>>, DataError>>>,
+>;
+
+/// Collection of `Barter-Data` market event stream subscriptions that, once initialised, are
+/// routed to a live [`MarketFeed`] for each subscribed [`Market`]. See
+/// [`EngineBuilder::with_market_streams`](crate::engine::EngineBuilder::with_market_streams).
+#[derive(Default)]
+pub struct MarketSubscriptions {
+    markets: Vec<Market>,
+    streams: Vec<StreamFuture>,
+}
+
+impl Debug for MarketSubscriptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarketSubscriptions")
+            .field("markets", &self.markets)
+            .field("num_streams", &self.streams.len())
+            .finish()
+    }
+}
+
+impl MarketSubscriptions {
+    /// Constructs a new [`MarketSubscriptions`] with no subscriptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a collection of `Barter-Data` [`Subscription`]s that will be actioned on a distinct
+    /// WebSocket connection, as per
+    /// [`StreamBuilder::subscribe`](barter_data::streams::builder::StreamBuilder::subscribe).
+    ///
+    /// Note that [`Subscription`]s are not actioned until [`MarketSubscriptions::init`].
+    pub fn subscribe<SubIter, Sub, Exchange, Kind>(mut self, subscriptions: SubIter) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: SubKind + Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+        MarketEvent<DataKind>: From<MarketEvent<Kind::Event>>,
+    {
+        let subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+        self.markets.extend(
+            subscriptions
+                .iter()
+                .map(|subscription| Market::new(Exchange::ID, subscription.instrument.clone())),
+        );
+
+        self.streams.push(Box::pin(async move {
+            let mut kind_rx = Streams::<MarketEvent<Kind::Event>>::builder::<Kind>()
+                .subscribe(subscriptions)
+                .init()
+                .await?
+                .join()
+                .await;
+
+            // Convert each MarketEvent<Kind::Event> into the MarketEvent<DataKind> Traders consume
+            let (market_tx, market_rx) = mpsc::unbounded_channel();
+            tokio::spawn(async move {
+                while let Some(event) = kind_rx.recv().await {
+                    if market_tx.send(MarketEvent::from(event)).is_err() {
+                        break;
+                    }
+                }
+            });
+
+            Ok(market_rx)
+        }));
+
+        self
+    }
+
+    /// Adds an already initialised market event stream of the provided [`Market`]s (eg/ from a
+    /// custom data source).
+    pub fn stream<Markets>(
+        mut self,
+        markets: Markets,
+        market_rx: mpsc::UnboundedReceiver<MarketEvent<DataKind>>,
+    ) -> Self
+    where
+        Markets: IntoIterator<Item = Market>,
+    {
+        self.markets.extend(markets);
+        self.streams
+            .push(Box::pin(std::future::ready(Ok(market_rx))));
+        self
+    }
+
+    /// Returns an iterator over every subscribed [`Market`].
+    pub fn markets(&self) -> impl Iterator<Item = &Market> {
+        self.markets.iter()
+    }
+
+    /// Initialises every market event stream, and spawns tasks that route each
+    /// [`MarketEvent`] to the live [`MarketFeed`] of it's [`Market`]. Each [`MarketFeed`] is
+    /// [`Feed::Finished`] once every market event stream has ended.
+    pub async fn init(
+        self,
+    ) -> Result<HashMap<Market, MarketFeed<MarketEvent<DataKind>>>, DataError> {
+        let streams = futures::future::try_join_all(self.streams).await?;
+
+        let (market_txs, feeds): (HashMap<_, _>, HashMap<_, _>) = self
+            .markets
+            .into_iter()
+            .map(|market| {
+                let (market_tx, market_rx) = mpsc::unbounded_channel();
+                (
+                    (market.clone(), market_tx),
+                    (market, MarketFeed::new(market_rx)),
+                )
+            })
+            .unzip();
+
+        for mut stream_rx in streams {
+            let market_txs = market_txs.clone();
+            tokio::spawn(async move {
+                while let Some(event) = stream_rx.recv().await {
+                    let market = Market::new(event.exchange.clone(), event.instrument.clone());
+                    match market_txs.get(&market) {
+                        Some(market_tx) => {
+                            let _ = market_tx.send(event);
+                        }
+                        None => debug!(
+                            ?market,
+                            "dropping MarketEvent of a Market that is not subscribed"
+                        ),
+                    }
+                }
+            });
+        }
+
+        Ok(feeds)
+    }
+}
+
+/// Configuration for the reconnection backoff of a [`ReconnectingMarketFeed`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ReconnectConfig {
+    /// Delay before the first reconnection attempt, doubled for each consecutive failure.
+    pub base_delay: Duration,
+    /// Maximum delay between reconnection attempts, excluding jitter.
+    pub max_delay: Duration,
+    /// Upper bound of the random jitter added to each delay, in decimal form of the delay
+    /// (eg/ 0.1 for up to 10%).
+    pub jitter_pct: f64,
+    /// Number of consecutive failed reconnection attempts after which the feed is finished.
+    pub max_attempts: u32,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter_pct: 0.1,
+            max_attempts: 10,
+        }
+    }
+}
+
+impl ReconnectConfig {
+    /// Calculates the backoff delay before the next reconnection attempt, given the number of
+    /// consecutive failed attempts so far, drawing the jitter from the provided [`Rng`]. The
+    /// first attempt is made immediately.
+    pub fn delay<R>(&self, failed_attempts: u32, rng: &mut R) -> Duration
+    where
+        R: Rng + ?Sized,
+    {
+        if failed_attempts == 0 {
+            return Duration::ZERO;
+        }
+
+        let delay = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(failed_attempts - 1))
+            .min(self.max_delay);
+
+        let jitter = rng.gen_range(0.0..=self.jitter_pct.max(0.0));
+        delay.mul_f64(1.0 + jitter)
+    }
+}
+
+/// Live [`Feed`] of market events that reconnects the underlying market event stream with an
+/// exponential backoff if it disconnects. Whilst reconnecting the [`Feed`] is
+/// [`Feed::Unhealthy`], and after [`ReconnectConfig::max_attempts`] consecutive failed
+/// reconnection attempts it is [`Feed::Finished`].
+///
+/// Note that reconnecting blocks the calling thread, which is expected to be a dedicated
+/// [`Trader`](crate::engine::trader::Trader) thread.
+#[derive(Debug)]
+pub struct ReconnectingMarketFeed<Event, Connect>
+where
+    Connect: FnMut() -> Result<mpsc::UnboundedReceiver<Event>, DataError>,
+{
+    connect: Connect,
+    config: ReconnectConfig,
+    market_rx: Option<mpsc::UnboundedReceiver<Event>>,
+    failed_attempts: u32,
+}
+
+impl<Event, Connect> MarketGenerator<Event> for ReconnectingMarketFeed<Event, Connect>
+where
+    Connect: FnMut() -> Result<mpsc::UnboundedReceiver<Event>, DataError>,
+{
+    fn next(&mut self) -> Feed<Event> {
+        // Yield the next event from the connected stream, if there is one
+        if let Some(market_rx) = &mut self.market_rx {
+            match market_rx.try_recv() {
+                Ok(event) => return Feed::Next(event),
+                Err(mpsc::error::TryRecvError::Empty) => return Feed::Idle,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    warn!(action = "reconnecting", "market event stream disconnected");
+                    self.market_rx = None;
+                    return Feed::Unhealthy;
+                }
+            }
+        }
+
+        if self.failed_attempts >= self.config.max_attempts {
+            error!(
+                failed_attempts = self.failed_attempts,
+                action = "finishing Feed",
+                "failed to reconnect market event stream"
+            );
+            return Feed::Finished;
+        }
+
+        std::thread::sleep(
+            self.config
+                .delay(self.failed_attempts, &mut rand::thread_rng()),
+        );
+
+        match (self.connect)() {
+            Ok(market_rx) => {
+                self.market_rx = Some(market_rx);
+                self.failed_attempts = 0;
+            }
+            Err(error) => {
+                self.failed_attempts += 1;
+                warn!(
+                    ?error,
+                    failed_attempts = self.failed_attempts,
+                    "failed to connect market event stream"
+                );
+            }
+        }
+
+        Feed::Unhealthy
+    }
+}
+
+impl<Event, Connect> ReconnectingMarketFeed<Event, Connect>
+where
+    Connect: FnMut() -> Result<mpsc::UnboundedReceiver<Event>, DataError>,
+{
+    /// Initialises a [`ReconnectingMarketFeed`] that connects (& reconnects) to a market event
+    /// stream using the provided `connect` function. The initial connection is made on the
+    /// first call to [`MarketGenerator::next`].
+    pub fn new(connect: Connect, config: ReconnectConfig) -> Self {
+        Self {
+            connect,
+            config,
+            market_rx: None,
+            failed_attempts: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn config(max_attempts: u32) -> ReconnectConfig {
+        ReconnectConfig {
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(4),
+            jitter_pct: 0.0,
+            max_attempts,
+        }
+    }
+
+    /// Yields the next [`Feed`] that is not [`Feed::Unhealthy`], up to a limit.
+    fn next_healthy<Event>(feed: &mut impl MarketGenerator<Event>) -> Feed<Event> {
+        for _ in 0..10 {
+            match feed.next() {
+                Feed::Unhealthy => continue,
+                healthy => return healthy,
+            }
+        }
+        panic!("Feed remained Unhealthy");
+    }
+
+    #[test]
+    fn reconnect_config_delay_doubles_until_max_delay() {
+        let config = config(10);
+
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert_eq!(config.delay(0, &mut rng), Duration::ZERO);
+        assert_eq!(config.delay(1, &mut rng), Duration::from_millis(1));
+        assert_eq!(config.delay(2, &mut rng), Duration::from_millis(2));
+        assert_eq!(config.delay(3, &mut rng), Duration::from_millis(4));
+        assert_eq!(config.delay(10, &mut rng), Duration::from_millis(4));
+    }
+
+    #[test]
+    fn reconnect_config_delay_jitter_is_bounded_by_jitter_pct() {
+        let config = ReconnectConfig {
+            jitter_pct: 0.5,
+            ..config(10)
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let delays = (0..100)
+            .map(|_| config.delay(1, &mut rng))
+            .collect::<Vec<_>>();
+
+        assert!(delays
+            .iter()
+            .all(|delay| (Duration::from_millis(1)..=Duration::from_micros(1500)).contains(delay)));
+        assert!(delays.iter().any(|delay| *delay != delays[0]));
+    }
+
+    #[test]
+    #[allow(clippy::result_large_err)]
+    fn reconnecting_market_feed_should_recover_after_failed_connections() {
+        let mut connections = 0;
+        let mut connected_txs = Vec::new();
+        let mut feed = ReconnectingMarketFeed::new(
+            || {
+                connections += 1;
+                match connections {
+                    // First two connection attempts fail
+                    1 | 2 => Err(DataError::BuilderIncomplete("connection")),
+                    // Third connection succeeds, then disconnects after one event
+                    3 => {
+                        let (tx, rx) = mpsc::unbounded_channel();
+                        tx.send(1).unwrap();
+                        Ok(rx)
+                    }
+                    // Fourth connection succeeds & stays connected
+                    _ => {
+                        let (tx, rx) = mpsc::unbounded_channel();
+                        tx.send(2).unwrap();
+                        connected_txs.push(tx);
+                        Ok(rx)
+                    }
+                }
+            },
+            config(3),
+        );
+
+        assert_eq!(next_healthy(&mut feed), Feed::Next(1));
+        assert_eq!(next_healthy(&mut feed), Feed::Next(2));
+    }
+
+    #[test]
+    #[allow(clippy::result_large_err)]
+    fn reconnecting_market_feed_should_finish_after_max_failed_connections() {
+        let mut feed = ReconnectingMarketFeed::<i32, _>::new(
+            || Err(DataError::BuilderIncomplete("connection")),
+            config(2),
+        );
+
+        assert_eq!(feed.next(), Feed::Unhealthy);
+        assert_eq!(feed.next(), Feed::Unhealthy);
+        assert_eq!(feed.next(), Feed::Finished);
+    }
+
+    /// Sends the events 1 to 5 to a [`BoundedMarketFeed`] of capacity 3 with the provided
+    /// [`OverflowPolicy`], returning the events it then yields & the number dropped.
+    async fn bounded_feed_retained(policy: OverflowPolicy) -> (Vec<i32>, u64) {
+        let (market_tx, mut feed) = BoundedMarketFeed::channel(3, policy);
+        for event in 1..=5 {
+            market_tx.send(event).await.unwrap();
+        }
+        drop(market_tx);
+
+        let mut retained = Vec::new();
+        while let Feed::Next(event) = feed.next() {
+            retained.push(event);
+        }
+        (retained, feed.dropped_events())
+    }
+
+    #[tokio::test]
+    async fn bounded_market_feed_drop_oldest_should_retain_newest_events() {
+        assert_eq!(
+            bounded_feed_retained(OverflowPolicy::DropOldest).await,
+            (vec![3, 4, 5], 2)
+        );
+    }
+
+    #[tokio::test]
+    async fn bounded_market_feed_drop_newest_should_retain_oldest_events() {
+        assert_eq!(
+            bounded_feed_retained(OverflowPolicy::DropNewest).await,
+            (vec![1, 2, 3], 2)
+        );
+    }
+
+    #[tokio::test]
+    async fn bounded_market_feed_block_should_wait_for_capacity_without_dropping() {
+        use futures::FutureExt;
+
+        let (market_tx, mut feed) = BoundedMarketFeed::channel(3, OverflowPolicy::Block);
+        for event in 1..=3 {
+            market_tx.send(event).await.unwrap();
+        }
+
+        // Sending beyond capacity waits until the consumer makes room
+        let mut blocked = Box::pin(market_tx.send(4));
+        assert!((&mut blocked).now_or_never().is_none());
+        assert_eq!(feed.next(), Feed::Next(1));
+        blocked.await.unwrap();
+
+        // The queue is at capacity again, so the next send waits rather than dropping
+        assert!(market_tx.send(5).now_or_never().is_none());
+        drop(market_tx);
+
+        let mut retained = Vec::new();
+        while let Feed::Next(event) = feed.next() {
+            retained.push(event);
+        }
+        assert_eq!(retained, vec![2, 3, 4]);
+        assert_eq!(feed.dropped_events(), 0);
+    }
+
+    #[test]
+    fn market_feed_should_yield_idle_until_next_event_is_waiting() {
+        let (market_tx, market_rx) = mpsc::unbounded_channel();
+        let mut feed = MarketFeed::new(market_rx);
+
+        assert_eq!(feed.next(), Feed::Idle);
+
+        market_tx.send(1).unwrap();
+        assert_eq!(feed.next(), Feed::Next(1));
+        assert_eq!(feed.next(), Feed::Idle);
+
+        drop(market_tx);
+        assert_eq!(feed.next(), Feed::Finished);
+    }
+}
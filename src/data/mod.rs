@@ -1,4 +1,7 @@
+use crate::portfolio::decimal_from_f64;
+use barter_data::event::{DataKind, MarketEvent};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Barter data module specific errors.
@@ -10,16 +13,42 @@ pub mod live;
 /// Historical market event feed for backtesting.
 pub mod historical;
 
+/// Historical market event feed read lazily from columnar Parquet files, for backtesting over
+/// files too large for memory.
+#[cfg(feature = "parquet")]
+pub mod parquet;
+
+/// Aggregation of market events into time or tick-count bars, so strategies can trade candles
+/// built from a tick feed.
+pub mod bar;
+
+/// Reorder buffer releasing market events that interleave out of order in exchange timestamp
+/// order.
+pub mod reorder;
+
+/// Consolidated top-of-book of each instrument across every venue quoting it, so strategies can
+/// route to the venue with the best price.
+pub mod book;
+
 /// Generates the next `Event`. Acts as the system heartbeat.
 pub trait MarketGenerator<Event> {
     /// Return the next market `Event`.
     fn next(&mut self) -> Feed<Event>;
+
+    /// Returns the number of market `Event`s dropped rather than yielded (eg/ by a
+    /// [`BoundedMarketFeed`](live::BoundedMarketFeed) at capacity). Defaults to zero for
+    /// generators that never drop market `Event`s.
+    fn dropped_events(&self) -> u64 {
+        0
+    }
 }
 
 /// Communicates the state of the [`Feed`] as well as the next event.
 #[derive(Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub enum Feed<Event> {
     Next(Event),
+    /// No new event is available yet, but the [`Feed`] is healthy (eg/ during a quiet market).
+    Idle,
     Unhealthy,
     Finished,
 }
@@ -29,16 +58,36 @@ pub enum Feed<Event> {
 /// timestamp. Used to propagate key market information in downstream Events.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct MarketMeta {
-    /// Close value from the source market event.
-    pub close: f64,
+    /// Close value from the source market event, converted from the barter-data `f64` price.
+    pub close: Decimal,
     /// Exchange timestamp from the source market event.
     pub time: DateTime<Utc>,
 }
 
+impl MarketMeta {
+    /// Constructs [`MarketMeta`] from the close price & exchange timestamp of the provided
+    /// [`MarketEvent`]. Returns `None` if the [`DataKind`] does not communicate a price
+    /// (eg/ [`DataKind::Liquidation`]).
+    pub fn from_market(market: &MarketEvent<DataKind>) -> Option<Self> {
+        let close = match &market.kind {
+            DataKind::Trade(trade) => trade.price,
+            DataKind::Candle(candle) => candle.close,
+            DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price(),
+            DataKind::OrderBook(book) => book.volume_weighed_mid_price()?,
+            DataKind::Liquidation(_) => return None,
+        };
+
+        Some(Self {
+            close: decimal_from_f64(close),
+            time: market.exchange_time,
+        })
+    }
+}
+
 impl Default for MarketMeta {
     fn default() -> Self {
         Self {
-            close: 100.0,
+            close: Decimal::ONE_HUNDRED,
             time: Utc::now(),
         }
     }
@@ -0,0 +1,399 @@
+use crate::data::{error::DataError, Feed, MarketGenerator};
+use ::parquet::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
+use arrow_array::{
+    cast::AsArray,
+    types::{
+        Float64Type, Int64Type, TimestampMicrosecondType, TimestampMillisecondType,
+        TimestampNanosecondType, TimestampSecondType, UInt64Type,
+    },
+    Array, RecordBatch,
+};
+use arrow_schema::{DataType, Schema, TimeUnit};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::candle::Candle,
+};
+use barter_integration::model::Market;
+use chrono::{DateTime, TimeZone, Utc};
+use std::{fs::File, path::Path};
+use tracing::{error, warn};
+
+/// Columns of the OHLCV [`Candle`] schema of a Parquet file read by a [`ParquetFeed`], matching
+/// the header of the CSV files read by a [`Backtest`](crate::backtest::Backtest).
+const COLUMNS: [&str; 7] = [
+    "close_time",
+    "open",
+    "high",
+    "low",
+    "close",
+    "volume",
+    "trade_count",
+];
+
+/// Historical [`Feed`] of [`DataKind::Candle`] [`MarketEvent`]s of a [`Market`], read lazily
+/// from a columnar Parquet file one row group at a time, so files too large for memory can be
+/// backtested.
+///
+/// The file must contain the OHLCV columns `close_time` (a timestamp of any unit), `open`,
+/// `high`, `low`, `close` & `volume` (`Float64`) and `trade_count` (`UInt64` or `Int64`), none
+/// of which may be null. Additional columns are ignored. Each row group is released in
+/// `close_time` order, so row groups must be written in timestamp order (eg/ by appending a
+/// recorded feed) - a row group starting before the previous one ended is replayed as is &
+/// logged.
+///
+/// Failing to decode a row group finishes the [`ParquetFeed`] early, with the [`DataError`]
+/// available via [`ParquetFeed::error`].
+pub struct ParquetFeed {
+    market: Market,
+    reader: ParquetRecordBatchReader,
+    /// [`MarketEvent`]s of the current row group yet to be yielded, in reverse order.
+    batch: Vec<MarketEvent<DataKind>>,
+    /// Exchange time of the latest [`MarketEvent`] yielded.
+    latest: Option<DateTime<Utc>>,
+    error: Option<DataError>,
+}
+
+impl std::fmt::Debug for ParquetFeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParquetFeed")
+            .field("market", &self.market)
+            .field("batch", &self.batch.len())
+            .field("latest", &self.latest)
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MarketGenerator<MarketEvent<DataKind>> for ParquetFeed {
+    fn next(&mut self) -> Feed<MarketEvent<DataKind>> {
+        while self.batch.is_empty() {
+            if self.error.is_some() {
+                return Feed::Finished;
+            }
+
+            match self.reader.next() {
+                Some(Ok(batch)) => match decode_candles(&batch, &self.market) {
+                    Ok(mut markets) => {
+                        // Stable sort preserves the row order of candles sharing a close_time
+                        markets.sort_by_key(|market| market.exchange_time);
+                        markets.reverse();
+                        self.batch = markets;
+                    }
+                    Err(reason) => self.fail(DataError::ParquetSchema(reason)),
+                },
+                Some(Err(error)) => self.fail(DataError::Parquet(error.into())),
+                None => return Feed::Finished,
+            }
+        }
+
+        let market = self.batch.pop().expect("batch is not empty");
+        if self
+            .latest
+            .is_some_and(|latest| market.exchange_time < latest)
+        {
+            warn!(
+                market = ?self.market,
+                exchange_time = %market.exchange_time,
+                "ParquetFeed row group starts before the previous row group ended"
+            );
+        }
+        self.latest = Some(market.exchange_time);
+        Feed::Next(market)
+    }
+}
+
+impl ParquetFeed {
+    /// Opens the Parquet file at the provided path as a [`ParquetFeed`] of [`DataKind::Candle`]
+    /// [`MarketEvent`]s of the provided [`Market`], validating the file against the expected
+    /// OHLCV schema. Row groups are only read as the [`ParquetFeed`] yields them.
+    #[allow(clippy::result_large_err)]
+    pub fn open<P>(path: P, market: Market) -> Result<Self, DataError>
+    where
+        P: AsRef<Path>,
+    {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?;
+        validate_schema(builder.schema()).map_err(DataError::ParquetSchema)?;
+
+        // Decode each row group as a single batch
+        let batch_size = builder
+            .metadata()
+            .row_groups()
+            .iter()
+            .map(|row_group| row_group.num_rows())
+            .max()
+            .unwrap_or_default()
+            .max(1);
+        let reader = builder
+            .with_batch_size(usize::try_from(batch_size).unwrap_or(usize::MAX))
+            .build()?;
+
+        Ok(Self {
+            market,
+            reader,
+            batch: Vec::new(),
+            latest: None,
+            error: None,
+        })
+    }
+
+    /// Returns the [`DataError`] that finished the [`ParquetFeed`] early, if any.
+    pub fn error(&self) -> Option<&DataError> {
+        self.error.as_ref()
+    }
+
+    fn fail(&mut self, error: DataError) {
+        error!(
+            market = ?self.market,
+            %error,
+            action = "finishing ParquetFeed early",
+            "failed to decode Parquet row group"
+        );
+        self.error = Some(error);
+    }
+}
+
+/// Validates the Parquet file [`Schema`] contains every OHLCV column with a supported
+/// [`DataType`], describing the first mismatch found.
+fn validate_schema(schema: &Schema) -> Result<(), String> {
+    for column in COLUMNS {
+        let field = schema
+            .field_with_name(column)
+            .map_err(|_| format!("missing column `{column}`"))?;
+
+        let supported = match column {
+            "close_time" => matches!(field.data_type(), DataType::Timestamp(_, _)),
+            "trade_count" => matches!(field.data_type(), DataType::UInt64 | DataType::Int64),
+            _ => field.data_type() == &DataType::Float64,
+        };
+        if !supported {
+            let expected = match column {
+                "close_time" => "Timestamp",
+                "trade_count" => "UInt64 or Int64",
+                _ => "Float64",
+            };
+            return Err(format!(
+                "column `{column}` has type {}, expected {expected}",
+                field.data_type()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Decodes every row of the [`RecordBatch`] into a [`DataKind::Candle`] [`MarketEvent`] of the
+/// [`Market`]. The [`RecordBatch`] schema must have been validated.
+fn decode_candles(
+    batch: &RecordBatch,
+    market: &Market,
+) -> Result<Vec<MarketEvent<DataKind>>, String> {
+    let column = |name: &str| {
+        let column = batch
+            .column_by_name(name)
+            .ok_or_else(|| format!("missing column `{name}`"))?;
+        match column.null_count() {
+            0 => Ok(column),
+            _ => Err(format!("column `{name}` contains null values")),
+        }
+    };
+    let float = |name: &str| column(name).map(|column| column.as_primitive::<Float64Type>());
+
+    let close_time = column("close_time")?;
+    let close_times = (0..batch.num_rows())
+        .map(|row| timestamp(close_time.as_ref(), row))
+        .collect::<Result<Vec<_>, _>>()?;
+    let (open, high, low, close, volume) = (
+        float("open")?,
+        float("high")?,
+        float("low")?,
+        float("close")?,
+        float("volume")?,
+    );
+    let trade_count = column("trade_count")?;
+    let trade_counts = match trade_count.data_type() {
+        DataType::UInt64 => trade_count.as_primitive::<UInt64Type>().values().to_vec(),
+        _ => trade_count
+            .as_primitive::<Int64Type>()
+            .values()
+            .iter()
+            .map(|count| u64::try_from(*count).unwrap_or_default())
+            .collect(),
+    };
+
+    Ok(close_times
+        .into_iter()
+        .enumerate()
+        .map(|(row, close_time)| MarketEvent {
+            exchange_time: close_time,
+            received_time: close_time,
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            kind: DataKind::Candle(Candle {
+                close_time,
+                open: open.value(row),
+                high: high.value(row),
+                low: low.value(row),
+                close: close.value(row),
+                volume: volume.value(row),
+                trade_count: trade_counts[row],
+            }),
+        })
+        .collect())
+}
+
+/// Converts the value of the timestamp [`Array`] at the provided row into a UTC time.
+fn timestamp(column: &dyn Array, row: usize) -> Result<DateTime<Utc>, String> {
+    let nanos = match column.data_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => column
+            .as_primitive::<TimestampSecondType>()
+            .value(row)
+            .checked_mul(1_000_000_000),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => column
+            .as_primitive::<TimestampMillisecondType>()
+            .value(row)
+            .checked_mul(1_000_000),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => column
+            .as_primitive::<TimestampMicrosecondType>()
+            .value(row)
+            .checked_mul(1_000),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            Some(column.as_primitive::<TimestampNanosecondType>().value(row))
+        }
+        data_type => {
+            return Err(format!(
+                "column `close_time` has type {data_type}, expected Timestamp"
+            ))
+        }
+    };
+
+    nanos
+        .map(|nanos| Utc.timestamp_nanos(nanos))
+        .ok_or_else(|| "`close_time` out of range".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::parquet::{arrow::ArrowWriter, file::properties::WriterProperties};
+    use arrow_array::{ArrayRef, Float64Array, StringArray, TimestampMillisecondArray};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn market() -> Market {
+        Market::new("binance", ("btc", "usdt", InstrumentKind::Spot))
+    }
+
+    /// Writes the provided columns to a temporary Parquet file with row groups of two rows.
+    fn parquet_file(columns: Vec<(&str, ArrayRef)>) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("barter-candles-{}.parquet", Uuid::new_v4()));
+        let batch = RecordBatch::try_from_iter(columns).unwrap();
+        let properties = WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .build();
+
+        let mut writer = ArrowWriter::try_new(
+            File::create(&path).unwrap(),
+            batch.schema(),
+            Some(properties),
+        )
+        .unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        path
+    }
+
+    fn prices(values: [f64; 5]) -> ArrayRef {
+        Arc::new(Float64Array::from(values.to_vec()))
+    }
+
+    #[test]
+    fn parquet_feed_should_yield_every_candle_in_timestamp_order() {
+        let start = Utc.with_ymd_and_hms(2022, 4, 5, 21, 0, 0).unwrap();
+        let hours = [1, 0, 2, 3, 4];
+        let close_times = hours
+            .iter()
+            .map(|hour| (start + chrono::Duration::hours(*hour)).timestamp_millis())
+            .collect::<Vec<_>>();
+
+        // Row groups of two rows: [1h, 0h], [2h, 3h], [4h]
+        let path = parquet_file(vec![
+            (
+                "close_time",
+                Arc::new(TimestampMillisecondArray::from(close_times).with_timezone("UTC")),
+            ),
+            ("open", prices([1.0, 0.0, 2.0, 3.0, 4.0])),
+            ("high", prices([1.5, 0.5, 2.5, 3.5, 4.5])),
+            ("low", prices([0.5, -0.5, 1.5, 2.5, 3.5])),
+            ("close", prices([1.0, 0.0, 2.0, 3.0, 4.0])),
+            ("volume", prices([10.0; 5])),
+            (
+                "trade_count",
+                Arc::new(arrow_array::UInt64Array::from(vec![1_u64; 5])),
+            ),
+        ]);
+
+        let mut feed = ParquetFeed::open(&path, market()).unwrap();
+        let mut candles = Vec::new();
+        while let Feed::Next(market) = feed.next() {
+            candles.push(market);
+        }
+
+        assert_eq!(candles.len(), 5);
+        assert!(feed.error().is_none());
+        let yielded = candles
+            .iter()
+            .map(|market| (market.exchange_time - start).num_hours())
+            .collect::<Vec<_>>();
+        assert_eq!(yielded, vec![0, 1, 2, 3, 4]);
+        let DataKind::Candle(candle) = &candles[1].kind else {
+            panic!("ParquetFeed yielded a MarketEvent that is not a Candle");
+        };
+        assert_eq!(
+            (candle.open, candle.high, candle.trade_count),
+            (1.0, 1.5, 1)
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn parquet_feed_should_refuse_files_not_matching_the_candle_schema() {
+        let close_times = (0..5).collect::<Vec<i64>>();
+        let timestamps =
+            || -> ArrayRef { Arc::new(TimestampMillisecondArray::from(close_times.clone())) };
+        let columns = |close: ArrayRef| {
+            vec![
+                ("close_time", timestamps()),
+                ("open", prices([1.0; 5])),
+                ("high", prices([1.0; 5])),
+                ("low", prices([1.0; 5])),
+                ("close", close),
+                ("volume", prices([1.0; 5])),
+                (
+                    "trade_count",
+                    Arc::new(arrow_array::Int64Array::from(vec![1_i64; 5])) as ArrayRef,
+                ),
+            ]
+        };
+
+        // Mistyped column
+        let path = parquet_file(columns(Arc::new(StringArray::from(vec!["1.0"; 5]))));
+        let error = ParquetFeed::open(&path, market()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Parquet file does not match the OHLCV candle schema: column `close` has type Utf8, \
+             expected Float64"
+        );
+        std::fs::remove_file(path).unwrap();
+
+        // Missing column
+        let mut missing = columns(prices([1.0; 5]));
+        missing.retain(|(name, _)| *name != "volume");
+        let path = parquet_file(missing);
+        let error = ParquetFeed::open(&path, market()).unwrap_err();
+        assert!(
+            matches!(error, DataError::ParquetSchema(reason) if reason == "missing column `volume`")
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,130 @@
+use barter_data::event::{DataKind, MarketEvent};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::BTreeMap;
+
+/// Buffer holding [`MarketEvent`]s for a configurable window & releasing them in exchange
+/// timestamp order, so events that interleave out of order across streams (eg/ several venues
+/// or a merged recorded feed) are consumed in timestamp order.
+///
+/// An event is released once a later event arrives with an exchange time at least the window
+/// beyond it's own, or once it has been held for the window according to the
+/// [`Trader`](crate::engine::trader::Trader) [`Clock`](crate::clock::Clock), bounding the delay
+/// of a quiet stream. Release is driven purely by the timestamps provided, so replaying a
+/// recorded feed against a [`SimulatedClock`](crate::clock::SimulatedClock) merges it
+/// deterministically. Events arriving after a later event has already been released cannot be
+/// reordered & are released as soon as possible. Events with equal exchange times are released
+/// in arrival order.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ReorderBuffer {
+    window: Duration,
+    /// Held [`MarketEvent`]s keyed by exchange time & arrival sequence, with the time each
+    /// started being held.
+    held: BTreeMap<(DateTime<Utc>, u64), (DateTime<Utc>, MarketEvent<DataKind>)>,
+    sequence: u64,
+    /// Latest exchange time of any [`MarketEvent`] pushed.
+    newest: Option<DateTime<Utc>>,
+}
+
+impl ReorderBuffer {
+    /// Constructs a new empty [`ReorderBuffer`] holding [`MarketEvent`]s for the provided window.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window: window.max(Duration::zero()),
+            held: BTreeMap::new(),
+            sequence: 0,
+            newest: None,
+        }
+    }
+
+    /// Holds the [`MarketEvent`], received at the provided time, until it is released.
+    pub fn push(&mut self, market: MarketEvent<DataKind>, now: DateTime<Utc>) {
+        let time = market.exchange_time;
+        self.newest = Some(self.newest.map_or(time, |newest| newest.max(time)));
+        self.held.insert((time, self.sequence), (now, market));
+        self.sequence += 1;
+    }
+
+    /// Returns every held [`MarketEvent`] ready for release at the provided time, in exchange
+    /// timestamp order.
+    pub fn release(&mut self, now: DateTime<Utc>) -> Vec<MarketEvent<DataKind>> {
+        let watermark = self.newest.map(|newest| newest - self.window);
+        let expired = self
+            .held
+            .iter()
+            .filter(|(_, (held_since, _))| *held_since + self.window <= now)
+            .map(|((time, _), _)| *time)
+            .max();
+
+        match watermark.max(expired) {
+            Some(cutoff) => {
+                let later = self.held.split_off(&(cutoff, u64::MAX));
+                std::mem::replace(&mut self.held, later)
+                    .into_values()
+                    .map(|(_, market)| market)
+                    .collect()
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns every held [`MarketEvent`] in exchange timestamp order, eg/ once the market data
+    /// feed finishes.
+    pub fn drain(&mut self) -> Vec<MarketEvent<DataKind>> {
+        std::mem::take(&mut self.held)
+            .into_values()
+            .map(|(_, market)| market)
+            .collect()
+    }
+
+    /// Number of [`MarketEvent`]s held awaiting release.
+    pub fn len(&self) -> usize {
+        self.held.len()
+    }
+
+    /// Returns true if no [`MarketEvent`]s are held awaiting release.
+    pub fn is_empty(&self) -> bool {
+        self.held.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::model::Side;
+    use chrono::TimeZone;
+
+    fn market(time: DateTime<Utc>) -> MarketEvent<DataKind> {
+        MarketEvent {
+            exchange_time: time,
+            ..market_event_trade(Side::Buy)
+        }
+    }
+
+    #[test]
+    fn reorder_buffer_should_release_once_the_watermark_or_hold_time_passes() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 2, 9, 30, 0).unwrap();
+        let at = |seconds| start + Duration::seconds(seconds);
+        let mut buffer = ReorderBuffer::new(Duration::seconds(5));
+
+        buffer.push(market(at(3)), at(3));
+        buffer.push(market(at(1)), at(3));
+        assert!(buffer.release(at(4)).is_empty());
+
+        // Event 6s beyond the earliest held advances the watermark past both
+        buffer.push(market(at(9)), at(4));
+        let released = buffer.release(at(4));
+        assert_eq!(
+            released
+                .iter()
+                .map(|market| market.exchange_time)
+                .collect::<Vec<_>>(),
+            vec![at(1), at(3)]
+        );
+
+        // Quiet stream releases the remaining event once held for the window
+        assert!(buffer.release(at(8)).is_empty());
+        assert_eq!(buffer.release(at(9)).len(), 1);
+        assert!(buffer.is_empty());
+    }
+}
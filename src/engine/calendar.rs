@@ -0,0 +1,163 @@
+use chrono::{
+    DateTime, Datelike, Days, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc, Weekday,
+};
+use std::collections::{BTreeSet, HashSet};
+
+/// Trading session hours of an [`Instrument`](barter_integration::model::instrument::Instrument)
+/// with defined trading hours (eg/ equities or futures), used by the
+/// [`Trader`](super::trader::Trader) to refuse opening orders outside the session & optionally
+/// flatten the open Position before the session closes.
+///
+/// Sessions open & close at local times of a fixed UTC offset on every trading day, ie/ every
+/// trading weekday that is not a holiday. A session closing at or before it's open time closes
+/// on the following day (eg/ 18:00 - 17:00 futures sessions), & belongs to the trading day it
+/// opens on. Sessions are evaluated against the [`Clock`](crate::clock::Clock) of the
+/// [`Trader`](super::trader::Trader), so they follow simulated time when backtesting.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TradingCalendar {
+    open: NaiveTime,
+    close: NaiveTime,
+    offset: FixedOffset,
+    weekdays: HashSet<Weekday>,
+    holidays: BTreeSet<NaiveDate>,
+    auto_flatten_before_close: Option<Duration>,
+}
+
+impl TradingCalendar {
+    /// Constructs a new [`TradingCalendar`] with sessions opening & closing at the provided local
+    /// times of the provided UTC offset, trading Monday to Friday without holidays.
+    pub fn new(open: NaiveTime, close: NaiveTime, offset: FixedOffset) -> Self {
+        Self {
+            open,
+            close,
+            offset,
+            weekdays: HashSet::from([
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ]),
+            holidays: BTreeSet::new(),
+            auto_flatten_before_close: None,
+        }
+    }
+
+    /// Replaces the weekdays the [`TradingCalendar`] trades on.
+    pub fn with_weekdays<I>(mut self, weekdays: I) -> Self
+    where
+        I: IntoIterator<Item = Weekday>,
+    {
+        self.weekdays = weekdays.into_iter().collect();
+        self
+    }
+
+    /// Adds a holiday, ie/ a local date on which no session opens.
+    pub fn with_holiday(mut self, date: NaiveDate) -> Self {
+        self.holidays.insert(date);
+        self
+    }
+
+    /// Flattens the open Position once the session is within the provided duration of it's
+    /// close, refusing opening orders for the remainder of the session.
+    pub fn with_auto_flatten_before_close(mut self, value: Duration) -> Self {
+        self.auto_flatten_before_close = Some(value);
+        self
+    }
+
+    /// Duration before the session close the open Position is flattened, if configured.
+    pub fn auto_flatten_before_close(&self) -> Option<Duration> {
+        self.auto_flatten_before_close
+    }
+
+    /// Determines if a session is open at the provided time.
+    pub fn is_open(&self, time: DateTime<Utc>) -> bool {
+        self.session_close(time).is_some()
+    }
+
+    /// Returns the close time of the session open at the provided time, or `None` if no session
+    /// is open.
+    pub fn session_close(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let local_date = time.with_timezone(&self.offset).date_naive();
+
+        // Session open at the time opened today, or yesterday if it closes overnight
+        [local_date.checked_sub_days(Days::new(1)), Some(local_date)]
+            .into_iter()
+            .flatten()
+            .filter(|date| self.is_trading_day(*date))
+            .filter_map(|date| self.session(date))
+            .find(|(open, close)| *open <= time && time < *close)
+            .map(|(_, close)| close)
+    }
+
+    /// Determines if the session is within the auto-flatten duration of it's close at the
+    /// provided time. Always false if auto-flatten is not configured.
+    pub fn is_flattening(&self, time: DateTime<Utc>) -> bool {
+        match (self.auto_flatten_before_close, self.session_close(time)) {
+            (Some(before_close), Some(close)) => time >= close - before_close,
+            _ => false,
+        }
+    }
+
+    /// Determines if a session opens on the provided local date.
+    fn is_trading_day(&self, date: NaiveDate) -> bool {
+        self.weekdays.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    /// Returns the open & close times of the session opening on the provided local date.
+    fn session(&self, date: NaiveDate) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let close_date = match self.close <= self.open {
+            true => date.checked_add_days(Days::new(1))?,
+            false => date,
+        };
+        let local = |date: NaiveDate, time| {
+            self.offset
+                .from_local_datetime(&date.and_time(time))
+                .single()
+                .map(|time| time.with_timezone(&Utc))
+        };
+        Some((local(date, self.open)?, local(close_date, self.close)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trading_calendar_should_only_be_open_during_sessions_of_trading_days() {
+        // 09:30 - 16:00 US Eastern (UTC-5), closed on the 2023-01-16 holiday
+        let calendar = TradingCalendar::new(
+            NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            FixedOffset::west_opt(5 * 3600).unwrap(),
+        )
+        .with_holiday(NaiveDate::from_ymd_opt(2023, 1, 16).unwrap())
+        .with_auto_flatten_before_close(Duration::minutes(15));
+        let utc = |day, hour, minute| Utc.with_ymd_and_hms(2023, 1, day, hour, minute, 0).unwrap();
+
+        // Friday 2023-01-13
+        assert!(!calendar.is_open(utc(13, 14, 29)));
+        assert!(calendar.is_open(utc(13, 14, 30)));
+        assert_eq!(calendar.session_close(utc(13, 15, 0)), Some(utc(13, 21, 0)));
+        assert!(!calendar.is_flattening(utc(13, 20, 44)));
+        assert!(calendar.is_flattening(utc(13, 20, 45)));
+        assert!(!calendar.is_open(utc(13, 21, 0)));
+
+        // Weekend & holiday
+        assert!(!calendar.is_open(utc(14, 15, 0)));
+        assert!(!calendar.is_open(utc(16, 15, 0)));
+        assert!(calendar.is_open(utc(17, 15, 0)));
+
+        // Overnight session opening Sunday 18:00 & closing Monday 17:00
+        let overnight = TradingCalendar::new(
+            NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            FixedOffset::east_opt(0).unwrap(),
+        )
+        .with_weekdays([Weekday::Sun]);
+        assert!(overnight.is_open(utc(15, 23, 0)));
+        assert_eq!(overnight.session_close(utc(16, 9, 0)), Some(utc(16, 17, 0)));
+        assert!(!overnight.is_open(utc(16, 17, 30)));
+    }
+}
@@ -0,0 +1,219 @@
+use super::error::EngineError;
+use crate::{
+    execution::order_id::ClientOrderId,
+    portfolio::{OrderEvent, PortfolioState},
+};
+use barter_integration::model::{Market, MarketId};
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+/// Snapshot of the full state of an [`Engine`](super::Engine), persisted periodically so a
+/// restarted [`Engine`](super::Engine) can resume from it via
+/// [`EngineBuilder::from_checkpoint`](super::EngineBuilder::from_checkpoint).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Checkpoint<Statistic> {
+    /// Identifier of the [`Engine`](super::Engine) the [`Checkpoint`] was taken from.
+    pub engine_id: Uuid,
+    /// Time the [`Checkpoint`] was taken.
+    pub time: DateTime<Utc>,
+    /// Portfolio [`Balance`](crate::portfolio::Balance) & open
+    /// [`Position`](crate::portfolio::position::Position)s.
+    pub portfolio: PortfolioState,
+    /// Market statistics tracked by the Portfolio, keyed by [`MarketId`].
+    pub statistics: HashMap<MarketId, Statistic>,
+    /// State of every [`Trader`](super::trader::Trader), sorted by [`Market`].
+    pub traders: Vec<TraderCheckpoint>,
+}
+
+/// Snapshot of the state of a [`Trader`](super::trader::Trader), taken as part of a
+/// [`Checkpoint`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct TraderCheckpoint {
+    /// [`Market`] the [`Trader`](super::trader::Trader) is bartering on.
+    pub market: Market,
+    /// [`OrderEvent`]s sent for execution that are yet to be filled or cancelled, sorted by
+    /// [`ClientOrderId`].
+    pub open_orders: Vec<OrderEvent>,
+    /// [`OrderEvent`]s throttled by a [`RateLimiter`](crate::execution::rate_limit::RateLimiter)
+    /// that are yet to be sent for execution.
+    pub throttled_orders: Vec<OrderEvent>,
+    /// Conditional [`OrderEvent`]s held inactive until their
+    /// [`Trigger`](crate::portfolio::Trigger) is met.
+    #[serde(default)]
+    pub triggered_orders: Vec<OrderEvent>,
+    /// Seed of the [`OrderIdGenerator`](crate::execution::order_id::OrderIdGenerator) used by
+    /// the [`Trader`](super::trader::Trader), if it supports resuming after a restart.
+    pub order_id_seed: Option<ClientOrderId>,
+    /// Snapshot of the internal Strategy state (eg/ rolling indicators) via
+    /// [`SignalGenerator::snapshot`](crate::strategy::SignalGenerator::snapshot).
+    #[serde(default)]
+    pub strategy: serde_json::Value,
+}
+
+/// Configuration for periodically saving a [`Checkpoint`] of a running
+/// [`Engine`](super::Engine).
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CheckpointConfig {
+    /// File path the [`Checkpoint`] is saved to, replacing the previous [`Checkpoint`].
+    pub path: PathBuf,
+    /// Interval of wall-clock time between [`Checkpoint`]s.
+    pub interval: Duration,
+    /// Optional number of [`Event`](crate::event::Event)s handled by a
+    /// [`Trader`](super::trader::Trader) after which it requests a [`Checkpoint`], in addition
+    /// to the wall-clock `interval`. Bounds the work lost on a crash when events are bursty.
+    #[serde(default)]
+    pub every_events: Option<u64>,
+}
+
+/// Counts the [`Event`](crate::event::Event)s handled by a [`Trader`](super::trader::Trader),
+/// requesting a [`Checkpoint`] from the [`Engine`](super::Engine) every
+/// [`CheckpointConfig::every_events`].
+#[derive(Debug)]
+pub(super) struct CheckpointRequester {
+    every_events: u64,
+    handled: u64,
+    request_tx: mpsc::Sender<()>,
+}
+
+impl CheckpointRequester {
+    /// Constructs a new [`CheckpointRequester`] sending it's requests on the provided
+    /// `mpsc::Sender`.
+    pub(super) fn new(every_events: u64, request_tx: mpsc::Sender<()>) -> Self {
+        Self {
+            every_events: every_events.max(1),
+            handled: 0,
+            request_tx,
+        }
+    }
+
+    /// Counts an [`Event`](crate::event::Event) handled, requesting a [`Checkpoint`] once
+    /// `every_events` have been handled since the previous request. Requests made whilst one is
+    /// still pending are coalesced into it.
+    pub(super) fn record_event(&mut self) {
+        self.handled += 1;
+        if self.handled >= self.every_events {
+            self.handled = 0;
+            let _ = self.request_tx.try_send(());
+        }
+    }
+}
+
+impl<Statistic> Checkpoint<Statistic>
+where
+    Statistic: Serialize,
+{
+    /// Saves the [`Checkpoint`] as JSON to the file path provided.
+    ///
+    /// The [`Checkpoint`] is written to a temporary file in the same directory which is then
+    /// renamed over the path provided, so a crash mid-write never corrupts the previous
+    /// [`Checkpoint`].
+    pub fn save<P>(&self, path: P) -> Result<(), EngineError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+
+        let mut file = fs::File::create(&temp_path)?;
+        serde_json::to_writer(&mut file, self)?;
+        file.flush()?;
+        file.sync_all()?;
+
+        fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+}
+
+impl<Statistic> Checkpoint<Statistic>
+where
+    Statistic: DeserializeOwned,
+{
+    /// Loads a [`Checkpoint`] previously saved to the file path provided.
+    pub fn load<P>(path: P) -> Result<Self, EngineError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = fs::File::open(path)?;
+        Ok(serde_json::from_reader(std::io::BufReader::new(file))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::Balance,
+        statistic::summary::pnl::PnLReturnSummary,
+        test_util::{order_event, position},
+    };
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn checkpoint_should_round_trip_through_saved_file() {
+        let directory = std::env::temp_dir().join(format!("barter-checkpoint-{}", Uuid::new_v4()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("engine.json");
+
+        let checkpoint = Checkpoint {
+            engine_id: Uuid::new_v4(),
+            time: Utc::now(),
+            portfolio: PortfolioState {
+                balance: Balance::new(Utc::now(), Decimal::from(10_000), Decimal::from(9000)),
+                open_positions: vec![position()],
+            },
+            statistics: HashMap::from([(
+                MarketId::from(&Market::new(position().exchange, position().instrument)),
+                PnLReturnSummary::new(),
+            )]),
+            traders: vec![TraderCheckpoint {
+                market: Market::new(position().exchange, position().instrument),
+                open_orders: vec![order_event()],
+                throttled_orders: vec![],
+                triggered_orders: vec![],
+                order_id_seed: Some(ClientOrderId::default()),
+                strategy: serde_json::json!({ "rsi": 42.0 }),
+            }],
+        };
+
+        checkpoint.save(&path).unwrap();
+
+        // Checkpoint replaces the temporary file it was written to
+        let files = fs::read_dir(&directory).unwrap().count();
+        assert_eq!(files, 1);
+
+        let actual = Checkpoint::<PnLReturnSummary>::load(&path).unwrap();
+        assert_eq!(actual, checkpoint);
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn checkpoint_requester_should_request_every_n_events_and_coalesce_pending_requests() {
+        let (request_tx, mut request_rx) = mpsc::channel(1);
+        let mut requester = CheckpointRequester::new(3, request_tx);
+
+        requester.record_event();
+        requester.record_event();
+        assert!(request_rx.try_recv().is_err());
+
+        requester.record_event();
+        assert!(request_rx.try_recv().is_ok());
+
+        // Requests made whilst one is pending are coalesced
+        for _ in 0..6 {
+            requester.record_event();
+        }
+        assert!(request_rx.try_recv().is_ok());
+        assert!(request_rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Activity of a [`Trader`](super::trader::Trader) whose rate is monitored by a
+/// [`CircuitBreaker`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum CircuitMetric {
+    /// [`OrderEvent`](crate::portfolio::OrderEvent)s submitted for execution.
+    Orders,
+    /// [`FillEvent`](crate::execution::FillEvent)s received from the exchange.
+    Fills,
+}
+
+/// Circuit breaker tracking the rate of order submissions & fills of a
+/// [`Trader`](super::trader::Trader) over a sliding window of [`Clock`](crate::clock::Clock)
+/// time, so a runaway strategy firing orders far faster than intended can be halted.
+///
+/// Rates are the number of events recorded within the window, per second of the window.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CircuitBreaker {
+    window: Duration,
+    max_order_rate: f64,
+    max_fill_rate: f64,
+    orders: VecDeque<DateTime<Utc>>,
+    fills: VecDeque<DateTime<Utc>>,
+}
+
+impl CircuitBreaker {
+    /// Constructs a new [`CircuitBreaker`] measuring rates over the provided sliding window,
+    /// that trips once more than `max_order_rate` orders or `max_fill_rate` fills per second are
+    /// recorded within it.
+    pub fn new(window: Duration, max_order_rate: f64, max_fill_rate: f64) -> Self {
+        Self {
+            window: window.max(Duration::milliseconds(1)),
+            max_order_rate,
+            max_fill_rate,
+            orders: VecDeque::new(),
+            fills: VecDeque::new(),
+        }
+    }
+
+    /// Records an event of the [`CircuitMetric`] at the provided time, returning the rate of
+    /// the [`CircuitMetric`] over the sliding window if it exceeds the ceiling.
+    pub fn record(&mut self, metric: CircuitMetric, now: DateTime<Utc>) -> Option<f64> {
+        let window = self.window;
+        let ceiling = self.ceiling(metric);
+        let events = match metric {
+            CircuitMetric::Orders => &mut self.orders,
+            CircuitMetric::Fills => &mut self.fills,
+        };
+
+        events.push_back(now);
+        while events.front().is_some_and(|time| now - *time >= window) {
+            events.pop_front();
+        }
+
+        let rate = events.len() as f64 / (window.num_milliseconds() as f64 / 1000.0);
+        (rate > ceiling).then_some(rate)
+    }
+
+    /// Returns the ceiling on the rate per second of the [`CircuitMetric`].
+    pub fn ceiling(&self, metric: CircuitMetric) -> f64 {
+        match metric {
+            CircuitMetric::Orders => self.max_order_rate,
+            CircuitMetric::Fills => self.max_fill_rate,
+        }
+    }
+
+    /// Forgets every event recorded, eg/ once a tripped [`CircuitBreaker`] is cleared.
+    pub fn reset(&mut self) {
+        self.orders.clear();
+        self.fills.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circuit_breaker_should_only_trip_while_rate_exceeds_the_ceiling_over_the_window() {
+        let start = Utc::now();
+        let mut breaker = CircuitBreaker::new(Duration::seconds(10), 0.25, 1.0);
+
+        // Ceiling of 2.5 orders per 10s window
+        assert_eq!(breaker.record(CircuitMetric::Orders, start), None);
+        assert_eq!(
+            breaker.record(CircuitMetric::Orders, start + Duration::seconds(1)),
+            None
+        );
+        assert_eq!(
+            breaker.record(CircuitMetric::Orders, start + Duration::seconds(2)),
+            Some(0.3)
+        );
+
+        // Orders older than the window no longer count towards the rate
+        assert_eq!(
+            breaker.record(CircuitMetric::Orders, start + Duration::seconds(11)),
+            None
+        );
+
+        // Fill rate is tracked independently
+        assert_eq!(
+            breaker.record(CircuitMetric::Fills, start + Duration::seconds(11)),
+            None
+        );
+    }
+}
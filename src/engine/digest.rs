@@ -0,0 +1,159 @@
+use super::transition::{TraderState, Transition, TransitionTrigger};
+use crate::{
+    execution::FillEvent,
+    portfolio::{OrderEvent, OrderType},
+    strategy::Decision,
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Stable hash of the [`Transition`], [`OrderEvent`] & [`FillEvent`] sequence of a trading
+/// session, used to detect regressions that subtly change the results of a deterministic replay
+/// (eg/ a backtest over a recorded feed).
+///
+/// Each record is hashed from it's canonical bytes, which exclude every value that differs
+/// between two runs of the same replay: wall-clock times, the [`Clock`](crate::clock::Clock)
+/// time of [`Transition`]s & the random session of each
+/// [`ClientOrderId`](crate::execution::order_id::ClientOrderId). Times propagated from the
+/// recorded feed (eg/ the [`MarketMeta`](crate::data::MarketMeta) time of an [`OrderEvent`])
+/// are included. The hash function (64-bit FNV-1a) is fixed, so digests can be compared across
+/// processes & platforms.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeterminismDigest {
+    /// Hash of every record fed into the [`DeterminismDigest`].
+    pub hash: u64,
+    /// Number of records fed into the [`DeterminismDigest`].
+    pub records: u64,
+}
+
+impl Default for DeterminismDigest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for DeterminismDigest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.hash)
+    }
+}
+
+/// Canonical representation of a record fed into a [`DeterminismDigest`].
+#[derive(Serialize)]
+enum Record<'a> {
+    Transition {
+        from_state: TraderState,
+        to_state: TraderState,
+        trigger: TransitionTrigger,
+    },
+    Order {
+        sequence: u64,
+        exchange: &'a Exchange,
+        instrument: &'a Instrument,
+        market_time: DateTime<Utc>,
+        price: Decimal,
+        decision: Decision,
+        quantity: Decimal,
+        order_type: OrderType,
+        stop_price: Option<Decimal>,
+    },
+    Fill {
+        sequence: u64,
+        decision: Decision,
+        quantity: Decimal,
+        fill_value_gross: Decimal,
+        fees: Decimal,
+    },
+}
+
+impl DeterminismDigest {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    /// Constructs a new [`DeterminismDigest`] that has been fed no records.
+    pub fn new() -> Self {
+        Self {
+            hash: Self::FNV_OFFSET_BASIS,
+            records: 0,
+        }
+    }
+
+    /// Feeds the canonical bytes of the [`Transition`] into the [`DeterminismDigest`].
+    pub fn record_transition(&mut self, transition: &Transition) {
+        self.record(&Record::Transition {
+            from_state: transition.from_state,
+            to_state: transition.to_state,
+            trigger: transition.trigger,
+        });
+    }
+
+    /// Feeds the canonical bytes of the [`OrderEvent`] into the [`DeterminismDigest`].
+    pub fn record_order(&mut self, order: &OrderEvent) {
+        self.record(&Record::Order {
+            sequence: order.cid.sequence,
+            exchange: &order.exchange,
+            instrument: &order.instrument,
+            market_time: order.market_meta.time,
+            price: order.market_meta.close,
+            decision: order.decision,
+            quantity: order.quantity.normalize(),
+            order_type: order.order_type,
+            stop_price: order.stop_price,
+        });
+    }
+
+    /// Feeds the canonical bytes of the [`FillEvent`] into the [`DeterminismDigest`].
+    pub fn record_fill(&mut self, fill: &FillEvent) {
+        self.record(&Record::Fill {
+            sequence: fill.cid.sequence,
+            decision: fill.decision,
+            quantity: fill.quantity.normalize(),
+            fill_value_gross: fill.fill_value_gross,
+            fees: fill.fees.calculate_total_fees(),
+        });
+    }
+
+    fn record(&mut self, record: &Record<'_>) {
+        let bytes = serde_json::to_vec(record)
+            .expect("DeterminismDigest Record contains only serialisable values");
+
+        // Length prefix each record so the boundaries between records are part of the hash
+        for byte in (bytes.len() as u64).to_le_bytes().iter().chain(&bytes) {
+            self.hash ^= u64::from(*byte);
+            self.hash = self.hash.wrapping_mul(Self::FNV_PRIME);
+        }
+        self.records += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::order_event;
+
+    #[test]
+    fn determinism_digest_should_ignore_wall_clock_time_of_records() {
+        let order = order_event();
+
+        let mut digest = DeterminismDigest::new();
+        digest.record_order(&order);
+
+        let mut other = DeterminismDigest::new();
+        other.record_order(&OrderEvent {
+            time: order.time + chrono::Duration::hours(1),
+            ..order.clone()
+        });
+        assert_eq!(digest, other);
+        assert_eq!(digest.records, 1);
+
+        let mut other = DeterminismDigest::new();
+        other.record_order(&OrderEvent {
+            quantity: order.quantity * Decimal::TWO,
+            ..order
+        });
+        assert_ne!(digest, other);
+    }
+}
@@ -1,4 +1,10 @@
-use crate::portfolio::repository::error::RepositoryError;
+use crate::{
+    data::error::DataError,
+    engine::transition::TraderState,
+    execution::error::ExecutionError,
+    portfolio::{error::PortfolioError, repository::error::RepositoryError},
+    strategy::error::SnapshotError,
+};
 use thiserror::Error;
 
 /// All errors generated in barter-engine.
@@ -7,6 +13,54 @@ pub enum EngineError {
     #[error("Failed to build struct due to missing attributes: {0}")]
     BuilderIncomplete(&'static str),
 
+    #[error("Failed to build struct due to invalid Markets: {0}")]
+    InvalidMarkets(&'static str),
+
     #[error("Failed to interact with repository")]
     RepositoryInteractionError(#[from] RepositoryError),
+
+    #[error("Failed to interact with portfolio: {0}")]
+    PortfolioInteractionError(#[from] PortfolioError),
+
+    #[error("Failed to execute order: {0}")]
+    ExecutionError(#[from] ExecutionError),
+
+    #[error("Failed to subscribe to market data: {0}")]
+    DataError(#[from] Box<DataError>),
+
+    #[error("Trader thread panicked during execution: {0}")]
+    TraderPanic(String),
+
+    #[error("Failed to take Checkpoint: {0}")]
+    Checkpoint(&'static str),
+
+    #[error("Failed to read or write Checkpoint file: {0}")]
+    CheckpointIo(#[from] std::io::Error),
+
+    #[error("Failed to (de)serialise Checkpoint: {0}")]
+    CheckpointSerde(#[from] serde_json::Error),
+
+    #[error("Failed to restore Strategy from Checkpoint: {0}")]
+    StrategyRestore(#[from] SnapshotError),
+
+    #[error(
+        "Trader livelocked transitioning from {from_state:?} to {to_state:?} without consuming a \
+         MarketEvent"
+    )]
+    Livelock {
+        from_state: TraderState,
+        to_state: TraderState,
+    },
+
+    #[error("Trader terminated after {0}")]
+    Panicked(String),
+
+    #[error("Engine shutdown forced before every Trader stopped: {0}")]
+    ShutdownForced(&'static str),
+
+    #[error("Failed to read or write session log file: {0}")]
+    SessionLogIo(std::io::Error),
+
+    #[error("Failed to (de)serialise session log record: {0}")]
+    SessionLogSerde(serde_json::Error),
 }
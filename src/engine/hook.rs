@@ -0,0 +1,114 @@
+use crate::{execution::FillEvent, portfolio::PortfolioSnapshot};
+use futures::future::BoxFuture;
+use std::{fmt, future::Future, sync::Arc};
+use tokio::runtime::Handle;
+use tracing::warn;
+
+/// Error returned by a [`FillHook`], logged by the [`FillHooks`] invoking it.
+pub type FillHookError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Asynchronous side-effect (eg/ a webhook, a notification or a database insert) invoked with
+/// every [`FillEvent`] applied to the Portfolio by a [`Trader`](super::trader::Trader).
+///
+/// Invoked via [`FillHooks`] on a separate task, so a slow [`FillHook`] never blocks the
+/// [`Trader`](super::trader::Trader) trading loop.
+///
+/// ```ignore
+/// impl FillHook for SlackNotifier {
+///     async fn on_fill(&self, fill: &FillEvent, portfolio: &PortfolioSnapshot) -> Result<(), FillHookError> {
+///         self.client.post(&self.webhook).json(&Message::from((fill, portfolio))).send().await?;
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait FillHook: Send + Sync + 'static {
+    /// Handles a [`FillEvent`], given the [`PortfolioSnapshot`] taken after it was applied.
+    fn on_fill(
+        &self,
+        fill: &FillEvent,
+        portfolio: &PortfolioSnapshot,
+    ) -> impl Future<Output = Result<(), FillHookError>> + Send;
+}
+
+/// Type erased [`FillHook`] invocation, returning a `'static` future owning it's inputs.
+type ErasedFillHook = Arc<
+    dyn Fn(FillEvent, PortfolioSnapshot) -> BoxFuture<'static, Result<(), FillHookError>>
+        + Send
+        + Sync,
+>;
+
+/// Set of [`FillHook`]s a [`Trader`](super::trader::Trader) invokes with every applied
+/// [`FillEvent`], each spawned as it's own task onto the provided tokio runtime.
+///
+/// [`FillHook`] errors & panics are isolated to their task & logged, never affecting the
+/// [`Trader`](super::trader::Trader). Cloning a [`FillHooks`] returns a handle to the same
+/// [`FillHook`]s, so they can be shared by every [`Trader`](super::trader::Trader) of an
+/// [`Engine`](super::Engine).
+#[derive(Clone)]
+pub struct FillHooks {
+    runtime: Handle,
+    hooks: Vec<(&'static str, ErasedFillHook)>,
+}
+
+impl fmt::Debug for FillHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FillHooks")
+            .field(
+                "hooks",
+                &self.hooks.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl FillHooks {
+    /// Constructs a new [`FillHooks`] without any [`FillHook`]s, spawning invocations onto the
+    /// tokio runtime of the provided [`Handle`] (eg/ `Handle::current()`).
+    pub fn new(runtime: Handle) -> Self {
+        Self {
+            runtime,
+            hooks: Vec::new(),
+        }
+    }
+
+    /// Adds a [`FillHook`] invoked with every applied [`FillEvent`].
+    pub fn with_hook<Hook>(mut self, hook: Hook) -> Self
+    where
+        Hook: FillHook,
+    {
+        let hook = Arc::new(hook);
+        self.hooks.push((
+            std::any::type_name::<Hook>(),
+            Arc::new(move |fill, portfolio| {
+                let hook = Arc::clone(&hook);
+                Box::pin(async move { hook.on_fill(&fill, &portfolio).await })
+            }),
+        ));
+        self
+    }
+
+    /// Spawns an invocation of every [`FillHook`] with the provided [`FillEvent`] &
+    /// [`PortfolioSnapshot`], returning without waiting for them to complete.
+    pub fn spawn(&self, fill: &FillEvent, portfolio: &PortfolioSnapshot) {
+        for (name, hook) in &self.hooks {
+            let name = *name;
+            let cid = fill.cid;
+            let invocation = self.runtime.spawn(hook(fill.clone(), portfolio.clone()));
+
+            // Panics are caught by the runtime & surfaced as an Err when joining the task
+            self.runtime.spawn(async move {
+                match invocation.await {
+                    Ok(Ok(())) => {}
+                    Ok(Err(error)) => {
+                        warn!(hook = name, %cid, %error, "FillHook failed to handle FillEvent")
+                    }
+                    Err(error) if error.is_panic() => {
+                        warn!(hook = name, %cid, %error, "FillHook panicked handling FillEvent")
+                    }
+                    // Cancelled by the runtime shutting down
+                    Err(_) => {}
+                }
+            });
+        }
+    }
+}
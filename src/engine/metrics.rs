@@ -0,0 +1,421 @@
+use crate::{
+    clock::{Clock, LiveClock},
+    event::{Event, MessageTransmitter},
+    execution::order_id::ClientOrderId,
+    portfolio::position::PositionId,
+    statistic::metric::latency::{LatencyHistogram, LATENCY_BUCKETS},
+};
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    net::SocketAddr,
+    sync::Arc,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+};
+use tracing::{debug, info, warn};
+
+/// Content type of the Prometheus text exposition format.
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Counters & gauges of the trading session, maintained from the [`Event`]s emitted by every
+/// [`Trader`](super::trader::Trader) via a [`MetricsTx`] & rendered in the Prometheus text
+/// exposition format.
+///
+/// Shared between the [`MetricsTx`] of each [`Trader`](super::trader::Trader) & the
+/// [`MetricsExporter`] serving them, so every [`Market`](barter_integration::model::Market) is
+/// aggregated into the same metrics.
+///
+/// Order latency is measured by the [`Clock`] of the [`Metrics`], from the time an order is
+/// recorded as sent to the time it's first fill is recorded.
+#[derive(Debug)]
+pub struct Metrics {
+    clock: Arc<dyn Clock + Send + Sync>,
+    state: Mutex<MetricsState>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            clock: Arc::new(LiveClock),
+            state: Mutex::default(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct MetricsState {
+    /// Number of [`Event`]s emitted, by [`Event::kind`].
+    events: BTreeMap<&'static str, u64>,
+    /// Number of orders sent for execution.
+    orders_sent: u64,
+    /// Number of fills received.
+    fills: u64,
+    /// Send time of every order yet to receive it's first fill.
+    in_flight: HashMap<ClientOrderId, DateTime<Utc>>,
+    /// Unrealised P&L of every open Position.
+    open_positions: HashMap<PositionId, Decimal>,
+    /// Total of the latest Portfolio [`Balance`](crate::portfolio::Balance).
+    balance: Decimal,
+    /// Latency from sending an order to it's first fill.
+    order_latency: LatencyHistogram,
+}
+
+impl Metrics {
+    /// Constructs a new [`Metrics`] instance with every counter & gauge at zero, measuring
+    /// order latency with the [`LiveClock`].
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Constructs a new [`Metrics`] instance with every counter & gauge at zero, measuring
+    /// order latency with the provided [`Clock`] (eg/ the
+    /// [`SimulatedClock`](crate::clock::SimulatedClock) shared with the Traders of a backtest).
+    pub fn with_clock(clock: Arc<dyn Clock + Send + Sync>) -> Arc<Self> {
+        Arc::new(Self {
+            clock,
+            state: Mutex::default(),
+        })
+    }
+
+    /// Updates the metrics from an [`Event`] emitted by a [`Trader`](super::trader::Trader).
+    pub fn record(&self, event: &Event) {
+        let mut state = self.state.lock();
+        *state.events.entry(event.kind()).or_default() += 1;
+
+        match event {
+            // Conditional orders are only sent once triggered
+            Event::OrderNew(order) if order.trigger.is_none() => {
+                state.orders_sent += 1;
+                state.in_flight.insert(order.cid, self.clock.now());
+            }
+            Event::OrderTriggered(order) => {
+                state.orders_sent += 1;
+                state.in_flight.insert(order.cid, self.clock.now());
+            }
+            Event::OrderCancelled(order) => {
+                state.in_flight.remove(&order.cid);
+            }
+            Event::OrderRejected(rejection) => {
+                state.in_flight.remove(&rejection.order.cid);
+            }
+            Event::Fill(fill) => {
+                state.fills += 1;
+                if let Some(sent_at) = state.in_flight.remove(&fill.cid) {
+                    let latency = self.clock.now() - sent_at;
+                    if !state.order_latency.record(latency) {
+                        warn!(?fill, ?latency, "ignoring negative order latency");
+                    }
+                }
+            }
+            Event::PositionNew(position) => {
+                state.open_positions.insert(
+                    position.position_id.clone(),
+                    position.unrealised_profit_loss,
+                );
+            }
+            Event::PositionUpdate(update) => {
+                if let Some(unrealised) = state.open_positions.get_mut(&update.position_id) {
+                    *unrealised = update.unrealised_profit_loss;
+                }
+            }
+            Event::PositionExit(exit) => {
+                state.open_positions.remove(&exit.position_id);
+            }
+            Event::Balance(balance) => {
+                state.balance = balance.total;
+            }
+            _ => {}
+        }
+    }
+
+    /// Renders every metric in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let state = self.state.lock();
+        let mut out = String::new();
+
+        header(
+            &mut out,
+            "barter_events_total",
+            "counter",
+            "Events emitted by the Traders, by kind.",
+        );
+        for (kind, count) in &state.events {
+            let _ = writeln!(out, "barter_events_total{{kind=\"{kind}\"}} {count}");
+        }
+
+        header(
+            &mut out,
+            "barter_orders_sent_total",
+            "counter",
+            "Orders sent for execution.",
+        );
+        let _ = writeln!(out, "barter_orders_sent_total {}", state.orders_sent);
+
+        header(
+            &mut out,
+            "barter_fills_total",
+            "counter",
+            "Fills received from the exchange.",
+        );
+        let _ = writeln!(out, "barter_fills_total {}", state.fills);
+
+        header(
+            &mut out,
+            "barter_open_positions",
+            "gauge",
+            "Open Positions.",
+        );
+        let _ = writeln!(out, "barter_open_positions {}", state.open_positions.len());
+
+        header(
+            &mut out,
+            "barter_equity",
+            "gauge",
+            "Portfolio balance plus the unrealised P&L of the open Positions.",
+        );
+        let equity = state.balance + state.open_positions.values().sum::<Decimal>();
+        let _ = writeln!(out, "barter_equity {equity}");
+
+        header(
+            &mut out,
+            "barter_order_latency_seconds",
+            "histogram",
+            "Latency from sending an order to it's first fill.",
+        );
+        let histogram = &state.order_latency;
+        let mut cumulative = 0;
+        for (bucket, count) in histogram.buckets[..LATENCY_BUCKETS - 1].iter().enumerate() {
+            cumulative += count;
+            let upper_bound = (1_u64 << bucket) as f64 / 1000.0;
+            let _ = writeln!(
+                out,
+                "barter_order_latency_seconds_bucket{{le=\"{upper_bound}\"}} {cumulative}"
+            );
+        }
+        let _ = writeln!(
+            out,
+            "barter_order_latency_seconds_bucket{{le=\"+Inf\"}} {}",
+            histogram.count
+        );
+        let _ = writeln!(
+            out,
+            "barter_order_latency_seconds_sum {}",
+            histogram.total_ms as f64 / 1000.0
+        );
+        let _ = writeln!(
+            out,
+            "barter_order_latency_seconds_count {}",
+            histogram.count
+        );
+
+        out
+    }
+}
+
+/// Writes the `HELP` & `TYPE` lines of a metric.
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+/// [`MessageTransmitter`] recording every [`Event`] into the shared [`Metrics`] before
+/// forwarding it to the wrapped [`MessageTransmitter`] (eg/ an
+/// [`EventTx`](crate::event::EventTx)).
+///
+/// Used as the `EventTx` of each [`Trader`](super::trader::Trader), so the metrics are updated
+/// from exactly the [`Event`]s sent to the audit channel.
+#[derive(Debug, Clone)]
+pub struct MetricsTx<Tx> {
+    metrics: Arc<Metrics>,
+    inner: Tx,
+}
+
+impl<Tx> MessageTransmitter<Event> for MetricsTx<Tx>
+where
+    Tx: MessageTransmitter<Event>,
+{
+    fn send(&mut self, message: Event) {
+        self.metrics.record(&message);
+        self.inner.send(message);
+    }
+
+    fn send_many(&mut self, messages: Vec<Event>) {
+        messages
+            .iter()
+            .for_each(|message| self.metrics.record(message));
+        self.inner.send_many(messages);
+    }
+}
+
+impl<Tx> MetricsTx<Tx> {
+    /// Constructs a new [`MetricsTx`] recording into the provided [`Metrics`] before forwarding
+    /// to the provided [`MessageTransmitter`].
+    pub fn new(metrics: Arc<Metrics>, inner: Tx) -> Self {
+        Self { metrics, inner }
+    }
+}
+
+/// HTTP server exposing the [`Metrics`] on `GET /metrics` in the Prometheus text exposition
+/// format, for scraping by Prometheus.
+#[derive(Debug)]
+pub struct MetricsExporter {
+    metrics: Arc<Metrics>,
+    listener: TcpListener,
+}
+
+impl MetricsExporter {
+    /// Binds a new [`MetricsExporter`] of the provided [`Metrics`] to the provided address (eg/
+    /// `0.0.0.0:9184`). Binding port 0 assigns an ephemeral port, see
+    /// [`MetricsExporter::local_addr`].
+    pub async fn bind<A>(address: A, metrics: Arc<Metrics>) -> std::io::Result<Self>
+    where
+        A: ToSocketAddrs,
+    {
+        let listener = TcpListener::bind(address).await?;
+        Ok(Self { metrics, listener })
+    }
+
+    /// Address the [`MetricsExporter`] is bound to.
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Serves scrapes of the [`Metrics`] until the returned future is dropped, eg/ via
+    /// `tokio::spawn(exporter.serve())`.
+    pub async fn serve(self) {
+        if let Ok(address) = self.local_addr() {
+            info!(%address, "serving Prometheus metrics");
+        }
+
+        loop {
+            match self.listener.accept().await {
+                Ok((stream, peer)) => {
+                    let metrics = Arc::clone(&self.metrics);
+                    tokio::spawn(async move {
+                        if let Err(error) = respond(stream, &metrics).await {
+                            debug!(%peer, ?error, "failed to respond to metrics scrape");
+                        }
+                    });
+                }
+                Err(error) => warn!(?error, "failed to accept metrics scrape connection"),
+            }
+        }
+    }
+}
+
+/// Responds to a single HTTP request with the rendered [`Metrics`], or 404 for any path other
+/// than `/metrics`.
+async fn respond(mut stream: TcpStream, metrics: &Metrics) -> std::io::Result<()> {
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") && request.len() < 8192 {
+        match stream.read(&mut buffer).await? {
+            0 => break,
+            read => request.extend_from_slice(&buffer[..read]),
+        }
+    }
+
+    let request = String::from_utf8_lossy(&request);
+    let path = request.split_whitespace().nth(1).unwrap_or_default();
+    let (status, body) = match path.split('?').next() {
+        Some("/metrics") => ("200 OK", metrics.render()),
+        _ => ("404 Not Found", String::new()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {CONTENT_TYPE}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        clock::SimulatedClock,
+        event::EventTx,
+        execution::FillEvent,
+        test_util::{fill_event, order_event},
+    };
+    use chrono::{Duration, TimeZone};
+    use tokio::sync::mpsc;
+
+    async fn scrape(address: SocketAddr) -> String {
+        let mut stream = TcpStream::connect(address).await.unwrap();
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    fn orders_sent(response: &str) -> u64 {
+        response
+            .lines()
+            .find_map(|line| line.strip_prefix("barter_orders_sent_total "))
+            .expect("scrape is missing barter_orders_sent_total")
+            .parse()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn metrics_exporter_should_serve_orders_sent_counter_incremented_by_orders() {
+        let metrics = Metrics::new();
+        let exporter = MetricsExporter::bind("127.0.0.1:0", Arc::clone(&metrics))
+            .await
+            .unwrap();
+        let address = exporter.local_addr().unwrap();
+        tokio::spawn(exporter.serve());
+
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let mut metrics_tx = MetricsTx::new(metrics, EventTx::new(event_tx));
+
+        let before = scrape(address).await;
+        assert!(before.starts_with("HTTP/1.1 200 OK"));
+        assert!(before.contains("# TYPE barter_orders_sent_total counter"));
+        assert_eq!(orders_sent(&before), 0);
+
+        metrics_tx.send(Event::OrderNew(order_event()));
+
+        let after = scrape(address).await;
+        assert_eq!(orders_sent(&after), 1);
+        assert!(after.contains("barter_events_total{kind=\"OrderNew\"} 1"));
+
+        // Events are still forwarded to the audit channel
+        assert!(matches!(event_rx.try_recv(), Ok(Event::OrderNew(_))));
+    }
+
+    #[test]
+    fn metrics_should_bucket_order_latency_measured_by_the_metrics_clock() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let clock = SimulatedClock::new(start);
+        let metrics = Metrics::with_clock(Arc::new(clock.clone()));
+
+        let order = order_event();
+        metrics.record(&Event::OrderNew(order.clone()));
+
+        // FillEvent timestamp is ignored in favour of the time it's recorded
+        clock.advance(start + Duration::milliseconds(1500));
+        metrics.record(&Event::Fill(FillEvent {
+            cid: order.cid,
+            time: start - Duration::days(1),
+            ..fill_event()
+        }));
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("barter_order_latency_seconds_bucket{le=\"1.024\"} 0"));
+        assert!(rendered.contains("barter_order_latency_seconds_bucket{le=\"2.048\"} 1"));
+        assert!(rendered.contains("barter_order_latency_seconds_sum 1.5\n"));
+        assert!(rendered.contains("barter_order_latency_seconds_count 1\n"));
+    }
+}
@@ -1,22 +1,50 @@
 use crate::{
-    data::MarketGenerator,
-    engine::{error::EngineError, trader::Trader},
+    clock::{Clock, LiveClock, SimulatedClock},
+    data::{
+        live::{self, MarketSubscriptions},
+        MarketGenerator,
+    },
+    engine::{
+        checkpoint::{Checkpoint, CheckpointConfig, CheckpointRequester, TraderCheckpoint},
+        digest::DeterminismDigest,
+        error::EngineError,
+        trader::{PanicPolicy, QueuedOrder, SessionSummary, Trader},
+        transition::{TraderState, TransitionLog},
+    },
     event::{Event, MessageTransmitter},
-    execution::ExecutionClient,
+    execution::{
+        order_id::{ClientOrderId, MonotonicOrderIdGenerator, OrderIdGenerator},
+        order_state::OrderState,
+        rate_limit::RateLimiter,
+        AccountId, ExecutionClient,
+    },
     portfolio::{
-        position::Position,
-        repository::{PositionHandler, StatisticHandler},
-        FillUpdater, MarketUpdater, OrderGenerator,
+        equity::EquityRecorder,
+        position::{determine_position_id, Position},
+        repository::{BalanceHandler, PositionHandler, StatisticHandler},
+        Balance, FillUpdater, ManualOrderRequest, MarketUpdater, OrderGenerator, PortfolioSnapshot,
+        PortfolioState,
     },
     statistic::summary::{PositionSummariser, TableBuilder},
     strategy::SignalGenerator,
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{Market, MarketId};
+use barter_integration::model::{instrument::Instrument, Market, MarketId};
+use chrono::{DateTime, Utc};
+use futures::Stream;
 use parking_lot::Mutex;
 use prettytable::Table;
-use serde::Serialize;
-use std::{collections::HashMap, fmt::Debug, sync::Arc, thread};
+use rust_decimal::Decimal;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    future::Future,
+    path::Path,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
 use tokio::sync::{mpsc, oneshot};
 use tracing::{error, info, warn};
 use uuid::Uuid;
@@ -24,18 +52,89 @@ use uuid::Uuid;
 /// Barter Engine module specific errors.
 pub mod error;
 
+/// Checkpoints of the full [`Engine`] state, persisted so a restarted [`Engine`] can resume.
+pub mod checkpoint;
+
+/// Typed [`TraderState`](transition::TraderState) transitions of a Trader, recorded in an
+/// in-memory log for reproducibility & audit.
+pub mod transition;
+
+/// Stable hash of the transition, order & fill sequence of a Trader, used to verify a replay
+/// is deterministic.
+pub mod digest;
+
+/// Session logs recording the inputs of a live Trader, from which the trading session can be
+/// replayed to reproduce an incident.
+pub mod replay;
+
+/// Trading session hours of instruments with defined trading hours, outside of which a Trader
+/// refuses opening orders.
+pub mod calendar;
+
+/// Asynchronous side-effects (eg/ webhooks or notifications) spawned with every fill applied by a
+/// Trader, without blocking it's trading loop.
+pub mod hook;
+
+/// Circuit breaker halting a Trader whose order submission or fill rate exceeds a ceiling.
+pub mod circuit;
+
+/// Prometheus metrics of the trading session, maintained from the Events emitted by every Trader
+/// & served over HTTP in the text exposition format.
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
 /// Contains the trading event loop for a Trader capable of trading a single market pair. A Trader
 /// has it's own Data handler, Strategy & Execution handler, as well as shared access to a global
 /// Portfolio instance.
 pub mod trader;
 
 /// Commands that can be actioned by an [`Engine`] and it's associated [`Trader`]s.
-#[derive(Debug)]
+///
+/// Serialised as an adjacently tagged enum (eg/ `{"type":"ExitPosition","content":{..}}`) so
+/// remote control planes can submit [`Command`]s. Fetch [`Command`]s (eg/
+/// [`Command::FetchOpenPositions`]) contain a `oneshot::Sender` and so cannot be (de)serialised.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type", content = "content")]
 pub enum Command {
     /// Fetches all the [`Engine`]'s open [`Position`]s and sends them on the provided
     /// `oneshot::Sender`. Involves the [`Engine`] only.
+    #[serde(skip)]
     FetchOpenPositions(oneshot::Sender<Result<Vec<Position>, EngineError>>),
 
+    /// Fetches a [`PortfolioSnapshot`] of the live Portfolio and sends it on the provided
+    /// `oneshot::Sender`, without interrupting trading. Involves the [`Engine`] only.
+    #[serde(skip)]
+    FetchPortfolioSnapshot(oneshot::Sender<Result<PortfolioSnapshot, EngineError>>),
+
+    /// Fetches a [`TraderCheckpoint`] of a [`Trader`] and sends it on the provided
+    /// `oneshot::Sender`. Sent by the [`Engine`] to each [`Trader`] when taking a [`Checkpoint`].
+    #[serde(skip)]
+    FetchTraderCheckpoint(oneshot::Sender<TraderCheckpoint>),
+
+    /// Fetches the [`OrderState`] of an order sent for execution and sends it on the provided
+    /// `oneshot::Sender`, or `None` if no [`Trader`] tracks it (eg/ it is yet to be sent, or
+    /// finished long ago). The [`ClientOrderId`] does not identify the [`Market`] it was sent on,
+    /// so this [`Command`] is routed to every [`Trader`]. Involves all [`Trader`]s.
+    #[serde(skip)]
+    FetchOrderState {
+        id: ClientOrderId,
+        state_tx: oneshot::Sender<Option<OrderState>>,
+    },
+
+    /// Fetches every [`OrderEvent`](crate::portfolio::OrderEvent) queued by the [`Trader`]s
+    /// rather than sent for execution (eg/ throttled by a
+    /// [`RateLimiter`](crate::execution::rate_limit::RateLimiter)), alongside why & when it is
+    /// expected to be released, and sends them on the provided `oneshot::Sender`. Involves all
+    /// [`Trader`]s.
+    #[serde(skip)]
+    QueryPendingOrders(oneshot::Sender<Vec<QueuedOrder>>),
+
+    /// Drop a queued order returned by a [`Command::QueryPendingOrders`] before it is sent for
+    /// execution. An order that was already sent is cancelled as per a [`Command::CancelOrder`].
+    /// The [`ClientOrderId`] does not identify the [`Market`] it was queued on, so this
+    /// [`Command`] is routed to every [`Trader`]. Involves all [`Trader`]s.
+    DropPendingOrder { id: ClientOrderId },
+
     /// Terminate every running [`Trader`] associated with this [`Engine`]. Involves all [`Trader`]s.
     Terminate(String),
 
@@ -45,6 +144,200 @@ pub enum Command {
     /// Exit a [`Position`]. Uses the [`Market`] provided to route this [`Command`] to the relevant
     /// [`Trader`] instance. Involves one [`Trader`].
     ExitPosition(Market),
+
+    /// Submit a [`ManualOrderRequest`]. Uses the [`Market`] of the request to route this
+    /// [`Command`] to the relevant [`Trader`] instance. Involves one [`Trader`].
+    ManualOrder(ManualOrderRequest),
+
+    /// Submit a one-cancels-other (OCO) group of take profit & stop [`ManualOrderRequest`]s
+    /// (eg/ to exit a [`Position`] via a bracket), which share a group id. Once one leg fills the
+    /// other is cancelled, or reduced proportionally by a partial fill. Both legs must be of the
+    /// same [`Market`], which is used to route this [`Command`] to the relevant [`Trader`].
+    /// Involves one [`Trader`].
+    SubmitOco {
+        take_profit: Box<ManualOrderRequest>,
+        stop: Box<ManualOrderRequest>,
+    },
+
+    /// Cancel a resting order that has not yet been filled. The [`ClientOrderId`] does not
+    /// identify the [`Market`] it was sent on, so this [`Command`] is routed to every [`Trader`].
+    /// Involves all [`Trader`]s.
+    CancelOrder { id: ClientOrderId },
+
+    /// Amend the limit price and/or the total quantity of a resting order in place, which is
+    /// cheaper than cancel-replace on exchanges that support it. Amends to a quantity that does
+    /// not exceed the quantity already filled are refused. The [`ClientOrderId`] does not identify
+    /// the [`Market`] it was sent on, so this [`Command`] is routed to every [`Trader`]. Involves
+    /// all [`Trader`]s.
+    AmendOrder {
+        id: ClientOrderId,
+        new_price: Option<Decimal>,
+        new_quantity: Option<Decimal>,
+    },
+
+    /// Cancel every resting order, or only those of the [`Instrument`] provided. Routed to every
+    /// [`Trader`] trading a [`Market`] of the [`Instrument`], or to all [`Trader`]s if `None`.
+    CancelAllOrders { instrument: Option<Instrument> },
+
+    /// Pause trading: [`Trader`]s keep consuming market data to keep the Portfolio current, but
+    /// stop generating new orders. Cancels & exits are still actioned. Involves all [`Trader`]s.
+    Pause,
+
+    /// Resume trading after a [`Command::Pause`], or disarm a [`Command::KillSwitch`]. Involves
+    /// all [`Trader`]s.
+    Resume,
+
+    /// Arm the kill switch: cancel every open order, flatten every open [`Position`] with market
+    /// orders, and lock every [`Trader`] so no new orders are generated (including
+    /// [`Command::ManualOrder`]s & [`Command::SubmitOco`]s) until a [`Command::Resume`] is
+    /// received. Involves all [`Trader`]s.
+    KillSwitch,
+
+    /// Reconcile every internal [`Position`] against the Position held on the exchange, as
+    /// reported by [`ExecutionClient::exchange_position`], correcting any [`Position`] that has
+    /// drifted beyond the [`Trader`] reconcile tolerance to match the exchange. Involves all
+    /// [`Trader`]s.
+    Reconcile,
+
+    /// Rebalance the Portfolio towards target weights of the Portfolio equity per
+    /// [`Instrument`] (eg/ 0.5 for 50%). Weights must be non-negative & sum to at most 1.0, with
+    /// the remainder held in cash rather than normalised away. Routed to every [`Trader`] trading
+    /// a [`Market`] of a target [`Instrument`], which sends the market order required to move
+    /// it's [`Position`] towards the target weight, rounded down to it's lot size.
+    Rebalance {
+        #[serde(with = "instrument_weights")]
+        targets: HashMap<Instrument, f64>,
+    },
+
+    /// Apply updated strategy parameters (eg/ thresholds, sizes) to the Strategy of a [`Trader`]
+    /// via [`SignalGenerator::apply_params`](crate::strategy::SignalGenerator::apply_params),
+    /// without restarting it. Invalid parameters are rejected, leaving the existing parameters
+    /// unchanged. Uses the [`Market`] provided to route this [`Command`] to the relevant
+    /// [`Trader`] instance. Involves one [`Trader`].
+    UpdateStrategyParams {
+        market: Market,
+        params: serde_json::Value,
+    },
+
+    /// Rebalance the [`Position`] of a [`Trader`] towards the target weight of the provided
+    /// Portfolio equity, sending the [`CommandResult`] on the provided `oneshot::Sender` if
+    /// present. Sent by the [`Engine`] to every [`Trader`] of a target [`Instrument`] when
+    /// actioning a [`Command::Rebalance`].
+    #[serde(skip)]
+    RebalanceMarket {
+        weight: f64,
+        equity: Decimal,
+        result_tx: Option<oneshot::Sender<CommandResult>>,
+    },
+
+    /// Action a [`Command`] & report exactly one [`CommandOutcome`] with the provided
+    /// correlation id on the [`Event`] stream, so a control plane can tell the caller if it
+    /// succeeded. [`Command`]s involving one [`Trader`] report the outcome of actioning them,
+    /// & a [`Command::Rebalance`] reports the combined outcome of every [`Trader`] it involves,
+    /// whilst other [`Command`]s involving the [`Engine`] or every [`Trader`] are
+    /// [`CommandResult::Accepted`] once routed.
+    Correlated { id: Uuid, command: Box<Command> },
+
+    /// Reports a [`CommandOutcome`] on the [`Event`] stream. Sent by the [`Engine`] to a
+    /// [`Trader`] for [`Command::Correlated`] outcomes determined by the [`Engine`].
+    #[serde(skip)]
+    ReportOutcome(CommandOutcome),
+}
+
+/// Outcome of actioning a [`Command::Correlated`], reported on the [`Event`] stream.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct CommandOutcome {
+    /// Correlation id of the [`Command::Correlated`].
+    pub id: Uuid,
+    pub result: CommandResult,
+}
+
+/// Result of actioning a [`Command`].
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum CommandResult {
+    /// [`Command`] was actioned (eg/ an order was sent for execution).
+    Accepted,
+    /// [`Command`] could not be actioned, for the provided reason (eg/ insufficient margin).
+    Rejected(String),
+    /// [`Command`] had nothing to action (eg/ exiting a [`Position`] that is not open).
+    NoOp,
+    /// [`Command`] involving several [`Trader`]s was actioned by some, but rejected by others,
+    /// for the provided reasons (eg/ one [`Market`] of a [`Command::Rebalance`] is paused).
+    PartiallyAccepted(String),
+}
+
+impl CommandResult {
+    /// Combines the [`CommandResult`] of each [`Market`] involved in a [`Command`] into a single
+    /// [`CommandResult`], citing the reason of every [`Market`] that rejected it in [`Market`]
+    /// order.
+    fn combine(mut results: Vec<(Market, CommandResult)>) -> Self {
+        results.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let rejected = results
+            .iter()
+            .filter_map(|(market, result)| match result {
+                CommandResult::Rejected(reason) => Some(format!("{market:?}: {reason}")),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if rejected.is_empty() {
+            if results
+                .iter()
+                .all(|(_, result)| *result == CommandResult::NoOp)
+            {
+                CommandResult::NoOp
+            } else {
+                CommandResult::Accepted
+            }
+        } else if rejected.len() < results.len() {
+            CommandResult::PartiallyAccepted(rejected.join("; "))
+        } else {
+            CommandResult::Rejected(rejected.join("; "))
+        }
+    }
+}
+
+/// Validates the target weights of a [`Command::Rebalance`] are non-negative & sum to at most
+/// 1.0, returning the reason they are invalid if not.
+fn validate_rebalance_targets(targets: &HashMap<Instrument, f64>) -> Result<(), &'static str> {
+    if targets
+        .values()
+        .any(|weight| !weight.is_finite() || weight.is_sign_negative())
+    {
+        return Err("target weights must be finite & non-negative");
+    }
+
+    // Tolerate the rounding error of weights that sum to 1.0 in decimal (eg/ 0.1 + 0.2 + 0.7)
+    if targets.values().sum::<f64>() > 1.0 + 1e-9 {
+        return Err("target weights must sum to at most 1.0");
+    }
+
+    Ok(())
+}
+
+/// (De)serialises the [`Instrument`] target weights of a [`Command::Rebalance`] as a sequence of
+/// `[instrument, weight]` pairs, since JSON object keys must be strings.
+mod instrument_weights {
+    use barter_integration::model::instrument::Instrument;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::collections::HashMap;
+
+    pub fn serialize<S>(
+        targets: &HashMap<Instrument, f64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(targets)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<HashMap<Instrument, f64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<(Instrument, f64)>::deserialize(deserializer).map(HashMap::from_iter)
+    }
 }
 
 /// Lego components for constructing an [`Engine`] via the new() constructor method.
@@ -75,6 +368,10 @@ where
     pub statistics_summary: Statistic,
 }
 
+/// Default time the [`Trader`]s of an [`Engine`] are given to exit their Positions once
+/// terminated, & again to stop, before the shutdown is forced.
+pub const DEFAULT_SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
 /// Multi-threaded Trading Engine capable of trading with an arbitrary number of [`Trader`]s, one
 /// for each unique [`Market`].
 ///
@@ -114,6 +411,51 @@ where
     /// Uses trading session's exited [`Position`]s to calculate an average statistical summary
     /// across all [`Market`]s traded.
     statistics_summary: Statistic,
+    /// Handle to the [`Clock`] of every [`Trader`], used to tell the time of the [`Engine`]
+    /// records (eg/ [`Checkpoint`]s) so they follow simulated time when backtesting.
+    trader_clocks: Vec<Arc<dyn Clock + Send + Sync>>,
+    /// Optional [`CheckpointConfig`] used to periodically save a [`Checkpoint`] of the [`Engine`].
+    checkpoint: Option<CheckpointConfig>,
+    /// Optional `mpsc::Receiver` of the [`Checkpoint`] requests made by the [`Trader`]s every
+    /// [`CheckpointConfig::every_events`].
+    checkpoint_request_rx: Option<mpsc::Receiver<()>>,
+    /// Optional timeout for fetching the opening [`Balance`] of every exchange before trading.
+    opening_balance_timeout: Option<Duration>,
+    /// Time the [`Trader`]s are given to exit their Positions once terminated, & again to stop,
+    /// before the [`Engine`] shutdown is forced.
+    shutdown_grace_period: Duration,
+    /// Progress of the [`Engine`] when driven via [`Engine::step`] rather than [`Engine::run`].
+    stepping: Stepping,
+}
+
+/// Progress of an [`Engine`] driven via [`Engine::step`].
+#[derive(Debug, Default)]
+struct Stepping {
+    /// Set once the opening [`Balance`] is seeded & every [`Trader`] started.
+    started: bool,
+    /// Index of the next [`Trader`] to step, round-robin.
+    next_trader: usize,
+    /// Terminate message of a received [`Command::Terminate`], with the number of [`Trader`]
+    /// steps remaining until every [`Trader`] actions the exit of it's Position & it is sent.
+    terminate: Option<(String, usize)>,
+    /// Set once a [`Command::Terminate`] has been sent to every [`Trader`].
+    terminated: bool,
+}
+
+/// Audit record of a single step of an [`Engine`] driven via [`Engine::step`] or
+/// [`Engine::into_stream`], ie/ one iteration of the trading loop of one of it's [`Trader`]s.
+#[derive(Debug)]
+pub struct EngineAudit<Statistic> {
+    /// [`Market`] of the [`Trader`] that was stepped.
+    pub market: Market,
+    /// [`TraderState`] of the [`Trader`] after the step.
+    pub state: TraderState,
+    /// Every [`Event`] handled by the [`Trader`] during the step (eg/ the [`MarketEvent`]
+    /// consumed & the resulting Signals, orders & fills), in the order handled.
+    pub events: Vec<Event>,
+    /// [`SessionSummary`] of the [`Trader`] if it stopped during the step, or the
+    /// [`EngineError`] that caused it to terminate early.
+    pub stopped: Option<Result<SessionSummary<Statistic>, EngineError>>,
 }
 
 impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -122,6 +464,7 @@ where
     EventTx: MessageTransmitter<Event> + Send + 'static,
     Statistic: PositionSummariser + TableBuilder + Serialize + Send + 'static,
     Portfolio: PositionHandler
+        + BalanceHandler
         + StatisticHandler<Statistic>
         + MarketUpdater
         + OrderGenerator
@@ -142,9 +485,15 @@ where
             engine_id: lego.engine_id,
             command_rx: lego.command_rx,
             portfolio: lego.portfolio,
+            trader_clocks: lego.traders.iter().map(Trader::clock).collect(),
             traders: lego.traders,
             trader_command_txs: lego.trader_command_txs,
             statistics_summary: lego.statistics_summary,
+            checkpoint: None,
+            checkpoint_request_rx: None,
+            opening_balance_timeout: None,
+            shutdown_grace_period: DEFAULT_SHUTDOWN_GRACE_PERIOD,
+            stepping: Stepping::default(),
         }
     }
 
@@ -157,50 +506,283 @@ where
     /// receives [`Command`]s via the `command_rx` and actions them
     /// (eg/ terminate_traders, fetch_open_positions). If all of the [`Trader`]s stop organically
     /// (eg/ due to a finished [`MarketGenerator`]), the [`Engine`] terminates & prints a summary
-    /// for the trading session.
-    pub async fn run(mut self) {
+    /// for the trading session. Returns the [`SessionSummary`] of every [`Trader`] once stopped,
+    /// including a final snapshot of its Market statistics, or the first [`EngineError`] that
+    /// caused a [`Trader`] to terminate early.
+    pub async fn run(self) -> Result<Vec<SessionSummary<Statistic>>, EngineError> {
+        self.run_until(std::future::pending()).await
+    }
+
+    /// Run the trading [`Engine`] until a SIGINT (ctrl-c) or SIGTERM is received, at which point
+    /// every [`Trader`] is gracefully terminated as if a [`Command::Terminate`] was received.
+    /// Receiving a second signal whilst the [`Trader`]s are stopping forces the shutdown,
+    /// returning an [`EngineError::ShutdownForced`] without awaiting them.
+    pub async fn run_until_signal(self) -> Result<Vec<SessionSummary<Statistic>>, EngineError> {
+        let shutdown = async {
+            shutdown_signal().await;
+            info!(
+                action = "terminating Traders",
+                "Engine received shutdown signal"
+            );
+        };
+
+        // Only polled once the first signal is received, so completes on the second
+        self.run_until_forced(shutdown, shutdown_signal()).await
+    }
+
+    /// Run the trading [`Engine`] until the provided shutdown `Future` completes, at which point
+    /// every [`Trader`] is gracefully terminated as if a [`Command::Terminate`] was received.
+    /// Terminated [`Trader`]s that fail to stop within the shutdown grace period force the
+    /// shutdown, returning an [`EngineError::ShutdownForced`]. See [`Engine::run`] for more
+    /// details.
+    pub async fn run_until<Shutdown>(
+        self,
+        shutdown: Shutdown,
+    ) -> Result<Vec<SessionSummary<Statistic>>, EngineError>
+    where
+        Shutdown: Future<Output = ()>,
+    {
+        self.run_until_forced(shutdown, std::future::pending())
+            .await
+    }
+
+    /// Run the trading [`Engine`] until the provided shutdown `Future` completes (see
+    /// [`Engine::run_until`]). If the provided force `Future` completes whilst the [`Trader`]s
+    /// are stopping, the shutdown is forced without awaiting them.
+    async fn run_until_forced<Shutdown, Force>(
+        mut self,
+        shutdown: Shutdown,
+        force: Force,
+    ) -> Result<Vec<SessionSummary<Statistic>>, EngineError>
+    where
+        Shutdown: Future<Output = ()>,
+        Force: Future<Output = ()>,
+    {
+        if let Some(timeout) = self.opening_balance_timeout {
+            self.seed_opening_balance(timeout).await?;
+        }
+
         // Run Traders on threads & send notification when they have stopped organically
         let mut notify_traders_stopped = self.run_traders().await;
+        let mut trader_summaries = None;
+        let mut shutdown_received = false;
+        let mut checkpoint_interval = self.checkpoint.as_ref().map(|config| {
+            tokio::time::interval_at(
+                tokio::time::Instant::now() + config.interval,
+                config.interval,
+            )
+        });
+        tokio::pin!(shutdown);
 
         loop {
             // Action received commands from remote, or wait for all Traders to stop organically
             tokio::select! {
-                _ = notify_traders_stopped.recv() => {
+                summaries = notify_traders_stopped.recv() => {
+                    trader_summaries = summaries;
+                    break;
+                },
+
+                _ = next_checkpoint(&mut checkpoint_interval) => {
+                    self.save_checkpoint().await;
+                },
+
+                _ = next_checkpoint_request(&mut self.checkpoint_request_rx) => {
+                    self.save_checkpoint().await;
+                },
+
+                _ = &mut shutdown => {
+                    shutdown_received = true;
                     break;
                 },
 
                 command = self.command_rx.recv() => {
-                    if let Some(command) = command {
-                        match command {
-                            Command::FetchOpenPositions(positions_tx) => {
-                                self.fetch_open_positions(positions_tx).await;
-                            },
-                            Command::Terminate(message) => {
-                                self.terminate_traders(message).await;
-                                break;
-                            },
-                            Command::ExitPosition(market) => {
-                                self.exit_position(market).await;
-                            },
-                            Command::ExitAllPositions => {
-                                self.exit_all_positions().await;
-                            },
-                        }
-                    } else {
+                    let terminated = match command {
+                        Some(Command::Correlated { id, command }) => {
+                            self.correlated_command(id, *command).await
+                        },
+                        Some(command) => self.action_command(command).await,
                         // Terminate traders due to dropped receiver
+                        None => true,
+                    };
+
+                    if terminated {
                         break;
                     }
                 }
             }
         }
 
+        // Wait for the Traders to stop if they were terminated, unless the shutdown is forced
+        let stopping = async {
+            if shutdown_received {
+                self.terminate_traders("shutdown signal received".to_owned())
+                    .await;
+            }
+            match trader_summaries {
+                Some(results) => Ok(results),
+                None => {
+                    tokio::time::timeout(self.shutdown_grace_period, notify_traders_stopped.recv())
+                        .await
+                        .map(Option::unwrap_or_default)
+                        .map_err(|_| "Traders failed to stop within the shutdown grace period")
+                }
+            }
+        };
+        let trader_results = tokio::select! {
+            results = stopping => results,
+            _ = force => Err("second shutdown signal received"),
+        };
+        let trader_results = match trader_results {
+            Ok(results) => results,
+            Err(why) => {
+                error!(
+                    why,
+                    action = "exiting without awaiting every Trader",
+                    "Engine shutdown forced"
+                );
+                return Err(EngineError::ShutdownForced(why));
+            }
+        };
+
+        // Fold the final Market statistics snapshot into each Trader's SessionSummary
+        let summaries = trader_results
+            .into_iter()
+            .map(|result| self.with_statistics(result))
+            .collect();
+
         // Print Trading Session Summary
         self.generate_session_summary().printstd();
+
+        summaries
+    }
+
+    /// Folds the final snapshot of the [`Market`] statistics tracked by the Portfolio into the
+    /// [`SessionSummary`] of a stopped [`Trader`].
+    fn with_statistics(
+        &self,
+        result: Result<SessionSummary<Statistic>, EngineError>,
+    ) -> Result<SessionSummary<Statistic>, EngineError> {
+        result.map(|mut summary| {
+            summary.statistics = self
+                .portfolio
+                .lock()
+                .get_statistics(&MarketId::from(&summary.market))
+                .ok();
+            summary
+        })
+    }
+
+    /// Single-steps the trading [`Engine`] on the current task, as an alternative to
+    /// [`Engine::run`] for embedding it in an external event loop (eg/ a UI). Each step actions
+    /// the [`Command`]s received via the `command_rx`, then runs one iteration of the trading
+    /// loop of the next [`Trader`] (round-robin), returning an [`EngineAudit`] of it. Returns
+    /// `None` once every [`Trader`] has stopped, either organically or due to a
+    /// [`Command::Terminate`].
+    ///
+    /// The first step seeds the opening [`Balance`] (if configured) & starts every [`Trader`].
+    /// A [`Command::Terminate`] exits every open [`Position`] before terminating the [`Trader`]s.
+    /// Periodic [`Checkpoint`]s are only taken by [`Engine::run`], and no trading session
+    /// summary is printed.
+    pub async fn step(&mut self) -> Option<EngineAudit<Statistic>> {
+        if !self.stepping.started {
+            self.stepping.started = true;
+            if let Some(timeout) = self.opening_balance_timeout {
+                if let Err(error) = self.seed_opening_balance(timeout).await {
+                    error!(?error, "failed to seed opening Balance, stopping Engine");
+                    self.traders.clear();
+                    return None;
+                }
+            }
+            self.traders.iter_mut().for_each(Trader::start);
+        }
+
+        if self.stepping.terminate.is_none() && !self.stepping.terminated {
+            while let Ok(command) = self.command_rx.try_recv() {
+                let terminate = match command {
+                    Command::Terminate(message) => Some(message),
+                    Command::Correlated { id, command } => match *command {
+                        Command::Terminate(message) => {
+                            self.report_command_outcome(CommandOutcome {
+                                id,
+                                result: CommandResult::Accepted,
+                            })
+                            .await;
+                            Some(message)
+                        }
+                        command => {
+                            self.correlated_command(id, command).await;
+                            None
+                        }
+                    },
+                    command => {
+                        self.action_command(command).await;
+                        None
+                    }
+                };
+
+                if let Some(message) = terminate {
+                    self.exit_all_positions().await;
+                    self.stepping.terminate = Some((message, self.traders.len()));
+                    break;
+                }
+            }
+        }
+
+        if self.traders.is_empty() {
+            return None;
+        }
+
+        let index = self.stepping.next_trader % self.traders.len();
+        let trader = &mut self.traders[index];
+        let market = trader.market().clone();
+        let (events, stopped) = trader.step();
+        let state = trader.state();
+
+        let stopped = match stopped {
+            Some(result) => {
+                let result = self.traders.remove(index).stop(result);
+                self.stepping.next_trader = index;
+                Some(self.with_statistics(result))
+            }
+            None => {
+                self.stepping.next_trader = index + 1;
+                None
+            }
+        };
+
+        // Terminate the Traders once each has been stepped to action the exit of it's Position
+        if let Some((message, remaining)) = &mut self.stepping.terminate {
+            *remaining = remaining.saturating_sub(1).min(self.traders.len());
+            if *remaining == 0 {
+                let message = std::mem::take(message);
+                self.stepping.terminate = None;
+                self.stepping.terminated = true;
+                self.send_terminate(message).await;
+            }
+        }
+
+        Some(EngineAudit {
+            market,
+            state,
+            events,
+            stopped,
+        })
     }
 
-    /// Runs each [`Trader`] it's own thread. Sends a message on the returned `mpsc::Receiver<bool>`
-    /// if all the [`Trader`]s have stopped organically (eg/ due to a finished [`MarketEvent`] feed).
-    async fn run_traders(&mut self) -> mpsc::Receiver<bool> {
+    /// Converts the trading [`Engine`] into a `Stream` of the [`EngineAudit`] of every
+    /// [`Engine::step`], which drives the [`Engine`] when polled & ends once every [`Trader`]
+    /// has stopped.
+    pub fn into_stream(self) -> impl Stream<Item = EngineAudit<Statistic>> {
+        futures::stream::unfold(self, |mut engine| async move {
+            engine.step().await.map(|audit| (audit, engine))
+        })
+    }
+
+    /// Runs each [`Trader`] it's own thread. Sends the result of every [`Trader`] on the returned
+    /// `mpsc::Receiver` once they have all stopped (eg/ due to a finished [`MarketEvent`] feed).
+    async fn run_traders(
+        &mut self,
+    ) -> mpsc::Receiver<Vec<Result<SessionSummary<Statistic>, EngineError>>> {
         // Extract Traders out of the Engine so we can move them into threads
         let traders = std::mem::take(&mut self.traders);
 
@@ -214,23 +796,88 @@ where
         // Create channel to notify the Engine when the Traders have stopped organically
         let (notify_tx, notify_rx) = mpsc::channel(1);
 
-        // Create Task that notifies Engine when the Traders have stopped organically
-        tokio::spawn(async move {
+        // Create blocking Task that notifies Engine when the Traders have stopped organically
+        // '--> joining the Trader threads must not block the async runtime the Engine runs on
+        tokio::task::spawn_blocking(move || {
+            let mut summaries = Vec::with_capacity(thread_handles.len());
             for handle in thread_handles {
-                if let Err(err) = handle.join() {
-                    error!(
-                        error = &*format!("{:?}", err),
-                        "Trader thread has panicked during execution",
-                    )
+                match handle.join() {
+                    Ok(result) => summaries.push(result),
+                    Err(err) => {
+                        error!(
+                            error = &*format!("{:?}", err),
+                            "Trader thread has panicked during execution",
+                        );
+                        summaries.push(Err(EngineError::TraderPanic(format!("{:?}", err))));
+                    }
                 }
             }
 
-            let _ = notify_tx.send(true).await;
+            let _ = notify_tx.blocking_send(summaries);
         });
 
         notify_rx
     }
 
+    /// Seeds the Portfolio [`Balance`] with the sum of the opening [`Balance`]s fetched from the
+    /// exchange account of every traded exchange, awaiting each concurrently. The first
+    /// [`Trader`] of each exchange fetches it's [`Balance`] via
+    /// [`ExecutionClient::fetch_balance`]. An exchange that fails to respond within the timeout
+    /// provided contributes a zero [`Balance`], and a warning is logged.
+    async fn seed_opening_balance(&mut self, timeout: Duration) -> Result<(), EngineError> {
+        let mut exchanges = HashSet::new();
+        let fetches = self
+            .traders
+            .iter_mut()
+            .filter(|trader| exchanges.insert(trader.market().exchange.clone()))
+            .map(|trader| async move {
+                let exchange = trader.market().exchange.clone();
+                let balance = match tokio::time::timeout(timeout, trader.fetch_balance()).await {
+                    Ok(Ok(Some(balance))) => Some(balance),
+                    Ok(Ok(None)) => {
+                        warn!(
+                            %exchange,
+                            why = "ExecutionClient does not support fetching a Balance",
+                            "assuming zero opening Balance for exchange"
+                        );
+                        None
+                    }
+                    Ok(Err(error)) => {
+                        warn!(
+                            %exchange,
+                            ?error,
+                            "failed to fetch opening Balance, assuming zero for exchange"
+                        );
+                        None
+                    }
+                    Err(_) => {
+                        warn!(
+                            %exchange,
+                            ?timeout,
+                            "timed out fetching opening Balance, assuming zero for exchange"
+                        );
+                        None
+                    }
+                };
+                (exchange, balance)
+            });
+
+        let balance = futures::future::join_all(fetches).await.into_iter().fold(
+            Balance::new(self.now(), Decimal::ZERO, Decimal::ZERO),
+            |mut opening, (exchange, balance)| {
+                if let Some(balance) = balance {
+                    info!(%exchange, ?balance, "fetched opening Balance for exchange");
+                    opening.total += balance.total;
+                    opening.available += balance.available;
+                }
+                opening
+            },
+        );
+
+        self.portfolio.lock().set_balance(self.engine_id, balance)?;
+        Ok(())
+    }
+
     /// Fetches all the [`Engine`]'s open [`Position`]s and sends them on the provided
     /// `oneshot::Sender`.
     async fn fetch_open_positions(
@@ -251,223 +898,2725 @@ where
         }
     }
 
-    /// Terminate every running [`Trader`] associated with this [`Engine`].
-    async fn terminate_traders(&self, message: String) {
-        // Firstly, exit all Positions
-        self.exit_all_positions().await;
-        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    /// Fetches a [`PortfolioSnapshot`] of the live Portfolio and sends it on the provided
+    /// `oneshot::Sender`. Traders hold the Portfolio lock while processing each Event, so the
+    /// [`PortfolioSnapshot`] always reflects the Portfolio between Event transitions.
+    async fn fetch_portfolio_snapshot(
+        &self,
+        snapshot_tx: oneshot::Sender<Result<PortfolioSnapshot, EngineError>>,
+    ) {
+        if snapshot_tx.send(self.portfolio_snapshot()).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::FetchPortfolioSnapshot"
+            );
+        }
+    }
 
-        // Distribute Command::Terminate to all the Engine's Traders
-        for (market, command_tx) in self.trader_command_txs.iter() {
-            if command_tx
-                .send(Command::Terminate(message.clone()))
+    /// Returns the current time of the [`Engine`], ie/ the latest time told by the [`Clock`] of
+    /// any of it's [`Trader`]s.
+    fn now(&self) -> DateTime<Utc> {
+        self.trader_clocks
+            .iter()
+            .map(|clock| clock.now())
+            .max()
+            .unwrap_or_else(Utc::now)
+    }
+
+    /// Takes a [`PortfolioSnapshot`] of the Portfolio [`Balance`] & the open [`Position`]s of
+    /// every [`Market`] traded.
+    fn portfolio_snapshot(&self) -> Result<PortfolioSnapshot, EngineError> {
+        let mut portfolio = self.portfolio.lock();
+        portfolio
+            .get_balance(self.engine_id)
+            .and_then(|balance| {
+                portfolio
+                    .get_open_positions(self.engine_id, self.trader_command_txs.keys())
+                    .map(|open_positions| {
+                        PortfolioSnapshot::new(self.now(), balance, open_positions)
+                    })
+            })
+            .map_err(EngineError::RepositoryInteractionError)
+    }
+
+    /// Takes a [`Checkpoint`] of the full [`Engine`] state, fetching a [`TraderCheckpoint`] from
+    /// every running [`Trader`].
+    async fn take_checkpoint(&self) -> Result<Checkpoint<Statistic>, EngineError> {
+        let mut traders = Vec::with_capacity(self.trader_command_txs.len());
+        for command_tx in self.trader_command_txs.values() {
+            let (checkpoint_tx, checkpoint_rx) = oneshot::channel();
+            command_tx
+                .send(Command::FetchTraderCheckpoint(checkpoint_tx))
                 .await
-                .is_err()
-            {
-                error!(
-                    market = &*format!("{:?}", market),
-                    why = "dropped receiver",
-                    "failed to send Command::Terminate to Trader command_rx"
-                );
-            }
+                .map_err(|_| EngineError::Checkpoint("Trader command_rx dropped"))?;
+            traders.push(
+                checkpoint_rx
+                    .await
+                    .map_err(|_| EngineError::Checkpoint("Trader stopped before responding"))?,
+            );
+        }
+        traders.sort_by(|a, b| a.market.cmp(&b.market));
+
+        let mut portfolio = self.portfolio.lock();
+        let statistics = self
+            .trader_command_txs
+            .keys()
+            .map(|market| {
+                let market_id = MarketId::from(market);
+                portfolio
+                    .get_statistics(&market_id)
+                    .map(|statistic| (market_id, statistic))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        Ok(Checkpoint {
+            engine_id: self.engine_id,
+            time: self.now(),
+            portfolio: PortfolioState {
+                balance: portfolio.get_balance(self.engine_id)?,
+                open_positions: portfolio
+                    .get_open_positions(self.engine_id, self.trader_command_txs.keys())?,
+            },
+            statistics,
+            traders,
+        })
+    }
+
+    /// Fetches the [`OrderState`] of an order from every [`Trader`] & sends the first known
+    /// [`OrderState`] on the provided `oneshot::Sender`, since only the [`Trader`] that sent the
+    /// order can identify it.
+    async fn fetch_order_state(
+        &self,
+        id: ClientOrderId,
+        state_tx: oneshot::Sender<Option<OrderState>>,
+    ) {
+        let state = match self.stepping.started {
+            // Stepped Traders only receive Commands whilst stepped on this task, so are queried
+            // directly rather than via their command_rx
+            true => self
+                .traders
+                .iter()
+                .find_map(|trader| trader.order_state(&id)),
+            false => self.fetch_trader_order_state(id).await,
+        };
+
+        if state_tx.send(state).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::FetchOrderState"
+            );
         }
     }
 
-    /// Exit every open [`Position`] associated with this [`Engine`].
-    async fn exit_all_positions(&self) {
+    /// Fetches the [`OrderState`] of an order from each running [`Trader`] via it's command_rx,
+    /// returning the first known [`OrderState`].
+    async fn fetch_trader_order_state(&self, id: ClientOrderId) -> Option<OrderState> {
         for (market, command_tx) in self.trader_command_txs.iter() {
-            if command_tx
-                .send(Command::ExitPosition(market.clone()))
-                .await
-                .is_err()
-            {
+            let (trader_state_tx, trader_state_rx) = oneshot::channel();
+            let command = Command::FetchOrderState {
+                id,
+                state_tx: trader_state_tx,
+            };
+            if command_tx.send(command).await.is_err() {
                 error!(
                     market = &*format!("{:?}", market),
                     why = "dropped receiver",
-                    "failed to send Command::Terminate to Trader command_rx"
+                    "failed to send Command::FetchOrderState to Trader command_rx"
                 );
+                continue;
+            }
+            if let Ok(Some(state)) = trader_state_rx.await {
+                return Some(state);
             }
         }
+        None
     }
 
-    /// Exit a [`Position`]. Uses the [`Market`] provided to route this [`Command`] to the relevant
-    /// [`Trader`] instance.
-    async fn exit_position(&self, market: Market) {
-        if let Some((market_ref, command_tx)) = self.trader_command_txs.get_key_value(&market) {
+    /// Fetches the queued orders of every [`Trader`] & sends them on the provided
+    /// `oneshot::Sender`.
+    async fn query_pending_orders(&self, orders_tx: oneshot::Sender<Vec<QueuedOrder>>) {
+        let orders = match self.stepping.started {
+            // Stepped Traders only receive Commands whilst stepped on this task, so are queried
+            // directly rather than via their command_rx
+            true => self
+                .traders
+                .iter()
+                .flat_map(Trader::queued_orders)
+                .collect(),
+            false => self.fetch_trader_queued_orders().await,
+        };
+
+        if orders_tx.send(orders).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::QueryPendingOrders"
+            );
+        }
+    }
+
+    /// Fetches the queued orders of each running [`Trader`] via it's command_rx.
+    async fn fetch_trader_queued_orders(&self) -> Vec<QueuedOrder> {
+        let mut orders = Vec::new();
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            let (trader_orders_tx, trader_orders_rx) = oneshot::channel();
             if command_tx
-                .send(Command::ExitPosition(market))
+                .send(Command::QueryPendingOrders(trader_orders_tx))
                 .await
                 .is_err()
             {
                 error!(
-                    market = &*format!("{:?}", market_ref),
+                    market = &*format!("{:?}", market),
                     why = "dropped receiver",
-                    "failed to send Command::Terminate to Trader command_rx"
+                    "failed to send Command::QueryPendingOrders to Trader command_rx"
                 );
+                continue;
+            }
+            if let Ok(trader_orders) = trader_orders_rx.await {
+                orders.extend(trader_orders);
             }
-        } else {
-            warn!(
-                market = &*format!("{:?}", market),
-                why = "Engine has no trader_command_tx associated with provided Market",
-                "failed to exit Position"
-            );
         }
+        orders
     }
 
-    /// Generate a trading session summary. Uses the Portfolio's statistics per [`Market`] in
-    /// combination with the average statistics across all [`Market`]s traded.
-    fn generate_session_summary(mut self) -> Table {
-        // Fetch statistics for each Market
-        let stats_per_market = self.trader_command_txs.into_keys().filter_map(|market| {
-            let market_id = MarketId::from(&market);
+    /// Takes a [`Checkpoint`] & saves it to the configured [`CheckpointConfig`] path. Failures
+    /// are logged rather than terminating the [`Engine`].
+    async fn save_checkpoint(&self) {
+        let Some(config) = &self.checkpoint else {
+            return;
+        };
 
-            match self.portfolio.lock().get_statistics(&market_id) {
-                Ok(statistics) => Some((market_id.0, statistics)),
-                Err(error) => {
-                    error!(
-                        ?error,
-                        ?market,
-                        "failed to get Market statistics when generating trading session summary"
-                    );
-                    None
-                }
-            }
-        });
+        let result = match self.take_checkpoint().await {
+            Ok(checkpoint) => checkpoint.save(&config.path),
+            Err(error) => Err(error),
+        };
 
-        // Generate average statistics across all markets using session's exited Positions
-        self.portfolio
-            .lock()
-            .get_exited_positions(self.engine_id)
-            .map(|exited_positions| {
-                self.statistics_summary.generate_summary(&exited_positions);
-            })
-            .unwrap_or_else(|error| {
+        match result {
+            Ok(()) => info!(path = ?config.path, "saved Engine Checkpoint"),
+            Err(error) => warn!(path = ?config.path, ?error, "failed to save Engine Checkpoint"),
+        }
+    }
+
+    /// Action a [`Command`] received from remote. Returns `true` if the [`Command`] terminated
+    /// the [`Trader`]s.
+    async fn action_command(&self, command: Command) -> bool {
+        match command {
+            Command::FetchOpenPositions(positions_tx) => {
+                self.fetch_open_positions(positions_tx).await;
+            }
+            Command::FetchPortfolioSnapshot(snapshot_tx) => {
+                self.fetch_portfolio_snapshot(snapshot_tx).await;
+            }
+            Command::FetchTraderCheckpoint(_) => {
                 warn!(
-                    ?error,
-                    why = "failed to get exited Positions from Portfolio's repository",
-                    "failed to generate Statistics summary for trading session"
+                    why = "Command::FetchTraderCheckpoint is routed to Traders by the Engine",
+                    "cannot action Command::FetchTraderCheckpoint"
                 );
+            }
+            Command::FetchOrderState { id, state_tx } => {
+                self.fetch_order_state(id, state_tx).await;
+            }
+            Command::QueryPendingOrders(orders_tx) => {
+                self.query_pending_orders(orders_tx).await;
+            }
+            Command::ReportOutcome(_) => {
+                warn!(
+                    why = "Command::ReportOutcome is routed to Traders by the Engine",
+                    "cannot action Command::ReportOutcome"
+                );
+            }
+            Command::RebalanceMarket { .. } => {
+                warn!(
+                    why = "Command::RebalanceMarket is routed to Traders by the Engine",
+                    "cannot action Command::RebalanceMarket"
+                );
+            }
+            Command::Correlated { .. } => {
+                warn!(
+                    why = "Command::Correlated cannot be nested",
+                    "cannot action Command::Correlated"
+                );
+            }
+            Command::Terminate(message) => {
+                self.terminate_traders(message).await;
+                return true;
+            }
+            Command::ExitPosition(market) => {
+                self.exit_position(market).await;
+            }
+            Command::ExitAllPositions => {
+                self.exit_all_positions().await;
+            }
+            Command::ManualOrder(request) => {
+                self.manual_order(request).await;
+            }
+            Command::SubmitOco { take_profit, stop } => {
+                self.submit_oco(take_profit, stop).await;
+            }
+            Command::CancelOrder { id } => {
+                self.cancel_order(id).await;
+            }
+            Command::DropPendingOrder { id } => {
+                self.drop_pending_order(id).await;
+            }
+            Command::AmendOrder {
+                id,
+                new_price,
+                new_quantity,
+            } => {
+                self.amend_order(id, new_price, new_quantity).await;
+            }
+            Command::CancelAllOrders { instrument } => {
+                self.cancel_all_orders(instrument).await;
+            }
+            Command::Pause => {
+                self.set_traders_paused(true).await;
+            }
+            Command::Resume => {
+                self.set_traders_paused(false).await;
+            }
+            Command::KillSwitch => {
+                self.arm_kill_switch().await;
+            }
+            Command::Reconcile => {
+                self.reconcile_positions().await;
+            }
+            Command::Rebalance { targets } => {
+                if let Err(why) = self.rebalance(targets, false).await {
+                    warn!(why, "cannot action Command::Rebalance");
+                }
+            }
+            Command::UpdateStrategyParams { market, params } => {
+                self.update_strategy_params(market, params).await;
+            }
+        }
+
+        false
+    }
+
+    /// Action a [`Command::Correlated`], ensuring exactly one [`CommandOutcome`] is reported.
+    /// [`Command`]s involving one [`Trader`] are routed to it, so it reports the outcome of
+    /// actioning them. A [`Command::Rebalance`] is reported once every [`Trader`] it involves
+    /// has responded (see [`CommandResult::combine`]). Otherwise the [`Engine`] reports the
+    /// outcome via a [`Trader`] before actioning the [`Command`], since a [`Command::Terminate`]
+    /// stops every [`Trader`]. Returns `true` if the [`Command`] terminated the [`Trader`]s.
+    async fn correlated_command(&self, id: Uuid, command: Command) -> bool {
+        if let Command::Rebalance { targets } = command {
+            let result = match self.rebalance(targets, true).await {
+                Ok(result_rxs) => {
+                    let mut results = Vec::with_capacity(result_rxs.len());
+                    for (market, result_rx) in result_rxs {
+                        let result = result_rx.await.unwrap_or_else(|_| {
+                            CommandResult::Rejected("Trader stopped before responding".to_owned())
+                        });
+                        results.push((market, result));
+                    }
+                    CommandResult::combine(results)
+                }
+                Err(why) => CommandResult::Rejected(why),
+            };
+            self.report_command_outcome(CommandOutcome { id, result })
+                .await;
+            return false;
+        }
+
+        let market = match &command {
+            Command::ExitPosition(market) => Some(market.clone()),
+            Command::ManualOrder(request) => Some(request.market()),
+            Command::SubmitOco { take_profit, .. } => Some(take_profit.market()),
+            Command::UpdateStrategyParams { market, .. } => Some(market.clone()),
+            _ => None,
+        };
+
+        if let Some(market) = market {
+            match self.trader_command_txs.get(&market) {
+                Some(command_tx) => {
+                    let command = Command::Correlated {
+                        id,
+                        command: Box::new(command),
+                    };
+                    if command_tx.send(command).await.is_err() {
+                        error!(
+                            market = &*format!("{:?}", market),
+                            why = "dropped receiver",
+                            "failed to send Command::Correlated to Trader command_rx"
+                        );
+                    }
+                }
+                None => {
+                    let reason = format!("Engine has no Trader associated with Market {market:?}");
+                    self.report_command_outcome(CommandOutcome {
+                        id,
+                        result: CommandResult::Rejected(reason),
+                    })
+                    .await;
+                }
+            }
+            return false;
+        }
+
+        let result = match &command {
+            Command::Correlated { .. }
+            | Command::ReportOutcome(_)
+            | Command::RebalanceMarket { .. } => {
+                CommandResult::Rejected("Command cannot be correlated".to_owned())
+            }
+            _ => CommandResult::Accepted,
+        };
+        let accepted = result == CommandResult::Accepted;
+        self.report_command_outcome(CommandOutcome { id, result })
+            .await;
+
+        accepted && self.action_command(command).await
+    }
+
+    /// Reports a [`CommandOutcome`] determined by the [`Engine`] on the [`Event`] stream, via
+    /// one of the Engine's [`Trader`]s.
+    async fn report_command_outcome(&self, outcome: CommandOutcome) {
+        let reported = match self.trader_command_txs.values().next() {
+            Some(command_tx) => command_tx
+                .send(Command::ReportOutcome(outcome))
+                .await
+                .is_ok(),
+            None => false,
+        };
+
+        if !reported {
+            warn!(
+                why = "Engine has no running Trader to report on the Event stream",
+                "failed to report CommandOutcome"
+            );
+        }
+    }
+
+    /// Terminate every running [`Trader`] associated with this [`Engine`], once every open
+    /// [`Position`] is exited or the shutdown grace period expires.
+    async fn terminate_traders(&self, message: String) {
+        // Firstly, exit all Positions
+        self.exit_all_positions().await;
+        self.await_positions_exited().await;
+
+        self.send_terminate(message).await;
+    }
+
+    /// Waits until the Portfolio holds no open [`Position`] associated with this [`Engine`], or
+    /// the shutdown grace period expires.
+    async fn await_positions_exited(&self) {
+        let deadline = tokio::time::Instant::now() + self.shutdown_grace_period;
+        loop {
+            let open_positions = self
+                .portfolio
+                .lock()
+                .get_open_positions(self.engine_id, self.trader_command_txs.keys());
+            match open_positions {
+                Ok(positions) if positions.is_empty() => return,
+                Ok(_) => {}
+                Err(error) => {
+                    warn!(?error, "failed to fetch open Positions to await their exit");
+                    return;
+                }
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                warn!(
+                    grace_period = ?self.shutdown_grace_period,
+                    action = "terminating Traders with open Positions",
+                    "open Positions were not exited within the shutdown grace period"
+                );
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    /// Distribute a [`Command::Terminate`] to all the Engine's [`Trader`]s.
+    async fn send_terminate(&self, message: String) {
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            if command_tx
+                .send(Command::Terminate(message.clone()))
+                .await
+                .is_err()
+            {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::Terminate to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Exit every open [`Position`] associated with this [`Engine`].
+    async fn exit_all_positions(&self) {
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            if command_tx
+                .send(Command::ExitPosition(market.clone()))
+                .await
+                .is_err()
+            {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::ExitPosition to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Cancel a resting order. Routed to every [`Trader`] since only the [`Trader`] that sent
+    /// the order can identify it.
+    async fn cancel_order(&self, id: ClientOrderId) {
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            if command_tx.send(Command::CancelOrder { id }).await.is_err() {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::CancelOrder to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Drop a queued order before it is sent for execution, or cancel it if already sent. Routed
+    /// to every [`Trader`] since only the [`Trader`] that queued the order can identify it.
+    async fn drop_pending_order(&self, id: ClientOrderId) {
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            if command_tx
+                .send(Command::DropPendingOrder { id })
+                .await
+                .is_err()
+            {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::DropPendingOrder to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Amend a resting order in place. Routed to every [`Trader`] since only the [`Trader`] that
+    /// sent the order can identify it.
+    async fn amend_order(
+        &self,
+        id: ClientOrderId,
+        new_price: Option<Decimal>,
+        new_quantity: Option<Decimal>,
+    ) {
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            let command = Command::AmendOrder {
+                id,
+                new_price,
+                new_quantity,
+            };
+            if command_tx.send(command).await.is_err() {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::AmendOrder to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Cancel every resting order of the [`Instrument`] provided, or of every [`Trader`] if
+    /// `None`.
+    async fn cancel_all_orders(&self, instrument: Option<Instrument>) {
+        let command_txs = self.trader_command_txs.iter().filter(|(market, _)| {
+            instrument
+                .as_ref()
+                .is_none_or(|instrument| &market.instrument == instrument)
+        });
+
+        for (market, command_tx) in command_txs {
+            if command_tx
+                .send(Command::CancelAllOrders {
+                    instrument: instrument.clone(),
+                })
+                .await
+                .is_err()
+            {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::CancelAllOrders to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Distribute a [`Command::Pause`], or [`Command::Resume`], to all the Engine's [`Trader`]s.
+    async fn set_traders_paused(&self, paused: bool) {
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            let command = if paused {
+                Command::Pause
+            } else {
+                Command::Resume
+            };
+
+            if command_tx.send(command).await.is_err() {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    paused,
+                    "failed to send Command::Pause or Command::Resume to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Distribute a [`Command::KillSwitch`] to all the Engine's [`Trader`]s.
+    async fn arm_kill_switch(&self) {
+        warn!("kill switch armed, flattening every open Position & locking every Trader");
+
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            if command_tx.send(Command::KillSwitch).await.is_err() {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::KillSwitch to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Distribute a [`Command::Reconcile`] to all the Engine's [`Trader`]s.
+    async fn reconcile_positions(&self) {
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            if command_tx.send(Command::Reconcile).await.is_err() {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::Reconcile to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Distribute the target weight of each [`Instrument`] of a [`Command::Rebalance`], alongside
+    /// the current Portfolio equity, to every [`Trader`] trading a [`Market`] of it. If `reply` is
+    /// set, returns a `oneshot::Receiver` of the [`CommandResult`] of each [`Trader`] involved.
+    /// Returns the reason the [`Command::Rebalance`] cannot be actioned, if so.
+    async fn rebalance(
+        &self,
+        targets: HashMap<Instrument, f64>,
+        reply: bool,
+    ) -> Result<Vec<(Market, oneshot::Receiver<CommandResult>)>, String> {
+        validate_rebalance_targets(&targets)?;
+
+        let equity = self
+            .portfolio_snapshot()
+            .map_err(|error| format!("failed to fetch Portfolio equity: {error}"))?
+            .equity;
+
+        let mut result_rxs = Vec::new();
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            let Some(&weight) = targets.get(&market.instrument) else {
+                continue;
+            };
+
+            // Sender is dropped if the Trader never receives it, so the Receiver still resolves
+            let result_tx = reply.then(|| {
+                let (result_tx, result_rx) = oneshot::channel();
+                result_rxs.push((market.clone(), result_rx));
+                result_tx
             });
 
-        // Combine Total & Per-Market Statistics Into Table
-        crate::statistic::summary::combine(
-            stats_per_market.chain([("Total".to_owned(), self.statistics_summary)]),
-        )
+            if command_tx
+                .send(Command::RebalanceMarket {
+                    weight,
+                    equity,
+                    result_tx,
+                })
+                .await
+                .is_err()
+            {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::RebalanceMarket to Trader command_rx"
+                );
+            }
+        }
+
+        Ok(result_rxs)
+    }
+
+    /// Exit a [`Position`]. Uses the [`Market`] provided to route this [`Command`] to the relevant
+    /// [`Trader`] instance.
+    async fn exit_position(&self, market: Market) {
+        if let Some((market_ref, command_tx)) = self.trader_command_txs.get_key_value(&market) {
+            if command_tx
+                .send(Command::ExitPosition(market))
+                .await
+                .is_err()
+            {
+                error!(
+                    market = &*format!("{:?}", market_ref),
+                    why = "dropped receiver",
+                    "failed to send Command::ExitPosition to Trader command_rx"
+                );
+            }
+        } else {
+            warn!(
+                market = &*format!("{:?}", market),
+                why = "Engine has no trader_command_tx associated with provided Market",
+                "failed to exit Position"
+            );
+        }
+    }
+
+    /// Submit a [`ManualOrderRequest`]. Uses the [`Market`] of the request to route this
+    /// [`Command`] to the relevant [`Trader`] instance.
+    async fn manual_order(&self, request: ManualOrderRequest) {
+        let market = request.market();
+
+        if let Some((market_ref, command_tx)) = self.trader_command_txs.get_key_value(&market) {
+            if command_tx
+                .send(Command::ManualOrder(request))
+                .await
+                .is_err()
+            {
+                error!(
+                    market = &*format!("{:?}", market_ref),
+                    why = "dropped receiver",
+                    "failed to send Command::ManualOrder to Trader command_rx"
+                );
+            }
+        } else {
+            warn!(
+                market = &*format!("{:?}", market),
+                why = "Engine has no trader_command_tx associated with provided Market",
+                "rejected ManualOrderRequest"
+            );
+        }
+    }
+
+    /// Submit a one-cancels-other group of [`ManualOrderRequest`]s. Uses the [`Market`] of the
+    /// take profit leg to route this [`Command`] to the relevant [`Trader`] instance, which
+    /// rejects the group if the stop leg is of a different [`Market`].
+    async fn submit_oco(
+        &self,
+        take_profit: Box<ManualOrderRequest>,
+        stop: Box<ManualOrderRequest>,
+    ) {
+        let market = take_profit.market();
+
+        if let Some((market_ref, command_tx)) = self.trader_command_txs.get_key_value(&market) {
+            if command_tx
+                .send(Command::SubmitOco { take_profit, stop })
+                .await
+                .is_err()
+            {
+                error!(
+                    market = &*format!("{:?}", market_ref),
+                    why = "dropped receiver",
+                    "failed to send Command::SubmitOco to Trader command_rx"
+                );
+            }
+        } else {
+            warn!(
+                market = &*format!("{:?}", market),
+                why = "Engine has no trader_command_tx associated with provided Market",
+                "rejected OCO ManualOrderRequests"
+            );
+        }
+    }
+
+    /// Apply updated strategy parameters. Uses the [`Market`] provided to route this
+    /// [`Command`] to the relevant [`Trader`] instance.
+    async fn update_strategy_params(&self, market: Market, params: serde_json::Value) {
+        if let Some((market_ref, command_tx)) = self.trader_command_txs.get_key_value(&market) {
+            if command_tx
+                .send(Command::UpdateStrategyParams { market, params })
+                .await
+                .is_err()
+            {
+                error!(
+                    market = &*format!("{:?}", market_ref),
+                    why = "dropped receiver",
+                    "failed to send Command::UpdateStrategyParams to Trader command_rx"
+                );
+            }
+        } else {
+            warn!(
+                market = &*format!("{:?}", market),
+                why = "Engine has no trader_command_tx associated with provided Market",
+                "rejected strategy parameters update"
+            );
+        }
+    }
+
+    /// Generate a trading session summary. Uses the Portfolio's statistics per [`Market`] in
+    /// combination with the average statistics across all [`Market`]s traded.
+    fn generate_session_summary(mut self) -> Table {
+        // Fetch statistics for each Market
+        let stats_per_market = self.trader_command_txs.into_keys().filter_map(|market| {
+            let market_id = MarketId::from(&market);
+
+            match self.portfolio.lock().get_statistics(&market_id) {
+                Ok(statistics) => Some((market_id.0, statistics)),
+                Err(error) => {
+                    error!(
+                        ?error,
+                        ?market,
+                        "failed to get Market statistics when generating trading session summary"
+                    );
+                    None
+                }
+            }
+        });
+
+        // Generate average statistics across all markets using session's exited Positions
+        self.portfolio
+            .lock()
+            .get_exited_positions(self.engine_id)
+            .map(|exited_positions| {
+                self.statistics_summary.generate_summary(&exited_positions);
+            })
+            .unwrap_or_else(|error| {
+                warn!(
+                    ?error,
+                    why = "failed to get exited Positions from Portfolio's repository",
+                    "failed to generate Statistics summary for trading session"
+                );
+            });
+
+        // Combine Total & Per-Market Statistics Into Table
+        crate::statistic::summary::combine(
+            stats_per_market.chain([("Total".to_owned(), self.statistics_summary)]),
+        )
+    }
+}
+
+/// Validates that the [`Engine`] has at least one [`Trader`], that every [`Trader`] barters on a
+/// unique [`Market`], and that every [`Market`] has exactly one associated `trader_command_tx`.
+fn validate_trader_markets<EventTx, Statistic, Portfolio, Data, Strategy, Execution>(
+    traders: &[Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>],
+    trader_command_txs: &HashMap<Market, mpsc::Sender<Command>>,
+) -> Result<(), EngineError>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + PositionHandler + BalanceHandler,
+    Data: MarketGenerator<MarketEvent<DataKind>> + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    if traders.is_empty() {
+        return Err(EngineError::InvalidMarkets("no Traders provided"));
+    }
+
+    let mut markets = HashSet::with_capacity(traders.len());
+    if !traders.iter().all(|trader| markets.insert(trader.market())) {
+        return Err(EngineError::InvalidMarkets(
+            "multiple Traders provided for the same Market",
+        ));
+    }
+
+    if markets.len() != trader_command_txs.len()
+        || !markets
+            .iter()
+            .all(|market| trader_command_txs.contains_key(*market))
+    {
+        return Err(EngineError::InvalidMarkets(
+            "trader_command_txs Markets do not match the Trader Markets",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Seeds the Portfolio with the provided [`PortfolioState`], validating that every open
+/// [`Position`] is for a [`Market`] traded by one of the [`Engine`]'s [`Trader`]s. Open
+/// [`Position`]s are re-keyed with the [`PositionId`](crate::portfolio::position::PositionId) of
+/// the provided engine_id, since the [`PortfolioState`] may have been persisted by a previous
+/// [`Engine`].
+fn seed_portfolio<Portfolio>(
+    engine_id: Uuid,
+    portfolio: &Mutex<Portfolio>,
+    state: PortfolioState,
+    trader_command_txs: &HashMap<Market, mpsc::Sender<Command>>,
+) -> Result<(), EngineError>
+where
+    Portfolio: PositionHandler + BalanceHandler,
+{
+    let all_traded = state.open_positions.iter().all(|position| {
+        trader_command_txs.contains_key(&Market::new(
+            position.exchange.clone(),
+            position.instrument.clone(),
+        ))
+    });
+    if !all_traded {
+        return Err(EngineError::InvalidMarkets(
+            "initial portfolio contains a Position for a Market without an associated Trader",
+        ));
+    }
+
+    let mut portfolio = portfolio.lock();
+    portfolio.set_balance(engine_id, state.balance)?;
+
+    for mut position in state.open_positions {
+        position.position_id =
+            determine_position_id(engine_id, &position.exchange, &position.instrument);
+        portfolio.set_open_position(position)?;
+    }
+
+    Ok(())
+}
+
+impl<EventTx, Statistic, Portfolio, Strategy, Execution>
+    EngineBuilder<
+        EventTx,
+        Statistic,
+        Portfolio,
+        live::MarketFeed<MarketEvent<DataKind>>,
+        Strategy,
+        Execution,
+    >
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: PositionSummariser + Serialize + Send,
+    Portfolio: PositionHandler
+        + BalanceHandler
+        + StatisticHandler<Statistic>
+        + MarketUpdater
+        + OrderGenerator
+        + FillUpdater
+        + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    /// Initialises the `Barter-Data` market event streams of the provided
+    /// [`MarketSubscriptions`], and subscribes each [`Trader`] to the live
+    /// [`MarketFeed`](live::MarketFeed) of it's [`Market`]. Each [`Trader`] may therefore be
+    /// built with a placeholder [`MarketFeed::default`](live::MarketFeed::default).
+    ///
+    /// Must be called after [`EngineBuilder::traders`]. Returns an
+    /// [`EngineError::InvalidMarkets`] if a subscription references a [`Market`] without an
+    /// associated [`Trader`].
+    pub async fn with_market_streams(
+        mut self,
+        subscriptions: MarketSubscriptions,
+    ) -> Result<Self, EngineError> {
+        let traders = self
+            .traders
+            .as_mut()
+            .ok_or(EngineError::BuilderIncomplete("traders"))?;
+
+        if subscriptions
+            .markets()
+            .any(|market| !traders.iter().any(|trader| trader.market() == market))
+        {
+            return Err(EngineError::InvalidMarkets(
+                "market stream subscription references a Market without an associated Trader",
+            ));
+        }
+
+        let mut feeds = subscriptions.init().await.map_err(Box::new)?;
+        for trader in traders.iter_mut() {
+            if let Some(feed) = feeds.remove(trader.market()) {
+                trader.set_data(feed);
+            }
+        }
+
+        Ok(self)
+    }
+}
+
+/// Restores each [`Trader`] from it's [`TraderCheckpoint`]. [`Trader`]s that shared an
+/// [`OrderIdGenerator`] session before the restart share one resumed
+/// [`MonotonicOrderIdGenerator`], so their [`ClientOrderId`]s never collide.
+fn restore_traders<EventTx, Statistic, Portfolio, Data, Strategy, Execution>(
+    traders: &mut [Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>],
+    checkpoints: Vec<TraderCheckpoint>,
+) -> Result<(), EngineError>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + PositionHandler + BalanceHandler,
+    Data: MarketGenerator<MarketEvent<DataKind>> + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    // Resume each OrderIdGenerator session after the latest ClientOrderId it generated
+    let mut seeds = HashMap::<Uuid, ClientOrderId>::new();
+    for seed in checkpoints
+        .iter()
+        .filter_map(|checkpoint| checkpoint.order_id_seed)
+    {
+        let latest = seeds.entry(seed.session).or_insert(seed);
+        *latest = (*latest).max(seed);
+    }
+    let order_id_generators = seeds
+        .into_iter()
+        .map(|(session, seed)| {
+            let generator: Arc<dyn OrderIdGenerator + Send + Sync> =
+                Arc::new(MonotonicOrderIdGenerator::resume_after(seed));
+            (session, generator)
+        })
+        .collect::<HashMap<_, _>>();
+
+    for checkpoint in checkpoints {
+        let trader = traders
+            .iter_mut()
+            .find(|trader| trader.market() == &checkpoint.market)
+            .ok_or(EngineError::InvalidMarkets(
+                "checkpoint contains a Trader for a Market without an associated Trader",
+            ))?;
+
+        if let Some(seed) = checkpoint.order_id_seed {
+            trader.set_order_id_generator(Arc::clone(&order_id_generators[&seed.session]));
+        }
+        trader.restore(checkpoint)?;
+    }
+
+    Ok(())
+}
+
+/// Completes on the next tick of the optional checkpoint `Interval`, or never if `None`.
+async fn next_checkpoint(interval: &mut Option<tokio::time::Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Completes on the next [`Checkpoint`] request of a [`Trader`], or never if `None` or every
+/// [`Trader`] has dropped it's `mpsc::Sender`.
+async fn next_checkpoint_request(request_rx: &mut Option<mpsc::Receiver<()>>) {
+    match request_rx {
+        Some(request_rx) => {
+            if request_rx.recv().await.is_none() {
+                std::future::pending::<()>().await;
+            }
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Completes when the process receives a SIGINT (ctrl-c), or a SIGTERM on unix platforms. Never
+/// completes if the signal handlers cannot be installed.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(error) = tokio::signal::ctrl_c().await {
+            error!(?error, "failed to install SIGINT handler");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(error) => {
+                error!(?error, "failed to install SIGTERM handler");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
+/// [`Clock`] the [`EngineBuilder`] runs every [`Trader`] of an [`Engine`] against.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum TraderClock {
+    /// Every [`Trader`] tells the wall-clock time via a [`LiveClock`].
+    Live,
+    /// Every [`Trader`] runs against it's own unstarted [`SimulatedClock`], starting at (and
+    /// advanced by) the exchange time of the [`MarketEvent`]s it consumes.
+    Simulated,
+}
+
+impl TraderClock {
+    fn clock(&self) -> Arc<dyn Clock + Send + Sync> {
+        match self {
+            Self::Live => Arc::new(LiveClock),
+            Self::Simulated => Arc::new(SimulatedClock::new(DateTime::<Utc>::MIN_UTC)),
+        }
+    }
+}
+
+/// Builder to construct [`Engine`] instances.
+#[derive(Debug, Default)]
+pub struct EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + Send,
+    Data: MarketGenerator<MarketEvent<DataKind>> + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    engine_id: Option<Uuid>,
+    command_rx: Option<mpsc::Receiver<Command>>,
+    portfolio: Option<Arc<Mutex<Portfolio>>>,
+    traders: Option<Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>>,
+    trader_command_txs: Option<HashMap<Market, mpsc::Sender<Command>>>,
+    statistics_summary: Option<Statistic>,
+    order_id_generator: Option<Arc<dyn OrderIdGenerator + Send + Sync>>,
+    rate_limiter: Option<RateLimiter>,
+    stale_data_timeout: Option<Duration>,
+    clock: Option<TraderClock>,
+    equity_recorder: Option<EquityRecorder>,
+    transition_log: Option<TransitionLog>,
+    determinism_digest: bool,
+    account: Option<AccountId>,
+    market_accounts: HashMap<Market, AccountId>,
+    initial_portfolio: Option<PortfolioState>,
+    checkpoint: Option<CheckpointConfig>,
+    restore_from: Option<Checkpoint<Statistic>>,
+    opening_balance_timeout: Option<Duration>,
+    panic_policy: Option<PanicPolicy>,
+    shutdown_grace_period: Option<Duration>,
+}
+
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+    EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: PositionSummariser + Serialize + Send,
+    Portfolio: PositionHandler
+        + BalanceHandler
+        + StatisticHandler<Statistic>
+        + MarketUpdater
+        + OrderGenerator
+        + FillUpdater
+        + Send,
+    Data: MarketGenerator<MarketEvent<DataKind>> + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    fn new() -> Self {
+        Self {
+            engine_id: None,
+            command_rx: None,
+            portfolio: None,
+            traders: None,
+            trader_command_txs: None,
+            statistics_summary: None,
+            order_id_generator: None,
+            rate_limiter: None,
+            stale_data_timeout: None,
+            clock: None,
+            equity_recorder: None,
+            transition_log: None,
+            determinism_digest: false,
+            account: None,
+            market_accounts: HashMap::new(),
+            initial_portfolio: None,
+            checkpoint: None,
+            restore_from: None,
+            opening_balance_timeout: None,
+            panic_policy: None,
+            shutdown_grace_period: None,
+        }
+    }
+
+    /// Constructs an [`EngineBuilder`] preset with sensible defaults for live trading, leaving
+    /// only the [`Trader`]s (ie/ the Strategy & market data feed of each [`Market`]) and the
+    /// required Engine attributes to be provided:
+    /// - [`TraderClock::Live`] wall-clock time.
+    /// - A shared [`RateLimiter`] of 10 orders per second, with bursts of up to 10 orders.
+    /// - A 30 second stale data timeout dead-man's switch, flattening & locking a [`Trader`]
+    ///   whose market data feed goes silent.
+    ///
+    /// Every preset may be overridden by a subsequent builder call.
+    pub fn live_defaults() -> Self {
+        Self::new()
+            .clock(TraderClock::Live)
+            .rate_limiter(RateLimiter::new(10.0, 10))
+            .stale_data_timeout(Duration::from_secs(30))
+    }
+
+    /// Constructs an [`EngineBuilder`] preset with sensible defaults for backtesting, leaving
+    /// only the [`Trader`]s (ie/ the Strategy & replayed market data of each [`Market`]) and the
+    /// required Engine attributes to be provided:
+    /// - [`TraderClock::Simulated`] time, advanced by the replayed [`MarketEvent`]s.
+    /// - No [`RateLimiter`] or stale data timeout, since neither is meaningful for replays.
+    ///
+    /// Every preset may be overridden by a subsequent builder call. See
+    /// [`Backtest`](crate::backtest::Backtest) for a one-call backtest with replayed data,
+    /// simulated fills & a fee model.
+    pub fn backtest_defaults() -> Self {
+        Self::new().clock(TraderClock::Simulated)
+    }
+
+    pub fn engine_id(self, value: Uuid) -> Self {
+        Self {
+            engine_id: Some(value),
+            ..self
+        }
+    }
+
+    pub fn command_rx(self, value: mpsc::Receiver<Command>) -> Self {
+        Self {
+            command_rx: Some(value),
+            ..self
+        }
+    }
+
+    pub fn portfolio(self, value: Arc<Mutex<Portfolio>>) -> Self {
+        Self {
+            portfolio: Some(value),
+            ..self
+        }
+    }
+
+    pub fn traders(
+        self,
+        value: Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>,
+    ) -> Self {
+        Self {
+            traders: Some(value),
+            ..self
+        }
+    }
+
+    pub fn trader_command_txs(self, value: HashMap<Market, mpsc::Sender<Command>>) -> Self {
+        Self {
+            trader_command_txs: Some(value),
+            ..self
+        }
+    }
+
+    pub fn statistics_summary(self, value: Statistic) -> Self {
+        Self {
+            statistics_summary: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`OrderIdGenerator`] shared by every [`Trader`] to assign unique client order
+    /// ids, for both algorithmic & manual orders. Provide a generator resumed from a persisted
+    /// seed (eg/ [`MonotonicOrderIdGenerator::resume_after`]) for ids that are unique across
+    /// restarts. Defaults to each [`Trader`] using it's own [`MonotonicOrderIdGenerator`].
+    ///
+    /// [`MonotonicOrderIdGenerator`]: crate::execution::order_id::MonotonicOrderIdGenerator
+    /// [`MonotonicOrderIdGenerator::resume_after`]: crate::execution::order_id::MonotonicOrderIdGenerator::resume_after
+    pub fn order_id_generator(self, value: Arc<dyn OrderIdGenerator + Send + Sync>) -> Self {
+        Self {
+            order_id_generator: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`RateLimiter`] shared by every [`Trader`], capping the combined rate the
+    /// [`Trader`]s send [`OrderEvent`](crate::portfolio::OrderEvent)s for execution. Replaces
+    /// any [`RateLimiter`] a [`Trader`] was built with.
+    pub fn rate_limiter(self, value: RateLimiter) -> Self {
+        Self {
+            rate_limiter: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional stale data timeout of every [`Trader`]: the interval of wall-clock time without
+    /// a [`MarketEvent`] after which it's market data is deemed stale & it's kill switch is
+    /// armed. Replaces any timeout a [`Trader`] was built with.
+    pub fn stale_data_timeout(self, value: Duration) -> Self {
+        Self {
+            stale_data_timeout: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`TraderClock`] every [`Trader`] is run against. Replaces the [`Clock`] a
+    /// [`Trader`] was built with.
+    pub fn clock(self, value: TraderClock) -> Self {
+        Self {
+            clock: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`EquityRecorder`] shared by every [`Trader`], recording one equity curve of the
+    /// whole Portfolio every time a [`Trader`] revalues it. Replaces any [`EquityRecorder`] a
+    /// [`Trader`] was built with.
+    pub fn equity_recorder(self, value: EquityRecorder) -> Self {
+        Self {
+            equity_recorder: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`TransitionLog`] every [`Trader`] records it's
+    /// [`TraderState`](transition::TraderState) transitions to, included in it's
+    /// [`SessionSummary`]. Each [`Trader`] records to it's own copy of the [`TransitionLog`],
+    /// replacing any [`TransitionLog`] it was built with.
+    pub fn transition_log(self, value: TransitionLog) -> Self {
+        Self {
+            transition_log: Some(value),
+            ..self
+        }
+    }
+
+    /// Every [`Trader`] hashes it's transition, order & fill sequence into a
+    /// [`DeterminismDigest`], included in it's [`SessionSummary`].
+    /// Two runs over the same recorded feed produce identical digests.
+    pub fn determinism_digest(self) -> Self {
+        Self {
+            determinism_digest: true,
+            ..self
+        }
+    }
+
+    /// Optional default [`AccountId`] of the exchange account (or sub-account) the
+    /// [`OrderEvent`](crate::portfolio::OrderEvent)s of every [`Trader`] are executed on, unless
+    /// overridden for it's [`Market`] via [`EngineBuilder::market_account`]. Replaces the
+    /// [`AccountId`] a [`Trader`] was built with.
+    pub fn account(self, value: AccountId) -> Self {
+        Self {
+            account: Some(value),
+            ..self
+        }
+    }
+
+    /// Overrides the [`AccountId`] the [`Trader`] (and so Strategy) of the provided [`Market`]
+    /// executes it's [`OrderEvent`](crate::portfolio::OrderEvent)s on, eg/ to trade each Strategy
+    /// on it's own sub-account.
+    pub fn market_account(mut self, market: Market, account: AccountId) -> Self {
+        self.market_accounts.insert(market, account);
+        self
+    }
+
+    /// Optional [`PortfolioState`] (eg/ persisted before a restart) to seed the Portfolio with,
+    /// replacing the fresh [`Balance`](crate::portfolio::Balance) it was initialised with. Every
+    /// open [`Position`] must be for a [`Market`] traded by one of the [`Trader`]s.
+    pub fn initial_portfolio(self, value: PortfolioState) -> Self {
+        Self {
+            initial_portfolio: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`CheckpointConfig`] used to periodically save a [`Checkpoint`] of the running
+    /// [`Engine`], eg/ for crash recovery.
+    pub fn checkpoint(self, value: CheckpointConfig) -> Self {
+        Self {
+            checkpoint: Some(value),
+            ..self
+        }
+    }
+
+    /// Seeds the Portfolio [`Balance`] from the exchange accounts at the start of
+    /// [`Engine::run`], rather than the starting cash it was initialised with. The opening
+    /// [`Balance`] of every traded exchange is fetched via [`ExecutionClient::fetch_balance`]
+    /// & summed, with any exchange that fails to respond within the timeout provided assumed to
+    /// hold a zero [`Balance`]. Replaces the [`Balance`] of any
+    /// [`EngineBuilder::initial_portfolio`] or [`Checkpoint`].
+    pub fn fetch_opening_balance(self, timeout: Duration) -> Self {
+        Self {
+            opening_balance_timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Optional [`PanicPolicy`] of every [`Trader`], determining how it handles a panic of the
+    /// Strategy or the Portfolio risk manager. Replaces the [`PanicPolicy`] a [`Trader`] was
+    /// built with.
+    pub fn panic_policy(self, value: PanicPolicy) -> Self {
+        Self {
+            panic_policy: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional time the [`Trader`]s are given to exit their Positions once terminated, & again
+    /// to stop, before the [`Engine`] shutdown is forced with an [`EngineError::ShutdownForced`].
+    /// Defaults to [`DEFAULT_SHUTDOWN_GRACE_PERIOD`].
+    pub fn shutdown_grace_period(self, value: Duration) -> Self {
+        Self {
+            shutdown_grace_period: Some(value),
+            ..self
+        }
+    }
+
+    /// Resumes from the [`Checkpoint`] saved at the file path provided: the Portfolio is seeded
+    /// (see [`EngineBuilder::initial_portfolio`]) & has its statistics restored, and each
+    /// [`Trader`] has its open orders, order id seed & Strategy state restored, so rolling
+    /// indicators resume without a fresh warm-up.
+    pub fn from_checkpoint<P>(self, path: P) -> Result<Self, EngineError>
+    where
+        P: AsRef<Path>,
+        Statistic: DeserializeOwned,
+    {
+        let checkpoint = Checkpoint::load(path)?;
+        Ok(Self {
+            initial_portfolio: Some(checkpoint.portfolio.clone()),
+            restore_from: Some(checkpoint),
+            ..self
+        })
+    }
+
+    pub fn build(
+        self,
+    ) -> Result<Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
+        let mut traders = self
+            .traders
+            .ok_or(EngineError::BuilderIncomplete("traders"))?;
+        let trader_command_txs = self
+            .trader_command_txs
+            .ok_or(EngineError::BuilderIncomplete("trader_command_txs"))?;
+        validate_trader_markets(&traders, &trader_command_txs)?;
+
+        if let Some(order_id_generator) = self.order_id_generator {
+            for trader in traders.iter_mut() {
+                trader.set_order_id_generator(Arc::clone(&order_id_generator));
+            }
+        }
+
+        if let Some(rate_limiter) = self.rate_limiter {
+            for trader in traders.iter_mut() {
+                trader.set_rate_limiter(rate_limiter.clone());
+            }
+        }
+
+        if let Some(stale_data_timeout) = self.stale_data_timeout {
+            for trader in traders.iter_mut() {
+                trader.set_stale_data_timeout(stale_data_timeout);
+            }
+        }
+
+        if let Some(clock) = self.clock {
+            for trader in traders.iter_mut() {
+                trader.set_clock(clock.clock());
+            }
+        }
+
+        if let Some(equity_recorder) = self.equity_recorder {
+            let markets = trader_command_txs.keys().cloned().collect::<Vec<_>>();
+            for trader in traders.iter_mut() {
+                trader.set_equity_recorder(equity_recorder.clone(), markets.clone());
+            }
+        }
+
+        for trader in traders.iter_mut() {
+            let account = self
+                .market_accounts
+                .get(trader.market())
+                .or(self.account.as_ref());
+            if let Some(account) = account {
+                trader.set_account(account.clone());
+            }
+        }
+
+        if let Some(transition_log) = self.transition_log {
+            for trader in traders.iter_mut() {
+                trader.set_transition_log(transition_log.clone());
+            }
+        }
+
+        if self.determinism_digest {
+            for trader in traders.iter_mut() {
+                trader.set_determinism_digest(DeterminismDigest::new());
+            }
+        }
+
+        if let Some(panic_policy) = self.panic_policy {
+            for trader in traders.iter_mut() {
+                trader.set_panic_policy(panic_policy);
+            }
+        }
+
+        // Traders request a Checkpoint every configured number of Events, coalesced whilst one
+        // is pending
+        let every_events = self
+            .checkpoint
+            .as_ref()
+            .and_then(|config| config.every_events);
+        let checkpoint_request_rx = every_events.map(|every_events| {
+            let (request_tx, request_rx) = mpsc::channel(1);
+            for trader in traders.iter_mut() {
+                trader.set_checkpoint_requester(CheckpointRequester::new(
+                    every_events,
+                    request_tx.clone(),
+                ));
+            }
+            request_rx
+        });
+
+        let engine_id = self
+            .engine_id
+            .ok_or(EngineError::BuilderIncomplete("engine_id"))?;
+        let portfolio = self
+            .portfolio
+            .ok_or(EngineError::BuilderIncomplete("portfolio"))?;
+
+        if let Some(initial_portfolio) = self.initial_portfolio {
+            seed_portfolio(
+                engine_id,
+                &portfolio,
+                initial_portfolio,
+                &trader_command_txs,
+            )?;
+        }
+
+        if let Some(checkpoint) = self.restore_from {
+            let mut repository = portfolio.lock();
+            for (market_id, statistic) in checkpoint.statistics {
+                repository.set_statistics(market_id, statistic)?;
+            }
+            drop(repository);
+
+            restore_traders(&mut traders, checkpoint.traders)?;
+        }
+
+        Ok(Engine {
+            engine_id,
+            command_rx: self
+                .command_rx
+                .ok_or(EngineError::BuilderIncomplete("command_rx"))?,
+            portfolio,
+            trader_clocks: traders.iter().map(Trader::clock).collect(),
+            traders,
+            trader_command_txs,
+            statistics_summary: self
+                .statistics_summary
+                .ok_or(EngineError::BuilderIncomplete("statistics_summary"))?,
+            checkpoint: self.checkpoint,
+            checkpoint_request_rx,
+            opening_balance_timeout: self.opening_balance_timeout,
+            shutdown_grace_period: self
+                .shutdown_grace_period
+                .unwrap_or(DEFAULT_SHUTDOWN_GRACE_PERIOD),
+            stepping: Stepping::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::historical,
+        engine::trader::QueueReason,
+        event::EventTx,
+        execution::{
+            error::ExecutionError,
+            rate_limit::RateLimiter,
+            simulated::{Config as ExecutionConfig, SimulatedExecution},
+            FillEvent,
+        },
+        portfolio::{
+            allocator::DefaultAllocator, portfolio::MetaPortfolio,
+            repository::in_memory::InMemoryRepository, risk::DefaultRisk, Balance, OrderEvent,
+            OrderTags, OrderType, TimeInForce,
+        },
+        statistic::summary::{
+            trading::{Config as StatisticConfig, TradingSummary},
+            Initialiser,
+        },
+        strategy::example::{Config as StrategyConfig, RSIStrategy},
+        test_util::{market_event_trade, order_event, position},
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::{TimeZone, Utc};
+    use futures::StreamExt;
+    use rust_decimal::Decimal;
+
+    type TestPortfolio = MetaPortfolio<
+        InMemoryRepository<TradingSummary>,
+        DefaultAllocator,
+        DefaultRisk,
+        TradingSummary,
+    >;
+
+    type TestData =
+        historical::MarketFeed<std::vec::IntoIter<MarketEvent<DataKind>>, MarketEvent<DataKind>>;
+
+    type TraderOf<Execution> =
+        Trader<EventTx, TradingSummary, TestPortfolio, TestData, RSIStrategy, Execution>;
+
+    type TestTrader = TraderOf<SimulatedExecution>;
+
+    type TestEngine =
+        Engine<EventTx, TradingSummary, TestPortfolio, TestData, RSIStrategy, SimulatedExecution>;
+
+    type TestEngineBuilder = EngineBuilder<
+        EventTx,
+        TradingSummary,
+        TestPortfolio,
+        TestData,
+        RSIStrategy,
+        SimulatedExecution,
+    >;
+
+    fn market(base: &str) -> Market {
+        Market::new("binance", (base, "usdt", InstrumentKind::Spot))
+    }
+
+    fn portfolio(engine_id: Uuid, markets: &[Market]) -> Arc<Mutex<TestPortfolio>> {
+        Arc::new(Mutex::new(
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(markets.to_vec())
+                .starting_cash(Decimal::from(10_000))
+                .repository(InMemoryRepository::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: Decimal::ONE_HUNDRED,
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(StatisticConfig {
+                    starting_equity: 10_000.0,
+                    trading_days_per_year: 365,
+                    risk_free_return: 0.0,
+                })
+                .build_and_init()
+                .unwrap(),
+        ))
+    }
+
+    fn traders(markets: &[Market]) -> (Vec<TestTrader>, HashMap<Market, mpsc::Sender<Command>>) {
+        let engine_id = Uuid::new_v4();
+        let (event_tx, _) = mpsc::unbounded_channel();
+        let portfolio = portfolio(engine_id, markets);
+
+        let mut trader_command_txs = HashMap::new();
+        let traders = markets
+            .iter()
+            .map(|market| {
+                let (command_tx, command_rx) = mpsc::channel(10);
+                trader_command_txs.insert(market.clone(), command_tx);
+
+                Trader::builder()
+                    .engine_id(engine_id)
+                    .market(market.clone())
+                    .command_rx(command_rx)
+                    .event_tx(EventTx::new(event_tx.clone()))
+                    .portfolio(Arc::clone(&portfolio))
+                    .data(historical::MarketFeed::new(Vec::new()))
+                    .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+                    .execution(SimulatedExecution::new(ExecutionConfig::default()))
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+
+        (traders, trader_command_txs)
+    }
+
+    /// Builds an [`Engine`] with a [`Trader`] for each [`Market`] provided, returning the
+    /// receiving end of each trader_command_tx so the [`Command`]s routed can be inspected.
+    fn engine(markets: &[Market]) -> (TestEngine, HashMap<Market, mpsc::Receiver<Command>>) {
+        let (builder, trader_command_rxs) = engine_builder(markets);
+        (builder.build().unwrap(), trader_command_rxs)
+    }
+
+    /// Constructs an [`EngineBuilder`] with every attribute required to build an [`Engine`]
+    /// with a [`Trader`] for each [`Market`] provided. See [`engine`].
+    fn engine_builder(
+        markets: &[Market],
+    ) -> (TestEngineBuilder, HashMap<Market, mpsc::Receiver<Command>>) {
+        engine_builder_from(Engine::builder(), markets)
+    }
+
+    /// Supplies the provided (eg/ preset) [`EngineBuilder`] with every attribute required to
+    /// build an [`Engine`] with a [`Trader`] for each [`Market`] provided.
+    fn engine_builder_from(
+        builder: TestEngineBuilder,
+        markets: &[Market],
+    ) -> (TestEngineBuilder, HashMap<Market, mpsc::Receiver<Command>>) {
+        let engine_id = Uuid::new_v4();
+        let (traders, _) = traders(markets);
+
+        let (trader_command_txs, trader_command_rxs) = markets
+            .iter()
+            .map(|market| {
+                let (command_tx, command_rx) = mpsc::channel(10);
+                ((market.clone(), command_tx), (market.clone(), command_rx))
+            })
+            .unzip();
+
+        let builder = builder
+            .engine_id(engine_id)
+            .command_rx(mpsc::channel(10).1)
+            .portfolio(portfolio(engine_id, markets))
+            .traders(traders)
+            .trader_command_txs(trader_command_txs)
+            .statistics_summary(TradingSummary::init(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            }));
+
+        (builder, trader_command_rxs)
+    }
+
+    #[test]
+    fn engine_builder_live_defaults_should_build_with_only_traders_supplied() {
+        let (builder, _) = engine_builder_from(EngineBuilder::live_defaults(), &[market("btc")]);
+        assert_eq!(builder.clock, Some(TraderClock::Live));
+        assert!(builder.rate_limiter.is_some());
+        assert_eq!(builder.stale_data_timeout, Some(Duration::from_secs(30)));
+
+        // Presets are overridden by subsequent builder calls
+        let builder = builder.stale_data_timeout(Duration::from_secs(5));
+        assert_eq!(builder.stale_data_timeout, Some(Duration::from_secs(5)));
+
+        assert!(builder.build().is_ok());
+    }
+
+    #[tokio::test]
+    async fn engine_builder_backtest_defaults_should_build_with_only_traders_supplied() {
+        let (builder, _) =
+            engine_builder_from(EngineBuilder::backtest_defaults(), &[market("btc")]);
+        assert_eq!(builder.clock, Some(TraderClock::Simulated));
+        assert!(builder.rate_limiter.is_none());
+        assert!(builder.stale_data_timeout.is_none());
+        assert!(builder.build().is_ok());
+
+        // Replay MarketEvents from a day in the past
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let market_events = (0..3)
+            .map(|hour| MarketEvent {
+                exchange_time: day + chrono::Duration::hours(hour),
+                exchange: market("btc").exchange,
+                instrument: market("btc").instrument,
+                ..market_event_trade(Side::Buy)
+            })
+            .collect::<Vec<_>>();
+        let engine_id = Uuid::new_v4();
+        let (mut traders, trader_command_txs) = traders(&[market("btc")]);
+        traders[0].set_data(historical::MarketFeed::new(market_events));
+        let engine = EngineBuilder::backtest_defaults()
+            .engine_id(engine_id)
+            .command_rx(mpsc::channel(10).1)
+            .portfolio(portfolio(engine_id, &[market("btc")]))
+            .traders(traders)
+            .trader_command_txs(trader_command_txs.clone())
+            .statistics_summary(TradingSummary::init(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            }))
+            .build()
+            .unwrap();
+
+        // The simulated Clock of the Trader tells the time of the replayed MarketEvents
+        let sessions = engine.run().await.unwrap();
+        assert_eq!(sessions[0].started_at, day);
+        assert_eq!(sessions[0].ended_at, day + chrono::Duration::hours(2));
+    }
+
+    #[test]
+    fn command_should_round_trip_through_tagged_json() {
+        let manual_order = ManualOrderRequest {
+            exchange: market("btc").exchange,
+            instrument: market("btc").instrument,
+            side: Side::Buy,
+            quantity: Decimal::ONE,
+            notional: None,
+            limit_price: Some(Decimal::ONE_HUNDRED),
+            stop_price: None,
+            time_in_force: TimeInForce::default(),
+            trigger: None,
+            tags: OrderTags::default(),
+        };
+
+        let commands = vec![
+            Command::Terminate("shutdown".to_owned()),
+            Command::ExitAllPositions,
+            Command::ExitPosition(market("btc")),
+            Command::ManualOrder(manual_order.clone()),
+            Command::CancelOrder {
+                id: ClientOrderId::default(),
+            },
+            Command::CancelAllOrders {
+                instrument: Some(market("btc").instrument),
+            },
+            Command::DropPendingOrder {
+                id: ClientOrderId::default(),
+            },
+            Command::Pause,
+            Command::Resume,
+            Command::KillSwitch,
+            Command::Reconcile,
+            Command::Rebalance {
+                targets: HashMap::from([
+                    (market("btc").instrument, 0.5),
+                    (market("eth").instrument, 0.25),
+                ]),
+            },
+            Command::UpdateStrategyParams {
+                market: market("btc"),
+                params: serde_json::json!({ "oversold": 30.0 }),
+            },
+        ];
+
+        for command in commands {
+            let json = serde_json::to_string(&command).unwrap();
+            let actual = serde_json::from_str::<Command>(&json).unwrap();
+
+            match (command, actual) {
+                (Command::Terminate(expected), Command::Terminate(actual)) => {
+                    assert_eq!(actual, expected)
+                }
+                (Command::ExitAllPositions, Command::ExitAllPositions) => {}
+                (Command::Pause, Command::Pause) => {}
+                (Command::Resume, Command::Resume) => {}
+                (Command::KillSwitch, Command::KillSwitch) => {}
+                (Command::Reconcile, Command::Reconcile) => {}
+                (
+                    Command::Rebalance { targets: expected },
+                    Command::Rebalance { targets: actual },
+                ) => {
+                    assert_eq!(actual, expected)
+                }
+                (Command::ExitPosition(expected), Command::ExitPosition(actual)) => {
+                    assert_eq!(actual, expected)
+                }
+                (
+                    Command::UpdateStrategyParams {
+                        market: expected_market,
+                        params: expected_params,
+                    },
+                    Command::UpdateStrategyParams { market, params },
+                ) => {
+                    assert_eq!(market, expected_market);
+                    assert_eq!(params, expected_params);
+                }
+                (Command::ManualOrder(expected), Command::ManualOrder(actual)) => {
+                    assert_eq!(actual, expected)
+                }
+                (Command::CancelOrder { id: expected }, Command::CancelOrder { id: actual }) => {
+                    assert_eq!(actual, expected)
+                }
+                (
+                    Command::DropPendingOrder { id: expected },
+                    Command::DropPendingOrder { id: actual },
+                ) => {
+                    assert_eq!(actual, expected)
+                }
+                (
+                    Command::CancelAllOrders {
+                        instrument: expected,
+                    },
+                    Command::CancelAllOrders { instrument: actual },
+                ) => {
+                    assert_eq!(actual, expected)
+                }
+                (expected, actual) => panic!("expected: {expected:?}, actual: {actual:?}"),
+            }
+        }
     }
-}
 
-/// Builder to construct [`Engine`] instances.
-#[derive(Debug, Default)]
-pub struct EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
-where
-    EventTx: MessageTransmitter<Event>,
-    Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + Send,
-    Data: MarketGenerator<MarketEvent<DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
-    Execution: ExecutionClient + Send,
-{
-    engine_id: Option<Uuid>,
-    command_rx: Option<mpsc::Receiver<Command>>,
-    portfolio: Option<Arc<Mutex<Portfolio>>>,
-    traders: Option<Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>>,
-    trader_command_txs: Option<HashMap<Market, mpsc::Sender<Command>>>,
-    statistics_summary: Option<Statistic>,
-}
+    #[test]
+    fn command_should_serialise_with_type_tag() {
+        let actual = serde_json::to_value(Command::ExitAllPositions).unwrap();
+        assert_eq!(actual, serde_json::json!({ "type": "ExitAllPositions" }));
 
-impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
-    EngineBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
-where
-    EventTx: MessageTransmitter<Event>,
-    Statistic: PositionSummariser + Serialize + Send,
-    Portfolio: PositionHandler
-        + StatisticHandler<Statistic>
-        + MarketUpdater
-        + OrderGenerator
-        + FillUpdater
-        + Send,
-    Data: MarketGenerator<MarketEvent<DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
-    Execution: ExecutionClient + Send,
-{
-    fn new() -> Self {
-        Self {
-            engine_id: None,
-            command_rx: None,
-            portfolio: None,
-            traders: None,
-            trader_command_txs: None,
-            statistics_summary: None,
+        let actual = serde_json::to_value(Command::Terminate("shutdown".to_owned())).unwrap();
+        assert_eq!(
+            actual,
+            serde_json::json!({ "type": "Terminate", "content": "shutdown" })
+        );
+    }
+
+    #[test]
+    fn command_should_fail_to_deserialise_unknown_or_unserialisable_type() {
+        let unknown = r#"{"type":"Unknown","content":"shutdown"}"#;
+        assert!(serde_json::from_str::<Command>(unknown).is_err());
+
+        let fetch_open_positions = r#"{"type":"FetchOpenPositions"}"#;
+        assert!(serde_json::from_str::<Command>(fetch_open_positions).is_err());
+
+        let (positions_tx, _positions_rx) = oneshot::channel();
+        assert!(serde_json::to_string(&Command::FetchOpenPositions(positions_tx)).is_err());
+    }
+
+    #[test]
+    fn validate_trader_markets_with_distinct_markets_is_ok() {
+        let (traders, trader_command_txs) = traders(&[market("btc"), market("eth")]);
+
+        assert!(validate_trader_markets(&traders, &trader_command_txs).is_ok());
+    }
+
+    #[test]
+    fn validate_trader_markets_with_no_traders_is_err() {
+        let (traders, trader_command_txs) = traders(&[]);
+
+        assert!(matches!(
+            validate_trader_markets(&traders, &trader_command_txs),
+            Err(EngineError::InvalidMarkets(_))
+        ));
+    }
+
+    #[test]
+    fn validate_trader_markets_with_duplicate_markets_is_err() {
+        let (traders, trader_command_txs) = traders(&[market("btc"), market("btc")]);
+
+        assert!(matches!(
+            validate_trader_markets(&traders, &trader_command_txs),
+            Err(EngineError::InvalidMarkets(_))
+        ));
+    }
+
+    #[test]
+    fn validate_trader_markets_with_mismatched_trader_command_txs_is_err() {
+        // Trader Market missing a trader_command_tx
+        let (traders, mut trader_command_txs) = traders(&[market("btc"), market("eth")]);
+        trader_command_txs.remove(&market("eth"));
+
+        assert!(matches!(
+            validate_trader_markets(&traders, &trader_command_txs),
+            Err(EngineError::InvalidMarkets(_))
+        ));
+
+        // trader_command_tx Market without an associated Trader
+        let (command_tx, _command_rx) = mpsc::channel(10);
+        trader_command_txs.insert(market("sol"), command_tx);
+
+        assert!(matches!(
+            validate_trader_markets(&traders, &trader_command_txs),
+            Err(EngineError::InvalidMarkets(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn cancel_all_orders_should_route_to_traders_of_instrument_provided() {
+        let (engine, mut trader_command_rxs) = engine(&[market("btc"), market("eth")]);
+
+        engine
+            .cancel_all_orders(Some(market("btc").instrument))
+            .await;
+
+        let btc_command = trader_command_rxs
+            .get_mut(&market("btc"))
+            .unwrap()
+            .try_recv();
+        assert!(matches!(
+            btc_command,
+            Ok(Command::CancelAllOrders { instrument: Some(instrument) })
+                if instrument == market("btc").instrument
+        ));
+        assert!(trader_command_rxs
+            .get_mut(&market("eth"))
+            .unwrap()
+            .try_recv()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn cancel_all_orders_without_instrument_should_route_to_every_trader() {
+        let (engine, mut trader_command_rxs) = engine(&[market("btc"), market("eth")]);
+
+        engine.cancel_all_orders(None).await;
+
+        for command_rx in trader_command_rxs.values_mut() {
+            assert!(matches!(
+                command_rx.try_recv(),
+                Ok(Command::CancelAllOrders { instrument: None })
+            ));
         }
     }
 
-    pub fn engine_id(self, value: Uuid) -> Self {
-        Self {
-            engine_id: Some(value),
-            ..self
+    #[tokio::test]
+    async fn pause_and_resume_should_route_to_every_trader() {
+        let (engine, mut trader_command_rxs) = engine(&[market("btc"), market("eth")]);
+
+        engine.set_traders_paused(true).await;
+        engine.set_traders_paused(false).await;
+
+        for command_rx in trader_command_rxs.values_mut() {
+            assert!(matches!(command_rx.try_recv(), Ok(Command::Pause)));
+            assert!(matches!(command_rx.try_recv(), Ok(Command::Resume)));
         }
     }
 
-    pub fn command_rx(self, value: mpsc::Receiver<Command>) -> Self {
-        Self {
-            command_rx: Some(value),
-            ..self
+    #[tokio::test]
+    async fn kill_switch_should_route_to_every_trader() {
+        let (engine, mut trader_command_rxs) = engine(&[market("btc"), market("eth")]);
+
+        assert!(!engine.action_command(Command::KillSwitch).await);
+
+        for command_rx in trader_command_rxs.values_mut() {
+            assert!(matches!(command_rx.try_recv(), Ok(Command::KillSwitch)));
+            assert!(command_rx.try_recv().is_err());
         }
     }
 
-    pub fn portfolio(self, value: Arc<Mutex<Portfolio>>) -> Self {
-        Self {
-            portfolio: Some(value),
-            ..self
+    #[tokio::test]
+    async fn rebalance_should_route_target_weight_and_equity_to_traders_of_target_instruments() {
+        let (engine, mut trader_command_rxs) =
+            engine(&[market("btc"), market("eth"), market("sol")]);
+
+        let targets = HashMap::from([
+            (market("btc").instrument, 0.5),
+            (market("eth").instrument, 0.25),
+        ]);
+        assert!(!engine.action_command(Command::Rebalance { targets }).await);
+
+        for (base, expected) in [("btc", 0.5), ("eth", 0.25)] {
+            let command_rx = trader_command_rxs.get_mut(&market(base)).unwrap();
+            assert!(matches!(
+                command_rx.try_recv(),
+                Ok(Command::RebalanceMarket { weight, equity, result_tx: None })
+                    if weight == expected && equity == Decimal::from(10_000)
+            ));
         }
+        assert!(trader_command_rxs
+            .get_mut(&market("sol"))
+            .unwrap()
+            .try_recv()
+            .is_err());
     }
 
-    pub fn traders(
-        self,
-        value: Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>,
-    ) -> Self {
-        Self {
-            traders: Some(value),
-            ..self
+    #[tokio::test]
+    async fn rebalance_should_not_route_invalid_target_weights() {
+        let (engine, mut trader_command_rxs) = engine(&[market("btc"), market("eth")]);
+
+        let invalid_targets = [
+            // Weights summing to more than 1.0
+            HashMap::from([
+                (market("btc").instrument, 0.75),
+                (market("eth").instrument, 0.5),
+            ]),
+            // Negative weight
+            HashMap::from([(market("btc").instrument, -0.5)]),
+        ];
+
+        for targets in invalid_targets {
+            engine.action_command(Command::Rebalance { targets }).await;
+        }
+
+        for command_rx in trader_command_rxs.values_mut() {
+            assert!(command_rx.try_recv().is_err());
         }
     }
 
-    pub fn trader_command_txs(self, value: HashMap<Market, mpsc::Sender<Command>>) -> Self {
-        Self {
-            trader_command_txs: Some(value),
-            ..self
+    #[tokio::test]
+    async fn correlated_command_should_report_exactly_one_outcome() {
+        let (engine, mut trader_command_rxs) = engine(&[market("btc")]);
+        let btc_command_rx = trader_command_rxs.get_mut(&market("btc")).unwrap();
+
+        // Valid close is routed to the Trader, which reports the outcome of actioning it
+        let valid = Uuid::new_v4();
+        engine
+            .correlated_command(valid, Command::ExitPosition(market("btc")))
+            .await;
+        assert!(matches!(
+            btc_command_rx.try_recv(),
+            Ok(Command::Correlated { id, command })
+                if id == valid && matches!(*command, Command::ExitPosition(_))
+        ));
+        assert!(btc_command_rx.try_recv().is_err());
+
+        // Close of an untraded Market is rejected by the Engine
+        let rejected = Uuid::new_v4();
+        engine
+            .correlated_command(rejected, Command::ExitPosition(market("eth")))
+            .await;
+        assert!(matches!(
+            btc_command_rx.try_recv(),
+            Ok(Command::ReportOutcome(CommandOutcome {
+                id,
+                result: CommandResult::Rejected(_),
+            })) if id == rejected
+        ));
+        assert!(btc_command_rx.try_recv().is_err());
+
+        // Commands involving every Trader are accepted before being routed
+        let paused = Uuid::new_v4();
+        engine.correlated_command(paused, Command::Pause).await;
+        assert!(matches!(
+            btc_command_rx.try_recv(),
+            Ok(Command::ReportOutcome(CommandOutcome {
+                id,
+                result: CommandResult::Accepted,
+            })) if id == paused
+        ));
+        assert!(matches!(btc_command_rx.try_recv(), Ok(Command::Pause)));
+        assert!(btc_command_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn correlated_rebalance_should_report_the_combined_outcome_of_every_trader() {
+        let (engine, mut trader_command_rxs) = engine(&[market("btc"), market("eth")]);
+        let targets = HashMap::from([
+            (market("btc").instrument, 0.5),
+            (market("eth").instrument, 0.25),
+        ]);
+
+        // Responds to the Command::RebalanceMarket of every Trader with the provided results
+        async fn respond(
+            trader_command_rxs: &mut HashMap<Market, mpsc::Receiver<Command>>,
+            btc: CommandResult,
+            eth: CommandResult,
+        ) {
+            for (base, result) in [("btc", btc), ("eth", eth)] {
+                let command_rx = trader_command_rxs.get_mut(&market(base)).unwrap();
+                match command_rx.recv().await {
+                    Some(Command::RebalanceMarket {
+                        result_tx: Some(result_tx),
+                        ..
+                    }) => result_tx.send(result).unwrap(),
+                    other => panic!("expected Command::RebalanceMarket, got {other:?}"),
+                }
+            }
+        }
+
+        let cases = [
+            (
+                CommandResult::Accepted,
+                CommandResult::NoOp,
+                CommandResult::Accepted,
+            ),
+            (
+                CommandResult::NoOp,
+                CommandResult::NoOp,
+                CommandResult::NoOp,
+            ),
+            (
+                CommandResult::Accepted,
+                CommandResult::Rejected("Trader is paused".to_owned()),
+                CommandResult::PartiallyAccepted(format!("{:?}: Trader is paused", market("eth"))),
+            ),
+            (
+                CommandResult::Rejected("Trader is paused".to_owned()),
+                CommandResult::Rejected("Trader is paused".to_owned()),
+                CommandResult::Rejected(format!(
+                    "{:?}: Trader is paused; {:?}: Trader is paused",
+                    market("btc"),
+                    market("eth")
+                )),
+            ),
+        ];
+
+        for (btc, eth, expected) in cases {
+            let id = Uuid::new_v4();
+            tokio::join!(
+                engine.correlated_command(
+                    id,
+                    Command::Rebalance {
+                        targets: targets.clone(),
+                    },
+                ),
+                respond(&mut trader_command_rxs, btc, eth),
+            );
+
+            // Exactly one CommandOutcome is reported, once every Trader has responded
+            let outcomes = trader_command_rxs
+                .values_mut()
+                .flat_map(|command_rx| std::iter::from_fn(|| command_rx.try_recv().ok()))
+                .collect::<Vec<_>>();
+            assert!(matches!(
+                outcomes.as_slice(),
+                [Command::ReportOutcome(CommandOutcome { id: actual, result })]
+                    if *actual == id && *result == expected
+            ));
         }
     }
 
-    pub fn statistics_summary(self, value: Statistic) -> Self {
-        Self {
-            statistics_summary: Some(value),
-            ..self
+    fn initial_portfolio(open_positions: Vec<Position>) -> PortfolioState {
+        PortfolioState {
+            balance: Balance::new(Utc::now(), Decimal::from(12_000), Decimal::from(11_000)),
+            open_positions,
         }
     }
 
-    pub fn build(
-        self,
-    ) -> Result<Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
-        Ok(Engine {
-            engine_id: self
-                .engine_id
-                .ok_or(EngineError::BuilderIncomplete("engine_id"))?,
-            command_rx: self
-                .command_rx
-                .ok_or(EngineError::BuilderIncomplete("command_rx"))?,
-            portfolio: self
+    /// [`ExecutionClient`] of an exchange account holding the provided [`Balance`], which never
+    /// responds to [`ExecutionClient::fetch_balance`] if `None`.
+    #[derive(Debug)]
+    struct BalanceResponder(Option<Balance>);
+
+    impl ExecutionClient for BalanceResponder {
+        fn generate_fill(&mut self, _: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+            Ok(None)
+        }
+
+        fn fetch_balance(
+            &mut self,
+        ) -> impl Future<Output = Result<Option<Balance>, ExecutionError>> + Send {
+            let balance = self.0;
+            async move {
+                match balance {
+                    Some(balance) => Ok(Some(balance)),
+                    None => std::future::pending().await,
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn engine_should_seed_portfolio_with_opening_balance_of_every_exchange() {
+        let engine_id = Uuid::new_v4();
+        let accounts = [
+            (
+                market("btc"),
+                Some(Balance::new(
+                    Utc::now(),
+                    Decimal::ONE_THOUSAND,
+                    Decimal::from(800),
+                )),
+            ),
+            // Second Trader of an exchange does not fetch the exchange Balance again
+            (
+                market("eth"),
+                Some(Balance::new(
+                    Utc::now(),
+                    Decimal::ONE_THOUSAND,
+                    Decimal::from(800),
+                )),
+            ),
+            (
+                Market::new("ftx", ("btc", "usdt", InstrumentKind::Spot)),
+                Some(Balance::new(
+                    Utc::now(),
+                    Decimal::from(500),
+                    Decimal::from(500),
+                )),
+            ),
+            // Unresponsive exchange falls back to a zero Balance after the timeout
+            (
+                Market::new("kraken", ("btc", "usdt", InstrumentKind::Spot)),
+                None,
+            ),
+        ];
+        let markets = accounts
+            .iter()
+            .map(|(market, _)| market.clone())
+            .collect::<Vec<_>>();
+        let portfolio = portfolio(engine_id, &markets);
+
+        let (event_tx, _) = mpsc::unbounded_channel();
+        let mut trader_command_txs = HashMap::new();
+        let traders = accounts
+            .into_iter()
+            .map(|(market, balance)| {
+                let (command_tx, command_rx) = mpsc::channel(10);
+                trader_command_txs.insert(market.clone(), command_tx);
+
+                Trader::builder()
+                    .engine_id(engine_id)
+                    .market(market)
+                    .command_rx(command_rx)
+                    .event_tx(EventTx::new(event_tx.clone()))
+                    .portfolio(Arc::clone(&portfolio))
+                    .data(historical::MarketFeed::new(Vec::new()))
+                    .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+                    .execution(BalanceResponder(balance))
+                    .build()
+                    .unwrap()
+            })
+            .collect::<Vec<TraderOf<BalanceResponder>>>();
+
+        Engine::builder()
+            .engine_id(engine_id)
+            .command_rx(mpsc::channel(10).1)
+            .portfolio(Arc::clone(&portfolio))
+            .traders(traders)
+            .trader_command_txs(trader_command_txs)
+            .statistics_summary(TradingSummary::init(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            }))
+            .fetch_opening_balance(std::time::Duration::from_millis(100))
+            .build()
+            .unwrap()
+            .run()
+            .await
+            .unwrap();
+
+        let balance = portfolio.lock().get_balance(engine_id).unwrap();
+        assert_eq!(balance.total, Decimal::from(1500));
+        assert_eq!(balance.available, Decimal::from(1300));
+    }
+
+    #[test]
+    fn engine_should_resume_with_initial_portfolio_state() {
+        // Long Position persisted by a previous Engine, so keyed with a stale PositionId
+        let long = Position {
+            exchange: market("eth").exchange,
+            instrument: market("eth").instrument,
+            ..position()
+        };
+
+        let (builder, _) = engine_builder(&[market("btc"), market("eth")]);
+        let engine = builder
+            .initial_portfolio(initial_portfolio(vec![long.clone()]))
+            .build()
+            .unwrap();
+
+        let mut portfolio = engine.portfolio.lock();
+        let open_positions = portfolio
+            .get_open_positions(engine.engine_id, engine.trader_command_txs.keys())
+            .unwrap();
+        assert_eq!(
+            open_positions,
+            vec![Position {
+                position_id: determine_position_id(
+                    engine.engine_id,
+                    &long.exchange,
+                    &long.instrument
+                ),
+                ..long
+            }]
+        );
+        assert_eq!(
+            portfolio.get_balance(engine.engine_id).unwrap().total,
+            Decimal::from(12_000)
+        );
+    }
+
+    #[test]
+    fn engine_with_initial_portfolio_position_for_untraded_market_is_err() {
+        let untraded = Position {
+            exchange: market("sol").exchange,
+            instrument: market("sol").instrument,
+            ..position()
+        };
+
+        let (builder, _) = engine_builder(&[market("btc")]);
+        let result = builder
+            .initial_portfolio(initial_portfolio(vec![untraded]))
+            .build();
+
+        assert!(matches!(result, Err(EngineError::InvalidMarkets(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_portfolio_snapshot_should_reflect_portfolio_mid_session() {
+        let long = Position {
+            exchange: market("eth").exchange,
+            instrument: market("eth").instrument,
+            side: Side::Buy,
+            quantity: Decimal::ONE,
+            enter_avg_price_gross: Decimal::ONE_HUNDRED,
+            enter_value_gross: Decimal::ONE_HUNDRED,
+            enter_fees_total: Decimal::ZERO,
+            ..position()
+        };
+
+        let (builder, _) = engine_builder(&[market("btc"), market("eth")]);
+        let engine = builder
+            .initial_portfolio(initial_portfolio(vec![long]))
+            .build()
+            .unwrap();
+
+        // Trader updates the open Position from a MarketEvent mid-session
+        engine
+            .portfolio
+            .lock()
+            .update_from_market(&MarketEvent {
+                exchange: market("eth").exchange,
+                instrument: market("eth").instrument,
+                ..market_event_trade(Side::Buy)
+            })
+            .unwrap();
+
+        let (snapshot_tx, snapshot_rx) = oneshot::channel();
+        engine.fetch_portfolio_snapshot(snapshot_tx).await;
+        let snapshot = snapshot_rx.await.unwrap().unwrap();
+
+        assert_eq!(snapshot.open_positions.len(), 1);
+        let position = &snapshot.open_positions[0];
+        assert_eq!(position.instrument, market("eth").instrument);
+        assert_eq!(position.quantity, Decimal::ONE);
+        assert_eq!(position.enter_avg_price_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.current_symbol_price, Decimal::ONE_THOUSAND);
+        assert_eq!(position.unrealised_profit_loss, Decimal::from(900));
+
+        assert_eq!(snapshot.balance.available, Decimal::from(11_000));
+        assert_eq!(snapshot.unrealised_profit_loss, Decimal::from(900));
+        assert_eq!(snapshot.equity, Decimal::from(12_900));
+    }
+
+    #[tokio::test]
+    async fn engine_restarted_from_checkpoint_should_resume_positions_and_open_orders() {
+        let markets = [market("btc"), market("eth")];
+        let long = Position {
+            exchange: market("eth").exchange,
+            instrument: market("eth").instrument,
+            ..position()
+        };
+
+        let (builder, trader_command_rxs) = engine_builder(&markets);
+        let engine = builder
+            .initial_portfolio(initial_portfolio(vec![long]))
+            .build()
+            .unwrap();
+
+        // Each Trader answers with a resting order generated by one shared session
+        let seed = ClientOrderId {
+            session: Uuid::new_v4(),
+            sequence: 0,
+        };
+        for (sequence, (market, mut command_rx)) in (1..).zip(trader_command_rxs) {
+            tokio::spawn(async move {
+                while let Some(command) = command_rx.recv().await {
+                    if let Command::FetchTraderCheckpoint(checkpoint_tx) = command {
+                        let cid = ClientOrderId { sequence, ..seed };
+                        let _ = checkpoint_tx.send(TraderCheckpoint {
+                            market: market.clone(),
+                            open_orders: vec![OrderEvent {
+                                cid,
+                                exchange: market.exchange.clone(),
+                                instrument: market.instrument.clone(),
+                                order_type: OrderType::Limit,
+                                time_in_force: TimeInForce::GoodUntilCancelled,
+                                ..order_event()
+                            }],
+                            throttled_orders: vec![],
+                            triggered_orders: vec![],
+                            order_id_seed: Some(ClientOrderId { sequence, ..seed }),
+                            strategy: serde_json::Value::Null,
+                        });
+                    }
+                }
+            });
+        }
+
+        let checkpoint = engine.take_checkpoint().await.unwrap();
+        assert_eq!(checkpoint.portfolio.open_positions.len(), 1);
+        assert_eq!(checkpoint.traders.len(), 2);
+
+        let directory = std::env::temp_dir().join(format!("barter-engine-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("engine.json");
+        checkpoint.save(&path).unwrap();
+
+        // Restart a new Engine from the saved Checkpoint
+        let (builder, _) = engine_builder(&markets);
+        let restarted = builder.from_checkpoint(&path).unwrap().build().unwrap();
+        std::fs::remove_dir_all(directory).unwrap();
+
+        let open_positions = restarted
+            .portfolio
+            .lock()
+            .get_open_positions(restarted.engine_id, restarted.trader_command_txs.keys())
+            .unwrap();
+        assert_eq!(
+            open_positions
+                .iter()
+                .map(|position| (&position.instrument, position.quantity))
+                .collect::<Vec<_>>(),
+            checkpoint
                 .portfolio
-                .ok_or(EngineError::BuilderIncomplete("portfolio"))?,
-            traders: self
-                .traders
-                .ok_or(EngineError::BuilderIncomplete("traders"))?,
-            trader_command_txs: self
-                .trader_command_txs
-                .ok_or(EngineError::BuilderIncomplete("trader_command_txs"))?,
-            statistics_summary: self
-                .statistics_summary
-                .ok_or(EngineError::BuilderIncomplete("statistics_summary"))?,
-        })
+                .open_positions
+                .iter()
+                .map(|position| (&position.instrument, position.quantity))
+                .collect::<Vec<_>>()
+        );
+
+        let mut restored = restarted
+            .traders
+            .iter()
+            .map(|trader| trader.checkpoint())
+            .collect::<Vec<_>>();
+        restored.sort_by(|a, b| a.market.cmp(&b.market));
+
+        for (restored, checkpointed) in restored.iter().zip(&checkpoint.traders) {
+            assert_eq!(restored.open_orders, checkpointed.open_orders);
+
+            // Traders sharing a session resume after the latest ClientOrderId of the session
+            assert_eq!(
+                restored.order_id_seed,
+                Some(ClientOrderId {
+                    sequence: 2,
+                    ..seed
+                })
+            );
+        }
+    }
+
+    /// Builds an [`Engine`] with one [`Trader`] consuming three [`MarketEvent`]s, returning the
+    /// Engine command_tx.
+    fn stepped_engine() -> (TestEngine, mpsc::Sender<Command>) {
+        let (builder, command_tx) = stepped_engine_builder();
+        (builder.build().unwrap(), command_tx)
+    }
+
+    /// Constructs an [`EngineBuilder`] with one [`Trader`] consuming three [`MarketEvent`]s,
+    /// returning the Engine command_tx. See [`stepped_engine`].
+    fn stepped_engine_builder() -> (TestEngineBuilder, mpsc::Sender<Command>) {
+        let engine_id = Uuid::new_v4();
+        let market = Market::new("binance_spot", ("btc", "usdt", InstrumentKind::Spot));
+        let portfolio = portfolio(engine_id, std::slice::from_ref(&market));
+
+        let (event_tx, _) = mpsc::unbounded_channel();
+        let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+        let trader = Trader::builder()
+            .engine_id(engine_id)
+            .market(market.clone())
+            .command_rx(trader_command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(&portfolio))
+            .data(historical::MarketFeed::new(vec![
+                market_event_trade(Side::Buy),
+                market_event_trade(Side::Sell),
+                market_event_trade(Side::Buy),
+            ]))
+            .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+            .execution(SimulatedExecution::new(ExecutionConfig::default()))
+            .build()
+            .unwrap();
+
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let engine = Engine::builder()
+            .engine_id(engine_id)
+            .command_rx(command_rx)
+            .portfolio(portfolio)
+            .traders(vec![trader])
+            .trader_command_txs(HashMap::from([(market, trader_command_tx)]))
+            .statistics_summary(TradingSummary::init(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            }));
+
+        (engine, command_tx)
+    }
+
+    type UnstoppableEngine = Engine<
+        EventTx,
+        TradingSummary,
+        TestPortfolio,
+        live::MarketFeed<MarketEvent<DataKind>>,
+        RSIStrategy,
+        SimulatedExecution,
+    >;
+
+    /// Channels that must be held to keep the [`Trader`] of an [`UnstoppableEngine`] running.
+    type UnstoppableChannels = (
+        mpsc::UnboundedSender<MarketEvent<DataKind>>,
+        mpsc::Sender<Command>,
+        mpsc::Receiver<Command>,
+        mpsc::Sender<Command>,
+    );
+
+    /// Builds an [`Engine`] with the provided shutdown grace period, whose [`Trader`] idles on a
+    /// live feed & never receives the [`Command`]s routed to it, so never stops.
+    fn unstoppable_engine(
+        shutdown_grace_period: Duration,
+    ) -> (UnstoppableEngine, UnstoppableChannels) {
+        let engine_id = Uuid::new_v4();
+        let portfolio = portfolio(engine_id, &[market("btc")]);
+
+        let (event_tx, _) = mpsc::unbounded_channel();
+        let (market_tx, market_rx) = mpsc::unbounded_channel();
+        let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+        let trader = Trader::builder()
+            .engine_id(engine_id)
+            .market(market("btc"))
+            .command_rx(trader_command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(&portfolio))
+            .data(live::MarketFeed::new(market_rx))
+            .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+            .execution(SimulatedExecution::new(ExecutionConfig::default()))
+            .build()
+            .unwrap();
+
+        let (unrouted_tx, unrouted_rx) = mpsc::channel(10);
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let engine = Engine::builder()
+            .engine_id(engine_id)
+            .command_rx(command_rx)
+            .portfolio(portfolio)
+            .traders(vec![trader])
+            .trader_command_txs(HashMap::from([(market("btc"), unrouted_tx)]))
+            .statistics_summary(TradingSummary::init(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            }))
+            .shutdown_grace_period(shutdown_grace_period)
+            .build()
+            .unwrap();
+
+        (
+            engine,
+            (market_tx, trader_command_tx, unrouted_rx, command_tx),
+        )
+    }
+
+    #[tokio::test]
+    async fn run_until_should_force_shutdown_once_traders_exceed_the_shutdown_grace_period() {
+        let (engine, _held) = unstoppable_engine(Duration::from_millis(50));
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(3),
+            engine.run_until(std::future::ready(())),
+        )
+        .await
+        .expect("Engine shutdown was not forced");
+
+        assert!(matches!(
+            result,
+            Err(EngineError::ShutdownForced(why)) if why.contains("grace period")
+        ));
+    }
+
+    #[tokio::test]
+    async fn run_until_forced_should_force_shutdown_once_force_future_completes() {
+        let (engine, _held) = unstoppable_engine(Duration::from_secs(3600));
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(3),
+            engine.run_until_forced(std::future::ready(()), std::future::ready(())),
+        )
+        .await
+        .expect("Engine shutdown was not forced");
+
+        assert!(matches!(
+            result,
+            Err(EngineError::ShutdownForced(why)) if why.contains("second shutdown signal")
+        ));
+    }
+
+    #[test]
+    fn engine_records_should_be_timed_by_the_simulated_clock_of_the_traders() {
+        let (builder, _command_tx) = stepped_engine_builder();
+        let engine = builder.clock(TraderClock::Simulated).build().unwrap();
+
+        let time = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        engine.traders[0].clock().advance(time);
+
+        assert_eq!(engine.now(), time);
+        assert_eq!(engine.portfolio_snapshot().unwrap().time, time);
+    }
+
+    #[tokio::test]
+    async fn traders_should_request_a_checkpoint_every_configured_number_of_events() {
+        let (builder, _command_tx) = stepped_engine_builder();
+        let mut engine = builder
+            .checkpoint(CheckpointConfig {
+                path: std::env::temp_dir().join(format!("barter-engine-{}", Uuid::new_v4())),
+                interval: Duration::from_secs(3600),
+                every_events: Some(2),
+            })
+            .build()
+            .unwrap();
+        let mut request_rx = engine.checkpoint_request_rx.take().unwrap();
+
+        // Each step handles a single Event::Market, so a Checkpoint is requested every second step
+        engine.traders[0].start();
+        engine.traders[0].step();
+        assert!(request_rx.try_recv().is_err());
+        engine.traders[0].step();
+        assert!(request_rx.try_recv().is_ok());
+        engine.traders[0].step();
+        assert!(request_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn engine_should_single_step_through_session_collecting_audits() {
+        let (mut engine, _command_tx) = stepped_engine();
+
+        let mut audits = Vec::new();
+        while let Some(audit) = engine.step().await {
+            audits.push(audit);
+        }
+
+        // One step per MarketEvent, then a final step once the MarketEvent feed finishes
+        assert_eq!(audits.len(), 4);
+        for audit in &audits[..3] {
+            assert!(matches!(audit.events.as_slice(), [Event::Market(_)]));
+            assert!(audit.stopped.is_none());
+        }
+        assert_eq!(
+            audits.iter().map(|audit| audit.state).collect::<Vec<_>>(),
+            vec![
+                TraderState::Trading,
+                TraderState::Trading,
+                TraderState::Trading,
+                TraderState::Stopped
+            ]
+        );
+
+        let summary = audits[3].stopped.take().unwrap().unwrap();
+        assert_eq!(summary.market_events, 3);
+        assert!(summary.statistics.is_some());
+        assert!(engine.step().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn stepped_engine_should_answer_fetch_order_state_without_awaiting_the_trader() {
+        let (mut engine, command_tx) = stepped_engine();
+        engine.step().await.unwrap();
+
+        let (state_tx, state_rx) = oneshot::channel();
+        command_tx
+            .send(Command::FetchOrderState {
+                id: ClientOrderId::default(),
+                state_tx,
+            })
+            .await
+            .unwrap();
+
+        // Trader is only stepped after the Command is actioned, so awaiting it would deadlock
+        let step = tokio::time::timeout(std::time::Duration::from_secs(3), engine.step());
+        assert!(step.await.unwrap().is_some());
+        assert_eq!(state_rx.await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn stepped_engine_should_answer_query_pending_orders_without_awaiting_the_trader() {
+        let (mut engine, command_tx) = stepped_engine();
+        engine.traders[0].set_rate_limiter(RateLimiter::new(0.001, 1));
+        let market = engine.traders[0].market().clone();
+
+        // First of two limit orders is sent, the second is throttled by the RateLimiter
+        for limit_price in [Decimal::ONE_HUNDRED, Decimal::from(110)] {
+            command_tx
+                .send(Command::ManualOrder(ManualOrderRequest {
+                    exchange: market.exchange.clone(),
+                    instrument: market.instrument.clone(),
+                    side: Side::Buy,
+                    quantity: Decimal::ONE,
+                    notional: None,
+                    limit_price: Some(limit_price),
+                    stop_price: None,
+                    time_in_force: TimeInForce::default(),
+                    trigger: None,
+                    tags: OrderTags::default(),
+                }))
+                .await
+                .unwrap();
+        }
+        engine.step().await.unwrap();
+
+        let (orders_tx, orders_rx) = oneshot::channel();
+        command_tx
+            .send(Command::QueryPendingOrders(orders_tx))
+            .await
+            .unwrap();
+
+        // Trader is only stepped after the Command is actioned, so awaiting it would deadlock
+        let step = tokio::time::timeout(std::time::Duration::from_secs(3), engine.step());
+        assert!(step.await.unwrap().is_some());
+        let queued = orders_rx.await.unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].reason, QueueReason::RateLimited);
+        assert_eq!(queued[0].order.order_type, OrderType::Limit);
+    }
+
+    #[tokio::test]
+    async fn engine_stream_should_end_once_terminated() {
+        let (engine, command_tx) = stepped_engine();
+        command_tx
+            .send(Command::Terminate("stepping stopped".to_owned()))
+            .await
+            .unwrap();
+
+        // Trader actions the exit of it's Position with the first step & then terminates
+        let audits = engine.into_stream().collect::<Vec<_>>().await;
+        assert_eq!(audits.len(), 2);
+        assert!(audits[0].stopped.is_none());
+        assert_eq!(audits[1].state, TraderState::Stopped);
+        assert!(audits[1].events.is_empty());
+        assert!(matches!(audits[1].stopped, Some(Ok(_))));
     }
 }
@@ -0,0 +1,745 @@
+use super::{
+    error::EngineError,
+    trader::{SessionSummary, TraderBuilder},
+    Command,
+};
+use crate::{
+    clock::{Clock, SimulatedClock},
+    data::{Feed, MarketGenerator},
+    event::{Event, MessageTransmitter},
+    execution::{
+        error::ExecutionError, order_id::ClientOrderId, ExecutionClient, FillEvent, OrderRejection,
+    },
+    portfolio::{
+        repository::{BalanceHandler, PositionHandler},
+        Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator,
+    },
+    strategy::SignalGenerator,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter},
+    fs,
+    future::Future,
+    io::{BufRead, BufWriter, Write},
+    path::Path,
+    sync::Arc,
+};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+/// Input of a [`Trader`](super::trader::Trader) recorded to a session log by a
+/// [`SessionRecorder`], from which the trading session can be replayed via [`replay_from_log`].
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type", content = "content")]
+pub enum SessionRecord {
+    /// [`MarketEvent`] consumed from the [`MarketGenerator`], at the
+    /// [`Clock`](crate::clock::Clock) time it was consumed.
+    Market {
+        at: DateTime<Utc>,
+        market: MarketEvent<DataKind>,
+    },
+    /// Remote [`Command`] received, at the [`Clock`](crate::clock::Clock) time it was received.
+    Command { at: DateTime<Utc>, command: Command },
+    /// Response of the [`ExecutionClient`] to a call made by the
+    /// [`Trader`](super::trader::Trader), recorded by a [`RecordingExecution`].
+    Execution(ExecutionRecord),
+}
+
+/// Borrowed [`SessionRecord`] with an identical serialised form, so inputs are recorded without
+/// being cloned.
+#[derive(Serialize)]
+#[serde(tag = "type", content = "content")]
+enum SessionRecordRef<'a> {
+    Market {
+        at: DateTime<Utc>,
+        market: &'a MarketEvent<DataKind>,
+    },
+    Command {
+        at: DateTime<Utc>,
+        command: &'a Command,
+    },
+    Execution(&'a ExecutionRecord),
+}
+
+/// Response of an [`ExecutionClient`] to a single call, in the order the calls were made.
+/// Failed calls are recorded as `failed`, since an [`ExecutionError`] is not serialisable.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum ExecutionRecord {
+    GenerateFill {
+        fill: Option<FillEvent>,
+        failed: bool,
+    },
+    FillRestingOrders(Vec<FillEvent>),
+    CancelOrder(Option<OrderEvent>),
+    AmendOrder(Option<OrderEvent>),
+    RejectedOrders(Vec<OrderRejection>),
+    ExchangePosition {
+        quantity: Option<Decimal>,
+        failed: bool,
+    },
+}
+
+/// Records the inputs of a live trading session (the [`MarketEvent`]s consumed, the
+/// [`Command`]s received & the responses of the [`ExecutionClient`]) to a JSON lines session
+/// log file, so an incident can be reproduced via [`replay_from_log`].
+///
+/// Every record is flushed as it is written, so the session log survives a crash. Cloning a
+/// [`SessionRecorder`] returns a handle to the same session log, which must be shared by the
+/// [`Trader`](super::trader::Trader) & it's [`RecordingExecution`]. Failures to write are logged
+/// rather than interrupting trading.
+#[derive(Clone)]
+pub struct SessionRecorder {
+    writer: Arc<Mutex<BufWriter<fs::File>>>,
+}
+
+impl Debug for SessionRecorder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionRecorder").finish_non_exhaustive()
+    }
+}
+
+impl SessionRecorder {
+    /// Constructs a new [`SessionRecorder`] writing to a session log at the file path
+    /// provided, replacing any existing file.
+    pub fn create<P>(path: P) -> Result<Self, EngineError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = fs::File::create(path).map_err(EngineError::SessionLogIo)?;
+        Ok(Self {
+            writer: Arc::new(Mutex::new(BufWriter::new(file))),
+        })
+    }
+
+    /// Records the [`MarketEvent`] consumed at the provided [`Clock`](crate::clock::Clock) time.
+    pub fn record_market(&self, at: DateTime<Utc>, market: &MarketEvent<DataKind>) {
+        self.write(&SessionRecordRef::Market { at, market });
+    }
+
+    /// Records the [`Command`] received at the provided [`Clock`](crate::clock::Clock) time.
+    /// [`Command`]s that cannot be serialised (eg/ [`Command::FetchOpenPositions`]) do not
+    /// affect trading, so are skipped.
+    pub fn record_command(&self, at: DateTime<Utc>, command: &Command) {
+        self.write(&SessionRecordRef::Command { at, command });
+    }
+
+    /// Records the response of the [`ExecutionClient`] to a call.
+    pub fn record_execution(&self, record: &ExecutionRecord) {
+        self.write(&SessionRecordRef::Execution(record));
+    }
+
+    fn write(&self, record: &SessionRecordRef<'_>) {
+        let line = match serde_json::to_string(record) {
+            Ok(line) => line,
+            Err(error) => {
+                debug!(?error, "skipping SessionRecord that cannot be serialised");
+                return;
+            }
+        };
+
+        let mut writer = self.writer.lock();
+        if let Err(error) = writeln!(writer, "{line}").and_then(|_| writer.flush()) {
+            warn!(?error, "failed to write SessionRecord to session log");
+        }
+    }
+}
+
+/// Loads every [`SessionRecord`] from the session log at the file path provided, in the order
+/// they were recorded.
+pub fn load_session_log<P>(path: P) -> Result<Vec<SessionRecord>, EngineError>
+where
+    P: AsRef<Path>,
+{
+    let file = fs::File::open(path).map_err(EngineError::SessionLogIo)?;
+    std::io::BufReader::new(file)
+        .lines()
+        .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|line| {
+            let line = line.map_err(EngineError::SessionLogIo)?;
+            serde_json::from_str(&line).map_err(EngineError::SessionLogSerde)
+        })
+        .collect()
+}
+
+/// Execution handler that wraps another [`ExecutionClient`], recording every response of it
+/// to a [`SessionRecorder`] so the trading session can be replayed by a [`ReplayExecution`].
+#[derive(Debug)]
+pub struct RecordingExecution<Execution>
+where
+    Execution: ExecutionClient,
+{
+    execution: Execution,
+    recorder: SessionRecorder,
+}
+
+impl<Execution> RecordingExecution<Execution>
+where
+    Execution: ExecutionClient,
+{
+    /// Constructs a new [`RecordingExecution`] wrapping the provided [`ExecutionClient`].
+    pub fn new(execution: Execution, recorder: SessionRecorder) -> Self {
+        Self {
+            execution,
+            recorder,
+        }
+    }
+}
+
+impl<Execution> ExecutionClient for RecordingExecution<Execution>
+where
+    Execution: ExecutionClient,
+{
+    fn generate_fill(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        let result = self.execution.generate_fill(order);
+        self.recorder
+            .record_execution(&ExecutionRecord::GenerateFill {
+                fill: result.as_ref().ok().cloned().flatten(),
+                failed: result.is_err(),
+            });
+        result
+    }
+
+    fn fill_resting_orders(&mut self, market: &MarketEvent<DataKind>) -> Vec<FillEvent> {
+        let fills = self.execution.fill_resting_orders(market);
+        self.recorder
+            .record_execution(&ExecutionRecord::FillRestingOrders(fills.clone()));
+        fills
+    }
+
+    fn cancel_order(&mut self, cid: &ClientOrderId) -> Option<OrderEvent> {
+        let cancelled = self.execution.cancel_order(cid);
+        self.recorder
+            .record_execution(&ExecutionRecord::CancelOrder(cancelled.clone()));
+        cancelled
+    }
+
+    fn amend_order(&mut self, order: &OrderEvent) -> Option<OrderEvent> {
+        let amended = self.execution.amend_order(order);
+        self.recorder
+            .record_execution(&ExecutionRecord::AmendOrder(amended.clone()));
+        amended
+    }
+
+    fn restore_order(&mut self, order: &OrderEvent) {
+        self.execution.restore_order(order)
+    }
+
+    fn rejected_orders(&mut self) -> Vec<OrderRejection> {
+        let rejections = self.execution.rejected_orders();
+        self.recorder
+            .record_execution(&ExecutionRecord::RejectedOrders(rejections.clone()));
+        rejections
+    }
+
+    fn exchange_position(
+        &mut self,
+        instrument: &Instrument,
+    ) -> Result<Option<Decimal>, ExecutionError> {
+        let result = self.execution.exchange_position(instrument);
+        self.recorder
+            .record_execution(&ExecutionRecord::ExchangePosition {
+                quantity: result.as_ref().ok().copied().flatten(),
+                failed: result.is_err(),
+            });
+        result
+    }
+
+    fn fetch_balance(
+        &mut self,
+    ) -> impl Future<Output = Result<Option<Balance>, ExecutionError>> + Send {
+        self.execution.fetch_balance()
+    }
+}
+
+/// Market feed replaying the [`MarketEvent`]s & [`Command`]s of a session log in the order they
+/// were recorded, advancing a [`SimulatedClock`] to the time each was recorded at.
+///
+/// [`Command`]s received together are sent to the [`Trader`](super::trader::Trader) together
+/// via an idle poll, so they are actioned before the next [`MarketEvent`] is consumed, as they
+/// were during the recorded session.
+#[derive(Debug)]
+pub struct ReplayFeed {
+    inputs: VecDeque<SessionRecord>,
+    clock: SimulatedClock,
+    command_tx: mpsc::Sender<Command>,
+}
+
+impl MarketGenerator<MarketEvent<DataKind>> for ReplayFeed {
+    fn next(&mut self) -> Feed<MarketEvent<DataKind>> {
+        match self.inputs.pop_front() {
+            Some(SessionRecord::Market { at, market }) => {
+                self.clock.advance(at);
+                Feed::Next(market)
+            }
+            Some(SessionRecord::Command { at, command }) => {
+                self.clock.advance(at);
+                self.send_command(command);
+                while let Some(SessionRecord::Command { .. }) = self.inputs.front() {
+                    let Some(SessionRecord::Command { at, command }) = self.inputs.pop_front()
+                    else {
+                        unreachable!("inputs front was checked to be a SessionRecord::Command");
+                    };
+                    self.clock.advance(at);
+                    self.send_command(command);
+                }
+                Feed::Idle
+            }
+            Some(SessionRecord::Execution(_)) => self.next(),
+            None => Feed::Finished,
+        }
+    }
+}
+
+impl ReplayFeed {
+    fn send_command(&self, command: Command) {
+        if let Err(error) = self.command_tx.try_send(command) {
+            warn!(?error, "failed to send replayed Command to Trader");
+        }
+    }
+}
+
+/// Execution handler replaying the recorded responses of the [`ExecutionClient`] of a session
+/// log, in the order the calls were made. A call that differs from the recorded call indicates
+/// the replay diverged from the recorded session, so is logged & answered with an empty
+/// response.
+///
+/// The [`ClientOrderId`]s of replayed [`FillEvent`]s & [`OrderEvent`]s are rewritten to the
+/// session of the replaying [`OrderIdGenerator`](crate::execution::order_id::OrderIdGenerator),
+/// since it's session differs from that of the recorded session.
+#[derive(Debug)]
+pub struct ReplayExecution {
+    responses: VecDeque<ExecutionRecord>,
+    session: Option<Uuid>,
+}
+
+impl ReplayExecution {
+    /// Returns the next recorded response if it is of the expected kind.
+    fn next_response<T>(&mut self, parse: impl FnOnce(ExecutionRecord) -> Option<T>) -> Option<T> {
+        let response = self.responses.pop_front()?;
+        let recorded = format!("{response:?}");
+        let parsed = parse(response);
+        if parsed.is_none() {
+            warn!(
+                response = %recorded,
+                "replayed ExecutionClient call differs from the recorded call"
+            );
+        }
+        parsed
+    }
+
+    fn rewrite_cid(&self, cid: ClientOrderId) -> ClientOrderId {
+        match self.session {
+            Some(session) => ClientOrderId { session, ..cid },
+            None => cid,
+        }
+    }
+
+    fn rewrite_fill(&self, fill: FillEvent) -> FillEvent {
+        FillEvent {
+            cid: self.rewrite_cid(fill.cid),
+            ..fill
+        }
+    }
+
+    fn rewrite_order(&self, order: OrderEvent) -> OrderEvent {
+        OrderEvent {
+            cid: self.rewrite_cid(order.cid),
+            ..order
+        }
+    }
+}
+
+impl ExecutionClient for ReplayExecution {
+    fn generate_fill(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        self.session = Some(order.cid.session);
+        match self.next_response(|response| match response {
+            ExecutionRecord::GenerateFill { fill, failed } => Some((fill, failed)),
+            _ => None,
+        }) {
+            Some((_, true)) => Err(ExecutionError::ReplayedFailure),
+            Some((fill, false)) => Ok(fill.map(|fill| self.rewrite_fill(fill))),
+            None => Ok(None),
+        }
+    }
+
+    fn fill_resting_orders(&mut self, _: &MarketEvent<DataKind>) -> Vec<FillEvent> {
+        self.next_response(|response| match response {
+            ExecutionRecord::FillRestingOrders(fills) => Some(fills),
+            _ => None,
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .map(|fill| self.rewrite_fill(fill))
+        .collect()
+    }
+
+    fn cancel_order(&mut self, cid: &ClientOrderId) -> Option<OrderEvent> {
+        self.session = Some(cid.session);
+        self.next_response(|response| match response {
+            ExecutionRecord::CancelOrder(cancelled) => Some(cancelled),
+            _ => None,
+        })
+        .flatten()
+        .map(|order| self.rewrite_order(order))
+    }
+
+    fn amend_order(&mut self, order: &OrderEvent) -> Option<OrderEvent> {
+        self.session = Some(order.cid.session);
+        self.next_response(|response| match response {
+            ExecutionRecord::AmendOrder(amended) => Some(amended),
+            _ => None,
+        })
+        .flatten()
+        .map(|order| self.rewrite_order(order))
+    }
+
+    fn rejected_orders(&mut self) -> Vec<OrderRejection> {
+        self.next_response(|response| match response {
+            ExecutionRecord::RejectedOrders(rejections) => Some(rejections),
+            _ => None,
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .map(|rejection| OrderRejection {
+            order: self.rewrite_order(rejection.order),
+            ..rejection
+        })
+        .collect()
+    }
+
+    fn exchange_position(&mut self, _: &Instrument) -> Result<Option<Decimal>, ExecutionError> {
+        match self.next_response(|response| match response {
+            ExecutionRecord::ExchangePosition { quantity, failed } => Some((quantity, failed)),
+            _ => None,
+        }) {
+            Some((_, true)) => Err(ExecutionError::ReplayedFailure),
+            Some((quantity, false)) => Ok(quantity),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Replays the trading session recorded to the session log at the file path provided (see
+/// [`SessionRecorder`]) against a fresh [`Trader`](super::trader::Trader), returning it's
+/// [`SessionSummary`].
+///
+/// The provided [`TraderBuilder`] must be configured as the recorded
+/// [`Trader`](super::trader::Trader) was (eg/ the same `engine_id`, [`Market`](barter_integration::model::Market),
+/// Strategy & a fresh Portfolio with the same starting state). It's data feed, execution handler,
+/// `command_rx` & [`Clock`](crate::clock::Clock) are replaced by a [`ReplayFeed`], a
+/// [`ReplayExecution`] & a [`SimulatedClock`] advanced to the recorded time of every input.
+/// Differences between the recorded & replayed [`SessionSummary`]s (see
+/// [`replay_discrepancies`]) indicate non-determinism to investigate.
+pub fn replay_from_log<P, EventTx, Statistic, Portfolio, Strategy>(
+    path: P,
+    trader: TraderBuilder<EventTx, Statistic, Portfolio, ReplayFeed, Strategy, ReplayExecution>,
+) -> Result<SessionSummary<Statistic>, EngineError>
+where
+    P: AsRef<Path>,
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + PositionHandler + BalanceHandler,
+    Strategy: SignalGenerator + Send,
+{
+    let mut inputs = VecDeque::new();
+    let mut responses = VecDeque::new();
+    for record in load_session_log(path)? {
+        match record {
+            SessionRecord::Execution(response) => responses.push_back(response),
+            input => inputs.push_back(input),
+        }
+    }
+
+    let start = inputs
+        .iter()
+        .find_map(|input| match input {
+            SessionRecord::Market { at, .. } | SessionRecord::Command { at, .. } => Some(*at),
+            SessionRecord::Execution(_) => None,
+        })
+        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+    let clock = SimulatedClock::new(start);
+
+    let commands = inputs
+        .iter()
+        .filter(|input| matches!(input, SessionRecord::Command { .. }))
+        .count();
+    let (command_tx, command_rx) = mpsc::channel(commands.max(1));
+
+    trader
+        .data(ReplayFeed {
+            inputs,
+            clock: clock.clone(),
+            command_tx,
+        })
+        .execution(ReplayExecution {
+            responses,
+            session: None,
+        })
+        .command_rx(command_rx)
+        .clock(Arc::new(clock))
+        .build()?
+        .run()
+}
+
+/// Compares the deterministic fields of a recorded & a replayed [`SessionSummary`], returning
+/// a description of every field that differs. Wall-clock & latency fields are not compared,
+/// and [`Transition`](super::transition::Transition)s are compared without their time.
+pub fn replay_discrepancies<Statistic>(
+    recorded: &SessionSummary<Statistic>,
+    replayed: &SessionSummary<Statistic>,
+) -> Vec<String> {
+    let mut discrepancies = Vec::new();
+    let mut compare = |field: &str, recorded: String, replayed: String| {
+        if recorded != replayed {
+            discrepancies.push(format!("{field}: recorded {recorded}, replayed {replayed}"));
+        }
+    };
+
+    compare(
+        "market",
+        format!("{:?}", recorded.market),
+        format!("{:?}", replayed.market),
+    );
+    compare(
+        "market_events",
+        recorded.market_events.to_string(),
+        replayed.market_events.to_string(),
+    );
+    compare(
+        "orders",
+        recorded.orders.to_string(),
+        replayed.orders.to_string(),
+    );
+    compare(
+        "fills",
+        recorded.fills.to_string(),
+        replayed.fills.to_string(),
+    );
+    compare(
+        "realised_profit_loss",
+        recorded.realised_profit_loss.to_string(),
+        replayed.realised_profit_loss.to_string(),
+    );
+    compare(
+        "orphan_fills",
+        recorded.orphan_fills.to_string(),
+        replayed.orphan_fills.to_string(),
+    );
+    compare(
+        "foreign_account_fills",
+        recorded.foreign_account_fills.to_string(),
+        replayed.foreign_account_fills.to_string(),
+    );
+    compare(
+        "duplicate_fills",
+        recorded.duplicate_fills.to_string(),
+        replayed.duplicate_fills.to_string(),
+    );
+
+    let transitions = |summary: &SessionSummary<Statistic>| {
+        let transitions = summary
+            .transitions
+            .iter()
+            .map(|transition| {
+                (
+                    transition.from_state,
+                    transition.to_state,
+                    transition.trigger,
+                )
+            })
+            .collect::<Vec<_>>();
+        format!("{transitions:?}")
+    };
+    compare("transitions", transitions(recorded), transitions(replayed));
+    compare(
+        "digest",
+        format!("{:?}", recorded.digest),
+        format!("{:?}", replayed.digest),
+    );
+
+    discrepancies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::{digest::DeterminismDigest, trader::Trader},
+        event::EventTx,
+        execution::simulated::{Config as ExecutionConfig, SimulatedExecution},
+        portfolio::{
+            allocator::DefaultAllocator, portfolio::MetaPortfolio,
+            repository::in_memory::InMemoryRepository, risk::DefaultRisk, ManualOrderRequest,
+            OrderTags, TimeInForce,
+        },
+        statistic::summary::trading::{Config as StatisticConfig, TradingSummary},
+        strategy::example::{Config as StrategyConfig, RSIStrategy},
+        test_util::market_event_trade,
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Market, Side};
+    use std::time::Duration;
+
+    type TestPortfolio = MetaPortfolio<
+        InMemoryRepository<TradingSummary>,
+        DefaultAllocator,
+        DefaultRisk,
+        TradingSummary,
+    >;
+
+    type TestTraderBuilder<Data, Execution> =
+        TraderBuilder<EventTx, TradingSummary, TestPortfolio, Data, RSIStrategy, Execution>;
+
+    /// Step of a [`LiveLikeFeed`].
+    enum FeedStep {
+        Market(MarketEvent<DataKind>),
+        Command(Command),
+    }
+
+    /// Feed standing in for a live feed, yielding each [`FeedStep`] in turn. [`Command`] steps
+    /// are sent to the [`Trader`] via an idle poll, as if received from a remote operator.
+    struct LiveLikeFeed {
+        steps: VecDeque<FeedStep>,
+        command_tx: mpsc::Sender<Command>,
+    }
+
+    impl MarketGenerator<MarketEvent<DataKind>> for LiveLikeFeed {
+        fn next(&mut self) -> Feed<MarketEvent<DataKind>> {
+            match self.steps.pop_front() {
+                Some(FeedStep::Market(market)) => Feed::Next(market),
+                Some(FeedStep::Command(command)) => {
+                    self.command_tx.try_send(command).unwrap();
+                    Feed::Idle
+                }
+                None => Feed::Finished,
+            }
+        }
+    }
+
+    fn market() -> Market {
+        Market::new("binance_spot", ("btc", "usdt", InstrumentKind::Spot))
+    }
+
+    fn manual_order(side: Side) -> Command {
+        let market = market();
+        Command::ManualOrder(ManualOrderRequest {
+            exchange: market.exchange,
+            instrument: market.instrument,
+            side,
+            quantity: Decimal::ONE,
+            notional: None,
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::default(),
+            trigger: None,
+            tags: OrderTags::default(),
+        })
+    }
+
+    fn market_at(price: f64) -> MarketEvent<DataKind> {
+        let mut market_event = market_event_trade(Side::Buy);
+        if let DataKind::Trade(trade) = &mut market_event.kind {
+            trade.price = price;
+        }
+        market_event
+    }
+
+    /// [`TraderBuilder`] of a fresh [`Trader`] & Portfolio of the provided engine.
+    fn trader_builder<Data, Execution>(engine_id: Uuid) -> TestTraderBuilder<Data, Execution>
+    where
+        Data: MarketGenerator<MarketEvent<DataKind>> + Send,
+        Execution: ExecutionClient + Send,
+    {
+        let portfolio = MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market()])
+            .starting_cash(Decimal::from(10_000))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: Decimal::ONE_HUNDRED,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .unwrap();
+
+        Trader::builder()
+            .engine_id(engine_id)
+            .market(market())
+            .event_tx(EventTx::new(mpsc::unbounded_channel().0))
+            .portfolio(Arc::new(Mutex::new(portfolio)))
+            .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+            .fill_cooldown(Duration::from_secs(60))
+            .determinism_digest(DeterminismDigest::new())
+    }
+
+    #[test]
+    fn replay_from_log_should_reproduce_summary_of_recorded_session() {
+        let directory = std::env::temp_dir().join(format!("barter-replay-{}", Uuid::new_v4()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("session.jsonl");
+
+        // Record a live-like session entering & exiting a Position via remote Commands
+        let engine_id = Uuid::new_v4();
+        let recorder = SessionRecorder::create(&path).unwrap();
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let recorded = trader_builder(engine_id)
+            .command_rx(command_rx)
+            .data(LiveLikeFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_at(1000.0)),
+                    FeedStep::Command(manual_order(Side::Buy)),
+                    FeedStep::Market(market_at(1010.0)),
+                    FeedStep::Command(Command::Pause),
+                    FeedStep::Market(market_at(1020.0)),
+                    FeedStep::Command(Command::Resume),
+                    FeedStep::Command(manual_order(Side::Sell)),
+                    FeedStep::Market(market_at(1030.0)),
+                ]),
+                command_tx,
+            })
+            .execution(RecordingExecution::new(
+                SimulatedExecution::new(ExecutionConfig::default()),
+                recorder.clone(),
+            ))
+            .session_recorder(recorder)
+            .build()
+            .unwrap()
+            .run()
+            .unwrap();
+        assert_eq!(recorded.orders, 2);
+        assert_ne!(recorded.realised_profit_loss, Decimal::ZERO);
+
+        // Replay the session log against a fresh Trader & Portfolio
+        let replayed = replay_from_log(&path, trader_builder(engine_id)).unwrap();
+
+        assert_eq!(
+            replay_discrepancies(&recorded, &replayed),
+            Vec::<String>::new()
+        );
+        assert_eq!(replayed.digest, recorded.digest);
+
+        // Sessions that diverge are reported as discrepancies
+        let diverged = SessionSummary {
+            orders: 1,
+            ..replay_from_log(&path, trader_builder(engine_id)).unwrap()
+        };
+        assert_eq!(
+            replay_discrepancies(&recorded, &diverged),
+            vec!["orders: recorded 2, replayed 1".to_owned()]
+        );
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
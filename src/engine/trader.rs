@@ -1,18 +1,64 @@
-use super::{error::EngineError, Command};
+use super::{
+    calendar::TradingCalendar,
+    checkpoint::{CheckpointRequester, TraderCheckpoint},
+    circuit::{CircuitBreaker, CircuitMetric},
+    digest::DeterminismDigest,
+    error::EngineError,
+    hook::FillHooks,
+    replay::SessionRecorder,
+    transition::{LivelockWatchdog, TraderState, Transition, TransitionLog, TransitionTrigger},
+    Command, CommandOutcome, CommandResult,
+};
 use crate::{
-    data::{Feed, MarketGenerator},
+    clock::{Clock, LiveClock},
+    data::{bar::BarAggregator, reorder::ReorderBuffer, Feed, MarketGenerator, MarketMeta},
     event::{Event, MessageTransmitter},
-    execution::ExecutionClient,
-    portfolio::{FillUpdater, MarketUpdater, OrderGenerator},
-    strategy::{SignalForceExit, SignalGenerator},
+    execution::{
+        error::ExecutionError,
+        filter::InstrumentFilters,
+        order_id::{ClientOrderId, MonotonicOrderIdGenerator, OrderIdGenerator},
+        order_state::{OrderState, OrderStates},
+        rate_limit::RateLimiter,
+        retry::RetryPolicy,
+        AccountId, ExecutionClient, Fees, FillEvent, OrderRejection,
+    },
+    portfolio::{
+        decimal_from_f64,
+        equity::{EquityRecorder, EquitySample},
+        funding::FundingModel,
+        margin::MarginModel,
+        position::{determine_position_id, Position, PositionEnterer},
+        repository::{error::RepositoryError, BalanceHandler, PositionHandler},
+        risk::CashGuard,
+        self_match::{is_self_match, net_self_match},
+        stop::StopManager,
+        Balance, FillUpdater, ManualOrderRequest, MarketUpdater, OrderEvent, OrderGenerator,
+        OrderTags, OrderType, PortfolioSnapshot, TimeInForce,
+    },
+    statistic::{
+        metric::latency::LatencyHistogram,
+        trade::{InstrumentStats, StrategyStats, Trade, TradeLedger, TradeStats},
+    },
+    strategy::{Decision, SignalForceExit, SignalGenerator},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::Market;
+use barter_integration::model::{instrument::Instrument, Market};
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
-use serde::Serialize;
-use std::{collections::VecDeque, fmt::Debug, marker::PhantomData, sync::Arc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Debug,
+    future::Future,
+    marker::PhantomData,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tokio::sync::mpsc;
-use tracing::{debug, info, warn};
+use tracing::{debug, debug_span, error, info, warn};
 use uuid::Uuid;
 
 /// Lego components for constructing a [`Trader`] via the new() constructor method.
@@ -31,6 +77,8 @@ where
     pub engine_id: Uuid,
     /// Communicates the unique [`Market`] this [`Trader`] is bartering on.
     pub market: Market,
+    /// [`AccountId`] of the exchange account (or sub-account) every [`OrderEvent`] is executed on.
+    pub account: AccountId,
     /// mpsc::Receiver for receiving [`Command`]s from a remote source.
     pub command_rx: mpsc::Receiver<Command>,
     /// [`Event`] transmitter for sending every [`Event`] the [`Trader`] encounters to an external sink.
@@ -44,6 +92,90 @@ where
     pub strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     pub execution: Execution,
+    /// [`Clock`] used to tell the time, advanced to the exchange timestamp of every
+    /// [`MarketEvent`] consumed.
+    pub clock: Arc<dyn Clock + Send + Sync>,
+    /// [`OrderIdGenerator`] used to assign a unique [`ClientOrderId`] to every [`OrderEvent`].
+    pub order_id_generator: Arc<dyn OrderIdGenerator + Send + Sync>,
+    /// Optional interval of [`Clock`] time without any market or fill events after which the
+    /// [`Trader`] sends an [`Event::Heartbeat`].
+    pub heartbeat_interval: Option<Duration>,
+    /// Optional interval of wall-clock time without a [`MarketEvent`] of the [`Market`] after
+    /// which the market data is deemed stale & the kill switch is armed.
+    pub stale_data_timeout: Option<Duration>,
+    /// [`StopManager`] used to exit open Positions that cross a stop-loss or take-profit.
+    pub stop_manager: StopManager,
+    /// [`MarginModel`] used to check entry orders against the available margin & to liquidate
+    /// under-margined Positions.
+    pub margin_model: MarginModel,
+    /// [`FundingModel`] used to settle the funding of the open perpetual Position at each
+    /// funding time.
+    pub funding_model: FundingModel,
+    /// Optional [`RateLimiter`] capping the rate [`OrderEvent`]s are sent for execution.
+    pub rate_limiter: Option<RateLimiter>,
+    /// Optional [`RetryPolicy`] resubmitting [`OrderEvent`]s the exchange rejected for a
+    /// transient reason.
+    pub retry_policy: Option<RetryPolicy>,
+    /// Optional [`InstrumentFilters`] of the [`Market`] every [`OrderEvent`] is normalised to
+    /// before it is sent for execution.
+    pub instrument_filters: Option<InstrumentFilters>,
+    /// Optional hard cap on the absolute net Position quantity of the [`Market`].
+    pub position_cap: Option<Decimal>,
+    /// Optional [`CashGuard`] checking buy [`OrderEvent`]s against the available quote asset
+    /// cash before they are sent for execution.
+    pub cash_guard: Option<CashGuard>,
+    /// [`TradingCalendar`] of each [`Instrument`] with defined trading hours, outside of which
+    /// opening orders are refused.
+    pub trading_calendars: HashMap<Instrument, TradingCalendar>,
+    /// Absolute difference between the internal & exchange Position quantity tolerated by a
+    /// [`Command::Reconcile`] before the internal Position is corrected.
+    pub reconcile_tolerance: Decimal,
+    /// Optional [`Clock`] duration after a fill on an [`Instrument`] during which Signals for that
+    /// [`Instrument`] are dropped.
+    pub fill_cooldown: Option<Duration>,
+    /// Optional [`Clock`] duration a Position must be held before a Signal may reduce or flatten
+    /// it.
+    pub min_holding_time: Option<Duration>,
+    /// [`TradeLedger`] pairing every applied [`FillEvent`] into completed
+    /// [`Trade`]s, which the [`SessionSummary`] breakdowns are
+    /// derived from.
+    pub trade_ledger: TradeLedger,
+    /// Optional [`TransitionLog`] every [`TraderState`] [`Transition`] is recorded to.
+    pub transition_log: Option<TransitionLog>,
+    /// Optional [`DeterminismDigest`] every [`Transition`], [`OrderEvent`] & [`FillEvent`] is
+    /// hashed into.
+    pub determinism_digest: Option<DeterminismDigest>,
+    /// Optional [`BarAggregator`] the [`MarketEvent`]s of interest are aggregated into, so the
+    /// Strategy is only invoked with completed bars.
+    pub bar_aggregator: Option<BarAggregator>,
+    /// Optional [`ReorderBuffer`] the consumed [`MarketEvent`]s are held in, so they are
+    /// processed in exchange timestamp order.
+    pub reorder_buffer: Option<ReorderBuffer>,
+    /// Optional [`SessionRecorder`] every [`MarketEvent`] consumed & [`Command`] received is
+    /// recorded to.
+    pub session_recorder: Option<SessionRecorder>,
+    /// [`LivelockWatchdog`] terminating the trading loop if it keeps transitioning between
+    /// [`TraderState`]s without consuming a [`MarketEvent`].
+    pub livelock_watchdog: LivelockWatchdog,
+    /// [`PanicPolicy`] determining how the trading loop handles a panic of the Strategy or the
+    /// Portfolio risk manager.
+    pub panic_policy: PanicPolicy,
+    /// Time the trading loop keeps handling [`FillEvent`]s after a panic under
+    /// [`PanicPolicy::Terminate`], waiting for the open Position to be flattened before
+    /// terminating regardless.
+    pub panic_flatten_timeout: Duration,
+    /// Optional [`CircuitBreaker`] halting new orders once the order submission or fill rate
+    /// exceeds it's ceiling.
+    pub circuit_breaker: Option<CircuitBreaker>,
+    /// Number of [`MarketEvent`]s consumed to warm up the Strategy before it may trade.
+    pub warm_up: usize,
+    /// Whether opposing [`OrderEvent`]s that would self-match on the exchange are netted before
+    /// they are sent for execution.
+    pub self_match_prevention: bool,
+    /// Optional [`EquityRecorder`] the Portfolio equity is recorded to on every revaluation.
+    pub equity_recorder: Option<EquityRecorder>,
+    /// Optional [`FillHooks`] invoked with every [`FillEvent`] applied to the Portfolio.
+    pub fill_hooks: Option<FillHooks>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -67,6 +199,9 @@ where
     engine_id: Uuid,
     /// Communicates the unique [`Market`] this [`Trader`] is bartering on.
     market: Market,
+    /// [`AccountId`] of the exchange account (or sub-account) every [`OrderEvent`] is executed
+    /// on. Only [`FillEvent`]s of this account are applied to the Portfolio.
+    account: AccountId,
     /// `mpsc::Receiver` for receiving [`Command`]s from a remote source.
     command_rx: mpsc::Receiver<Command>,
     /// [`Event`] transmitter for sending every [`Event`] the [`Trader`] encounters to an external
@@ -74,6 +209,16 @@ where
     event_tx: EventTx,
     /// Queue for storing [`Event`]s used by the trading loop in the run() method.
     event_q: VecDeque<Event>,
+    /// [`MarketMeta`] of the latest [`MarketEvent`] consumed, used to price manual market orders.
+    latest_market_meta: Option<MarketMeta>,
+    /// [`SessionSummary`] accumulated by the trading loop & returned when the [`Trader`] stops.
+    session: SessionSummary<Statistic>,
+    /// Every in-flight [`OrderEvent`] & the time it was dispatched to the [`ExecutionClient`],
+    /// keyed by it's [`ClientOrderId`]. Used to calculate order round-trip latency.
+    pending_orders: HashMap<ClientOrderId, PendingOrder>,
+    /// [`OrderState`] of every [`OrderEvent`] sent for execution, including recently finished
+    /// [`OrderEvent`]s, fetched via [`Command::FetchOrderState`].
+    order_states: OrderStates,
     /// Shared-access to a global Portfolio instance that implements [`MarketUpdater`],
     /// [`OrderGenerator`] & [`FillUpdater`].
     portfolio: Arc<Mutex<Portfolio>>,
@@ -83,6 +228,156 @@ where
     strategy: Strategy,
     /// Execution handler that implements [`ExecutionClient`].
     execution: Execution,
+    /// [`Clock`] used to tell the time, advanced to the exchange timestamp of every
+    /// [`MarketEvent`] consumed.
+    clock: Arc<dyn Clock + Send + Sync>,
+    /// [`OrderIdGenerator`] used to assign a unique [`ClientOrderId`] to every [`OrderEvent`].
+    order_id_generator: Arc<dyn OrderIdGenerator + Send + Sync>,
+    /// Interval of [`Clock`] time without any market or fill events after which an
+    /// [`Event::Heartbeat`] is sent. Heartbeats are disabled if `None`.
+    heartbeat_interval: Option<Duration>,
+    /// Time the latest market or fill event was consumed.
+    last_event_at: DateTime<Utc>,
+    /// Time the latest [`Heartbeat`] was sent.
+    last_heartbeat_at: DateTime<Utc>,
+    /// Interval of wall-clock time without a [`MarketEvent`] of the [`Market`] after which the
+    /// market data is deemed stale & the kill switch is armed. Disabled if `None`, and never
+    /// fires when the [`Clock`] is simulated (eg/ in a backtest).
+    stale_data_timeout: Option<Duration>,
+    /// Wall-clock time the latest [`MarketEvent`] of the [`Market`] was received.
+    last_market_received_at: Instant,
+    /// [`StopManager`] used to exit open Positions that cross a stop-loss or take-profit.
+    stop_manager: StopManager,
+    /// [`MarginModel`] used to check entry orders against the available margin & to liquidate
+    /// under-margined Positions.
+    margin_model: MarginModel,
+    /// [`FundingModel`] used to settle the funding of the open perpetual Position at each
+    /// funding time.
+    funding_model: FundingModel,
+    /// [`Clock`] time funding has been settled up to, or `None` until the first [`MarketEvent`].
+    funded_until: Option<DateTime<Utc>>,
+    /// Flag to communicate a stop or liquidation has been triggered & the open Position is being
+    /// exited, so the exit is not repeated on every market price update until the Position is
+    /// exited.
+    exit_pending: bool,
+    /// Flag to communicate trading is paused via [`Command::Pause`], so no new orders are
+    /// generated until a [`Command::Resume`] is received.
+    paused: bool,
+    /// Flag to communicate the kill switch has been armed via [`Command::KillSwitch`], so every
+    /// new order is rejected (including [`ManualOrderRequest`]s) until a [`Command::Resume`] is
+    /// received.
+    kill_switch: bool,
+    /// Optional [`RateLimiter`] capping the rate [`OrderEvent`]s are sent for execution.
+    rate_limiter: Option<RateLimiter>,
+    /// Optional [`RetryPolicy`] resubmitting [`OrderEvent`]s the exchange rejected for a
+    /// transient reason, whereas terminal rejections are only surfaced.
+    retry_policy: Option<RetryPolicy>,
+    /// [`OrderEvent`]s rejected for a transient reason, awaiting resubmission once their backoff
+    /// elapses.
+    order_retries: Vec<OrderRetry>,
+    /// Number of retries made by each resubmitted [`OrderEvent`] yet to be sent for execution,
+    /// keyed by it's fresh [`ClientOrderId`].
+    resubmitted: HashMap<ClientOrderId, usize>,
+    /// Optional [`InstrumentFilters`] of the [`Market`] every [`OrderEvent`] is normalised to
+    /// before it is sent for execution.
+    instrument_filters: Option<InstrumentFilters>,
+    /// Optional hard cap on the absolute net Position quantity of the [`Market`], refusing any
+    /// [`OrderEvent`] that would breach it.
+    position_cap: Option<Decimal>,
+    /// Optional [`CashGuard`] downsizing or refusing buy [`OrderEvent`]s that would over-spend
+    /// the available quote asset cash.
+    cash_guard: Option<CashGuard>,
+    /// [`TradingCalendar`] of each [`Instrument`] with defined trading hours, refusing opening
+    /// [`OrderEvent`]s outside the session & optionally flattening the open Position before the
+    /// session closes.
+    trading_calendars: HashMap<Instrument, TradingCalendar>,
+    /// Absolute difference between the internal & exchange Position quantity tolerated by a
+    /// [`Command::Reconcile`] before the internal Position is corrected to match the exchange.
+    reconcile_tolerance: Decimal,
+    /// Exchange assigned identifiers of the most recently applied [`FillEvent`]s, so a fill
+    /// received twice is not double counted.
+    applied_fills: AppliedFills,
+    /// Optional [`Clock`] duration after a fill on an [`Instrument`] during which Signals for that
+    /// [`Instrument`] are dropped, preventing overtrading.
+    fill_cooldown: Option<Duration>,
+    /// Time until which Signals for each [`Instrument`] are dropped, set by the latest fill on
+    /// that [`Instrument`].
+    cooldowns: HashMap<Instrument, DateTime<Utc>>,
+    /// Optional [`Clock`] duration a Position must be held before a Signal may reduce or flatten
+    /// it, preventing immediate churn.
+    min_holding_time: Option<Duration>,
+    /// Signal exit [`OrderEvent`] deferred until the open Position, entered at the paired time,
+    /// reaches the minimum holding time.
+    deferred_exit: Option<(DateTime<Utc>, OrderEvent)>,
+    /// Current [`TraderState`] of the trading loop.
+    state: TraderState,
+    /// [`TradeLedger`] pairing every applied [`FillEvent`] into completed
+    /// [`Trade`]s, each folded into the [`SessionSummary`] per
+    /// [`Instrument`] & per strategy breakdowns as it completes.
+    trade_ledger: TradeLedger,
+    /// Optional [`TransitionLog`] every [`TraderState`] [`Transition`] is recorded to, included
+    /// in the [`SessionSummary`] once the [`Trader`] stops.
+    transition_log: Option<TransitionLog>,
+    /// Optional [`DeterminismDigest`] every [`Transition`], executed [`OrderEvent`] & applied
+    /// [`FillEvent`] is hashed into, included in the [`SessionSummary`] once the [`Trader`] stops.
+    determinism_digest: Option<DeterminismDigest>,
+    /// Optional [`BarAggregator`] the [`MarketEvent`]s of interest are aggregated into, so the
+    /// Strategy is only invoked with completed bars. The Portfolio is still updated by every
+    /// [`MarketEvent`].
+    bar_aggregator: Option<BarAggregator>,
+    /// Optional [`ReorderBuffer`] every [`MarketEvent`] consumed from the [`MarketGenerator`] is
+    /// held in until released in exchange timestamp order.
+    reorder_buffer: Option<ReorderBuffer>,
+    /// Optional [`SessionRecorder`] every [`MarketEvent`] consumed & [`Command`] received is
+    /// recorded to, so the trading session can be replayed.
+    session_recorder: Option<SessionRecorder>,
+    /// [`OrderEvent`]s throttled by the [`RateLimiter`], queued in the order they were generated
+    /// until tokens refill.
+    throttled_orders: VecDeque<OrderEvent>,
+    /// Conditional [`OrderEvent`]s held inactive until the market price meets their
+    /// [`Trigger`](crate::portfolio::Trigger), in the order they were submitted.
+    triggered_orders: Vec<OrderEvent>,
+    /// Open legs of one-cancels-other groups submitted via [`Command::SubmitOco`], keyed by
+    /// their [`ClientOrderId`].
+    oco_legs: HashMap<ClientOrderId, OcoLeg>,
+    /// [`LivelockWatchdog`] counting the consecutive [`Transition`]s without a [`MarketEvent`]
+    /// consumed.
+    livelock_watchdog: LivelockWatchdog,
+    /// [`TraderState`] pair of the [`Transition`] that tripped the [`LivelockWatchdog`], if any.
+    livelock: Option<(TraderState, TraderState)>,
+    /// [`PanicPolicy`] applied when the Strategy or the Portfolio risk manager panics.
+    panic_policy: PanicPolicy,
+    /// Time waited for the open Position to be flattened after a panic under
+    /// [`PanicPolicy::Terminate`], before terminating regardless.
+    panic_flatten_timeout: Duration,
+    /// Description & [`Clock`] time of the panic that is terminating the trading loop once the
+    /// open Position is flattened, if any.
+    panicked: Option<(String, DateTime<Utc>)>,
+    /// Optional [`CircuitBreaker`] tracking the order submission & fill rates of the trading
+    /// loop.
+    circuit_breaker: Option<CircuitBreaker>,
+    /// Whether the [`CircuitBreaker`] has tripped, refusing every order other than exits until
+    /// a [`Command::Resume`] is received.
+    circuit_tripped: bool,
+    /// Number of [`MarketEvent`]s remaining until the Strategy is warmed up. Signals generated
+    /// whilst warming up are ignored, and paused [`MarketEvent`]s do not count.
+    warm_up: usize,
+    /// Flag to communicate opposing [`OrderEvent`]s queued for execution that would self-match
+    /// on the exchange are netted internally, so only the residual is sent for execution.
+    self_match_prevention: bool,
+    /// Optional [`EquityRecorder`] the Portfolio equity is recorded to on every revaluation.
+    equity_recorder: Option<EquityRecorder>,
+    /// Optional [`CheckpointRequester`] counting the [`Event`]s handled, requesting a
+    /// [`Checkpoint`](super::checkpoint::Checkpoint) from the [`Engine`](super::Engine) every
+    /// configured number of [`Event`]s.
+    checkpoint_requester: Option<CheckpointRequester>,
+    /// Optional [`FillHooks`] spawned with every [`FillEvent`] applied to the Portfolio, so
+    /// their side-effects never block the trading loop.
+    fill_hooks: Option<FillHooks>,
+    /// [`Market`]s whose open Positions are valued by the [`EquityRecorder`].
+    equity_markets: Vec<Market>,
+    /// [`Instrument`]s the Strategy is interested in, or `None` if every [`Instrument`].
+    instruments_of_interest: Option<HashSet<Instrument>>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -91,7 +386,7 @@ impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
 where
     EventTx: MessageTransmitter<Event>,
     Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater + PositionHandler + BalanceHandler,
     Data: MarketGenerator<MarketEvent<DataKind>> + Send,
     Strategy: SignalGenerator + Send,
     Execution: ExecutionClient + Send,
@@ -104,18 +399,202 @@ where
             "constructed new Trader instance"
         );
 
+        let instruments_of_interest = lego.strategy.instruments_of_interest();
         Self {
             engine_id: lego.engine_id,
+            session: SessionSummary::new(lego.market.clone()),
+            equity_markets: vec![lego.market.clone()],
             market: lego.market,
+            account: lego.account,
             command_rx: lego.command_rx,
             event_tx: lego.event_tx,
             event_q: VecDeque::with_capacity(4),
+            latest_market_meta: None,
+            pending_orders: HashMap::new(),
+            order_states: OrderStates::default(),
             portfolio: lego.portfolio,
             data: lego.data,
             strategy: lego.strategy,
             execution: lego.execution,
-            _statistic_marker: PhantomData::default(),
+            clock: lego.clock,
+            order_id_generator: lego.order_id_generator,
+            heartbeat_interval: lego.heartbeat_interval,
+            stale_data_timeout: lego.stale_data_timeout,
+            last_market_received_at: Instant::now(),
+            last_event_at: Utc::now(),
+            last_heartbeat_at: Utc::now(),
+            stop_manager: lego.stop_manager,
+            margin_model: lego.margin_model,
+            funding_model: lego.funding_model,
+            funded_until: None,
+            exit_pending: false,
+            paused: false,
+            kill_switch: false,
+            rate_limiter: lego.rate_limiter,
+            retry_policy: lego.retry_policy,
+            order_retries: Vec::new(),
+            resubmitted: HashMap::new(),
+            instrument_filters: lego.instrument_filters,
+            position_cap: lego.position_cap,
+            cash_guard: lego.cash_guard,
+            trading_calendars: lego.trading_calendars,
+            reconcile_tolerance: lego.reconcile_tolerance,
+            applied_fills: AppliedFills::default(),
+            fill_cooldown: lego.fill_cooldown,
+            cooldowns: HashMap::new(),
+            min_holding_time: lego.min_holding_time,
+            deferred_exit: None,
+            state: TraderState::Trading,
+            trade_ledger: lego.trade_ledger,
+            transition_log: lego.transition_log,
+            determinism_digest: lego.determinism_digest,
+            bar_aggregator: lego.bar_aggregator,
+            reorder_buffer: lego.reorder_buffer,
+            session_recorder: lego.session_recorder,
+            throttled_orders: VecDeque::new(),
+            triggered_orders: Vec::new(),
+            oco_legs: HashMap::new(),
+            livelock_watchdog: lego.livelock_watchdog,
+            livelock: None,
+            panic_policy: lego.panic_policy,
+            panic_flatten_timeout: lego.panic_flatten_timeout,
+            circuit_breaker: lego.circuit_breaker,
+            circuit_tripped: false,
+            panicked: None,
+            warm_up: lego.warm_up,
+            self_match_prevention: lego.self_match_prevention,
+            equity_recorder: lego.equity_recorder,
+            checkpoint_requester: None,
+            fill_hooks: lego.fill_hooks,
+            instruments_of_interest,
+            _statistic_marker: PhantomData,
+        }
+    }
+
+    /// Returns the unique [`Market`] this [`Trader`] is bartering on.
+    pub fn market(&self) -> &Market {
+        &self.market
+    }
+
+    /// Returns a handle to the [`Clock`] this [`Trader`] tells the time with.
+    pub(super) fn clock(&self) -> Arc<dyn Clock + Send + Sync> {
+        Arc::clone(&self.clock)
+    }
+
+    /// Replaces the [`OrderIdGenerator`] of this [`Trader`], which defaults to a
+    /// [`MonotonicOrderIdGenerator`] when built via the [`TraderBuilder`]. Used by the
+    /// [`EngineBuilder`](super::EngineBuilder) to share one [`OrderIdGenerator`] between every
+    /// [`Trader`] of an [`Engine`](super::Engine).
+    pub(super) fn set_order_id_generator(
+        &mut self,
+        order_id_generator: Arc<dyn OrderIdGenerator + Send + Sync>,
+    ) {
+        self.order_id_generator = order_id_generator;
+    }
+
+    /// Restores the Strategy state & the open, throttled & conditional [`OrderEvent`]s of a
+    /// [`TraderCheckpoint`] taken before a restart. Open [`OrderEvent`]s are re-registered with
+    /// the [`ExecutionClient`] via [`ExecutionClient::restore_order`].
+    pub(super) fn restore(&mut self, checkpoint: TraderCheckpoint) -> Result<(), EngineError> {
+        // Checkpoints taken before Strategy snapshots were persisted have a null snapshot
+        if !checkpoint.strategy.is_null() {
+            self.strategy.restore(checkpoint.strategy)?;
         }
+        for order in checkpoint.open_orders {
+            self.execution.restore_order(&order);
+            self.order_states.new_order(order.cid, order.quantity);
+            self.order_states.acknowledge(&order.cid);
+            self.pending_orders.insert(
+                order.cid,
+                PendingOrder {
+                    dispatched_at: self.clock.now(),
+                    order,
+                    filled: Decimal::ZERO,
+                    retries: 0,
+                },
+            );
+        }
+        self.throttled_orders.extend(checkpoint.throttled_orders);
+        self.triggered_orders.extend(checkpoint.triggered_orders);
+        Ok(())
+    }
+
+    /// Replaces the market data [`MarketGenerator`] of this [`Trader`]. Used by the
+    /// [`EngineBuilder`](super::EngineBuilder) to subscribe every [`Trader`] to it's market
+    /// event stream.
+    pub(super) fn set_data(&mut self, data: Data) {
+        self.data = data;
+    }
+
+    /// Replaces the [`AccountId`] of this [`Trader`]. Used by the
+    /// [`EngineBuilder`](super::EngineBuilder) to route the [`OrderEvent`]s of every [`Trader`]
+    /// to it's default or overriding account.
+    pub(super) fn set_account(&mut self, account: AccountId) {
+        self.account = account;
+    }
+
+    /// Replaces the [`TransitionLog`] of this [`Trader`]. Used by the
+    /// [`EngineBuilder`](super::EngineBuilder) to record the [`Transition`]s of every [`Trader`]
+    /// of an [`Engine`](super::Engine).
+    pub(super) fn set_transition_log(&mut self, transition_log: TransitionLog) {
+        self.transition_log = Some(transition_log);
+    }
+
+    /// Replaces the [`DeterminismDigest`] of this [`Trader`]. Used by the
+    /// [`EngineBuilder`](super::EngineBuilder) to hash the session of every [`Trader`] of an
+    /// [`Engine`](super::Engine).
+    pub(super) fn set_determinism_digest(&mut self, digest: DeterminismDigest) {
+        self.determinism_digest = Some(digest);
+    }
+
+    /// Replaces the [`Clock`] of this [`Trader`]. Used by the
+    /// [`EngineBuilder`](super::EngineBuilder) to run every [`Trader`] of an
+    /// [`Engine`](super::Engine) against a live or simulated [`Clock`].
+    pub(super) fn set_clock(&mut self, clock: Arc<dyn Clock + Send + Sync>) {
+        self.clock = clock;
+    }
+
+    /// Replaces the stale data timeout of this [`Trader`]. Used by the
+    /// [`EngineBuilder`](super::EngineBuilder) to arm the dead-man's switch of every [`Trader`]
+    /// of an [`Engine`](super::Engine).
+    pub(super) fn set_stale_data_timeout(&mut self, timeout: Duration) {
+        self.stale_data_timeout = Some(timeout);
+    }
+
+    /// Replaces the [`PanicPolicy`] of this [`Trader`]. Used by the
+    /// [`EngineBuilder`](super::EngineBuilder) to apply one [`PanicPolicy`] to every [`Trader`]
+    /// of an [`Engine`](super::Engine).
+    pub(super) fn set_panic_policy(&mut self, panic_policy: PanicPolicy) {
+        self.panic_policy = panic_policy;
+    }
+
+    /// Replaces the [`RateLimiter`] of this [`Trader`]. Used by the
+    /// [`EngineBuilder`](super::EngineBuilder) to share one [`RateLimiter`] between every
+    /// [`Trader`] of an [`Engine`](super::Engine).
+    pub(super) fn set_rate_limiter(&mut self, rate_limiter: RateLimiter) {
+        self.rate_limiter = Some(rate_limiter);
+    }
+
+    /// Replaces the [`EquityRecorder`] of this [`Trader`], valuing the open Positions of every
+    /// provided [`Market`]. Used by the [`EngineBuilder`](super::EngineBuilder) to record one
+    /// equity curve of the whole Portfolio across every [`Trader`] of an
+    /// [`Engine`](super::Engine).
+    pub(super) fn set_equity_recorder(
+        &mut self,
+        equity_recorder: EquityRecorder,
+        markets: Vec<Market>,
+    ) {
+        self.equity_recorder = Some(equity_recorder);
+        self.equity_markets = markets;
+    }
+
+    /// Replaces the [`CheckpointRequester`] of this [`Trader`]. Used by the
+    /// [`EngineBuilder`](super::EngineBuilder) to request a
+    /// [`Checkpoint`](super::checkpoint::Checkpoint) every
+    /// [`CheckpointConfig::every_events`](super::checkpoint::CheckpointConfig::every_events)
+    /// handled by each [`Trader`].
+    pub(super) fn set_checkpoint_requester(&mut self, requester: CheckpointRequester) {
+        self.checkpoint_requester = Some(requester);
     }
 
     /// Builder to construct [`Trader`] instances.
@@ -125,273 +604,8406 @@ where
 
     /// Run the trading event-loop for this [`Trader`] instance. Loop will run until [`Trader`]
     /// receives a [`Command::Terminate`] via the mpsc::Receiver command_rx, or the
-    /// [`MarketGenerator`] yields [`Feed::Finished`].
-    pub fn run(mut self) {
+    /// [`MarketGenerator`] yields [`Feed::Finished`]. Returns the [`SessionSummary`] of the
+    /// trading session once stopped, or the [`EngineError`] that caused the [`Trader`] to
+    /// terminate early.
+    ///
+    /// The trading loop runs within a DEBUG `trader` span tagged with the `engine_id`,
+    /// `exchange` & `instrument`, and every [`Event`] is handled within a nested DEBUG `state`
+    /// span tagged with the [`Event::kind`]. [`OrderEvent`]s sent for execution & [`FillEvent`]s
+    /// received are logged at INFO with their `cid`.
+    pub fn run(mut self) -> Result<SessionSummary<Statistic>, EngineError> {
+        let _trader_span = self.span().entered();
+        self.start();
+
         // Run trading loop for this Trader instance
-        'trading: loop {
-            // Check for new remote Commands before continuing to generate another MarketEvent
-            while let Some(command) = self.receive_remote_command() {
-                match command {
-                    Command::Terminate(_) => break 'trading,
-                    Command::ExitPosition(market) => {
-                        self.event_q
-                            .push_back(Event::SignalForceExit(SignalForceExit::from(market)));
-                    }
-                    _ => continue,
-                }
+        let result = loop {
+            if let Some(result) = self.next_step(None) {
+                break result;
             }
+        };
+
+        self.stop(result)
+    }
+
+    /// Starts the trading session of this [`Trader`], sending an [`Event::TraderStarted`].
+    pub(super) fn start(&mut self) {
+        self.session.started_at = self.clock.now();
+        self.last_event_at = self.session.started_at;
+        self.last_heartbeat_at = self.session.started_at;
+        self.state = self.derive_state();
+        self.event_tx
+            .send(Event::TraderStarted(self.market.clone()));
+    }
+
+    /// Runs a single iteration of the trading loop of a started [`Trader`] (see
+    /// [`Trader::run`]): actions any remote [`Command`]s, consumes the next [`MarketEvent`] &
+    /// handles every [`Event`] it generates. Returns the [`Event`]s handled, and the result of
+    /// the trading session if the [`Trader`] stopped, in which case it must be passed to
+    /// [`Trader::stop`].
+    pub(super) fn step(&mut self) -> (Vec<Event>, Option<Result<(), EngineError>>) {
+        let _trader_span = self.span().entered();
+        let mut handled = Vec::new();
+        let stopped = self.next_step(Some(&mut handled));
+        (handled, stopped)
+    }
+
+    /// Stops the trading session of this [`Trader`] with the result of the trading loop,
+    /// sending an [`Event::TraderStopped`] & returning the [`SessionSummary`].
+    pub(super) fn stop(
+        mut self,
+        result: Result<(), EngineError>,
+    ) -> Result<SessionSummary<Statistic>, EngineError> {
+        self.session.ended_at = self.clock.now();
+        self.session.dropped_events = self.data.dropped_events();
+        if let Some(transition_log) = &self.transition_log {
+            self.session.transitions = transition_log.transitions().copied().collect();
+        }
+        self.session.digest = self.determinism_digest;
+        self.event_tx
+            .send(Event::TraderStopped(self.market.clone()));
+        result.map(|_| self.session)
+    }
+
+    /// Returns the current [`TraderState`] of the trading loop.
+    pub fn state(&self) -> TraderState {
+        self.state
+    }
 
-            // If the Feed<MarketEvent> yields, populate event_q with the next MarketEvent
-            match self.data.next() {
-                Feed::Next(market) => {
-                    self.event_tx.send(Event::Market(market.clone()));
-                    self.event_q.push_back(Event::Market(market));
+    /// Returns the [`OrderState`] of the order with the provided [`ClientOrderId`], if this
+    /// [`Trader`] tracks it.
+    pub(super) fn order_state(&self, id: &ClientOrderId) -> Option<OrderState> {
+        self.order_states.get(id)
+    }
+
+    /// DEBUG `trader` span tagged with the `engine_id`, `exchange` & `instrument`.
+    fn span(&self) -> tracing::Span {
+        debug_span!(
+            "trader",
+            engine_id = %self.engine_id,
+            exchange = %self.market.exchange,
+            instrument = %self.market.instrument,
+        )
+    }
+
+    /// Runs a single iteration of the trading loop, recording every [`Event`] handled to
+    /// `handled` if provided. Returns the result of the trading session if the [`Trader`]
+    /// stopped.
+    fn next_step(
+        &mut self,
+        mut handled: Option<&mut Vec<Event>>,
+    ) -> Option<Result<(), EngineError>> {
+        if let Some(error) = self.check_livelock() {
+            return Some(Err(error));
+        }
+
+        // Check for new remote Commands before continuing to generate another MarketEvent
+        while let Some(command) = self.receive_remote_command() {
+            match command {
+                Command::Terminate(_) => {
+                    self.transition_to(TraderState::Stopped, TransitionTrigger::Command);
+                    return Some(self.stopped());
                 }
-                Feed::Unhealthy => {
-                    warn!(
+                Command::ExitPosition(market) => {
+                    self.exit_position(market);
+                }
+                Command::ManualOrder(request) => {
+                    self.generate_manual_order(request);
+                }
+                Command::SubmitOco { take_profit, stop } => {
+                    self.submit_oco(*take_profit, *stop);
+                }
+                Command::UpdateStrategyParams { params, .. } => {
+                    self.update_strategy_params(params);
+                }
+                Command::Correlated { id, command } => {
+                    let result = match *command {
+                        Command::ExitPosition(market) => self.exit_position(market),
+                        Command::ManualOrder(request) => self.generate_manual_order(request),
+                        Command::SubmitOco { take_profit, stop } => {
+                            self.submit_oco(*take_profit, *stop)
+                        }
+                        Command::UpdateStrategyParams { params, .. } => {
+                            self.update_strategy_params(params)
+                        }
+                        _ => CommandResult::Rejected(
+                            "Command is not routed to a single Trader".to_owned(),
+                        ),
+                    };
+                    self.event_tx
+                        .send(Event::CommandOutcome(CommandOutcome { id, result }));
+                }
+                Command::ReportOutcome(outcome) => {
+                    self.event_tx.send(Event::CommandOutcome(outcome));
+                }
+                Command::CancelOrder { id } => {
+                    self.cancel_order(id);
+                }
+                Command::AmendOrder {
+                    id,
+                    new_price,
+                    new_quantity,
+                } => {
+                    self.amend_order(id, new_price, new_quantity);
+                }
+                Command::CancelAllOrders { .. } => {
+                    self.cancel_all_orders();
+                }
+                Command::FetchOrderState { id, state_tx } => {
+                    if state_tx.send(self.order_state(&id)).is_err() {
+                        warn!(
+                            engine_id = %self.engine_id,
+                            market = ?self.market,
+                            why = "oneshot receiver dropped",
+                            "cannot action Command::FetchOrderState"
+                        );
+                    }
+                }
+                Command::QueryPendingOrders(orders_tx) => {
+                    if orders_tx.send(self.queued_orders()).is_err() {
+                        warn!(
+                            engine_id = %self.engine_id,
+                            market = ?self.market,
+                            why = "oneshot receiver dropped",
+                            "cannot action Command::QueryPendingOrders"
+                        );
+                    }
+                }
+                Command::DropPendingOrder { id } => {
+                    self.drop_pending_order(id);
+                }
+                Command::FetchTraderCheckpoint(checkpoint_tx) => {
+                    if checkpoint_tx.send(self.checkpoint()).is_err() {
+                        warn!(
+                            engine_id = %self.engine_id,
+                            market = ?self.market,
+                            why = "oneshot receiver dropped",
+                            "cannot action Command::FetchTraderCheckpoint"
+                        );
+                    }
+                }
+                Command::Pause => {
+                    info!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        "Trader paused, no new orders will be generated"
+                    );
+                    self.paused = true;
+                }
+                Command::Resume => {
+                    info!(
                         engine_id = %self.engine_id,
                         market = ?self.market,
-                        action = "continuing while waiting for healthy Feed",
-                        "MarketFeed unhealthy"
+                        "Trader resumed"
                     );
-                    continue 'trading;
+                    self.paused = false;
+                    self.kill_switch = false;
+                    self.circuit_tripped = false;
+                    if let Some(breaker) = &mut self.circuit_breaker {
+                        breaker.reset();
+                    }
+                    self.last_market_received_at = Instant::now();
+                }
+                Command::KillSwitch => {
+                    self.arm_kill_switch(KillSwitchReason::Command);
+                }
+                Command::RebalanceMarket {
+                    weight,
+                    equity,
+                    result_tx,
+                } => {
+                    let result = self.rebalance(weight, equity);
+                    if let Some(result_tx) = result_tx {
+                        if result_tx.send(result).is_err() {
+                            warn!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                why = "oneshot receiver dropped",
+                                "cannot report Command::RebalanceMarket result"
+                            );
+                        }
+                    }
+                }
+                Command::Reconcile => {
+                    if let Err(error) = self.reconcile() {
+                        return Some(Err(error));
+                    }
+                }
+                _ => continue,
+            }
+            self.transition(TransitionTrigger::Command);
+            if let Some(error) = self.check_livelock() {
+                return Some(Err(error));
+            }
+        }
+
+        // If the Feed<MarketEvent> yields, populate event_q with the next MarketEvent
+        match self.data.next() {
+            Feed::Next(market) => {
+                self.livelock_watchdog.reset();
+                if let Some(recorder) = &self.session_recorder {
+                    recorder.record_market(self.clock.now(), &market);
+                }
+                if market.instrument == self.market.instrument {
+                    self.last_market_received_at = Instant::now();
+                }
+                self.session.market_events += 1;
+                match &mut self.reorder_buffer {
+                    Some(buffer) => {
+                        buffer.push(market, self.clock.now());
+                        self.release_reordered_markets();
+                    }
+                    None => self.enqueue_market(market),
+                }
+            }
+            Feed::Idle => {
+                // Continue to handle any Events generated by remote Commands
+                self.release_reordered_markets();
+                self.expire_cooldowns();
+                self.check_data_stale();
+                self.send_heartbeat_if_due();
+            }
+            Feed::Unhealthy => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    action = "continuing while waiting for healthy Feed",
+                    "MarketFeed unhealthy"
+                );
+                let released = self.release_reordered_markets();
+                self.check_data_stale();
+                self.send_heartbeat_if_due();
+                if !released {
+                    return None;
                 }
-                Feed::Finished => break 'trading,
             }
+            Feed::Finished
+                if self
+                    .reorder_buffer
+                    .as_ref()
+                    .is_some_and(|buffer| !buffer.is_empty()) =>
+            {
+                // Handle every MarketEvent still held before stopping
+                let markets = self
+                    .reorder_buffer
+                    .as_mut()
+                    .map(ReorderBuffer::drain)
+                    .unwrap_or_default();
+                for market in markets {
+                    self.enqueue_market(market);
+                }
+            }
+            Feed::Finished => {
+                // Handle the Signals of any partial bars flushed before stopping
+                let bars = self
+                    .bar_aggregator
+                    .as_mut()
+                    .map(BarAggregator::flush)
+                    .unwrap_or_default();
+                if bars.is_empty() {
+                    self.transition_to(TraderState::Stopped, TransitionTrigger::Market);
+                    return Some(self.stopped());
+                }
+                for bar in bars {
+                    self.generate_signal(&bar);
+                }
+            }
+        }
+
+        // Send any throttled OrderEvents the RateLimiter now has capacity for
+        while let Some(order) = self.next_unthrottled_order() {
+            if let Err(error) = self.execute_order(order) {
+                return Some(Err(error));
+            }
+        }
+
+        // Handle Events in the event_q
+        // '--> While loop will break when event_q is empty and requires another MarketEvent
+        while let Some(event) = self.event_q.pop_front() {
+            let _state_span = debug_span!("state", state = event.kind()).entered();
+            if let Some(handled) = handled.as_deref_mut() {
+                handled.push(event.clone());
+            }
+            if let Some(requester) = &mut self.checkpoint_requester {
+                requester.record_event();
+            }
+            match event {
+                Event::Market(market) => {
+                    self.clock.advance(market.exchange_time);
+                    if self.session.started_at == DateTime::<Utc>::MIN_UTC {
+                        // Simulated Clock left unstarted, so start at the first MarketEvent
+                        self.session.started_at = self.clock.now();
+                        self.last_heartbeat_at = self.session.started_at;
+                    }
+                    self.last_event_at = self.clock.now();
+                    self.expire_cooldowns();
+
+                    if let Some(market_meta) = MarketMeta::from_market(&market) {
+                        self.latest_market_meta = Some(market_meta);
+                        if market.instrument == self.market.instrument {
+                            self.activate_triggered_orders(market_meta);
+                        }
+                    }
+                    self.release_deferred_exit();
+                    self.release_order_retries();
 
-            // Handle Events in the event_q
-            // '--> While loop will break when event_q is empty and requires another MarketEvent
-            while let Some(event) = self.event_q.pop_front() {
-                match event {
-                    Event::Market(market) => {
-                        if let Some(signal) = self.strategy.generate_signal(&market) {
-                            self.event_tx.send(Event::Signal(signal.clone()));
-                            self.event_q.push_back(Event::Signal(signal));
+                    for fill in self.execution.fill_resting_orders(&market) {
+                        self.event_tx.send(Event::Fill(fill.clone()));
+                        self.event_q.push_back(Event::Fill(fill));
+                    }
+                    self.remove_rejected_orders();
+
+                    if self.is_of_interest(&market.instrument) {
+                        match &mut self.bar_aggregator {
+                            // Strategy is only invoked with completed bars
+                            Some(aggregator) => {
+                                if let Some(bar) = aggregator.update(&market) {
+                                    self.generate_signal(&bar);
+                                }
+                            }
+                            None => self.generate_signal(&market),
                         }
+                    }
+                    self.transition(TransitionTrigger::Market);
 
-                        if let Some(position_update) = self
-                            .portfolio
-                            .lock()
-                            .update_from_market(&market)
-                            .expect("failed to update Portfolio from market")
-                        {
+                    let position_update = self.portfolio.lock().update_from_market(&market);
+                    match position_update {
+                        Ok(Some(position_update)) => {
+                            let price = position_update.current_symbol_price;
                             self.event_tx.send(Event::PositionUpdate(position_update));
+                            self.check_stops(price);
+                            self.check_margin();
+                            self.check_session_close();
+                        }
+                        Ok(None) => {}
+                        Err(error) => {
+                            error!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                ?error,
+                                action = "terminating Trader",
+                                "failed to update Portfolio from MarketEvent"
+                            );
+                            return Some(Err(EngineError::from(error)));
+                        }
+                    }
+
+                    if let Err(error) = self.settle_funding() {
+                        error!(
+                            engine_id = %self.engine_id,
+                            market = ?self.market,
+                            ?error,
+                            action = "terminating Trader",
+                            "failed to settle funding of open Position"
+                        );
+                        return Some(Err(error));
+                    }
+
+                    self.record_equity();
+                }
+
+                Event::Signal(signal) => {
+                    if self.paused {
+                        debug!(
+                            engine_id = %self.engine_id,
+                            market = ?self.market,
+                            "Trader paused, ignoring Signal"
+                        );
+                        continue;
+                    }
+
+                    if self.is_cooling_down(&signal.instrument) {
+                        debug!(
+                            engine_id = %self.engine_id,
+                            market = ?self.market,
+                            instrument = %signal.instrument,
+                            "Instrument cooling down after fill, dropping Signal"
+                        );
+                        continue;
+                    }
+
+                    // OrderGenerator::generate_order does not mutate the Portfolio, so a panic of
+                    // the risk manager leaves it unchanged
+                    let order = catch_unwind(AssertUnwindSafe(|| {
+                        self.portfolio.lock().generate_order(&signal)
+                    }));
+                    let order = match order {
+                        Ok(order) => order,
+                        Err(payload) => {
+                            self.handle_panic("Portfolio risk manager", payload.as_ref());
+                            continue;
+                        }
+                    };
+                    match order {
+                        Ok(Some(order)) => {
+                            if let Some(order) = self.defer_early_exit(order) {
+                                self.dispatch_order(order);
+                            }
+                        }
+                        Ok(None) => {}
+                        Err(error) => {
+                            error!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                ?error,
+                                action = "terminating Trader",
+                                "failed to generate OrderEvent from Signal"
+                            );
+                            return Some(Err(EngineError::from(error)));
+                        }
+                    }
+                }
+
+                Event::SignalForceExit(signal_force_exit) => {
+                    // Forced exits supersede any Signal exit deferred by the minimum holding time
+                    self.deferred_exit = None;
+                    let order = self.portfolio.lock().generate_exit_order(signal_force_exit);
+                    match order {
+                        Ok(Some(order)) => {
+                            self.dispatch_order(order);
+                        }
+                        Ok(None) => {}
+                        Err(error) => {
+                            error!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                ?error,
+                                action = "terminating Trader",
+                                "failed to generate forced exit OrderEvent"
+                            );
+                            return Some(Err(EngineError::from(error)));
                         }
                     }
+                }
+
+                Event::OrderNew(order) => {
+                    if let Some(digest) = &mut self.determinism_digest {
+                        digest.record_order(&order);
+                    }
+
+                    let Some(order) = self.net_self_matches(order) else {
+                        continue;
+                    };
+
+                    // Queue behind any throttled OrderEvents so they are sent in order
+                    if !self.throttled_orders.is_empty() || !self.acquire_order_token() {
+                        debug!(
+                            engine_id = %self.engine_id,
+                            market = ?self.market,
+                            cid = %order.cid,
+                            "OrderEvent throttled until RateLimiter tokens refill"
+                        );
+                        self.throttled_orders.push_back(order);
+                        continue;
+                    }
+
+                    if let Err(error) = self.execute_order(order) {
+                        return Some(Err(error));
+                    }
+                }
+
+                Event::Fill(fill) => {
+                    self.last_event_at = self.clock.now();
+                    info!(
+                        cid = %fill.cid,
+                        decision = ?fill.decision,
+                        quantity = %fill.quantity,
+                        fill_value_gross = %fill.fill_value_gross,
+                        "received FillEvent"
+                    );
+
+                    // FillEvents of other accounts must not pollute this account's Portfolio
+                    if fill.account != self.account {
+                        self.session.foreign_account_fills += 1;
+                        warn!(
+                            engine_id = %self.engine_id,
+                            market = ?self.market,
+                            account = %self.account,
+                            ?fill,
+                            "dropping FillEvent of another account"
+                        );
+                        continue;
+                    }
 
-                    Event::Signal(signal) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
-                            .generate_order(&signal)
-                            .expect("failed to generate order")
-                        {
-                            self.event_tx.send(Event::OrderNew(order.clone()));
-                            self.event_q.push_back(Event::OrderNew(order));
+                    // FillEvents received twice (eg/ on an account stream reconnect) must only
+                    // be applied once
+                    if let Some(fill_id) = &fill.fill_id {
+                        if !self.applied_fills.insert(fill_id) {
+                            self.session.duplicate_fills += 1;
+                            warn!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                ?fill,
+                                "dropping duplicate FillEvent already applied"
+                            );
+                            continue;
                         }
                     }
+                    self.session.fills += 1;
+                    if let Some(digest) = &mut self.determinism_digest {
+                        digest.record_fill(&fill);
+                    }
+                    self.start_cooldown(&fill.instrument);
+                    self.record_circuit(CircuitMetric::Fills);
+                    self.transition(TransitionTrigger::Account);
 
-                    Event::SignalForceExit(signal_force_exit) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
-                            .generate_exit_order(signal_force_exit)
-                            .expect("failed to generate forced exit order")
-                        {
-                            self.event_tx.send(Event::OrderNew(order.clone()));
-                            self.event_q.push_back(Event::OrderNew(order));
+                    self.order_states.fill(&fill.cid, fill.quantity);
+                    match self.apply_pending_fill(&fill) {
+                        Some((first_fill, pending)) => {
+                            let latency = self.clock.now() - pending.dispatched_at;
+                            if first_fill && !self.session.order_latency.record(latency) {
+                                warn!(
+                                    engine_id = %self.engine_id,
+                                    market = ?self.market,
+                                    ?fill,
+                                    ?latency,
+                                    "ignoring negative order round-trip latency"
+                                );
+                            }
+                            self.update_oco_group(&fill, &pending);
+                        }
+                        None => {
+                            self.session.orphan_fills += 1;
+                            warn!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                ?fill,
+                                "received FillEvent for an unknown OrderEvent"
+                            );
                         }
                     }
 
-                    Event::OrderNew(order) => {
-                        let fill = self
-                            .execution
-                            .generate_fill(&order)
-                            .expect("failed to generate Fill");
+                    // Strategy reacts to it's own fills before they are applied to the Portfolio
+                    self.notify_strategy_fill(&fill);
 
-                        self.event_tx.send(Event::Fill(fill.clone()));
-                        self.event_q.push_back(Event::Fill(fill));
+                    let fill_side_effect_events =
+                        match self.portfolio.lock().update_from_fill(&fill) {
+                            Ok(events) => events,
+                            Err(error) => {
+                                error!(
+                                    engine_id = %self.engine_id,
+                                    market = ?self.market,
+                                    ?error,
+                                    action = "terminating Trader",
+                                    "failed to update Portfolio from FillEvent"
+                                );
+                                return Some(Err(EngineError::from(error)));
+                            }
+                        };
+
+                    for trade in self.trade_ledger.record(&fill) {
+                        self.session.record_trade(&trade);
                     }
 
-                    Event::Fill(fill) => {
-                        let fill_side_effect_events = self
-                            .portfolio
-                            .lock()
-                            .update_from_fill(&fill)
-                            .expect("failed to update Portfolio from fill");
+                    self.session.realised_profit_loss += fill_side_effect_events
+                        .iter()
+                        .filter_map(|event| match event {
+                            Event::PositionExit(exit) => Some(exit.realised_profit_loss),
+                            _ => None,
+                        })
+                        .sum::<Decimal>();
 
-                        self.event_tx.send_many(fill_side_effect_events);
+                    if fill_side_effect_events
+                        .iter()
+                        .any(|event| matches!(event, Event::PositionExit(_)))
+                    {
+                        self.exit_pending = false;
+                        self.deferred_exit = None;
                     }
-                    _ => {}
+
+                    self.event_tx.send_many(fill_side_effect_events);
+                    self.record_equity();
+                    self.spawn_fill_hooks(&fill);
                 }
+                _ => {}
+            }
+            if let Some(error) = self.check_livelock() {
+                return Some(Err(error));
             }
+        }
 
-            debug!(
-                engine_id = &*self.engine_id.to_string(),
-                market = &*format!("{:?}", self.market),
-                "Trader trading loop stopped"
-            );
+        // Terminate once the Position has been flattened after a panic, or the timeout expires
+        if self.panic_flattened_or_expired() {
+            return Some(self.stopped());
         }
+
+        debug!(
+            engine_id = &*self.engine_id.to_string(),
+            market = &*format!("{:?}", self.market),
+            "Trader trading loop stopped"
+        );
+        None
     }
 
-    /// Returns a [`Command`] if one has been received.
-    fn receive_remote_command(&mut self) -> Option<Command> {
-        match self.command_rx.try_recv() {
-            Ok(command) => {
+    /// Sends the [`OrderEvent`] to the [`ExecutionClient`], adding the resulting [`FillEvent`]
+    /// (if not resting) to the event_q.
+    ///
+    /// [`FillEvent`]: crate::execution::FillEvent
+    fn execute_order(&mut self, order: OrderEvent) -> Result<(), EngineError> {
+        info!(
+            cid = %order.cid,
+            decision = ?order.decision,
+            quantity = %order.quantity,
+            order_type = ?order.order_type,
+            price = %order.market_meta.close,
+            "sending OrderEvent for execution"
+        );
+        self.pending_orders.insert(
+            order.cid,
+            PendingOrder {
+                dispatched_at: self.clock.now(),
+                order: order.clone(),
+                filled: Decimal::ZERO,
+                retries: self.resubmitted.remove(&order.cid).unwrap_or_default(),
+            },
+        );
+        self.order_states.new_order(order.cid, order.quantity);
+        self.session.orders += 1;
+
+        let result = self.execution.generate_fill(&order);
+        let rejected = self.remove_rejected_orders();
+
+        match result {
+            Ok(None) if rejected.contains(&order.cid) => {}
+            Ok(Some(fill)) => {
+                self.order_states.acknowledge(&order.cid);
+
+                // Immediate OrderEvents partially filled have their remaining quantity cancelled
+                let remaining = order.quantity - fill.quantity;
+                if order.time_in_force.is_immediate() && !remaining.is_zero() {
+                    if let Some(pending) = self.pending_orders.get_mut(&order.cid) {
+                        pending.order.quantity = fill.quantity;
+                    }
+                    self.order_states.cancel(&order.cid);
+                    self.event_tx.send(Event::OrderCancelled(OrderEvent {
+                        quantity: remaining,
+                        ..order
+                    }));
+                }
+
+                self.event_tx.send(Event::Fill(fill.clone()));
+                self.event_q.push_back(Event::Fill(fill));
+            }
+            Ok(None) if order.time_in_force.is_immediate() => {
+                self.pending_orders.remove(&order.cid);
+                self.order_states.cancel(&order.cid);
+                self.event_tx.send(Event::OrderCancelled(order));
+            }
+            Ok(None) => {
+                self.order_states.acknowledge(&order.cid);
                 debug!(
-                    engine_id = &*self.engine_id.to_string(),
-                    market = &*format!("{:?}", self.market),
-                    command = &*format!("{:?}", command),
-                    "Trader received remote command"
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    cid = %order.cid,
+                    "OrderEvent resting until filled or cancelled"
                 );
-                Some(command)
             }
-            Err(err) => match err {
-                mpsc::error::TryRecvError::Empty => None,
-                mpsc::error::TryRecvError::Disconnected => {
-                    warn!(
-                        action = "synthesising a Command::Terminate",
-                        "remote Command transmitter has been dropped"
-                    );
-                    Some(Command::Terminate(
-                        "remote command transmitter dropped".to_owned(),
-                    ))
-                }
-            },
+            Err(error) => {
+                error!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    action = "terminating Trader",
+                    "failed to generate FillEvent from OrderEvent"
+                );
+                return Err(EngineError::from(error));
+            }
         }
-    }
-}
-
-/// Builder to construct [`Trader`] instances.
-#[derive(Debug, Default)]
-pub struct TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
-where
-    EventTx: MessageTransmitter<Event>,
-    Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
-    Data: MarketGenerator<MarketEvent<DataKind>>,
-    Strategy: SignalGenerator,
-    Execution: ExecutionClient,
-{
-    engine_id: Option<Uuid>,
-    market: Option<Market>,
-    command_rx: Option<mpsc::Receiver<Command>>,
-    event_tx: Option<EventTx>,
-    portfolio: Option<Arc<Mutex<Portfolio>>>,
-    data: Option<Data>,
-    strategy: Option<Strategy>,
-    execution: Option<Execution>,
-    _statistic_marker: Option<PhantomData<Statistic>>,
-}
 
-impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
-    TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
-where
-    EventTx: MessageTransmitter<Event>,
-    Statistic: Serialize + Send,
-    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
-    Data: MarketGenerator<MarketEvent<DataKind>> + Send,
-    Strategy: SignalGenerator + Send,
-    Execution: ExecutionClient + Send,
-{
-    fn new() -> Self {
-        Self {
-            engine_id: None,
-            market: None,
-            command_rx: None,
-            event_tx: None,
-            portfolio: None,
-            data: None,
-            strategy: None,
-            execution: None,
-            _statistic_marker: None,
-        }
+        Ok(())
     }
 
-    pub fn engine_id(self, value: Uuid) -> Self {
-        Self {
-            engine_id: Some(value),
-            ..self
-        }
+    /// Takes a token from the [`RateLimiter`], if configured, returning `true` if an
+    /// [`OrderEvent`] may be sent for execution.
+    fn acquire_order_token(&self) -> bool {
+        self.rate_limiter
+            .as_ref()
+            .is_none_or(|rate_limiter| rate_limiter.try_acquire(self.clock.now()))
     }
 
-    pub fn market(self, value: Market) -> Self {
-        Self {
-            market: Some(value),
-            ..self
+    /// Returns the oldest throttled [`OrderEvent`] if the [`RateLimiter`] has a token for it.
+    fn next_unthrottled_order(&mut self) -> Option<OrderEvent> {
+        if self.throttled_orders.is_empty() || !self.acquire_order_token() {
+            return None;
         }
+        self.throttled_orders.pop_front()
     }
 
-    pub fn command_rx(self, value: mpsc::Receiver<Command>) -> Self {
-        Self {
-            command_rx: Some(value),
-            ..self
+    /// Translates a [`ManualOrderRequest`] into an [`OrderEvent`] and adds it to the event_q. Requests that fail Portfolio validation are logged & dropped.
+    fn generate_manual_order(&mut self, request: ManualOrderRequest) -> CommandResult {
+        if self.kill_switch {
+            warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                ?request,
+                "rejected ManualOrderRequest while the kill switch is armed"
+            );
+            return CommandResult::Rejected("Trader kill switch is armed".to_owned());
         }
-    }
+
+        // Convert any quote currency notional into a quantity rounded down to the lot size
+        let request = if request.notional.is_some() {
+            let price = self.latest_market_meta.map(|market_meta| market_meta.close);
+            match request.resolve_notional(price) {
+                Ok(mut request) => {
+                    if let Some(filters) = &self.instrument_filters {
+                        request.quantity = filters.round_quantity(request.quantity);
+                    }
+                    request
+                }
+                Err(error) => {
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        ?error,
+                        "rejected ManualOrderRequest"
+                    );
+                    return CommandResult::Rejected(error.to_string());
+                }
+            }
+        } else {
+            request
+        };
+
+        let order = self
+            .portfolio
+            .lock()
+            .generate_manual_order(request, self.latest_market_meta);
+
+        match order {
+            Ok(order) if self.paused && order.decision.is_entry() => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?order,
+                    "rejected ManualOrderRequest entering a Position while paused"
+                );
+                CommandResult::Rejected("Trader is paused".to_owned())
+            }
+            Ok(order) => self.dispatch_order(order),
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    "rejected ManualOrderRequest"
+                );
+                CommandResult::Rejected(error.to_string())
+            }
+        }
+    }
+
+    /// Applies the updated strategy parameters to the Strategy, which leaves it's existing
+    /// parameters unchanged if they are rejected.
+    fn update_strategy_params(&mut self, params: serde_json::Value) -> CommandResult {
+        match self.strategy.apply_params(&params) {
+            Ok(()) => {
+                info!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    %params,
+                    "applied updated strategy parameters"
+                );
+                CommandResult::Accepted
+            }
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    %params,
+                    ?error,
+                    "rejected updated strategy parameters"
+                );
+                CommandResult::Rejected(error.to_string())
+            }
+        }
+    }
+
+    /// Exits the open Position of the provided [`Market`], if this [`Trader`] trades it.
+    fn exit_position(&mut self, market: Market) -> CommandResult {
+        if market != self.market {
+            return CommandResult::Rejected(format!("Trader does not trade Market {market:?}"));
+        }
+
+        let position_id = determine_position_id(
+            self.engine_id,
+            &self.market.exchange,
+            &self.market.instrument,
+        );
+        let position = self.portfolio.lock().get_open_position(&position_id);
+
+        match position {
+            Ok(Some(_)) => {
+                self.event_q
+                    .push_back(Event::SignalForceExit(SignalForceExit::from(market)));
+                CommandResult::Accepted
+            }
+            Ok(None) => CommandResult::NoOp,
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    "failed to fetch open Position to exit"
+                );
+                CommandResult::Rejected(error.to_string())
+            }
+        }
+    }
+
+    /// Sends the market order required to move the open Position towards the target weight of
+    /// the provided Portfolio equity, valued at the latest market price & rounded down to the lot
+    /// size of the [`InstrumentFilters`]. A target weight of zero exits the open Position.
+    ///
+    /// An open Position above the target quantity is partially exited by the difference (see
+    /// [`Position::decrease`](crate::portfolio::position::Position::decrease)), & an open Position
+    /// on the opposite side of the target is exited in full before the target is entered.
+    /// Reductions reduce risk, so they are still sent whilst the Trader is paused.
+    fn rebalance(&mut self, weight: f64, equity: Decimal) -> CommandResult {
+        if self.kill_switch {
+            return CommandResult::Rejected("Trader kill switch is armed".to_owned());
+        }
+
+        let Some(market_meta) = self.latest_market_meta else {
+            return CommandResult::Rejected(
+                "no market price available to price a rebalance".to_owned(),
+            );
+        };
+
+        let position_id = determine_position_id(
+            self.engine_id,
+            &self.market.exchange,
+            &self.market.instrument,
+        );
+        let position = match self.portfolio.lock().get_open_position(&position_id) {
+            Ok(position) => position,
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    "failed to fetch open Position to rebalance"
+                );
+                return CommandResult::Rejected(error.to_string());
+            }
+        };
+
+        let target = (decimal_from_f64(weight) * equity)
+            .checked_div(market_meta.close)
+            .unwrap_or_default();
+        let target = match &self.instrument_filters {
+            Some(filters) => filters.round_quantity(target),
+            None => target,
+        };
+        let current = position
+            .as_ref()
+            .map_or(Decimal::ZERO, |position| position.quantity);
+
+        debug!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            weight,
+            %equity,
+            %current,
+            %target,
+            "rebalancing Position towards target weight"
+        );
+
+        let entry_decision = if target.is_sign_positive() {
+            Decision::Long
+        } else {
+            Decision::Short
+        };
+
+        match position {
+            Some(_) if target.is_zero() => self.exit_position(self.market.clone()),
+            _ if target == current => CommandResult::NoOp,
+
+            // Target on the same side as the open Position, but smaller: partially exit it
+            Some(position)
+                if target.is_sign_positive() == current.is_sign_positive()
+                    && target.abs() < current.abs() =>
+            {
+                let order = self.rebalance_order(
+                    market_meta,
+                    position.determine_exit_decision(),
+                    target - current,
+                );
+                self.dispatch_order(order)
+            }
+
+            _ if self.paused => CommandResult::Rejected("Trader is paused".to_owned()),
+
+            // Target on the opposite side of the open Position: exit in full, then enter target
+            Some(position) if target.is_sign_positive() != current.is_sign_positive() => {
+                let exit =
+                    self.rebalance_order(market_meta, position.determine_exit_decision(), -current);
+                if let CommandResult::Rejected(reason) = self.dispatch_order(exit) {
+                    return CommandResult::Rejected(reason);
+                }
+                let entry = self.rebalance_order(market_meta, entry_decision, target);
+                self.dispatch_order(entry)
+            }
+
+            // No open Position, or target on the same side & larger: enter or increase
+            _ => {
+                let order = self.rebalance_order(market_meta, entry_decision, target - current);
+                self.dispatch_order(order)
+            }
+        }
+    }
+
+    /// Constructs the market [`OrderEvent`] of the provided [`Decision`] & signed quantity sent
+    /// to rebalance the open Position (see [`Trader::rebalance`]).
+    fn rebalance_order(
+        &self,
+        market_meta: MarketMeta,
+        decision: Decision,
+        quantity: Decimal,
+    ) -> OrderEvent {
+        OrderEvent {
+            time: self.clock.now(),
+            cid: ClientOrderId::default(),
+            account: AccountId::default(),
+            exchange: self.market.exchange.clone(),
+            instrument: self.market.instrument.clone(),
+            market_meta,
+            decision,
+            quantity,
+            order_type: OrderType::Market,
+            stop_price: None,
+            time_in_force: TimeInForce::default(),
+            trigger: None,
+            tags: OrderTags::default(),
+        }
+    }
+
+    /// Reconciles the internal Position of the [`Market`] against the Position held on the
+    /// exchange, as reported by [`ExecutionClient::exchange_position`]. If their net quantities
+    /// differ by more than the reconcile tolerance, the drift is audited via an
+    /// [`Event::PositionDiscrepancy`] & the internal Position is corrected to match the exchange:
+    /// - Exchange flat: the internal Position is removed.
+    /// - Same [`Side`]: the internal Position quantity is corrected, keeping it's entry price.
+    /// - No internal Position, or of the opposite [`Side`]: a Position is entered at the latest
+    ///   market price.
+    ///
+    /// The Portfolio [`Balance`] is left untouched, since it is reconciled separately (eg/ via
+    /// [`ExecutionClient::fetch_balance`]).
+    fn reconcile(&mut self) -> Result<(), EngineError> {
+        let exchange_quantity = match self.execution.exchange_position(&self.market.instrument) {
+            Ok(Some(quantity)) => quantity,
+            Ok(None) => {
+                debug!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    "ExecutionClient does not track the exchange Position, skipping reconciliation"
+                );
+                return Ok(());
+            }
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    "failed to fetch exchange Position to reconcile"
+                );
+                return Ok(());
+            }
+        };
+
+        let position_id = determine_position_id(
+            self.engine_id,
+            &self.market.exchange,
+            &self.market.instrument,
+        );
+        let position = self.portfolio.lock().get_open_position(&position_id)?;
+        let internal_quantity = position
+            .as_ref()
+            .map_or(Decimal::ZERO, |position| position.quantity);
+
+        if (exchange_quantity - internal_quantity).abs() <= self.reconcile_tolerance {
+            debug!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                %internal_quantity,
+                %exchange_quantity,
+                "internal Position reconciled with exchange Position"
+            );
+            return Ok(());
+        }
+
+        warn!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            %internal_quantity,
+            %exchange_quantity,
+            action = "correcting internal Position to match the exchange",
+            "internal Position drifted from exchange Position"
+        );
+        self.event_tx
+            .send(Event::PositionDiscrepancy(PositionDiscrepancy {
+                time: self.clock.now(),
+                market: self.market.clone(),
+                internal_quantity,
+                exchange_quantity,
+            }));
+
+        match position {
+            _ if exchange_quantity.is_zero() => {
+                self.portfolio.lock().remove_position(&position_id)?;
+            }
+            Some(mut position)
+                if position.quantity.is_sign_positive() == exchange_quantity.is_sign_positive() =>
+            {
+                let update = position.reconcile(exchange_quantity, self.clock.now())?;
+                self.portfolio.lock().set_open_position(position)?;
+                self.event_tx.send(Event::PositionUpdate(update));
+            }
+            _ => {
+                let Some(market_meta) = self.latest_market_meta else {
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        why = "no market price available to price the Position",
+                        "cannot enter reconciled Position"
+                    );
+                    return Ok(());
+                };
+
+                // Synthetic fill of the exchange quantity at the latest market price
+                let fill = FillEvent {
+                    time: self.clock.now(),
+                    cid: ClientOrderId::default(),
+                    account: self.account.clone(),
+                    exchange: self.market.exchange.clone(),
+                    instrument: self.market.instrument.clone(),
+                    market_meta,
+                    decision: if exchange_quantity.is_sign_positive() {
+                        Decision::Long
+                    } else {
+                        Decision::Short
+                    },
+                    quantity: exchange_quantity,
+                    fill_value_gross: market_meta.close * exchange_quantity.abs(),
+                    fees: Fees::default(),
+                    fill_id: None,
+                    tags: OrderTags::default(),
+                };
+                let position = Position::enter(self.engine_id, &fill)?;
+                self.portfolio.lock().set_open_position(position.clone())?;
+                self.event_tx.send(Event::PositionNew(position));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates the generated [`OrderEvent`], assigns it the next unique [`ClientOrderId`] & adds
+    /// it to the event_q to be executed. Invalid [`OrderEvent`]s are dropped, and the reason is
+    /// returned as a [`CommandResult::Rejected`].
+    fn dispatch_order(&mut self, order: OrderEvent) -> CommandResult {
+        match self.try_dispatch_order(order) {
+            Ok(_) => CommandResult::Accepted,
+            Err(reason) => CommandResult::Rejected(reason),
+        }
+    }
+
+    /// Dispatches the [`OrderEvent`] (see [`Trader::dispatch_order`]), returning it's assigned
+    /// [`ClientOrderId`] or the reason it was dropped.
+    fn try_dispatch_order(&mut self, order: OrderEvent) -> Result<ClientOrderId, String> {
+        // Exits are always dispatched so a tripped CircuitBreaker can still flatten the Position
+        if !order.decision.is_exit() && self.circuit_tripped {
+            return Err(self.refuse_tripped_order(&order));
+        }
+
+        if let Err(reason) = self.check_session_hours(&order) {
+            warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                ?order,
+                reason,
+                "refused opening OrderEvent outside trading session hours"
+            );
+            return Err(reason.to_owned());
+        }
+
+        let order = self.prepare_order(order)?;
+
+        // Only OrderEvents that pass every pre-trade check count towards the order rate
+        if !order.decision.is_exit() && !self.record_circuit(CircuitMetric::Orders) {
+            return Err(self.refuse_tripped_order(&order));
+        }
+
+        match order.trigger {
+            Some(_) => Ok(self.hold_order(order)),
+            None => Ok(self.send_order(order)),
+        }
+    }
+
+    /// Logs the refusal of an [`OrderEvent`] whilst the [`CircuitBreaker`] is tripped, returning
+    /// the reason.
+    fn refuse_tripped_order(&self, order: &OrderEvent) -> String {
+        warn!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            ?order,
+            "refused OrderEvent while the circuit breaker is tripped"
+        );
+        "Trader circuit breaker is tripped".to_owned()
+    }
+
+    /// Validates the generated [`OrderEvent`], evaluates it against the available margin,
+    /// normalises it to the [`InstrumentFilters`] & checks it against the available cash and the
+    /// position cap, returning the reason if it must be dropped.
+    fn prepare_order(&mut self, order: OrderEvent) -> Result<OrderEvent, String> {
+        if let Err(error) = order.validate() {
+            warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                ?error,
+                ?order,
+                "dropping invalid OrderEvent"
+            );
+            return Err(error.to_string());
+        }
+
+        let order = self.evaluate_margin(order).map_err(str::to_owned)?;
+
+        let order = self.normalise_order(order)?;
+        let order = self.check_cash(order)?;
+        self.check_position_cap(order)
+    }
+
+    /// Normalises the [`OrderEvent`] to the [`InstrumentFilters`], if configured, returning the
+    /// reason if it violates them.
+    fn normalise_order(&self, order: OrderEvent) -> Result<OrderEvent, String> {
+        match &self.instrument_filters {
+            Some(filters) => filters.normalise(order.clone()).map_err(|error| {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    ?order,
+                    "dropping OrderEvent violating InstrumentFilters"
+                );
+                error.to_string()
+            }),
+            None => Ok(order),
+        }
+    }
+
+    /// Constrains buy [`OrderEvent`]s to the available Portfolio cash via the [`CashGuard`], if
+    /// configured. Cash committed to buy [`OrderEvent`]s that are queued, throttled or resting
+    /// awaiting execution is excluded from the available cash. Downsized [`OrderEvent`]s are normalised
+    /// to the [`InstrumentFilters`] again.
+    fn check_cash(&self, order: OrderEvent) -> Result<OrderEvent, String> {
+        let Some(guard) = self.cash_guard else {
+            return Ok(order);
+        };
+        if order.quantity <= Decimal::ZERO {
+            return Ok(order);
+        }
+
+        let balance = self.portfolio.lock().get_balance(self.engine_id);
+        let balance = match balance {
+            Ok(balance) => balance,
+            Err(error) => {
+                error!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    ?order,
+                    "refusing buy OrderEvent since the available cash could not be evaluated"
+                );
+                return Err("available cash could not be evaluated".to_owned());
+            }
+        };
+
+        let queued = self.event_q.iter().filter_map(|event| match event {
+            Event::OrderNew(order) => Some(order),
+            _ => None,
+        });
+        let committed = self
+            .pending_orders
+            .values()
+            .map(|pending| &pending.order)
+            .chain(self.throttled_orders.iter())
+            .chain(queued)
+            .map(|order| guard.required_cash(order))
+            .sum::<Decimal>();
+        let available_cash = balance.available - committed;
+
+        match guard.constrain_order(order.clone(), available_cash) {
+            Ok(constrained) if constrained.quantity == order.quantity => Ok(constrained),
+            Ok(constrained) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    %available_cash,
+                    %committed,
+                    quantity = %constrained.quantity,
+                    ?order,
+                    "downsizing buy OrderEvent to the available cash"
+                );
+                self.normalise_order(constrained)
+            }
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    %committed,
+                    ?order,
+                    "refusing buy OrderEvent with insufficient available cash"
+                );
+                Err(error.to_string())
+            }
+        }
+    }
+
+    /// Refuses the [`OrderEvent`] if the net Position quantity resulting from it's execution
+    /// would exceed the position cap (eg/ an order doubling the Position when flipping it),
+    /// sending an [`Event::PositionCapBreach`] to audit the refusal.
+    fn check_position_cap(&mut self, order: OrderEvent) -> Result<OrderEvent, String> {
+        let Some(cap) = self.position_cap else {
+            return Ok(order);
+        };
+
+        let position_id = determine_position_id(self.engine_id, &order.exchange, &order.instrument);
+        let position = self.portfolio.lock().get_open_position(&position_id);
+        let position_quantity = match position {
+            Ok(position) => position.map_or(Decimal::ZERO, |position| position.quantity),
+            Err(error) => {
+                error!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    ?order,
+                    "refusing OrderEvent since the position cap could not be evaluated"
+                );
+                return Err("position cap could not be evaluated".to_owned());
+            }
+        };
+
+        let resulting_quantity = position_quantity + order.quantity;
+        if resulting_quantity.abs() <= cap {
+            return Ok(order);
+        }
+
+        error!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            %position_quantity,
+            %resulting_quantity,
+            %cap,
+            ?order,
+            "refusing OrderEvent breaching the hard position cap"
+        );
+        let reason = format!(
+            "resulting Position quantity {resulting_quantity} exceeds the position cap {cap}"
+        );
+        self.event_tx
+            .send(Event::PositionCapBreach(PositionCapBreach {
+                time: self.clock.now(),
+                order,
+                position_quantity,
+                resulting_quantity,
+                cap,
+            }));
+        Err(reason)
+    }
+
+    /// Invokes the Strategy with the [`MarketEvent`] (or completed bar), adding any
+    /// [`Signal`](crate::strategy::Signal) it generates to the event_q once warmed up.
+    fn generate_signal(&mut self, market: &MarketEvent<DataKind>) {
+        // Strategy is not invoked again whilst flattening the open Position after it panicked
+        if self.panicked.is_some() {
+            return;
+        }
+
+        let signal = catch_unwind(AssertUnwindSafe(|| self.strategy.generate_signal(market)));
+        let signal = match signal {
+            Ok(signal) => signal,
+            Err(payload) => {
+                self.handle_panic("Strategy", payload.as_ref());
+                return;
+            }
+        };
+        if self.warm_up > 0 {
+            // Paused MarketEvents do not count towards the warm up
+            if !self.paused {
+                self.warm_up -= 1;
+            }
+            debug!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                remaining = self.warm_up,
+                "Trader warming up, ignoring Signal"
+            );
+        } else if let Some(signal) = signal {
+            self.event_tx.send(Event::Signal(signal.clone()));
+            self.event_q.push_back(Event::Signal(signal));
+        }
+    }
+
+    /// Notifies the Strategy of a [`FillEvent`] of this [`Trader`] account, before it is applied
+    /// to the Portfolio.
+    fn notify_strategy_fill(&mut self, fill: &FillEvent) {
+        if let Err(payload) = catch_unwind(AssertUnwindSafe(|| self.strategy.on_fill(fill))) {
+            self.handle_panic("Strategy", payload.as_ref());
+        }
+    }
+
+    /// Assigns the prepared [`OrderEvent`] the next unique [`ClientOrderId`] & the [`AccountId`]
+    /// of this [`Trader`], and adds it to the event_q to be executed.
+    fn send_order(&mut self, mut order: OrderEvent) -> ClientOrderId {
+        order.cid = self.order_id_generator.next_id();
+        order.account = self.account.clone();
+        let cid = order.cid;
+        self.event_tx.send(Event::OrderNew(order.clone()));
+        self.event_q.push_back(Event::OrderNew(order));
+        cid
+    }
+
+    /// Assigns the prepared conditional [`OrderEvent`] the next unique [`ClientOrderId`] & the
+    /// [`AccountId`] of this [`Trader`], and holds it inactive until it's
+    /// [`Trigger`](crate::portfolio::Trigger) is met. Returns the assigned [`ClientOrderId`],
+    /// which may be used to cancel the [`OrderEvent`] before it is triggered.
+    fn hold_order(&mut self, mut order: OrderEvent) -> ClientOrderId {
+        order.cid = self.order_id_generator.next_id();
+        order.account = self.account.clone();
+        let cid = order.cid;
+        info!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            cid = %cid,
+            trigger = ?order.trigger,
+            "holding conditional OrderEvent until triggered"
+        );
+        self.event_tx.send(Event::OrderNew(order.clone()));
+        self.triggered_orders.push(order);
+        cid
+    }
+
+    /// Sends every held conditional [`OrderEvent`] whose [`Trigger`](crate::portfolio::Trigger)
+    /// is met by the latest market price for execution as it's inner [`OrderType`]. Triggered
+    /// market orders are priced by the triggering [`MarketMeta`].
+    fn activate_triggered_orders(&mut self, market_meta: MarketMeta) {
+        let (triggered, held) = std::mem::take(&mut self.triggered_orders)
+            .into_iter()
+            .partition::<Vec<_>, _>(|order| {
+                order
+                    .trigger
+                    .is_some_and(|trigger| trigger.is_met(market_meta.close))
+            });
+        self.triggered_orders = held;
+
+        for mut order in triggered {
+            info!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                cid = %order.cid,
+                price = %market_meta.close,
+                trigger = ?order.trigger,
+                "conditional OrderEvent triggered"
+            );
+            order.trigger = None;
+            order.time = self.clock.now();
+            if order.order_type == OrderType::Market {
+                order.market_meta = market_meta;
+            }
+            self.event_tx.send(Event::OrderTriggered(order.clone()));
+            self.event_q.push_back(Event::OrderNew(order));
+        }
+    }
+
+    /// Nets the [`OrderEvent`] against every opposing [`OrderEvent`] queued for execution behind
+    /// it that would self-match on the exchange, if self-match prevention is enabled. Returns
+    /// the residual [`OrderEvent`] to be sent for execution, or `None` if it was fully netted.
+    fn net_self_matches(&mut self, order: OrderEvent) -> Option<OrderEvent> {
+        if !self.self_match_prevention {
+            return Some(order);
+        }
+
+        let mut residual = order;
+        let mut index = 0;
+        while index < self.event_q.len() {
+            let Event::OrderNew(queued) = &self.event_q[index] else {
+                index += 1;
+                continue;
+            };
+            if !is_self_match(&residual, queued) {
+                index += 1;
+                continue;
+            }
+            let Some(Event::OrderNew(queued)) = self.event_q.remove(index) else {
+                unreachable!("event_q index was checked to be an Event::OrderNew");
+            };
+
+            let netted = net_self_match(residual, queued);
+            for crossed in netted.crossed {
+                info!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    cid = %crossed.cid,
+                    quantity = %crossed.quantity,
+                    "crossed self-matching OrderEvent quantity internally"
+                );
+                self.event_tx.send(Event::OrderCancelled(crossed));
+            }
+
+            residual = netted.residual?;
+        }
+
+        Some(residual)
+    }
+
+    /// Submits the take profit & stop legs of a one-cancels-other group, which are only sent for
+    /// execution if both are valid.
+    fn submit_oco(
+        &mut self,
+        take_profit: ManualOrderRequest,
+        stop: ManualOrderRequest,
+    ) -> CommandResult {
+        let (take_profit, stop) = match self.prepare_oco(take_profit, stop) {
+            Ok(legs) => legs,
+            Err(reason) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    reason,
+                    "rejected OCO ManualOrderRequests"
+                );
+                return CommandResult::Rejected(reason);
+            }
+        };
+
+        let group = Uuid::new_v4();
+        let (take_profit_quantity, stop_quantity) = (take_profit.quantity, stop.quantity);
+        let take_profit = self.send_order(take_profit);
+        let stop = self.send_order(stop);
+        self.oco_legs.insert(
+            take_profit,
+            OcoLeg {
+                group,
+                other: stop,
+                other_quantity: stop_quantity,
+            },
+        );
+        self.oco_legs.insert(
+            stop,
+            OcoLeg {
+                group,
+                other: take_profit,
+                other_quantity: take_profit_quantity,
+            },
+        );
+
+        info!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            %group,
+            %take_profit,
+            %stop,
+            "submitted OCO group"
+        );
+        CommandResult::Accepted
+    }
+
+    /// Generates & prepares the take profit & stop legs of a one-cancels-other group, returning
+    /// the reason the group is rejected if either leg is invalid.
+    fn prepare_oco(
+        &mut self,
+        take_profit: ManualOrderRequest,
+        stop: ManualOrderRequest,
+    ) -> Result<(OrderEvent, OrderEvent), String> {
+        if self.kill_switch {
+            return Err("Trader kill switch is armed".to_owned());
+        }
+
+        if take_profit.market() != self.market || stop.market() != self.market {
+            return Err(format!(
+                "OCO legs must both be of the Trader Market {:?}",
+                self.market
+            ));
+        }
+
+        let (take_profit, stop) = {
+            let mut portfolio = self.portfolio.lock();
+            (
+                portfolio.generate_manual_order(take_profit, self.latest_market_meta),
+                portfolio.generate_manual_order(stop, self.latest_market_meta),
+            )
+        };
+        let take_profit = take_profit.map_err(|error| error.to_string())?;
+        let stop = stop.map_err(|error| error.to_string())?;
+
+        if self.paused && (take_profit.decision.is_entry() || stop.decision.is_entry()) {
+            return Err("Trader is paused".to_owned());
+        }
+
+        Ok((self.prepare_order(take_profit)?, self.prepare_order(stop)?))
+    }
+
+    /// Cancels or reduces the other leg of the one-cancels-other group of the filled
+    /// [`PendingOrder`], if any. Each partial fill reduces the other leg to the unfilled fraction
+    /// of the filled leg, which remains grouped until it's fully filled & the other leg is
+    /// cancelled. A fill of a leg whose other leg has already filled (eg/ both legs crossed by the
+    /// same [`MarketEvent`]) is an over-fill, and is warned of.
+    fn update_oco_group(&mut self, fill: &FillEvent, pending: &PendingOrder) {
+        let Some(leg) = self.oco_legs.get(&fill.cid).copied() else {
+            return;
+        };
+
+        if !self.oco_legs.contains_key(&leg.other) {
+            self.oco_legs.remove(&fill.cid);
+            warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                group = %leg.group,
+                cid = %fill.cid,
+                "both legs of OCO group filled, Position may be over-filled"
+            );
+            return;
+        }
+
+        let unfilled = Decimal::ONE - pending.filled / pending.order.quantity.abs();
+        if unfilled <= Decimal::ZERO {
+            // Other leg remains grouped if it could not be cancelled, so a later fill is detected
+            if !self.cancel_order(leg.other) {
+                self.oco_legs.remove(&fill.cid);
+            }
+            return;
+        }
+
+        self.reduce_order(leg.other, leg.other_quantity * unfilled);
+    }
+
+    /// Adds the [`FillEvent`] to the filled quantity of it's open [`OrderEvent`], which is no
+    /// longer tracked as open once fully filled. Returns whether it was the first fill of the
+    /// [`OrderEvent`] alongside it's [`PendingOrder`], or `None` if the [`OrderEvent`] is unknown.
+    fn apply_pending_fill(&mut self, fill: &FillEvent) -> Option<(bool, PendingOrder)> {
+        let pending = self.pending_orders.get_mut(&fill.cid)?;
+        let first_fill = pending.filled.is_zero();
+        pending.filled += fill.quantity.abs();
+
+        if pending.filled >= pending.order.quantity.abs() {
+            self.pending_orders
+                .remove(&fill.cid)
+                .map(|pending| (first_fill, pending))
+        } else {
+            Some((first_fill, pending.clone()))
+        }
+    }
+
+    /// Amends the limit price and/or the quantity of the open [`OrderEvent`] with the provided
+    /// [`ClientOrderId`] in place, rather than cancelling & replacing it. Returns `true` if it was
+    /// amended. Since [`Command::AmendOrder`] is routed to every [`Trader`], an id this [`Trader`]
+    /// does not believe is open is a no-op.
+    ///
+    /// The new quantity is the absolute total quantity of the [`OrderEvent`], so amends to a
+    /// quantity that does not exceed the quantity already filled are refused. The open
+    /// [`OrderEvent`] record is updated once the [`ExecutionClient`] acknowledges the amend, which
+    /// is audited via an [`Event::OrderAmended`].
+    fn amend_order(
+        &mut self,
+        id: ClientOrderId,
+        new_price: Option<Decimal>,
+        new_quantity: Option<Decimal>,
+    ) -> bool {
+        let amend = |order: &OrderEvent, filled: Decimal| -> Result<OrderEvent, &'static str> {
+            if new_price.is_none() && new_quantity.is_none() {
+                return Err("amend changes neither the price nor the quantity");
+            }
+            if new_price.is_some() && order.order_type == OrderType::Market {
+                return Err("market orders have no price to amend");
+            }
+            if new_price.is_some_and(|price| price <= Decimal::ZERO) {
+                return Err("amended price must be positive");
+            }
+            let quantity = new_quantity.unwrap_or(order.quantity.abs());
+            if quantity <= filled {
+                return Err("amended quantity must exceed the quantity already filled");
+            }
+
+            let mut amended = order.clone();
+            amended.quantity = if order.quantity.is_sign_negative() {
+                -quantity
+            } else {
+                quantity
+            };
+            if let Some(price) = new_price {
+                amended.market_meta.close = price;
+            }
+            Ok(amended)
+        };
+
+        // Throttled & untriggered OrderEvents have not been sent for execution, so are amended
+        // in place
+        let unsent = self
+            .throttled_orders
+            .iter_mut()
+            .chain(self.triggered_orders.iter_mut())
+            .find(|order| order.cid == id);
+        let result = match (unsent, self.pending_orders.get(&id)) {
+            (Some(order), _) => {
+                amend(order, Decimal::ZERO).inspect(|amended| *order = amended.clone())
+            }
+            (None, Some(pending)) => {
+                amend(&pending.order, pending.filled).and_then(|amended| {
+                    // ExecutionClient amends the unfilled quantity of the resting OrderEvent
+                    let unfilled = amended.quantity.abs() - pending.filled;
+                    let unfilled = OrderEvent {
+                        quantity: if amended.quantity.is_sign_negative() {
+                            -unfilled
+                        } else {
+                            unfilled
+                        },
+                        ..amended.clone()
+                    };
+                    self.execution
+                        .amend_order(&unfilled)
+                        .map(|_| amended)
+                        .ok_or("ExecutionClient failed to amend the open order")
+                })
+            }
+            (None, None) => {
+                debug!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    cid = %id,
+                    "ignoring Command::AmendOrder for an unknown OrderEvent"
+                );
+                return false;
+            }
+        };
+
+        match result {
+            Ok(amended) => {
+                if let Some(pending) = self.pending_orders.get_mut(&id) {
+                    pending.order = amended.clone();
+                }
+                self.order_states.amend(&id, amended.quantity);
+                info!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    cid = %id,
+                    quantity = %amended.quantity,
+                    price = %amended.market_meta.close,
+                    "amended open OrderEvent"
+                );
+                self.event_tx.send(Event::OrderAmended(amended));
+                true
+            }
+            Err(reason) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    cid = %id,
+                    ?new_price,
+                    ?new_quantity,
+                    reason,
+                    "refused Command::AmendOrder"
+                );
+                false
+            }
+        }
+    }
+
+    /// Reduces the quantity of the open [`OrderEvent`] with the provided [`ClientOrderId`] to
+    /// the provided quantity, if it's currently larger.
+    fn reduce_order(&mut self, id: ClientOrderId, quantity: Decimal) {
+        // Throttled & untriggered OrderEvents have not been sent for execution, so are reduced
+        // in place
+        if let Some(order) = self
+            .throttled_orders
+            .iter_mut()
+            .chain(self.triggered_orders.iter_mut())
+            .find(|order| order.cid == id)
+        {
+            if quantity.abs() < order.quantity.abs() {
+                order.quantity = quantity;
+            }
+            return;
+        }
+
+        let Some(pending) = self.pending_orders.get_mut(&id) else {
+            return;
+        };
+        if quantity.abs() >= pending.order.quantity.abs() {
+            return;
+        }
+        let amended = OrderEvent {
+            quantity,
+            ..pending.order.clone()
+        };
+
+        match self.execution.amend_order(&amended) {
+            Some(order) => {
+                self.order_states.amend(&id, order.quantity);
+                pending.order = order;
+            }
+            None => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    cid = %id,
+                    "failed to reduce open OrderEvent"
+                );
+            }
+        }
+    }
+
+    /// Cancels the open [`OrderEvent`] with the provided [`ClientOrderId`], returning `true` if
+    /// it was cancelled. Since [`Command::CancelOrder`] is routed to every [`Trader`], an id this
+    /// [`Trader`] does not believe is open is a no-op.
+    ///
+    /// A cancelled leg of a one-cancels-other group leaves the other leg as a standalone order.
+    fn cancel_order(&mut self, id: ClientOrderId) -> bool {
+        // Throttled & untriggered OrderEvents have not been sent for execution, so are cancelled
+        // in place
+        let cancelled = if let Some(index) = self
+            .throttled_orders
+            .iter()
+            .position(|order| order.cid == id)
+        {
+            self.throttled_orders.remove(index)
+        } else if let Some(index) = self
+            .triggered_orders
+            .iter()
+            .position(|order| order.cid == id)
+        {
+            Some(self.triggered_orders.remove(index))
+        } else if !self.pending_orders.contains_key(&id) {
+            debug!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                cid = %id,
+                "ignoring Command::CancelOrder for an unknown OrderEvent"
+            );
+            return false;
+        } else {
+            let cancelled = self.execution.cancel_order(&id);
+            if cancelled.is_none() {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    cid = %id,
+                    "failed to cancel open OrderEvent"
+                );
+            }
+            cancelled
+        };
+
+        let Some(order) = cancelled else {
+            return false;
+        };
+
+        self.pending_orders.remove(&order.cid);
+        self.order_states.cancel(&order.cid);
+        if let Some(leg) = self.oco_legs.remove(&order.cid) {
+            self.oco_legs.remove(&leg.other);
+        }
+        self.event_tx.send(Event::OrderCancelled(order));
+        true
+    }
+
+    /// Returns every [`OrderEvent`] this [`Trader`] has queued rather than sent for execution,
+    /// alongside the [`QueueReason`] & the expected release time.
+    ///
+    /// Fill cooldowns drop Signals rather than queue their [`OrderEvent`]s, so are not included.
+    pub(super) fn queued_orders(&self) -> Vec<QueuedOrder> {
+        let now = self.clock.now();
+
+        let throttled = self
+            .throttled_orders
+            .iter()
+            .enumerate()
+            .map(|(queued, order)| QueuedOrder {
+                order: order.clone(),
+                reason: QueueReason::RateLimited,
+                release_at: self
+                    .rate_limiter
+                    .as_ref()
+                    .map_or(now, |rate_limiter| rate_limiter.release_time(now, queued)),
+            });
+
+        let min_holding_time = self
+            .min_holding_time
+            .and_then(|min_holding_time| chrono::Duration::from_std(min_holding_time).ok())
+            .unwrap_or_default();
+        let deferred = self
+            .deferred_exit
+            .iter()
+            .map(|(entered_at, order)| QueuedOrder {
+                order: order.clone(),
+                reason: QueueReason::MinHoldingTime,
+                release_at: *entered_at + min_holding_time,
+            });
+
+        let retries = self.order_retries.iter().map(|retry| QueuedOrder {
+            order: retry.order.clone(),
+            reason: QueueReason::RetryBackoff,
+            release_at: retry.due_at,
+        });
+
+        throttled.chain(deferred).chain(retries).collect()
+    }
+
+    /// Drops the queued [`OrderEvent`] with the provided [`ClientOrderId`] before it is sent for
+    /// execution, returning `true` if it was dropped. An id that is not queued (eg/ an
+    /// [`OrderEvent`] already sent) falls through to cancelling it via [`Trader::cancel_order`].
+    fn drop_pending_order(&mut self, id: ClientOrderId) -> bool {
+        let dropped = if self
+            .deferred_exit
+            .as_ref()
+            .is_some_and(|(_, order)| order.cid == id)
+        {
+            self.deferred_exit.take().map(|(_, order)| order)
+        } else if let Some(index) = self
+            .order_retries
+            .iter()
+            .position(|retry| retry.order.cid == id)
+        {
+            Some(self.order_retries.remove(index).order)
+        } else {
+            // Throttled OrderEvents are dropped in place by cancelling them
+            return self.cancel_order(id);
+        };
+
+        let Some(order) = dropped else {
+            return false;
+        };
+        info!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            cid = %id,
+            "dropped queued OrderEvent before it was sent for execution"
+        );
+        self.event_tx.send(Event::OrderCancelled(order));
+        true
+    }
+
+    /// Removes every [`OrderEvent`] the exchange rejected from the open orders tracked by this
+    /// [`Trader`], sending an [`Event::OrderRejected`] for each & scheduling the resubmission of
+    /// transient rejections via the [`RetryPolicy`], if configured. Returns the
+    /// [`ClientOrderId`]s of the rejected [`OrderEvent`]s.
+    fn remove_rejected_orders(&mut self) -> Vec<ClientOrderId> {
+        self.execution
+            .rejected_orders()
+            .into_iter()
+            .map(|rejection| {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    cid = %rejection.order.cid,
+                    reason = ?rejection.reason,
+                    transient = rejection.reason.is_transient(),
+                    "exchange rejected OrderEvent"
+                );
+                let cid = rejection.order.cid;
+                let retries = self
+                    .pending_orders
+                    .remove(&cid)
+                    .map_or(0, |pending| pending.retries);
+                self.order_states.reject(&cid);
+                if let Some(leg) = self.oco_legs.remove(&cid) {
+                    self.oco_legs.remove(&leg.other);
+                }
+                self.schedule_retry(&rejection, retries);
+                self.event_tx.send(Event::OrderRejected(rejection));
+                cid
+            })
+            .collect()
+    }
+
+    /// Schedules the resubmission of the rejected [`OrderEvent`] once it's backoff elapses, if
+    /// the [`RetryPolicy`] retries it after the provided number of retries already made.
+    fn schedule_retry(&mut self, rejection: &OrderRejection, retries: usize) {
+        let Some(policy) = self.retry_policy else {
+            return;
+        };
+        if !policy.should_retry(rejection.reason, retries) {
+            if rejection.reason.is_transient() {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    cid = %rejection.order.cid,
+                    retries,
+                    "giving up on transiently rejected OrderEvent after the max retries"
+                );
+            }
+            return;
+        }
+
+        let retry = retries + 1;
+        let due_at = self.clock.now() + policy.backoff(retry);
+        info!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            cid = %rejection.order.cid,
+            retry,
+            %due_at,
+            "scheduling retry of transiently rejected OrderEvent"
+        );
+        self.order_retries.push(OrderRetry {
+            due_at,
+            retry,
+            order: rejection.order.clone(),
+        });
+    }
+
+    /// Resubmits every transiently rejected [`OrderEvent`] whose backoff has elapsed with a
+    /// fresh [`ClientOrderId`], validating it like a new [`OrderEvent`] so the retry respects the
+    /// position cap & every other pre-trade check. Retries are dropped whilst the kill switch is
+    /// armed, & retries of entries whilst paused.
+    fn release_order_retries(&mut self) {
+        if self.order_retries.is_empty() {
+            return;
+        }
+
+        let now = self.clock.now();
+        let (due, waiting) = std::mem::take(&mut self.order_retries)
+            .into_iter()
+            .partition::<Vec<_>, _>(|retry| retry.due_at <= now);
+        self.order_retries = waiting;
+
+        for OrderRetry {
+            retry, mut order, ..
+        } in due
+        {
+            if self.kill_switch || (self.paused && order.decision.is_entry()) {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    cid = %order.cid,
+                    "dropping retry of rejected OrderEvent while trading is halted"
+                );
+                continue;
+            }
+
+            let rejected_cid = order.cid;
+            order.time = now;
+            match self.try_dispatch_order(order) {
+                Ok(cid) => {
+                    info!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        %rejected_cid,
+                        %cid,
+                        retry,
+                        "resubmitted transiently rejected OrderEvent"
+                    );
+                    self.resubmitted.insert(cid, retry);
+                }
+                Err(reason) => {
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        %rejected_cid,
+                        retry,
+                        reason,
+                        "dropping retry of rejected OrderEvent refused by pre-trade checks"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Cancels every [`OrderEvent`] this [`Trader`] believes is open, including the deferred exit
+    /// & scheduled retries that have not been sent for execution yet.
+    fn cancel_all_orders(&mut self) {
+        let mut open_orders = self
+            .pending_orders
+            .keys()
+            .copied()
+            .chain(self.throttled_orders.iter().map(|order| order.cid))
+            .chain(self.triggered_orders.iter().map(|order| order.cid))
+            .chain(self.deferred_exit.iter().map(|(_, order)| order.cid))
+            .chain(self.order_retries.iter().map(|retry| retry.order.cid))
+            .collect::<Vec<_>>();
+        open_orders.sort();
+
+        for id in open_orders {
+            self.drop_pending_order(id);
+        }
+    }
+
+    /// Records an event of the [`CircuitMetric`] with the [`CircuitBreaker`] (if any), tripping
+    /// it if the rate exceeds it's ceiling: new orders are refused until a [`Command::Resume`]
+    /// is received, audited via an [`Event::CircuitTripped`]. Returns false if the
+    /// [`CircuitBreaker`] is tripped.
+    fn record_circuit(&mut self, metric: CircuitMetric) -> bool {
+        if self.circuit_tripped {
+            return false;
+        }
+        let Some(breaker) = &mut self.circuit_breaker else {
+            return true;
+        };
+        let Some(rate) = breaker.record(metric, self.clock.now()) else {
+            return true;
+        };
+
+        let ceiling = breaker.ceiling(metric);
+        error!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            ?metric,
+            rate,
+            ceiling,
+            action = "pausing Trader until a Command::Resume is received",
+            "circuit breaker tripped"
+        );
+        self.circuit_tripped = true;
+        self.paused = true;
+        self.event_tx.send(Event::CircuitTripped(CircuitTripped {
+            time: self.clock.now(),
+            market: self.market.clone(),
+            metric,
+            rate,
+            ceiling,
+        }));
+        false
+    }
+
+    /// Arms the kill switch: cancels every [`OrderEvent`] this [`Trader`] believes is open,
+    /// flattens the open Position with a market order, and locks the [`Trader`] so no new orders
+    /// are generated until a [`Command::Resume`] is received. The [`KillSwitchReason`] is audited
+    /// via an [`Event::KillSwitchArmed`].
+    fn arm_kill_switch(&mut self, reason: KillSwitchReason) {
+        warn!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            ?reason,
+            "Trader kill switch armed, flattening the open Position"
+        );
+        self.paused = true;
+        self.kill_switch = true;
+        self.event_tx.send(Event::KillSwitchArmed(KillSwitchArmed {
+            time: self.clock.now(),
+            market: self.market.clone(),
+            reason,
+        }));
+
+        self.cancel_all_orders();
+        self.exit_position(self.market.clone());
+    }
+
+    /// Handles a panic of the Strategy or the Portfolio risk manager caught whilst handling an
+    /// [`Event`], as determined by the [`PanicPolicy`]. The [`Event`] that caused the panic is
+    /// always dropped.
+    fn handle_panic(&mut self, source: &'static str, payload: &(dyn Any + Send)) {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic payload".to_owned());
+
+        match self.panic_policy {
+            PanicPolicy::Terminate => {
+                error!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    source,
+                    %message,
+                    action = "flattening the open Position & terminating Trader",
+                    "caught panic handling Event"
+                );
+                if self.panicked.is_none() {
+                    self.panicked =
+                        Some((format!("{source} panicked: {message}"), self.clock.now()));
+                    self.arm_kill_switch(KillSwitchReason::Panic);
+                }
+            }
+            PanicPolicy::SkipEvent => {
+                error!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    source,
+                    %message,
+                    action = "skipping Event",
+                    "caught panic handling Event"
+                );
+            }
+            PanicPolicy::PauseTrading => {
+                error!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    source,
+                    %message,
+                    action = "pausing Trader until a Command::Resume is received",
+                    "caught panic handling Event"
+                );
+                self.paused = true;
+            }
+        }
+    }
+
+    /// Determines if the trading loop may terminate after a panic under [`PanicPolicy::Terminate`],
+    /// since the open Position has been flattened or the panic flatten timeout has expired.
+    fn panic_flattened_or_expired(&self) -> bool {
+        let Some((_, panicked_at)) = &self.panicked else {
+            return false;
+        };
+
+        let position_id = determine_position_id(
+            self.engine_id,
+            &self.market.exchange,
+            &self.market.instrument,
+        );
+        if matches!(
+            self.portfolio.lock().get_open_position(&position_id),
+            Ok(None)
+        ) {
+            return true;
+        }
+
+        let expired = chrono::Duration::from_std(self.panic_flatten_timeout)
+            .is_ok_and(|timeout| self.clock.now() >= *panicked_at + timeout);
+        if expired {
+            warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                timeout = ?self.panic_flatten_timeout,
+                "terminating Trader before the open Position was flattened after a panic"
+            );
+        }
+        expired
+    }
+
+    /// Result of the trading session once the trading loop stops, which is an
+    /// [`EngineError::Panicked`] if it stopped after a panic under [`PanicPolicy::Terminate`].
+    fn stopped(&mut self) -> Result<(), EngineError> {
+        match self.panicked.take() {
+            Some((panic, _)) => Err(EngineError::Panicked(panic)),
+            None => Ok(()),
+        }
+    }
+
+    /// Arms the kill switch if no [`MarketEvent`] of the [`Market`] has been received for the
+    /// stale data timeout of wall-clock time, since the open Position can no longer be managed.
+    fn check_data_stale(&mut self) {
+        let Some(timeout) = self.stale_data_timeout else {
+            return;
+        };
+
+        if self.kill_switch
+            || !self.clock.is_realtime()
+            || self.last_market_received_at.elapsed() < timeout
+        {
+            return;
+        }
+
+        error!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            ?timeout,
+            "no MarketEvent received within the stale data timeout"
+        );
+        self.arm_kill_switch(KillSwitchReason::DataStale);
+    }
+
+    /// Checks an opening [`OrderEvent`] against the [`TradingCalendar`] of it's [`Instrument`],
+    /// if any, returning the reason if the session is closed or about to close. Exits are
+    /// always allowed.
+    fn check_session_hours(&self, order: &OrderEvent) -> Result<(), &'static str> {
+        let Some(calendar) = self.trading_calendars.get(&order.instrument) else {
+            return Ok(());
+        };
+        if order.decision.is_exit() {
+            return Ok(());
+        }
+
+        let now = self.clock.now();
+        if !calendar.is_open(now) {
+            Err("outside trading session hours")
+        } else if calendar.is_flattening(now) {
+            Err("within the auto-flatten window before the trading session close")
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Flattens the open Position with a market order once the trading session is within the
+    /// auto-flatten duration of it's close, as configured in the [`TradingCalendar`].
+    fn check_session_close(&mut self) {
+        if self.exit_pending {
+            return;
+        }
+        let Some(calendar) = self.trading_calendars.get(&self.market.instrument) else {
+            return;
+        };
+        if !calendar.is_flattening(self.clock.now()) {
+            return;
+        }
+
+        info!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            session_close = ?calendar.session_close(self.clock.now()),
+            "trading session closing, flattening Position"
+        );
+        self.exit_pending = true;
+        self.event_q
+            .push_back(Event::SignalForceExit(SignalForceExit::from(
+                self.market.clone(),
+            )));
+    }
+
+    /// Exits the open Position if the latest market price crosses it's stop-loss or take-profit,
+    /// as configured in the [`StopManager`].
+    fn check_stops(&mut self, price: Decimal) {
+        if self.exit_pending || self.stop_manager.config(&self.market).is_none() {
+            return;
+        }
+
+        let position_id = determine_position_id(
+            self.engine_id,
+            &self.market.exchange,
+            &self.market.instrument,
+        );
+        let position = self.portfolio.lock().get_open_position(&position_id);
+
+        match position {
+            Ok(Some(position)) => {
+                if let Some(trigger) = self.stop_manager.check(&position, price) {
+                    info!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        ?trigger,
+                        %price,
+                        "stop triggered, exiting Position"
+                    );
+                    self.exit_pending = true;
+                    self.event_q
+                        .push_back(Event::SignalForceExit(SignalForceExit::from(
+                            self.market.clone(),
+                        )));
+                }
+            }
+            Ok(None) => {}
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    "failed to fetch open Position to check stops"
+                );
+            }
+        }
+    }
+
+    /// Evaluates an entry [`OrderEvent`] against the available margin of the [`MarginModel`],
+    /// returning it downsized if required, or the reason it is refused.
+    fn evaluate_margin(&self, order: OrderEvent) -> Result<OrderEvent, &'static str> {
+        if !order.decision.is_entry() || self.margin_model.config(&self.market).is_none() {
+            return Ok(order);
+        }
+
+        let margin = self.fetch_margin_inputs();
+        match margin {
+            Ok((balance, open_positions)) => {
+                let evaluated =
+                    self.margin_model
+                        .evaluate_order(order.clone(), &balance, &open_positions);
+                if evaluated.is_none() {
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        ?order,
+                        "refusing OrderEvent with insufficient available margin"
+                    );
+                }
+                evaluated.ok_or("insufficient available margin")
+            }
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    ?order,
+                    "refusing OrderEvent since margin could not be evaluated"
+                );
+                Err("margin could not be evaluated")
+            }
+        }
+    }
+
+    /// Liquidates the open Position if the latest market price breaches it's maintenance margin,
+    /// as modelled by the [`MarginModel`]. With cross margin, each [`Trader`] liquidates it's own
+    /// Position on it's next market price update after the account breaches maintenance margin.
+    fn check_margin(&mut self) {
+        if self.exit_pending || self.margin_model.config(&self.market).is_none() {
+            return;
+        }
+
+        let margin = self.fetch_margin_inputs();
+        match margin {
+            Ok((balance, open_positions)) => {
+                if self
+                    .margin_model
+                    .liquidations(&balance, &open_positions)
+                    .contains(&self.market)
+                {
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        margin = ?self.margin_model.margin(&balance, &open_positions),
+                        "maintenance margin breached, liquidating Position"
+                    );
+                    self.exit_pending = true;
+                    self.event_q
+                        .push_back(Event::SignalForceExit(SignalForceExit::from(
+                            self.market.clone(),
+                        )));
+                }
+            }
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    "failed to fetch Portfolio state to check margin"
+                );
+            }
+        }
+    }
+
+    /// Settles the funding of the open perpetual Position at every funding time of the
+    /// [`FundingModel`] crossed since the previous [`MarketEvent`], debiting or crediting the
+    /// Portfolio cash. Funding is folded into the realised P&L of the trading session.
+    fn settle_funding(&mut self) -> Result<(), EngineError> {
+        if self.funding_model.rate(&self.market).is_none() {
+            return Ok(());
+        }
+
+        let now = self.clock.now();
+        let Some(funded_until) = self.funded_until.replace(now) else {
+            return Ok(());
+        };
+        let funding_times = self.funding_model.funding_times(funded_until, now);
+        if funding_times.is_empty() {
+            return Ok(());
+        }
+
+        let position_id = determine_position_id(
+            self.engine_id,
+            &self.market.exchange,
+            &self.market.instrument,
+        );
+        let (payments, balance) = {
+            let mut portfolio = self.portfolio.lock();
+            let Some(position) = portfolio.get_open_position(&position_id)? else {
+                return Ok(());
+            };
+
+            let payments = funding_times
+                .into_iter()
+                .filter_map(|time| self.funding_model.payment(&self.market, &position, time))
+                .collect::<Vec<_>>();
+            if payments.is_empty() {
+                return Ok(());
+            }
+
+            let mut balance = portfolio.get_balance(self.engine_id)?;
+            let amount = payments
+                .iter()
+                .map(|payment| payment.amount)
+                .sum::<Decimal>();
+            balance.time = now;
+            balance.total += amount;
+            balance.available += amount;
+            portfolio.set_balance(self.engine_id, balance)?;
+            (payments, balance)
+        };
+
+        for payment in payments {
+            info!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                rate = payment.rate,
+                amount = %payment.amount,
+                "settled funding of open Position"
+            );
+            self.session.funding += payment.amount;
+            self.session.realised_profit_loss += payment.amount;
+            self.event_tx.send(Event::FundingPayment(payment));
+        }
+        self.event_tx.send(Event::Balance(balance));
+        Ok(())
+    }
+
+    /// Fetches the Portfolio [`Balance`] & the open [`Position`]s of every margin traded
+    /// [`Market`] used to evaluate the [`MarginModel`].
+    fn fetch_margin_inputs(&self) -> Result<(Balance, Vec<Position>), EngineError> {
+        let mut portfolio = self.portfolio.lock();
+        let balance = portfolio.get_balance(self.engine_id)?;
+        let open_positions =
+            portfolio.get_open_positions(self.engine_id, self.margin_model.markets())?;
+        Ok((balance, open_positions))
+    }
+
+    /// Determines if the Strategy is interested in [`MarketEvent`]s of the provided
+    /// [`Instrument`].
+    fn is_of_interest(&self, instrument: &Instrument) -> bool {
+        self.instruments_of_interest
+            .as_ref()
+            .is_none_or(|instruments| instruments.contains(instrument))
+    }
+
+    /// Records an [`EquitySample`] of the revalued Portfolio to the [`EquityRecorder`], if
+    /// configured.
+    fn record_equity(&self) {
+        let Some(equity_recorder) = &self.equity_recorder else {
+            return;
+        };
+
+        match self.portfolio_snapshot() {
+            Ok(snapshot) => equity_recorder.record(EquitySample::from(&snapshot)),
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    "failed to fetch Portfolio valuation for EquityRecorder"
+                );
+            }
+        }
+    }
+
+    /// Spawns the [`FillHooks`], if configured, with the applied [`FillEvent`] & the revalued
+    /// Portfolio.
+    fn spawn_fill_hooks(&self, fill: &FillEvent) {
+        let Some(fill_hooks) = &self.fill_hooks else {
+            return;
+        };
+
+        match self.portfolio_snapshot() {
+            Ok(snapshot) => fill_hooks.spawn(fill, &snapshot),
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    cid = %fill.cid,
+                    ?error,
+                    "failed to fetch Portfolio valuation for FillHooks, skipping them"
+                );
+            }
+        }
+    }
+
+    /// Takes a [`PortfolioSnapshot`] of the Portfolio [`Balance`] & the open [`Position`]s of
+    /// the valued [`Market`]s.
+    fn portfolio_snapshot(&self) -> Result<PortfolioSnapshot, RepositoryError> {
+        let mut portfolio = self.portfolio.lock();
+        let balance = portfolio.get_balance(self.engine_id)?;
+        let open_positions =
+            portfolio.get_open_positions(self.engine_id, self.equity_markets.iter())?;
+        Ok(PortfolioSnapshot::new(
+            self.clock.now(),
+            balance,
+            open_positions,
+        ))
+    }
+
+    /// Starts the fill cooldown of the [`Instrument`], if configured, so Signals for it are
+    /// dropped until the cooldown elapses.
+    fn start_cooldown(&mut self, instrument: &Instrument) {
+        let Some(cooldown) = self
+            .fill_cooldown
+            .and_then(|cooldown| chrono::Duration::from_std(cooldown).ok())
+        else {
+            return;
+        };
+
+        self.cooldowns
+            .insert(instrument.clone(), self.clock.now() + cooldown);
+    }
+
+    /// Determines if the [`Instrument`] is cooling down after a fill, removing it's cooldown
+    /// once elapsed.
+    fn is_cooling_down(&mut self, instrument: &Instrument) -> bool {
+        match self.cooldowns.get(instrument) {
+            Some(until) if self.clock.now() < *until => true,
+            Some(_) => {
+                self.cooldowns.remove(instrument);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Sends the consumed [`MarketEvent`] to the event_q for processing.
+    fn enqueue_market(&mut self, market: MarketEvent<DataKind>) {
+        self.event_tx.send(Event::Market(market.clone()));
+        self.event_q.push_back(Event::Market(market));
+    }
+
+    /// Sends every [`MarketEvent`] the [`ReorderBuffer`] (if any) is ready to release to the
+    /// event_q, in exchange timestamp order. Returns true if any were released.
+    fn release_reordered_markets(&mut self) -> bool {
+        let Some(buffer) = &mut self.reorder_buffer else {
+            return false;
+        };
+        let markets = buffer.release(self.clock.now());
+        let released = !markets.is_empty();
+        for market in markets {
+            self.enqueue_market(market);
+        }
+        released
+    }
+
+    /// Defers a Signal exit [`OrderEvent`] reducing or flattening an open Position younger than
+    /// the minimum holding time, if configured, returning the [`OrderEvent`] if it may be
+    /// dispatched now. A deferred exit replaces any exit already deferred.
+    fn defer_early_exit(&mut self, mut order: OrderEvent) -> Option<OrderEvent> {
+        let Some(min_holding_time) = self
+            .min_holding_time
+            .and_then(|min_holding_time| chrono::Duration::from_std(min_holding_time).ok())
+        else {
+            return Some(order);
+        };
+        if !order.decision.is_exit() {
+            return Some(order);
+        }
+
+        let Some(entered_at) = self.open_position_entered_at() else {
+            return Some(order);
+        };
+        if self.clock.now() >= entered_at + min_holding_time {
+            return Some(order);
+        }
+
+        // Identifies the deferred exit whilst queued, it is assigned a fresh ClientOrderId once
+        // sent for execution
+        order.cid = self.order_id_generator.next_id();
+        debug!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            %entered_at,
+            ?order,
+            "deferring exit OrderEvent until the Position reaches the minimum holding time"
+        );
+        self.deferred_exit = Some((entered_at, order));
+        None
+    }
+
+    /// Dispatches the deferred Signal exit [`OrderEvent`] once the Position it was deferred for
+    /// reaches the minimum holding time, priced at the latest [`MarketMeta`]. The exit is dropped
+    /// if that Position is no longer open.
+    fn release_deferred_exit(&mut self) {
+        let Some(min_holding_time) = self
+            .min_holding_time
+            .and_then(|min_holding_time| chrono::Duration::from_std(min_holding_time).ok())
+        else {
+            return;
+        };
+        match &self.deferred_exit {
+            Some((entered_at, _)) if self.clock.now() >= *entered_at + min_holding_time => {}
+            _ => return,
+        }
+
+        let Some((entered_at, mut order)) = self.deferred_exit.take() else {
+            return;
+        };
+        if self.open_position_entered_at() != Some(entered_at) {
+            debug!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                ?order,
+                "dropping deferred exit OrderEvent of a Position no longer open"
+            );
+            return;
+        }
+
+        order.time = self.clock.now();
+        if let Some(market_meta) = self.latest_market_meta {
+            order.market_meta = market_meta;
+        }
+        self.dispatch_order(order);
+    }
+
+    /// Returns the time the open Position of the [`Market`] was entered, or `None` if there is no
+    /// open Position (or it could not be fetched).
+    fn open_position_entered_at(&self) -> Option<DateTime<Utc>> {
+        let position_id = determine_position_id(
+            self.engine_id,
+            &self.market.exchange,
+            &self.market.instrument,
+        );
+        let position = self.portfolio.lock().get_open_position(&position_id);
+        match position {
+            Ok(position) => position.map(|position| position.meta.enter_time),
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    "failed to fetch open Position to evaluate the minimum holding time"
+                );
+                None
+            }
+        }
+    }
+
+    /// Removes every elapsed fill cooldown, transitioning out of [`TraderState::CoolingDown`]
+    /// once none remain.
+    fn expire_cooldowns(&mut self) {
+        let now = self.clock.now();
+        self.cooldowns.retain(|_, until| now < *until);
+        self.transition(TransitionTrigger::Timer);
+    }
+
+    /// Derives the [`TraderState`] of the trading loop from the pause, kill switch, warm up &
+    /// cooldown state of this [`Trader`].
+    fn derive_state(&self) -> TraderState {
+        if self.kill_switch {
+            TraderState::Halted
+        } else if self.paused {
+            TraderState::Paused
+        } else if self.warm_up > 0 {
+            TraderState::WarmingUp
+        } else if !self.cooldowns.is_empty() {
+            TraderState::CoolingDown
+        } else {
+            TraderState::Trading
+        }
+    }
+
+    /// Transitions to the [`TraderState`] derived from the current state of this [`Trader`],
+    /// if it has changed.
+    fn transition(&mut self, trigger: TransitionTrigger) {
+        let to_state = self.derive_state();
+        self.transition_to(to_state, trigger);
+    }
+
+    /// Transitions to the provided [`TraderState`] if it differs from the current
+    /// [`TraderState`], recording the [`Transition`] to the [`TransitionLog`] if configured.
+    fn transition_to(&mut self, to_state: TraderState, trigger: TransitionTrigger) {
+        if to_state == self.state {
+            return;
+        }
+
+        let transition = Transition {
+            at: self.clock.now(),
+            from_state: self.state,
+            to_state,
+            trigger,
+        };
+        debug!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            ?transition,
+            "Trader transitioned state"
+        );
+        if let Some(transition_log) = &mut self.transition_log {
+            transition_log.record(transition);
+        }
+        if let Some(digest) = &mut self.determinism_digest {
+            digest.record_transition(&transition);
+        }
+        if self.livelock_watchdog.record_transition() && self.livelock.is_none() {
+            self.livelock = Some((transition.from_state, transition.to_state));
+        }
+        self.state = to_state;
+    }
+
+    /// Returns an [`EngineError::Livelock`] if the [`LivelockWatchdog`] tripped, in which case
+    /// the trading loop must terminate.
+    fn check_livelock(&mut self) -> Option<EngineError> {
+        let (from_state, to_state) = self.livelock.take()?;
+        error!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            ?from_state,
+            ?to_state,
+            action = "terminating Trader",
+            "Trader livelocked transitioning between states without consuming a MarketEvent"
+        );
+        Some(EngineError::Livelock {
+            from_state,
+            to_state,
+        })
+    }
+
+    /// Sends an [`Event::Heartbeat`] if the heartbeat interval of [`Clock`] time has elapsed
+    /// without any market or fill events, or since the previous [`Heartbeat`]. Since a
+    /// [`SimulatedClock`](crate::clock::SimulatedClock) is only advanced by market events, a
+    /// backtest never sends a [`Heartbeat`].
+    fn send_heartbeat_if_due(&mut self) {
+        let Some(interval) = self.heartbeat_interval else {
+            return;
+        };
+
+        let now = self.clock.now();
+        let since_heartbeat = now - self.last_event_at.max(self.last_heartbeat_at);
+        if since_heartbeat
+            .to_std()
+            .map_or(true, |elapsed| elapsed < interval)
+        {
+            return;
+        }
+
+        let balance = self.portfolio.lock().get_balance(self.engine_id);
+        match balance {
+            Ok(balance) => {
+                self.event_tx.send(Event::Heartbeat(Heartbeat {
+                    time: now,
+                    market: self.market.clone(),
+                    last_event_age: (now - self.last_event_at).to_std().unwrap_or_default(),
+                    open_orders: self.pending_orders.len()
+                        + self.throttled_orders.len()
+                        + self.triggered_orders.len(),
+                    equity: balance.total,
+                }));
+            }
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    ?error,
+                    "failed to fetch Portfolio Balance for Heartbeat"
+                );
+            }
+        }
+
+        self.last_heartbeat_at = now;
+    }
+
+    /// Fetches the [`Balance`] of the exchange account this [`Trader`] executes on via
+    /// [`ExecutionClient::fetch_balance`]. Used by the [`Engine`](super::Engine) to seed the
+    /// Portfolio with the opening balance of every exchange before trading.
+    pub(super) fn fetch_balance(
+        &mut self,
+    ) -> impl Future<Output = Result<Option<Balance>, ExecutionError>> + Send + '_ {
+        self.execution.fetch_balance()
+    }
+
+    /// Takes a [`TraderCheckpoint`] of the Strategy state & the [`OrderEvent`]s this [`Trader`]
+    /// believes are open.
+    pub(super) fn checkpoint(&self) -> TraderCheckpoint {
+        let mut open_orders = self
+            .pending_orders
+            .values()
+            .map(|pending| pending.order.clone())
+            .collect::<Vec<_>>();
+        open_orders.sort_by_key(|order| order.cid);
+
+        TraderCheckpoint {
+            market: self.market.clone(),
+            open_orders,
+            throttled_orders: self.throttled_orders.iter().cloned().collect(),
+            triggered_orders: self.triggered_orders.clone(),
+            order_id_seed: self.order_id_generator.checkpoint_seed(),
+            strategy: self.strategy.snapshot(),
+        }
+    }
+
+    /// Returns a [`Command`] if one has been received, recording it to the [`SessionRecorder`]
+    /// if configured.
+    fn receive_remote_command(&mut self) -> Option<Command> {
+        let command = self.try_receive_remote_command()?;
+        if let Some(recorder) = &self.session_recorder {
+            recorder.record_command(self.clock.now(), &command);
+        }
+        Some(command)
+    }
+
+    fn try_receive_remote_command(&mut self) -> Option<Command> {
+        match self.command_rx.try_recv() {
+            Ok(command) => {
+                debug!(
+                    engine_id = &*self.engine_id.to_string(),
+                    market = &*format!("{:?}", self.market),
+                    command = &*format!("{:?}", command),
+                    "Trader received remote command"
+                );
+                Some(command)
+            }
+            Err(err) => match err {
+                mpsc::error::TryRecvError::Empty => None,
+                mpsc::error::TryRecvError::Disconnected => {
+                    warn!(
+                        action = "synthesising a Command::Terminate",
+                        "remote Command transmitter has been dropped"
+                    );
+                    Some(Command::Terminate(
+                        "remote command transmitter dropped".to_owned(),
+                    ))
+                }
+            },
+        }
+    }
+}
+
+/// Leg of a one-cancels-other group of [`OrderEvent`]s submitted via [`Command::SubmitOco`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+struct OcoLeg {
+    /// Identifier shared by both legs of the group.
+    group: Uuid,
+    /// [`ClientOrderId`] of the other leg of the group.
+    other: ClientOrderId,
+    /// Quantity the other leg of the group was submitted with.
+    other_quantity: Decimal,
+}
+
+/// [`OrderEvent`] sent for execution that is yet to be fully filled or cancelled.
+#[derive(Clone, PartialEq, Debug)]
+struct PendingOrder {
+    /// Time the [`OrderEvent`] was dispatched to the [`ExecutionClient`].
+    dispatched_at: DateTime<Utc>,
+    order: OrderEvent,
+    /// Absolute quantity of the [`OrderEvent`] filled so far.
+    filled: Decimal,
+    /// Number of times the [`OrderEvent`] was resubmitted after a transient rejection.
+    retries: usize,
+}
+
+/// [`OrderEvent`] rejected by the exchange for a transient reason, awaiting resubmission by the
+/// [`RetryPolicy`] of a [`Trader`].
+#[derive(Clone, PartialEq, Debug)]
+struct OrderRetry {
+    /// [`Clock`] time the [`OrderEvent`] is due to be resubmitted.
+    due_at: DateTime<Utc>,
+    /// Retry the resubmission will be, counting from 1 for the first retry.
+    retry: usize,
+    order: OrderEvent,
+}
+
+/// Bounded set of the exchange assigned `fill_id`s of the most recently applied [`FillEvent`]s,
+/// evicting the oldest once full.
+#[derive(Clone, PartialEq, Debug, Default)]
+struct AppliedFills {
+    ids: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl AppliedFills {
+    /// Maximum number of `fill_id`s remembered.
+    const CAPACITY: usize = 10_000;
+
+    /// Records the `fill_id` as applied, returning `false` if it was already applied.
+    fn insert(&mut self, fill_id: &str) -> bool {
+        if !self.ids.insert(fill_id.to_owned()) {
+            return false;
+        }
+        self.order.push_back(fill_id.to_owned());
+        if self.order.len() > Self::CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+/// Reason an [`OrderEvent`] is queued by a [`Trader`] rather than sent for execution.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum QueueReason {
+    /// Throttled until the [`RateLimiter`] has a token for it.
+    RateLimited,
+    /// Signal exit deferred until the open Position reaches the minimum holding time.
+    MinHoldingTime,
+    /// Rejected by the exchange for a transient reason, awaiting resubmission by the
+    /// [`RetryPolicy`] once it's backoff elapses.
+    RetryBackoff,
+}
+
+/// [`OrderEvent`] queued by a [`Trader`] rather than sent for execution, fetched via
+/// [`Command::QueryPendingOrders`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct QueuedOrder {
+    /// Queued [`OrderEvent`], identified by it's [`ClientOrderId`] when dropped via
+    /// [`Command::DropPendingOrder`].
+    pub order: OrderEvent,
+    /// Reason the [`OrderEvent`] is queued.
+    pub reason: QueueReason,
+    /// [`Clock`] time the [`OrderEvent`] is expected to be released. Queued [`OrderEvent`]s
+    /// are released by the trading loop, so may be released on the first [`MarketEvent`] after
+    /// this time.
+    pub release_at: DateTime<Utc>,
+}
+
+/// Audit record of an internal Position that drifted from the Position held on the exchange,
+/// detected by a [`Command::Reconcile`] & corrected to match the exchange.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PositionDiscrepancy {
+    /// Time the discrepancy was detected.
+    pub time: DateTime<Utc>,
+    /// [`Market`] of the drifted Position.
+    pub market: Market,
+    /// Net quantity of the internal Position before it was corrected.
+    pub internal_quantity: Decimal,
+    /// Net quantity of the Position held on the exchange.
+    pub exchange_quantity: Decimal,
+}
+
+/// Periodic liveness snapshot of a [`Trader`], sent when no market or fill events have been
+/// consumed for the configured heartbeat interval (eg/ during a quiet market).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Heartbeat {
+    /// Time the [`Heartbeat`] was generated.
+    pub time: DateTime<Utc>,
+    /// [`Market`] the [`Trader`] is bartering on.
+    pub market: Market,
+    /// Time elapsed since the latest market or fill event was consumed.
+    pub last_event_age: Duration,
+    /// Number of orders yet to be filled or cancelled, including orders throttled by a
+    /// [`RateLimiter`].
+    pub open_orders: usize,
+    /// Total Portfolio [`Balance`](crate::portfolio::Balance).
+    pub equity: Decimal,
+}
+
+/// Reason the kill switch of a [`Trader`] was armed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum KillSwitchReason {
+    /// Armed remotely via a [`Command::KillSwitch`].
+    Command,
+    /// Armed by the dead-man's switch since no [`MarketEvent`] was received within the stale
+    /// data timeout.
+    DataStale,
+    /// Armed since the Strategy or the Portfolio risk manager panicked under
+    /// [`PanicPolicy::Terminate`].
+    Panic,
+}
+
+/// Default time a [`Trader`] waits for the open Position to be flattened after a panic under
+/// [`PanicPolicy::Terminate`], before terminating regardless.
+pub const DEFAULT_PANIC_FLATTEN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Determines how the trading loop of a [`Trader`] handles a panic of the Strategy or the
+/// Portfolio risk manager. The [`Event`] that caused the panic is dropped in every case, and
+/// the Portfolio is left unchanged by it.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum PanicPolicy {
+    /// Arm the kill switch to cancel every open order & flatten the open Position, then
+    /// terminate the trading loop with an [`EngineError::Panicked`] once the open Position is
+    /// flattened, or the panic flatten timeout expires (eg/ the exit is never filled).
+    #[default]
+    Terminate,
+    /// Log the panic & continue trading.
+    SkipEvent,
+    /// Log the panic & pause the [`Trader`] (see [`Command::Pause`]), so no new orders are
+    /// generated until a [`Command::Resume`] is received.
+    PauseTrading,
+}
+
+/// Audit record of the kill switch of a [`Trader`] being armed.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KillSwitchArmed {
+    /// Time the kill switch was armed.
+    pub time: DateTime<Utc>,
+    /// [`Market`] the [`Trader`] is bartering on.
+    pub market: Market,
+    /// Reason the kill switch was armed.
+    pub reason: KillSwitchReason,
+}
+
+/// Audit record of the [`CircuitBreaker`] of a [`Trader`] tripping.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CircuitTripped {
+    /// Time the [`CircuitBreaker`] tripped.
+    pub time: DateTime<Utc>,
+    /// [`Market`] the [`Trader`] is bartering on.
+    pub market: Market,
+    /// [`CircuitMetric`] whose rate exceeded it's ceiling.
+    pub metric: CircuitMetric,
+    /// Rate per second of the [`CircuitMetric`] over the sliding window when it tripped.
+    pub rate: f64,
+    /// Ceiling on the rate per second of the [`CircuitMetric`].
+    pub ceiling: f64,
+}
+
+/// Audit record of an [`OrderEvent`] refused because the net Position quantity resulting from
+/// it's execution would exceed the hard position cap of the [`Trader`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PositionCapBreach {
+    /// Time the [`OrderEvent`] was refused.
+    pub time: DateTime<Utc>,
+    /// Refused [`OrderEvent`].
+    pub order: OrderEvent,
+    /// Net quantity of the open Position when the [`OrderEvent`] was refused.
+    pub position_quantity: Decimal,
+    /// Net Position quantity that would have resulted from executing the [`OrderEvent`].
+    pub resulting_quantity: Decimal,
+    /// Hard cap on the absolute net Position quantity.
+    pub cap: Decimal,
+}
+
+/// Results of a [`Trader`] trading session, returned by [`Trader::run`] once the [`Trader`] stops.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct SessionSummary<Statistic> {
+    /// [`Market`] the [`Trader`] was bartering on.
+    pub market: Market,
+    /// Time the trading session started.
+    pub started_at: DateTime<Utc>,
+    /// Time the trading session ended.
+    pub ended_at: DateTime<Utc>,
+    /// Number of [`MarketEvent`]s consumed from the [`MarketGenerator`].
+    pub market_events: u64,
+    /// Number of [`OrderEvent`](crate::portfolio::OrderEvent)s sent to the [`ExecutionClient`],
+    /// however many times each was (partially) filled.
+    pub orders: u64,
+    /// Number of [`FillEvent`](crate::execution::FillEvent)s applied to the Portfolio, excluding
+    /// foreign account & duplicate fills.
+    #[serde(default)]
+    pub fills: u64,
+    /// Sum of the realised P&L of every Position exited during the trading session, including
+    /// the net funding.
+    pub realised_profit_loss: Decimal,
+    /// Net funding received (+ve) or paid (-ve) on the open perpetual Position during the
+    /// trading session, as simulated by the [`FundingModel`].
+    #[serde(default)]
+    pub funding: Decimal,
+    /// Round-trip latency of every executed order, measured by the [`Trader`] [`Clock`] from the
+    /// time the [`OrderEvent`](crate::portfolio::OrderEvent) was dispatched to the
+    /// [`ExecutionClient`] to the time the first resulting
+    /// [`FillEvent`](crate::execution::FillEvent) was received.
+    pub order_latency: LatencyHistogram,
+    /// Number of [`FillEvent`](crate::execution::FillEvent)s received that did not match an
+    /// in-flight [`OrderEvent`](crate::portfolio::OrderEvent).
+    pub orphan_fills: u64,
+    /// Number of [`FillEvent`](crate::execution::FillEvent)s received for an account other than
+    /// the [`Trader`] [`AccountId`], which are not applied to the Portfolio.
+    pub foreign_account_fills: u64,
+    /// Number of [`FillEvent`](crate::execution::FillEvent)s received with the exchange
+    /// `fill_id` of a fill already applied, which are not applied to the Portfolio again.
+    pub duplicate_fills: u64,
+    /// Number of [`MarketEvent`]s dropped by the [`MarketGenerator`] rather than consumed (eg/ by
+    /// a [`BoundedMarketFeed`](crate::data::live::BoundedMarketFeed) at capacity).
+    pub dropped_events: u64,
+    /// [`TradeStats`] of every [`Trade`] completed during the
+    /// trading session, which the `by_instrument` & `by_strategy` breakdowns each sum to.
+    pub trade_totals: TradeStats,
+    /// [`InstrumentStats`] of the completed [`Trade`]s of each
+    /// [`Instrument`].
+    pub by_instrument: HashMap<Instrument, InstrumentStats>,
+    /// [`StrategyStats`] of the completed [`Trade`]s attributed
+    /// to each strategy via their [`STRATEGY_TAG`](crate::statistic::trade::STRATEGY_TAG).
+    pub by_strategy: HashMap<String, StrategyStats>,
+    /// Every [`TraderState`] [`Transition`] of the trading session, oldest first, if the
+    /// [`Trader`] was configured with a [`TransitionLog`]. Bounded by the [`TransitionLog`]
+    /// capacity.
+    pub transitions: Vec<Transition>,
+    /// [`DeterminismDigest`] of the trading session, if the [`Trader`] was configured with one.
+    pub digest: Option<DeterminismDigest>,
+    /// Final snapshot of the [`Market`] statistics (eg/ Sharpe ratio, max drawdown) tracked by
+    /// the Portfolio. Populated by the [`Engine`](super::Engine) once the [`Trader`] stops.
+    pub statistics: Option<Statistic>,
+}
+
+impl<Statistic> SessionSummary<Statistic> {
+    /// Constructs a new empty [`SessionSummary`] for the provided [`Market`].
+    pub fn new(market: Market) -> Self {
+        let now = Utc::now();
+        Self {
+            market,
+            started_at: now,
+            ended_at: now,
+            market_events: 0,
+            orders: 0,
+            fills: 0,
+            realised_profit_loss: Decimal::ZERO,
+            funding: Decimal::ZERO,
+            order_latency: LatencyHistogram::default(),
+            orphan_fills: 0,
+            foreign_account_fills: 0,
+            duplicate_fills: 0,
+            dropped_events: 0,
+            trade_totals: TradeStats::default(),
+            by_instrument: HashMap::new(),
+            by_strategy: HashMap::new(),
+            transitions: Vec::new(),
+            digest: None,
+            statistics: None,
+        }
+    }
+
+    /// Folds the completed [`Trade`] into the trade totals &
+    /// the breakdowns of it's [`Instrument`] & strategy.
+    pub fn record_trade(&mut self, trade: &Trade) {
+        self.trade_totals.update(trade);
+        self.by_instrument
+            .entry(trade.instrument.clone())
+            .or_default()
+            .update(trade);
+        self.by_strategy
+            .entry(trade.strategy().to_owned())
+            .or_default()
+            .update(trade);
+    }
+}
+
+/// Builder to construct [`Trader`] instances.
+#[derive(Debug, Default)]
+pub struct TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
+    Data: MarketGenerator<MarketEvent<DataKind>>,
+    Strategy: SignalGenerator,
+    Execution: ExecutionClient,
+{
+    engine_id: Option<Uuid>,
+    market: Option<Market>,
+    account: Option<AccountId>,
+    command_rx: Option<mpsc::Receiver<Command>>,
+    event_tx: Option<EventTx>,
+    portfolio: Option<Arc<Mutex<Portfolio>>>,
+    data: Option<Data>,
+    strategy: Option<Strategy>,
+    execution: Option<Execution>,
+    clock: Option<Arc<dyn Clock + Send + Sync>>,
+    heartbeat_interval: Option<Duration>,
+    stale_data_timeout: Option<Duration>,
+    stop_manager: Option<StopManager>,
+    margin_model: Option<MarginModel>,
+    funding_model: Option<FundingModel>,
+    rate_limiter: Option<RateLimiter>,
+    retry_policy: Option<RetryPolicy>,
+    instrument_filters: Option<InstrumentFilters>,
+    position_cap: Option<Decimal>,
+    cash_guard: Option<CashGuard>,
+    trading_calendars: HashMap<Instrument, TradingCalendar>,
+    reconcile_tolerance: Option<Decimal>,
+    fill_cooldown: Option<Duration>,
+    min_holding_time: Option<Duration>,
+    trade_ledger: Option<TradeLedger>,
+    transition_log: Option<TransitionLog>,
+    determinism_digest: Option<DeterminismDigest>,
+    bar_aggregator: Option<BarAggregator>,
+    reorder_buffer: Option<ReorderBuffer>,
+    session_recorder: Option<SessionRecorder>,
+    livelock_watchdog: Option<LivelockWatchdog>,
+    panic_policy: Option<PanicPolicy>,
+    panic_flatten_timeout: Option<Duration>,
+    circuit_breaker: Option<CircuitBreaker>,
+    warm_up: Option<usize>,
+    self_match_prevention: Option<bool>,
+    equity_recorder: Option<EquityRecorder>,
+    fill_hooks: Option<FillHooks>,
+    _statistic_marker: Option<PhantomData<Statistic>>,
+}
+
+impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+    TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
+where
+    EventTx: MessageTransmitter<Event>,
+    Statistic: Serialize + Send,
+    Portfolio: MarketUpdater + OrderGenerator + FillUpdater,
+    Data: MarketGenerator<MarketEvent<DataKind>> + Send,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    fn new() -> Self {
+        Self {
+            engine_id: None,
+            market: None,
+            account: None,
+            command_rx: None,
+            event_tx: None,
+            portfolio: None,
+            data: None,
+            strategy: None,
+            execution: None,
+            clock: None,
+            heartbeat_interval: None,
+            stale_data_timeout: None,
+            stop_manager: None,
+            margin_model: None,
+            funding_model: None,
+            rate_limiter: None,
+            retry_policy: None,
+            instrument_filters: None,
+            position_cap: None,
+            cash_guard: None,
+            trading_calendars: HashMap::new(),
+            reconcile_tolerance: None,
+            fill_cooldown: None,
+            min_holding_time: None,
+            trade_ledger: None,
+            transition_log: None,
+            determinism_digest: None,
+            bar_aggregator: None,
+            reorder_buffer: None,
+            session_recorder: None,
+            livelock_watchdog: None,
+            panic_policy: None,
+            panic_flatten_timeout: None,
+            circuit_breaker: None,
+            warm_up: None,
+            self_match_prevention: None,
+            equity_recorder: None,
+            fill_hooks: None,
+            _statistic_marker: None,
+        }
+    }
+
+    pub fn engine_id(self, value: Uuid) -> Self {
+        Self {
+            engine_id: Some(value),
+            ..self
+        }
+    }
+
+    pub fn market(self, value: Market) -> Self {
+        Self {
+            market: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`AccountId`] of the exchange account (or sub-account) every [`OrderEvent`] is
+    /// executed on. [`FillEvent`]s of other accounts are not applied to the Portfolio. Defaults
+    /// to [`AccountId::default`].
+    pub fn account(self, value: AccountId) -> Self {
+        Self {
+            account: Some(value),
+            ..self
+        }
+    }
+
+    pub fn command_rx(self, value: mpsc::Receiver<Command>) -> Self {
+        Self {
+            command_rx: Some(value),
+            ..self
+        }
+    }
 
     pub fn event_tx(self, value: EventTx) -> Self {
         Self {
-            event_tx: Some(value),
+            event_tx: Some(value),
+            ..self
+        }
+    }
+
+    pub fn portfolio(self, value: Arc<Mutex<Portfolio>>) -> Self {
+        Self {
+            portfolio: Some(value),
+            ..self
+        }
+    }
+
+    pub fn data(self, value: Data) -> Self {
+        Self {
+            data: Some(value),
+            ..self
+        }
+    }
+
+    pub fn strategy(self, value: Strategy) -> Self {
+        Self {
+            strategy: Some(value),
+            ..self
+        }
+    }
+
+    pub fn execution(self, value: Execution) -> Self {
+        Self {
+            execution: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`Clock`] used by the [`Trader`], defaults to a [`LiveClock`].
+    pub fn clock(self, value: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self {
+            clock: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional interval of [`Clock`] time without any market or fill events after which the
+    /// [`Trader`] sends an [`Event::Heartbeat`]. Heartbeats are disabled by default.
+    pub fn heartbeat_interval(self, value: Duration) -> Self {
+        Self {
+            heartbeat_interval: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional dead-man's switch interval of wall-clock time without a [`MarketEvent`] of the
+    /// [`Market`] after which the market data feed is deemed stale (eg/ silently disconnected).
+    /// The kill switch is then armed, flattening the open Position & locking the [`Trader`] until
+    /// a [`Command::Resume`], with an [`Event::KillSwitchArmed`] audited with
+    /// [`KillSwitchReason::DataStale`]. Never fires when replaying market events with a
+    /// simulated [`Clock`]. Disabled by default.
+    pub fn stale_data_timeout(self, value: Duration) -> Self {
+        Self {
+            stale_data_timeout: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`StopManager`] used to exit open Positions that cross a stop-loss or
+    /// take-profit, defaults to a [`StopManager`] with no stops configured.
+    pub fn stop_manager(self, value: StopManager) -> Self {
+        Self {
+            stop_manager: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`MarginModel`] used to check entry orders against the available margin & to
+    /// liquidate under-margined Positions, defaults to a [`MarginModel`] with no margin traded
+    /// [`Market`]s.
+    pub fn margin_model(self, value: MarginModel) -> Self {
+        Self {
+            margin_model: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`FundingModel`] used to settle the funding of the open perpetual Position at
+    /// each funding time, defaults to a [`FundingModel`] with no perpetual [`Market`]s.
+    pub fn funding_model(self, value: FundingModel) -> Self {
+        Self {
+            funding_model: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`RateLimiter`] capping the rate [`OrderEvent`]s are sent for execution. Orders
+    /// are unthrottled by default.
+    pub fn rate_limiter(self, value: RateLimiter) -> Self {
+        Self {
+            rate_limiter: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`RetryPolicy`] resubmitting [`OrderEvent`]s the exchange rejected for a
+    /// transient reason (eg/ [`RejectReason::RateLimited`](crate::execution::RejectReason)) with
+    /// a fresh [`ClientOrderId`] once it's backoff elapses. Rejections are not retried by
+    /// default.
+    pub fn retry_policy(self, value: RetryPolicy) -> Self {
+        Self {
+            retry_policy: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`InstrumentFilters`] of the [`Market`] every [`OrderEvent`] is normalised to
+    /// before it is sent for execution, rounding it's price to the tick size & it's quantity down
+    /// to the lot size. [`OrderEvent`]s are sent unchanged by default.
+    pub fn instrument_filters(self, value: InstrumentFilters) -> Self {
+        Self {
+            instrument_filters: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional hard cap on the absolute net Position quantity of the [`Market`]. Acts as a
+    /// last-line safety net independent of the Portfolio risk manager: every [`OrderEvent`] whose
+    /// resulting net Position quantity would exceed the cap is refused, logged at ERROR & audited
+    /// via an [`Event::PositionCapBreach`]. Uncapped by default.
+    pub fn position_cap(self, value: Decimal) -> Self {
+        Self {
+            position_cap: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`CashGuard`] preventing spot buys from over-spending the available quote asset
+    /// cash. Every buy [`OrderEvent`] whose notional value plus estimated fees exceeds the
+    /// available cash, less the cash committed to buy [`OrderEvent`]s awaiting execution, is
+    /// downsized or refused. Unguarded by default.
+    pub fn cash_guard(self, value: CashGuard) -> Self {
+        Self {
+            cash_guard: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`TradingCalendar`] of an [`Instrument`] with defined trading hours. Opening
+    /// [`OrderEvent`]s of the [`Instrument`] are refused outside it's sessions, and within the
+    /// auto-flatten duration before the session close, once the open Position is flattened.
+    /// Instruments trade around the clock by default.
+    pub fn trading_calendar<I>(mut self, instrument: I, value: TradingCalendar) -> Self
+    where
+        I: Into<Instrument>,
+    {
+        self.trading_calendars.insert(instrument.into(), value);
+        self
+    }
+
+    /// Absolute difference between the internal & exchange Position quantity tolerated by a
+    /// [`Command::Reconcile`] before the internal Position is corrected to match the exchange.
+    /// Defaults to zero, correcting any difference.
+    pub fn reconcile_tolerance(self, value: Decimal) -> Self {
+        Self {
+            reconcile_tolerance: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`Clock`] duration after a fill on an [`Instrument`] during which new Signals for
+    /// that [`Instrument`] are dropped (not queued), preventing the Strategy from overtrading.
+    /// Cooldowns elapse in wall time when live & simulated time when backtesting. Exits
+    /// (eg/ stops, [`Command::ExitPosition`]), cancels & the kill switch are unaffected.
+    /// Defaults to no cooldown.
+    pub fn fill_cooldown(self, value: Duration) -> Self {
+        Self {
+            fill_cooldown: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`Clock`] duration a Position must be held before a Signal may reduce or flatten
+    /// it, for venues penalising very short holds. Earlier Signal exits are deferred (not
+    /// dropped) until the first [`MarketEvent`] after the Position is old enough. Forced exits
+    /// (eg/ stops, liquidations, [`Command::ExitPosition`] & the kill switch) are unaffected.
+    /// Defaults to no minimum holding time.
+    pub fn min_holding_time(self, value: Duration) -> Self {
+        Self {
+            min_holding_time: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`TradeLedger`] pairing applied [`FillEvent`]s into the completed
+    /// [`Trade`]s broken down by the [`SessionSummary`]. Should
+    /// match the [`CostBasis`](crate::portfolio::position::CostBasis) of the Portfolio. Defaults
+    /// to a [`CostBasis::Fifo`](crate::portfolio::position::CostBasis::Fifo) ledger.
+    pub fn trade_ledger(self, value: TradeLedger) -> Self {
+        Self {
+            trade_ledger: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`TransitionLog`] every [`TraderState`] [`Transition`] of the trading loop is
+    /// recorded to, & returned in the [`SessionSummary`]. Transitions are not recorded by
+    /// default.
+    pub fn transition_log(self, value: TransitionLog) -> Self {
+        Self {
+            transition_log: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`DeterminismDigest`] every [`Transition`], [`OrderEvent`] & [`FillEvent`] of the
+    /// trading loop is hashed into, & returned in the [`SessionSummary`]. Sessions are not
+    /// hashed by default.
+    pub fn determinism_digest(self, value: DeterminismDigest) -> Self {
+        Self {
+            determinism_digest: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`BarAggregator`] the [`MarketEvent`]s of interest are aggregated into before
+    /// the Strategy is invoked, so tick-driven feeds can drive candle strategies. The Strategy is
+    /// invoked with every [`MarketEvent`] by default.
+    pub fn bar_aggregator(self, value: BarAggregator) -> Self {
+        Self {
+            bar_aggregator: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`ReorderBuffer`] every [`MarketEvent`] consumed from the [`MarketGenerator`] is
+    /// held in, so events interleaving out of order (eg/ across venue streams) are processed in
+    /// exchange timestamp order, at the cost of delaying each by up to the buffer window.
+    /// [`MarketEvent`]s are processed in the order consumed by default.
+    pub fn reorder_buffer(self, value: ReorderBuffer) -> Self {
+        Self {
+            reorder_buffer: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`SessionRecorder`] every [`MarketEvent`] consumed & [`Command`] received is
+    /// recorded to, so a live trading session can be replayed via
+    /// [`replay_from_log`](super::replay::replay_from_log). The [`ExecutionClient`] should be
+    /// wrapped in a [`RecordingExecution`](super::replay::RecordingExecution) sharing the same
+    /// [`SessionRecorder`]. Sessions are not recorded by default.
+    pub fn session_recorder(self, value: SessionRecorder) -> Self {
+        Self {
+            session_recorder: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`LivelockWatchdog`] terminating the trading loop with an
+    /// [`EngineError::Livelock`] once it makes more than the threshold of consecutive
+    /// [`TraderState`] [`Transition`]s without consuming a [`MarketEvent`]. Defaults to a
+    /// threshold of [`LivelockWatchdog::DEFAULT_THRESHOLD`].
+    pub fn livelock_watchdog(self, value: LivelockWatchdog) -> Self {
+        Self {
+            livelock_watchdog: Some(value),
             ..self
         }
     }
 
-    pub fn portfolio(self, value: Arc<Mutex<Portfolio>>) -> Self {
-        Self {
-            portfolio: Some(value),
-            ..self
+    /// Optional [`PanicPolicy`] determining how the trading loop handles a panic of the Strategy
+    /// or the Portfolio risk manager. Defaults to [`PanicPolicy::Terminate`].
+    pub fn panic_policy(self, value: PanicPolicy) -> Self {
+        Self {
+            panic_policy: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional time the trading loop keeps handling [`FillEvent`]s after a panic under
+    /// [`PanicPolicy::Terminate`], waiting for the open Position to be flattened before
+    /// terminating regardless. Defaults to [`DEFAULT_PANIC_FLATTEN_TIMEOUT`].
+    pub fn panic_flatten_timeout(self, value: Duration) -> Self {
+        Self {
+            panic_flatten_timeout: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`CircuitBreaker`] halting new orders once the order submission or fill rate of
+    /// the trading loop exceeds it's ceiling, audited via an [`Event::CircuitTripped`]. Cancels &
+    /// exits are still actioned whilst tripped, and a [`Command::Resume`] clears the
+    /// [`CircuitBreaker`]. Rates are not monitored by default.
+    pub fn circuit_breaker(self, value: CircuitBreaker) -> Self {
+        Self {
+            circuit_breaker: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional number of [`MarketEvent`]s consumed to warm up the Strategy (eg/ to populate
+    /// indicator history) before it may trade. The Portfolio & Strategy are updated by every
+    /// [`MarketEvent`], but the Signals of the first `value` unpaused [`MarketEvent`]s are
+    /// ignored. Defaults to no warm up.
+    pub fn warm_up(self, value: usize) -> Self {
+        Self {
+            warm_up: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional self-match prevention, netting opposing [`OrderEvent`]s of the same account &
+    /// [`Instrument`] that are queued for execution together (eg/ a Signal & a
+    /// [`Command::ManualOrder`]) and would match against each other on the exchange. Only the
+    /// residual of the larger [`OrderEvent`] is sent for execution, and the crossed quantity of
+    /// each is audited via an [`Event::OrderCancelled`]. Limit orders at non-crossing prices are
+    /// never netted. Disabled by default.
+    pub fn self_match_prevention(self, value: bool) -> Self {
+        Self {
+            self_match_prevention: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`EquityRecorder`] the Portfolio equity is recorded to every time the
+    /// [`Trader`] revalues the Portfolio from a [`MarketEvent`] or [`FillEvent`]. Only the open
+    /// Position of the [`Trader`] [`Market`] is valued, unless the [`Trader`] is part of an
+    /// [`Engine`](super::Engine) built with an
+    /// [`EngineBuilder::equity_recorder`](super::EngineBuilder::equity_recorder).
+    pub fn equity_recorder(self, value: EquityRecorder) -> Self {
+        Self {
+            equity_recorder: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`FillHooks`] spawned with every [`FillEvent`] applied to the Portfolio & the
+    /// [`PortfolioSnapshot`] of the [`Trader`] [`Market`] taken after it. No [`FillHooks`] by
+    /// default.
+    pub fn fill_hooks(self, value: FillHooks) -> Self {
+        Self {
+            fill_hooks: Some(value),
+            ..self
+        }
+    }
+
+    pub fn build(
+        self,
+    ) -> Result<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
+        let market = self
+            .market
+            .ok_or(EngineError::BuilderIncomplete("market"))?;
+        let strategy = self
+            .strategy
+            .ok_or(EngineError::BuilderIncomplete("strategy"))?;
+
+        Ok(Trader {
+            engine_id: self
+                .engine_id
+                .ok_or(EngineError::BuilderIncomplete("engine_id"))?,
+            session: SessionSummary::new(market.clone()),
+            equity_markets: vec![market.clone()],
+            market,
+            account: self.account.unwrap_or_default(),
+            command_rx: self
+                .command_rx
+                .ok_or(EngineError::BuilderIncomplete("command_rx"))?,
+            event_tx: self
+                .event_tx
+                .ok_or(EngineError::BuilderIncomplete("event_tx"))?,
+            event_q: VecDeque::with_capacity(2),
+            latest_market_meta: None,
+            pending_orders: HashMap::new(),
+            order_states: OrderStates::default(),
+            portfolio: self
+                .portfolio
+                .ok_or(EngineError::BuilderIncomplete("portfolio"))?,
+            data: self.data.ok_or(EngineError::BuilderIncomplete("data"))?,
+            instruments_of_interest: strategy.instruments_of_interest(),
+            strategy,
+            execution: self
+                .execution
+                .ok_or(EngineError::BuilderIncomplete("execution"))?,
+            clock: self.clock.unwrap_or_else(|| Arc::new(LiveClock)),
+            order_id_generator: Arc::new(MonotonicOrderIdGenerator::new()),
+            heartbeat_interval: self.heartbeat_interval,
+            stale_data_timeout: self.stale_data_timeout,
+            last_market_received_at: Instant::now(),
+            last_event_at: Utc::now(),
+            last_heartbeat_at: Utc::now(),
+            stop_manager: self.stop_manager.unwrap_or_default(),
+            margin_model: self.margin_model.unwrap_or_default(),
+            funding_model: self.funding_model.unwrap_or_default(),
+            funded_until: None,
+            exit_pending: false,
+            paused: false,
+            kill_switch: false,
+            rate_limiter: self.rate_limiter,
+            retry_policy: self.retry_policy,
+            order_retries: Vec::new(),
+            resubmitted: HashMap::new(),
+            instrument_filters: self.instrument_filters,
+            position_cap: self.position_cap,
+            cash_guard: self.cash_guard,
+            trading_calendars: self.trading_calendars,
+            reconcile_tolerance: self.reconcile_tolerance.unwrap_or_default(),
+            applied_fills: AppliedFills::default(),
+            fill_cooldown: self.fill_cooldown,
+            cooldowns: HashMap::new(),
+            min_holding_time: self.min_holding_time,
+            deferred_exit: None,
+            state: TraderState::Trading,
+            trade_ledger: self.trade_ledger.unwrap_or_default(),
+            transition_log: self.transition_log,
+            determinism_digest: self.determinism_digest,
+            bar_aggregator: self.bar_aggregator,
+            reorder_buffer: self.reorder_buffer,
+            session_recorder: self.session_recorder,
+            throttled_orders: VecDeque::new(),
+            triggered_orders: Vec::new(),
+            oco_legs: HashMap::new(),
+            livelock_watchdog: self.livelock_watchdog.unwrap_or_default(),
+            livelock: None,
+            panic_policy: self.panic_policy.unwrap_or_default(),
+            panic_flatten_timeout: self
+                .panic_flatten_timeout
+                .unwrap_or(DEFAULT_PANIC_FLATTEN_TIMEOUT),
+            circuit_breaker: self.circuit_breaker,
+            circuit_tripped: false,
+            panicked: None,
+            warm_up: self.warm_up.unwrap_or_default(),
+            self_match_prevention: self.self_match_prevention.unwrap_or_default(),
+            equity_recorder: self.equity_recorder,
+            checkpoint_requester: None,
+            fill_hooks: self.fill_hooks,
+            _statistic_marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        clock::SimulatedClock,
+        data::{
+            bar::BarInterval,
+            historical,
+            live::{BoundedMarketFeed, OverflowPolicy},
+            MarketMeta,
+        },
+        engine::hook::{FillHook, FillHookError},
+        event::EventTx,
+        execution::{
+            channel::{ChannelExecution, ExecutionRequest},
+            dry_run::{DryRunExecution, ExecutionMode},
+            error::ExecutionError,
+            order_state::OrderState,
+            retry::RetryPolicy,
+            simulated::{Config as ExecutionConfig, SimulatedExecution},
+            FillEvent, RejectReason,
+        },
+        portfolio::{
+            allocator::DefaultAllocator,
+            decimal_to_f64,
+            error::PortfolioError,
+            funding::FundingRate,
+            margin::{MarginConfig, MarginMode},
+            portfolio::MetaPortfolio,
+            position::{determine_position_id, CostBasis},
+            repository::{in_memory::InMemoryRepository, PositionHandler},
+            risk::DefaultRisk,
+            stop::{StopConfig, StopOffset},
+            OrderEvent, OrderType, TimeInForce, Trigger, TriggerDirection,
+        },
+        statistic::{
+            summary::trading::{Config as StatisticConfig, TradingSummary},
+            trade::{TradeLedger, STRATEGY_TAG, UNTAGGED_STRATEGY},
+        },
+        strategy::{
+            error::ParamError,
+            example::{Config as StrategyConfig, RSIStrategy},
+            Decision, Signal, SignalStrength,
+        },
+        test_util::{
+            fill_event, market_event_candle, market_event_trade,
+            mock::{MockExecution, MockFeed},
+            position,
+        },
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::{Duration, TimeZone, Timelike};
+    use std::collections::HashMap;
+
+    type TestPortfolio = MetaPortfolio<
+        InMemoryRepository<TradingSummary>,
+        DefaultAllocator,
+        DefaultRisk,
+        TradingSummary,
+    >;
+
+    /// Strategy that advises entering a long Position on every [`MarketEvent`].
+    #[derive(Debug)]
+    struct AlwaysLongStrategy;
+
+    impl SignalGenerator for AlwaysLongStrategy {
+        fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+            Some(Signal {
+                time: Utc::now(),
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+                market_meta: MarketMeta {
+                    close: Decimal::ONE_THOUSAND,
+                    time: market.exchange_time,
+                },
+                tags: Default::default(),
+            })
+        }
+    }
+
+    /// Strategy that records the [`Instrument`] of every [`MarketEvent`] it is invoked with, and
+    /// is only interested in the provided [`Instrument`].
+    #[derive(Debug)]
+    struct RecordingStrategy {
+        interest: Instrument,
+        invoked_with: Arc<Mutex<Vec<Instrument>>>,
+    }
+
+    impl SignalGenerator for RecordingStrategy {
+        fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+            self.invoked_with.lock().push(market.instrument.clone());
+            None
+        }
+
+        fn instruments_of_interest(&self) -> Option<HashSet<Instrument>> {
+            Some(HashSet::from([self.interest.clone()]))
+        }
+    }
+
+    /// Strategy that advises entering a long Position only at exactly 16:00, according to the
+    /// provided [`Clock`].
+    #[derive(Debug)]
+    struct FourPmStrategy {
+        clock: SimulatedClock,
+    }
+
+    impl SignalGenerator for FourPmStrategy {
+        fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+            let now = self.clock.now();
+            if (now.hour(), now.minute(), now.second()) != (16, 0, 0) {
+                return None;
+            }
+
+            Some(Signal {
+                time: now,
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+                market_meta: MarketMeta {
+                    close: Decimal::ONE_THOUSAND,
+                    time: market.exchange_time,
+                },
+                tags: Default::default(),
+            })
+        }
+    }
+
+    /// Execution handler that fails to execute every [`OrderEvent`].
+    #[derive(Debug)]
+    struct FailingExecution;
+
+    impl ExecutionClient for FailingExecution {
+        fn generate_fill(&mut self, _: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+            Err(ExecutionError::BuilderIncomplete("fill"))
+        }
+    }
+
+    /// Execution handler that simulates fills which cannot be matched to the executed
+    /// [`OrderEvent`] (eg/ a fill for an expired order).
+    #[derive(Debug)]
+    struct OrphanFillExecution;
+
+    impl ExecutionClient for OrphanFillExecution {
+        fn generate_fill(
+            &mut self,
+            order: &OrderEvent,
+        ) -> Result<Option<FillEvent>, ExecutionError> {
+            let fill = SimulatedExecution::new(ExecutionConfig::default())
+                .generate_fill(order)?
+                .map(|mut fill| {
+                    fill.cid.sequence += 1;
+                    fill
+                });
+            Ok(fill)
+        }
+    }
+
+    /// Execution handler for one sub-account of an exchange connection shared by several
+    /// sub-accounts. [`OrderEvent`]s are filled at the market price, with the [`FillEvent`]s
+    /// published to the shared stream of every sub-account & returned with the next
+    /// [`MarketEvent`].
+    #[derive(Debug)]
+    struct SharedVenueExecution {
+        fills: Arc<Mutex<Vec<FillEvent>>>,
+        seen: usize,
+    }
+
+    impl ExecutionClient for SharedVenueExecution {
+        fn generate_fill(
+            &mut self,
+            order: &OrderEvent,
+        ) -> Result<Option<FillEvent>, ExecutionError> {
+            let fill = SimulatedExecution::new(ExecutionConfig::default()).generate_fill(order)?;
+            self.fills.lock().extend(fill);
+            Ok(None)
+        }
+
+        fn fill_resting_orders(&mut self, _: &MarketEvent<DataKind>) -> Vec<FillEvent> {
+            let fills = self.fills.lock()[self.seen..].to_vec();
+            self.seen += fills.len();
+            fills
+        }
+    }
+
+    /// Market feed that sends the provided [`Command`]s to the [`Trader`] once every
+    /// [`MarketEvent`] has been yielded, before finishing. Used to action [`Command`]s after
+    /// the Trader has processed the [`MarketEvent`]s.
+    #[derive(Debug)]
+    struct CommandingFeed {
+        markets: VecDeque<MarketEvent<DataKind>>,
+        commands: Vec<Command>,
+        command_tx: Option<mpsc::Sender<Command>>,
+    }
+
+    impl CommandingFeed {
+        fn new(markets: Vec<MarketEvent<DataKind>>, commands: Vec<Command>) -> Self {
+            Self {
+                markets: VecDeque::from(markets),
+                commands,
+                command_tx: None,
+            }
+        }
+    }
+
+    impl MarketGenerator<MarketEvent<DataKind>> for CommandingFeed {
+        fn next(&mut self) -> Feed<MarketEvent<DataKind>> {
+            if let Some(market) = self.markets.pop_front() {
+                return Feed::Next(market);
+            }
+
+            match (self.commands.is_empty(), &self.command_tx) {
+                (false, Some(command_tx)) => {
+                    for command in self.commands.drain(..) {
+                        command_tx.try_send(command).unwrap();
+                    }
+                    // Unhealthy so the Trader receives the Commands before the Feed finishes
+                    Feed::Unhealthy
+                }
+                _ => Feed::Finished,
+            }
+        }
+    }
+
+    /// Step yielded by a [`ScriptedFeed`].
+    enum FeedStep {
+        Market(MarketEvent<DataKind>),
+        Command(Command),
+    }
+
+    /// Feed that yields each [`FeedStep`] in turn. [`Command`] steps are sent to the [`Trader`]
+    /// via an Unhealthy poll, so they are received before the next step is yielded.
+    struct ScriptedFeed {
+        steps: VecDeque<FeedStep>,
+        command_tx: mpsc::Sender<Command>,
+    }
+
+    impl MarketGenerator<MarketEvent<DataKind>> for ScriptedFeed {
+        fn next(&mut self) -> Feed<MarketEvent<DataKind>> {
+            match self.steps.pop_front() {
+                Some(FeedStep::Market(market)) => Feed::Next(market),
+                Some(FeedStep::Command(command)) => {
+                    self.command_tx.try_send(command).unwrap();
+                    Feed::Unhealthy
+                }
+                None => Feed::Finished,
+            }
+        }
+    }
+
+    fn market() -> Market {
+        Market::new("binance_spot", ("btc", "usdt", InstrumentKind::Spot))
+    }
+
+    fn portfolio(engine_id: Uuid) -> Arc<Mutex<TestPortfolio>> {
+        portfolio_of(engine_id, vec![market()])
+    }
+
+    fn portfolio_of(engine_id: Uuid, markets: Vec<Market>) -> Arc<Mutex<TestPortfolio>> {
+        Arc::new(Mutex::new(
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(markets)
+                .starting_cash(Decimal::from(10_000))
+                .repository(InMemoryRepository::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: Decimal::ONE_HUNDRED,
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(StatisticConfig {
+                    starting_equity: 10_000.0,
+                    trading_days_per_year: 365,
+                    risk_free_return: 0.0,
+                })
+                .build_and_init()
+                .unwrap(),
+        ))
+    }
+
+    fn trader<Data, Strategy, Execution>(
+        data: Data,
+        strategy: Strategy,
+        execution: Execution,
+    ) -> (
+        Trader<EventTx, TradingSummary, TestPortfolio, Data, Strategy, Execution>,
+        mpsc::Sender<Command>,
+        mpsc::UnboundedReceiver<Event>,
+    )
+    where
+        Data: MarketGenerator<MarketEvent<DataKind>> + Send,
+        Strategy: SignalGenerator + Send,
+        Execution: ExecutionClient + Send,
+    {
+        let engine_id = Uuid::new_v4();
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+
+        let trader = Trader::builder()
+            .engine_id(engine_id)
+            .market(market())
+            .command_rx(command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(portfolio(engine_id))
+            .data(data)
+            .strategy(strategy)
+            .execution(execution)
+            .build()
+            .unwrap();
+
+        (trader, command_tx, event_rx)
+    }
+
+    fn collect_events(mut event_rx: mpsc::UnboundedReceiver<Event>) -> Vec<Event> {
+        let mut events = Vec::new();
+        while let Ok(event) = event_rx.try_recv() {
+            events.push(event);
+        }
+        events
+    }
+
+    #[test]
+    fn trader_should_execute_order_generated_from_strategy_signal() {
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+
+        let order = events
+            .iter()
+            .find_map(|event| match event {
+                Event::OrderNew(order) => Some(order),
+                _ => None,
+            })
+            .expect("Trader did not generate an OrderEvent");
+        assert_eq!(order.decision, Decision::Long);
+        assert!(order.quantity > Decimal::ZERO);
+
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::Fill(fill) if fill.quantity == order.quantity)));
+    }
+
+    #[test]
+    fn trader_should_emit_events_in_trading_sequence_between_lifecycle_events() {
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        assert!(matches!(
+            events.as_slice(),
+            [
+                Event::TraderStarted(_),
+                Event::Market(_),
+                Event::Signal(_),
+                Event::OrderNew(_),
+                Event::Fill(_),
+                Event::PositionNew(_),
+                Event::Balance(_),
+                Event::TraderStopped(_)
+            ]
+        ));
+    }
+
+    /// Strategy that advises the next of the scripted [`Decision`]s on every [`MarketEvent`].
+    #[derive(Debug)]
+    struct ScriptedDecisionStrategy {
+        decisions: VecDeque<Decision>,
+    }
+
+    impl SignalGenerator for ScriptedDecisionStrategy {
+        fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+            Some(Signal {
+                time: market.exchange_time,
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(self.decisions.pop_front()?, SignalStrength(1.0))]),
+                market_meta: MarketMeta {
+                    close: Decimal::ONE_THOUSAND,
+                    time: market.exchange_time,
+                },
+                tags: Default::default(),
+            })
+        }
+    }
+
+    #[test]
+    fn trader_should_drop_signals_for_an_instrument_cooling_down_after_a_fill() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let market_events = [0, 10, 12, 20].map(|minute| {
+            let mut market_event = market_event_trade(Side::Buy);
+            market_event.exchange_time = day + Duration::minutes(minute);
+            market_event
+        });
+
+        let clock = SimulatedClock::new(day);
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new(market_events),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::from([
+                    Decision::Long,
+                    Decision::CloseLong,
+                    Decision::Long,
+                    Decision::Long,
+                ]),
+            },
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            clock: Arc::new(clock),
+            fill_cooldown: Some(std::time::Duration::from_secs(5 * 60)),
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        // The exit filled at 00:10 cools down until 00:15, so the 00:12 Long Signal is dropped
+        // rather than queued, and ordering resumes with the 00:20 Long Signal
+        let orders = collect_events(event_rx)
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) => Some((order.decision, order.market_meta.time)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            orders,
+            vec![
+                (Decision::Long, day),
+                (Decision::CloseLong, day + Duration::minutes(10)),
+                (Decision::Long, day + Duration::minutes(20)),
+            ]
+        );
+    }
+
+    /// Feed that never yields another [`MarketEvent`] after the first, instead bouncing the
+    /// [`Trader`] between [`TraderState::Trading`] & [`TraderState::Paused`] forever.
+    struct BouncingFeed {
+        market: Option<MarketEvent<DataKind>>,
+        paused: bool,
+        command_tx: mpsc::Sender<Command>,
+    }
+
+    impl MarketGenerator<MarketEvent<DataKind>> for BouncingFeed {
+        fn next(&mut self) -> Feed<MarketEvent<DataKind>> {
+            if let Some(market) = self.market.take() {
+                return Feed::Next(market);
+            }
+
+            self.paused = !self.paused;
+            let command = if self.paused {
+                Command::Pause
+            } else {
+                Command::Resume
+            };
+            self.command_tx.try_send(command).unwrap();
+            Feed::Idle
+        }
+    }
+
+    #[test]
+    fn trader_should_terminate_with_livelock_error_when_transitioning_without_consuming_events() {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (trader, _, _event_rx) = trader(
+            BouncingFeed {
+                market: Some(market_event_trade(Side::Buy)),
+                paused: false,
+                command_tx,
+            },
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            livelock_watchdog: LivelockWatchdog::new(10),
+            ..trader
+        };
+
+        // Without the watchdog the Trader would spin forever
+        let error = trader.run().unwrap_err();
+
+        // 11th consecutive Transition without a MarketEvent consumed trips the watchdog
+        assert!(
+            matches!(
+                error,
+                EngineError::Livelock {
+                    from_state: TraderState::Trading,
+                    to_state: TraderState::Paused,
+                }
+            ),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    /// Strategy that advises entering a long Position on it's first invocation, panics on it's
+    /// second & advises exiting the Position on every invocation thereafter. Counts it's
+    /// invocations.
+    #[derive(Debug)]
+    struct PanickingStrategy {
+        invocations: Arc<Mutex<usize>>,
+    }
+
+    impl SignalGenerator for PanickingStrategy {
+        fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+            let invocation = {
+                let mut invocations = self.invocations.lock();
+                *invocations += 1;
+                *invocations
+            };
+            let decision = match invocation {
+                1 => Decision::Long,
+                2 => panic!("PanickingStrategy panicked on it's second MarketEvent"),
+                _ => Decision::CloseLong,
+            };
+
+            Some(Signal {
+                time: Utc::now(),
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(decision, SignalStrength(1.0))]),
+                market_meta: MarketMeta {
+                    close: Decimal::ONE_THOUSAND,
+                    time: market.exchange_time,
+                },
+                tags: Default::default(),
+            })
+        }
+    }
+
+    #[test]
+    fn trader_should_handle_strategy_panic_according_to_panic_policy() {
+        let run = |panic_policy| {
+            let invocations = Arc::new(Mutex::new(0));
+            let (trader, _command_tx, event_rx) = trader(
+                historical::MarketFeed::new((0..4).map(|_| market_event_trade(Side::Buy))),
+                PanickingStrategy {
+                    invocations: Arc::clone(&invocations),
+                },
+                SimulatedExecution::new(ExecutionConfig::default()),
+            );
+            let portfolio = Arc::clone(&trader.portfolio);
+            let position_id = determine_position_id(
+                trader.engine_id,
+                &trader.market.exchange,
+                &trader.market.instrument,
+            );
+            let trader = Trader {
+                panic_policy,
+                ..trader
+            };
+
+            let result = trader.run();
+            let open_position = portfolio.lock().get_open_position(&position_id).unwrap();
+            let invocations = *invocations.lock();
+            (result, collect_events(event_rx), open_position, invocations)
+        };
+        let exited = |events: &[Event]| {
+            events
+                .iter()
+                .any(|event| matches!(event, Event::PositionExit(_)))
+        };
+
+        // Terminate flattens the open Position via the kill switch, then stops
+        let (result, events, open_position, invocations) = run(PanicPolicy::Terminate);
+        assert!(
+            matches!(
+                &result,
+                Err(EngineError::Panicked(panic)) if panic.starts_with("Strategy panicked")
+            ),
+            "unexpected result: {result:?}"
+        );
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::KillSwitchArmed(KillSwitchArmed {
+                reason: KillSwitchReason::Panic,
+                ..
+            })
+        )));
+        assert!(exited(&events));
+        assert!(open_position.is_none());
+        assert_eq!(invocations, 2);
+
+        // SkipEvent drops the panicking MarketEvent, then exits via the next Signal
+        let (result, events, open_position, invocations) = run(PanicPolicy::SkipEvent);
+        assert!(result.is_ok());
+        assert!(exited(&events));
+        assert!(open_position.is_none());
+        assert_eq!(invocations, 4);
+
+        // PauseTrading ignores every Signal after the panic, leaving the Position untouched
+        let (result, events, open_position, invocations) = run(PanicPolicy::PauseTrading);
+        assert!(result.is_ok());
+        assert!(!exited(&events));
+        assert_eq!(open_position.unwrap().quantity, Decimal::new(1, 1));
+        assert_eq!(invocations, 4);
+    }
+
+    /// Execution handler filling entry [`OrderEvent`]s at the market price immediately, whilst
+    /// exit [`OrderEvent`]s rest until filled by the next [`MarketEvent`], or never if
+    /// `fill_exits` is false.
+    #[derive(Debug)]
+    struct DelayedExitExecution {
+        resting: Vec<FillEvent>,
+        fill_exits: bool,
+    }
+
+    impl ExecutionClient for DelayedExitExecution {
+        fn generate_fill(
+            &mut self,
+            order: &OrderEvent,
+        ) -> Result<Option<FillEvent>, ExecutionError> {
+            let fill = SimulatedExecution::new(ExecutionConfig::default()).generate_fill(order)?;
+            if !order.decision.is_exit() {
+                return Ok(fill);
+            }
+            if self.fill_exits {
+                self.resting.extend(fill);
+            }
+            Ok(None)
+        }
+
+        fn fill_resting_orders(&mut self, _: &MarketEvent<DataKind>) -> Vec<FillEvent> {
+            std::mem::take(&mut self.resting)
+        }
+    }
+
+    #[test]
+    fn trader_should_wait_for_delayed_flatten_fill_before_terminating_after_panic() {
+        let run = |fill_exits, panic_flatten_timeout| {
+            let invocations = Arc::new(Mutex::new(0));
+            let (trader, _command_tx, event_rx) = trader(
+                historical::MarketFeed::new((0..4).map(|_| market_event_trade(Side::Buy))),
+                PanickingStrategy {
+                    invocations: Arc::clone(&invocations),
+                },
+                DelayedExitExecution {
+                    resting: Vec::new(),
+                    fill_exits,
+                },
+            );
+            let portfolio = Arc::clone(&trader.portfolio);
+            let position_id = determine_position_id(
+                trader.engine_id,
+                &trader.market.exchange,
+                &trader.market.instrument,
+            );
+            let trader = Trader {
+                panic_flatten_timeout,
+                ..trader
+            };
+
+            let result = trader.run();
+            let open_position = portfolio.lock().get_open_position(&position_id).unwrap();
+            let invocations = *invocations.lock();
+            (result, collect_events(event_rx), open_position, invocations)
+        };
+        let panicked = |result: &Result<SessionSummary<_>, EngineError>| {
+            matches!(
+                result,
+                Err(EngineError::Panicked(panic)) if panic.starts_with("Strategy panicked")
+            )
+        };
+
+        // Flatten order rests on the panicking MarketEvent & is filled by the next one
+        let (result, events, open_position, invocations) = run(true, DEFAULT_PANIC_FLATTEN_TIMEOUT);
+        assert!(panicked(&result), "unexpected result: {result:?}");
+        let armed_at = events
+            .iter()
+            .position(|event| matches!(event, Event::KillSwitchArmed(_)))
+            .unwrap();
+        assert!(events[armed_at..]
+            .iter()
+            .any(|event| matches!(event, Event::PositionExit(_))));
+        assert!(open_position.is_none());
+        assert_eq!(invocations, 2);
+
+        // Flatten order is never filled, so the Trader terminates once the timeout expires
+        let (result, events, open_position, invocations) = run(false, std::time::Duration::ZERO);
+        assert!(panicked(&result), "unexpected result: {result:?}");
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::PositionExit(_))));
+        assert_eq!(open_position.unwrap().quantity, Decimal::new(1, 1));
+        assert_eq!(invocations, 2);
+    }
+
+    #[test]
+    fn trader_should_record_transition_log_of_scripted_session_in_session_summary() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let market_at = |minute| {
+            let mut market_event = market_event_trade(Side::Buy);
+            market_event.exchange_time = day + Duration::minutes(minute);
+            market_event
+        };
+
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (trader, _, _event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_at(0)),
+                    FeedStep::Market(market_at(1)),
+                    FeedStep::Command(Command::Pause),
+                    FeedStep::Command(Command::Resume),
+                    FeedStep::Market(market_at(10)),
+                    FeedStep::Command(Command::KillSwitch),
+                ]),
+                command_tx,
+            },
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            clock: Arc::new(SimulatedClock::new(day)),
+            warm_up: 1,
+            fill_cooldown: Some(std::time::Duration::from_secs(5 * 60)),
+            transition_log: Some(TransitionLog::new(16)),
+            ..trader
+        };
+
+        let summary = trader.run().unwrap();
+
+        let transition = |minute, from_state, to_state, trigger| Transition {
+            at: day + Duration::minutes(minute),
+            from_state,
+            to_state,
+            trigger,
+        };
+        assert_eq!(
+            summary.transitions,
+            vec![
+                transition(
+                    0,
+                    TraderState::WarmingUp,
+                    TraderState::Trading,
+                    TransitionTrigger::Market
+                ),
+                transition(
+                    1,
+                    TraderState::Trading,
+                    TraderState::CoolingDown,
+                    TransitionTrigger::Account
+                ),
+                transition(
+                    1,
+                    TraderState::CoolingDown,
+                    TraderState::Paused,
+                    TransitionTrigger::Command
+                ),
+                transition(
+                    1,
+                    TraderState::Paused,
+                    TraderState::CoolingDown,
+                    TransitionTrigger::Command
+                ),
+                transition(
+                    10,
+                    TraderState::CoolingDown,
+                    TraderState::Trading,
+                    TransitionTrigger::Timer
+                ),
+                transition(
+                    10,
+                    TraderState::Trading,
+                    TraderState::Halted,
+                    TransitionTrigger::Command
+                ),
+                transition(
+                    10,
+                    TraderState::Halted,
+                    TraderState::Stopped,
+                    TransitionTrigger::Market
+                ),
+            ]
+        );
+    }
+
+    /// Replays a scripted session over a recorded feed, entering & exiting a manual order of
+    /// the provided quantity, and returns the [`DeterminismDigest`] of the session.
+    fn replay_digest(quantity: Decimal) -> DeterminismDigest {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let market_at = |minute, price| {
+            let mut market_event = market_event_trade(Side::Buy);
+            market_event.exchange_time = day + Duration::minutes(minute);
+            if let DataKind::Trade(trade) = &mut market_event.kind {
+                trade.price = price;
+            }
+            market_event
+        };
+
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (trader, _, _event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_at(0, 1000.0)),
+                    FeedStep::Command(Command::ManualOrder(manual_order_request(quantity, None))),
+                    FeedStep::Market(market_at(1, 1010.0)),
+                    FeedStep::Command(Command::ManualOrder(ManualOrderRequest {
+                        side: Side::Sell,
+                        ..manual_order_request(quantity, None)
+                    })),
+                    FeedStep::Market(market_at(10, 990.0)),
+                ]),
+                command_tx,
+            },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            clock: Arc::new(SimulatedClock::new(day)),
+            fill_cooldown: Some(std::time::Duration::from_secs(5 * 60)),
+            determinism_digest: Some(DeterminismDigest::new()),
+            ..trader
+        };
+
+        trader.run().unwrap().digest.unwrap()
+    }
+
+    #[test]
+    fn trader_should_produce_identical_determinism_digests_for_the_same_replay() {
+        let digest = replay_digest(Decimal::ONE);
+
+        // Two orders, two fills & the cooldown transitions after each fill
+        assert!(digest.records >= 4);
+        assert_eq!(digest, replay_digest(Decimal::ONE));
+    }
+
+    #[test]
+    fn trader_should_produce_a_different_determinism_digest_when_an_order_quantity_changes() {
+        let digest = replay_digest(Decimal::ONE);
+        let changed = replay_digest(Decimal::TWO);
+
+        assert_eq!(digest.records, changed.records);
+        assert_ne!(digest.hash, changed.hash);
+    }
+
+    /// Strategy that advises entering a long Position on every [`MarketEvent`] with the provided
+    /// [`SignalStrength`], eg/ erroneously scaling it's order size.
+    #[derive(Debug)]
+    struct ScaledLongStrategy {
+        strength: f64,
+    }
+
+    impl SignalGenerator for ScaledLongStrategy {
+        fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+            Some(Signal {
+                time: Utc::now(),
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(Decision::Long, SignalStrength(self.strength))]),
+                market_meta: MarketMeta {
+                    close: Decimal::ONE_THOUSAND,
+                    time: market.exchange_time,
+                },
+                tags: Default::default(),
+            })
+        }
+    }
+
+    #[test]
+    fn trader_should_only_apply_fills_of_its_account_to_its_portfolio_slice() {
+        let market = market();
+        let venue_fills = Arc::new(Mutex::new(Vec::new()));
+
+        // Sub-accounts share an exchange connection, but each trades a Strategy with it's own
+        // Portfolio slice. Orders are sized 0.1 per unit of SignalStrength.
+        let run = |account: &str, strength| {
+            let engine_id = Uuid::new_v4();
+            let portfolio = portfolio(engine_id);
+            let (trader, _command_tx, _event_rx) = trader(
+                historical::MarketFeed::new([
+                    market_event_trade(Side::Buy),
+                    market_event_trade(Side::Buy),
+                ]),
+                ScaledLongStrategy { strength },
+                SharedVenueExecution {
+                    fills: Arc::clone(&venue_fills),
+                    seen: 0,
+                },
+            );
+            let trader = Trader {
+                engine_id,
+                account: AccountId::new(account),
+                portfolio: Arc::clone(&portfolio),
+                ..trader
+            };
+
+            let summary = trader.run().unwrap();
+            let position_id =
+                determine_position_id(engine_id, &market.exchange, &market.instrument);
+            let position = portfolio
+                .lock()
+                .get_open_position(&position_id)
+                .unwrap()
+                .expect("sub-account did not enter a Position from it's FillEvent");
+            (summary, position)
+        };
+
+        let (summary_a, position_a) = run("sub_account_a", 1.0);
+        let (summary_b, position_b) = run("sub_account_b", 3.0);
+
+        // Sub-account B also received the FillEvent of sub-account A, which was not applied
+        assert_eq!(position_a.quantity, Decimal::new(1, 1));
+        assert_eq!(summary_a.foreign_account_fills, 0);
+        assert_eq!(position_b.quantity, Decimal::new(3, 1));
+        assert_eq!(summary_b.foreign_account_fills, 1);
+
+        let accounts = venue_fills
+            .lock()
+            .iter()
+            .map(|fill| fill.account.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            accounts,
+            vec![
+                AccountId::new("sub_account_a"),
+                AccountId::new("sub_account_b")
+            ]
+        );
+    }
+
+    #[test]
+    fn trader_should_refuse_orders_breaching_the_position_cap() {
+        // Orders are sized 0.1 at 1000.0 per unit of SignalStrength, so a 10x order is 1.0
+        let run = |strength| {
+            let (trader, _command_tx, event_rx) = trader(
+                historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+                ScaledLongStrategy { strength },
+                SimulatedExecution::new(ExecutionConfig::default()),
+            );
+            let trader = Trader {
+                position_cap: Some(Decimal::new(5, 1)),
+                ..trader
+            };
+            trader.run().unwrap();
+            collect_events(event_rx)
+        };
+
+        // Order within the position cap is sent for execution
+        let events = run(1.0);
+        assert!(events.iter().any(
+            |event| matches!(event, Event::OrderNew(order) if order.quantity == Decimal::new(1, 1))
+        ));
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::PositionCapBreach(_))));
+
+        // Erroneous 10x order is refused & audited, so no order is sent nor Position entered
+        let events = run(10.0);
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::OrderNew(_) | Event::PositionNew(_))));
+        let breaches = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::PositionCapBreach(breach) => Some(breach),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(breaches.len(), 1);
+        assert_eq!(breaches[0].position_quantity, Decimal::ZERO);
+        assert_eq!(breaches[0].resulting_quantity, Decimal::ONE);
+        assert_eq!(breaches[0].cap, Decimal::new(5, 1));
+    }
+
+    /// Runs a [`Trader`] with 10_000.0 starting cash & a [`CashGuard`] estimating 0.1% fees,
+    /// actioning each buy [`ManualOrderRequest`] of the provided quantity & limit price below the
+    /// 1000.0 market price (so it rests). Returns the [`CommandResult`] of each request alongside
+    /// the quantity of every [`OrderEvent`] sent for execution.
+    fn run_cash_guarded(
+        downsize: bool,
+        requests: &[(Decimal, f64)],
+    ) -> (Vec<CommandResult>, Vec<Decimal>) {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let mut steps = VecDeque::from([FeedStep::Market(market_event_trade(Side::Buy))]);
+        steps.extend(
+            requests
+                .iter()
+                .enumerate()
+                .map(|(sequence, (quantity, limit_price))| {
+                    FeedStep::Command(Command::Correlated {
+                        id: Uuid::from_u128(sequence as u128),
+                        command: Box::new(Command::ManualOrder(manual_order_request(
+                            *quantity,
+                            Some(*limit_price),
+                        ))),
+                    })
+                }),
+        );
+
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed { steps, command_tx },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            cash_guard: Some(CashGuard {
+                fee_rate: 0.001,
+                downsize,
+            }),
+            ..trader
+        };
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let results = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::CommandOutcome(outcome) => Some(outcome.result.clone()),
+                _ => None,
+            })
+            .collect();
+        let quantities = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) => Some(order.quantity),
+                _ => None,
+            })
+            .collect();
+        (results, quantities)
+    }
+
+    #[test]
+    fn trader_should_refuse_buy_exceeding_available_cash() {
+        // 10 @ 999.0 requires 9989.99 including fees, whereas 11 @ 999.0 requires 10998.99
+        let (results, quantities) =
+            run_cash_guarded(false, &[(Decimal::from(11), 999.0), (Decimal::TEN, 999.0)]);
+
+        assert!(matches!(
+            &results[0],
+            CommandResult::Rejected(reason) if reason.starts_with("Insufficient cash")
+        ));
+        assert_eq!(results[1], CommandResult::Accepted);
+        assert_eq!(quantities, vec![Decimal::TEN]);
+    }
+
+    #[test]
+    fn trader_should_downsize_buy_to_available_cash() {
+        // 20 @ 990.0 requires 19819.8 including fees, so is downsized to 10_000.0 / 990.99
+        let (results, quantities) = run_cash_guarded(true, &[(Decimal::from(20), 990.0)]);
+
+        assert_eq!(results, vec![CommandResult::Accepted]);
+        assert_eq!(quantities, vec![Decimal::new(100909, 4)]);
+    }
+
+    #[test]
+    fn trader_should_exclude_cash_reserved_by_resting_buys_from_available_cash() {
+        // Resting buys of 1 @ 990.0 & 1 @ 980.0 reserve 1971.97 including fees, leaving
+        // 8028.03 available, which cannot afford 9 @ 970.0 requiring 8738.73
+        let (results, quantities) = run_cash_guarded(
+            false,
+            &[
+                (Decimal::ONE, 990.0),
+                (Decimal::ONE, 980.0),
+                (Decimal::from(9), 970.0),
+            ],
+        );
+        assert_eq!(
+            results[..2],
+            [CommandResult::Accepted, CommandResult::Accepted]
+        );
+        assert!(matches!(&results[2], CommandResult::Rejected(_)));
+        assert_eq!(quantities, vec![Decimal::ONE, Decimal::ONE]);
+
+        // Cancelling the 990.0 buy releases it's reserved cash, leaving 9019.02 available
+        let (mut trader, cids, event_rx) = trader_with_resting_orders(&[990.0, 980.0], vec![]);
+        trader.data.commands = vec![
+            Command::CancelOrder { id: cids[0] },
+            Command::ManualOrder(manual_order_request(Decimal::from(9), Some(970.0))),
+        ];
+        let trader = Trader {
+            cash_guard: Some(CashGuard {
+                fee_rate: 0.001,
+                downsize: false,
+            }),
+            ..trader
+        };
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        assert_eq!(cancelled_cids(&events), vec![cids[0]]);
+        let quantities = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) => Some(order.quantity),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            quantities,
+            vec![Decimal::ONE, Decimal::ONE, Decimal::from(9)]
+        );
+    }
+
+    #[test]
+    fn trader_should_advance_simulated_clock_to_market_event_exchange_time() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let market_events = [(15, 59), (16, 0), (16, 1)].map(|(hour, minute)| {
+            let mut market_event = market_event_trade(Side::Buy);
+            market_event.exchange_time = day + Duration::hours(hour) + Duration::minutes(minute);
+            market_event
+        });
+
+        let clock = SimulatedClock::new(day);
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new(market_events),
+            FourPmStrategy {
+                clock: clock.clone(),
+            },
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            clock: Arc::new(clock),
+            ..trader
+        };
+
+        let summary = trader.run().unwrap();
+
+        let signal_times = collect_events(event_rx)
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::Signal(signal) => Some(signal.time),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(signal_times, vec![day + Duration::hours(16)]);
+        assert_eq!(summary.started_at, day);
+        assert_eq!(
+            summary.ended_at,
+            day + Duration::hours(16) + Duration::minutes(1)
+        );
+    }
+
+    /// Market feed of a quiet market that never yields a [`MarketEvent`], advancing the provided
+    /// [`SimulatedClock`] by a step of wall-clock time each time it is polled.
+    #[derive(Debug)]
+    struct PausedFeed {
+        clock: SimulatedClock,
+        step: Duration,
+        polls: usize,
+    }
+
+    impl MarketGenerator<MarketEvent<DataKind>> for PausedFeed {
+        fn next(&mut self) -> Feed<MarketEvent<DataKind>> {
+            if self.polls == 0 {
+                return Feed::Finished;
+            }
+
+            self.polls -= 1;
+            self.clock.advance(self.clock.now() + self.step);
+            Feed::Idle
+        }
+    }
+
+    #[test]
+    fn trader_should_send_heartbeats_at_configured_interval_during_quiet_market() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let clock = SimulatedClock::new(start);
+
+        // 100 seconds of a quiet market, polled every 10 seconds
+        let (trader, _command_tx, event_rx) = trader(
+            PausedFeed {
+                clock: clock.clone(),
+                step: Duration::seconds(10),
+                polls: 10,
+            },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            clock: Arc::new(clock),
+            heartbeat_interval: Some(std::time::Duration::from_secs(30)),
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        let heartbeats = collect_events(event_rx)
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::Heartbeat(heartbeat) => Some(heartbeat),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let expected_times = [30, 60, 90].map(|seconds| start + Duration::seconds(seconds));
+        assert_eq!(
+            heartbeats
+                .iter()
+                .map(|heartbeat| heartbeat.time)
+                .collect::<Vec<_>>(),
+            expected_times
+        );
+        assert_eq!(
+            heartbeats[2].last_event_age,
+            std::time::Duration::from_secs(90)
+        );
+        assert_eq!(heartbeats[2].open_orders, 0);
+        assert_eq!(heartbeats[2].equity, Decimal::from(10_000));
+    }
+
+    #[test]
+    fn trader_should_not_send_heartbeats_when_replaying_market_events() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let market_events = [0, 1, 2].map(|hour| {
+            let mut market_event = market_event_trade(Side::Buy);
+            market_event.exchange_time = day + Duration::hours(hour);
+            market_event
+        });
+
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new(market_events),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            clock: Arc::new(SimulatedClock::new(day)),
+            heartbeat_interval: Some(std::time::Duration::from_secs(30)),
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        assert!(!collect_events(event_rx)
+            .iter()
+            .any(|event| matches!(event, Event::Heartbeat(_))));
+    }
+
+    /// [`MarketGenerator`] that yields one [`MarketEvent`] and then goes silent, yielding
+    /// [`Feed::Idle`] for the provided wall-clock duration before finishing.
+    struct SilentFeed {
+        market: Option<MarketEvent<DataKind>>,
+        silent_for: std::time::Duration,
+        silent_since: Option<std::time::Instant>,
+    }
+
+    impl MarketGenerator<MarketEvent<DataKind>> for SilentFeed {
+        fn next(&mut self) -> Feed<MarketEvent<DataKind>> {
+            if let Some(market) = self.market.take() {
+                return Feed::Next(market);
+            }
+
+            let silent_since = *self.silent_since.get_or_insert_with(Instant::now);
+            if silent_since.elapsed() >= self.silent_for {
+                return Feed::Finished;
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(5));
+            Feed::Idle
+        }
+    }
+
+    #[test]
+    fn trader_should_arm_kill_switch_once_market_data_goes_stale() {
+        let timeout = std::time::Duration::from_millis(50);
+        let (trader, _command_tx, event_rx) = trader(
+            SilentFeed {
+                market: Some(market_event_trade(Side::Buy)),
+                silent_for: std::time::Duration::from_millis(250),
+                silent_since: None,
+            },
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            stale_data_timeout: Some(timeout),
+            ..trader
+        };
+
+        let started = Utc::now();
+        trader.run().unwrap();
+        let events = collect_events(event_rx);
+
+        // The dead-man's switch fired once the feed was silent for the timeout
+        let armed = events
+            .iter()
+            .position(|event| matches!(event, Event::KillSwitchArmed(_)))
+            .expect("kill switch was not armed once market data went stale");
+        let Event::KillSwitchArmed(KillSwitchArmed { time, reason, .. }) = &events[armed] else {
+            unreachable!()
+        };
+        assert_eq!(*reason, KillSwitchReason::DataStale);
+        assert!(*time - started >= Duration::from_std(timeout).unwrap());
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| matches!(event, Event::KillSwitchArmed(_)))
+                .count(),
+            1
+        );
+
+        // The open Position entered from the last MarketEvent is flattened after the switch fired
+        assert!(events[armed..].iter().any(|event| matches!(
+            event,
+            Event::OrderNew(order) if order.decision == Decision::CloseLong
+        )));
+    }
+
+    #[test]
+    fn trader_should_not_arm_kill_switch_for_stale_market_data_when_replaying() {
+        let (trader, _command_tx, event_rx) = trader(
+            SilentFeed {
+                market: Some(market_event_trade(Side::Buy)),
+                silent_for: std::time::Duration::from_millis(50),
+                silent_since: None,
+            },
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            clock: Arc::new(SimulatedClock::new(Utc::now())),
+            stale_data_timeout: Some(std::time::Duration::from_millis(10)),
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        assert!(!collect_events(event_rx)
+            .iter()
+            .any(|event| matches!(event, Event::KillSwitchArmed(_))));
+    }
+
+    #[test]
+    fn trader_should_exit_position_once_market_price_crosses_stop_loss() {
+        let market_events = [1000.0, 960.0, 940.0, 900.0].map(|price| {
+            let mut market_event = market_event_trade(Side::Buy);
+            if let DataKind::Trade(trade) = &mut market_event.kind {
+                trade.price = price;
+            }
+            market_event
+        });
+
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new(market_events),
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            stop_manager: StopManager::new().with_stop(
+                market(),
+                StopConfig {
+                    stop_loss: Some(StopOffset::Percent(0.05)),
+                    take_profit: None,
+                },
+            ),
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let exits = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::PositionExit(exit) => Some(exit),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let forced_exit_orders = events
+            .iter()
+            .filter(|event| {
+                matches!(event, Event::OrderNew(order) if order.decision == Decision::CloseLong)
+            })
+            .count();
+
+        // Entered at 1000.0, so the 5% stop-loss at 950.0 is first crossed at 940.0
+        assert_eq!(exits.len(), 1);
+        assert_eq!(exits[0].exit_avg_price_gross, Decimal::from(940));
+        assert_eq!(forced_exit_orders, 1);
+    }
+
+    #[test]
+    fn trader_should_refuse_entry_order_with_insufficient_available_margin() {
+        let margined = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let unleveraged = MarginConfig {
+            leverage: 1.0,
+            maintenance_margin_rate: 0.05,
+        };
+        let trader = Trader {
+            margin_model: MarginModel::new(MarginMode::Cross)
+                .with_market(market(), unleveraged)
+                .with_market(margined.clone(), unleveraged),
+            ..trader
+        };
+
+        // Unleveraged Position of another margin traded Market uses every unit of margin
+        trader
+            .portfolio
+            .lock()
+            .set_open_position(Position {
+                position_id: determine_position_id(
+                    trader.engine_id,
+                    &margined.exchange,
+                    &margined.instrument,
+                ),
+                exchange: margined.exchange.clone(),
+                instrument: margined.instrument.clone(),
+                quantity: Decimal::TEN,
+                current_symbol_price: Decimal::ONE_THOUSAND,
+                unrealised_profit_loss: Decimal::ZERO,
+                ..position()
+            })
+            .unwrap();
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        assert!(events.iter().any(|event| matches!(event, Event::Signal(_))));
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::OrderNew(_) | Event::Fill(_))));
+    }
+
+    #[test]
+    fn trader_should_liquidate_position_once_adverse_move_breaches_maintenance_margin() {
+        let market_events = [1000.0, 960.0, 940.0, 900.0].map(|price| {
+            let mut market_event = market_event_trade(Side::Buy);
+            if let DataKind::Trade(trade) = &mut market_event.kind {
+                trade.price = price;
+            }
+            market_event
+        });
+
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new(market_events),
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            margin_model: MarginModel::new(MarginMode::Isolated).with_market(
+                market(),
+                MarginConfig {
+                    leverage: 10.0,
+                    maintenance_margin_rate: 0.05,
+                },
+            ),
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let exits = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::PositionExit(exit) => Some(exit),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // 0.1 entered at 1000.0 on 10x leverage has an initial margin of 10.0, which an
+        // unrealised loss of 6.0 at 940.0 reduces below the maintenance margin of 4.7
+        assert_eq!(exits.len(), 1);
+        assert_eq!(exits[0].exit_avg_price_gross, Decimal::from(940));
+    }
+
+    #[test]
+    fn trader_should_settle_funding_of_open_long_across_funding_interval() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 2, 7, 30, 0).unwrap();
+        let market_at = |minutes| MarketEvent {
+            exchange_time: start + Duration::minutes(minutes),
+            ..market_event_trade(Side::Buy)
+        };
+
+        // Long entered at 07:30 remains open across the 08:00 funding time, but not 16:00
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_at(0), market_at(20), market_at(60)]),
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            clock: Arc::new(SimulatedClock::new(start)),
+            funding_model: FundingModel::new(Duration::hours(8))
+                .with_market(market(), FundingRate::Fixed(0.001)),
+            ..trader
+        };
+
+        let summary = trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let balances = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Balance(balance) => Some(balance),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let payments = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::FundingPayment(payment) => Some(payment),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Positive funding rate, so the long of 0.1 valued at 1000.0 pays 0.1
+        assert_eq!(payments.len(), 1);
+        assert_eq!(payments[0].time, start + Duration::minutes(30));
+        assert_eq!(payments[0].quantity, Decimal::new(1, 1));
+        assert_eq!(payments[0].amount, Decimal::new(-1, 1));
+
+        // Entry Balance & funded Balance
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[1].total, balances[0].total - Decimal::new(1, 1));
+        assert_eq!(
+            balances[1].available,
+            balances[0].available - Decimal::new(1, 1)
+        );
+
+        assert_eq!(summary.funding, Decimal::new(-1, 1));
+        assert_eq!(summary.realised_profit_loss, Decimal::new(-1, 1));
+    }
+
+    /// Returns the exchange time of the [`MarketEvent`] each generated exit [`OrderEvent`]
+    /// priced.
+    fn exit_order_times(events: &[Event]) -> Vec<DateTime<Utc>> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) if order.decision.is_exit() => Some(order.market_meta.time),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Runs a [`Trader`] with a five minute minimum holding time over trade [`MarketEvent`]s at
+    /// the provided minutes & prices, advising the scripted [`Decision`]s.
+    fn run_with_min_holding_time(
+        minutes_and_prices: &[(i64, f64)],
+        decisions: &[Decision],
+        stop_manager: StopManager,
+    ) -> (DateTime<Utc>, Vec<Event>) {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let market_events = minutes_and_prices.iter().map(|(minute, price)| {
+            let mut market_event = market_event_trade(Side::Buy);
+            market_event.exchange_time = day + Duration::minutes(*minute);
+            if let DataKind::Trade(trade) = &mut market_event.kind {
+                trade.price = *price;
+            }
+            market_event
+        });
+
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new(market_events.collect::<Vec<_>>()),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::from(decisions.to_vec()),
+            },
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            clock: Arc::new(SimulatedClock::new(day)),
+            min_holding_time: Some(std::time::Duration::from_secs(5 * 60)),
+            stop_manager,
+            ..trader
+        };
+
+        trader.run().unwrap();
+        (day, collect_events(event_rx))
+    }
+
+    #[test]
+    fn trader_should_defer_signal_exit_of_position_younger_than_min_holding_time() {
+        let (day, events) = run_with_min_holding_time(
+            &[(0, 1000.0), (1, 1000.0), (3, 1000.0), (10, 1000.0)],
+            &[Decision::Long, Decision::CloseLong],
+            StopManager::new(),
+        );
+
+        // CloseLong advised at 00:01 is deferred until the first MarketEvent after 00:05
+        assert_eq!(exit_order_times(&events), vec![day + Duration::minutes(10)]);
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| matches!(event, Event::PositionExit(_)))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn trader_should_dispatch_signal_exit_of_position_older_than_min_holding_time() {
+        let (day, events) = run_with_min_holding_time(
+            &[(0, 1000.0), (6, 1000.0)],
+            &[Decision::Long, Decision::CloseLong],
+            StopManager::new(),
+        );
+
+        assert_eq!(exit_order_times(&events), vec![day + Duration::minutes(6)]);
+    }
+
+    #[test]
+    fn trader_should_exit_stopped_position_younger_than_min_holding_time() {
+        let stop_manager = StopManager::new().with_stop(
+            market(),
+            StopConfig {
+                stop_loss: Some(StopOffset::Percent(0.05)),
+                take_profit: None,
+            },
+        );
+        let (day, events) =
+            run_with_min_holding_time(&[(0, 1000.0), (1, 900.0)], &[Decision::Long], stop_manager);
+
+        // Stop-loss crossed at 00:01 bypasses the minimum holding time
+        assert_eq!(exit_order_times(&events), vec![day + Duration::minutes(1)]);
+    }
+
+    /// [`TradingCalendar`] with 09:30 - 16:00 UTC sessions from Monday to Friday, flattening
+    /// the open Position 15 minutes before the close.
+    fn trading_calendar() -> TradingCalendar {
+        TradingCalendar::new(
+            chrono::NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            chrono::FixedOffset::east_opt(0).unwrap(),
+        )
+        .with_auto_flatten_before_close(Duration::minutes(15))
+    }
+
+    #[test]
+    fn trader_should_refuse_opening_orders_outside_trading_session_hours() {
+        // Saturday 2023-01-14
+        let saturday = Utc.with_ymd_and_hms(2023, 1, 14, 12, 0, 0).unwrap();
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let steps = VecDeque::from([
+            FeedStep::Market(MarketEvent {
+                exchange_time: saturday,
+                ..market_event_trade(Side::Buy)
+            }),
+            FeedStep::Command(Command::Correlated {
+                id: Uuid::from_u128(1),
+                command: Box::new(Command::ManualOrder(manual_order_request(
+                    Decimal::ONE,
+                    None,
+                ))),
+            }),
+        ]);
+
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed { steps, command_tx },
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            clock: Arc::new(SimulatedClock::new(saturday)),
+            trading_calendars: HashMap::from([(market().instrument, trading_calendar())]),
+            ..trader
+        };
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let results = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::CommandOutcome(outcome) => Some(outcome.result.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Both the Signal & the ManualOrderRequest entries are refused
+        assert!(events.iter().any(|event| matches!(event, Event::Signal(_))));
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::OrderNew(_))));
+        assert_eq!(
+            results,
+            vec![CommandResult::Rejected(
+                "outside trading session hours".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn trader_should_flatten_position_before_trading_session_close() {
+        // Friday 2023-01-13
+        let friday = Utc.with_ymd_and_hms(2023, 1, 13, 0, 0, 0).unwrap();
+        let at = |hour, minute| friday + Duration::hours(hour) + Duration::minutes(minute);
+        let market_events =
+            [at(15, 0), at(15, 30), at(15, 50), at(15, 55)].map(|time| MarketEvent {
+                exchange_time: time,
+                ..market_event_trade(Side::Buy)
+            });
+
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new(market_events),
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            clock: Arc::new(SimulatedClock::new(friday)),
+            trading_calendars: HashMap::from([(market().instrument, trading_calendar())]),
+            ..trader
+        };
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let orders = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) => Some(order),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Long entered at 15:00 is flattened at 15:50, within 15 minutes of the 16:00 close, and
+        // not re-entered for the remainder of the session
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].decision, Decision::Long);
+        assert_eq!(orders[1].decision, Decision::CloseLong);
+        assert_eq!(orders[1].order_type, OrderType::Market);
+        assert_eq!(exit_order_times(&events), vec![at(15, 50)]);
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| matches!(event, Event::PositionExit(_)))
+                .count(),
+            1
+        );
+    }
+
+    /// [`FillHook`] recording every [`FillEvent`] it handles, once the gate grants a permit.
+    struct RecordingHook {
+        gate: Arc<tokio::sync::Semaphore>,
+        fills: Arc<Mutex<Vec<(FillEvent, usize)>>>,
+    }
+
+    impl FillHook for RecordingHook {
+        async fn on_fill(
+            &self,
+            fill: &FillEvent,
+            portfolio: &PortfolioSnapshot,
+        ) -> Result<(), FillHookError> {
+            self.gate.acquire().await?.forget();
+            self.fills
+                .lock()
+                .push((fill.clone(), portfolio.open_positions.len()));
+            Ok(())
+        }
+    }
+
+    /// [`FillHook`] panicking on every [`FillEvent`].
+    struct PanickingHook;
+
+    impl FillHook for PanickingHook {
+        async fn on_fill(&self, _: &FillEvent, _: &PortfolioSnapshot) -> Result<(), FillHookError> {
+            panic!("FillHook panicked")
+        }
+    }
+
+    #[test]
+    fn trader_should_spawn_fill_hooks_per_fill_without_blocking_trading_loop() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap();
+        let gate = Arc::new(tokio::sync::Semaphore::new(0));
+        let fills = Arc::new(Mutex::new(Vec::new()));
+        let fill_hooks = FillHooks::new(runtime.handle().clone())
+            .with_hook(PanickingHook)
+            .with_hook(RecordingHook {
+                gate: Arc::clone(&gate),
+                fills: Arc::clone(&fills),
+            });
+
+        let market_events = [0, 1, 2].map(market_event_at);
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new(market_events),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::from([Decision::Long, Decision::CloseLong, Decision::Long]),
+            },
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            fill_hooks: Some(fill_hooks),
+            ..trader
+        };
+
+        // Trading loop runs to completion whilst every RecordingHook invocation is still blocked
+        trader.run().unwrap();
+        let events = collect_events(event_rx);
+        let expected = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Fill(fill) => Some(fill.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(expected.len(), 3);
+        assert!(fills.lock().is_empty());
+
+        gate.add_permits(expected.len());
+        runtime.block_on(async {
+            tokio::time::timeout(std::time::Duration::from_secs(5), async {
+                while fills.lock().len() < expected.len() {
+                    tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+                }
+            })
+            .await
+            .unwrap()
+        });
+
+        // Each FillEvent handled once, with the Portfolio revalued after it was applied
+        let mut recorded = fills.lock().clone();
+        recorded.sort_by_key(|(fill, _)| fill.time);
+        assert_eq!(
+            recorded,
+            expected.into_iter().zip([1, 0, 1]).collect::<Vec<_>>()
+        );
+    }
+
+    /// Builds a trade [`MarketEvent`] with an exchange time offset by the provided seconds.
+    fn market_event_at(seconds: i64) -> MarketEvent<DataKind> {
+        let market = market_event_trade(Side::Buy);
+        MarketEvent {
+            exchange_time: market.exchange_time + chrono::Duration::seconds(seconds),
+            ..market
+        }
+    }
+
+    /// Returns the exchange time of the [`MarketEvent`] each generated [`OrderEvent`] priced.
+    fn order_times(events: &[Event]) -> Vec<DateTime<Utc>> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) => Some(order.market_meta.time),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn warming_up_trader_should_not_generate_orders_until_warm_up_is_consumed() {
+        let markets = (0..4).map(market_event_at).collect::<Vec<_>>();
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new(markets.clone()),
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            warm_up: 3,
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        // Every MarketEvent would trigger a buy, but the first three only warm up the Trader
+        let events = collect_events(event_rx);
+        assert_eq!(order_times(&events), vec![markets[3].exchange_time]);
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| matches!(event, Event::Market(_)))
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn paused_market_events_should_not_count_towards_warm_up() {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let markets = (0..4).map(market_event_at).collect::<Vec<_>>();
+
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Command(Command::Pause),
+                    FeedStep::Market(markets[0].clone()),
+                    FeedStep::Command(Command::Resume),
+                    FeedStep::Market(markets[1].clone()),
+                    FeedStep::Market(markets[2].clone()),
+                    FeedStep::Market(markets[3].clone()),
+                ]),
+                command_tx,
+            },
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            warm_up: 2,
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        // Paused MarketEvent is not counted, so the warm up ends after the next two
+        let events = collect_events(event_rx);
+        assert_eq!(order_times(&events), vec![markets[3].exchange_time]);
+    }
+
+    #[test]
+    fn paused_trader_should_consume_market_events_but_not_generate_orders_until_resumed() {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let paused_market = market_event_trade(Side::Buy);
+        let resumed_market = MarketEvent {
+            exchange_time: paused_market.exchange_time + chrono::Duration::seconds(1),
+            ..market_event_trade(Side::Buy)
+        };
+
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Command(Command::Pause),
+                    FeedStep::Market(paused_market.clone()),
+                    FeedStep::Command(Command::Resume),
+                    FeedStep::Market(resumed_market.clone()),
+                ]),
+                command_tx,
+            },
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        // MarketEvent consumed while paused would normally trigger a buy, but no order is sent
+        let events = collect_events(event_rx);
+        let orders = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) => Some(order),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].decision, Decision::Long);
+        assert_eq!(orders[0].market_meta.time, resumed_market.exchange_time);
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::Market(market) if market == &paused_market)));
+    }
+
+    #[test]
+    fn trader_should_not_generate_order_if_strategy_generates_no_signal() {
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        assert!(matches!(
+            events.as_slice(),
+            [
+                Event::TraderStarted(_),
+                Event::Market(_),
+                Event::TraderStopped(_)
+            ]
+        ));
+    }
+
+    #[test]
+    fn trader_should_terminate_rather_than_panic_if_execution_fails() {
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new([
+                market_event_trade(Side::Buy),
+                market_event_trade(Side::Buy),
+            ]),
+            AlwaysLongStrategy,
+            FailingExecution,
+        );
+
+        let result = trader.run();
+        assert!(matches!(
+            result,
+            Err(EngineError::ExecutionError(
+                ExecutionError::BuilderIncomplete("fill")
+            ))
+        ));
+
+        let events = collect_events(event_rx);
+
+        // Trader stops after the first OrderEvent fails to execute, so the second MarketEvent
+        // is never consumed
+        let num_market_events = events
+            .iter()
+            .filter(|event| matches!(event, Event::Market(_)))
+            .count();
+        assert_eq!(num_market_events, 1);
+        assert!(!events.iter().any(|event| matches!(event, Event::Fill(_))));
+    }
+
+    #[test]
+    fn trader_should_update_portfolio_from_paper_fill_when_dry_running() {
+        // Live ExecutionClient fails every OrderEvent, so any that reach it terminate the Trader
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            AlwaysLongStrategy,
+            DryRunExecution::new(
+                ExecutionMode::DryRun,
+                FailingExecution,
+                ExecutionConfig::default(),
+            ),
+        );
+
+        let summary = trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        assert_eq!(summary.orders, 1);
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::PositionNew(_))));
+    }
+
+    /// Feed that plays the executor a [`ChannelExecution`] forwards to before yielding each
+    /// [`MarketEvent`], filling every received [`OrderEvent`] via a [`SimulatedExecution`].
+    struct ExecutorBridgeFeed {
+        markets: VecDeque<MarketEvent<DataKind>>,
+        request_rx: mpsc::UnboundedReceiver<ExecutionRequest>,
+        fill_tx: mpsc::UnboundedSender<FillEvent>,
+        executor: SimulatedExecution,
+    }
+
+    impl MarketGenerator<MarketEvent<DataKind>> for ExecutorBridgeFeed {
+        fn next(&mut self) -> Feed<MarketEvent<DataKind>> {
+            while let Ok(request) = self.request_rx.try_recv() {
+                if let ExecutionRequest::Open(order) = request {
+                    if let Some(fill) = self.executor.generate_fill(&order).unwrap() {
+                        self.fill_tx.send(fill).unwrap();
+                    }
+                }
+            }
+
+            match self.markets.pop_front() {
+                Some(market) => Feed::Next(market),
+                None => Feed::Finished,
+            }
+        }
+    }
+
+    #[test]
+    fn trader_should_apply_fills_of_channel_execution_with_the_next_market_event() {
+        let (request_tx, request_rx) = mpsc::unbounded_channel();
+        let (fill_tx, fill_rx) = mpsc::unbounded_channel();
+        let (trader, _command_tx, event_rx) = trader(
+            ExecutorBridgeFeed {
+                markets: VecDeque::from([
+                    market_event_trade(Side::Buy),
+                    market_event_trade(Side::Buy),
+                ]),
+                request_rx,
+                fill_tx,
+                executor: SimulatedExecution::new(ExecutionConfig::default()),
+            },
+            AlwaysLongStrategy,
+            ChannelExecution::new(request_tx, fill_rx),
+        );
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let market_events = events
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| matches!(event, Event::Market(_)))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        let position_new = events
+            .iter()
+            .position(|event| matches!(event, Event::PositionNew(_)))
+            .expect("Portfolio did not enter a Position from the executor FillEvent");
+
+        // Unlike an in-process SimulatedExecution, the order rests until the executor FillEvent
+        // is received with the second MarketEvent
+        assert_eq!(market_events.len(), 2);
+        assert!(position_new > market_events[1]);
+    }
+
+    #[test]
+    fn trader_should_process_out_of_order_market_events_in_timestamp_order() {
+        let start = Utc.with_ymd_and_hms(2023, 1, 2, 9, 30, 0).unwrap();
+        let at = |seconds| MarketEvent {
+            exchange_time: start + Duration::seconds(seconds),
+            ..market_event_trade(Side::Buy)
+        };
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new([at(0), at(3), at(1), at(2), at(6), at(4), at(20)]),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            reorder_buffer: Some(ReorderBuffer::new(Duration::seconds(5))),
+            ..trader
+        };
+
+        let summary = trader.run().unwrap();
+
+        // Events still held once the feed finishes are drained in timestamp order
+        let processed = collect_events(event_rx)
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::Market(market) => Some((market.exchange_time - start).num_seconds()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(processed, vec![0, 1, 2, 3, 4, 6, 20]);
+        assert_eq!(summary.market_events, 7);
+    }
+
+    #[test]
+    fn trader_should_record_order_round_trip_latency_of_matched_fills() {
+        let (trader, _command_tx, _event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        let summary = trader.run().unwrap();
+
+        assert_eq!(summary.order_latency.count, 1);
+        assert!(summary.order_latency.mean().unwrap() >= Duration::zero());
+        assert_eq!(summary.orphan_fills, 0);
+    }
+
+    #[test]
+    fn trader_should_measure_order_round_trip_latency_with_the_trader_clock() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let at = |seconds| MarketEvent {
+            exchange_time: day + Duration::seconds(seconds),
+            ..market_event_trade(Side::Buy)
+        };
+        let feed = MockFeed::new();
+        let execution = MockExecution::new();
+        let (trader, _command_tx, _event_rx) = trader(
+            feed.clone(),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::from([Decision::Long]),
+            },
+            execution.clone(),
+        );
+        let mut trader = Trader {
+            clock: Arc::new(SimulatedClock::new(day)),
+            ..trader
+        };
+        trader.start();
+
+        // Long OrderEvent is dispatched at t=0s & rests with the MockExecution
+        feed.push(at(0));
+        trader.step();
+        let order = execution.orders().remove(0);
+
+        // FillEvent is received with the MarketEvent at t=2s, regardless of it's own timestamp
+        execution.fill_order(&order, Decimal::ONE_THOUSAND);
+        feed.push(at(2));
+        trader.step();
+
+        let latency = trader.session.order_latency;
+        assert_eq!(latency.count, 1);
+        assert_eq!(latency.mean(), Some(Duration::seconds(2)));
+        assert_eq!(latency.buckets[11], 1);
+    }
+
+    #[test]
+    fn backtests_sharing_a_simulated_clock_should_timestamp_orders_and_balances_identically() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let backtest = || {
+            let market_events = [0, 10, 20].map(|minute| MarketEvent {
+                exchange_time: day + Duration::minutes(minute),
+                ..market_event_trade(Side::Buy)
+            });
+            let (trader, _command_tx, event_rx) = trader(
+                historical::MarketFeed::new(market_events),
+                ScriptedDecisionStrategy {
+                    decisions: VecDeque::from([Decision::Long, Decision::CloseLong]),
+                },
+                SimulatedExecution::new(ExecutionConfig::default()),
+            );
+
+            // Trader, Portfolio & SimulatedExecution all tell the simulated time
+            let clock: Arc<dyn Clock + Send + Sync> = Arc::new(SimulatedClock::new(day));
+            let portfolio = MetaPortfolio::builder()
+                .engine_id(trader.engine_id)
+                .markets(vec![market()])
+                .starting_cash(Decimal::from(10_000))
+                .repository(InMemoryRepository::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: Decimal::ONE_HUNDRED,
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(StatisticConfig {
+                    starting_equity: 10_000.0,
+                    trading_days_per_year: 365,
+                    risk_free_return: 0.0,
+                })
+                .clock(Arc::clone(&clock))
+                .build_and_init()
+                .unwrap();
+            let trader = Trader {
+                clock,
+                portfolio: Arc::new(Mutex::new(portfolio)),
+                ..trader
+            };
+            trader.run().unwrap();
+
+            collect_events(event_rx)
+                .into_iter()
+                .filter_map(|event| match event {
+                    Event::OrderNew(order) => Some(("order", order.time)),
+                    Event::Fill(fill) => Some(("fill", fill.time)),
+                    Event::Balance(balance) => Some(("balance", balance.time)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let first = backtest();
+        assert_eq!(first, backtest());
+
+        let entry = day;
+        let exit = day + Duration::minutes(10);
+        assert_eq!(
+            first,
+            vec![
+                ("order", entry),
+                ("fill", entry),
+                ("balance", entry),
+                ("order", exit),
+                ("fill", exit),
+                ("balance", exit),
+            ]
+        );
+    }
+
+    #[test]
+    fn trader_should_count_fills_for_unknown_orders_as_orphan_fills() {
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            AlwaysLongStrategy,
+            OrphanFillExecution,
+        );
+
+        let summary = trader.run().unwrap();
+
+        // Orphan fills are still applied to the Portfolio
+        let events = collect_events(event_rx);
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::PositionNew(_))));
+        assert_eq!(summary.order_latency.count, 0);
+        assert_eq!(summary.orphan_fills, 1);
+    }
+
+    #[tokio::test]
+    async fn trader_should_surface_market_events_dropped_by_a_bounded_feed_in_session_summary() {
+        let (market_tx, feed) = BoundedMarketFeed::channel(1, OverflowPolicy::DropOldest);
+        market_tx.send(market_event_trade(Side::Buy)).await.unwrap();
+        market_tx.send(market_event_trade(Side::Buy)).await.unwrap();
+        drop(market_tx);
+
+        let (trader, _command_tx, _event_rx) = trader(
+            feed,
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        let summary = trader.run().unwrap();
+
+        assert_eq!(summary.market_events, 1);
+        assert_eq!(summary.dropped_events, 1);
+    }
+
+    #[test]
+    fn trader_should_return_session_summary_of_consumed_feed() {
+        let (trader, _command_tx, event_rx) = trader(
+            historical::MarketFeed::new([
+                market_event_trade(Side::Buy),
+                market_event_trade(Side::Buy),
+                market_event_trade(Side::Buy),
+            ]),
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        let summary = trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let num_orders = events
+            .iter()
+            .filter(|event| matches!(event, Event::OrderNew(_)))
+            .count();
+
+        assert_eq!(summary.market, market());
+        assert_eq!(summary.market_events, 3);
+        // Only the first Signal generates an OrderEvent, subsequent ones are ignored since the
+        // Position is already open
+        assert_eq!(num_orders, 1);
+        assert_eq!(summary.orders, 1);
+        assert_eq!(summary.realised_profit_loss, Decimal::ZERO);
+        assert!(summary.statistics.is_none());
+        assert!(summary.started_at <= summary.ended_at);
+        assert!(serde_json::to_string(&summary).is_ok());
+    }
+
+    #[test]
+    fn trader_should_exit_open_position_with_offsetting_order_on_command_exit_position() {
+        let (trader, command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        // Seed the Portfolio with an open long Position
+        let market = market();
+        let mut position = position();
+        position.position_id =
+            determine_position_id(trader.engine_id, &market.exchange, &market.instrument);
+        position.exchange = market.exchange.clone();
+        position.instrument = market.instrument.clone();
+        position.quantity = Decimal::TWO;
+        trader.portfolio.lock().set_open_position(position).unwrap();
+
+        command_tx.try_send(Command::ExitPosition(market)).unwrap();
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+
+        let order = events
+            .iter()
+            .find_map(|event| match event {
+                Event::OrderNew(order) => Some(order),
+                _ => None,
+            })
+            .expect("Trader did not generate an exit OrderEvent");
+        assert_eq!(order.decision, Decision::CloseLong);
+        assert_eq!(order.quantity, -Decimal::TWO);
+        assert_eq!(order.order_type, OrderType::Market);
+
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::PositionExit(_))));
+    }
+
+    #[test]
+    fn trader_should_ignore_command_exit_position_if_no_position_is_open() {
+        let (trader, command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        command_tx
+            .try_send(Command::ExitPosition(market()))
+            .unwrap();
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        assert!(matches!(
+            events.as_slice(),
+            [
+                Event::TraderStarted(_),
+                Event::Market(_),
+                Event::TraderStopped(_)
+            ]
+        ));
+    }
+
+    fn manual_order_request(quantity: Decimal, limit_price: Option<f64>) -> ManualOrderRequest {
+        let market = market();
+        ManualOrderRequest {
+            exchange: market.exchange,
+            instrument: market.instrument,
+            side: Side::Buy,
+            quantity,
+            notional: None,
+            limit_price: limit_price.map(decimal_from_f64),
+            stop_price: None,
+            time_in_force: TimeInForce::default(),
+            trigger: None,
+            tags: OrderTags::default(),
+        }
+    }
+
+    #[test]
+    fn trader_should_execute_valid_manual_order() {
+        let (trader, command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        command_tx
+            .try_send(Command::ManualOrder(manual_order_request(
+                Decimal::TWO,
+                Some(1500.0),
+            )))
+            .unwrap();
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+
+        let order = events
+            .iter()
+            .find_map(|event| match event {
+                Event::OrderNew(order) => Some(order),
+                _ => None,
+            })
+            .expect("Trader did not generate an OrderEvent from the ManualOrderRequest");
+        assert_eq!(order.decision, Decision::Long);
+        assert_eq!(order.quantity, Decimal::TWO);
+        assert_eq!(order.market_meta.close, Decimal::from(1500));
+        assert_eq!(order.order_type, OrderType::Limit);
+
+        assert!(events.iter().any(
+            |event| matches!(event, Event::PositionNew(position) if position.quantity == Decimal::TWO)
+        ));
+    }
+
+    #[test]
+    fn trader_should_tag_closed_trades_with_order_tags_set_on_manual_order_submission() {
+        let tags = OrderTags::from([
+            ("strategy".to_owned(), "breakout".to_owned()),
+            ("signal_id".to_owned(), "42".to_owned()),
+        ]);
+
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                    FeedStep::Command(Command::ManualOrder(ManualOrderRequest {
+                        tags: tags.clone(),
+                        ..manual_order_request(Decimal::TWO, None)
+                    })),
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                    FeedStep::Command(Command::ManualOrder(ManualOrderRequest {
+                        side: Side::Sell,
+                        ..manual_order_request(Decimal::TWO, None)
+                    })),
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                ]),
+                command_tx,
+            },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            ..trader
+        };
+        let summary = trader.run().unwrap();
+
+        // Tags ride along from the ManualOrderRequest to the entry FillEvent
+        let fills = collect_events(event_rx)
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::Fill(fill) => Some(fill),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].tags, tags);
+
+        // Closed Trade is attributed to the tags of the entry that opened it
+        let mut ledger = TradeLedger::new(CostBasis::Fifo);
+        let trades = fills
+            .iter()
+            .flat_map(|fill| ledger.record(fill))
+            .collect::<Vec<_>>();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].quantity, Decimal::TWO);
+        assert_eq!(trades[0].tags, tags);
+
+        // SessionSummary attributes the Trade to the tagged strategy
+        assert_eq!(summary.by_strategy["breakout"].trades, 1);
+        assert_eq!(summary.trade_totals.trades, 1);
+    }
+
+    #[test]
+    fn session_summary_breakdowns_should_sum_to_the_trade_totals() {
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        let fill = |instrument: &Instrument,
+                    strategy: Option<&str>,
+                    quantity: i32,
+                    price: f64,
+                    fees| FillEvent {
+            instrument: instrument.clone(),
+            quantity: Decimal::from(quantity),
+            fill_value_gross: decimal_from_f64(price) * Decimal::from(quantity.abs()),
+            fees: Fees {
+                exchange: fees,
+                ..Fees::default()
+            },
+            tags: strategy
+                .map(|strategy| OrderTags::from([(STRATEGY_TAG.to_owned(), strategy.to_owned())]))
+                .unwrap_or_default(),
+            ..fill_event()
+        };
+
+        // Round trips of two instruments, attributed to two strategies & one untagged
+        let fills = [
+            fill(&btc, Some("breakout"), 1, 100.0, Decimal::ONE),
+            fill(&btc, None, -1, 110.0, Decimal::ONE),
+            fill(&btc, Some("mean_reversion"), 2, 100.0, Decimal::ONE),
+            fill(&btc, None, -2, 95.0, Decimal::ONE),
+            fill(&eth, Some("breakout"), 1, 50.0, Decimal::new(5, 1)),
+            fill(&eth, None, -1, 60.0, Decimal::new(5, 1)),
+            fill(&eth, None, 1, 50.0, Decimal::new(25, 2)),
+            fill(&eth, None, -1, 50.0, Decimal::new(25, 2)),
+        ];
+
+        let mut ledger = TradeLedger::new(CostBasis::Fifo);
+        let mut summary = SessionSummary::<TradingSummary>::new(market());
+        for fill in &fills {
+            for trade in ledger.record(fill) {
+                summary.record_trade(&trade);
+            }
+        }
+
+        let totals = summary.trade_totals;
+        assert_eq!(totals.trades, 4);
+        assert_eq!(totals.wins, 2);
+        assert_eq!(
+            totals.profit_loss,
+            Decimal::from(8 - 12 + 9) - Decimal::new(5, 1)
+        );
+        assert_eq!(totals.fees, Decimal::new(55, 1));
+        assert_eq!(totals.win_rate(), Some(0.5));
+
+        assert_eq!(summary.by_instrument[&btc].profit_loss, Decimal::from(-4));
+        assert_eq!(summary.by_instrument[&eth].profit_loss, Decimal::new(85, 1));
+        assert_eq!(
+            summary.by_strategy["breakout"].profit_loss,
+            Decimal::from(17)
+        );
+        assert_eq!(summary.by_strategy["breakout"].win_rate(), Some(1.0));
+        assert_eq!(
+            summary.by_strategy["mean_reversion"].profit_loss,
+            Decimal::from(-12)
+        );
+        assert_eq!(
+            summary.by_strategy[UNTAGGED_STRATEGY].profit_loss,
+            Decimal::new(-5, 1)
+        );
+
+        for breakdown in [
+            summary.by_instrument.values().collect::<Vec<_>>(),
+            summary.by_strategy.values().collect::<Vec<_>>(),
+        ] {
+            let sum = breakdown
+                .into_iter()
+                .fold(TradeStats::default(), |sum, stats| TradeStats {
+                    profit_loss: sum.profit_loss + stats.profit_loss,
+                    trades: sum.trades + stats.trades,
+                    wins: sum.wins + stats.wins,
+                    fees: sum.fees + stats.fees,
+                });
+            assert_eq!(sum, totals);
+        }
+    }
+
+    #[test]
+    fn trader_should_normalise_manual_orders_to_instrument_filters() {
+        let (trader, command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            instrument_filters: Some(InstrumentFilters {
+                tick_size: Decimal::ONE,
+                lot_size: Decimal::new(1, 2),
+                min_notional: Decimal::TEN,
+            }),
+            ..trader
+        };
+
+        // Snapped to 1.23 @ 1500.0
+        command_tx
+            .try_send(Command::ManualOrder(manual_order_request(
+                Decimal::new(123_456, 5),
+                Some(1500.4),
+            )))
+            .unwrap();
+        // Floored to zero lots
+        command_tx
+            .try_send(Command::ManualOrder(manual_order_request(
+                Decimal::new(9, 3),
+                Some(1500.0),
+            )))
+            .unwrap();
+
+        trader.run().unwrap();
+
+        let orders = collect_events(event_rx)
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) => Some(order),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].quantity, Decimal::new(123, 2));
+        assert_eq!(orders[0].market_meta.close, Decimal::from(1500));
+    }
+
+    /// [`Subscriber`](tracing::Subscriber) capturing the level & fields of every logged event.
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<(tracing::Level, HashMap<&'static str, String>)>>>);
+
+    impl tracing::Subscriber for CapturedLogs {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            tracing::span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            let mut fields = LogFields::default();
+            event.record(&mut fields);
+            self.0.lock().push((*event.metadata().level(), fields.0));
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[derive(Default)]
+    struct LogFields(HashMap<&'static str, String>);
+
+    impl tracing::field::Visit for LogFields {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn Debug) {
+            self.0.insert(field.name(), format!("{value:?}"));
+        }
+    }
+
+    #[test]
+    fn trader_should_log_orders_sent_for_execution_at_info_with_their_client_order_id() {
+        let (trader, command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        command_tx
+            .try_send(Command::ManualOrder(manual_order_request(
+                Decimal::ONE,
+                Some(1500.0),
+            )))
+            .unwrap();
+
+        let logs = CapturedLogs::default();
+        tracing::subscriber::with_default(logs.clone(), || trader.run()).unwrap();
+
+        let cid = collect_events(event_rx)
+            .into_iter()
+            .find_map(|event| match event {
+                Event::OrderNew(order) => Some(order.cid.to_string()),
+                _ => None,
+            })
+            .expect("Trader did not generate an OrderEvent from the ManualOrderRequest");
+
+        let logs = logs.0.lock();
+        assert!(logs.iter().any(|(level, fields)| {
+            *level == tracing::Level::INFO
+                && fields.get("message").map(String::as_str)
+                    == Some("sending OrderEvent for execution")
+                && fields.get("cid") == Some(&cid)
+        }));
+    }
+
+    #[test]
+    fn trader_should_net_self_matching_orders_before_sending_the_residual_for_execution() {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let execution = MockExecution::new();
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                    FeedStep::Command(Command::ManualOrder(manual_order_request(
+                        Decimal::new(5, 0),
+                        None,
+                    ))),
+                    FeedStep::Command(Command::ManualOrder(ManualOrderRequest {
+                        side: Side::Sell,
+                        ..manual_order_request(Decimal::new(3, 0), None)
+                    })),
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                ]),
+                command_tx,
+            },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            execution.clone(),
+        );
+        let trader = Trader {
+            command_rx,
+            self_match_prevention: true,
+            ..trader
+        };
+        trader.run().unwrap();
+
+        // Only the residual +2 buy reaches the exchange
+        let orders = execution.orders();
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].quantity, Decimal::TWO);
+        assert_eq!(orders[0].decision, Decision::Long);
+
+        let crossed = collect_events(event_rx)
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::OrderCancelled(order) => Some(order.quantity),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(crossed, vec![Decimal::new(3, 0), Decimal::new(-3, 0)]);
+    }
+
+    #[test]
+    fn trader_should_stop_tracking_orders_rejected_by_the_exchange() {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                    // Post-only buy limit above the 1000.0 market price would take liquidity
+                    FeedStep::Command(Command::ManualOrder(ManualOrderRequest {
+                        time_in_force: TimeInForce::PostOnly,
+                        ..manual_order_request(Decimal::ONE, Some(1500.0))
+                    })),
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                ]),
+                command_tx,
+            },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            ..trader
+        };
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let rejections = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderRejected(rejection) => Some(rejection.reason),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(rejections, vec![RejectReason::PostOnlyWouldCross]);
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::Fill(_) | Event::OrderCancelled(_))));
+    }
+
+    /// [`FillEvent`] buying the quantity of the test [`Market`] at 1000.0, without fees.
+    fn market_fill(quantity: Decimal, fill_id: Option<&str>) -> FillEvent {
+        FillEvent {
+            exchange: market().exchange,
+            instrument: market().instrument,
+            market_meta: MarketMeta {
+                close: Decimal::ONE_THOUSAND,
+                time: Utc::now(),
+            },
+            decision: Decision::Long,
+            quantity,
+            fill_value_gross: Decimal::ONE_THOUSAND * quantity,
+            fill_id: fill_id.map(str::to_owned),
+            ..fill_event()
+        }
+    }
+
+    #[test]
+    fn trader_should_correct_internal_position_drifted_from_the_exchange_on_reconcile() {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let execution = MockExecution::new();
+        execution.inject_fill(market_fill(Decimal::TWO, None));
+        execution.set_exchange_position(market().instrument, Decimal::new(15, 1));
+
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                    FeedStep::Command(Command::Reconcile),
+                    // Reconciled Position no longer differs from the exchange
+                    FeedStep::Command(Command::Reconcile),
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                ]),
+                command_tx,
+            },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            execution,
+        );
+        let trader = Trader {
+            command_rx,
+            reconcile_tolerance: Decimal::new(1, 1),
+            ..trader
+        };
+        let portfolio = Arc::clone(&trader.portfolio);
+        let position_id =
+            determine_position_id(trader.engine_id, &market().exchange, &market().instrument);
+        trader.run().unwrap();
+
+        let discrepancies = collect_events(event_rx)
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::PositionDiscrepancy(discrepancy) => Some(discrepancy),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(discrepancies.len(), 1);
+        assert_eq!(discrepancies[0].internal_quantity, Decimal::TWO);
+        assert_eq!(discrepancies[0].exchange_quantity, Decimal::new(15, 1));
+
+        let position = portfolio
+            .lock()
+            .get_open_position(&position_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(position.quantity, Decimal::new(15, 1));
+        assert_eq!(position.enter_avg_price_gross, Decimal::ONE_THOUSAND);
+        assert_eq!(position.enter_value_gross, Decimal::from(1500));
+    }
+
+    /// Strategy counting the [`FillEvent`]s it is notified of, never advising a [`Signal`].
+    struct FillCountingStrategy {
+        fills: Arc<Mutex<Vec<FillEvent>>>,
+    }
+
+    impl SignalGenerator for FillCountingStrategy {
+        fn generate_signal(&mut self, _: &MarketEvent<DataKind>) -> Option<Signal> {
+            None
+        }
+
+        fn on_fill(&mut self, fill: &FillEvent) {
+            self.fills.lock().push(fill.clone());
+        }
+    }
+
+    #[test]
+    fn trader_should_notify_strategy_of_every_fill_it_applies() {
+        let execution = MockExecution::new();
+        execution.inject_fill(market_fill(Decimal::ONE, Some("trade-1")));
+        execution.inject_fill(market_fill(Decimal::ONE, Some("trade-2")));
+
+        let fills = Arc::new(Mutex::new(Vec::new()));
+        let (trader, _command_tx, _event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            FillCountingStrategy {
+                fills: Arc::clone(&fills),
+            },
+            execution,
+        );
+        trader.run().unwrap();
+
+        let fill_ids = fills
+            .lock()
+            .iter()
+            .map(|fill| fill.fill_id.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fill_ids,
+            vec![Some("trade-1".to_owned()), Some("trade-2".to_owned())]
+        );
+    }
+
+    #[test]
+    fn trader_should_track_order_state_of_resting_order_through_partial_fills() {
+        let feed = MockFeed::new();
+        let execution = MockExecution::new();
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (trader, _, _event_rx) = trader(
+            feed.clone(),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::from([Decision::Long]),
+            },
+            execution.clone(),
+        );
+        let mut trader = Trader {
+            command_rx,
+            ..trader
+        };
+        trader.start();
+
+        // Fetches the OrderState with the next MarketEvent, after any fills it delivers
+        let fetch_order_state = |trader: &mut Trader<_, _, _, _, _, _>, id| {
+            let (state_tx, mut state_rx) = tokio::sync::oneshot::channel();
+            command_tx
+                .try_send(Command::FetchOrderState { id, state_tx })
+                .unwrap();
+            feed.push(market_event_trade(Side::Buy));
+            trader.step();
+            state_rx.try_recv().unwrap()
+        };
+
+        // Long OrderEvent rests with the MockExecution
+        feed.push(market_event_trade(Side::Buy));
+        trader.step();
+        let order = execution.orders().remove(0);
+        assert_eq!(order.quantity, Decimal::new(1, 1));
+        assert_eq!(
+            fetch_order_state(&mut trader, order.cid),
+            Some(OrderState::Acknowledged)
+        );
+
+        let half = |fill_id| FillEvent {
+            cid: order.cid,
+            ..market_fill(Decimal::new(5, 2), Some(fill_id))
+        };
+        execution.inject_fill(half("trade-1"));
+        feed.push(market_event_trade(Side::Buy));
+        trader.step();
+        assert_eq!(
+            fetch_order_state(&mut trader, order.cid),
+            Some(OrderState::PartiallyFilled {
+                filled: Decimal::new(5, 2),
+                remaining: Decimal::new(5, 2),
+            })
+        );
+
+        execution.inject_fill(half("trade-2"));
+        feed.push(market_event_trade(Side::Buy));
+        trader.step();
+        assert_eq!(
+            fetch_order_state(&mut trader, order.cid),
+            Some(OrderState::Filled)
+        );
+
+        // Orders never sent for execution are unknown
+        assert_eq!(
+            fetch_order_state(&mut trader, ClientOrderId::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn session_should_count_an_order_once_however_many_fills_it_receives() {
+        let feed = MockFeed::new();
+        let execution = MockExecution::new();
+        let (mut trader, _command_tx, _event_rx) = trader(
+            feed.clone(),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::from([Decision::Long]),
+            },
+            execution.clone(),
+        );
+        trader.start();
+
+        // Long OrderEvent rests with the MockExecution & is filled in two halves
+        feed.push(market_event_trade(Side::Buy));
+        trader.step();
+        let order = execution.orders().remove(0);
+        assert_eq!(trader.session.orders, 1);
+        assert_eq!(trader.session.fills, 0);
+
+        for fill_id in ["trade-1", "trade-2"] {
+            execution.inject_fill(FillEvent {
+                cid: order.cid,
+                ..market_fill(Decimal::new(5, 2), Some(fill_id))
+            });
+            feed.push(market_event_trade(Side::Buy));
+            trader.step();
+        }
+
+        assert_eq!(trader.session.orders, 1);
+        assert_eq!(trader.session.fills, 2);
+    }
+
+    #[test]
+    fn trader_should_resubmit_transient_rejection_with_fresh_order_id_once_backoff_elapses() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let at = |seconds| MarketEvent {
+            exchange_time: day + Duration::seconds(seconds),
+            ..market_event_trade(Side::Buy)
+        };
+        let feed = MockFeed::new();
+        let execution = MockExecution::new();
+        execution.reject_next(RejectReason::RateLimited);
+        let (trader, _command_tx, _event_rx) = trader(
+            feed.clone(),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::from([Decision::Long]),
+            },
+            execution.clone(),
+        );
+        let mut trader = Trader {
+            clock: Arc::new(SimulatedClock::new(day)),
+            retry_policy: Some(RetryPolicy::new(2, Duration::seconds(1))),
+            ..trader
+        };
+        trader.start();
+
+        // Long OrderEvent is rejected as rate limited & scheduled for retry after a 1s backoff
+        feed.push(at(0));
+        trader.step();
+        let rejected = execution.orders().remove(0);
+        assert_eq!(
+            trader.order_states.get(&rejected.cid),
+            Some(OrderState::Rejected)
+        );
+
+        // Not resubmitted until the backoff elapses
+        feed.push(at(0));
+        trader.step();
+        assert_eq!(execution.orders().len(), 1);
+
+        feed.push(at(1));
+        trader.step();
+        let orders = execution.orders();
+        assert_eq!(orders.len(), 2);
+        let retry = &orders[1];
+        assert_ne!(retry.cid, rejected.cid);
+        assert_eq!(retry.decision, Decision::Long);
+        assert_eq!(retry.quantity, rejected.quantity);
+
+        // Resubmitted OrderEvent is accepted & filled
+        execution.fill_order(retry, Decimal::ONE_THOUSAND);
+        feed.push(at(2));
+        trader.step();
+        assert_eq!(
+            trader.order_states.get(&retry.cid),
+            Some(OrderState::Filled)
+        );
+        assert!(trader.order_retries.is_empty());
+    }
+
+    #[test]
+    fn trader_should_surface_terminal_rejection_without_retrying_it() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let at = |seconds| MarketEvent {
+            exchange_time: day + Duration::seconds(seconds),
+            ..market_event_trade(Side::Buy)
+        };
+        let feed = MockFeed::new();
+        let execution = MockExecution::new();
+        execution.reject_next(RejectReason::InsufficientBalance);
+        let (trader, _command_tx, event_rx) = trader(
+            feed.clone(),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::from([Decision::Long]),
+            },
+            execution.clone(),
+        );
+        let mut trader = Trader {
+            clock: Arc::new(SimulatedClock::new(day)),
+            retry_policy: Some(RetryPolicy::new(2, Duration::seconds(1))),
+            ..trader
+        };
+        trader.start();
+
+        for seconds in [0, 10, 60] {
+            feed.push(at(seconds));
+            trader.step();
+        }
+
+        // Rejection is surfaced, but the OrderEvent is never resubmitted
+        let rejections = collect_events(event_rx)
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::OrderRejected(rejection) => Some(rejection.reason),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(rejections, vec![RejectReason::InsufficientBalance]);
+        assert_eq!(execution.orders().len(), 1);
+        assert!(trader.order_retries.is_empty());
+    }
+
+    #[test]
+    fn cancel_all_orders_should_drop_scheduled_retries() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let at = |seconds| MarketEvent {
+            exchange_time: day + Duration::seconds(seconds),
+            ..market_event_trade(Side::Buy)
+        };
+        let feed = MockFeed::new();
+        let execution = MockExecution::new();
+        execution.reject_next(RejectReason::RateLimited);
+        let (trader, _command_tx, event_rx) = trader(
+            feed.clone(),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::from([Decision::Long]),
+            },
+            execution.clone(),
+        );
+        let mut trader = Trader {
+            clock: Arc::new(SimulatedClock::new(day)),
+            retry_policy: Some(RetryPolicy::new(2, Duration::seconds(1))),
+            ..trader
+        };
+        trader.start();
+
+        // Long OrderEvent is rejected as rate limited & scheduled for retry
+        feed.push(at(0));
+        trader.step();
+        assert_eq!(trader.order_retries.len(), 1);
+        let retry = trader.order_retries[0].order.cid;
+
+        trader.cancel_all_orders();
+        assert!(trader.order_retries.is_empty());
+
+        // Dropped retry is never resubmitted once the backoff elapses
+        feed.push(at(5));
+        trader.step();
+        assert_eq!(execution.orders().len(), 1);
+        assert_eq!(cancelled_cids(&collect_events(event_rx)), vec![retry]);
+    }
+
+    #[test]
+    fn kill_switch_should_drop_the_deferred_exit() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let at = |minutes| MarketEvent {
+            exchange_time: day + Duration::minutes(minutes),
+            ..market_event_trade(Side::Buy)
+        };
+        let feed = MockFeed::new();
+        let (trader, _command_tx, event_rx) = trader(
+            feed.clone(),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::from([Decision::Long, Decision::CloseLong]),
+            },
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let mut trader = Trader {
+            clock: Arc::new(SimulatedClock::new(day)),
+            min_holding_time: Some(std::time::Duration::from_secs(5 * 60)),
+            ..trader
+        };
+        trader.start();
+
+        // CloseLong advised at 00:01 is deferred until the minimum holding time
+        for minute in [0, 1] {
+            feed.push(at(minute));
+            trader.step();
+        }
+        let deferred = trader.deferred_exit.as_ref().unwrap().1.cid;
+
+        trader.arm_kill_switch(KillSwitchReason::Command);
+        assert!(trader.deferred_exit.is_none());
+
+        // Only the kill switch flattens the Position, the deferred exit is never released
+        feed.push(at(10));
+        trader.step();
+        let events = collect_events(event_rx);
+        assert!(cancelled_cids(&events).contains(&deferred));
+        assert_eq!(exit_order_times(&events), vec![day + Duration::minutes(1)]);
+    }
+
+    #[test]
+    fn trader_should_apply_fills_received_twice_with_the_same_fill_id_once() {
+        let execution = MockExecution::new();
+        execution.inject_fill(market_fill(Decimal::ONE, Some("trade-1")));
+        execution.inject_fill(market_fill(Decimal::ONE, Some("trade-1")));
+        execution.inject_fill(market_fill(Decimal::ONE, Some("trade-2")));
+
+        let (trader, _command_tx, _event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            execution,
+        );
+        let portfolio = Arc::clone(&trader.portfolio);
+        let position_id =
+            determine_position_id(trader.engine_id, &market().exchange, &market().instrument);
+        let summary = trader.run().unwrap();
+
+        assert_eq!(summary.duplicate_fills, 1);
+        let position = portfolio
+            .lock()
+            .get_open_position(&position_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(position.quantity, Decimal::TWO);
+    }
+
+    #[test]
+    fn trader_should_cancel_unfilled_quantity_of_immediate_orders() {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let immediate_order = |time_in_force| {
+            FeedStep::Command(Command::ManualOrder(ManualOrderRequest {
+                time_in_force,
+                ..manual_order_request(Decimal::TWO, Some(1500.0))
+            }))
+        };
+
+        // Latest trade of 1.0 at 1000.0 leaves 1.0 available at the touch for each OrderEvent
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                    immediate_order(TimeInForce::ImmediateOrCancel),
+                    immediate_order(TimeInForce::FillOrKill),
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                ]),
+                command_tx,
+            },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            ..trader
+        };
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let fills = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Fill(fill) => Some(fill.quantity),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let cancelled = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderCancelled(order) => Some((order.time_in_force, order.quantity)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // ImmediateOrCancel fills 1.0 & cancels the remaining 1.0, FillOrKill cancels in full
+        assert_eq!(fills, vec![Decimal::ONE]);
+        assert_eq!(
+            cancelled,
+            vec![
+                (TimeInForce::ImmediateOrCancel, Decimal::ONE),
+                (TimeInForce::FillOrKill, Decimal::TWO)
+            ]
+        );
+    }
+
+    #[test]
+    fn trader_should_convert_notional_manual_order_into_quantity_at_the_latest_price() {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let notional_buy = |sequence| {
+            FeedStep::Command(Command::Correlated {
+                id: Uuid::from_u128(sequence),
+                command: Box::new(Command::ManualOrder(ManualOrderRequest {
+                    notional: Some(Decimal::ONE_HUNDRED),
+                    ..manual_order_request(Decimal::ZERO, None)
+                })),
+            })
+        };
+        let execution = MockExecution::new();
+
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    // No market price is known to convert the notional
+                    notional_buy(1),
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                    notional_buy(2),
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                ]),
+                command_tx,
+            },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            execution.clone(),
+        );
+        let trader = Trader {
+            command_rx,
+            instrument_filters: Some(InstrumentFilters {
+                tick_size: Decimal::new(1, 2),
+                lot_size: Decimal::new(1, 3),
+                min_notional: Decimal::TEN,
+            }),
+            ..trader
+        };
+        trader.run().unwrap();
+
+        let outcomes = collect_events(event_rx)
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::CommandOutcome(outcome) => Some((outcome.id.as_u128(), outcome.result)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            vec![
+                (
+                    1,
+                    CommandResult::Rejected(
+                        PortfolioError::ManualOrderRejected(
+                            "no market price available to convert the notional into a quantity"
+                        )
+                        .to_string()
+                    )
+                ),
+                (2, CommandResult::Accepted),
+            ]
+        );
+
+        // $100 at the 1000.0 market price buys 0.1 btc
+        let orders = execution.orders();
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].quantity, Decimal::new(1, 1));
+        assert_eq!(orders[0].decision, Decision::Long);
+    }
+
+    #[test]
+    fn trader_should_report_one_command_outcome_per_correlated_command() {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let correlated = |sequence, command| {
+            FeedStep::Command(Command::Correlated {
+                id: Uuid::from_u128(sequence),
+                command: Box::new(command),
+            })
+        };
+        let untraded = Market::new("binance_spot", ("eth", "usdt", InstrumentKind::Spot));
+
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                    correlated(
+                        1,
+                        Command::ManualOrder(manual_order_request(Decimal::TWO, Some(1500.0))),
+                    ),
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                    correlated(2, Command::ExitPosition(market())),
+                    correlated(3, Command::ExitPosition(untraded)),
+                    FeedStep::Market(market_event_trade(Side::Buy)),
+                ]),
+                command_tx,
+            },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            ..trader
+        };
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let outcomes = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::CommandOutcome(outcome) => Some((outcome.id.as_u128(), &outcome.result)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Valid close is accepted, whereas closing a Market the Trader does not trade is rejected
+        assert_eq!(outcomes.len(), 3);
+        assert_eq!(outcomes[0], (1, &CommandResult::Accepted));
+        assert_eq!(outcomes[1], (2, &CommandResult::Accepted));
+        assert!(matches!(outcomes[2], (3, CommandResult::Rejected(_))));
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::PositionExit(_))));
+    }
+
+    /// Strategy that advises entering a long Position whenever the trade price is above it's
+    /// threshold, which may be updated via [`SignalGenerator::apply_params`].
+    #[derive(Debug)]
+    struct ThresholdStrategy {
+        threshold: f64,
+    }
+
+    impl SignalGenerator for ThresholdStrategy {
+        fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+            let market_meta = MarketMeta::from_market(market)?;
+            (decimal_to_f64(market_meta.close) > self.threshold).then(|| Signal {
+                time: Utc::now(),
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+                market_meta,
+                tags: Default::default(),
+            })
+        }
+
+        fn apply_params(&mut self, params: &serde_json::Value) -> Result<(), ParamError> {
+            match params.get("threshold").and_then(serde_json::Value::as_f64) {
+                Some(threshold) if threshold > 0.0 => {
+                    self.threshold = threshold;
+                    Ok(())
+                }
+                Some(_) => Err(ParamError::Invalid("threshold must be greater than zero")),
+                None => Err(ParamError::Deserialise("missing threshold".to_owned())),
+            }
+        }
+    }
+
+    /// Runs a [`Trader`] with a [`ThresholdStrategy`] of threshold 1500.0 through a trade at
+    /// 1000.0, a correlated [`Command::UpdateStrategyParams`] with the provided params & another
+    /// trade at 1000.0. Returns the [`CommandOutcome`] & the [`Event::Signal`] prices.
+    fn trader_with_updated_strategy_params(
+        params: serde_json::Value,
+    ) -> (CommandResult, Vec<Decimal>) {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_event_priced(1000.0)),
+                    FeedStep::Command(Command::Correlated {
+                        id: Uuid::from_u128(1),
+                        command: Box::new(Command::UpdateStrategyParams {
+                            market: market(),
+                            params,
+                        }),
+                    }),
+                    FeedStep::Market(market_event_priced(1000.0)),
+                ]),
+                command_tx,
+            },
+            ThresholdStrategy { threshold: 1500.0 },
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            ..trader
+        };
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let outcome = events
+            .iter()
+            .find_map(|event| match event {
+                Event::CommandOutcome(outcome) => Some(outcome.result.clone()),
+                _ => None,
+            })
+            .expect("Trader did not report a CommandOutcome");
+        let signals = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Signal(signal) => Some(signal.market_meta.close),
+                _ => None,
+            })
+            .collect();
+
+        (outcome, signals)
+    }
+
+    #[test]
+    fn trader_should_apply_updated_strategy_params_to_subsequent_signals() {
+        let (outcome, signals) =
+            trader_with_updated_strategy_params(serde_json::json!({ "threshold": 900.0 }));
+
+        // First trade is below the initial threshold, second is above the updated threshold
+        assert_eq!(outcome, CommandResult::Accepted);
+        assert_eq!(signals, vec![Decimal::ONE_THOUSAND]);
+    }
+
+    #[test]
+    fn trader_should_reject_invalid_strategy_params_leaving_existing_params_unchanged() {
+        let (outcome, signals) =
+            trader_with_updated_strategy_params(serde_json::json!({ "threshold": -900.0 }));
+
+        assert_eq!(
+            outcome,
+            CommandResult::Rejected(
+                ParamError::Invalid("threshold must be greater than zero").to_string()
+            )
+        );
+        assert!(signals.is_empty());
+    }
+
+    #[test]
+    fn trader_restored_from_checkpoint_should_resume_strategy_indicators_without_warm_up() {
+        let candle = |close: f64| {
+            let mut market = market_event_candle();
+            if let DataKind::Candle(candle) = &mut market.kind {
+                candle.close = close;
+            }
+            market
+        };
+        let decisions = |signal: Option<Signal>| {
+            signal.map(|signal| {
+                let mut decisions = signal.signals.into_keys().collect::<Vec<_>>();
+                decisions.sort();
+                decisions
+            })
+        };
+        let new_trader = || {
+            trader(
+                historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+                RSIStrategy::new(StrategyConfig { rsi_period: 3 }),
+                SimulatedExecution::new(ExecutionConfig::default()),
+            )
+            .0
+        };
+
+        // Falling closes build up an oversold RSI
+        let mut original = new_trader();
+        for close in [1000.0, 990.0, 980.0, 970.0, 960.0] {
+            original.strategy.generate_signal(&candle(close));
         }
+
+        let checkpoint = serde_json::to_string(&original.checkpoint()).unwrap();
+        let mut restored = new_trader();
+        restored
+            .restore(serde_json::from_str(&checkpoint).unwrap())
+            .unwrap();
+
+        // Restored RSI advises identically, whereas a fresh RSI has no history to be oversold
+        let mut fresh = RSIStrategy::new(StrategyConfig { rsi_period: 3 });
+        assert_eq!(decisions(fresh.generate_signal(&candle(962.0))), None);
+
+        let closes = [962.0, 975.0, 1000.0, 1030.0, 950.0];
+        let expected = closes
+            .iter()
+            .map(|close| decisions(original.strategy.generate_signal(&candle(*close))))
+            .collect::<Vec<_>>();
+        let actual = closes
+            .iter()
+            .map(|close| decisions(restored.strategy.generate_signal(&candle(*close))))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            expected[0],
+            Some(vec![Decision::Long, Decision::CloseShort])
+        );
+        assert_eq!(actual, expected);
     }
 
-    pub fn data(self, value: Data) -> Self {
-        Self {
-            data: Some(value),
-            ..self
+    #[test]
+    fn trader_should_only_invoke_strategy_for_instruments_of_interest() {
+        let instruments = (0..100)
+            .map(|index| {
+                Instrument::from((
+                    format!("coin{index}"),
+                    "usdt".to_owned(),
+                    InstrumentKind::Spot,
+                ))
+            })
+            .collect::<Vec<_>>();
+        let market_events = (0..10).flat_map(|_| {
+            instruments.iter().map(|instrument| MarketEvent {
+                instrument: instrument.clone(),
+                ..market_event_trade(Side::Buy)
+            })
+        });
+
+        let invoked_with = Arc::new(Mutex::new(Vec::new()));
+        let (trader, _command_tx, _event_rx) = trader(
+            historical::MarketFeed::new(market_events.collect::<Vec<_>>()),
+            RecordingStrategy {
+                interest: instruments[42].clone(),
+                invoked_with: Arc::clone(&invoked_with),
+            },
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        let session = trader.run().unwrap();
+
+        // Every MarketEvent is consumed, but the Strategy only sees the Instrument of interest
+        assert_eq!(session.market_events, 1000);
+        assert_eq!(*invoked_with.lock(), vec![instruments[42].clone(); 10]);
+    }
+
+    /// Strategy that records every [`MarketEvent`] it is invoked with, without advising.
+    #[derive(Debug)]
+    struct MarketRecordingStrategy {
+        invoked_with: Arc<Mutex<Vec<MarketEvent<DataKind>>>>,
+    }
+
+    impl SignalGenerator for MarketRecordingStrategy {
+        fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+            self.invoked_with.lock().push(market.clone());
+            None
         }
     }
 
-    pub fn strategy(self, value: Strategy) -> Self {
-        Self {
-            strategy: Some(value),
-            ..self
+    #[test]
+    fn trader_should_only_invoke_strategy_with_completed_bars_when_aggregating() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let ticks = (0..9).map(|index| {
+            let mut market_event = market_event_trade(Side::Buy);
+            market_event.exchange_time = day + Duration::seconds(index * 20);
+            market_event
+        });
+
+        let invoked_with = Arc::new(Mutex::new(Vec::new()));
+        let (trader, _command_tx, _event_rx) = trader(
+            historical::MarketFeed::new(ticks.collect::<Vec<_>>()),
+            MarketRecordingStrategy {
+                invoked_with: Arc::clone(&invoked_with),
+            },
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            clock: Arc::new(SimulatedClock::new(day)),
+            bar_aggregator: Some(BarAggregator::new(
+                BarInterval::Time(Duration::minutes(1)),
+                true,
+            )),
+            ..trader
+        };
+
+        let session = trader.run().unwrap();
+
+        // Every tick is consumed, but the Strategy only sees the three 1m bars, the last of
+        // which is flushed as the feed finishes
+        assert_eq!(session.market_events, 9);
+        let bars = invoked_with
+            .lock()
+            .iter()
+            .map(|market| match &market.kind {
+                DataKind::Candle(candle) => (candle.close_time, candle.trade_count),
+                kind => panic!("Strategy invoked with raw {kind:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bars,
+            vec![
+                (day + Duration::minutes(1), 3),
+                (day + Duration::minutes(2), 3),
+                (day + Duration::minutes(3), 3),
+            ]
+        );
+    }
+
+    /// Runs a [`Trader`] that trades at 1000.0, is submitted a conditional market buy of 1.0
+    /// triggered once the price rises above 1050.0 (followed by the [`Command`]s generated from
+    /// it's [`ClientOrderId`]), and then trades at 1040.0 & 1060.0. Returns the generated
+    /// [`Event`]s & the [`ClientOrderId`] assigned to the conditional order.
+    fn trader_with_triggered_order<Commands>(commands: Commands) -> (Vec<Event>, ClientOrderId)
+    where
+        Commands: FnOnce(ClientOrderId) -> Vec<Command>,
+    {
+        // Deterministic ClientOrderIds, so Commands can reference the conditional order
+        let seed = ClientOrderId {
+            session: Uuid::new_v4(),
+            sequence: 0,
+        };
+        let cid = ClientOrderId {
+            sequence: 1,
+            ..seed
+        };
+
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let triggered_buy = Command::ManualOrder(ManualOrderRequest {
+            trigger: Some(Trigger {
+                price: Decimal::from(1050),
+                direction: TriggerDirection::Above,
+            }),
+            ..manual_order_request(Decimal::ONE, None)
+        });
+
+        let mut steps = VecDeque::from([
+            FeedStep::Market(market_event_priced(1000.0)),
+            FeedStep::Command(triggered_buy),
+        ]);
+        steps.extend(commands(cid).into_iter().map(FeedStep::Command));
+        steps.extend([
+            FeedStep::Market(market_event_priced(1040.0)),
+            FeedStep::Market(market_event_priced(1060.0)),
+        ]);
+
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed { steps, command_tx },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            order_id_generator: Arc::new(MonotonicOrderIdGenerator::resume_after(seed)),
+            ..trader
+        };
+        trader.run().unwrap();
+
+        (collect_events(event_rx), cid)
+    }
+
+    #[test]
+    fn trader_should_hold_triggered_order_until_price_condition_is_met_then_execute_it() {
+        let (events, cid) = trader_with_triggered_order(|_| vec![]);
+
+        let held = events
+            .iter()
+            .position(|event| matches!(event, Event::OrderNew(order) if order.cid == cid))
+            .expect("Trader did not accept the conditional OrderEvent");
+        let Event::OrderNew(order) = &events[held] else {
+            unreachable!()
+        };
+        assert!(order.trigger.is_some());
+
+        // Not triggered by the trade at 1040.0, but by the trade at 1060.0
+        let triggered_by = events
+            .iter()
+            .rposition(|event| matches!(event, Event::Market(_)))
+            .unwrap();
+        let triggered = events
+            .iter()
+            .position(|event| matches!(event, Event::OrderTriggered(order) if order.cid == cid))
+            .expect("conditional OrderEvent was not triggered");
+        assert!(triggered > triggered_by);
+        let Event::OrderTriggered(order) = &events[triggered] else {
+            unreachable!()
+        };
+        assert_eq!(order.trigger, None);
+        assert_eq!(order.market_meta.close, Decimal::from(1060));
+
+        let fill = events
+            .iter()
+            .find_map(|event| match event {
+                Event::Fill(fill) if fill.cid == cid => Some(fill),
+                _ => None,
+            })
+            .expect("triggered OrderEvent was not executed");
+        assert_eq!(fill.quantity, Decimal::ONE);
+        assert_eq!(fill.market_meta.close, Decimal::from(1060));
+    }
+
+    #[test]
+    fn trader_should_remove_cancelled_triggered_order_before_it_is_triggered() {
+        let (events, cid) =
+            trader_with_triggered_order(|cid| vec![Command::CancelOrder { id: cid }]);
+
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::OrderCancelled(order) if order.cid == cid)));
+
+        // The cancelled conditional order is no longer watched, so the trade at 1060.0 is ignored
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::OrderTriggered(_) | Event::Fill(_))));
+    }
+
+    fn market_event_priced(price: f64) -> MarketEvent<DataKind> {
+        let mut market_event = market_event_trade(Side::Buy);
+        if let DataKind::Trade(trade) = &mut market_event.kind {
+            trade.price = price;
         }
+        market_event
     }
 
-    pub fn execution(self, value: Execution) -> Self {
-        Self {
-            execution: Some(value),
-            ..self
+    /// Enters a long Position of 1.0 at 1000.0, protects it with an OCO group of a take profit
+    /// limit sell at 1100.0 & a stop limit sell triggered at 900.0, then trades at the provided
+    /// price. Returns the generated [`Event`]s alongside the take profit & stop [`OrderEvent`]s.
+    fn trader_with_oco_group_trading_at(price: f64) -> (Vec<Event>, OrderEvent, OrderEvent) {
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let submit_oco = Command::SubmitOco {
+            take_profit: Box::new(ManualOrderRequest {
+                side: Side::Sell,
+                ..manual_order_request(Decimal::ONE, Some(1100.0))
+            }),
+            stop: Box::new(ManualOrderRequest {
+                side: Side::Sell,
+                stop_price: Some(Decimal::from(900)),
+                ..manual_order_request(Decimal::ONE, Some(850.0))
+            }),
+        };
+
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_event_priced(1000.0)),
+                    FeedStep::Command(Command::ManualOrder(manual_order_request(
+                        Decimal::ONE,
+                        None,
+                    ))),
+                    FeedStep::Market(market_event_priced(1000.0)),
+                    FeedStep::Command(submit_oco),
+                    FeedStep::Market(market_event_priced(1000.0)),
+                    FeedStep::Market(market_event_priced(price)),
+                    FeedStep::Market(market_event_priced(1000.0)),
+                ]),
+                command_tx,
+            },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+        let trader = Trader {
+            command_rx,
+            ..trader
+        };
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let legs = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) if order.decision.is_exit() => Some(order.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(legs.len(), 2);
+
+        (events, legs[0].clone(), legs[1].clone())
+    }
+
+    fn fill_cids(events: &[Event]) -> Vec<ClientOrderId> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Fill(fill) => Some(fill.cid),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn filled_oco_take_profit_should_cancel_stop() {
+        let (events, take_profit, stop) = trader_with_oco_group_trading_at(1150.0);
+
+        assert_eq!(take_profit.order_type, OrderType::Limit);
+        assert_eq!(stop.order_type, OrderType::StopLimit);
+        assert_eq!(fill_cids(&events)[1..], [take_profit.cid]);
+        assert_eq!(cancelled_cids(&events), vec![stop.cid]);
+    }
+
+    #[test]
+    fn filled_oco_stop_should_cancel_take_profit() {
+        let (events, take_profit, stop) = trader_with_oco_group_trading_at(850.0);
+
+        assert_eq!(fill_cids(&events)[1..], [stop.cid]);
+        assert_eq!(cancelled_cids(&events), vec![take_profit.cid]);
+    }
+
+    /// Builds a stepped [`Trader`] with a [`MockExecution`] & submits an OCO group of a take
+    /// profit limit sell of 1.0 at 1100.0 & the provided stop.
+    /// Returns the [`Trader`] alongside it's feed, execution, command sender & event receiver.
+    fn stepped_trader_with_oco_group(
+        stop: ManualOrderRequest,
+    ) -> (
+        Trader<
+            EventTx,
+            TradingSummary,
+            TestPortfolio,
+            MockFeed,
+            ScriptedDecisionStrategy,
+            MockExecution,
+        >,
+        MockFeed,
+        MockExecution,
+        mpsc::Sender<Command>,
+        mpsc::UnboundedReceiver<Event>,
+    ) {
+        let feed = MockFeed::new();
+        let execution = MockExecution::new();
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (trader, _, event_rx) = trader(
+            feed.clone(),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::new(),
+            },
+            execution.clone(),
+        );
+        let mut trader = Trader {
+            command_rx,
+            ..trader
+        };
+        trader.start();
+
+        command_tx
+            .try_send(Command::SubmitOco {
+                take_profit: Box::new(ManualOrderRequest {
+                    side: Side::Sell,
+                    ..manual_order_request(Decimal::ONE, Some(1100.0))
+                }),
+                stop: Box::new(stop),
+            })
+            .unwrap();
+        feed.push(market_event_trade(Side::Buy));
+        trader.step();
+
+        (trader, feed, execution, command_tx, event_rx)
+    }
+
+    #[test]
+    fn partially_filled_oco_leg_should_reduce_other_leg_by_cumulative_fill_until_fully_filled() {
+        let (mut trader, feed, execution, _command_tx, event_rx) =
+            stepped_trader_with_oco_group(ManualOrderRequest {
+                side: Side::Sell,
+                stop_price: Some(Decimal::from(900)),
+                ..manual_order_request(Decimal::ONE, Some(850.0))
+            });
+
+        // Both legs rest with the MockExecution
+        let [take_profit, stop] = <[OrderEvent; 2]>::try_from(execution.orders()).unwrap();
+        assert_eq!(stop.order_type, OrderType::StopLimit);
+        let fill = |quantity: Decimal| FillEvent {
+            cid: take_profit.cid,
+            decision: take_profit.decision,
+            quantity: -quantity,
+            ..market_fill(quantity, None)
+        };
+
+        // Each partial fill reduces the stop to the unfilled fraction of the take profit
+        for (filled, remaining) in [
+            (Decimal::new(25, 2), Decimal::new(-75, 2)),
+            (Decimal::new(25, 2), Decimal::new(-5, 1)),
+        ] {
+            execution.inject_fill(fill(filled));
+            feed.push(market_event_trade(Side::Buy));
+            trader.step();
+            assert_eq!(trader.pending_orders[&stop.cid].order.quantity, remaining);
+            assert_eq!(trader.oco_legs.len(), 2);
         }
+
+        // Final fill cancels the stop
+        execution.inject_fill(fill(Decimal::new(5, 1)));
+        feed.push(market_event_trade(Side::Buy));
+        trader.step();
+        assert!(trader.pending_orders.is_empty());
+        assert!(trader.oco_legs.is_empty());
+        assert_eq!(cancelled_cids(&collect_events(event_rx)), vec![stop.cid]);
     }
 
-    pub fn build(
-        self,
-    ) -> Result<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
-        Ok(Trader {
-            engine_id: self
-                .engine_id
-                .ok_or(EngineError::BuilderIncomplete("engine_id"))?,
-            market: self
-                .market
-                .ok_or(EngineError::BuilderIncomplete("market"))?,
-            command_rx: self
-                .command_rx
-                .ok_or(EngineError::BuilderIncomplete("command_rx"))?,
-            event_tx: self
-                .event_tx
-                .ok_or(EngineError::BuilderIncomplete("event_tx"))?,
-            event_q: VecDeque::with_capacity(2),
-            portfolio: self
-                .portfolio
-                .ok_or(EngineError::BuilderIncomplete("portfolio"))?,
-            data: self.data.ok_or(EngineError::BuilderIncomplete("data"))?,
-            strategy: self
-                .strategy
-                .ok_or(EngineError::BuilderIncomplete("strategy"))?,
-            execution: self
-                .execution
-                .ok_or(EngineError::BuilderIncomplete("execution"))?,
-            _statistic_marker: PhantomData::default(),
-        })
+    #[test]
+    fn oco_legs_filled_by_the_same_market_event_should_both_apply_as_over_fill() {
+        let (mut trader, feed, execution, _command_tx, event_rx) =
+            stepped_trader_with_oco_group(ManualOrderRequest {
+                side: Side::Sell,
+                ..manual_order_request(Decimal::ONE, Some(1000.0))
+            });
+
+        // Both legs rest with the MockExecution & are filled before either fill is received, so
+        // the stop can no longer be cancelled once the take profit fill is applied
+        let legs = execution.orders();
+        assert_eq!(legs.len(), 2);
+        for leg in &legs {
+            execution.inject_fill(FillEvent {
+                cid: leg.cid,
+                decision: leg.decision,
+                quantity: leg.quantity,
+                ..market_fill(Decimal::ONE, None)
+            });
+        }
+        feed.push(market_event_trade(Side::Buy));
+        trader.step();
+
+        let events = collect_events(event_rx);
+        assert_eq!(fill_cids(&events), vec![legs[0].cid, legs[1].cid]);
+        assert!(cancelled_cids(&events).is_empty());
+        assert!(trader.oco_legs.is_empty());
+        assert!(trader.pending_orders.is_empty());
+    }
+
+    /// Builds a [`Trader`] that rests a buy limit order for each of the provided limit prices
+    /// below the market, before actioning the provided [`Command`]s. Returns the [`Trader`]
+    /// alongside the predictable [`ClientOrderId`]s of the resting orders.
+    fn trader_with_resting_orders(
+        limit_prices: &[f64],
+        commands: Vec<Command>,
+    ) -> (
+        Trader<
+            EventTx,
+            TradingSummary,
+            TestPortfolio,
+            CommandingFeed,
+            RSIStrategy,
+            SimulatedExecution,
+        >,
+        Vec<ClientOrderId>,
+        mpsc::UnboundedReceiver<Event>,
+    ) {
+        let (mut trader, command_tx, event_rx) = trader(
+            CommandingFeed::new(vec![market_event_trade(Side::Buy)], commands),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        let seed = ClientOrderId {
+            session: Uuid::new_v4(),
+            sequence: 0,
+        };
+        trader.set_order_id_generator(Arc::new(MonotonicOrderIdGenerator::resume_after(seed)));
+        trader.data.command_tx = Some(command_tx.clone());
+
+        let cids = (1..=limit_prices.len() as u64)
+            .map(|sequence| ClientOrderId { sequence, ..seed })
+            .collect();
+
+        for limit_price in limit_prices {
+            command_tx
+                .try_send(Command::ManualOrder(manual_order_request(
+                    Decimal::ONE,
+                    Some(*limit_price),
+                )))
+                .unwrap();
+        }
+
+        (trader, cids, event_rx)
+    }
+
+    fn cancelled_cids(events: &[Event]) -> Vec<ClientOrderId> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderCancelled(order) => Some(order.cid),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn trader_should_cancel_only_the_open_order_identified() {
+        let unknown = ClientOrderId {
+            session: Uuid::new_v4(),
+            sequence: 1,
+        };
+        let (mut trader, cids, event_rx) = trader_with_resting_orders(&[500.0, 600.0], vec![]);
+        trader.data.commands = vec![
+            Command::CancelOrder { id: cids[1] },
+            Command::CancelOrder { id: unknown },
+        ];
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        assert_eq!(cancelled_cids(&events), vec![cids[1]]);
+        assert!(!events.iter().any(|event| matches!(event, Event::Fill(_))));
+    }
+
+    #[test]
+    fn trader_should_cancel_every_open_order_on_cancel_all_orders() {
+        let (trader, cids, event_rx) = trader_with_resting_orders(
+            &[500.0, 600.0, 700.0],
+            vec![Command::CancelAllOrders {
+                instrument: Some(market().instrument),
+            }],
+        );
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        assert_eq!(cancelled_cids(&events), cids);
+        assert!(!events.iter().any(|event| matches!(event, Event::Fill(_))));
+    }
+
+    fn amended_orders(events: &[Event]) -> Vec<&OrderEvent> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderAmended(order) => Some(order),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn cancelled_orders(events: &[Event]) -> Vec<&OrderEvent> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderCancelled(order) => Some(order),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn trader_should_amend_the_price_of_an_open_order_in_place() {
+        let (mut trader, cids, event_rx) = trader_with_resting_orders(&[500.0], vec![]);
+        trader.data.commands = vec![
+            Command::AmendOrder {
+                id: cids[0],
+                new_price: Some(Decimal::from(600)),
+                new_quantity: None,
+            },
+            Command::CancelOrder { id: cids[0] },
+        ];
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let amended = amended_orders(&events);
+        assert_eq!(amended.len(), 1);
+        assert_eq!(amended[0].cid, cids[0]);
+        assert_eq!(amended[0].market_meta.close, Decimal::from(600));
+        assert_eq!(amended[0].quantity, Decimal::ONE);
+
+        // Resting order is amended in place rather than replaced by a new order
+        let cancelled = cancelled_orders(&events);
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(cancelled[0].market_meta.close, Decimal::from(600));
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| matches!(event, Event::OrderNew(_)))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn trader_should_amend_the_quantity_of_an_open_order_down() {
+        let (mut trader, cids, event_rx) = trader_with_resting_orders(&[500.0], vec![]);
+        trader.data.commands = vec![
+            Command::AmendOrder {
+                id: cids[0],
+                new_price: None,
+                new_quantity: Some(Decimal::new(4, 1)),
+            },
+            Command::CancelOrder { id: cids[0] },
+        ];
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let amended = amended_orders(&events);
+        assert_eq!(amended.len(), 1);
+        assert_eq!(amended[0].quantity, Decimal::new(4, 1));
+        assert_eq!(amended[0].market_meta.close, Decimal::from(500));
+
+        let cancelled = cancelled_orders(&events);
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(cancelled[0].quantity, Decimal::new(4, 1));
+    }
+
+    #[test]
+    fn trader_should_refuse_to_amend_quantity_below_already_filled_quantity() {
+        let (request_tx, mut request_rx) = mpsc::unbounded_channel();
+        let (fill_tx, fill_rx) = mpsc::unbounded_channel();
+        let seed = ClientOrderId {
+            session: Uuid::new_v4(),
+            sequence: 0,
+        };
+        let cid = ClientOrderId {
+            sequence: 1,
+            ..seed
+        };
+        let amend = |quantity| Command::AmendOrder {
+            id: cid,
+            new_price: None,
+            new_quantity: Some(quantity),
+        };
+        let (mut trader, command_tx, event_rx) = trader(
+            CommandingFeed::new(
+                vec![market_event_trade(Side::Buy)],
+                vec![amend(Decimal::new(5, 1)), amend(Decimal::new(8, 1))],
+            ),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            ChannelExecution::new(request_tx, fill_rx),
+        );
+
+        trader.set_order_id_generator(Arc::new(MonotonicOrderIdGenerator::resume_after(seed)));
+        trader.data.command_tx = Some(command_tx.clone());
+
+        // Executor partially fills 0.6 of the resting order with the MarketEvent
+        command_tx
+            .try_send(Command::ManualOrder(manual_order_request(
+                Decimal::ONE,
+                Some(500.0),
+            )))
+            .unwrap();
+        fill_tx
+            .send(FillEvent {
+                cid,
+                exchange: market().exchange,
+                instrument: market().instrument,
+                quantity: Decimal::new(6, 1),
+                fill_value_gross: Decimal::from(300),
+                ..fill_event()
+            })
+            .unwrap();
+
+        trader.run().unwrap();
+
+        // Amend to 0.5 is below the filled 0.6 & refused, whereas the amend to 0.8 leaves 0.2
+        // unfilled at the executor
+        let events = collect_events(event_rx);
+        let amended = amended_orders(&events);
+        assert_eq!(amended.len(), 1);
+        assert_eq!(amended[0].quantity, Decimal::new(8, 1));
+
+        let mut amend_requests = Vec::new();
+        while let Ok(request) = request_rx.try_recv() {
+            if let ExecutionRequest::Amend(order) = request {
+                amend_requests.push(order);
+            }
+        }
+        assert_eq!(amend_requests.len(), 1);
+        assert_eq!(amend_requests[0].quantity, Decimal::new(2, 1));
+    }
+
+    #[test]
+    fn trader_should_queue_orders_throttled_by_rate_limiter_rather_than_execute_them() {
+        let (trader, cids, event_rx) = trader_with_resting_orders(
+            &[1500.0, 1500.0],
+            vec![Command::CancelAllOrders { instrument: None }],
+        );
+        let trader = Trader {
+            rate_limiter: Some(RateLimiter::new(0.001, 1)),
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        // First order is executed & filled, the second is throttled until it is cancelled
+        let events = collect_events(event_rx);
+        let fills = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Fill(fill) => Some(fill.cid),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(fills, vec![cids[0]]);
+        assert_eq!(cancelled_cids(&events), vec![cids[1]]);
+    }
+
+    #[test]
+    fn trader_should_report_rate_limited_queue_and_drop_pending_order_before_release() {
+        let day = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+        let at = |seconds| MarketEvent {
+            exchange_time: day + Duration::seconds(seconds),
+            ..market_event_trade(Side::Buy)
+        };
+        let feed = MockFeed::new();
+        let execution = MockExecution::new();
+        let (trader, command_tx, event_rx) = trader(
+            feed.clone(),
+            ScriptedDecisionStrategy {
+                decisions: VecDeque::new(),
+            },
+            execution.clone(),
+        );
+        let mut trader = Trader {
+            clock: Arc::new(SimulatedClock::new(day)),
+            rate_limiter: Some(RateLimiter::new(1.0, 1)),
+            ..trader
+        };
+        trader.start();
+
+        // Queries the queued orders with the next MarketEvent
+        let query = |trader: &mut Trader<_, _, _, _, _, _>, seconds| {
+            let (orders_tx, mut orders_rx) = tokio::sync::oneshot::channel();
+            command_tx
+                .try_send(Command::QueryPendingOrders(orders_tx))
+                .unwrap();
+            feed.push(at(seconds));
+            trader.step();
+            orders_rx.try_recv().unwrap()
+        };
+
+        // First of three limit orders is sent, the others are throttled a token apart
+        for limit_price in [900.0, 910.0, 920.0] {
+            command_tx
+                .try_send(Command::ManualOrder(manual_order_request(
+                    Decimal::ONE,
+                    Some(limit_price),
+                )))
+                .unwrap();
+        }
+        feed.push(at(0));
+        trader.step();
+        assert_eq!(execution.orders().len(), 1);
+
+        let queued = query(&mut trader, 0);
+        assert_eq!(queued.len(), 2);
+        assert!(queued
+            .iter()
+            .all(|queued| queued.reason == QueueReason::RateLimited));
+        assert_eq!(queued[0].release_at, day + Duration::seconds(1));
+        assert_eq!(queued[1].release_at, day + Duration::seconds(2));
+
+        // Dropping the first queued order leaves the second at the front of the queue
+        command_tx
+            .try_send(Command::DropPendingOrder {
+                id: queued[0].order.cid,
+            })
+            .unwrap();
+        let remaining = query(&mut trader, 0);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].order.cid, queued[1].order.cid);
+        assert_eq!(remaining[0].release_at, day + Duration::seconds(1));
+
+        // Only the remaining queued order is sent once the Clock reaches it's release time
+        feed.push(at(1));
+        trader.step();
+        feed.push(at(1));
+        trader.step();
+        let orders = execution.orders();
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[1].cid, queued[1].order.cid);
+        assert!(query(&mut trader, 5).is_empty());
+
+        // Dropping an order already sent falls through to cancelling it
+        command_tx
+            .try_send(Command::DropPendingOrder { id: orders[0].cid })
+            .unwrap();
+        feed.push(at(5));
+        trader.step();
+        assert_eq!(
+            cancelled_cids(&collect_events(event_rx)),
+            vec![queued[0].order.cid, orders[0].cid]
+        );
+    }
+
+    #[test]
+    fn trader_should_trip_circuit_breaker_once_order_rate_exceeds_the_ceiling() {
+        let (trader, cids, event_rx) = trader_with_resting_orders(
+            &[500.0, 510.0, 520.0, 530.0, 540.0],
+            vec![Command::CancelAllOrders { instrument: None }],
+        );
+        let trader = Trader {
+            // Ceiling of 2.5 orders per minute
+            circuit_breaker: Some(CircuitBreaker::new(Duration::minutes(1), 2.5 / 60.0, 100.0)),
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        // Third order trips the circuit breaker, which refuses every order thereafter
+        let events = collect_events(event_rx);
+        let orders = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) => Some(order.cid),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(orders, cids[..2]);
+
+        let tripped = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::CircuitTripped(tripped) => Some(tripped),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(tripped.len(), 1);
+        assert_eq!(tripped[0].metric, CircuitMetric::Orders);
+        assert_eq!(tripped[0].rate, 3.0 / 60.0);
+
+        // Open orders may still be cancelled whilst tripped
+        assert_eq!(cancelled_cids(&events), cids[..2]);
+    }
+
+    #[test]
+    fn trader_circuit_breaker_should_not_count_orders_refused_by_pre_trade_checks() {
+        let (trader, _, event_rx) =
+            trader_with_resting_orders(&[500.0, 510.0, 520.0, 530.0, 540.0], vec![]);
+        let trader = Trader {
+            // Ceiling of 2.5 orders per minute
+            circuit_breaker: Some(CircuitBreaker::new(Duration::minutes(1), 2.5 / 60.0, 100.0)),
+            // Every order exceeds the position cap
+            position_cap: Some(Decimal::new(5, 1)),
+            ..trader
+        };
+
+        trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::OrderNew(_) | Event::CircuitTripped(_))));
+    }
+
+    #[test]
+    fn trader_should_assign_client_order_ids_from_provided_order_id_generator() {
+        let (mut trader, command_tx, event_rx) = trader(
+            historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        let seed = ClientOrderId {
+            session: Uuid::new_v4(),
+            sequence: 41,
+        };
+        trader.set_order_id_generator(Arc::new(MonotonicOrderIdGenerator::resume_after(seed)));
+
+        command_tx
+            .try_send(Command::ManualOrder(manual_order_request(
+                Decimal::TWO,
+                Some(1500.0),
+            )))
+            .unwrap();
+
+        let summary = trader.run().unwrap();
+
+        let events = collect_events(event_rx);
+        let order_cids = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) => Some(order.cid),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let fill_cids = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Fill(fill) => Some(fill.cid),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let expected = vec![ClientOrderId {
+            sequence: 42,
+            ..seed
+        }];
+        assert_eq!(order_cids, expected);
+        assert_eq!(fill_cids, expected);
+        assert_eq!(summary.orphan_fills, 0);
+    }
+
+    #[test]
+    fn trader_should_reject_invalid_manual_orders() {
+        let invalid_requests = [
+            // Zero quantity
+            manual_order_request(Decimal::ZERO, None),
+            // Negative quantity
+            manual_order_request(Decimal::NEGATIVE_ONE, Some(500.0)),
+            // Market order before any market price is known
+            manual_order_request(Decimal::ONE, None),
+        ];
+
+        for request in invalid_requests {
+            let (trader, command_tx, event_rx) = trader(
+                historical::MarketFeed::new([market_event_trade(Side::Buy)]),
+                RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+                SimulatedExecution::new(ExecutionConfig::default()),
+            );
+
+            command_tx
+                .try_send(Command::ManualOrder(request.clone()))
+                .unwrap();
+
+            trader.run().unwrap();
+
+            let events = collect_events(event_rx);
+            assert!(
+                !events
+                    .iter()
+                    .any(|event| matches!(event, Event::OrderNew(_))),
+                "OrderEvent generated from invalid request: {request:?}"
+            );
+        }
+    }
+
+    /// Runs a [`Trader`] of the provided [`Market`] & [`InstrumentFilters`], sharing the provided
+    /// Portfolio of the provided engine_id, which trades at the provided price & is rebalanced
+    /// to the target weight of an equity of 10,000.0. Returns the [`OrderEvent`]s sent for
+    /// execution.
+    fn rebalanced_orders(
+        engine_id: Uuid,
+        portfolio: Arc<Mutex<TestPortfolio>>,
+        market: Market,
+        instrument_filters: InstrumentFilters,
+        price: f64,
+        weight: f64,
+    ) -> Vec<OrderEvent> {
+        let market_event = || MarketEvent {
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            ..market_event_priced(price)
+        };
+
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_event()),
+                    FeedStep::Command(Command::RebalanceMarket {
+                        weight,
+                        equity: Decimal::from(10_000),
+                        result_tx: None,
+                    }),
+                    FeedStep::Market(market_event()),
+                ]),
+                command_tx,
+            },
+            RSIStrategy::new(StrategyConfig { rsi_period: 14 }),
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        let trader = Trader {
+            engine_id,
+            market,
+            portfolio,
+            command_rx,
+            instrument_filters: Some(instrument_filters),
+            ..trader
+        };
+        trader.run().unwrap();
+
+        collect_events(event_rx)
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::OrderNew(order) => Some(order),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rebalance_should_move_two_instrument_portfolio_from_cash_to_target_weights() {
+        let engine_id = Uuid::new_v4();
+        let btc = Market::new("binance_spot", ("btc", "usdt", InstrumentKind::Spot));
+        let eth = Market::new("binance_spot", ("eth", "usdt", InstrumentKind::Spot));
+        let portfolio = portfolio_of(engine_id, vec![btc.clone(), eth.clone()]);
+        let filters = InstrumentFilters {
+            tick_size: Decimal::new(1, 2),
+            lot_size: Decimal::new(1, 3),
+            min_notional: Decimal::TEN,
+        };
+
+        // 0.5 * 10,000.0 / 1,000.0 = 5.0 btc
+        let btc_orders = rebalanced_orders(
+            engine_id,
+            Arc::clone(&portfolio),
+            btc.clone(),
+            filters,
+            1000.0,
+            0.5,
+        );
+        // 0.5 * 10,000.0 / 3,000.0 = 1.6666.. eth, rounded down to the 0.001 lot size
+        let eth_orders = rebalanced_orders(
+            engine_id,
+            Arc::clone(&portfolio),
+            eth.clone(),
+            filters,
+            3000.0,
+            0.5,
+        );
+
+        for (orders, expected) in [
+            (&btc_orders, Decimal::new(5, 0)),
+            (&eth_orders, Decimal::new(1666, 3)),
+        ] {
+            assert_eq!(orders.len(), 1);
+            assert_eq!(orders[0].decision, Decision::Long);
+            assert_eq!(orders[0].order_type, OrderType::Market);
+            assert_eq!(orders[0].quantity, expected);
+        }
+
+        for (market, expected) in [(btc, Decimal::new(5, 0)), (eth, Decimal::new(1666, 3))] {
+            let position_id =
+                determine_position_id(engine_id, &market.exchange, &market.instrument);
+            let position = portfolio.lock().get_open_position(&position_id).unwrap();
+            assert_eq!(position.unwrap().quantity, expected);
+        }
+    }
+
+    #[test]
+    fn rebalance_should_partially_reduce_an_open_position_towards_a_lower_target_weight() {
+        let engine_id = Uuid::new_v4();
+        let portfolio = portfolio(engine_id);
+        let filters = InstrumentFilters::default();
+
+        // Enter 0.5 * 10,000.0 / 1,000.0 = 5.0, then rebalance again towards 0.25
+        let entered = rebalanced_orders(
+            engine_id,
+            Arc::clone(&portfolio),
+            market(),
+            filters,
+            1000.0,
+            0.5,
+        );
+        let reduced = rebalanced_orders(
+            engine_id,
+            Arc::clone(&portfolio),
+            market(),
+            filters,
+            1000.0,
+            0.25,
+        );
+
+        assert_eq!(entered.len(), 1);
+        assert_eq!(entered[0].quantity, Decimal::new(5, 0));
+
+        // 0.25 * 10,000.0 / 1,000.0 = 2.5, so 5.0 - 2.5 is sold
+        assert_eq!(reduced.len(), 1);
+        assert_eq!(reduced[0].decision, Decision::CloseLong);
+        assert_eq!(reduced[0].order_type, OrderType::Market);
+        assert_eq!(reduced[0].quantity, Decimal::new(-25, 1));
+
+        let position_id =
+            determine_position_id(engine_id, &market().exchange, &market().instrument);
+        let position = portfolio.lock().get_open_position(&position_id).unwrap();
+        assert_eq!(position.unwrap().quantity, Decimal::new(25, 1));
+    }
+
+    /// Runs a [`Trader`] of the provided [`Market`], sharing the provided Portfolio, which holds
+    /// an open long Position of 2.0 protected by a resting limit exit, then arms the kill switch
+    /// & trades on with a [`Strategy`](AlwaysLongStrategy) advising a long entry at every price.
+    fn killed_trader_events(
+        engine_id: Uuid,
+        portfolio: Arc<Mutex<TestPortfolio>>,
+        market: Market,
+    ) -> Vec<Event> {
+        let market_event = || MarketEvent {
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            ..market_event_priced(1000.0)
+        };
+        let resting_exit = ManualOrderRequest {
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            side: Side::Sell,
+            ..manual_order_request(Decimal::TWO, Some(2000.0))
+        };
+
+        let (command_tx, command_rx) = mpsc::channel(10);
+        let (trader, _, event_rx) = trader(
+            ScriptedFeed {
+                steps: VecDeque::from([
+                    FeedStep::Market(market_event()),
+                    FeedStep::Command(Command::ManualOrder(resting_exit)),
+                    FeedStep::Market(market_event()),
+                    FeedStep::Command(Command::KillSwitch),
+                    FeedStep::Market(market_event()),
+                    FeedStep::Market(market_event()),
+                ]),
+                command_tx,
+            },
+            AlwaysLongStrategy,
+            SimulatedExecution::new(ExecutionConfig::default()),
+        );
+
+        portfolio
+            .lock()
+            .set_open_position(Position {
+                position_id: determine_position_id(engine_id, &market.exchange, &market.instrument),
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                quantity: Decimal::TWO,
+                ..position()
+            })
+            .unwrap();
+
+        let trader = Trader {
+            engine_id,
+            market,
+            portfolio,
+            command_rx,
+            ..trader
+        };
+        trader.run().unwrap();
+
+        collect_events(event_rx)
+    }
+
+    #[test]
+    fn kill_switch_should_flatten_every_open_position_and_refuse_new_orders() {
+        let engine_id = Uuid::new_v4();
+        let eth = Market::new("binance_spot", ("eth", "usdt", InstrumentKind::Spot));
+        let portfolio = portfolio_of(engine_id, vec![market(), eth.clone()]);
+
+        for market in [market(), eth.clone()] {
+            let events = killed_trader_events(engine_id, Arc::clone(&portfolio), market.clone());
+
+            let orders = events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::OrderNew(order) => Some(order),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            // Resting limit exit is cancelled & replaced by a flattening market order
+            assert_eq!(orders.len(), 2);
+            let (resting, flatten) = (orders[0], orders[1]);
+            assert_eq!(resting.order_type, OrderType::Limit);
+            assert_eq!(cancelled_cids(&events), vec![resting.cid]);
+            assert_eq!(flatten.order_type, OrderType::Market);
+            assert_eq!(flatten.instrument, market.instrument);
+            assert_eq!(flatten.decision, Decision::CloseLong);
+            assert_eq!(flatten.quantity, -Decimal::TWO);
+            assert_eq!(fill_cids(&events), vec![flatten.cid]);
+
+            // Long Signals are ignored once the kill switch is armed, despite the flat Portfolio
+            let signals_after_kill_switch = events
+                .iter()
+                .skip_while(|event| !matches!(event, Event::OrderCancelled(_)))
+                .filter(|event| matches!(event, Event::Signal(_)))
+                .count();
+            assert_eq!(signals_after_kill_switch, 2);
+        }
+
+        assert!(portfolio
+            .lock()
+            .get_open_positions(engine_id, [market(), eth].iter())
+            .unwrap()
+            .is_empty());
     }
 }
@@ -0,0 +1,168 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// State of the trading loop of a [`Trader`](super::trader::Trader).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum TraderState {
+    /// Consuming [`MarketEvent`](barter_data::event::MarketEvent)s to warm up the Strategy, so
+    /// Signals are ignored.
+    WarmingUp,
+    /// Generating orders from Signals.
+    Trading,
+    /// Dropping Signals until the fill cooldown of every
+    /// [`Instrument`](barter_integration::model::instrument::Instrument) elapses.
+    CoolingDown,
+    /// Paused via [`Command::Pause`](super::Command::Pause), so no new orders are generated.
+    Paused,
+    /// Halted via [`Command::KillSwitch`](super::Command::KillSwitch), so every new order is
+    /// rejected.
+    Halted,
+    /// Trading loop stopped (eg/ by [`Command::Terminate`](super::Command::Terminate)).
+    Stopped,
+}
+
+/// Cause of a [`Transition`] between [`TraderState`]s.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum TransitionTrigger {
+    /// A [`MarketEvent`](barter_data::event::MarketEvent) was consumed (or the market data feed
+    /// finished).
+    Market,
+    /// An account event (eg/ a [`FillEvent`](crate::execution::FillEvent)) was received.
+    Account,
+    /// A remote [`Command`](super::Command) was actioned.
+    Command,
+    /// A timer elapsed (eg/ a fill cooldown).
+    Timer,
+}
+
+/// Transition of a [`Trader`](super::trader::Trader) between [`TraderState`]s.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Transition {
+    /// [`Clock`](crate::clock::Clock) time of the [`Transition`].
+    pub at: DateTime<Utc>,
+    pub from_state: TraderState,
+    pub to_state: TraderState,
+    pub trigger: TransitionTrigger,
+}
+
+/// Bounded in-memory log of the [`Transition`]s of a [`Trader`](super::trader::Trader), forming
+/// a replayable record of a trading session. Once at capacity the oldest [`Transition`] is
+/// dropped for every new [`Transition`] recorded.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct TransitionLog {
+    capacity: usize,
+    transitions: VecDeque<Transition>,
+}
+
+impl TransitionLog {
+    /// Constructs a new empty [`TransitionLog`] retaining at most `capacity` [`Transition`]s.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            transitions: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records the [`Transition`], dropping the oldest [`Transition`] if at capacity.
+    pub fn record(&mut self, transition: Transition) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.transitions.len() == self.capacity {
+            self.transitions.pop_front();
+        }
+        self.transitions.push_back(transition);
+    }
+
+    /// Returns the recorded [`Transition`]s, oldest first.
+    pub fn transitions(&self) -> impl Iterator<Item = &Transition> {
+        self.transitions.iter()
+    }
+}
+
+/// Watchdog detecting a livelocked trading loop of a [`Trader`](super::trader::Trader) that
+/// keeps bouncing between [`TraderState`]s (eg/ GenerateOrder -> Consume -> GenerateOrder)
+/// without consuming any [`MarketEvent`](barter_data::event::MarketEvent)s.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct LivelockWatchdog {
+    threshold: u64,
+    transitions: u64,
+}
+
+impl Default for LivelockWatchdog {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_THRESHOLD)
+    }
+}
+
+impl LivelockWatchdog {
+    /// Default number of consecutive [`Transition`]s tolerated without a
+    /// [`MarketEvent`](barter_data::event::MarketEvent) consumed.
+    pub const DEFAULT_THRESHOLD: u64 = 10_000;
+
+    /// Constructs a new [`LivelockWatchdog`] that trips once more than `threshold` consecutive
+    /// [`Transition`]s are recorded without a
+    /// [`MarketEvent`](barter_data::event::MarketEvent) consumed.
+    pub fn new(threshold: u64) -> Self {
+        Self {
+            threshold,
+            transitions: 0,
+        }
+    }
+
+    /// Records a [`Transition`], returning `true` if the threshold of consecutive
+    /// [`Transition`]s has been exceeded.
+    pub fn record_transition(&mut self) -> bool {
+        self.transitions += 1;
+        self.transitions > self.threshold
+    }
+
+    /// Resets the consecutive [`Transition`] count once a
+    /// [`MarketEvent`](barter_data::event::MarketEvent) is consumed.
+    pub fn reset(&mut self) {
+        self.transitions = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transition_log_should_drop_oldest_transition_once_at_capacity() {
+        let transition = |trigger| Transition {
+            at: Utc::now(),
+            from_state: TraderState::Trading,
+            to_state: TraderState::Paused,
+            trigger,
+        };
+
+        let mut log = TransitionLog::new(2);
+        log.record(transition(TransitionTrigger::Market));
+        log.record(transition(TransitionTrigger::Account));
+        log.record(transition(TransitionTrigger::Command));
+
+        let triggers = log
+            .transitions()
+            .map(|transition| transition.trigger)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            triggers,
+            vec![TransitionTrigger::Account, TransitionTrigger::Command]
+        );
+    }
+
+    #[test]
+    fn livelock_watchdog_should_trip_past_threshold_of_consecutive_transitions() {
+        let mut watchdog = LivelockWatchdog::new(2);
+        assert!(!watchdog.record_transition());
+        assert!(!watchdog.record_transition());
+
+        // Consuming a MarketEvent resets the count
+        watchdog.reset();
+        assert!(!watchdog.record_transition());
+        assert!(!watchdog.record_transition());
+        assert!(watchdog.record_transition());
+    }
+}
@@ -1,12 +1,20 @@
 use crate::{
-    execution::FillEvent,
+    engine::{
+        trader::{
+            CircuitTripped, Heartbeat, KillSwitchArmed, PositionCapBreach, PositionDiscrepancy,
+        },
+        CommandOutcome,
+    },
+    execution::{FillEvent, OrderRejection},
     portfolio::{
+        funding::FundingPayment,
         position::{Position, PositionExit, PositionUpdate},
         Balance, OrderEvent,
     },
     strategy::{Signal, SignalForceExit},
 };
 use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::Market;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use tokio::sync::mpsc;
@@ -15,19 +23,74 @@ use tracing::warn;
 /// Events that occur when bartering. [`MarketEvent`], [`Signal`], [`OrderEvent`], and
 /// [`FillEvent`] are vital to the [`Trader`](crate::engine::trader::Trader) event loop, dictating
 /// the trading sequence. The [`PositionExit`] Event is a representation of work done by the
-/// system, and is useful for analysing performance & reconciliations.
+/// system, and is useful for analysing performance & reconciliations. The
+/// [`Event::TraderStarted`] & [`Event::TraderStopped`] lifecycle Events enable the
+/// [`Trader`](crate::engine::trader::Trader) event loop to be monitored.
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Event {
+    TraderStarted(Market),
     Market(MarketEvent<DataKind>),
     Signal(Signal),
     SignalForceExit(SignalForceExit),
     OrderNew(OrderEvent),
+    /// Conditional [`OrderEvent`] whose [`Trigger`](crate::portfolio::Trigger) was met, and so
+    /// is sent for execution.
+    OrderTriggered(OrderEvent),
     OrderUpdate,
+    OrderCancelled(OrderEvent),
+    /// Open [`OrderEvent`] amended in place via a
+    /// [`Command::AmendOrder`](crate::engine::Command::AmendOrder), with it's amended price &
+    /// total quantity.
+    OrderAmended(OrderEvent),
+    /// [`OrderEvent`] the exchange refused to accept, and so is no longer open.
+    OrderRejected(OrderRejection),
+    PositionCapBreach(PositionCapBreach),
+    PositionDiscrepancy(PositionDiscrepancy),
+    KillSwitchArmed(KillSwitchArmed),
+    CircuitTripped(CircuitTripped),
+    Heartbeat(Heartbeat),
     Fill(FillEvent),
     PositionNew(Position),
     PositionUpdate(PositionUpdate),
     PositionExit(PositionExit),
+    /// Funding paid or received on an open perpetual [`Position`] at a funding time, as
+    /// simulated by a [`FundingModel`](crate::portfolio::funding::FundingModel).
+    FundingPayment(FundingPayment),
     Balance(Balance),
+    CommandOutcome(CommandOutcome),
+    TraderStopped(Market),
+}
+
+impl Event {
+    /// Returns the name of the [`Event`] variant, eg/ to tag the trading state a
+    /// [`Trader`](crate::engine::trader::Trader) is handling in logs.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::TraderStarted(_) => "TraderStarted",
+            Self::Market(_) => "Market",
+            Self::Signal(_) => "Signal",
+            Self::SignalForceExit(_) => "SignalForceExit",
+            Self::OrderNew(_) => "OrderNew",
+            Self::OrderTriggered(_) => "OrderTriggered",
+            Self::OrderUpdate => "OrderUpdate",
+            Self::OrderCancelled(_) => "OrderCancelled",
+            Self::OrderAmended(_) => "OrderAmended",
+            Self::OrderRejected(_) => "OrderRejected",
+            Self::PositionCapBreach(_) => "PositionCapBreach",
+            Self::PositionDiscrepancy(_) => "PositionDiscrepancy",
+            Self::KillSwitchArmed(_) => "KillSwitchArmed",
+            Self::CircuitTripped(_) => "CircuitTripped",
+            Self::Heartbeat(_) => "Heartbeat",
+            Self::Fill(_) => "Fill",
+            Self::PositionNew(_) => "PositionNew",
+            Self::PositionUpdate(_) => "PositionUpdate",
+            Self::PositionExit(_) => "PositionExit",
+            Self::FundingPayment(_) => "FundingPayment",
+            Self::Balance(_) => "Balance",
+            Self::CommandOutcome(_) => "CommandOutcome",
+            Self::TraderStopped(_) => "TraderStopped",
+        }
+    }
 }
 
 /// Message transmitter for sending Barter messages to downstream consumers.
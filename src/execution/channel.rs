@@ -0,0 +1,214 @@
+use crate::{
+    execution::{error::ExecutionError, order_id::ClientOrderId, ExecutionClient, FillEvent},
+    portfolio::OrderEvent,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Request sent by a [`ChannelExecution`] to an executor running outside of the
+/// [`Trader`](crate::engine::trader::Trader) event loop (eg/ on it's own task or process).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum ExecutionRequest {
+    /// Execute the [`OrderEvent`].
+    Open(OrderEvent),
+    /// Cancel the open [`OrderEvent`] with the [`ClientOrderId`].
+    Cancel(ClientOrderId),
+    /// Amend the limit price & unfilled quantity of the open [`OrderEvent`] with the
+    /// [`ClientOrderId`] of the provided [`OrderEvent`].
+    Amend(OrderEvent),
+}
+
+/// [`ExecutionClient`] that forwards every request as an [`ExecutionRequest`] over an mpsc
+/// channel to an executor running outside of the trading event loop, and returns the
+/// [`FillEvent`]s the executor sends back with the next [`MarketEvent`] consumed.
+///
+/// Since the executor fills [`OrderEvent`]s asynchronously, every [`OrderEvent`] is resting
+/// until it's [`FillEvent`]s are received. In-process executors (eg/
+/// [`SimulatedExecution`](super::simulated::SimulatedExecution)) should instead be used as the
+/// [`ExecutionClient`] directly, so their [`FillEvent`]s are applied immediately.
+#[derive(Debug)]
+pub struct ChannelExecution {
+    request_tx: mpsc::UnboundedSender<ExecutionRequest>,
+    fill_rx: mpsc::UnboundedReceiver<FillEvent>,
+    /// [`OrderEvent`]s sent to the executor that are yet to be fully filled or cancelled, with
+    /// their unfilled quantity.
+    open: HashMap<ClientOrderId, OrderEvent>,
+}
+
+impl ExecutionClient for ChannelExecution {
+    fn generate_fill(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        self.send(ExecutionRequest::Open(order.clone()))?;
+        self.open.insert(order.cid, order.clone());
+        Ok(None)
+    }
+
+    fn fill_resting_orders(&mut self, _: &MarketEvent<DataKind>) -> Vec<FillEvent> {
+        let mut fills = Vec::new();
+        while let Ok(mut fill) = self.fill_rx.try_recv() {
+            if let Some(order) = self.open.get_mut(&fill.cid) {
+                // Executors need not echo the OrderTags of the OrderEvent they filled
+                if fill.tags.is_empty() {
+                    fill.tags = order.tags.clone();
+                }
+                order.quantity -= fill.quantity;
+                if order.quantity.is_zero()
+                    || order.quantity.is_sign_negative() != fill.quantity.is_sign_negative()
+                {
+                    self.open.remove(&fill.cid);
+                }
+            }
+            fills.push(fill);
+        }
+        fills
+    }
+
+    fn cancel_order(&mut self, cid: &ClientOrderId) -> Option<OrderEvent> {
+        let order = self.open.remove(cid)?;
+        self.send(ExecutionRequest::Cancel(*cid)).ok()?;
+        Some(order)
+    }
+
+    fn amend_order(&mut self, order: &OrderEvent) -> Option<OrderEvent> {
+        let open = self.open.get_mut(&order.cid)?;
+        open.quantity = order.quantity;
+        open.market_meta.close = order.market_meta.close;
+        let amended = open.clone();
+        self.send(ExecutionRequest::Amend(amended.clone())).ok()?;
+        Some(amended)
+    }
+
+    fn restore_order(&mut self, order: &OrderEvent) {
+        self.open.insert(order.cid, order.clone());
+    }
+}
+
+impl ChannelExecution {
+    /// Constructs a new [`ChannelExecution`] that sends [`ExecutionRequest`]s to the executor
+    /// via the provided `request_tx`, and receives the [`FillEvent`]s it generates via the
+    /// provided `fill_rx`.
+    pub fn new(
+        request_tx: mpsc::UnboundedSender<ExecutionRequest>,
+        fill_rx: mpsc::UnboundedReceiver<FillEvent>,
+    ) -> Self {
+        Self {
+            request_tx,
+            fill_rx,
+            open: HashMap::new(),
+        }
+    }
+
+    /// Returns the unfilled quantity of the open [`OrderEvent`] with the [`ClientOrderId`], if
+    /// it has been sent to the executor & is yet to be fully filled or cancelled.
+    pub fn unfilled_quantity(&self, cid: &ClientOrderId) -> Option<Decimal> {
+        self.open.get(cid).map(|order| order.quantity)
+    }
+
+    fn send(&self, request: ExecutionRequest) -> Result<(), ExecutionError> {
+        self.request_tx.send(request).map_err(|error| {
+            warn!(request = ?error.0, "executor dropped the ExecutionRequest channel");
+            ExecutionError::ExecutorDisconnected
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        execution::{
+            simulated::{Config as SimulatedConfig, SimulatedExecution},
+            Fees,
+        },
+        test_util::{fill_event, market_event_trade, order_event},
+    };
+    use barter_integration::model::Side;
+
+    #[test]
+    fn channel_execution_should_forward_orders_and_return_executor_fills_with_next_market() {
+        let (request_tx, mut request_rx) = mpsc::unbounded_channel();
+        let (fill_tx, fill_rx) = mpsc::unbounded_channel();
+        let mut execution = ChannelExecution::new(request_tx, fill_rx);
+
+        // OrderEvent is forwarded to the executor & rests until it's FillEvent is received
+        let order = order_event();
+        assert!(execution.generate_fill(&order).unwrap().is_none());
+        let Ok(ExecutionRequest::Open(forwarded)) = request_rx.try_recv() else {
+            panic!("OrderEvent was not forwarded to the executor");
+        };
+        assert_eq!(forwarded, order);
+
+        // Executor fills the OrderEvent in process via a SimulatedExecution
+        let mut executor = SimulatedExecution::new(SimulatedConfig {
+            simulated_fees_pct: Fees::default(),
+        });
+        let fill = executor
+            .generate_fill(&forwarded)
+            .unwrap()
+            .expect("SimulatedExecution did not fill the market OrderEvent");
+        fill_tx.send(fill.clone()).unwrap();
+
+        assert_eq!(
+            execution.fill_resting_orders(&market_event_trade(Side::Buy)),
+            vec![fill]
+        );
+        assert_eq!(execution.unfilled_quantity(&order.cid), None);
+    }
+
+    #[test]
+    fn channel_execution_should_forward_cancels_of_open_orders_only() {
+        let (request_tx, mut request_rx) = mpsc::unbounded_channel();
+        let (fill_tx, fill_rx) = mpsc::unbounded_channel();
+        let mut execution = ChannelExecution::new(request_tx, fill_rx);
+
+        let order = order_event();
+        execution.generate_fill(&order).unwrap();
+        request_rx.try_recv().unwrap();
+
+        // Partially filled OrderEvent remains open with it's unfilled quantity
+        fill_tx
+            .send(FillEvent {
+                cid: order.cid,
+                quantity: order.quantity / Decimal::TWO,
+                ..fill_event()
+            })
+            .unwrap();
+        execution.fill_resting_orders(&market_event_trade(Side::Buy));
+        assert_eq!(
+            execution.unfilled_quantity(&order.cid),
+            Some(order.quantity / Decimal::TWO)
+        );
+
+        assert_eq!(
+            execution.cancel_order(&order.cid),
+            Some(OrderEvent {
+                quantity: order.quantity / Decimal::TWO,
+                ..order.clone()
+            })
+        );
+        assert_eq!(
+            request_rx.try_recv().unwrap(),
+            ExecutionRequest::Cancel(order.cid)
+        );
+
+        // Unknown OrderEvents are not forwarded
+        assert_eq!(execution.cancel_order(&order.cid), None);
+        assert!(request_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn channel_execution_should_error_once_executor_disconnects() {
+        let (request_tx, request_rx) = mpsc::unbounded_channel();
+        let (_fill_tx, fill_rx) = mpsc::unbounded_channel();
+        let mut execution = ChannelExecution::new(request_tx, fill_rx);
+        drop(request_rx);
+
+        assert!(matches!(
+            execution.generate_fill(&order_event()),
+            Err(ExecutionError::ExecutorDisconnected)
+        ));
+    }
+}
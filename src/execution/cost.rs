@@ -0,0 +1,219 @@
+use crate::portfolio::{decimal_from_f64, OrderEvent, OrderType};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Calculates the commission charged for filling an [`OrderEvent`].
+pub trait FeeModel: Debug {
+    /// Return the commission charged for filling `fill_quantity` of the [`OrderEvent`] at the
+    /// `fill_price`. The `fill_quantity` may be less than the [`OrderEvent`] quantity for a
+    /// partial fill, in which case the commission is proportional to the filled quantity.
+    fn commission(
+        &self,
+        order: &OrderEvent,
+        fill_quantity: Decimal,
+        fill_price: Decimal,
+    ) -> Decimal;
+}
+
+/// Determines the price an [`OrderEvent`] is filled at, given the quoted market price.
+pub trait SlippageModel: Debug {
+    /// Return the fill price of the [`OrderEvent`] after perturbing the quoted market `price`.
+    fn fill_price(&self, order: &OrderEvent, price: Decimal) -> Decimal;
+}
+
+/// [`FeeModel`] that charges no commission.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct NoCommission;
+
+impl FeeModel for NoCommission {
+    fn commission(&self, _: &OrderEvent, _: Decimal, _: Decimal) -> Decimal {
+        Decimal::ZERO
+    }
+}
+
+/// [`FeeModel`] that charges a percentage (in decimal form, eg/ 0.001 for 0.1%) of the filled
+/// value. [`OrderType::Limit`] orders are charged the maker rate, and every other order the
+/// taker rate.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct MakerTakerCommission {
+    pub maker_pct: f64,
+    pub taker_pct: f64,
+}
+
+impl FeeModel for MakerTakerCommission {
+    fn commission(
+        &self,
+        order: &OrderEvent,
+        fill_quantity: Decimal,
+        fill_price: Decimal,
+    ) -> Decimal {
+        let pct = match order.order_type {
+            OrderType::Limit => self.maker_pct,
+            OrderType::Market | OrderType::Bracket | OrderType::StopLimit => self.taker_pct,
+        };
+
+        decimal_from_f64(pct) * fill_quantity.abs() * fill_price
+    }
+}
+
+/// [`FeeModel`] that charges a flat commission per [`OrderEvent`], pro-rated by the filled
+/// quantity for partial fills.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct FlatCommission {
+    pub per_order: Decimal,
+}
+
+impl FeeModel for FlatCommission {
+    fn commission(&self, order: &OrderEvent, fill_quantity: Decimal, _: Decimal) -> Decimal {
+        if order.quantity.is_zero() {
+            return Decimal::ZERO;
+        }
+
+        self.per_order * (fill_quantity / order.quantity).abs().min(Decimal::ONE)
+    }
+}
+
+/// [`SlippageModel`] that fills every [`OrderEvent`] at the quoted market price.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct NoSlippage;
+
+impl SlippageModel for NoSlippage {
+    fn fill_price(&self, _: &OrderEvent, price: Decimal) -> Decimal {
+        price
+    }
+}
+
+/// [`SlippageModel`] that moves the fill price against the [`OrderEvent`] by a fixed number of
+/// basis points.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct FixedSlippage {
+    pub bps: f64,
+}
+
+impl SlippageModel for FixedSlippage {
+    fn fill_price(&self, order: &OrderEvent, price: Decimal) -> Decimal {
+        slip(order, price, decimal_from_f64(self.bps))
+    }
+}
+
+/// [`SlippageModel`] that moves the fill price against the [`OrderEvent`] by a number of basis
+/// points proportional to the absolute order quantity, modelling market impact.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct VolumeSlippage {
+    pub bps_per_unit: f64,
+}
+
+impl SlippageModel for VolumeSlippage {
+    fn fill_price(&self, order: &OrderEvent, price: Decimal) -> Decimal {
+        slip(
+            order,
+            price,
+            decimal_from_f64(self.bps_per_unit) * order.quantity.abs(),
+        )
+    }
+}
+
+/// Moves the price against the [`OrderEvent`] by the provided basis points: buys (+ve quantity)
+/// fill higher, and sells (-ve quantity) fill lower.
+fn slip(order: &OrderEvent, price: Decimal, bps: Decimal) -> Decimal {
+    let slippage = price * bps / Decimal::from(10_000);
+
+    if order.quantity.is_sign_negative() {
+        price - slippage
+    } else {
+        price + slippage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::order_event;
+
+    fn order(quantity: Decimal, order_type: OrderType) -> OrderEvent {
+        OrderEvent {
+            quantity,
+            order_type,
+            ..order_event()
+        }
+    }
+
+    #[test]
+    fn maker_taker_commission_should_charge_rate_of_order_type_on_filled_value() {
+        let fee_model = MakerTakerCommission {
+            maker_pct: 0.001,
+            taker_pct: 0.002,
+        };
+
+        let limit = order(-Decimal::TEN, OrderType::Limit);
+        let market = order(Decimal::TEN, OrderType::Market);
+
+        assert_eq!(
+            fee_model.commission(&limit, -Decimal::TEN, Decimal::ONE_HUNDRED),
+            Decimal::ONE
+        );
+        assert_eq!(
+            fee_model.commission(&market, Decimal::TEN, Decimal::ONE_HUNDRED),
+            Decimal::TWO
+        );
+    }
+
+    #[test]
+    fn commission_should_be_proportional_to_filled_quantity_of_partial_fill() {
+        let order = order(Decimal::TEN, OrderType::Market);
+        let partial = Decimal::new(25, 1);
+
+        let maker_taker = MakerTakerCommission {
+            maker_pct: 0.0,
+            taker_pct: 0.002,
+        };
+        assert_eq!(
+            maker_taker.commission(&order, partial, Decimal::ONE_HUNDRED),
+            Decimal::new(5, 1)
+        );
+
+        let flat = FlatCommission {
+            per_order: Decimal::from(4),
+        };
+        assert_eq!(
+            flat.commission(&order, Decimal::TEN, Decimal::ONE_HUNDRED),
+            Decimal::from(4)
+        );
+        assert_eq!(
+            flat.commission(&order, partial, Decimal::ONE_HUNDRED),
+            Decimal::ONE
+        );
+    }
+
+    #[test]
+    fn slippage_models_should_move_fill_price_against_the_order() {
+        let buy = order(Decimal::TWO, OrderType::Market);
+        let sell = order(-Decimal::TWO, OrderType::Market);
+
+        let fixed = FixedSlippage { bps: 10.0 };
+        assert_eq!(
+            fixed.fill_price(&buy, Decimal::ONE_THOUSAND),
+            Decimal::from(1001)
+        );
+        assert_eq!(
+            fixed.fill_price(&sell, Decimal::ONE_THOUSAND),
+            Decimal::from(999)
+        );
+
+        let volume = VolumeSlippage { bps_per_unit: 5.0 };
+        assert_eq!(
+            volume.fill_price(&buy, Decimal::ONE_THOUSAND),
+            Decimal::from(1001)
+        );
+        assert_eq!(
+            volume.fill_price(&sell, Decimal::ONE_THOUSAND),
+            Decimal::from(999)
+        );
+
+        assert_eq!(
+            NoSlippage.fill_price(&buy, Decimal::ONE_THOUSAND),
+            Decimal::ONE_THOUSAND
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{
+    execution::{
+        error::ExecutionError,
+        order_id::ClientOrderId,
+        simulated::{Config as SimulatedConfig, SimulatedExecution},
+        ExecutionClient, FillEvent, OrderRejection,
+    },
+    portfolio::{Balance, OrderEvent},
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use tracing::info;
+
+/// Determines whether [`OrderEvent`]s are executed for real, or only paper filled.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum ExecutionMode {
+    /// [`OrderEvent`]s are executed by the wrapped [`ExecutionClient`].
+    #[default]
+    Live,
+    /// [`OrderEvent`]s are logged & paper filled at their market price, and never reach the
+    /// wrapped [`ExecutionClient`].
+    DryRun,
+}
+
+/// Execution handler that wraps another [`ExecutionClient`] and, depending on the configured
+/// [`ExecutionMode`], either delegates [`OrderEvent`] execution to it, or logs the
+/// [`OrderEvent`] and generates a paper [`FillEvent`] via a [`SimulatedExecution`]. Paper fills
+/// still update the Portfolio, so statistics remain meaningful during a dry run.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DryRunExecution<Execution>
+where
+    Execution: ExecutionClient,
+{
+    mode: ExecutionMode,
+    execution: Execution,
+    paper: SimulatedExecution,
+}
+
+impl<Execution> ExecutionClient for DryRunExecution<Execution>
+where
+    Execution: ExecutionClient,
+{
+    fn generate_fill(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        match self.mode {
+            ExecutionMode::Live => self.execution.generate_fill(order),
+            ExecutionMode::DryRun => {
+                info!(
+                    ?order,
+                    action = "generating paper FillEvent",
+                    "dry run skipped OrderEvent execution"
+                );
+                self.paper.generate_fill(order)
+            }
+        }
+    }
+
+    fn fill_resting_orders(&mut self, market: &MarketEvent<DataKind>) -> Vec<FillEvent> {
+        match self.mode {
+            ExecutionMode::Live => self.execution.fill_resting_orders(market),
+            ExecutionMode::DryRun => self.paper.fill_resting_orders(market),
+        }
+    }
+
+    fn cancel_order(&mut self, cid: &ClientOrderId) -> Option<OrderEvent> {
+        match self.mode {
+            ExecutionMode::Live => self.execution.cancel_order(cid),
+            ExecutionMode::DryRun => self.paper.cancel_order(cid),
+        }
+    }
+
+    fn amend_order(&mut self, order: &OrderEvent) -> Option<OrderEvent> {
+        match self.mode {
+            ExecutionMode::Live => self.execution.amend_order(order),
+            ExecutionMode::DryRun => self.paper.amend_order(order),
+        }
+    }
+
+    fn fetch_balance(
+        &mut self,
+    ) -> impl Future<Output = Result<Option<Balance>, ExecutionError>> + Send {
+        // Paper trading is seeded from the balance of the live exchange account
+        self.execution.fetch_balance()
+    }
+
+    fn restore_order(&mut self, order: &OrderEvent) {
+        match self.mode {
+            ExecutionMode::Live => self.execution.restore_order(order),
+            ExecutionMode::DryRun => self.paper.restore_order(order),
+        }
+    }
+
+    fn rejected_orders(&mut self) -> Vec<OrderRejection> {
+        match self.mode {
+            ExecutionMode::Live => self.execution.rejected_orders(),
+            ExecutionMode::DryRun => self.paper.rejected_orders(),
+        }
+    }
+
+    fn exchange_position(
+        &mut self,
+        instrument: &Instrument,
+    ) -> Result<Option<Decimal>, ExecutionError> {
+        match self.mode {
+            ExecutionMode::Live => self.execution.exchange_position(instrument),
+            ExecutionMode::DryRun => self.paper.exchange_position(instrument),
+        }
+    }
+}
+
+impl<Execution> DryRunExecution<Execution>
+where
+    Execution: ExecutionClient,
+{
+    /// Constructs a new [`DryRunExecution`] component. The [`SimulatedConfig`] configures the
+    /// fees applied to paper [`FillEvent`]s generated in [`ExecutionMode::DryRun`].
+    pub fn new(mode: ExecutionMode, execution: Execution, paper_cfg: SimulatedConfig) -> Self {
+        Self {
+            mode,
+            execution,
+            paper: SimulatedExecution::new(paper_cfg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::order_event;
+    use rust_decimal::Decimal;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Execution handler that counts the [`OrderEvent`]s it receives.
+    #[derive(Debug, Default)]
+    struct CountingExecution {
+        orders: AtomicUsize,
+    }
+
+    impl ExecutionClient for CountingExecution {
+        fn generate_fill(
+            &mut self,
+            order: &OrderEvent,
+        ) -> Result<Option<FillEvent>, ExecutionError> {
+            self.orders.fetch_add(1, Ordering::SeqCst);
+            SimulatedExecution::new(SimulatedConfig::default()).generate_fill(order)
+        }
+    }
+
+    #[test]
+    fn dry_run_execution_should_paper_fill_without_executing_order() {
+        let mut execution = DryRunExecution::new(
+            ExecutionMode::DryRun,
+            CountingExecution::default(),
+            SimulatedConfig::default(),
+        );
+
+        let mut input_order = order_event();
+        input_order.quantity = Decimal::TWO;
+        input_order.market_meta.close = Decimal::ONE_HUNDRED;
+
+        let actual = execution.generate_fill(&input_order).unwrap().unwrap();
+
+        assert_eq!(execution.execution.orders.load(Ordering::SeqCst), 0);
+        assert_eq!(actual.quantity, Decimal::TWO);
+        assert_eq!(actual.fill_value_gross, Decimal::from(200));
+    }
+
+    #[test]
+    fn live_execution_should_execute_order() {
+        let mut execution = DryRunExecution::new(
+            ExecutionMode::Live,
+            CountingExecution::default(),
+            SimulatedConfig::default(),
+        );
+
+        execution.generate_fill(&order_event()).unwrap();
+
+        assert_eq!(execution.execution.orders.load(Ordering::SeqCst), 1);
+    }
+}
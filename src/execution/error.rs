@@ -5,4 +5,10 @@ use thiserror::Error;
 pub enum ExecutionError {
     #[error("Failed to build struct due to missing attributes: {0}")]
     BuilderIncomplete(&'static str),
+
+    #[error("Executor disconnected from the ExecutionRequest channel")]
+    ExecutorDisconnected,
+
+    #[error("Execution failed during the recorded session being replayed")]
+    ReplayedFailure,
 }
@@ -0,0 +1,134 @@
+use crate::portfolio::{error::PortfolioError, OrderEvent, OrderType};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+/// Price & quantity increments (and minimum notional value) an exchange enforces on the
+/// [`OrderEvent`]s of an [`Instrument`](barter_integration::model::instrument::Instrument).
+/// Exchanges reject [`OrderEvent`]s that violate them, so they are normalised before submission.
+///
+/// A non-positive `tick_size` or `lot_size` leaves prices or quantities unconstrained.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct InstrumentFilters {
+    /// Increment every order price must be a multiple of (eg/ 0.01).
+    pub tick_size: Decimal,
+    /// Increment every order quantity must be a multiple of (eg/ 0.001).
+    pub lot_size: Decimal,
+    /// Minimum notional value (abs(quantity) * price) of an order.
+    pub min_notional: Decimal,
+}
+
+impl InstrumentFilters {
+    /// Rounds the provided price to the nearest multiple of the tick size.
+    pub fn round_price(&self, price: Decimal) -> Decimal {
+        if self.tick_size <= Decimal::ZERO {
+            return price;
+        }
+
+        (price / self.tick_size).round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+            * self.tick_size
+    }
+
+    /// Rounds the provided quantity towards zero to a multiple of the lot size, so rounding never
+    /// increases exposure.
+    pub fn round_quantity(&self, quantity: Decimal) -> Decimal {
+        if self.lot_size <= Decimal::ZERO {
+            return quantity;
+        }
+
+        (quantity / self.lot_size).trunc() * self.lot_size
+    }
+
+    /// Normalises the [`OrderEvent`] to these [`InstrumentFilters`], rounding it's limit & stop
+    /// prices to the tick size and it's quantity down to the lot size. Returns an error if the
+    /// quantity rounds down to zero, or the notional value is below the minimum notional.
+    pub fn normalise(&self, mut order: OrderEvent) -> Result<OrderEvent, PortfolioError> {
+        order.quantity = self.round_quantity(order.quantity);
+        if order.quantity.is_zero() {
+            return Err(PortfolioError::InvalidOrder(
+                "quantity rounds down to zero at the instrument lot size",
+            ));
+        }
+
+        // Market orders are priced by the market, so only limit & stop prices are rounded
+        if matches!(order.order_type, OrderType::Limit | OrderType::StopLimit) {
+            order.market_meta.close = self.round_price(order.market_meta.close);
+        }
+        order.stop_price = order.stop_price.map(|price| self.round_price(price));
+
+        let notional = order.quantity.abs() * order.market_meta.close;
+        if notional < self.min_notional {
+            return Err(PortfolioError::InvalidOrder(
+                "notional value is below the instrument minimum notional",
+            ));
+        }
+
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::order_event;
+
+    fn filters() -> InstrumentFilters {
+        InstrumentFilters {
+            tick_size: Decimal::new(5, 2),
+            lot_size: Decimal::new(1, 3),
+            min_notional: Decimal::TEN,
+        }
+    }
+
+    #[test]
+    fn normalise_should_snap_limit_price_to_the_nearest_tick() {
+        let mut order = order_event();
+        order.order_type = OrderType::Limit;
+        order.market_meta.close = Decimal::new(100123, 3);
+        order.stop_price = Some(Decimal::new(9997, 2));
+
+        let actual = filters().normalise(order).unwrap();
+
+        assert_eq!(actual.market_meta.close, Decimal::new(1001, 1));
+        assert_eq!(actual.stop_price, Some(Decimal::new(9995, 2)));
+    }
+
+    #[test]
+    fn normalise_should_floor_quantity_to_the_lot_size() {
+        let mut order = order_event();
+        order.quantity = Decimal::new(12_345_678, 6);
+
+        let actual = filters().normalise(order.clone()).unwrap();
+        assert_eq!(actual.quantity, Decimal::new(12_345, 3));
+
+        // Sell quantities round towards zero too, so exposure is never increased
+        order.quantity = -order.quantity;
+        let actual = filters().normalise(order).unwrap();
+        assert_eq!(actual.quantity, Decimal::new(-12_345, 3));
+    }
+
+    #[test]
+    fn normalise_should_reject_order_whose_quantity_rounds_down_to_zero() {
+        let mut order = order_event();
+        order.quantity = Decimal::new(9, 4);
+
+        assert!(matches!(
+            filters().normalise(order),
+            Err(PortfolioError::InvalidOrder(_))
+        ));
+    }
+
+    #[test]
+    fn normalise_should_reject_order_below_min_notional() {
+        // 0.099 * 100.0 = 9.9 < 10.0
+        let mut order = order_event();
+        order.quantity = Decimal::new(99, 3);
+        assert!(matches!(
+            filters().normalise(order.clone()),
+            Err(PortfolioError::InvalidOrder(_))
+        ));
+
+        // 0.1 * 100.0 = 10.0
+        order.quantity = Decimal::new(1, 1);
+        assert!(filters().normalise(order).is_ok());
+    }
+}
@@ -1,19 +1,146 @@
-use crate::{data::MarketMeta, portfolio::OrderEvent, strategy::Decision};
+use crate::{
+    data::MarketMeta,
+    portfolio::{Balance, OrderEvent, OrderTags},
+    strategy::Decision,
+};
+use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Exchange};
 use chrono::{DateTime, Utc};
 use error::ExecutionError;
+use order_id::ClientOrderId;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter},
+    future::Future,
+};
 
 /// Barter execution module specific errors.
 pub mod error;
 
+/// Commission & slippage models used to simulate the transaction costs of [`OrderEvent`]
+/// execution.
+pub mod cost;
+
 /// Handlers for simulated and live [`OrderEvent`] execution.
 pub mod simulated;
 
+/// Generators of unique client order identifiers.
+pub mod order_id;
+
+/// Partial fill aware lifecycle state of every order sent for execution.
+pub mod order_state;
+
+/// Token bucket rate limiter capping the rate [`OrderEvent`]s are sent for execution.
+pub mod rate_limit;
+
+/// Retry policy resubmitting [`OrderEvent`]s the exchange rejected for a transient reason.
+pub mod retry;
+
+/// Tick size, lot size & minimum notional filters [`OrderEvent`]s are normalised to before they
+/// are sent for execution.
+pub mod filter;
+
+/// Execution handler wrapper that paper fills [`OrderEvent`]s instead of executing them when
+/// dry running.
+pub mod dry_run;
+
+/// Execution handler that forwards [`OrderEvent`]s over a channel to an executor running outside
+/// of the trading event loop.
+pub mod channel;
+
+/// Per-exchange latency & rejection profiles applied to simulated [`OrderEvent`] execution, to
+/// stress test strategies against an unfriendly exchange.
+pub mod profile;
+
 /// Generates a result [`FillEvent`] by executing an [`OrderEvent`].
 pub trait ExecutionClient {
-    /// Return a [`FillEvent`] from executing the input [`OrderEvent`].
-    fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError>;
+    /// Return a [`FillEvent`] from executing the input [`OrderEvent`], or `None` if the
+    /// [`OrderEvent`] is resting (eg/ an uncrossed limit order) & may be filled by a subsequent
+    /// [`MarketEvent`].
+    fn generate_fill(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError>;
+
+    /// Return a [`FillEvent`] for every resting [`OrderEvent`] filled by the input
+    /// [`MarketEvent`]. Defaults to no [`FillEvent`]s for clients that never rest orders.
+    fn fill_resting_orders(&mut self, _market: &MarketEvent<DataKind>) -> Vec<FillEvent> {
+        Vec::new()
+    }
+
+    /// Cancel the resting [`OrderEvent`] with the provided [`ClientOrderId`], returning it if it
+    /// was resting. Defaults to `None` for clients that never rest orders.
+    fn cancel_order(&mut self, _cid: &ClientOrderId) -> Option<OrderEvent> {
+        None
+    }
+
+    /// Amend the resting [`OrderEvent`] with the [`ClientOrderId`] of the provided
+    /// [`OrderEvent`] to it's limit price (the [`MarketMeta`](crate::data::MarketMeta) close) &
+    /// unfilled quantity, returning the amended [`OrderEvent`] once acknowledged if it was
+    /// resting. Defaults to `None` for clients that never rest orders.
+    fn amend_order(&mut self, _order: &OrderEvent) -> Option<OrderEvent> {
+        None
+    }
+
+    /// Re-register an [`OrderEvent`] that was open before a restart (eg/ restored from a
+    /// [`Checkpoint`](crate::engine::checkpoint::Checkpoint)), so it may be filled or cancelled.
+    /// Defaults to a no-op for clients whose open orders persist on the exchange.
+    fn restore_order(&mut self, _order: &OrderEvent) {}
+
+    /// Return every [`OrderRejection`] of an [`OrderEvent`] the exchange refused (eg/ a post-only
+    /// order that would cross) since last called, so the [`OrderEvent`] is no longer tracked as
+    /// open. Defaults to no [`OrderRejection`]s for clients that never reject orders.
+    fn rejected_orders(&mut self) -> Vec<OrderRejection> {
+        Vec::new()
+    }
+
+    /// Return the net signed quantity of the [`Instrument`] position held on the exchange (eg/
+    /// as tracked from the exchange account stream), so a drifted internal Position can be
+    /// reconciled against it. Must not block the event loop. Defaults to `Ok(None)` for clients
+    /// that do not track the exchange position (eg/ simulated execution).
+    fn exchange_position(
+        &mut self,
+        _instrument: &Instrument,
+    ) -> Result<Option<Decimal>, ExecutionError> {
+        Ok(None)
+    }
+
+    /// Fetch the [`Balance`] of the account on the exchange, eg/ to seed the Portfolio of a live
+    /// [`Engine`](crate::engine::Engine) with it's opening balance. Defaults to `Ok(None)` for
+    /// clients without an exchange account (eg/ simulated execution).
+    fn fetch_balance(
+        &mut self,
+    ) -> impl Future<Output = Result<Option<Balance>, ExecutionError>> + Send {
+        std::future::ready(Ok(None))
+    }
+}
+
+/// Identifier of the exchange account (or sub-account) an [`OrderEvent`] is executed on, and so
+/// the Portfolio slice the resulting [`FillEvent`]s are applied to.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct AccountId(pub String);
+
+impl AccountId {
+    /// Name of the default account, used unless a [`Trader`](crate::engine::trader::Trader) is
+    /// configured with another [`AccountId`].
+    pub const DEFAULT: &'static str = "default";
+
+    pub fn new<S>(id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self(id.into())
+    }
+}
+
+impl Default for AccountId {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT)
+    }
+}
+
+impl Display for AccountId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
 /// Fills are journals of work done by an Execution handler. These are sent back to the portfolio
@@ -21,6 +148,11 @@ pub trait ExecutionClient {
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct FillEvent {
     pub time: DateTime<Utc>,
+    /// [`ClientOrderId`] of the [`OrderEvent`] this [`FillEvent`] filled.
+    pub cid: ClientOrderId,
+    /// [`AccountId`] of the [`OrderEvent`] this [`FillEvent`] filled.
+    #[serde(default)]
+    pub account: AccountId,
     pub exchange: Exchange,
     pub instrument: Instrument,
     /// Metadata propagated from source MarketEvent
@@ -28,11 +160,19 @@ pub struct FillEvent {
     /// LONG, CloseLong, SHORT or CloseShort
     pub decision: Decision,
     /// +ve or -ve Quantity depending on Decision
-    pub quantity: f64,
+    pub quantity: Decimal,
     /// abs(Quantity) * ClosePrice, excluding TotalFees
-    pub fill_value_gross: f64,
+    pub fill_value_gross: Decimal,
     /// All fee types incurred when executing an [`OrderEvent`], and their associated [`FeeAmount`].
     pub fees: Fees,
+    /// Exchange assigned identifier of the trade that generated this [`FillEvent`], used to
+    /// detect the same fill being received twice (eg/ on a websocket reconnect). `None` for
+    /// simulated fills.
+    #[serde(default)]
+    pub fill_id: Option<String>,
+    /// [`OrderTags`] of the [`OrderEvent`] this [`FillEvent`] filled.
+    #[serde(default)]
+    pub tags: OrderTags,
 }
 
 impl FillEvent {
@@ -44,6 +184,38 @@ impl FillEvent {
     }
 }
 
+/// [`OrderEvent`] rejected by the exchange rather than filled or rested.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderRejection {
+    pub time: DateTime<Utc>,
+    pub order: OrderEvent,
+    pub reason: RejectReason,
+}
+
+/// Reason an [`OrderEvent`] was rejected by the exchange.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum RejectReason {
+    /// [`TimeInForce::PostOnly`](crate::portfolio::TimeInForce) order would have crossed the
+    /// market & taken liquidity.
+    PostOnlyWouldCross,
+    /// Notional value of the [`OrderEvent`] exceeds the available balance.
+    InsufficientBalance,
+    /// Too many [`OrderEvent`]s were sent to the exchange within it's rate limit window.
+    RateLimited,
+    /// Exchange was temporarily unable to accept the [`OrderEvent`] (eg/ overloaded or in
+    /// maintenance).
+    Unavailable,
+}
+
+impl RejectReason {
+    /// Determines if the rejection is transient, ie/ the same [`OrderEvent`] may be accepted if
+    /// resubmitted later (eg/ once the rate limit window elapses). Other rejections are terminal
+    /// & would be rejected again.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::RateLimited | Self::Unavailable)
+    }
+}
+
 /// All potential fees incurred by a [`FillEvent`].
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct Fees {
@@ -57,25 +229,29 @@ pub struct Fees {
 
 impl Fees {
     /// Calculates the sum of every [FeeAmount] in [Fees].
-    pub fn calculate_total_fees(&self) -> f64 {
+    pub fn calculate_total_fees(&self) -> FeeAmount {
         self.exchange + self.network + self.slippage
     }
 }
 
-/// Communicative type alias for Fee amount as f64.
-pub type FeeAmount = f64;
+/// Communicative type alias for Fee amount as a [`Decimal`].
+pub type FeeAmount = Decimal;
 
 /// Builder to construct [FillEvent] instances.
 #[derive(Debug, Default)]
 pub struct FillEventBuilder {
     pub time: Option<DateTime<Utc>>,
+    pub cid: Option<ClientOrderId>,
+    pub account: Option<AccountId>,
     pub exchange: Option<Exchange>,
     pub instrument: Option<Instrument>,
     pub market_meta: Option<MarketMeta>,
     pub decision: Option<Decision>,
-    pub quantity: Option<f64>,
-    pub fill_value_gross: Option<f64>,
+    pub quantity: Option<Decimal>,
+    pub fill_value_gross: Option<Decimal>,
     pub fees: Option<Fees>,
+    pub fill_id: Option<String>,
+    pub tags: Option<OrderTags>,
 }
 
 impl FillEventBuilder {
@@ -90,6 +266,21 @@ impl FillEventBuilder {
         }
     }
 
+    pub fn cid(self, value: ClientOrderId) -> Self {
+        Self {
+            cid: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`AccountId`], defaults to [`AccountId::default`].
+    pub fn account(self, value: AccountId) -> Self {
+        Self {
+            account: Some(value),
+            ..self
+        }
+    }
+
     pub fn exchange(self, value: Exchange) -> Self {
         Self {
             exchange: Some(value),
@@ -118,14 +309,14 @@ impl FillEventBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Decimal) -> Self {
         Self {
             quantity: Some(value),
             ..self
         }
     }
 
-    pub fn fill_value_gross(self, value: f64) -> Self {
+    pub fn fill_value_gross(self, value: Decimal) -> Self {
         Self {
             fill_value_gross: Some(value),
             ..self
@@ -139,9 +330,30 @@ impl FillEventBuilder {
         }
     }
 
+    /// Optional exchange assigned trade identifier, defaults to `None`.
+    pub fn fill_id<S>(self, value: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            fill_id: Some(value.into()),
+            ..self
+        }
+    }
+
+    /// Optional [`OrderTags`] of the filled [`OrderEvent`], defaults to no tags.
+    pub fn tags(self, value: OrderTags) -> Self {
+        Self {
+            tags: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<FillEvent, ExecutionError> {
         Ok(FillEvent {
             time: self.time.ok_or(ExecutionError::BuilderIncomplete("time"))?,
+            cid: self.cid.ok_or(ExecutionError::BuilderIncomplete("cid"))?,
+            account: self.account.unwrap_or_default(),
             exchange: self
                 .exchange
                 .ok_or(ExecutionError::BuilderIncomplete("exchange"))?,
@@ -161,6 +373,8 @@ impl FillEventBuilder {
                 .fill_value_gross
                 .ok_or(ExecutionError::BuilderIncomplete("fill_value_gross"))?,
             fees: self.fees.ok_or(ExecutionError::BuilderIncomplete("fees"))?,
+            fill_id: self.fill_id,
+            tags: self.tags.unwrap_or_default(),
         })
     }
 }
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Display, Formatter},
+    sync::atomic::{AtomicU64, Ordering},
+};
+use uuid::Uuid;
+
+/// Generates a unique [`ClientOrderId`] for every [`OrderEvent`](crate::portfolio::OrderEvent)
+/// sent for execution. Used for idempotent order submission & matching
+/// [`FillEvent`](super::FillEvent)s to the [`OrderEvent`](crate::portfolio::OrderEvent) they
+/// filled.
+pub trait OrderIdGenerator: Debug {
+    /// Return the next unique [`ClientOrderId`].
+    fn next_id(&self) -> ClientOrderId;
+
+    /// Return the seed to persist (eg/ in a [`Checkpoint`](crate::engine::checkpoint::Checkpoint))
+    /// so a restarted [`MonotonicOrderIdGenerator`] can resume without collision. Defaults to
+    /// `None` for generators that cannot be resumed.
+    fn checkpoint_seed(&self) -> Option<ClientOrderId> {
+        None
+    }
+}
+
+/// Unique client identifier of an [`OrderEvent`](crate::portfolio::OrderEvent), propagated to
+/// the [`FillEvent`](super::FillEvent) that fills it.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct ClientOrderId {
+    /// Identifier of the [`OrderIdGenerator`] session that generated this [`ClientOrderId`].
+    pub session: Uuid,
+    /// Monotonically increasing sequence number within the session.
+    pub sequence: u64,
+}
+
+impl Display for ClientOrderId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", self.session, self.sequence)
+    }
+}
+
+/// Default [`OrderIdGenerator`] that generates [`ClientOrderId`]s from a session Uuid & a
+/// monotonically increasing counter. Safe to share between [`Trader`](crate::engine::trader::Trader)s.
+#[derive(Debug)]
+pub struct MonotonicOrderIdGenerator {
+    session: Uuid,
+    sequence: AtomicU64,
+}
+
+impl Default for MonotonicOrderIdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OrderIdGenerator for MonotonicOrderIdGenerator {
+    fn next_id(&self) -> ClientOrderId {
+        ClientOrderId {
+            session: self.session,
+            sequence: self.sequence.fetch_add(1, Ordering::Relaxed) + 1,
+        }
+    }
+
+    fn checkpoint_seed(&self) -> Option<ClientOrderId> {
+        Some(self.seed())
+    }
+}
+
+impl MonotonicOrderIdGenerator {
+    /// Constructs a new [`MonotonicOrderIdGenerator`] using a random session Uuid.
+    pub fn new() -> Self {
+        Self {
+            session: Uuid::new_v4(),
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    /// Constructs a [`MonotonicOrderIdGenerator`] that resumes generating [`ClientOrderId`]s
+    /// after the persisted seed provided (eg/ the last [`ClientOrderId`] generated before a
+    /// restart), guaranteeing no collision with any [`ClientOrderId`] generated before it.
+    pub fn resume_after(seed: ClientOrderId) -> Self {
+        Self {
+            session: seed.session,
+            sequence: AtomicU64::new(seed.sequence),
+        }
+    }
+
+    /// Returns the seed to persist so a restarted [`MonotonicOrderIdGenerator`] can resume via
+    /// [`MonotonicOrderIdGenerator::resume_after`].
+    pub fn seed(&self) -> ClientOrderId {
+        ClientOrderId {
+            session: self.session,
+            sequence: self.sequence.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn should_generate_unique_monotonically_increasing_order_ids() {
+        let generator = MonotonicOrderIdGenerator::new();
+
+        let ids = (0..10_000).map(|_| generator.next_id()).collect::<Vec<_>>();
+
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(ids.iter().collect::<HashSet<_>>().len(), 10_000);
+    }
+
+    #[test]
+    fn seeded_order_id_generator_should_resume_without_collision_after_restart() {
+        let generator = MonotonicOrderIdGenerator::new();
+        let before_restart = (0..100)
+            .map(|_| generator.next_id())
+            .collect::<HashSet<_>>();
+
+        // Simulate a restart by persisting the seed & constructing a new generator from it
+        let seed = generator.seed();
+
+        let resumed = MonotonicOrderIdGenerator::resume_after(seed);
+        let after_restart = (0..100).map(|_| resumed.next_id()).collect::<Vec<_>>();
+
+        assert!(after_restart.iter().all(|id| !before_restart.contains(id)));
+        assert!(after_restart.iter().all(|id| id > &seed));
+    }
+}
@@ -0,0 +1,233 @@
+use super::order_id::ClientOrderId;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Lifecycle state of an [`OrderEvent`](crate::portfolio::OrderEvent) sent for execution,
+/// updated by the [`Trader`](crate::engine::trader::Trader) from every event relating to it, so
+/// strategies & risk logic can determine how much of it is still working.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum OrderState {
+    /// Sent for execution, but yet to be acknowledged.
+    New,
+    /// Accepted for execution & working, with nothing filled yet.
+    Acknowledged,
+    /// Partially filled, with the remaining absolute quantity still working.
+    PartiallyFilled { filled: Decimal, remaining: Decimal },
+    /// Fully filled.
+    Filled,
+    /// Cancelled before it was fully filled.
+    Cancelled,
+    /// Refused by the exchange.
+    Rejected,
+}
+
+impl OrderState {
+    /// Determines if the order is still working, ie/ may still be filled.
+    pub fn is_working(&self) -> bool {
+        matches!(
+            self,
+            Self::New | Self::Acknowledged | Self::PartiallyFilled { .. }
+        )
+    }
+}
+
+/// [`OrderState`] of every order sent for execution by a
+/// [`Trader`](crate::engine::trader::Trader), alongside it's absolute quantity & filled quantity.
+/// The [`OrderState`] of finished orders is remembered until [`OrderStates::CAPACITY`] more
+/// recent orders have finished.
+///
+/// Events may be received out of order (eg/ a fill before the acknowledgement), so a fill
+/// implies the order was acknowledged, & a late acknowledgement is ignored. Fills of an order
+/// that was cancelled whilst they were in flight are accumulated, but leave it cancelled.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) struct OrderStates {
+    orders: HashMap<ClientOrderId, TrackedOrder>,
+    finished: VecDeque<ClientOrderId>,
+}
+
+/// Order tracked by an [`OrderStates`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct TrackedOrder {
+    /// Absolute quantity of the order.
+    quantity: Decimal,
+    /// Absolute quantity of the order filled so far.
+    filled: Decimal,
+    state: OrderState,
+}
+
+impl OrderStates {
+    /// Maximum number of finished orders remembered.
+    pub const CAPACITY: usize = 10_000;
+
+    /// Returns the [`OrderState`] of the order with the provided [`ClientOrderId`], if known.
+    pub fn get(&self, cid: &ClientOrderId) -> Option<OrderState> {
+        self.orders.get(cid).map(|order| order.state)
+    }
+
+    /// Starts tracking an order of the provided quantity sent for execution.
+    pub fn new_order(&mut self, cid: ClientOrderId, quantity: Decimal) {
+        self.orders.insert(
+            cid,
+            TrackedOrder {
+                quantity: quantity.abs(),
+                filled: Decimal::ZERO,
+                state: OrderState::New,
+            },
+        );
+    }
+
+    /// Marks the order as acknowledged, unless it has progressed beyond [`OrderState::New`].
+    pub fn acknowledge(&mut self, cid: &ClientOrderId) {
+        if let Some(order) = self.orders.get_mut(cid) {
+            if order.state == OrderState::New {
+                order.state = OrderState::Acknowledged;
+            }
+        }
+    }
+
+    /// Adds the absolute quantity of a fill to the filled quantity of the order.
+    pub fn fill(&mut self, cid: &ClientOrderId, quantity: Decimal) {
+        let Some(order) = self.orders.get_mut(cid) else {
+            return;
+        };
+        order.filled += quantity.abs();
+        if order.state.is_working() {
+            self.update_fill_state(cid);
+        }
+    }
+
+    /// Replaces the absolute quantity of a working order that was amended.
+    pub fn amend(&mut self, cid: &ClientOrderId, quantity: Decimal) {
+        let Some(order) = self.orders.get_mut(cid) else {
+            return;
+        };
+        order.quantity = quantity.abs();
+        if order.state.is_working() && !order.filled.is_zero() {
+            self.update_fill_state(cid);
+        }
+    }
+
+    /// Marks the order as cancelled, if it is still working.
+    pub fn cancel(&mut self, cid: &ClientOrderId) {
+        self.finish(cid, OrderState::Cancelled);
+    }
+
+    /// Marks the order as rejected, if it is still working.
+    pub fn reject(&mut self, cid: &ClientOrderId) {
+        self.finish(cid, OrderState::Rejected);
+    }
+
+    /// Derives the [`OrderState`] of a working order from it's filled quantity.
+    fn update_fill_state(&mut self, cid: &ClientOrderId) {
+        let Some(order) = self.orders.get_mut(cid) else {
+            return;
+        };
+        match order.quantity - order.filled {
+            remaining if remaining <= Decimal::ZERO => self.finish(cid, OrderState::Filled),
+            remaining => {
+                order.state = OrderState::PartiallyFilled {
+                    filled: order.filled,
+                    remaining,
+                }
+            }
+        }
+    }
+
+    /// Moves a working order to the provided finished [`OrderState`], forgetting the oldest
+    /// finished order once [`OrderStates::CAPACITY`] is exceeded.
+    fn finish(&mut self, cid: &ClientOrderId, state: OrderState) {
+        let Some(order) = self.orders.get_mut(cid) else {
+            return;
+        };
+        if !order.state.is_working() {
+            return;
+        }
+        order.state = state;
+
+        self.finished.push_back(*cid);
+        if self.finished.len() > Self::CAPACITY {
+            if let Some(oldest) = self.finished.pop_front() {
+                self.orders.remove(&oldest);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cid(sequence: u64) -> ClientOrderId {
+        ClientOrderId {
+            sequence,
+            ..ClientOrderId::default()
+        }
+    }
+
+    #[test]
+    fn order_states_should_track_order_from_new_through_partial_fill_to_filled() {
+        let mut states = OrderStates::default();
+        let order = cid(1);
+
+        states.new_order(order, Decimal::new(-10, 0));
+        assert_eq!(states.get(&order), Some(OrderState::New));
+
+        states.acknowledge(&order);
+        assert_eq!(states.get(&order), Some(OrderState::Acknowledged));
+
+        states.fill(&order, Decimal::new(-4, 0));
+        assert_eq!(
+            states.get(&order),
+            Some(OrderState::PartiallyFilled {
+                filled: Decimal::new(4, 0),
+                remaining: Decimal::new(6, 0),
+            })
+        );
+
+        states.fill(&order, Decimal::new(-6, 0));
+        assert_eq!(states.get(&order), Some(OrderState::Filled));
+
+        // Finished orders are not revived by late events
+        states.cancel(&order);
+        assert_eq!(states.get(&order), Some(OrderState::Filled));
+    }
+
+    #[test]
+    fn order_states_should_track_order_from_new_to_rejected() {
+        let mut states = OrderStates::default();
+        let order = cid(1);
+
+        states.new_order(order, Decimal::ONE);
+        states.reject(&order);
+        assert_eq!(states.get(&order), Some(OrderState::Rejected));
+        assert!(!states.get(&order).unwrap().is_working());
+
+        // Unknown orders are ignored
+        states.fill(&cid(2), Decimal::ONE);
+        assert_eq!(states.get(&cid(2)), None);
+    }
+
+    #[test]
+    fn order_states_should_handle_fill_received_before_acknowledgement() {
+        let mut states = OrderStates::default();
+        let order = cid(1);
+
+        states.new_order(order, Decimal::TWO);
+        states.fill(&order, Decimal::ONE);
+        assert_eq!(
+            states.get(&order),
+            Some(OrderState::PartiallyFilled {
+                filled: Decimal::ONE,
+                remaining: Decimal::ONE,
+            })
+        );
+
+        // Late acknowledgement does not regress the partially filled order
+        states.acknowledge(&order);
+        assert!(matches!(
+            states.get(&order),
+            Some(OrderState::PartiallyFilled { .. })
+        ));
+    }
+}
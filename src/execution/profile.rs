@@ -0,0 +1,338 @@
+use crate::{
+    data::MarketMeta,
+    execution::{
+        simulated::{DefaultFillSimulator, FillSimulator, SimulatedBook},
+        FillEvent, RejectReason,
+    },
+    portfolio::{OrderEvent, OrderType},
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Latency & rejection behaviour of a simulated exchange.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ExchangeProfile {
+    /// Exchange time between an [`OrderEvent`] being sent & it arriving at the exchange, during
+    /// which it can neither fill nor be cancelled.
+    #[serde(
+        deserialize_with = "crate::statistic::de_duration_from_secs",
+        serialize_with = "crate::statistic::se_duration_as_secs"
+    )]
+    pub latency: Duration,
+    /// Optional available balance the notional value of every [`OrderEvent`] must not exceed, or
+    /// it is rejected with [`RejectReason::InsufficientBalance`].
+    pub max_order_notional: Option<Decimal>,
+    /// Optional maximum number of [`OrderEvent`]s accepted per second of exchange time, beyond
+    /// which they are rejected with [`RejectReason::RateLimited`].
+    pub max_orders_per_second: Option<usize>,
+}
+
+impl Default for ExchangeProfile {
+    fn default() -> Self {
+        Self {
+            latency: Duration::zero(),
+            max_order_notional: None,
+            max_orders_per_second: None,
+        }
+    }
+}
+
+/// [`OrderEvent`] sent to the simulated exchange that is yet to arrive.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+struct InFlight {
+    arrives_at: DateTime<Utc>,
+    order: OrderEvent,
+}
+
+/// [`FillSimulator`] that applies the [`ExchangeProfile`] of each [`OrderEvent`]'s exchange
+/// before handing it to the wrapped [`FillSimulator`], so strategies can be stress tested
+/// against an unfriendly exchange. Exchanges without a configured [`ExchangeProfile`] accept
+/// every [`OrderEvent`] immediately.
+///
+/// Rejected [`OrderEvent`]s are returned via
+/// [`ExecutionClient::rejected_orders`](super::ExecutionClient::rejected_orders). Delayed
+/// [`OrderEvent`]s arrive with the first [`MarketEvent`] at or after their arrival time, so
+/// they are matched against the market at arrival rather than when sent. Since delayed
+/// [`OrderEvent`]s never fill on submission, latency should not be applied to immediate
+/// [`TimeInForce`](crate::portfolio::TimeInForce)s.
+///
+/// Time is the exchange time of the latest [`MarketEvent`], so profiles behave identically in
+/// backtests & live simulations.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct ProfiledSimulator<Simulator = DefaultFillSimulator> {
+    simulator: Simulator,
+    profiles: HashMap<Exchange, ExchangeProfile>,
+    in_flight: VecDeque<InFlight>,
+    /// Arrival times of the [`OrderEvent`]s accepted by each exchange within the last second.
+    accepted: HashMap<Exchange, VecDeque<DateTime<Utc>>>,
+    /// Exchange time of the latest [`MarketEvent`].
+    latest_time: Option<DateTime<Utc>>,
+}
+
+impl<Simulator> FillSimulator for ProfiledSimulator<Simulator>
+where
+    Simulator: FillSimulator,
+{
+    fn on_order(&mut self, book: &mut SimulatedBook, order: &OrderEvent) -> Vec<FillEvent> {
+        let profile = self.profile(&order.exchange);
+        let now = self.latest_time.unwrap_or(order.market_meta.time);
+
+        if profile.latency.is_zero() {
+            return self.arrive(book, now, order.clone());
+        }
+
+        self.in_flight.push_back(InFlight {
+            arrives_at: now + profile.latency,
+            order: order.clone(),
+        });
+        Vec::new()
+    }
+
+    fn on_market(
+        &mut self,
+        book: &mut SimulatedBook,
+        market: &MarketEvent<DataKind>,
+    ) -> Vec<FillEvent> {
+        self.latest_time = Some(market.exchange_time);
+
+        // Match resting orders before any arrivals, which missed the MarketEvent price range
+        let mut fills = self.simulator.on_market(book, market);
+
+        let (arrived, in_flight) = std::mem::take(&mut self.in_flight)
+            .into_iter()
+            .partition::<VecDeque<_>, _>(|in_flight| in_flight.arrives_at <= market.exchange_time);
+        self.in_flight = in_flight;
+
+        for InFlight { arrives_at, order } in arrived {
+            // Market orders are filled at the market price at arrival, not when sent
+            let order = match (order.order_type, book.latest_price) {
+                (OrderType::Market | OrderType::Bracket, Some(close)) => OrderEvent {
+                    market_meta: MarketMeta {
+                        close,
+                        time: market.exchange_time,
+                    },
+                    ..order
+                },
+                _ => order,
+            };
+            fills.extend(self.arrive(book, arrives_at, order));
+        }
+        fills
+    }
+}
+
+impl ProfiledSimulator {
+    /// Constructs a new [`ProfiledSimulator`] wrapping the provided [`FillSimulator`], with no
+    /// [`ExchangeProfile`]s configured.
+    pub fn new<Simulator>(simulator: Simulator) -> ProfiledSimulator<Simulator>
+    where
+        Simulator: FillSimulator,
+    {
+        ProfiledSimulator {
+            simulator,
+            profiles: HashMap::new(),
+            in_flight: VecDeque::new(),
+            accepted: HashMap::new(),
+            latest_time: None,
+        }
+    }
+}
+
+impl<Simulator> ProfiledSimulator<Simulator>
+where
+    Simulator: FillSimulator,
+{
+    /// Applies the provided [`ExchangeProfile`] to every [`OrderEvent`] sent to the
+    /// [`Exchange`].
+    pub fn with_profile<E>(mut self, exchange: E, profile: ExchangeProfile) -> Self
+    where
+        E: Into<Exchange>,
+    {
+        self.profiles.insert(exchange.into(), profile);
+        self
+    }
+
+    /// Returns the [`OrderEvent`]s sent to the simulated exchange that are yet to arrive.
+    pub fn in_flight_orders(&self) -> impl Iterator<Item = &OrderEvent> {
+        self.in_flight.iter().map(|in_flight| &in_flight.order)
+    }
+
+    fn profile(&self, exchange: &Exchange) -> ExchangeProfile {
+        self.profiles.get(exchange).copied().unwrap_or_default()
+    }
+
+    /// Applies the [`ExchangeProfile`] rejection rules to the [`OrderEvent`] arriving at the
+    /// exchange, handing it to the wrapped [`FillSimulator`] if accepted.
+    fn arrive(
+        &mut self,
+        book: &mut SimulatedBook,
+        now: DateTime<Utc>,
+        order: OrderEvent,
+    ) -> Vec<FillEvent> {
+        let profile = self.profile(&order.exchange);
+
+        let notional = order.quantity.abs() * order.market_meta.close;
+        if profile
+            .max_order_notional
+            .is_some_and(|max_notional| notional > max_notional)
+        {
+            book.reject(now, &order, RejectReason::InsufficientBalance);
+            return Vec::new();
+        }
+
+        if let Some(max_orders) = profile.max_orders_per_second {
+            let accepted = self.accepted.entry(order.exchange.clone()).or_default();
+            while accepted
+                .front()
+                .is_some_and(|time| *time <= now - Duration::seconds(1))
+            {
+                accepted.pop_front();
+            }
+            if accepted.len() >= max_orders {
+                book.reject(now, &order, RejectReason::RateLimited);
+                return Vec::new();
+            }
+            accepted.push_back(now);
+        }
+
+        self.simulator.on_order(book, &order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        execution::{
+            simulated::{Config, SimulatedExecution},
+            ExecutionClient,
+        },
+        portfolio::TimeInForce,
+        test_util::{market_event_trade, order_event},
+    };
+    use barter_integration::model::Side;
+    use rust_decimal::Decimal;
+
+    fn market_at(exchange_time: DateTime<Utc>, price: f64) -> MarketEvent<DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange = Exchange::from("binance");
+        market.exchange_time = exchange_time;
+        if let DataKind::Trade(trade) = &mut market.kind {
+            trade.price = price;
+        }
+        market
+    }
+
+    fn execution(profile: ExchangeProfile) -> SimulatedExecution<ProfiledSimulator> {
+        SimulatedExecution::with_simulator(
+            ProfiledSimulator::new(DefaultFillSimulator::default())
+                .with_profile("binance", profile),
+        )
+    }
+
+    #[test]
+    fn post_only_order_that_would_cross_should_be_rejected() {
+        let mut execution = SimulatedExecution::new(Config::default());
+        execution.fill_resting_orders(&market_at(Utc::now(), 1000.0));
+
+        // Buy post-only limit above the market price would take liquidity
+        let order = OrderEvent {
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::PostOnly,
+            market_meta: MarketMeta {
+                close: Decimal::from(1010),
+                time: Utc::now(),
+            },
+            ..order_event()
+        };
+        assert_eq!(execution.generate_fill(&order).unwrap(), None);
+        assert!(execution.resting_orders().is_empty());
+
+        let rejections = execution.rejected_orders();
+        assert_eq!(rejections.len(), 1);
+        assert_eq!(rejections[0].order.cid, order.cid);
+        assert_eq!(rejections[0].reason, RejectReason::PostOnlyWouldCross);
+        assert!(execution.rejected_orders().is_empty());
+
+        // Buy post-only limit below the market price rests as a maker order
+        let order = OrderEvent {
+            market_meta: MarketMeta {
+                close: Decimal::from(990),
+                time: Utc::now(),
+            },
+            ..order
+        };
+        assert_eq!(execution.generate_fill(&order).unwrap(), None);
+        assert_eq!(execution.resting_orders().len(), 1);
+        assert!(execution.rejected_orders().is_empty());
+    }
+
+    #[test]
+    fn order_should_fill_after_configured_exchange_latency() {
+        let start = Utc::now();
+        let mut execution = execution(ExchangeProfile {
+            latency: Duration::seconds(2),
+            ..ExchangeProfile::default()
+        });
+        execution.fill_resting_orders(&market_at(start, 1000.0));
+
+        let order = order_event();
+        assert_eq!(execution.generate_fill(&order).unwrap(), None);
+
+        // Order is still in flight one second later
+        let fills = execution.fill_resting_orders(&market_at(start + Duration::seconds(1), 1005.0));
+        assert!(fills.is_empty());
+
+        // Order arrives & fills at the market price at arrival
+        let fills = execution.fill_resting_orders(&market_at(start + Duration::seconds(2), 1010.0));
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].cid, order.cid);
+        assert_eq!(fills[0].fill_value_gross, Decimal::from(1010));
+    }
+
+    #[test]
+    fn orders_breaching_exchange_profile_limits_should_be_rejected() {
+        let mut execution = execution(ExchangeProfile {
+            max_order_notional: Some(Decimal::from(5000)),
+            max_orders_per_second: Some(1),
+            ..ExchangeProfile::default()
+        });
+        execution.fill_resting_orders(&market_at(Utc::now(), 1000.0));
+
+        let order = |quantity| OrderEvent {
+            quantity,
+            market_meta: MarketMeta {
+                close: Decimal::ONE_THOUSAND,
+                time: Utc::now(),
+            },
+            ..order_event()
+        };
+
+        assert!(execution
+            .generate_fill(&order(Decimal::new(10, 0)))
+            .unwrap()
+            .is_none());
+        assert!(execution
+            .generate_fill(&order(Decimal::ONE))
+            .unwrap()
+            .is_some());
+        assert!(execution
+            .generate_fill(&order(Decimal::ONE))
+            .unwrap()
+            .is_none());
+
+        let reasons = execution
+            .rejected_orders()
+            .into_iter()
+            .map(|rejection| rejection.reason)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            vec![RejectReason::InsufficientBalance, RejectReason::RateLimited]
+        );
+    }
+}
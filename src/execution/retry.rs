@@ -0,0 +1,78 @@
+use super::RejectReason;
+use chrono::Duration;
+
+/// Policy resubmitting [`OrderEvent`](crate::portfolio::OrderEvent)s the exchange rejected for
+/// a transient [`RejectReason`] (see [`RejectReason::is_transient`]), with an exponential
+/// backoff of [`Clock`](crate::clock::Clock) time between attempts.
+///
+/// The [`Trader`](crate::engine::trader::Trader) resubmits each retry with a fresh
+/// [`ClientOrderId`](super::order_id::ClientOrderId), validating it exactly like a new order, so
+/// retries respect the [`RateLimiter`](super::rate_limit::RateLimiter), the position cap & every
+/// other pre-trade check. Terminal rejections are never retried.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct RetryPolicy {
+    max_retries: usize,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Constructs a new [`RetryPolicy`] resubmitting a transiently rejected order up to
+    /// `max_retries` times, waiting `initial_backoff` before the first retry & doubling the
+    /// backoff for every subsequent retry, up to a max backoff of one minute.
+    pub fn new(max_retries: usize, initial_backoff: Duration) -> Self {
+        Self {
+            max_retries,
+            initial_backoff: initial_backoff.max(Duration::zero()),
+            max_backoff: Duration::minutes(1).max(initial_backoff),
+        }
+    }
+
+    /// Replaces the max backoff between retries.
+    pub fn with_max_backoff(mut self, value: Duration) -> Self {
+        self.max_backoff = value.max(self.initial_backoff);
+        self
+    }
+
+    /// Max number of times a transiently rejected order is resubmitted.
+    pub fn max_retries(&self) -> usize {
+        self.max_retries
+    }
+
+    /// Determines if an order rejected for the provided [`RejectReason`], after already being
+    /// resubmitted `retries` times, should be resubmitted again.
+    pub fn should_retry(&self, reason: RejectReason, retries: usize) -> bool {
+        reason.is_transient() && retries < self.max_retries
+    }
+
+    /// Backoff before the provided retry, counting from 1 for the first retry.
+    pub fn backoff(&self, retry: usize) -> Duration {
+        let doublings = u32::try_from(retry.saturating_sub(1)).unwrap_or(u32::MAX);
+        2_i32
+            .checked_pow(doublings)
+            .and_then(|factor| self.initial_backoff.checked_mul(factor))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_policy_should_only_retry_transient_rejections_with_doubling_backoff() {
+        let policy = RetryPolicy::new(3, Duration::milliseconds(100))
+            .with_max_backoff(Duration::milliseconds(300));
+
+        assert!(policy.should_retry(RejectReason::RateLimited, 0));
+        assert!(policy.should_retry(RejectReason::Unavailable, 2));
+        assert!(!policy.should_retry(RejectReason::RateLimited, 3));
+        assert!(!policy.should_retry(RejectReason::InsufficientBalance, 0));
+        assert!(!policy.should_retry(RejectReason::PostOnlyWouldCross, 0));
+
+        assert_eq!(policy.backoff(1), Duration::milliseconds(100));
+        assert_eq!(policy.backoff(2), Duration::milliseconds(200));
+        assert_eq!(policy.backoff(3), Duration::milliseconds(300));
+        assert_eq!(policy.backoff(usize::MAX), Duration::milliseconds(300));
+    }
+}
@@ -1,10 +1,19 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 
 use crate::{
-    execution::{error::ExecutionError, ExecutionClient, Fees, FillEvent},
-    portfolio::OrderEvent,
+    data::MarketMeta,
+    execution::{
+        cost::{FeeModel, NoCommission, NoSlippage, SlippageModel},
+        error::ExecutionError,
+        order_id::ClientOrderId,
+        ExecutionClient, Fees, FillEvent, OrderRejection, RejectReason,
+    },
+    portfolio::{decimal_from_f64, OrderEvent, OrderType, TimeInForce},
 };
+use barter_data::event::{DataKind, MarketEvent};
 
 /// Configuration for constructing a [`SimulatedExecution`] via the new() constructor method.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
@@ -13,46 +22,360 @@ pub struct Config {
     pub simulated_fees_pct: Fees,
 }
 
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+/// Matching logic of a [`SimulatedExecution`], determining if & how the [`OrderEvent`]s it is
+/// sent are filled. Implement to model exchange behaviour the [`DefaultFillSimulator`] does not,
+/// such as queue position, latency, or rejection probability.
+///
+/// Both methods are handed the [`SimulatedBook`], communicating the latest market price & the
+/// [`OrderEvent`]s resting on the simulated exchange.
+pub trait FillSimulator {
+    /// Returns the [`FillEvent`]s generated by the [`OrderEvent`] being sent to the simulated
+    /// exchange. An [`OrderEvent`] that is not filled immediately may be rested in the
+    /// [`SimulatedBook`], or rejected by returning no [`FillEvent`]s without resting it.
+    fn on_order(&mut self, book: &mut SimulatedBook, order: &OrderEvent) -> Vec<FillEvent>;
+
+    /// Returns the [`FillEvent`]s of the resting [`OrderEvent`]s filled by the [`MarketEvent`].
+    /// The [`SimulatedBook`] market price has already been updated to the [`MarketEvent`].
+    fn on_market(
+        &mut self,
+        book: &mut SimulatedBook,
+        market: &MarketEvent<DataKind>,
+    ) -> Vec<FillEvent>;
+}
+
+/// State of the simulated exchange shared with a [`FillSimulator`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct SimulatedBook {
+    /// Latest market price, used to determine if a limit [`OrderEvent`] is marketable.
+    pub latest_price: Option<Decimal>,
+    /// Liquidity at the touch of the latest [`MarketEvent`], used to fill
+    /// [`TimeInForce::ImmediateOrCancel`] & [`TimeInForce::FillOrKill`] [`OrderEvent`]s.
+    latest_liquidity: Option<Liquidity>,
+    /// [`OrderEvent`]s resting until filled by a [`MarketEvent`], or cancelled.
+    pub resting: Vec<OrderEvent>,
+    /// [`OrderRejection`]s of [`OrderEvent`]s refused by the simulated exchange, yet to be
+    /// returned via [`ExecutionClient::rejected_orders`].
+    pub rejected: Vec<OrderRejection>,
+    /// Exchange time of the latest [`MarketEvent`], used as the time of the simulated exchange.
+    latest_time: Option<DateTime<Utc>>,
+}
+
+impl SimulatedBook {
+    /// Updates the latest market price & liquidity to those of the [`MarketEvent`], unless the
+    /// [`DataKind`] does not communicate a price (eg/ [`DataKind::Liquidation`]).
+    fn update(&mut self, market: &MarketEvent<DataKind>) {
+        self.latest_time = Some(
+            self.latest_time
+                .map_or(market.exchange_time, |time| time.max(market.exchange_time)),
+        );
+        if let Some(market_meta) = MarketMeta::from_market(market) {
+            self.latest_price = Some(market_meta.close);
+            self.latest_liquidity = Liquidity::from_market(market);
+        }
+    }
+
+    /// Returns the time of the simulated exchange when handling the provided [`OrderEvent`]: the
+    /// exchange time of the latest [`MarketEvent`], else the time of the market data the
+    /// [`OrderEvent`] was priced from. Never reads the wall-clock, so backtests are reproducible.
+    pub fn time(&self, order: &OrderEvent) -> DateTime<Utc> {
+        self.latest_time.unwrap_or(order.market_meta.time)
+    }
+
+    /// Rejects the [`OrderEvent`] for the provided [`RejectReason`], without resting it.
+    pub fn reject(&mut self, time: DateTime<Utc>, order: &OrderEvent, reason: RejectReason) {
+        self.rejected.push(OrderRejection {
+            time,
+            order: order.clone(),
+            reason,
+        });
+    }
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 /// Simulated execution handler that executes [`OrderEvent`]s to generate [`FillEvent`]s via a
-/// simulated broker interaction.
-pub struct SimulatedExecution {
-    fees_pct: Fees,
+/// simulated broker interaction, matching them using the [`FillSimulator`].
+///
+/// If the [`FillSimulator`] generates several [`FillEvent`]s for an [`OrderEvent`] (eg/ partial
+/// fills), the first is returned immediately & the remainder with the next [`MarketEvent`].
+pub struct SimulatedExecution<Simulator = DefaultFillSimulator> {
+    simulator: Simulator,
+    book: SimulatedBook,
+    /// [`FillEvent`]s generated by an [`OrderEvent`] that are yet to be returned.
+    pending: VecDeque<FillEvent>,
 }
 
-impl ExecutionClient for SimulatedExecution {
-    fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
-        // Assume (for now) that all orders are filled at the market price
-        let fill_value_gross = SimulatedExecution::calculate_fill_value_gross(order);
+impl<Simulator> ExecutionClient for SimulatedExecution<Simulator>
+where
+    Simulator: FillSimulator,
+{
+    fn generate_fill(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        let mut fills = self.simulator.on_order(&mut self.book, order).into_iter();
+        let fill = fills.next();
+        self.pending.extend(fills);
+        Ok(fill)
+    }
 
-        Ok(FillEvent {
-            time: Utc::now(),
-            exchange: order.exchange.clone(),
-            instrument: order.instrument.clone(),
-            market_meta: order.market_meta,
-            decision: order.decision,
-            quantity: order.quantity,
-            fill_value_gross,
-            fees: self.calculate_fees(&fill_value_gross),
-        })
+    fn fill_resting_orders(&mut self, market: &MarketEvent<DataKind>) -> Vec<FillEvent> {
+        self.book.update(market);
+        let mut fills: Vec<_> = self.pending.drain(..).collect();
+        fills.extend(self.simulator.on_market(&mut self.book, market));
+        fills
+    }
+
+    fn cancel_order(&mut self, cid: &ClientOrderId) -> Option<OrderEvent> {
+        let index = self
+            .book
+            .resting
+            .iter()
+            .position(|order| &order.cid == cid)?;
+        Some(self.book.resting.remove(index))
+    }
+
+    fn amend_order(&mut self, order: &OrderEvent) -> Option<OrderEvent> {
+        let resting = self
+            .book
+            .resting
+            .iter_mut()
+            .find(|resting| resting.cid == order.cid)?;
+        resting.quantity = order.quantity;
+        resting.market_meta.close = order.market_meta.close;
+        Some(resting.clone())
+    }
+
+    fn restore_order(&mut self, order: &OrderEvent) {
+        self.book.resting.push(order.clone());
+    }
+
+    fn rejected_orders(&mut self) -> Vec<OrderRejection> {
+        std::mem::take(&mut self.book.rejected)
     }
 }
 
 impl SimulatedExecution {
-    /// Constructs a new [`SimulatedExecution`] component.
+    /// Constructs a new [`SimulatedExecution`] component that charges no commission & fills at
+    /// the market price.
     pub fn new(cfg: Config) -> Self {
-        Self {
+        Self::with_models(cfg, NoCommission, NoSlippage)
+    }
+
+    /// Calculates the simulated gross fill value (excluding TotalFees) of filling the input
+    /// [`OrderEvent`] at the provided fill price.
+    fn calculate_fill_value_gross(order: &OrderEvent, fill_price: Decimal) -> Decimal {
+        order.quantity.abs() * fill_price
+    }
+}
+
+impl<Fee, Slippage> SimulatedExecution<DefaultFillSimulator<Fee, Slippage>>
+where
+    Fee: FeeModel,
+    Slippage: SlippageModel,
+{
+    /// Constructs a new [`SimulatedExecution`] component using the provided [`FeeModel`] &
+    /// [`SlippageModel`].
+    pub fn with_models(cfg: Config, fee_model: Fee, slippage_model: Slippage) -> Self {
+        Self::with_simulator(DefaultFillSimulator {
             fees_pct: cfg.simulated_fees_pct,
+            fee_model,
+            slippage_model,
+            fill_gaps_at_market: false,
+        })
+    }
+
+    /// Fill resting limit [`OrderEvent`]s crossed by a price gap between [`MarketEvent`]s at the
+    /// gapped market price, rather than the limit price (default).
+    pub fn fill_gaps_at_market(mut self, value: bool) -> Self {
+        self.simulator.fill_gaps_at_market = value;
+        self
+    }
+}
+
+impl<Simulator> SimulatedExecution<Simulator>
+where
+    Simulator: FillSimulator,
+{
+    /// Constructs a new [`SimulatedExecution`] component matching [`OrderEvent`]s using the
+    /// provided [`FillSimulator`].
+    pub fn with_simulator(simulator: Simulator) -> Self {
+        Self {
+            simulator,
+            book: SimulatedBook::default(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Returns the [`OrderEvent`]s currently resting.
+    pub fn resting_orders(&self) -> &[OrderEvent] {
+        &self.book.resting
+    }
+}
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+/// Default [`FillSimulator`] of a [`SimulatedExecution`], matching [`OrderEvent`]s against the
+/// touch & the traded price range of each [`MarketEvent`].
+///
+/// Market [`OrderEvent`]s are filled in full at the market price perturbed by the
+/// [`SlippageModel`]. Limit [`OrderEvent`]s are filled at the market price if marketable, else
+/// they rest until a subsequent [`MarketEvent`] price crosses the limit price, or they are
+/// cancelled. StopLimit [`OrderEvent`]s rest until the market trades through their stop price,
+/// after which they are handled as limit [`OrderEvent`]s. Every fill incurs the commission of the
+/// [`FeeModel`] in addition to the simulated percentage [`Fees`].
+///
+/// [`TimeInForce::ImmediateOrCancel`] & [`TimeInForce::FillOrKill`] [`OrderEvent`]s never rest:
+/// they fill against the liquidity at the touch of the latest [`MarketEvent`] (in part, or in
+/// full), or are cancelled with zero fill. Liquidity is unlimited if the latest [`MarketEvent`]
+/// does not communicate it (eg/ [`DataKind::OrderBook`]). [`TimeInForce::PostOnly`]
+/// [`OrderEvent`]s that would cross the latest market price are rejected rather than filled.
+pub struct DefaultFillSimulator<Fee = NoCommission, Slippage = NoSlippage>
+where
+    Fee: FeeModel,
+    Slippage: SlippageModel,
+{
+    fees_pct: Fees,
+    fee_model: Fee,
+    slippage_model: Slippage,
+    /// Fill resting limit [`OrderEvent`]s crossed by a price gap at the gapped market price,
+    /// rather than the limit price.
+    fill_gaps_at_market: bool,
+}
+
+impl<Fee, Slippage> FillSimulator for DefaultFillSimulator<Fee, Slippage>
+where
+    Fee: FeeModel,
+    Slippage: SlippageModel,
+{
+    fn on_order(&mut self, book: &mut SimulatedBook, order: &OrderEvent) -> Vec<FillEvent> {
+        if order.time_in_force == TimeInForce::PostOnly && would_take_liquidity(book, order) {
+            book.reject(book.time(order), order, RejectReason::PostOnlyWouldCross);
+            return Vec::new();
+        }
+
+        let order = match order.order_type {
+            OrderType::Limit => order.clone(),
+            OrderType::StopLimit => match book.latest_price {
+                Some(price) if is_stop_triggered(order, price, price) => triggered(order),
+                _ => return rest(book, order.clone()),
+            },
+            OrderType::Market | OrderType::Bracket => {
+                // Assume (for now) that market orders are filled at the slipped market price
+                let order = match immediate_quantity(book, order) {
+                    Some(quantity) => OrderEvent {
+                        quantity,
+                        ..order.clone()
+                    },
+                    None => return Vec::new(),
+                };
+                let fill_price = self
+                    .slippage_model
+                    .fill_price(&order, order.market_meta.close);
+                return vec![self.fill(&order, fill_price, order.market_meta, book.time(&order))];
+            }
+        };
+
+        // Marketable limit orders are filled at the latest market price, others rest
+        match book.latest_price {
+            Some(price) if is_limit_crossed(&order, price, price) => {
+                let Some(quantity) = immediate_quantity(book, &order) else {
+                    return Vec::new();
+                };
+                let market_meta = MarketMeta {
+                    close: price,
+                    time: order.market_meta.time,
+                };
+                let time = book.time(&order);
+                vec![self.fill(&OrderEvent { quantity, ..order }, price, market_meta, time)]
+            }
+            _ => rest(book, order),
         }
     }
 
-    /// Calculates the simulated gross fill value (excluding TotalFees) based on the input [`OrderEvent`].
-    fn calculate_fill_value_gross(order: &OrderEvent) -> f64 {
-        order.quantity.abs() * order.market_meta.close
+    fn on_market(
+        &mut self,
+        book: &mut SimulatedBook,
+        market: &MarketEvent<DataKind>,
+    ) -> Vec<FillEvent> {
+        let (open, high, low) = match price_range(market) {
+            Some(range) => range,
+            None => return Vec::new(),
+        };
+
+        // Resting StopLimit orders rest as limit orders once the market trades through their stop
+        for order in book.resting.iter_mut() {
+            if order.order_type == OrderType::StopLimit && is_stop_triggered(order, high, low) {
+                *order = triggered(order);
+            }
+        }
+
+        let (crossed, resting): (Vec<_>, Vec<_>) = std::mem::take(&mut book.resting)
+            .into_iter()
+            .partition(|order| {
+                order.order_type == OrderType::Limit && is_limit_crossed(order, high, low)
+            });
+        book.resting = resting;
+
+        crossed
+            .iter()
+            .map(|order| {
+                // Limit orders crossed by a price gap (ie/ at the open) fill at the limit price,
+                // unless configured to fill at the gapped market price
+                let limit_price = order.market_meta.close;
+                let fill_price = if self.fill_gaps_at_market && is_limit_crossed(order, open, open)
+                {
+                    open
+                } else {
+                    limit_price
+                };
+
+                self.fill(
+                    order,
+                    fill_price,
+                    MarketMeta {
+                        close: fill_price,
+                        time: market.exchange_time,
+                    },
+                    market.exchange_time,
+                )
+            })
+            .collect()
+    }
+}
+
+impl<Fee, Slippage> DefaultFillSimulator<Fee, Slippage>
+where
+    Fee: FeeModel,
+    Slippage: SlippageModel,
+{
+    /// Generates a [`FillEvent`] for the input [`OrderEvent`] filled in full at the fill price,
+    /// at the provided time of the simulated exchange.
+    fn fill(
+        &self,
+        order: &OrderEvent,
+        fill_price: Decimal,
+        market_meta: MarketMeta,
+        time: DateTime<Utc>,
+    ) -> FillEvent {
+        let fill_value_gross = SimulatedExecution::calculate_fill_value_gross(order, fill_price);
+
+        let mut fees = self.calculate_fees(&fill_value_gross);
+        fees.exchange += self.fee_model.commission(order, order.quantity, fill_price);
+
+        FillEvent {
+            time,
+            cid: order.cid,
+            account: order.account.clone(),
+            exchange: order.exchange.clone(),
+            instrument: order.instrument.clone(),
+            market_meta,
+            decision: order.decision,
+            quantity: order.quantity,
+            fill_value_gross,
+            fees,
+            fill_id: None,
+            tags: order.tags.clone(),
+        }
     }
 
     /// Calculates the simulated [`Fees`] a [`FillEvent`] will incur, based on the input [`OrderEvent`].
-    fn calculate_fees(&self, fill_value_gross: &f64) -> Fees {
+    fn calculate_fees(&self, fill_value_gross: &Decimal) -> Fees {
         Fees {
             exchange: self.fees_pct.exchange * fill_value_gross,
             slippage: self.fees_pct.slippage * fill_value_gross,
@@ -61,36 +384,532 @@ impl SimulatedExecution {
     }
 }
 
+/// Rests the input [`OrderEvent`] in the [`SimulatedBook`] until it is crossed by a subsequent
+/// [`MarketEvent`], unless it's [`TimeInForce`] requires immediate execution, in which case it is
+/// cancelled.
+fn rest(book: &mut SimulatedBook, order: OrderEvent) -> Vec<FillEvent> {
+    if !order.time_in_force.is_immediate() {
+        book.resting.push(order);
+    }
+    Vec::new()
+}
+
+/// Determines the quantity of the input marketable [`OrderEvent`] that fills immediately,
+/// according to it's [`TimeInForce`] & the liquidity at the touch. Returns `None` if the
+/// [`OrderEvent`] is cancelled with zero fill.
+fn immediate_quantity(book: &SimulatedBook, order: &OrderEvent) -> Option<Decimal> {
+    let available = match book.latest_liquidity {
+        Some(liquidity) if order.quantity.is_sign_negative() => liquidity.bid,
+        Some(liquidity) => liquidity.ask,
+        None => Decimal::MAX,
+    };
+
+    match order.time_in_force {
+        TimeInForce::GoodUntilCancelled => Some(order.quantity),
+        TimeInForce::ImmediateOrCancel if available > Decimal::ZERO => {
+            let quantity = order.quantity.abs().min(available);
+            Some(if order.quantity.is_sign_negative() {
+                -quantity
+            } else {
+                quantity
+            })
+        }
+        TimeInForce::FillOrKill if available >= order.quantity.abs() => Some(order.quantity),
+        _ => None,
+    }
+}
+
+/// Quantity available at the touch of a [`MarketEvent`], by side of the book.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+struct Liquidity {
+    /// Quantity available to sell (-ve quantity) [`OrderEvent`]s.
+    bid: Decimal,
+    /// Quantity available to buy (+ve quantity) [`OrderEvent`]s.
+    ask: Decimal,
+}
+
+impl Liquidity {
+    /// Determines the [`Liquidity`] at the touch of the [`MarketEvent`], or `None` if the
+    /// [`DataKind`] does not communicate it. Trade & Candle volumes are available to both sides.
+    fn from_market(market: &MarketEvent<DataKind>) -> Option<Self> {
+        match &market.kind {
+            DataKind::Trade(trade) => Some(Self {
+                bid: decimal_from_f64(trade.amount),
+                ask: decimal_from_f64(trade.amount),
+            }),
+            DataKind::OrderBookL1(book_l1) => Some(Self {
+                bid: decimal_from_f64(book_l1.best_bid.amount),
+                ask: decimal_from_f64(book_l1.best_ask.amount),
+            }),
+            DataKind::Candle(candle) => Some(Self {
+                bid: decimal_from_f64(candle.volume),
+                ask: decimal_from_f64(candle.volume),
+            }),
+            DataKind::OrderBook(_) | DataKind::Liquidation(_) => None,
+        }
+    }
+}
+
+/// Determines if the [`OrderEvent`] would cross the latest market price on arrival, and so take
+/// liquidity rather than rest as a maker order.
+fn would_take_liquidity(book: &SimulatedBook, order: &OrderEvent) -> bool {
+    match (order.order_type, book.latest_price) {
+        (OrderType::Market | OrderType::Bracket, _) => true,
+        (OrderType::Limit, Some(price)) => is_limit_crossed(order, price, price),
+        (OrderType::StopLimit, Some(price)) => {
+            is_stop_triggered(order, price, price) && is_limit_crossed(order, price, price)
+        }
+        (_, None) => false,
+    }
+}
+
+/// Determines if a limit [`OrderEvent`] is crossed by a market trading between the high & low
+/// prices provided. Buy (+ve quantity) limits are crossed at or below the limit price, and sell
+/// (-ve quantity) limits at or above it.
+fn is_limit_crossed(order: &OrderEvent, high: Decimal, low: Decimal) -> bool {
+    let limit_price = order.market_meta.close;
+
+    if order.quantity.is_sign_negative() {
+        high >= limit_price
+    } else {
+        low <= limit_price
+    }
+}
+
+/// Determines if a [`OrderType::StopLimit`] [`OrderEvent`] is triggered by a market trading
+/// between the high & low prices provided. Buy (+ve quantity) stops are triggered at or above the
+/// stop price, and sell (-ve quantity) stops at or below it.
+fn is_stop_triggered(order: &OrderEvent, high: Decimal, low: Decimal) -> bool {
+    order.stop_price.is_some_and(|stop_price| {
+        if order.quantity.is_sign_negative() {
+            low <= stop_price
+        } else {
+            high >= stop_price
+        }
+    })
+}
+
+/// Returns the limit [`OrderEvent`] a triggered [`OrderType::StopLimit`] [`OrderEvent`] rests as.
+fn triggered(order: &OrderEvent) -> OrderEvent {
+    OrderEvent {
+        order_type: OrderType::Limit,
+        ..order.clone()
+    }
+}
+
+/// Returns the (open, high, low) prices traded during the [`MarketEvent`], or `None` if the
+/// [`DataKind`] does not communicate a price (eg/ [`DataKind::Liquidation`]).
+fn price_range(market: &MarketEvent<DataKind>) -> Option<(Decimal, Decimal, Decimal)> {
+    match &market.kind {
+        DataKind::Candle(candle) => Some((
+            decimal_from_f64(candle.open),
+            decimal_from_f64(candle.high),
+            decimal_from_f64(candle.low),
+        )),
+        _ => MarketMeta::from_market(market)
+            .map(|market_meta| (market_meta.close, market_meta.close, market_meta.close)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::order_event;
+    use crate::{
+        execution::cost::{FixedSlippage, MakerTakerCommission},
+        portfolio::{
+            position::{Position, PositionEnterer, PositionExiter},
+            Balance,
+        },
+        strategy::Decision,
+        test_util::{market_event_candle, order_event},
+    };
+    use uuid::Uuid;
+
+    /// Calculates the realised P&L of buying 10.0 contracts at 100.0 & selling them at 110.0,
+    /// filled by the provided [`ExecutionClient`].
+    fn round_trip_realised_profit_loss(execution: &mut impl ExecutionClient) -> Decimal {
+        let mut entry = order_event();
+        entry.decision = Decision::Long;
+        entry.quantity = Decimal::TEN;
+        entry.market_meta.close = Decimal::ONE_HUNDRED;
+
+        let mut exit = entry.clone();
+        exit.decision = Decision::CloseLong;
+        exit.quantity = -Decimal::TEN;
+        exit.market_meta.close = Decimal::from(110);
+
+        let mut position = Position::enter(
+            Uuid::new_v4(),
+            &execution.generate_fill(&entry).unwrap().unwrap(),
+        )
+        .unwrap();
+        position
+            .exit(
+                Balance::new(Utc::now(), Decimal::from(10_000), Decimal::from(10_000)),
+                &execution.generate_fill(&exit).unwrap().unwrap(),
+            )
+            .unwrap();
+
+        position.realised_profit_loss
+    }
+
+    #[test]
+    fn realised_profit_loss_should_reflect_commission_and_slippage() {
+        let mut frictionless = SimulatedExecution::new(Config::default());
+        assert_eq!(
+            round_trip_realised_profit_loss(&mut frictionless),
+            Decimal::ONE_HUNDRED
+        );
+
+        // Commission of 0.1% taker: 1.0 on entry (1000.0 value) & 1.1 on exit (1100.0 value)
+        let mut with_commission = SimulatedExecution::with_models(
+            Config::default(),
+            MakerTakerCommission {
+                maker_pct: 0.0,
+                taker_pct: 0.001,
+            },
+            NoSlippage,
+        );
+        assert_eq!(
+            round_trip_realised_profit_loss(&mut with_commission),
+            Decimal::ONE_HUNDRED - Decimal::ONE - Decimal::new(11, 1)
+        );
+
+        // Slippage of 100bps: buys at 101.0 & sells at 108.9
+        let mut with_slippage = SimulatedExecution::with_models(
+            Config::default(),
+            NoCommission,
+            FixedSlippage { bps: 100.0 },
+        );
+        assert_eq!(
+            round_trip_realised_profit_loss(&mut with_slippage),
+            Decimal::from(1089 - 1010)
+        );
+    }
+
+    /// Builds a buy limit [`OrderEvent`] for 1.0 contract at the provided limit price.
+    fn buy_limit(limit_price: i64) -> OrderEvent {
+        let mut order = order_event();
+        order.order_type = OrderType::Limit;
+        order.quantity = Decimal::ONE;
+        order.market_meta.close = Decimal::from(limit_price);
+        order
+    }
+
+    /// Builds a candle [`MarketEvent`] with the provided open, high & low prices.
+    fn candle(open: f64, high: f64, low: f64) -> MarketEvent<DataKind> {
+        let mut market = market_event_candle();
+        if let DataKind::Candle(candle) = &mut market.kind {
+            candle.open = open;
+            candle.high = high;
+            candle.low = low;
+            candle.close = open;
+        }
+        market
+    }
+
+    #[test]
+    fn resting_limit_order_should_fill_at_limit_price_once_touched() {
+        let mut simulated_execution = SimulatedExecution::new(Config::default());
+        simulated_execution.fill_resting_orders(&candle(1000.0, 1010.0, 990.0));
+
+        // Buy limit below the latest market price is not marketable, so rests
+        let order = buy_limit(950);
+        assert_eq!(simulated_execution.generate_fill(&order).unwrap(), None);
+        assert_eq!(simulated_execution.resting_orders().len(), 1);
+
+        // Candle trading above the limit price does not cross the resting order
+        let fills = simulated_execution.fill_resting_orders(&candle(990.0, 1000.0, 960.0));
+        assert!(fills.is_empty());
+
+        // Candle low touching the limit price fills the order at the limit price
+        let fills = simulated_execution.fill_resting_orders(&candle(960.0, 970.0, 950.0));
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].cid, order.cid);
+        assert_eq!(fills[0].market_meta.close, Decimal::from(950));
+        assert_eq!(fills[0].fill_value_gross, Decimal::from(950));
+        assert!(simulated_execution.resting_orders().is_empty());
+    }
+
+    #[test]
+    fn marketable_limit_order_should_fill_immediately_at_market_price() {
+        let mut simulated_execution = SimulatedExecution::new(Config::default());
+        simulated_execution.fill_resting_orders(&candle(1000.0, 1010.0, 990.0));
+
+        let fill = simulated_execution
+            .generate_fill(&buy_limit(1050))
+            .unwrap()
+            .expect("marketable limit OrderEvent should be filled immediately");
+
+        assert_eq!(fill.market_meta.close, Decimal::ONE_THOUSAND);
+        assert!(simulated_execution.resting_orders().is_empty());
+    }
+
+    #[test]
+    fn resting_limit_order_crossed_by_gap_should_fill_at_configured_price() {
+        let gap_down = candle(900.0, 920.0, 880.0);
+
+        // Default: gapped limit orders fill at the limit price
+        let mut at_limit = SimulatedExecution::new(Config::default());
+        at_limit.generate_fill(&buy_limit(950)).unwrap();
+        let fills = at_limit.fill_resting_orders(&gap_down);
+        assert_eq!(fills[0].market_meta.close, Decimal::from(950));
+
+        // Configured: gapped limit orders fill at the gapped open price
+        let mut at_market = SimulatedExecution::new(Config::default()).fill_gaps_at_market(true);
+        at_market.generate_fill(&buy_limit(950)).unwrap();
+        let fills = at_market.fill_resting_orders(&gap_down);
+        assert_eq!(fills[0].market_meta.close, Decimal::from(900));
+    }
+
+    #[test]
+    fn stop_limit_order_should_rest_until_stop_triggered_then_fill_at_limit_price() {
+        let mut simulated_execution = SimulatedExecution::new(Config::default());
+        simulated_execution.fill_resting_orders(&candle(1000.0, 1010.0, 990.0));
+
+        // Buy stop at 1050, limit at 1060: not triggered by the latest market price
+        let order = OrderEvent {
+            order_type: OrderType::StopLimit,
+            stop_price: Some(Decimal::from(1050)),
+            ..buy_limit(1060)
+        };
+        assert_eq!(simulated_execution.generate_fill(&order).unwrap(), None);
+
+        // Candle trading below the stop price does not trigger it, even though the limit is crossed
+        let fills = simulated_execution.fill_resting_orders(&candle(1020.0, 1040.0, 1000.0));
+        assert!(fills.is_empty());
+        assert_eq!(
+            simulated_execution.resting_orders()[0].order_type,
+            OrderType::StopLimit
+        );
+
+        // Candle trading through the stop price triggers it & crosses the limit price
+        let fills = simulated_execution.fill_resting_orders(&candle(1040.0, 1055.0, 1030.0));
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].cid, order.cid);
+        assert_eq!(fills[0].market_meta.close, Decimal::from(1060));
+        assert!(simulated_execution.resting_orders().is_empty());
+    }
+
+    /// Builds a candle [`MarketEvent`] trading at 1000.0, with the provided volume available at
+    /// the touch.
+    fn candle_with_volume(volume: f64) -> MarketEvent<DataKind> {
+        let mut market = candle(1000.0, 1010.0, 990.0);
+        if let DataKind::Candle(candle) = &mut market.kind {
+            candle.volume = volume;
+        }
+        market
+    }
+
+    #[test]
+    fn fill_or_kill_order_should_fill_in_full_if_touch_liquidity_suffices() {
+        let mut simulated_execution = SimulatedExecution::new(Config::default());
+        simulated_execution.fill_resting_orders(&candle_with_volume(1.0));
+
+        let order = OrderEvent {
+            time_in_force: TimeInForce::FillOrKill,
+            ..buy_limit(1050)
+        };
+        let fill = simulated_execution
+            .generate_fill(&order)
+            .unwrap()
+            .expect("FillOrKill OrderEvent should be filled in full");
+
+        assert_eq!(fill.quantity, Decimal::ONE);
+        assert_eq!(fill.market_meta.close, Decimal::ONE_THOUSAND);
+        assert_eq!(fill.fill_value_gross, Decimal::ONE_THOUSAND);
+    }
+
+    #[test]
+    fn fill_or_kill_limit_order_should_cancel_with_zero_fill_if_only_partially_fillable() {
+        let mut simulated_execution = SimulatedExecution::new(Config::default());
+        simulated_execution.fill_resting_orders(&candle_with_volume(0.4));
+
+        // Marketable, but only 0.4 of the 1.0 quantity is available at the touch
+        let order = OrderEvent {
+            time_in_force: TimeInForce::FillOrKill,
+            ..buy_limit(1050)
+        };
+        assert_eq!(simulated_execution.generate_fill(&order).unwrap(), None);
+
+        // Not marketable, so cancelled rather than left resting
+        let order = OrderEvent {
+            time_in_force: TimeInForce::FillOrKill,
+            ..buy_limit(950)
+        };
+        assert_eq!(simulated_execution.generate_fill(&order).unwrap(), None);
+        assert!(simulated_execution.resting_orders().is_empty());
+
+        let fills = simulated_execution.fill_resting_orders(&candle(900.0, 920.0, 880.0));
+        assert!(fills.is_empty());
+    }
+
+    #[test]
+    fn immediate_or_cancel_order_should_fill_available_quantity_and_cancel_remainder() {
+        let mut simulated_execution = SimulatedExecution::new(Config::default());
+        simulated_execution.fill_resting_orders(&candle_with_volume(0.4));
+
+        let mut order = order_event();
+        order.quantity = Decimal::ONE;
+        order.market_meta.close = Decimal::ONE_THOUSAND;
+        order.time_in_force = TimeInForce::ImmediateOrCancel;
+
+        let fill = simulated_execution
+            .generate_fill(&order)
+            .unwrap()
+            .expect("ImmediateOrCancel OrderEvent should be partially filled");
+
+        assert_eq!(fill.quantity, Decimal::new(4, 1));
+        assert_eq!(fill.fill_value_gross, Decimal::from(400));
+        assert!(simulated_execution.resting_orders().is_empty());
+    }
+
+    #[test]
+    fn cancelled_resting_limit_order_should_never_fill() {
+        let mut simulated_execution = SimulatedExecution::new(Config::default());
+
+        let mut order = buy_limit(950);
+        order.cid.sequence = 7;
+        assert_eq!(simulated_execution.generate_fill(&order).unwrap(), None);
+
+        assert_eq!(
+            simulated_execution.cancel_order(&order.cid),
+            Some(order.clone())
+        );
+        assert_eq!(simulated_execution.cancel_order(&order.cid), None);
+
+        let fills = simulated_execution.fill_resting_orders(&candle(900.0, 920.0, 880.0));
+        assert!(fills.is_empty());
+    }
+
+    /// [`FillSimulator`] that rejects every [`OrderEvent`], recording the market price & number of
+    /// resting [`OrderEvent`]s it observes in the [`SimulatedBook`].
+    #[derive(Debug, Default)]
+    struct RejectAllSimulator {
+        observed: Vec<(Option<Decimal>, usize)>,
+    }
+
+    impl FillSimulator for RejectAllSimulator {
+        fn on_order(&mut self, book: &mut SimulatedBook, _: &OrderEvent) -> Vec<FillEvent> {
+            self.observed.push((book.latest_price, book.resting.len()));
+            Vec::new()
+        }
+
+        fn on_market(
+            &mut self,
+            book: &mut SimulatedBook,
+            _: &MarketEvent<DataKind>,
+        ) -> Vec<FillEvent> {
+            self.observed.push((book.latest_price, book.resting.len()));
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn custom_fill_simulator_rejecting_every_order_should_generate_no_fills() {
+        let mut simulated_execution =
+            SimulatedExecution::with_simulator(RejectAllSimulator::default());
+        simulated_execution.fill_resting_orders(&candle(1000.0, 1010.0, 990.0));
+
+        // Marketable market & limit orders are both rejected
+        assert_eq!(
+            simulated_execution.generate_fill(&order_event()).unwrap(),
+            None
+        );
+        assert_eq!(
+            simulated_execution.generate_fill(&buy_limit(1100)).unwrap(),
+            None
+        );
+
+        // No fills flow back with subsequent MarketEvents, since nothing was rested
+        let fills = simulated_execution.fill_resting_orders(&candle(900.0, 1200.0, 800.0));
+        assert!(fills.is_empty());
+        assert!(simulated_execution.resting_orders().is_empty());
+
+        // The simulator observed the latest market price of the SimulatedBook
+        assert_eq!(
+            simulated_execution.simulator.observed,
+            vec![
+                (Some(Decimal::ONE_THOUSAND), 0),
+                (Some(Decimal::ONE_THOUSAND), 0),
+                (Some(Decimal::ONE_THOUSAND), 0),
+                (Some(Decimal::from(900)), 0),
+            ]
+        );
+    }
+
+    /// [`FillSimulator`] that fills every [`OrderEvent`] in two equal halves.
+    #[derive(Debug)]
+    struct SplitFillSimulator;
+
+    impl FillSimulator for SplitFillSimulator {
+        fn on_order(&mut self, book: &mut SimulatedBook, order: &OrderEvent) -> Vec<FillEvent> {
+            let half = OrderEvent {
+                quantity: order.quantity / Decimal::TWO,
+                ..order.clone()
+            };
+            let fill = DefaultFillSimulator::<NoCommission, NoSlippage>::default().fill(
+                &half,
+                half.market_meta.close,
+                half.market_meta,
+                book.time(&half),
+            );
+            vec![fill.clone(), fill]
+        }
+
+        fn on_market(
+            &mut self,
+            _: &mut SimulatedBook,
+            _: &MarketEvent<DataKind>,
+        ) -> Vec<FillEvent> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn subsequent_fills_generated_by_an_order_should_be_returned_with_the_next_market_event() {
+        let mut simulated_execution = SimulatedExecution::with_simulator(SplitFillSimulator);
+
+        let mut order = order_event();
+        order.quantity = Decimal::TWO;
+        let first = simulated_execution.generate_fill(&order).unwrap().unwrap();
+        assert_eq!(first.quantity, Decimal::ONE);
+
+        let fills = simulated_execution.fill_resting_orders(&candle(100.0, 100.0, 100.0));
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].quantity, Decimal::ONE);
+        assert!(simulated_execution
+            .fill_resting_orders(&candle(100.0, 100.0, 100.0))
+            .is_empty());
+    }
 
     #[test]
     fn should_generate_ok_fill_event_with_valid_order_event_provided() {
-        let simulated_execution = SimulatedExecution::new(Config {
+        let mut simulated_execution = SimulatedExecution::new(Config {
             simulated_fees_pct: Fees {
-                exchange: 0.1,
-                slippage: 0.05,
-                network: 0.0,
+                exchange: Decimal::new(1, 1),
+                slippage: Decimal::new(5, 2),
+                network: Decimal::ZERO,
             },
         });
 
         let mut input_order = order_event();
-        input_order.quantity = 10.0;
-        input_order.market_meta.close = 10.0;
+        input_order.quantity = Decimal::TEN;
+        input_order.market_meta.close = Decimal::TEN;
 
         let actual_result = simulated_execution.generate_fill(&input_order);
 
-        let expected_fill_value_gross = 100.0;
+        let expected_fill_value_gross = Decimal::ONE_HUNDRED;
         let expected_fees = Fees {
-            exchange: 10.0,
-            slippage: 5.0,
-            network: 0.0,
+            exchange: Decimal::TEN,
+            slippage: Decimal::from(5),
+            network: Decimal::ZERO,
         };
 
         assert!(actual_result.is_ok());
-        let actual_result = actual_result.unwrap();
+        let actual_result = actual_result
+            .unwrap()
+            .expect("market OrderEvent should be filled immediately");
         assert_eq!(actual_result.fill_value_gross, expected_fill_value_gross);
         assert_eq!(actual_result.fees, expected_fees);
     }
@@ -98,12 +917,15 @@ mod tests {
     #[test]
     fn should_calculate_fill_value_gross_correctly() {
         let mut input_order = order_event();
-        input_order.quantity = 100.0;
-        input_order.market_meta.close = 10.0;
+        input_order.quantity = Decimal::ONE_HUNDRED;
+        input_order.market_meta.close = Decimal::TEN;
 
-        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
+        let actual = SimulatedExecution::calculate_fill_value_gross(
+            &input_order,
+            input_order.market_meta.close,
+        );
 
-        let expected = 100.0 * 10.0;
+        let expected = Decimal::ONE_THOUSAND;
 
         assert_eq!(actual, expected)
     }
@@ -111,12 +933,15 @@ mod tests {
     #[test]
     fn should_calculate_fill_value_gross_correctly_with_negative_order_quantity_provided() {
         let mut input_order = order_event();
-        input_order.quantity = -(100.0);
-        input_order.market_meta.close = 10.0;
+        input_order.quantity = -Decimal::ONE_HUNDRED;
+        input_order.market_meta.close = Decimal::TEN;
 
-        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
+        let actual = SimulatedExecution::calculate_fill_value_gross(
+            &input_order,
+            input_order.market_meta.close,
+        );
 
-        let expected = (100.0 * 10.0) as f64;
+        let expected = Decimal::ONE_THOUSAND;
 
         assert_eq!(actual, expected)
     }
@@ -125,20 +950,22 @@ mod tests {
     fn should_calculate_simulated_fees_correctly() {
         let simulated_execution = SimulatedExecution::new(Config {
             simulated_fees_pct: Fees {
-                exchange: 0.5,
-                slippage: 0.1,
-                network: 0.001,
+                exchange: Decimal::new(5, 1),
+                slippage: Decimal::new(1, 1),
+                network: Decimal::new(1, 3),
             },
         });
 
-        let input_fill_value_gross = 100.0;
+        let input_fill_value_gross = Decimal::ONE_HUNDRED;
 
-        let actual_result = simulated_execution.calculate_fees(&input_fill_value_gross);
+        let actual_result = simulated_execution
+            .simulator
+            .calculate_fees(&input_fill_value_gross);
 
         let expected = Fees {
-            exchange: 50.0,
-            slippage: 10.0,
-            network: 0.1,
+            exchange: Decimal::from(50),
+            slippage: Decimal::TEN,
+            network: Decimal::new(1, 1),
         };
 
         assert_eq!(actual_result, expected)
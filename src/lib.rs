@@ -6,7 +6,7 @@
 //! * **Fast**: Barter provides a multi-threaded trading Engine framework built in high-performance Rust (in-rust-we-trust).
 //! * **Easy**: Barter provides a modularised data architecture that focuses on simplicity.
 //! * **Customisable**: A set of traits define how every Barter component communicates, providing a highly extensible
-//! framework for trading.
+//!   framework for trading.
 //!
 //! See [`Readme`].
 //!
@@ -18,22 +18,22 @@
 //! it provides several de-coupled components that interact via a set of traits:
 
 //! * **Data**: Continuer & MarketGenerator traits govern the generation of a MarketEvents data feed that acts as the system
-//! heartbeat. For example, a LiveCandleHandler implementation is provided utilising [`Barter-Data`]'s WebSocket functionality to
-//! provide a live market Candle data feed to the system.
+//!   heartbeat. For example, a LiveCandleHandler implementation is provided utilising [`Barter-Data`]'s WebSocket functionality to
+//!   provide a live market Candle data feed to the system.
 //! * **Strategy**: The SignalGenerator trait governs potential generation of SignalEvents after analysing incoming
-//! MarketEvents. SignalEvents are advisory signals sent to the Portfolio for analysis.
+//!   MarketEvents. SignalEvents are advisory signals sent to the Portfolio for analysis.
 //! * **Portfolio**: MarketUpdater, OrderGenerator, and FillUpdater govern global state Portfolio implementations. A
-//! Portfolio may generate OrderEvents after receiving advisory SignalEvents from a Strategy. The Portfolio's state
-//! updates after receiving MarketEvents and FillEvents.
+//!   Portfolio may generate OrderEvents after receiving advisory SignalEvents from a Strategy. The Portfolio's state
+//!   updates after receiving MarketEvents and FillEvents.
 //! * **Execution**: The FillGenerator trait governs the generation of FillEvents after receiving OrderEvents from the
-//! Portfolio. For example, a SimulatedExecution handler implementation is provided for simulating any exchange execution
-//! behaviour required in dry-trading or backtesting runs.
+//!   Portfolio. For example, a SimulatedExecution handler implementation is provided for simulating any exchange execution
+//!   behaviour required in dry-trading or backtesting runs.
 //! * **Statistic**: Provides metrics such as Sharpe Ratio, Calmar Ratio, and Max Drawdown to analyse trading session
-//! performance. One-pass dispersion algorithms analyse each closed Position and efficiently calculates a trading summary.
+//!   performance. One-pass dispersion algorithms analyse each closed Position and efficiently calculates a trading summary.
 //! * **Trader**: Capable of trading a single market pair using a customisable selection of it's own Data, Strategy &
-//! Execution instances, as well as shared access to a global Portfolio.
+//!   Execution instances, as well as shared access to a global Portfolio.
 //! * **Engine**: Multi-threaded trading Engine capable of trading with an arbitrary number of Trader market pairs. Each
-//! contained Trader instance operates on its own thread.
+//!   contained Trader instance operates on its own thread.
 //!
 //! [`Barter`]: https://github.com/barter-rs/barter-rs
 //! [`Barter-Data`]: https://crates.io/crates/barter-data
@@ -53,7 +53,7 @@
 //!     let market_event = match data.next() {
 //!         Feed::Next(market_event) => market_event,
 //!         Feed::Finished => break,
-//!         Feed::Unhealthy => continue,
+//!         Feed::Idle | Feed::Unhealthy => continue,
 //!     };
 //! }
 //! ```
@@ -95,6 +95,7 @@
 //!     test_util,
 //! };
 //! use barter_integration::model::{Market, instrument::kind::InstrumentKind};
+//! use rust_decimal::Decimal;
 //! use std::marker::PhantomData;
 //! use uuid::Uuid;
 //!
@@ -102,9 +103,9 @@
 //!     engine_id: Uuid::new_v4(),
 //!     markets: vec![Market::new("binance", ("btc", "usdt", InstrumentKind::Spot))],
 //!     repository: InMemoryRepository::new(),
-//!     allocator: DefaultAllocator{ default_order_value: 100.0 },
+//!     allocator: DefaultAllocator{ default_order_value: Decimal::ONE_HUNDRED },
 //!     risk: DefaultRisk{},
-//!     starting_cash: 10000.0,
+//!     starting_cash: Decimal::from(10000),
 //!     statistic_config: StatisticConfig {
 //!         starting_equity: 10000.0 ,
 //!         trading_days_per_year: 365,
@@ -144,12 +145,13 @@
 //!         Fees, ExecutionClient,
 //!     }
 //! };
+//! use rust_decimal::Decimal;
 //!
 //! let config = ExecutionConfig {
 //!     simulated_fees_pct: Fees {
-//!         exchange: 0.1,
-//!         slippage: 0.05, // Simulated slippage modelled as a Fee
-//!         network: 0.0,
+//!         exchange: Decimal::new(1, 1),
+//!         slippage: Decimal::new(5, 2), // Simulated slippage modelled as a Fee
+//!         network: Decimal::ZERO,
 //!     }
 //! };
 //!
@@ -237,19 +239,37 @@ pub mod event;
 /// several key metrics such as Sharpe Ratio, Calmar Ratio, and Max Drawdown.
 pub mod statistic;
 
+/// Defines the Clock trait used by the trading event loop to tell the time, with a LiveClock
+/// implementation for live-trading, and a SimulatedClock for deterministic backtests.
+pub mod clock;
+
 /// Multi-threaded trading Engine capable of trading with an arbitrary number market pairs. Contains
 /// a Trader for each Market pair that consists of it's own Data, Strategy &
 /// Execution components, as well as shared access to a global Portfolio.
 pub mod engine;
 
+/// One-call backtest harness that runs an Engine over a historical data file (eg/ OHLCV candle
+/// CSV) for a single Market pair, returning a summary of the trading session.
+pub mod backtest;
+
 #[macro_use]
 extern crate prettytable;
 
 pub mod test_util {
+    /// [`MockFeed`](mock::MockFeed) & [`MockExecution`](mock::MockExecution) harness for driving
+    /// a [`Trader`](crate::engine::trader::Trader) from a test. Requires the `test-util` feature.
+    #[cfg(any(test, feature = "test-util"))]
+    pub mod mock;
+
+    /// [`StrategyTestRig`](rig::StrategyTestRig) for driving a strategy through a scripted price
+    /// path in isolation & asserting the [`Signal`]s it emits. Requires the `test-util` feature.
+    #[cfg(any(test, feature = "test-util"))]
+    pub mod rig;
+
     use crate::{
         data::MarketMeta,
-        execution::{Fees, FillEvent},
-        portfolio::{position::Position, OrderEvent, OrderType},
+        execution::{order_id::ClientOrderId, AccountId, Fees, FillEvent},
+        portfolio::{position::Position, OrderEvent, OrderType, TimeInForce},
         strategy::{Decision, Signal},
     };
     use barter_data::{
@@ -262,6 +282,7 @@ pub mod test_util {
         Exchange, Side,
     };
     use chrono::Utc;
+    use rust_decimal::Decimal;
     use std::ops::Add;
 
     /// Build a [`MarketEvent`] of [`DataKind::PublicTrade`](DataKind) with the provided [`Side`].
@@ -308,6 +329,7 @@ pub mod test_util {
             instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
             signals: Default::default(),
             market_meta: Default::default(),
+            tags: Default::default(),
         }
     }
 
@@ -315,12 +337,18 @@ pub mod test_util {
     pub fn order_event() -> OrderEvent {
         OrderEvent {
             time: Utc::now(),
+            cid: ClientOrderId::default(),
+            account: AccountId::default(),
             exchange: Exchange::from("binance"),
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             market_meta: MarketMeta::default(),
             decision: Decision::default(),
-            quantity: 1.0,
+            quantity: Decimal::ONE,
             order_type: OrderType::default(),
+            stop_price: None,
+            time_in_force: TimeInForce::default(),
+            trigger: None,
+            tags: Default::default(),
         }
     }
 
@@ -328,13 +356,17 @@ pub mod test_util {
     pub fn fill_event() -> FillEvent {
         FillEvent {
             time: Utc::now(),
+            cid: ClientOrderId::default(),
+            account: AccountId::default(),
             exchange: Exchange::from("binance"),
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             market_meta: Default::default(),
             decision: Decision::default(),
-            quantity: 1.0,
-            fill_value_gross: 100.0,
+            quantity: Decimal::ONE,
+            fill_value_gross: Decimal::ONE_HUNDRED,
             fees: Fees::default(),
+            fill_id: None,
+            tags: Default::default(),
         }
     }
 
@@ -346,19 +378,20 @@ pub mod test_util {
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             meta: Default::default(),
             side: Side::Buy,
-            quantity: 1.0,
+            quantity: Decimal::ONE,
             enter_fees: Default::default(),
-            enter_fees_total: 0.0,
-            enter_avg_price_gross: 100.0,
-            enter_value_gross: 100.0,
+            enter_fees_total: Decimal::ZERO,
+            enter_avg_price_gross: Decimal::ONE_HUNDRED,
+            enter_value_gross: Decimal::ONE_HUNDRED,
             exit_fees: Default::default(),
-            exit_fees_total: 0.0,
-            exit_avg_price_gross: 0.0,
-            exit_value_gross: 0.0,
-            current_symbol_price: 100.0,
-            current_value_gross: 100.0,
-            unrealised_profit_loss: 0.0,
-            realised_profit_loss: 0.0,
+            exit_fees_total: Decimal::ZERO,
+            exit_avg_price_gross: Decimal::ZERO,
+            exit_value_gross: Decimal::ZERO,
+            current_symbol_price: Decimal::ONE_HUNDRED,
+            current_value_gross: Decimal::ONE_HUNDRED,
+            unrealised_profit_loss: Decimal::ZERO,
+            realised_profit_loss: Decimal::ZERO,
+            enter_lots: Vec::new(),
         }
     }
 }
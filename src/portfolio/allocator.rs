@@ -1,7 +1,8 @@
 use crate::{
-    portfolio::{position::Position, OrderEvent},
+    portfolio::{decimal_from_f64, position::Position, OrderEvent},
     strategy::{Decision, SignalStrength},
 };
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 
 /// Allocates an appropriate [`OrderEvent`] quantity.
@@ -20,7 +21,7 @@ pub trait OrderAllocator {
 /// using the default_order_value, symbol close value, and [`SignalStrength`].
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct DefaultAllocator {
-    pub default_order_value: f64,
+    pub default_order_value: Decimal,
 }
 
 impl OrderAllocator for DefaultAllocator {
@@ -30,19 +31,23 @@ impl OrderAllocator for DefaultAllocator {
         position: Option<&Position>,
         signal_strength: SignalStrength,
     ) {
-        // Calculate exact order_size, then round it to a more appropriate decimal place
-        let default_order_size = self.default_order_value / order.market_meta.close;
-        let default_order_size = (default_order_size * 10000.0).floor() / 10000.0;
+        // Calculate exact order_size, then round it down to a more appropriate decimal place
+        let default_order_size = self
+            .default_order_value
+            .checked_div(order.market_meta.close)
+            .unwrap_or_default()
+            .round_dp_with_strategy(4, RoundingStrategy::ToNegativeInfinity);
+        let signal_strength = decimal_from_f64(signal_strength.0);
 
         match order.decision {
             // Entry
-            Decision::Long => order.quantity = default_order_size * signal_strength.0,
+            Decision::Long => order.quantity = default_order_size * signal_strength,
 
             // Entry
-            Decision::Short => order.quantity = -default_order_size * signal_strength.0,
+            Decision::Short => order.quantity = -default_order_size * signal_strength,
 
             // Exit
-            _ => order.quantity = 0.0 - position.as_ref().unwrap().quantity,
+            _ => order.quantity = -position.as_ref().unwrap().quantity,
         }
     }
 }
@@ -51,18 +56,19 @@ impl OrderAllocator for DefaultAllocator {
 mod tests {
     use super::*;
     use crate::test_util::{order_event, position};
+    use rust_decimal::Decimal;
 
     #[test]
     fn should_allocate_order_to_exit_open_long_position() {
         let allocator = DefaultAllocator {
-            default_order_value: 1000.0,
+            default_order_value: Decimal::ONE_THOUSAND,
         };
 
         let mut input_order = order_event();
         input_order.decision = Decision::CloseLong;
 
         let mut input_position = position();
-        input_position.quantity = 100.0;
+        input_position.quantity = Decimal::ONE_HUNDRED;
 
         let input_signal_strength = SignalStrength(0.0);
 
@@ -73,7 +79,7 @@ mod tests {
         );
 
         let actual_result = input_order.quantity;
-        let expected_result = 0.0 - input_position.quantity;
+        let expected_result = -input_position.quantity;
 
         assert_eq!(actual_result, expected_result)
     }
@@ -81,14 +87,14 @@ mod tests {
     #[test]
     fn should_allocate_order_to_exit_open_short_position() {
         let allocator = DefaultAllocator {
-            default_order_value: 1000.0,
+            default_order_value: Decimal::ONE_THOUSAND,
         };
 
         let mut input_order = order_event();
         input_order.decision = Decision::CloseShort;
 
         let mut input_position = position();
-        input_position.quantity = -100.0;
+        input_position.quantity = -Decimal::ONE_HUNDRED;
 
         let input_signal_strength = SignalStrength(0.0);
 
@@ -99,19 +105,19 @@ mod tests {
         );
 
         let actual_result = input_order.quantity;
-        let expected_result = 0.0 - input_position.quantity;
+        let expected_result = -input_position.quantity;
 
         assert_eq!(actual_result, expected_result)
     }
 
     #[test]
     fn should_allocate_order_to_enter_long_position_with_correct_quantity() {
-        let default_order_value = 1000.0;
+        let default_order_value = Decimal::ONE_THOUSAND;
         let allocator = DefaultAllocator {
             default_order_value,
         };
 
-        let order_close = 10.0;
+        let order_close = Decimal::TEN;
         let mut input_order = order_event();
         input_order.market_meta.close = order_close;
         input_order.decision = Decision::Long;
@@ -121,19 +127,19 @@ mod tests {
         allocator.allocate_order(&mut input_order, None, input_signal_strength);
 
         let actual_result = input_order.quantity;
-        let expected_result = (default_order_value / order_close) * input_signal_strength.0 as f64;
+        let expected_result = Decimal::ONE_HUNDRED;
 
         assert_eq!(actual_result, expected_result)
     }
 
     #[test]
     fn should_allocate_order_to_enter_long_position_with_non_zero_quantity() {
-        let default_order_value = 200.0;
+        let default_order_value = Decimal::from(200);
         let allocator = DefaultAllocator {
             default_order_value,
         };
 
-        let order_close = 226.753403;
+        let order_close = Decimal::new(226753403, 6);
         let mut input_order = order_event();
         input_order.market_meta.close = order_close;
         input_order.decision = Decision::Long;
@@ -143,21 +149,21 @@ mod tests {
         allocator.allocate_order(&mut input_order, None, input_signal_strength);
 
         let actual_result = input_order.quantity;
-        let expected_order_size = ((default_order_value / order_close) * 10000.0).floor() / 10000.0;
-        let expected_result = expected_order_size * input_signal_strength.0 as f64;
+        // Order size of 0.882017... is rounded down to 4 decimal places
+        let expected_result = Decimal::new(8820, 4);
 
-        assert_ne!(actual_result, 0.0);
+        assert_ne!(actual_result, Decimal::ZERO);
         assert_eq!(actual_result, expected_result)
     }
 
     #[test]
     fn should_allocate_order_to_enter_short_position_with_correct_quantity() {
-        let default_order_value = 1000.0;
+        let default_order_value = Decimal::ONE_THOUSAND;
         let allocator = DefaultAllocator {
             default_order_value,
         };
 
-        let order_close = 10.0;
+        let order_close = Decimal::TEN;
         let mut input_order = order_event();
         input_order.market_meta.close = order_close;
         input_order.decision = Decision::Short;
@@ -167,19 +173,19 @@ mod tests {
         allocator.allocate_order(&mut input_order, None, input_signal_strength);
 
         let actual_result = input_order.quantity;
-        let expected_result = -(default_order_value / order_close) * input_signal_strength.0 as f64;
+        let expected_result = -Decimal::ONE_HUNDRED;
 
         assert_eq!(actual_result, expected_result)
     }
 
     #[test]
     fn should_allocate_order_to_enter_short_position_with_with_non_zero_quantity() {
-        let default_order_value = 200.0;
+        let default_order_value = Decimal::from(200);
         let allocator = DefaultAllocator {
             default_order_value,
         };
 
-        let order_close = 226.753403;
+        let order_close = Decimal::new(226753403, 6);
         let mut input_order = order_event();
         input_order.market_meta.close = order_close;
         input_order.decision = Decision::Short;
@@ -189,10 +195,10 @@ mod tests {
         allocator.allocate_order(&mut input_order, None, input_signal_strength);
 
         let actual_result = input_order.quantity;
-        let expected_order_size = ((default_order_value / order_close) * 10000.0).floor() / 10000.0;
-        let expected_result = -expected_order_size * input_signal_strength.0;
+        // Order size of 0.882017... is rounded down to 4 decimal places
+        let expected_result = -Decimal::new(8820, 4);
 
-        assert_ne!(actual_result, 0.0);
+        assert_ne!(actual_result, Decimal::ZERO);
         assert_eq!(actual_result, expected_result)
     }
 }
@@ -0,0 +1,186 @@
+use crate::data::MarketMeta;
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::{kind::InstrumentKind, symbol::Symbol};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+/// Rates used to convert values denominated in the quote currencies of a Portfolio's
+/// instruments (eg/ usdt, usdc, eur) into a single base currency, so they can be summed.
+///
+/// Rates can be injected via [`ConversionRates::set_rate`], or updated from the
+/// [`MarketEvent`]s of spot instruments pairing a currency with the base currency (eg/
+/// eur_usdt or usdt_usdc for base currency usdt).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ConversionRates {
+    base: Symbol,
+    /// Value of one unit of each currency, denominated in the base currency.
+    rates: HashMap<Symbol, Decimal>,
+}
+
+impl ConversionRates {
+    /// Constructs a new [`ConversionRates`] converting into the provided base currency, with
+    /// no rates for any other currency.
+    pub fn new<S>(base: S) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        Self {
+            base: base.into(),
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Base currency every value is converted into.
+    pub fn base(&self) -> &Symbol {
+        &self.base
+    }
+
+    /// Sets the value of one unit of the currency, denominated in the base currency.
+    pub fn set_rate<S>(&mut self, currency: S, rate: Decimal)
+    where
+        S: Into<Symbol>,
+    {
+        self.rates.insert(currency.into(), rate);
+    }
+
+    /// Sets the value of one unit of the currency, denominated in the base currency.
+    pub fn with_rate<S>(mut self, currency: S, rate: Decimal) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        self.set_rate(currency, rate);
+        self
+    }
+
+    /// Returns the value of one unit of the currency, denominated in the base currency, or
+    /// `None` if no rate is known.
+    pub fn rate(&self, currency: &Symbol) -> Option<Decimal> {
+        if currency == &self.base {
+            Some(Decimal::ONE)
+        } else {
+            self.rates.get(currency).copied()
+        }
+    }
+
+    /// Converts the value denominated in the currency into the base currency, or `None` if no
+    /// rate is known.
+    pub fn convert(&self, value: Decimal, currency: &Symbol) -> Option<Decimal> {
+        self.rate(currency).map(|rate| value * rate)
+    }
+
+    /// Updates the rate of a currency from the latest price of a [`MarketEvent`] of a spot
+    /// instrument pairing it with the base currency. Other [`MarketEvent`]s are ignored.
+    pub fn update_from_market(&mut self, market: &MarketEvent<DataKind>) {
+        if market.instrument.kind != InstrumentKind::Spot {
+            return;
+        }
+        let Some(MarketMeta { close, .. }) = MarketMeta::from_market(market) else {
+            return;
+        };
+        if close <= Decimal::ZERO {
+            return;
+        }
+
+        let instrument = &market.instrument;
+        if instrument.quote == self.base {
+            self.rates.insert(instrument.base.clone(), close);
+        } else if instrument.base == self.base {
+            self.rates
+                .insert(instrument.quote.clone(), Decimal::ONE / close);
+        }
+    }
+}
+
+/// Sum of values converted into a base currency via [`ConversionRates`]. Values denominated in
+/// a currency without a known rate are excluded from the sum & flagged, so a partial sum is
+/// never mistaken for a complete one.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Converted {
+    /// Base currency the `value` is denominated in.
+    pub base: Symbol,
+    /// Sum of every value that could be converted into the base currency.
+    pub value: Decimal,
+    /// Currencies without a known rate, whose values are excluded from the `value`.
+    pub missing_rates: BTreeSet<Symbol>,
+}
+
+impl Converted {
+    /// Constructs a new zero [`Converted`] sum denominated in the base currency of the
+    /// provided [`ConversionRates`].
+    pub fn new(rates: &ConversionRates) -> Self {
+        Self {
+            base: rates.base.clone(),
+            value: Decimal::ZERO,
+            missing_rates: BTreeSet::new(),
+        }
+    }
+
+    /// Converts the value denominated in the currency into the base currency & adds it to the
+    /// sum, or flags the currency if no rate is known. Returns the converted value, if any.
+    pub fn add(
+        &mut self,
+        rates: &ConversionRates,
+        value: Decimal,
+        currency: &Symbol,
+    ) -> Option<Decimal> {
+        let converted = rates.convert(value, currency);
+        match converted {
+            Some(converted) => self.value += converted,
+            None => {
+                self.missing_rates.insert(currency.clone());
+            }
+        }
+        converted
+    }
+
+    /// Returns `true` if the values of some currency were excluded for lack of a rate.
+    pub fn is_partial(&self) -> bool {
+        !self.missing_rates.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::model::{instrument::Instrument, Side};
+
+    #[test]
+    fn conversion_rates_should_update_from_market_events_pairing_the_base_currency() {
+        let mut rates = ConversionRates::new("usdt");
+
+        // eur_usdt @ 1.25 values one eur at 1.25 usdt
+        let mut eur_usdt = market_event_trade(Side::Buy);
+        eur_usdt.instrument = Instrument::from(("eur", "usdt", InstrumentKind::Spot));
+        if let DataKind::Trade(trade) = &mut eur_usdt.kind {
+            trade.price = 1.25;
+        }
+        rates.update_from_market(&eur_usdt);
+
+        // usdt_usdc @ 0.5 values one usdc at 2.0 usdt
+        let mut usdt_usdc = eur_usdt.clone();
+        usdt_usdc.instrument = Instrument::from(("usdt", "usdc", InstrumentKind::Spot));
+        if let DataKind::Trade(trade) = &mut usdt_usdc.kind {
+            trade.price = 0.5;
+        }
+        rates.update_from_market(&usdt_usdc);
+
+        assert_eq!(rates.rate(&Symbol::from("usdt")), Some(Decimal::ONE));
+        assert_eq!(rates.rate(&Symbol::from("eur")), Some(Decimal::new(125, 2)));
+        assert_eq!(rates.rate(&Symbol::from("usdc")), Some(Decimal::TWO));
+
+        // Currencies never priced against the base currency have no rate
+        assert_eq!(rates.rate(&Symbol::from("gbp")), None);
+
+        let mut converted = Converted::new(&rates);
+        converted.add(&rates, Decimal::TEN, &Symbol::from("eur"));
+        converted.add(&rates, Decimal::TEN, &Symbol::from("gbp"));
+        assert_eq!(converted.value, Decimal::new(125, 1));
+        assert!(converted.is_partial());
+        assert_eq!(
+            converted.missing_rates,
+            BTreeSet::from([Symbol::from("gbp")])
+        );
+    }
+}
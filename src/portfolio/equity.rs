@@ -0,0 +1,203 @@
+use crate::portfolio::PortfolioSnapshot;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, fmt, io::Write, sync::Arc};
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Point on the equity curve of a Portfolio, recorded by an [`EquityRecorder`] every time the
+/// Portfolio is revalued.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct EquitySample {
+    /// Time the Portfolio was revalued.
+    pub time: DateTime<Utc>,
+    /// Total [`Balance`](super::Balance) plus the unrealised profit and loss of every open
+    /// [`Position`](super::position::Position).
+    pub equity: Decimal,
+    /// Available [`Balance`](super::Balance) not allocated to open
+    /// [`Position`](super::position::Position)s.
+    pub cash: Decimal,
+    /// Sum of the unrealised profit and loss of every open
+    /// [`Position`](super::position::Position).
+    pub unrealised_profit_loss: Decimal,
+}
+
+impl From<&PortfolioSnapshot> for EquitySample {
+    fn from(snapshot: &PortfolioSnapshot) -> Self {
+        Self {
+            time: snapshot.time,
+            equity: snapshot.equity,
+            cash: snapshot.balance.available,
+            unrealised_profit_loss: snapshot.unrealised_profit_loss,
+        }
+    }
+}
+
+/// Records the full equity curve of a Portfolio to a configurable sink, appending an
+/// [`EquitySample`] every time a [`Trader`](crate::engine::trader::Trader) revalues the
+/// Portfolio from a market or fill event.
+///
+/// Cloning an [`EquityRecorder`] returns a handle to the same shared sink, so one equity curve
+/// can be recorded across every [`Trader`](crate::engine::trader::Trader) of an
+/// [`Engine`](crate::engine::Engine).
+#[derive(Clone, Debug)]
+pub struct EquityRecorder {
+    sink: Arc<Mutex<EquitySink>>,
+}
+
+/// Sink an [`EquityRecorder`] appends every [`EquitySample`] to.
+enum EquitySink {
+    /// In-memory buffer of [`EquitySample`]s, which drops the oldest [`EquitySample`] once the
+    /// optional capacity is reached.
+    Memory {
+        samples: VecDeque<EquitySample>,
+        capacity: Option<usize>,
+    },
+    /// CSV writer, flushed after every [`EquitySample`] so the equity curve can be tailed.
+    Csv(Box<csv::Writer<Box<dyn Write + Send>>>),
+    /// Channel receiving every [`EquitySample`].
+    Channel(mpsc::UnboundedSender<EquitySample>),
+}
+
+impl fmt::Debug for EquitySink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Memory { samples, capacity } => f
+                .debug_struct("Memory")
+                .field("samples", &samples.len())
+                .field("capacity", capacity)
+                .finish(),
+            Self::Csv(_) => f.write_str("Csv"),
+            Self::Channel(tx) => f.debug_tuple("Channel").field(tx).finish(),
+        }
+    }
+}
+
+impl EquityRecorder {
+    /// Constructs a new [`EquityRecorder`] that records every [`EquitySample`] in memory.
+    pub fn in_memory() -> Self {
+        Self::new(EquitySink::Memory {
+            samples: VecDeque::new(),
+            capacity: None,
+        })
+    }
+
+    /// Constructs a new [`EquityRecorder`] that records the latest `capacity` [`EquitySample`]s
+    /// (at least one) in memory, bounding the memory used by long live sessions.
+    pub fn ring_buffer(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self::new(EquitySink::Memory {
+            samples: VecDeque::with_capacity(capacity),
+            capacity: Some(capacity),
+        })
+    }
+
+    /// Constructs a new [`EquityRecorder`] that writes every [`EquitySample`] as a CSV row (with
+    /// the header `time,equity,cash,unrealised_profit_loss`) to the provided writer.
+    pub fn csv<W>(writer: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        Self::new(EquitySink::Csv(Box::new(csv::Writer::from_writer(
+            Box::new(writer),
+        ))))
+    }
+
+    /// Constructs a new [`EquityRecorder`] that sends every [`EquitySample`] on the provided
+    /// channel.
+    pub fn channel(sample_tx: mpsc::UnboundedSender<EquitySample>) -> Self {
+        Self::new(EquitySink::Channel(sample_tx))
+    }
+
+    fn new(sink: EquitySink) -> Self {
+        Self {
+            sink: Arc::new(Mutex::new(sink)),
+        }
+    }
+
+    /// Appends the provided [`EquitySample`] to the sink. Failures to write to the sink are
+    /// logged, rather than interrupting trading.
+    pub fn record(&self, sample: EquitySample) {
+        match &mut *self.sink.lock() {
+            EquitySink::Memory { samples, capacity } => {
+                if capacity.is_some_and(|capacity| samples.len() >= capacity) {
+                    samples.pop_front();
+                }
+                samples.push_back(sample);
+            }
+            EquitySink::Csv(writer) => {
+                if let Err(error) = writer
+                    .serialize(sample)
+                    .and_then(|_| writer.flush().map_err(csv::Error::from))
+                {
+                    warn!(?error, "failed to write EquitySample to CSV");
+                }
+            }
+            EquitySink::Channel(sample_tx) => {
+                if sample_tx.send(sample).is_err() {
+                    warn!(
+                        why = "receiver dropped",
+                        "failed to send EquitySample to channel"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns the [`EquitySample`]s recorded in memory, oldest first. Always empty for
+    /// [`EquityRecorder`]s that do not record in memory.
+    pub fn samples(&self) -> Vec<EquitySample> {
+        match &*self.sink.lock() {
+            EquitySink::Memory { samples, .. } => samples.iter().copied().collect(),
+            EquitySink::Csv(_) | EquitySink::Channel(_) => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn sample(seconds: i64, equity: i64) -> EquitySample {
+        EquitySample {
+            time: DateTime::<Utc>::MIN_UTC + Duration::seconds(seconds),
+            equity: Decimal::from(equity),
+            cash: Decimal::from(equity),
+            unrealised_profit_loss: Decimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn ring_buffer_should_retain_only_the_latest_samples() {
+        let recorder = EquityRecorder::ring_buffer(2);
+        let shared = recorder.clone();
+
+        recorder.record(sample(0, 100));
+        shared.record(sample(1, 110));
+        recorder.record(sample(2, 120));
+
+        assert_eq!(recorder.samples(), vec![sample(1, 110), sample(2, 120)]);
+    }
+
+    #[test]
+    fn csv_recorder_should_write_a_row_per_sample() {
+        let path = std::env::temp_dir().join(format!("barter_equity_{}.csv", uuid::Uuid::new_v4()));
+        let recorder = EquityRecorder::csv(std::fs::File::create(&path).unwrap());
+
+        recorder.record(sample(0, 100));
+        recorder.record(sample(1, 110));
+
+        let actual = csv::Reader::from_path(&path)
+            .unwrap()
+            .deserialize::<EquitySample>()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(actual, vec![sample(0, 100), sample(1, 110)]);
+        assert!(recorder.samples().is_empty());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::portfolio::repository::error::RepositoryError;
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 /// All errors generated in the barter::portfolio module.
@@ -16,9 +17,33 @@ pub enum PortfolioError {
     #[error("Cannot exit Position with an entry decision FillEvent.")]
     CannotExitPositionWithEntryFill,
 
+    #[error("Cannot increase Position with a FillEvent entering the opposite Side.")]
+    CannotIncreasePositionWithOppositeSideFill,
+
+    #[error("Cannot decrease Position by it's entire quantity, it must be exited instead.")]
+    CannotDecreasePositionByEntireQuantity,
+
+    #[error("Cannot reconcile Position to a zero quantity, or a quantity of the opposite Side.")]
+    CannotReconcilePositionToOppositeSide,
+
     #[error("Cannot generate PositionExit from Position that has not been exited")]
     PositionExit,
 
+    #[error("Manual order request rejected: {0}")]
+    ManualOrderRejected(&'static str),
+
+    #[error("Invalid OrderEvent: {0}")]
+    InvalidOrder(&'static str),
+
+    #[error("Short sell refused: {0}")]
+    ShortRefused(&'static str),
+
+    #[error("Insufficient cash: buy requires {required} but only {available} is available")]
+    InsufficientCash {
+        required: Decimal,
+        available: Decimal,
+    },
+
     #[error("Failed to interact with repository")]
     RepositoryInteraction(#[from] RepositoryError),
 }
@@ -0,0 +1,207 @@
+use crate::portfolio::{conversion::ConversionRates, position::Position};
+use barter_integration::model::{instrument::symbol::Symbol, Exchange};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+/// Net & gross notional value of a set of open [`Position`]s, denominated in their quote
+/// currencies & valued at the latest market price the Portfolio has been updated with.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct Exposure {
+    /// Sum of the signed notional value of every [`Position`], where shorts are -ve.
+    pub net: Decimal,
+    /// Sum of the absolute notional value of every [`Position`].
+    pub gross: Decimal,
+}
+
+impl Exposure {
+    /// Constructs the [`Exposure`] of the provided [`Position`], denominated in the quote
+    /// currency of it's instrument.
+    fn of(position: &Position) -> Self {
+        Self {
+            net: position.quantity * position.current_symbol_price,
+            gross: position.current_value_gross,
+        }
+    }
+
+    /// Adds the provided [`Exposure`] to this [`Exposure`].
+    fn add(&mut self, exposure: Exposure) {
+        self.net += exposure.net;
+        self.gross += exposure.gross;
+    }
+}
+
+/// Aggregate [`Exposure`] of every open [`Position`] of a Portfolio, per exchange, per base
+/// asset & across the whole book. Positions in the same base asset on different exchanges (eg/
+/// btc_usdt on binance & btc_usd on kraken) aggregate to one asset level [`Exposure`].
+///
+/// An [`ExposureReport`] constructed via [`ExposureReport::converted`] denominates every
+/// [`Exposure`] in the base currency of the [`ConversionRates`], flagging any quote currency
+/// without a known rate whose [`Position`]s are excluded. Otherwise [`Exposure`]s sum the
+/// notional values of [`Position`]s as denominated in their quote currencies.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ExposureReport {
+    /// Time the [`ExposureReport`] was generated.
+    pub time: DateTime<Utc>,
+    /// [`Exposure`] of the open [`Position`]s on each [`Exchange`].
+    pub exchanges: HashMap<Exchange, Exposure>,
+    /// [`Exposure`] to each base asset [`Symbol`] of the open [`Position`]s' instruments.
+    pub assets: HashMap<Symbol, Exposure>,
+    /// [`Exposure`] of every open [`Position`].
+    pub total: Exposure,
+    /// Base currency every [`Exposure`] is denominated in, if converted via
+    /// [`ConversionRates`].
+    #[serde(default)]
+    pub base: Option<Symbol>,
+    /// Quote currencies without a known conversion rate, whose [`Position`]s are excluded
+    /// from every [`Exposure`].
+    #[serde(default)]
+    pub missing_rates: BTreeSet<Symbol>,
+}
+
+impl ExposureReport {
+    /// Constructs a new [`ExposureReport`] aggregating the provided open [`Position`]s.
+    pub fn new<'a, Positions>(time: DateTime<Utc>, positions: Positions) -> Self
+    where
+        Positions: IntoIterator<Item = &'a Position>,
+    {
+        let mut report = Self::empty(time);
+        for position in positions {
+            report.add(position, Exposure::of(position));
+        }
+        report
+    }
+
+    /// Constructs a new [`ExposureReport`] aggregating the provided open [`Position`]s, with
+    /// each [`Position`]'s notional value converted from it's quote currency into the base
+    /// currency of the [`ConversionRates`].
+    pub fn converted<'a, Positions>(
+        time: DateTime<Utc>,
+        positions: Positions,
+        rates: &ConversionRates,
+    ) -> Self
+    where
+        Positions: IntoIterator<Item = &'a Position>,
+    {
+        let mut report = Self {
+            base: Some(rates.base().clone()),
+            ..Self::empty(time)
+        };
+
+        for position in positions {
+            let quote = &position.instrument.quote;
+            match rates.rate(quote) {
+                Some(rate) => {
+                    let Exposure { net, gross } = Exposure::of(position);
+                    report.add(
+                        position,
+                        Exposure {
+                            net: net * rate,
+                            gross: gross * rate,
+                        },
+                    );
+                }
+                None => {
+                    report.missing_rates.insert(quote.clone());
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Returns `true` if the [`Position`]s of some quote currency were excluded for lack of a
+    /// conversion rate.
+    pub fn is_partial(&self) -> bool {
+        !self.missing_rates.is_empty()
+    }
+
+    fn empty(time: DateTime<Utc>) -> Self {
+        Self {
+            time,
+            exchanges: HashMap::new(),
+            assets: HashMap::new(),
+            total: Exposure::default(),
+            base: None,
+            missing_rates: BTreeSet::new(),
+        }
+    }
+
+    /// Adds the [`Exposure`] of the [`Position`] to it's exchange, base asset & the total.
+    fn add(&mut self, position: &Position, exposure: Exposure) {
+        self.exchanges
+            .entry(position.exchange.clone())
+            .or_default()
+            .add(exposure);
+        self.assets
+            .entry(position.instrument.base.clone())
+            .or_default()
+            .add(exposure);
+        self.total.add(exposure);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::position;
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Side,
+    };
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn exposure_report_should_aggregate_same_asset_positions_across_exchanges() {
+        // Long 1.0 btc at 1000.0 on binance
+        let binance_long = Position {
+            exchange: Exchange::from("binance"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            side: Side::Buy,
+            quantity: Decimal::ONE,
+            current_symbol_price: Decimal::ONE_THOUSAND,
+            current_value_gross: Decimal::ONE_THOUSAND,
+            ..position()
+        };
+
+        // Short 0.4 btc at 1010.0 on kraken
+        let kraken_short = Position {
+            exchange: Exchange::from("kraken"),
+            instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+            side: Side::Sell,
+            quantity: Decimal::new(-4, 1),
+            current_symbol_price: Decimal::from(1010),
+            current_value_gross: Decimal::from(404),
+            ..position()
+        };
+
+        // Long 1.0 eth at 100.0 on binance
+        let binance_eth = position();
+
+        let report = ExposureReport::new(Utc::now(), [&binance_long, &kraken_short, &binance_eth]);
+
+        let btc = report.assets[&Symbol::from("btc")];
+        assert_eq!(btc.net, Decimal::from(596));
+        assert_eq!(btc.gross, Decimal::from(1404));
+        assert_eq!(
+            report.assets[&Symbol::from("eth")].net,
+            Decimal::ONE_HUNDRED
+        );
+
+        let binance = report.exchanges[&Exchange::from("binance")];
+        assert_eq!(binance.net, Decimal::from(1100));
+        assert_eq!(
+            report.exchanges[&Exchange::from("kraken")].net,
+            Decimal::from(-404)
+        );
+
+        assert_eq!(
+            report.total,
+            Exposure {
+                net: Decimal::from(696),
+                gross: Decimal::from(1504),
+            }
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::portfolio::{decimal_from_f64, position::Position};
+use barter_integration::model::Market;
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Funding rate charged on the open [`Position`]s of a perpetual futures [`Market`] at each
+/// funding interval, in decimal form (eg/ 0.0001 for 0.01%).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum FundingRate {
+    /// Same funding rate charged at every funding interval.
+    Fixed(f64),
+    /// Recorded funding rate history, keyed by the time each rate took effect. A funding
+    /// interval is charged the latest rate in effect at it's funding time, and nothing if no
+    /// rate was in effect yet.
+    ///
+    /// [`MarketEvent`](barter_data::event::MarketEvent)s carry no funding rates, so the
+    /// history of a replayed [`Market`] is recorded separately (eg/ from the exchange funding
+    /// rate history endpoint).
+    Scheduled(BTreeMap<DateTime<Utc>, f64>),
+}
+
+impl FundingRate {
+    /// Returns the funding rate in effect at the provided funding time, if any.
+    pub fn rate_at(&self, time: DateTime<Utc>) -> Option<f64> {
+        match self {
+            Self::Fixed(rate) => Some(*rate),
+            Self::Scheduled(rates) => rates.range(..=time).next_back().map(|(_, rate)| *rate),
+        }
+    }
+}
+
+/// Audit record of a funding payment paid or received on an open perpetual [`Position`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingPayment {
+    /// Funding time the payment was charged at.
+    pub time: DateTime<Utc>,
+    /// [`Market`] of the open [`Position`].
+    pub market: Market,
+    /// Funding rate charged, in decimal form.
+    pub rate: f64,
+    /// +ve or -ve quantity of the open [`Position`].
+    pub quantity: Decimal,
+    /// Symbol price the [`Position`] notional was valued at.
+    pub price: Decimal,
+    /// Cash received (+ve) or paid (-ve) by the Portfolio.
+    pub amount: Decimal,
+}
+
+/// Simulates the funding payments of the open [`Position`]s of perpetual futures [`Market`]s,
+/// charged at every multiple of the funding interval since the Unix epoch (eg/ 00:00, 08:00 &
+/// 16:00 UTC for 8h). Used by the [`Trader`](crate::engine::trader::Trader) to debit or credit
+/// the Portfolio cash as replayed market time crosses each funding time.
+///
+/// The payment is the [`Position`] notional multiplied by the funding rate, paid by longs &
+/// received by shorts when the funding rate is positive (& vice versa when negative).
+/// [`Market`]s without a [`FundingRate`] are not perpetuals, so are never charged funding.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FundingModel {
+    interval: Duration,
+    markets: HashMap<Market, FundingRate>,
+}
+
+impl Default for FundingModel {
+    fn default() -> Self {
+        Self::new(Duration::hours(8))
+    }
+}
+
+impl FundingModel {
+    /// Constructs a new [`FundingModel`] charging funding at the provided interval, with no
+    /// perpetual [`Market`]s.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval: interval.max(Duration::seconds(1)),
+            markets: HashMap::new(),
+        }
+    }
+
+    /// Adds the [`FundingRate`] of a perpetual [`Market`].
+    pub fn with_market(mut self, market: Market, rate: FundingRate) -> Self {
+        self.markets.insert(market, rate);
+        self
+    }
+
+    /// Returns the [`FundingRate`] of the provided [`Market`], if it is a perpetual.
+    pub fn rate(&self, market: &Market) -> Option<&FundingRate> {
+        self.markets.get(market)
+    }
+
+    /// Returns every funding time after `from`, up to & including `to`, oldest first.
+    pub fn funding_times(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+        let interval = self.interval.num_seconds();
+        let mut next = (from.timestamp().div_euclid(interval) + 1) * interval;
+
+        let mut times = Vec::new();
+        while let Some(time) = DateTime::from_timestamp(next, 0).filter(|time| *time <= to) {
+            times.push(time);
+            next += interval;
+        }
+        times
+    }
+
+    /// Calculates the [`FundingPayment`] of the open [`Position`] of the [`Market`] charged at
+    /// the provided funding time, valued at it's current symbol price. Returns `None` if the
+    /// [`Market`] is not a perpetual, or no funding rate was in effect.
+    pub fn payment(
+        &self,
+        market: &Market,
+        position: &Position,
+        time: DateTime<Utc>,
+    ) -> Option<FundingPayment> {
+        let rate = self.rate(market)?.rate_at(time)?;
+        let price = position.current_symbol_price;
+        Some(FundingPayment {
+            time,
+            market: market.clone(),
+            rate,
+            quantity: position.quantity,
+            price,
+            amount: -position.quantity * price * decimal_from_f64(rate),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::position;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::TimeZone;
+
+    #[test]
+    fn funding_model_should_charge_longs_and_pay_shorts_at_each_funding_time() {
+        let market = Market::new("binance", ("btc", "usdt", InstrumentKind::Perpetual));
+        let start = Utc.with_ymd_and_hms(2023, 1, 2, 7, 0, 0).unwrap();
+        let model = FundingModel::new(Duration::hours(8)).with_market(
+            market.clone(),
+            FundingRate::Scheduled(BTreeMap::from([
+                (start, 0.001),
+                (start + Duration::hours(8), -0.002),
+            ])),
+        );
+
+        // Funding times at 08:00 & 16:00 are crossed, but not the 00:00 of the next day
+        let times = model.funding_times(start, start + Duration::hours(10));
+        assert_eq!(
+            times,
+            vec![start + Duration::hours(1), start + Duration::hours(9)]
+        );
+
+        let mut long = position();
+        long.quantity = Decimal::TWO;
+        long.current_symbol_price = Decimal::ONE_HUNDRED;
+        let mut short = long.clone();
+        short.quantity = -Decimal::TWO;
+
+        // Positive funding rate: longs pay & shorts receive
+        let paid = model.payment(&market, &long, times[0]).unwrap();
+        assert_eq!(paid.amount, Decimal::new(-2, 1));
+        assert_eq!(
+            model.payment(&market, &short, times[0]).unwrap().amount,
+            Decimal::new(2, 1)
+        );
+
+        // Negative funding rate: longs receive & shorts pay
+        assert_eq!(
+            model.payment(&market, &long, times[1]).unwrap().amount,
+            Decimal::new(4, 1)
+        );
+        assert_eq!(
+            model.payment(&market, &short, times[1]).unwrap().amount,
+            Decimal::new(-4, 1)
+        );
+
+        // No funding before the first recorded rate, nor for non-perpetual Markets
+        assert_eq!(
+            model.payment(&market, &long, start - Duration::hours(1)),
+            None
+        );
+        let spot = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+        assert_eq!(model.payment(&spot, &long, times[0]), None);
+    }
+}
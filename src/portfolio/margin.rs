@@ -0,0 +1,354 @@
+use crate::portfolio::{decimal_from_f64, position::Position, Balance, OrderEvent};
+use barter_integration::model::Market;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Determines which collateral backs the margin of open [`Position`]s.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum MarginMode {
+    /// Every [`Position`] is backed by the equity of the whole account (total [`Balance`] plus
+    /// unrealised profit and loss). A breach of maintenance margin liquidates every [`Position`].
+    #[default]
+    Cross,
+    /// Every [`Position`] is backed only by the initial margin allocated on entry. A breach of
+    /// maintenance margin liquidates only that [`Position`].
+    Isolated,
+}
+
+/// Leverage & maintenance margin requirements of a margin traded [`Market`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MarginConfig {
+    /// Maximum leverage, such that the initial margin of a [`Position`] is it's notional value
+    /// divided by the leverage (eg/ 10.0 for 10x).
+    pub leverage: f64,
+    /// Fraction of the notional value of a [`Position`] that must be maintained as margin to
+    /// avoid liquidation, in decimal form (eg/ 0.05 for 5%).
+    pub maintenance_margin_rate: f64,
+}
+
+impl MarginConfig {
+    /// Calculates the initial margin required to open a [`Position`] of the provided notional.
+    pub fn initial_margin(&self, notional: Decimal) -> Decimal {
+        notional
+            .abs()
+            .checked_div(decimal_from_f64(self.leverage))
+            .unwrap_or_default()
+    }
+
+    /// Calculates the maintenance margin required to keep open a [`Position`] of the provided
+    /// notional.
+    pub fn maintenance_margin(&self, notional: Decimal) -> Decimal {
+        notional.abs() * decimal_from_f64(self.maintenance_margin_rate)
+    }
+}
+
+/// Margin usage of the account, as calculated by a [`MarginModel`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct MarginState {
+    /// Collateral backing the open [`Position`]s.
+    pub collateral: Decimal,
+    /// Initial margin used by the open [`Position`]s.
+    pub used: Decimal,
+    /// Maintenance margin required by the open [`Position`]s.
+    pub maintenance: Decimal,
+    /// Margin available to open new [`Position`]s, ie/ collateral less used margin.
+    pub available: Decimal,
+}
+
+/// Models the leverage & margin requirements of margin traded [`Market`]s (eg/ futures),
+/// configured per [`Market`]. Used by the [`Trader`](crate::engine::trader::Trader) to refuse or
+/// downsize entry [`OrderEvent`]s that would exceed the available margin, and to liquidate open
+/// [`Position`]s once an adverse price move breaches the maintenance margin.
+///
+/// [`Market`]s without a [`MarginConfig`] are not margin traded, so their [`OrderEvent`]s &
+/// [`Position`]s are ignored.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MarginModel {
+    mode: MarginMode,
+    markets: HashMap<Market, MarginConfig>,
+}
+
+impl MarginModel {
+    /// Constructs a new [`MarginModel`] using the provided [`MarginMode`], with no margin traded
+    /// [`Market`]s.
+    pub fn new(mode: MarginMode) -> Self {
+        Self {
+            mode,
+            markets: HashMap::new(),
+        }
+    }
+
+    /// Adds the [`MarginConfig`] of a margin traded [`Market`].
+    pub fn with_market(mut self, market: Market, config: MarginConfig) -> Self {
+        self.markets.insert(market, config);
+        self
+    }
+
+    /// Returns the [`MarginMode`] of this [`MarginModel`].
+    pub fn mode(&self) -> MarginMode {
+        self.mode
+    }
+
+    /// Returns the [`MarginConfig`] of the provided [`Market`], if it is margin traded.
+    pub fn config(&self, market: &Market) -> Option<&MarginConfig> {
+        self.markets.get(market)
+    }
+
+    /// Returns an iterator over every margin traded [`Market`].
+    pub fn markets(&self) -> impl Iterator<Item = &Market> {
+        self.markets.keys()
+    }
+
+    /// Calculates the [`MarginState`] of the account from the provided [`Balance`] & open
+    /// [`Position`]s. Unrealised profit and loss only counts towards the collateral of
+    /// [`MarginMode::Cross`] accounts.
+    pub fn margin(&self, balance: &Balance, open_positions: &[Position]) -> MarginState {
+        let mut state = MarginState {
+            collateral: balance.total,
+            ..MarginState::default()
+        };
+
+        for (position, config) in self.margined(open_positions) {
+            state.used += match self.mode {
+                MarginMode::Cross => config.initial_margin(notional(position)),
+                MarginMode::Isolated => config.initial_margin(position.enter_value_gross),
+            };
+            state.maintenance += config.maintenance_margin(notional(position));
+
+            if self.mode == MarginMode::Cross {
+                state.collateral += position.unrealised_profit_loss;
+            }
+        }
+
+        state.available = state.collateral - state.used;
+        state
+    }
+
+    /// Evaluates an entry [`OrderEvent`] against the available margin, downsizing it to the
+    /// largest quantity the available margin can back. Returns `None` if no margin is available,
+    /// or the [`OrderEvent`] has no valid price.
+    ///
+    /// Exit [`OrderEvent`]s & [`OrderEvent`]s of [`Market`]s that are not margin traded are
+    /// never amended, so an open [`Position`] can always be closed in full.
+    pub fn evaluate_order(
+        &self,
+        mut order: OrderEvent,
+        balance: &Balance,
+        open_positions: &[Position],
+    ) -> Option<OrderEvent> {
+        let market = Market::new(order.exchange.clone(), order.instrument.clone());
+        let config = match self.markets.get(&market) {
+            Some(config) if order.decision.is_entry() => config,
+            _ => return Some(order),
+        };
+
+        let price = order.market_meta.close;
+        if price <= Decimal::ZERO {
+            return None;
+        }
+
+        let available = self.margin(balance, open_positions).available;
+        if available <= Decimal::ZERO {
+            return None;
+        }
+
+        // Downsize the order quantity if it's initial margin exceeds the available margin
+        if config.initial_margin(order.quantity * price) > available {
+            let quantity = available * decimal_from_f64(config.leverage) / price;
+            order.quantity = if order.quantity.is_sign_negative() {
+                -quantity
+            } else {
+                quantity
+            };
+        }
+
+        Some(order)
+    }
+
+    /// Determines which open [`Position`]s must be liquidated since their maintenance margin is
+    /// breached. [`MarginMode::Cross`] accounts liquidate every margin traded [`Position`] once
+    /// the account collateral falls below the total maintenance margin, whereas
+    /// [`MarginMode::Isolated`] accounts liquidate each [`Position`] whose own initial margin
+    /// plus unrealised profit and loss falls below it's maintenance margin.
+    pub fn liquidations(&self, balance: &Balance, open_positions: &[Position]) -> Vec<Market> {
+        match self.mode {
+            MarginMode::Cross => {
+                let state = self.margin(balance, open_positions);
+                if state.collateral >= state.maintenance {
+                    return Vec::new();
+                }
+
+                self.margined(open_positions)
+                    .map(|(position, _)| position_market(position))
+                    .collect()
+            }
+            MarginMode::Isolated => self
+                .margined(open_positions)
+                .filter(|(position, config)| {
+                    config.initial_margin(position.enter_value_gross)
+                        + position.unrealised_profit_loss
+                        < config.maintenance_margin(notional(position))
+                })
+                .map(|(position, _)| position_market(position))
+                .collect(),
+        }
+    }
+
+    /// Returns an iterator over the open [`Position`]s of margin traded [`Market`]s, alongside
+    /// their [`MarginConfig`].
+    fn margined<'a>(
+        &'a self,
+        open_positions: &'a [Position],
+    ) -> impl Iterator<Item = (&'a Position, &'a MarginConfig)> {
+        open_positions.iter().filter_map(|position| {
+            self.markets
+                .get(&position_market(position))
+                .map(|config| (position, config))
+        })
+    }
+}
+
+/// Current notional value of a [`Position`].
+fn notional(position: &Position) -> Decimal {
+    position.quantity.abs() * position.current_symbol_price
+}
+
+/// [`Market`] of a [`Position`].
+fn position_market(position: &Position) -> Market {
+    Market::new(position.exchange.clone(), position.instrument.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        strategy::Decision,
+        test_util::{order_event, position},
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+
+    fn market() -> Market {
+        let position = position();
+        Market::new(position.exchange, position.instrument)
+    }
+
+    fn config() -> MarginConfig {
+        MarginConfig {
+            leverage: 10.0,
+            maintenance_margin_rate: 0.05,
+        }
+    }
+
+    /// Long [`Position`] of 10 entered at 100, currently priced at the provided price.
+    fn long_at(current_symbol_price: i64) -> Position {
+        Position {
+            quantity: Decimal::TEN,
+            enter_avg_price_gross: Decimal::ONE_HUNDRED,
+            enter_value_gross: Decimal::ONE_THOUSAND,
+            current_symbol_price: Decimal::from(current_symbol_price),
+            current_value_gross: Decimal::from(10 * current_symbol_price),
+            unrealised_profit_loss: Decimal::from(10 * (current_symbol_price - 100)),
+            ..position()
+        }
+    }
+
+    fn entry_order(quantity: Decimal, price: i64) -> OrderEvent {
+        let mut order = order_event();
+        order.exchange = market().exchange;
+        order.instrument = market().instrument;
+        order.decision = Decision::Long;
+        order.quantity = quantity;
+        order.market_meta.close = Decimal::from(price);
+        order
+    }
+
+    #[test]
+    fn margin_should_use_leveraged_notional_of_open_positions() {
+        let margin = MarginModel::new(MarginMode::Cross).with_market(market(), config());
+        let balance = Balance::new(Utc::now(), Decimal::from(500), Decimal::from(500));
+
+        let actual = margin.margin(&balance, &[long_at(110)]);
+
+        assert_eq!(actual.collateral, Decimal::from(600));
+        assert_eq!(actual.used, Decimal::from(110));
+        assert_eq!(actual.maintenance, Decimal::from(55));
+        assert_eq!(actual.available, Decimal::from(490));
+    }
+
+    #[test]
+    fn entry_order_should_be_refused_on_insufficient_margin_and_downsized_to_available() {
+        let margin = MarginModel::new(MarginMode::Isolated).with_market(market(), config());
+
+        // Isolated collateral of 100.0 is fully used by the open Position's initial margin
+        let balance = Balance::new(Utc::now(), Decimal::ONE_HUNDRED, Decimal::ONE_HUNDRED);
+        let order = entry_order(Decimal::TEN, 100);
+        assert_eq!(
+            margin.evaluate_order(order.clone(), &balance, &[long_at(150)]),
+            None
+        );
+
+        // 50.0 of margin at 10x backs a notional of 500.0, so 5 at a price of 100.0
+        let balance = Balance::new(Utc::now(), Decimal::from(150), Decimal::from(150));
+        let actual = margin
+            .evaluate_order(order.clone(), &balance, &[long_at(150)])
+            .unwrap();
+        assert_eq!(actual.quantity, Decimal::from(5));
+
+        // Exit orders are never amended
+        let exit = OrderEvent {
+            decision: Decision::CloseLong,
+            quantity: -Decimal::TEN,
+            ..order
+        };
+        assert_eq!(
+            margin.evaluate_order(exit.clone(), &Balance::default(), &[]),
+            Some(exit)
+        );
+    }
+
+    #[test]
+    fn cross_margin_should_liquidate_once_adverse_move_breaches_maintenance() {
+        let margin = MarginModel::new(MarginMode::Cross).with_market(market(), config());
+        let balance = Balance::new(Utc::now(), Decimal::ONE_HUNDRED, Decimal::ONE_HUNDRED);
+
+        // Collateral of 100.0 - 50.0 = 50.0 covers the 47.5 maintenance margin at 95.0
+        assert!(margin.liquidations(&balance, &[long_at(95)]).is_empty());
+
+        // Collateral of 100.0 - 60.0 = 40.0 does not cover the 47.0 maintenance margin at 94.0
+        assert_eq!(
+            margin.liquidations(&balance, &[long_at(94)]),
+            vec![market()]
+        );
+    }
+
+    #[test]
+    fn isolated_margin_should_liquidate_only_the_breaching_position() {
+        let other = Market::new("binance", ("btc", "usdt", InstrumentKind::Spot));
+        let margin = MarginModel::new(MarginMode::Isolated)
+            .with_market(market(), config())
+            .with_market(other.clone(), config());
+
+        let healthy = Position {
+            exchange: other.exchange.clone(),
+            instrument: other.instrument.clone(),
+            ..long_at(100)
+        };
+
+        // Initial margin of 100.0 less the unrealised loss of 100.0 at 90.0 does not cover the
+        // 45.0 maintenance margin, despite the ample account collateral
+        let balance = Balance::new(
+            Utc::now(),
+            Decimal::from(1_000_000),
+            Decimal::from(1_000_000),
+        );
+        assert_eq!(
+            margin.liquidations(&balance, &[long_at(90), healthy]),
+            vec![market()]
+        );
+    }
+}
@@ -1,14 +1,24 @@
 use crate::{
     data::MarketMeta,
     event::Event,
-    execution::FillEvent,
-    portfolio::{error::PortfolioError, position::PositionUpdate},
+    execution::{order_id::ClientOrderId, AccountId, FillEvent},
+    portfolio::{
+        conversion::{ConversionRates, Converted},
+        error::PortfolioError,
+        exposure::ExposureReport,
+        position::PositionUpdate,
+    },
     strategy::{Decision, Signal, SignalForceExit},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{instrument::Instrument, Exchange};
+use barter_integration::model::{instrument::Instrument, Exchange, Market, Side};
 use chrono::{DateTime, Utc};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 
 /// Logic for [`OrderEvent`] quantity allocation.
@@ -29,9 +39,41 @@ pub mod position;
 /// Repositories for persisting Portfolio state.
 pub mod repository;
 
+/// Position sizers converting a [`SignalStrength`](crate::strategy::SignalStrength), the
+/// Portfolio equity & the market price into an entry [`OrderEvent`] quantity.
+pub mod sizer;
+
 /// Logic for evaluating the risk associated with a proposed [`OrderEvent`].
 pub mod risk;
 
+/// Leverage & margin requirements of margin traded markets, used to check entry
+/// [`OrderEvent`]s against the available margin & to liquidate under-margined
+/// [`Position`](position::Position)s.
+pub mod margin;
+
+/// Funding payments of open perpetual futures [`Position`](position::Position)s, charged at each
+/// funding interval.
+pub mod funding;
+
+/// Recorders of the Portfolio equity curve, streamed to an in-memory buffer, CSV writer or
+/// channel.
+pub mod equity;
+
+/// Rates converting the values of a multi quote currency Portfolio into a single base currency.
+pub mod conversion;
+
+/// Aggregate net & gross notional exposure of the open [`Position`](position::Position)s of a
+/// Portfolio, per exchange & per base asset.
+pub mod exposure;
+
+/// Self-match prevention, netting opposing [`OrderEvent`]s that would match against each other
+/// on the exchange.
+pub mod self_match;
+
+/// Stop-loss & take-profit thresholds of open [`Position`](position::Position)s, managed on
+/// behalf of every strategy.
+pub mod stop;
+
 /// Updates the Portfolio from an input [`MarketEvent`].
 pub trait MarketUpdater {
     /// Determines if the Portfolio has an open Position relating to the input [`MarketEvent`]. If
@@ -54,6 +96,15 @@ pub trait OrderGenerator {
         &mut self,
         signal: SignalForceExit,
     ) -> Result<Option<OrderEvent>, PortfolioError>;
+
+    /// Validates a [`ManualOrderRequest`] and translates it into an [`OrderEvent`]. The provided
+    /// [`MarketMeta`] is the latest market price known to the caller, and is used to price
+    /// manual market orders.
+    fn generate_manual_order(
+        &mut self,
+        request: ManualOrderRequest,
+        market_meta: Option<MarketMeta>,
+    ) -> Result<OrderEvent, PortfolioError>;
 }
 
 /// Updates the Portfolio from an input [`FillEvent`].
@@ -64,11 +115,51 @@ pub trait FillUpdater {
     fn update_from_fill(&mut self, fill: &FillEvent) -> Result<Vec<Event>, PortfolioError>;
 }
 
+/// Reports the profit & loss of the Portfolio per [`Market`].
+pub trait ProfitLossReporter {
+    /// Returns the total realised P&L of every exited Position, summed per [`Market`].
+    fn realised_profit_loss(&mut self) -> Result<HashMap<Market, Decimal>, PortfolioError>;
+
+    /// Returns the unrealised P&L of every open Position associated with the provided
+    /// [`Market`]s, as of the latest [`MarketEvent`] the Portfolio has been updated with.
+    fn unrealised_profit_loss<'a, Markets>(
+        &mut self,
+        markets: Markets,
+    ) -> Result<HashMap<Market, Decimal>, PortfolioError>
+    where
+        Markets: Iterator<Item = &'a Market>;
+}
+
+/// Reports the aggregate exposure of the open Positions of the Portfolio.
+pub trait ExposureReporter {
+    /// Returns an [`ExposureReport`] of every open Position associated with the provided
+    /// [`Market`]s, summing their net & gross notional value per exchange, per base asset &
+    /// across the whole book.
+    fn exposure_report<'a, Markets>(
+        &mut self,
+        markets: Markets,
+    ) -> Result<ExposureReport, PortfolioError>
+    where
+        Markets: Iterator<Item = &'a Market>;
+}
+
+/// Arbitrary key-value metadata (eg/ strategy name, signal id) an [`OrderEvent`] is tagged
+/// with for attribution, propagated to the [`FillEvent`]s of the [`OrderEvent`] & the
+/// [`Trade`](crate::statistic::trade::Trade)s they open.
+pub type OrderTags = BTreeMap<String, String>;
+
 /// Orders are generated by the portfolio and details work to be done by an Execution handler to
 /// open a trade.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct OrderEvent {
     pub time: DateTime<Utc>,
+    /// Unique [`ClientOrderId`], assigned by the [`Trader`](crate::engine::trader::Trader) before
+    /// the [`OrderEvent`] is sent for execution.
+    pub cid: ClientOrderId,
+    /// [`AccountId`] of the exchange account (or sub-account) the [`OrderEvent`] is executed on,
+    /// assigned by the [`Trader`](crate::engine::trader::Trader) before execution.
+    #[serde(default)]
+    pub account: AccountId,
     pub exchange: Exchange,
     pub instrument: Instrument,
     /// Metadata propagated from source MarketEvent
@@ -76,32 +167,191 @@ pub struct OrderEvent {
     /// LONG, CloseLong, SHORT or CloseShort
     pub decision: Decision,
     /// +ve or -ve Quantity depending on Decision
-    pub quantity: f64,
+    pub quantity: Decimal,
     /// MARKET, LIMIT etc
     pub order_type: OrderType,
+    /// Price at which a [`OrderType::StopLimit`] order is triggered, after which it rests as a
+    /// limit order at the [`MarketMeta`] close price. Ignored by every other [`OrderType`].
+    #[serde(default)]
+    pub stop_price: Option<Decimal>,
+    /// How long the order remains open before it is cancelled.
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
+    /// Optional price condition the order is held inactive until, after which it is sent for
+    /// execution as it's [`OrderType`] (eg/ "buy if the price rises above 50k").
+    #[serde(default)]
+    pub trigger: Option<Trigger>,
+    /// [`OrderTags`] propagated to the [`FillEvent`]s of the [`OrderEvent`].
+    #[serde(default)]
+    pub tags: OrderTags,
 }
 
 impl OrderEvent {
     pub const ORGANIC_ORDER: &'static str = "Order";
     pub const FORCED_EXIT_ORDER: &'static str = "OrderForcedExit";
 
+    /// Validates the [`OrderEvent`] is well-formed for its [`OrderType`] before it is sent for
+    /// execution, eg/ a [`OrderType::StopLimit`] order must have a stop price.
+    pub fn validate(&self) -> Result<(), PortfolioError> {
+        if self.quantity.is_zero() {
+            return Err(PortfolioError::InvalidOrder("quantity must be non-zero"));
+        }
+
+        let price_is_valid = |price: Decimal| price > Decimal::ZERO;
+
+        match (self.order_type, self.stop_price) {
+            (OrderType::Limit | OrderType::StopLimit, _)
+                if !price_is_valid(self.market_meta.close) =>
+            {
+                Err(PortfolioError::InvalidOrder(
+                    "limit price must be greater than zero",
+                ))
+            }
+            (OrderType::StopLimit, None) => Err(PortfolioError::InvalidOrder(
+                "StopLimit order is missing its stop price",
+            )),
+            (OrderType::StopLimit, Some(stop_price)) if !price_is_valid(stop_price) => Err(
+                PortfolioError::InvalidOrder("stop price must be greater than zero"),
+            ),
+            _ if self
+                .trigger
+                .is_some_and(|trigger| !price_is_valid(trigger.price)) =>
+            {
+                Err(PortfolioError::InvalidOrder(
+                    "trigger price must be greater than zero",
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Returns a OrderEventBuilder instance.
     pub fn builder() -> OrderEventBuilder {
         OrderEventBuilder::new()
     }
 }
 
+/// Order requested manually via a [`Command::ManualOrder`](crate::engine::Command), rather than
+/// generated by a Portfolio from an advisory [`Signal`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ManualOrderRequest {
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    /// Buy or Sell.
+    pub side: Side,
+    /// Absolute quantity of contracts to buy or sell. Ignored if a `notional` is provided.
+    pub quantity: Decimal,
+    /// Optional value in the quote currency to buy or sell in place of the `quantity` (eg/ 100.0
+    /// to buy $100 of btc_usdt), converted into a quantity at the latest market price when the
+    /// order is submitted. Rejected if no market price is known.
+    #[serde(default)]
+    pub notional: Option<Decimal>,
+    /// Limit price of the order. A [`OrderType::Market`] order is placed if `None`.
+    pub limit_price: Option<Decimal>,
+    /// Stop price of the order. A [`OrderType::StopLimit`] order is placed if both this & the
+    /// limit price are provided.
+    #[serde(default)]
+    pub stop_price: Option<Decimal>,
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
+    /// Optional price condition the order is held inactive until, see [`OrderEvent::trigger`].
+    #[serde(default)]
+    pub trigger: Option<Trigger>,
+    /// [`OrderTags`] the generated [`OrderEvent`] is tagged with, see [`OrderEvent::tags`].
+    #[serde(default)]
+    pub tags: OrderTags,
+}
+
+impl ManualOrderRequest {
+    /// Returns the [`Market`] this [`ManualOrderRequest`] is routed to.
+    pub fn market(&self) -> Market {
+        Market::new(self.exchange.clone(), self.instrument.clone())
+    }
+
+    /// Converts the quote currency `notional` of this [`ManualOrderRequest`], if provided, into
+    /// the `quantity` at the latest market price. Requests sized by `quantity` are returned
+    /// unchanged.
+    pub fn resolve_notional(mut self, price: Option<Decimal>) -> Result<Self, PortfolioError> {
+        let Some(notional) = self.notional.take() else {
+            return Ok(self);
+        };
+        if notional <= Decimal::ZERO {
+            return Err(PortfolioError::ManualOrderRejected(
+                "notional must be greater than zero",
+            ));
+        }
+        let Some(price) = price.filter(|price| *price > Decimal::ZERO) else {
+            return Err(PortfolioError::ManualOrderRejected(
+                "no market price available to convert the notional into a quantity",
+            ));
+        };
+
+        self.quantity = notional / price;
+        Ok(self)
+    }
+}
+
 /// Type of order the portfolio wants the execution::handler to place.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
 pub enum OrderType {
+    #[default]
     Market,
     Limit,
     Bracket,
+    /// Limit order that is only placed once the market trades through its stop price.
+    StopLimit,
 }
 
-impl Default for OrderType {
-    fn default() -> Self {
-        Self::Market
+/// Direction the market price must move through a [`Trigger`] price for it to be met.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum TriggerDirection {
+    /// Met once the market price is at or above the trigger price.
+    Above,
+    /// Met once the market price is at or below the trigger price.
+    Below,
+}
+
+/// Price condition a conditional [`OrderEvent`] is held inactive until.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Trigger {
+    pub price: Decimal,
+    pub direction: TriggerDirection,
+}
+
+impl Trigger {
+    /// Determines if the provided market price meets this [`Trigger`].
+    pub fn is_met(&self, price: Decimal) -> bool {
+        match self.direction {
+            TriggerDirection::Above => price >= self.price,
+            TriggerDirection::Below => price <= self.price,
+        }
+    }
+}
+
+/// How long an [`OrderEvent`] remains open before it is cancelled.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum TimeInForce {
+    /// Remains open until filled or cancelled.
+    #[default]
+    GoodUntilCancelled,
+    /// Fills as much as possible immediately, cancelling any remaining quantity.
+    ImmediateOrCancel,
+    /// Fills in full immediately, or is cancelled.
+    FillOrKill,
+    /// Remains open until filled or cancelled as a maker order, and is rejected rather than
+    /// filled if it would immediately cross the market and take liquidity.
+    PostOnly,
+}
+
+impl TimeInForce {
+    /// Determines if an [`OrderEvent`] with this [`TimeInForce`] must execute immediately, and is
+    /// therefore cancelled rather than left resting.
+    pub fn is_immediate(&self) -> bool {
+        matches!(self, Self::ImmediateOrCancel | Self::FillOrKill)
     }
 }
 
@@ -109,12 +359,18 @@ impl Default for OrderType {
 #[derive(Debug, Default)]
 pub struct OrderEventBuilder {
     pub time: Option<DateTime<Utc>>,
+    pub cid: Option<ClientOrderId>,
+    pub account: Option<AccountId>,
     pub exchange: Option<Exchange>,
     pub instrument: Option<Instrument>,
     pub market_meta: Option<MarketMeta>,
     pub decision: Option<Decision>,
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     pub order_type: Option<OrderType>,
+    pub stop_price: Option<Decimal>,
+    pub time_in_force: Option<TimeInForce>,
+    pub trigger: Option<Trigger>,
+    pub tags: Option<OrderTags>,
 }
 
 impl OrderEventBuilder {
@@ -129,6 +385,24 @@ impl OrderEventBuilder {
         }
     }
 
+    /// Optional [`ClientOrderId`], defaults to the placeholder [`ClientOrderId::default`] since
+    /// the [`Trader`](crate::engine::trader::Trader) assigns one before execution.
+    pub fn cid(self, value: ClientOrderId) -> Self {
+        Self {
+            cid: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`AccountId`], defaults to [`AccountId::default`] since the
+    /// [`Trader`](crate::engine::trader::Trader) assigns one before execution.
+    pub fn account(self, value: AccountId) -> Self {
+        Self {
+            account: Some(value),
+            ..self
+        }
+    }
+
     pub fn exchange(self, value: Exchange) -> Self {
         Self {
             exchange: Some(value),
@@ -157,7 +431,7 @@ impl OrderEventBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Decimal) -> Self {
         Self {
             quantity: Some(value),
             ..self
@@ -171,9 +445,44 @@ impl OrderEventBuilder {
         }
     }
 
+    /// Optional stop price, required if the [`OrderType`] is [`OrderType::StopLimit`].
+    pub fn stop_price(self, value: Decimal) -> Self {
+        Self {
+            stop_price: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`TimeInForce`], defaults to [`TimeInForce::GoodUntilCancelled`].
+    pub fn time_in_force(self, value: TimeInForce) -> Self {
+        Self {
+            time_in_force: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`Trigger`] the order is held inactive until, defaults to `None`.
+    pub fn trigger(self, value: Trigger) -> Self {
+        Self {
+            trigger: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`OrderTags`] propagated to the [`FillEvent`]s of the [`OrderEvent`], defaults
+    /// to no tags.
+    pub fn tags(self, value: OrderTags) -> Self {
+        Self {
+            tags: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<OrderEvent, PortfolioError> {
         Ok(OrderEvent {
             time: self.time.ok_or(PortfolioError::BuilderIncomplete("time"))?,
+            cid: self.cid.unwrap_or_default(),
+            account: self.account.unwrap_or_default(),
             exchange: self
                 .exchange
                 .ok_or(PortfolioError::BuilderIncomplete("exchange"))?,
@@ -192,10 +501,85 @@ impl OrderEventBuilder {
             order_type: self
                 .order_type
                 .ok_or(PortfolioError::BuilderIncomplete("order_type"))?,
+            stop_price: self.stop_price,
+            time_in_force: self.time_in_force.unwrap_or_default(),
+            trigger: self.trigger,
+            tags: self.tags.unwrap_or_default(),
         })
     }
 }
 
+/// Known Portfolio state (eg/ persisted before a restart) used to seed an
+/// [`Engine`](crate::engine::Engine) so it resumes trading from it, rather than starting flat.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PortfolioState {
+    /// Portfolio [`Balance`] to resume with.
+    pub balance: Balance,
+    /// Open [`Position`](position::Position)s to resume managing.
+    pub open_positions: Vec<position::Position>,
+}
+
+/// Point in time view of a live Portfolio, fetched on demand (eg/ by a dashboard) via
+/// [`Command::FetchPortfolioSnapshot`](crate::engine::Command::FetchPortfolioSnapshot).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PortfolioSnapshot {
+    /// Time the [`PortfolioSnapshot`] was taken.
+    pub time: DateTime<Utc>,
+    /// Portfolio [`Balance`], of which `available` is the cash not allocated to open
+    /// [`Position`](position::Position)s.
+    pub balance: Balance,
+    /// Open [`Position`](position::Position)s, including their average entry price & unrealised
+    /// profit and loss.
+    pub open_positions: Vec<position::Position>,
+    /// Sum of the unrealised profit and loss of every open [`Position`](position::Position).
+    pub unrealised_profit_loss: Decimal,
+    /// Total [`Balance`] plus the unrealised profit and loss of every open
+    /// [`Position`](position::Position).
+    pub equity: Decimal,
+}
+
+impl PortfolioSnapshot {
+    /// Constructs a new [`PortfolioSnapshot`], deriving the unrealised profit and loss & equity
+    /// from the provided [`Balance`] & open [`Position`](position::Position)s.
+    pub fn new(
+        time: DateTime<Utc>,
+        balance: Balance,
+        open_positions: Vec<position::Position>,
+    ) -> Self {
+        let unrealised_profit_loss = open_positions
+            .iter()
+            .map(|position| position.unrealised_profit_loss)
+            .sum::<Decimal>();
+
+        Self {
+            time,
+            balance,
+            open_positions,
+            unrealised_profit_loss,
+            equity: balance.total + unrealised_profit_loss,
+        }
+    }
+
+    /// Converts the equity of this [`PortfolioSnapshot`] into the base currency of the
+    /// provided [`ConversionRates`]. The [`Balance`] is assumed to be denominated in the base
+    /// currency, and the unrealised profit and loss of each open
+    /// [`Position`](position::Position) is converted from it's quote currency. Positions
+    /// quoted in a currency without a known rate are excluded & flagged in the [`Converted`]
+    /// equity.
+    pub fn converted_equity(&self, rates: &ConversionRates) -> Converted {
+        let mut equity = Converted::new(rates);
+        equity.value = self.balance.total;
+        for position in &self.open_positions {
+            equity.add(
+                rates,
+                position.unrealised_profit_loss,
+                &position.instrument.quote,
+            );
+        }
+        equity
+    }
+}
+
 /// Communicates a String represents a unique identifier for an Engine's Portfolio [`Balance`].
 pub type BalanceId = String;
 
@@ -203,23 +587,23 @@ pub type BalanceId = String;
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Balance {
     pub time: DateTime<Utc>,
-    pub total: f64,
-    pub available: f64,
+    pub total: Decimal,
+    pub available: Decimal,
 }
 
 impl Default for Balance {
     fn default() -> Self {
         Self {
             time: Utc::now(),
-            total: 0.0,
-            available: 0.0,
+            total: Decimal::ZERO,
+            available: Decimal::ZERO,
         }
     }
 }
 
 impl Balance {
     /// Construct a new [`Balance`] using the provided total & available balance values.
-    pub fn new(time: DateTime<Utc>, total: f64, available: f64) -> Self {
+    pub fn new(time: DateTime<Utc>, total: Decimal, available: Decimal) -> Self {
         Self {
             time,
             total,
@@ -232,3 +616,79 @@ impl Balance {
         format!("{}_balance", engine_id)
     }
 }
+
+/// Converts an `f64` (eg/ a price from barter-data, or a quantity sized by an `f64` model) to a
+/// [`Decimal`], rounded to the precision of the `f64`. Non-finite values convert to zero, so an
+/// [`OrderEvent`] sized or priced by one is rejected by [`OrderEvent::validate`].
+pub fn decimal_from_f64(value: f64) -> Decimal {
+    Decimal::from_f64(value).unwrap_or_default()
+}
+
+/// Converts a [`Decimal`] to an `f64`, eg/ to feed a price or P&L into the `f64` statistics.
+pub fn decimal_to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::order_event;
+
+    fn order(order_type: OrderType, stop_price: Option<Decimal>) -> OrderEvent {
+        OrderEvent {
+            market_meta: MarketMeta {
+                close: Decimal::ONE_THOUSAND,
+                time: Utc::now(),
+            },
+            order_type,
+            stop_price,
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            ..order_event()
+        }
+    }
+
+    #[test]
+    fn order_event_of_every_order_type_should_round_trip_serde() {
+        let orders = [
+            order(OrderType::Market, None),
+            order(OrderType::Limit, None),
+            order(OrderType::Bracket, None),
+            order(OrderType::StopLimit, Some(Decimal::from(990))),
+        ];
+
+        for order in orders {
+            assert!(order.validate().is_ok(), "{order:?}");
+
+            let json = serde_json::to_string(&order).unwrap();
+            let actual: OrderEvent = serde_json::from_str(&json).unwrap();
+            assert_eq!(actual, order);
+        }
+    }
+
+    #[test]
+    fn order_event_without_stop_price_or_time_in_force_should_deserialise_with_defaults() {
+        let mut json = serde_json::to_value(order(OrderType::Limit, None)).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("stop_price");
+        fields.remove("time_in_force");
+
+        let actual: OrderEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(actual.stop_price, None);
+        assert_eq!(actual.time_in_force, TimeInForce::GoodUntilCancelled);
+    }
+
+    #[test]
+    fn stop_limit_order_event_missing_stop_price_should_be_rejected() {
+        let invalid = order(OrderType::StopLimit, None);
+        assert!(matches!(
+            invalid.validate(),
+            Err(PortfolioError::InvalidOrder(_))
+        ));
+
+        let invalid = order(OrderType::StopLimit, Some(Decimal::NEGATIVE_ONE));
+        assert!(matches!(
+            invalid.validate(),
+            Err(PortfolioError::InvalidOrder(_))
+        ));
+    }
+}
@@ -1,26 +1,32 @@
 use super::{
     allocator::OrderAllocator,
+    conversion::{ConversionRates, Converted},
+    decimal_to_f64,
     error::PortfolioError,
+    exposure::ExposureReport,
     position::{
-        determine_position_id, Position, PositionEnterer, PositionExiter, PositionId,
+        determine_position_id, CostBasis, Position, PositionEnterer, PositionExiter, PositionId,
         PositionUpdate, PositionUpdater,
     },
     repository::{error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler},
-    risk::OrderEvaluator,
-    Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, OrderType,
+    risk::{OrderEvaluator, ShortConstraint},
+    sizer::{PortfolioVolTargeter, PositionSizer},
+    Balance, ExposureReporter, FillUpdater, ManualOrderRequest, MarketUpdater, OrderEvent,
+    OrderGenerator, OrderTags, OrderType, PortfolioSnapshot, ProfitLossReporter, TimeInForce,
 };
 use crate::{
-    data::MarketMeta,
+    clock::{Clock, LiveClock},
+    data::{book::ConsolidatedBook, MarketMeta},
     event::Event,
-    execution::FillEvent,
+    execution::{order_id::ClientOrderId, AccountId, Fees, FillEvent},
     statistic::summary::{Initialiser, PositionSummariser},
     strategy::{Decision, Signal, SignalForceExit, SignalStrength},
 };
 use barter_data::event::{DataKind, MarketEvent};
-use barter_integration::model::{Market, MarketId, Side};
-use chrono::Utc;
+use barter_integration::model::{instrument::Instrument, Market, MarketId, Side};
+use rust_decimal::Decimal;
 use serde::Serialize;
-use std::{collections::HashMap, marker::PhantomData};
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
 use tracing::info;
 use uuid::Uuid;
 
@@ -47,7 +53,7 @@ where
     /// Risk manager implements [`OrderEvaluator`].
     pub risk: RiskManager,
     /// Cash balance a [`MetaPortfolio`] starts with.
-    pub starting_cash: f64,
+    pub starting_cash: Decimal,
     /// Configuration used to initialise the Statistics for every Market's performance tracked by a
     /// [`MetaPortfolio`].
     pub statistic_config: Statistic::Config,
@@ -73,6 +79,26 @@ where
     allocation_manager: Allocator,
     /// Risk manager implements [`OrderEvaluator`].
     risk_manager: RiskManager,
+    /// Optional [`ConversionRates`] used to convert equity & exposure of Positions quoted in
+    /// different currencies into a single base currency. Updated from every [`MarketEvent`].
+    conversion_rates: Option<ConversionRates>,
+    /// Optional [`ConsolidatedBook`] of the best bid & ask of each instrument across every venue.
+    /// Updated from every [`MarketEvent`].
+    consolidated_book: Option<ConsolidatedBook>,
+    /// Optional [`PositionSizer`] sizing every entry [`OrderEvent`] in place of the allocation
+    /// manager. Updated from every [`MarketEvent`].
+    position_sizer: Option<Box<dyn PositionSizer + Send>>,
+    /// Optional [`PortfolioVolTargeter`] scaling every entry [`OrderEvent`] so the Portfolio
+    /// equity targets a volatility. Sampled from [`MarketEvent`]s.
+    vol_targeter: Option<PortfolioVolTargeter>,
+    /// [`ShortConstraint`]s of the instruments that must be borrowed to be shorted. Instruments
+    /// without a [`ShortConstraint`] can be shorted without limit.
+    short_constraints: HashMap<Instrument, ShortConstraint>,
+    /// [`CostBasis`] used to match partial exits against the entry lots of a [`Position`] that
+    /// was scaled into.
+    cost_basis: CostBasis,
+    /// [`Clock`] used to timestamp the generated [`OrderEvent`]s, [`Balance`]s & reports.
+    clock: Arc<dyn Clock + Send + Sync>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -88,21 +114,35 @@ where
         &mut self,
         market: &MarketEvent<DataKind>,
     ) -> Result<Option<PositionUpdate>, PortfolioError> {
+        if let Some(rates) = &mut self.conversion_rates {
+            rates.update_from_market(market);
+        }
+        if let Some(book) = &mut self.consolidated_book {
+            book.update_from_market(market);
+        }
+        if let Some(sizer) = &mut self.position_sizer {
+            sizer.update_from_market(market);
+        }
+
         // Determine the position_id associated to the input MarketEvent
         let position_id =
             determine_position_id(self.engine_id, &market.exchange, &market.instrument);
 
         // Update Position if Portfolio has an open Position for that Symbol-Exchange combination
+        let mut position_update = None;
         if let Some(mut position) = self.repository.get_open_position(&position_id)? {
             // Derive PositionUpdate event that communicates the open Position's change in state
-            if let Some(position_update) = position.update(market) {
+            if let Some(update) = position.update(market) {
                 // Save updated open Position in the repository
                 self.repository.set_open_position(position)?;
-                return Ok(Some(position_update));
+                position_update = Some(update);
             }
         }
 
-        Ok(None)
+        // Sample the revalued Portfolio equity for the PortfolioVolTargeter if it is due
+        self.sample_equity(market)?;
+
+        Ok(position_update)
     }
 }
 
@@ -135,21 +175,70 @@ where
 
         // Construct mutable OrderEvent that can be modified by Allocation & Risk management
         let mut order = OrderEvent {
-            time: Utc::now(),
+            time: self.clock.now(),
+            cid: ClientOrderId::default(),
+            account: AccountId::default(),
             exchange: signal.exchange.clone(),
             instrument: signal.instrument.clone(),
             market_meta: signal.market_meta,
             decision: *signal_decision,
-            quantity: 0.0,
+            quantity: Decimal::ZERO,
             order_type: OrderType::default(),
+            stop_price: None,
+            time_in_force: TimeInForce::default(),
+            trigger: None,
+            tags: signal.tags.clone(),
         };
 
         // Manage OrderEvent size allocation
         self.allocation_manager
             .allocate_order(&mut order, position, *signal_strength);
 
+        // Size entry OrderEvents via the PositionSizer if configured, skipping entry whilst it
+        // cannot produce a size (eg/ warming up)
+        if let (Some(sizer), true) = (&self.position_sizer, order.decision.is_entry()) {
+            let equity = self.repository.get_balance(self.engine_id)?.total;
+            let market = Market::new(order.exchange.clone(), order.instrument.clone());
+            let Some(quantity) = sizer.size(
+                &market,
+                *signal_strength,
+                decimal_to_f64(equity),
+                decimal_to_f64(order.market_meta.close),
+            ) else {
+                return Ok(None);
+            };
+            order.quantity = match order.decision {
+                Decision::Short => -quantity,
+                _ => quantity,
+            };
+        }
+
+        // Scale entry OrderEvents to the target Portfolio volatility if configured, skipping
+        // entry whilst the realised volatility is still warming up
+        if let (Some(vol_targeter), true) = (&self.vol_targeter, order.decision.is_entry()) {
+            let Some(quantity) = vol_targeter.scale_quantity(order.quantity) else {
+                return Ok(None);
+            };
+            order.quantity = quantity;
+        }
+
         // Manage global risk when evaluating OrderEvent - keep the same, refine or cancel
-        Ok(self.risk_manager.evaluate_order(order))
+        let Some(order) = self.risk_manager.evaluate_order(order) else {
+            return Ok(None);
+        };
+
+        // Shorts of instruments that must be borrowed are limited to the available borrow
+        match self.constrain_short(order) {
+            Ok(order) => Ok(Some(order)),
+            Err(error) => {
+                info!(
+                    ?error,
+                    outcome = "no OrderEvent generated",
+                    "short entry OrderEvent refused by ShortConstraint"
+                );
+                Ok(None)
+            }
+        }
     }
 
     fn generate_exit_order(
@@ -174,7 +263,9 @@ where
         };
 
         Ok(Some(OrderEvent {
-            time: Utc::now(),
+            time: self.clock.now(),
+            cid: ClientOrderId::default(),
+            account: AccountId::default(),
             exchange: signal.exchange,
             instrument: signal.instrument,
             market_meta: MarketMeta {
@@ -182,10 +273,120 @@ where
                 time: position.meta.update_time,
             },
             decision: position.determine_exit_decision(),
-            quantity: 0.0 - position.quantity,
+            quantity: -position.quantity,
             order_type: OrderType::Market,
+            stop_price: None,
+            time_in_force: TimeInForce::default(),
+            trigger: None,
+            tags: OrderTags::default(),
         }))
     }
+
+    fn generate_manual_order(
+        &mut self,
+        request: ManualOrderRequest,
+        market_meta: Option<MarketMeta>,
+    ) -> Result<OrderEvent, PortfolioError> {
+        // Convert any quote currency notional into a quantity at the latest market price
+        let request = request.resolve_notional(market_meta.map(|market_meta| market_meta.close))?;
+
+        // Validate the requested quantity & optional limit price
+        if request.quantity <= Decimal::ZERO {
+            return Err(PortfolioError::ManualOrderRejected(
+                "quantity must be greater than zero",
+            ));
+        }
+        if let Some(limit_price) = request.limit_price {
+            if limit_price <= Decimal::ZERO {
+                return Err(PortfolioError::ManualOrderRejected(
+                    "limit price must be greater than zero",
+                ));
+            }
+        }
+        let order_type = match (request.limit_price, request.stop_price) {
+            (None, None) => OrderType::Market,
+            (Some(_), None) => OrderType::Limit,
+            (Some(_), Some(_)) => OrderType::StopLimit,
+            (None, Some(_)) => {
+                return Err(PortfolioError::ManualOrderRejected(
+                    "stop price requires a limit price",
+                ));
+            }
+        };
+
+        // Determine the position_id & associated Option<Position> related to the request
+        let position_id =
+            determine_position_id(self.engine_id, &request.exchange, &request.instrument);
+        let position = self.repository.get_open_position(&position_id)?;
+
+        // Determine the Decision & signed quantity required to action the request
+        let (decision, quantity) = match &position {
+            None if self.no_cash_to_enter_new_position()? => {
+                return Err(PortfolioError::ManualOrderRejected(
+                    "no cash available to enter a new Position",
+                ));
+            }
+            None => match request.side {
+                Side::Buy => (Decision::Long, request.quantity),
+                Side::Sell => (Decision::Short, -request.quantity),
+            },
+            Some(position) if position.side == request.side => {
+                if self.no_cash_to_enter_new_position()? {
+                    return Err(PortfolioError::ManualOrderRejected(
+                        "no cash available to increase the open Position",
+                    ));
+                }
+                match request.side {
+                    Side::Buy => (Decision::Long, request.quantity),
+                    Side::Sell => (Decision::Short, -request.quantity),
+                }
+            }
+            Some(position) if request.quantity > position.quantity.abs() => {
+                return Err(PortfolioError::ManualOrderRejected(
+                    "exit quantity exceeds the open Position quantity",
+                ));
+            }
+            // Exits the open Position in full or in part, matched against it's entry lots
+            Some(position) => match position.side {
+                Side::Buy => (position.determine_exit_decision(), -request.quantity),
+                Side::Sell => (position.determine_exit_decision(), request.quantity),
+            },
+        };
+
+        // Determine the order price: limit price, else latest known market price
+        let market_meta = match (request.limit_price, market_meta, &position) {
+            (Some(limit_price), market_meta, _) => MarketMeta {
+                close: limit_price,
+                time: market_meta.map_or_else(|| self.clock.now(), |market_meta| market_meta.time),
+            },
+            (None, Some(market_meta), _) => market_meta,
+            (None, None, Some(position)) => MarketMeta {
+                close: position.current_symbol_price,
+                time: position.meta.update_time,
+            },
+            (None, None, None) => {
+                return Err(PortfolioError::ManualOrderRejected(
+                    "no market price available to price a market order",
+                ));
+            }
+        };
+
+        self.constrain_short(OrderEvent {
+            time: self.clock.now(),
+            cid: ClientOrderId::default(),
+            account: AccountId::default(),
+            exchange: request.exchange,
+            instrument: request.instrument,
+            market_meta,
+            decision,
+            quantity,
+            order_type,
+            stop_price: request.stop_price,
+            time_in_force: request.time_in_force,
+            trigger: request.trigger,
+            tags: request.tags,
+        })
+    }
 }
 
 impl<Repository, Allocator, RiskManager, Statistic> FillUpdater
@@ -209,18 +410,67 @@ where
 
         // Determine FillEvent context based on existence or absence of an open Position
         match self.repository.remove_position(&position_id)? {
+            // INCREASE SCENARIO - entry FillEvent for Symbol-Exchange with open Position
+            Some(mut position) if fill.decision.is_entry() => {
+                // Increase Position (in place mutation), & add the PositionUpdate event to Vec<Event>
+                let position_update = position.increase(fill)?;
+                generated_events.push(Event::PositionUpdate(position_update));
+
+                // Update Portfolio Balance.available on Position increase
+                balance.available += -fill.fill_value_gross - fill.fees.calculate_total_fees();
+
+                // Persist increased Position in Repository
+                self.repository.set_open_position(position)?;
+            }
+
+            // DECREASE SCENARIO - exit FillEvent for less than the open Position quantity
+            Some(mut position) if fill.quantity.abs() < position.quantity.abs() => {
+                let enter_value_gross = position.enter_value_gross;
+                let enter_fees_total = position.enter_fees_total;
+                let realised_profit_loss = position.realised_profit_loss;
+
+                // Decrease Position (in place mutation), & add the PositionUpdate event to Vec<Event>
+                let position_update = position.decrease(fill, self.cost_basis)?;
+                generated_events.push(Event::PositionUpdate(position_update));
+
+                // Update Portfolio balance with the exited quantity, as on Position exit
+                let profit_loss = position.realised_profit_loss - realised_profit_loss;
+                balance.available += (enter_value_gross - position.enter_value_gross)
+                    + profit_loss
+                    + (enter_fees_total - position.enter_fees_total);
+                balance.total += profit_loss;
+
+                // Persist decreased Position in Repository
+                self.repository.set_open_position(position)?;
+            }
+
+            // FLIP SCENARIO - exit FillEvent for more than the open Position quantity, which exits
+            // the Position & enters the remainder in the opposite direction
+            Some(position) if fill.quantity.abs() > position.quantity.abs() => {
+                let (exit_fill, entry_fill) = split_flipping_fill(fill, position.quantity);
+
+                // Restore the open Position so it's exited by the exit portion of the FillEvent
+                self.repository.set_open_position(position)?;
+                let mut generated_events = self.update_from_fill(&exit_fill)?;
+                generated_events.extend(self.update_from_fill(&entry_fill)?);
+                return Ok(generated_events);
+            }
+
             // EXIT SCENARIO - FillEvent for Symbol-Exchange combination with open Position
             Some(mut position) => {
+                let realised_profit_loss = position.realised_profit_loss;
+
                 // Exit Position (in place mutation), & add the PositionExit event to Vec<Event>
                 let position_exit = position.exit(balance, fill)?;
                 generated_events.push(Event::PositionExit(position_exit));
 
                 // Update Portfolio balance on Position exit
                 // '--> available balance adds enter_total_fees since included in result PnL calc
-                balance.available += position.enter_value_gross
-                    + position.realised_profit_loss
-                    + position.enter_fees_total;
-                balance.total += position.realised_profit_loss;
+                // '--> excludes profit & loss realised by previous partial exits
+                let profit_loss = position.realised_profit_loss - realised_profit_loss;
+                balance.available +=
+                    position.enter_value_gross + profit_loss + position.enter_fees_total;
+                balance.total += profit_loss;
 
                 // Update statistics for exited Position market
                 let market_id = MarketId::new(&fill.exchange, &fill.instrument);
@@ -258,6 +508,118 @@ where
     }
 }
 
+impl<Repository, Allocator, RiskManager, Statistic> ProfitLossReporter
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser,
+{
+    fn realised_profit_loss(&mut self) -> Result<HashMap<Market, Decimal>, PortfolioError> {
+        let exited_positions = self.repository.get_exited_positions(self.engine_id)?;
+        Ok(sum_profit_loss_per_market(exited_positions, |position| {
+            position.realised_profit_loss
+        }))
+    }
+
+    fn unrealised_profit_loss<'a, Markets>(
+        &mut self,
+        markets: Markets,
+    ) -> Result<HashMap<Market, Decimal>, PortfolioError>
+    where
+        Markets: Iterator<Item = &'a Market>,
+    {
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, markets)?;
+        Ok(sum_profit_loss_per_market(open_positions, |position| {
+            position.unrealised_profit_loss
+        }))
+    }
+}
+
+impl<Repository, Allocator, RiskManager, Statistic> ExposureReporter
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser,
+{
+    fn exposure_report<'a, Markets>(
+        &mut self,
+        markets: Markets,
+    ) -> Result<ExposureReport, PortfolioError>
+    where
+        Markets: Iterator<Item = &'a Market>,
+    {
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, markets)?;
+        Ok(match &self.conversion_rates {
+            Some(rates) => ExposureReport::converted(self.clock.now(), &open_positions, rates),
+            None => ExposureReport::new(self.clock.now(), &open_positions),
+        })
+    }
+}
+
+/// Splits a [`FillEvent`] flipping an open Position of the provided quantity into the exit
+/// [`FillEvent`] closing it & the entry [`FillEvent`] of the remainder in the opposite direction.
+/// The fill value & fees are pro-rated by quantity.
+fn split_flipping_fill(fill: &FillEvent, position_quantity: Decimal) -> (FillEvent, FillEvent) {
+    let exit_quantity = -position_quantity;
+    let pro_rate = |value: Decimal| value * exit_quantity / fill.quantity;
+    let exit_fees = Fees {
+        exchange: pro_rate(fill.fees.exchange),
+        slippage: pro_rate(fill.fees.slippage),
+        network: pro_rate(fill.fees.network),
+    };
+
+    let exit = FillEvent {
+        quantity: exit_quantity,
+        fill_value_gross: pro_rate(fill.fill_value_gross),
+        fees: exit_fees,
+        ..fill.clone()
+    };
+    let entry = FillEvent {
+        decision: match fill.decision {
+            Decision::CloseLong => Decision::Short,
+            _ => Decision::Long,
+        },
+        quantity: fill.quantity - exit_quantity,
+        fill_value_gross: fill.fill_value_gross - exit.fill_value_gross,
+        fees: Fees {
+            exchange: fill.fees.exchange - exit_fees.exchange,
+            slippage: fill.fees.slippage - exit_fees.slippage,
+            network: fill.fees.network - exit_fees.network,
+        },
+        ..fill.clone()
+    };
+    (exit, entry)
+}
+
+/// Sums the profit & loss extracted from each [`Position`] per [`Market`].
+fn sum_profit_loss_per_market<F>(
+    positions: Vec<Position>,
+    profit_loss: F,
+) -> HashMap<Market, Decimal>
+where
+    F: Fn(&Position) -> Decimal,
+{
+    positions
+        .into_iter()
+        .fold(HashMap::new(), |mut profit_loss_per_market, position| {
+            *profit_loss_per_market
+                .entry(Market::new(
+                    position.exchange.clone(),
+                    position.instrument.clone(),
+                ))
+                .or_default() += profit_loss(&position);
+            profit_loss_per_market
+        })
+}
+
 impl<Repository, Allocator, RiskManager, Statistic> PositionHandler
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
@@ -302,6 +664,23 @@ where
     }
 }
 
+impl<Repository, Allocator, RiskManager, Statistic> BalanceHandler
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser,
+{
+    fn set_balance(&mut self, _: Uuid, balance: Balance) -> Result<(), RepositoryError> {
+        self.repository.set_balance(self.engine_id, balance)
+    }
+
+    fn get_balance(&mut self, _: Uuid) -> Result<Balance, RepositoryError> {
+        self.repository.get_balance(self.engine_id)
+    }
+}
+
 impl<Repository, Allocator, RiskManager, Statistic> StatisticHandler<Statistic>
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
@@ -342,7 +721,14 @@ where
             repository: lego.repository,
             allocation_manager: lego.allocator,
             risk_manager: lego.risk,
-            _statistic_marker: PhantomData::default(),
+            conversion_rates: None,
+            consolidated_book: None,
+            position_sizer: None,
+            vol_targeter: None,
+            short_constraints: HashMap::new(),
+            cost_basis: CostBasis::default(),
+            clock: Arc::new(LiveClock),
+            _statistic_marker: PhantomData,
         };
 
         // Persist initial state in the repository
@@ -355,7 +741,7 @@ where
     /// Statistics every market provided, as well as starting `AvailableCash` & `TotalEquity`.
     pub fn bootstrap_repository<Markets, Id>(
         &mut self,
-        starting_cash: f64,
+        starting_cash: Decimal,
         markets: Markets,
         statistic_config: Statistic::Config,
     ) -> Result<(), PortfolioError>
@@ -367,7 +753,7 @@ where
         self.repository.set_balance(
             self.engine_id,
             Balance {
-                time: Utc::now(),
+                time: self.clock.now(),
                 total: starting_cash,
                 available: starting_cash,
             },
@@ -386,11 +772,100 @@ where
         MetaPortfolioBuilder::new()
     }
 
+    /// Returns the [`ConversionRates`] of this [`MetaPortfolio`], if configured.
+    pub fn conversion_rates(&self) -> Option<&ConversionRates> {
+        self.conversion_rates.as_ref()
+    }
+
+    /// Returns a mutable reference to the [`ConversionRates`] of this [`MetaPortfolio`], if
+    /// configured, so rates can be injected (eg/ from a separate FX feed).
+    pub fn conversion_rates_mut(&mut self) -> Option<&mut ConversionRates> {
+        self.conversion_rates.as_mut()
+    }
+
+    /// Returns the [`ConsolidatedBook`] of this [`MetaPortfolio`], if configured.
+    pub fn consolidated_book(&self) -> Option<&ConsolidatedBook> {
+        self.consolidated_book.as_ref()
+    }
+
+    /// Returns the equity of this [`MetaPortfolio`] converted into the base currency of it's
+    /// [`ConversionRates`], or `None` if not configured. See
+    /// [`PortfolioSnapshot::converted_equity`].
+    pub fn converted_equity<'a, Markets>(
+        &mut self,
+        markets: Markets,
+    ) -> Result<Option<Converted>, PortfolioError>
+    where
+        Markets: Iterator<Item = &'a Market>,
+    {
+        let Some(rates) = &self.conversion_rates else {
+            return Ok(None);
+        };
+
+        let balance = self.repository.get_balance(self.engine_id)?;
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, markets)?;
+        Ok(Some(
+            PortfolioSnapshot::new(self.clock.now(), balance, open_positions)
+                .converted_equity(rates),
+        ))
+    }
+
+    /// Returns the [`CostBasis`] used to match partial exits against the entry lots of a
+    /// [`Position`].
+    pub fn cost_basis(&self) -> CostBasis {
+        self.cost_basis
+    }
+
+    /// Returns a mutable reference to the [`ShortConstraint`]s of this [`MetaPortfolio`], so
+    /// the available borrow of an instrument can be updated (eg/ from a broker borrow feed).
+    pub fn short_constraints_mut(&mut self) -> &mut HashMap<Instrument, ShortConstraint> {
+        &mut self.short_constraints
+    }
+
+    /// Returns the [`PortfolioVolTargeter`] of this [`MetaPortfolio`], if configured.
+    pub fn vol_targeter(&self) -> Option<&PortfolioVolTargeter> {
+        self.vol_targeter.as_ref()
+    }
+
+    /// Samples the Portfolio equity (the [`Balance`] total plus the unrealised P&L of the open
+    /// Positions) for the [`PortfolioVolTargeter`], if configured & a sample is due.
+    fn sample_equity(&mut self, market: &MarketEvent<DataKind>) -> Result<(), PortfolioError> {
+        let Some(vol_targeter) = &mut self.vol_targeter else {
+            return Ok(());
+        };
+        if !vol_targeter.update_from_market(market) {
+            return Ok(());
+        }
+
+        let balance = self.repository.get_balance(self.engine_id)?;
+        let unrealised_profit_loss = self
+            .repository
+            .get_open_positions(self.engine_id, vol_targeter.markets())?
+            .iter()
+            .map(|position| position.unrealised_profit_loss)
+            .sum::<Decimal>();
+        vol_targeter.sample(
+            market.exchange_time,
+            decimal_to_f64(balance.total + unrealised_profit_loss),
+        );
+        Ok(())
+    }
+
+    /// Constrains the [`OrderEvent`] to the [`ShortConstraint`] of it's instrument, if any.
+    fn constrain_short(&self, order: OrderEvent) -> Result<OrderEvent, PortfolioError> {
+        match self.short_constraints.get(&order.instrument) {
+            Some(constraint) => constraint.constrain_order(order),
+            None => Ok(order),
+        }
+    }
+
     /// Determines if the Portfolio has any cash to enter a new [`Position`].
     fn no_cash_to_enter_new_position(&mut self) -> Result<bool, PortfolioError> {
         self.repository
             .get_balance(self.engine_id)
-            .map(|balance| balance.available == 0.0)
+            .map(|balance| balance.available.is_zero())
             .map_err(PortfolioError::RepositoryInteraction)
     }
 }
@@ -405,11 +880,18 @@ where
 {
     engine_id: Option<Uuid>,
     markets: Option<Vec<Market>>,
-    starting_cash: Option<f64>,
+    starting_cash: Option<Decimal>,
     repository: Option<Repository>,
     allocation_manager: Option<Allocator>,
     risk_manager: Option<RiskManager>,
     statistic_config: Option<Statistic::Config>,
+    conversion_rates: Option<ConversionRates>,
+    consolidated_book: Option<ConsolidatedBook>,
+    position_sizer: Option<Box<dyn PositionSizer + Send>>,
+    vol_targeter: Option<PortfolioVolTargeter>,
+    short_constraints: HashMap<Instrument, ShortConstraint>,
+    cost_basis: Option<CostBasis>,
+    clock: Option<Arc<dyn Clock + Send + Sync>>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
 
@@ -430,6 +912,13 @@ where
             allocation_manager: None,
             risk_manager: None,
             statistic_config: None,
+            conversion_rates: None,
+            consolidated_book: None,
+            position_sizer: None,
+            vol_targeter: None,
+            short_constraints: HashMap::new(),
+            cost_basis: None,
+            clock: None,
             _statistic_marker: None,
         }
     }
@@ -448,7 +937,7 @@ where
         }
     }
 
-    pub fn starting_cash(self, value: f64) -> Self {
+    pub fn starting_cash(self, value: Decimal) -> Self {
         Self {
             starting_cash: Some(value),
             ..self
@@ -483,6 +972,77 @@ where
         }
     }
 
+    /// Optional [`ConversionRates`] used to convert the equity & exposure of Positions quoted
+    /// in different currencies into it's base currency.
+    pub fn conversion_rates(self, value: ConversionRates) -> Self {
+        Self {
+            conversion_rates: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`ConsolidatedBook`] maintained from every [`MarketEvent`], consolidating the
+    /// best bid & ask of each instrument across the venues quoting it.
+    pub fn consolidated_book(self, value: ConsolidatedBook) -> Self {
+        Self {
+            consolidated_book: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`PositionSizer`] sizing every entry [`OrderEvent`] from the [`Signal`]
+    /// strength, the Portfolio equity (the [`Balance`] total) & the market price, in place of
+    /// the allocation manager. Exits are still sized by the allocation manager.
+    pub fn position_sizer<Sizer>(self, value: Sizer) -> Self
+    where
+        Sizer: PositionSizer + Send + 'static,
+    {
+        Self {
+            position_sizer: Some(Box::new(value)),
+            ..self
+        }
+    }
+
+    /// Optional [`PortfolioVolTargeter`] scaling every entry [`OrderEvent`] by the ratio of the
+    /// target volatility to the realised volatility of the Portfolio equity, after it is sized
+    /// by the allocation manager or [`PositionSizer`].
+    pub fn vol_targeter(self, value: PortfolioVolTargeter) -> Self {
+        Self {
+            vol_targeter: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`ShortConstraint`] of an instrument that must be borrowed to be shorted. Short
+    /// entries of the instrument are refused if it is not shortable, and downsized to the
+    /// available borrow.
+    pub fn short_constraint<I>(mut self, instrument: I, value: ShortConstraint) -> Self
+    where
+        I: Into<Instrument>,
+    {
+        self.short_constraints.insert(instrument.into(), value);
+        self
+    }
+
+    /// Optional [`CostBasis`] used to match partial exits against the entry lots of a
+    /// [`Position`] that was scaled into, defaulting to [`CostBasis::Fifo`].
+    pub fn cost_basis(self, value: CostBasis) -> Self {
+        Self {
+            cost_basis: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional [`Clock`] used to timestamp [`OrderEvent`]s & [`Balance`]s, defaults to a
+    /// [`LiveClock`]. Backtests should share the [`SimulatedClock`](crate::clock::SimulatedClock)
+    /// of the Traders.
+    pub fn clock(self, value: Arc<dyn Clock + Send + Sync>) -> Self {
+        Self {
+            clock: Some(value),
+            ..self
+        }
+    }
+
     pub fn build_and_init(
         self,
     ) -> Result<MetaPortfolio<Repository, Allocator, RiskManager, Statistic>, PortfolioError> {
@@ -500,7 +1060,14 @@ where
             risk_manager: self
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
-            _statistic_marker: PhantomData::default(),
+            conversion_rates: self.conversion_rates,
+            consolidated_book: self.consolidated_book,
+            position_sizer: self.position_sizer,
+            vol_targeter: self.vol_targeter,
+            short_constraints: self.short_constraints,
+            cost_basis: self.cost_basis.unwrap_or_default(),
+            clock: self.clock.unwrap_or_else(|| Arc::new(LiveClock)),
+            _statistic_marker: PhantomData,
         };
 
         // Persist initial state in the Repository
@@ -548,14 +1115,19 @@ pub fn parse_signal_decisions<'a>(
 }
 
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 pub mod tests {
     use super::*;
 
     use crate::{
         execution::Fees,
         portfolio::{
-            allocator::DefaultAllocator, position::PositionBuilder,
-            repository::error::RepositoryError, risk::DefaultRisk,
+            allocator::DefaultAllocator,
+            decimal_from_f64, decimal_to_f64,
+            position::PositionBuilder,
+            repository::{error::RepositoryError, in_memory::InMemoryRepository},
+            risk::DefaultRisk,
+            sizer::{PercentOfEquity, PortfolioVolTargeter},
         },
         statistic::summary::pnl::PnLReturnSummary,
         strategy::SignalForceExit,
@@ -565,6 +1137,8 @@ pub mod tests {
         instrument::{kind::InstrumentKind, Instrument},
         Exchange, Side,
     };
+    use chrono::Utc;
+    use rust_decimal::RoundingStrategy;
 
     #[derive(Default)]
     struct MockRepository<Statistic> {
@@ -592,7 +1166,7 @@ pub mod tests {
         fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
             self.position = Some(
                 Position::builder()
-                    .side(position.side.clone())
+                    .side(position.side)
                     .current_symbol_price(position.current_symbol_price)
                     .current_value_gross(position.current_value_gross)
                     .enter_fees_total(position.enter_fees_total)
@@ -683,10 +1257,10 @@ pub mod tests {
     {
         let builder = MetaPortfolio::builder()
             .engine_id(Uuid::new_v4())
-            .starting_cash(1000.0)
+            .starting_cash(Decimal::ONE_THOUSAND)
             .repository(mock_repository)
             .allocation_manager(DefaultAllocator {
-                default_order_value: 100.0,
+                default_order_value: Decimal::ONE_HUNDRED,
             })
             .risk_manager(DefaultRisk {});
 
@@ -713,6 +1287,13 @@ pub mod tests {
             risk_manager: builder
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            conversion_rates: None,
+            consolidated_book: None,
+            position_sizer: None,
+            vol_targeter: None,
+            short_constraints: HashMap::new(),
+            cost_basis: CostBasis::default(),
+            clock: builder.clock.unwrap_or_else(|| Arc::new(LiveClock)),
             _statistic_marker: Default::default(),
         })
     }
@@ -725,6 +1306,122 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn realised_profit_loss_sums_exited_positions_per_market() {
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_exited_positions = Some(|_| {
+            Ok(vec![
+                Position {
+                    realised_profit_loss: Decimal::TEN,
+                    ..position()
+                },
+                Position {
+                    realised_profit_loss: Decimal::from(-4),
+                    ..position()
+                },
+                Position {
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    realised_profit_loss: Decimal::from(3),
+                    ..position()
+                },
+            ])
+        });
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        let actual = portfolio.realised_profit_loss().unwrap();
+
+        let expected = HashMap::from([
+            (
+                Market::new("binance", ("eth", "usdt", InstrumentKind::Spot)),
+                Decimal::from(6),
+            ),
+            (
+                Market::new("binance", ("btc", "usdt", InstrumentKind::Spot)),
+                Decimal::from(3),
+            ),
+        ]);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unrealised_profit_loss_reports_open_positions_per_market() {
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_positions = Some(|_, _| {
+            Ok(vec![Position {
+                unrealised_profit_loss: Decimal::from(25),
+                ..position()
+            }])
+        });
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        let markets = [
+            Market::new("binance", ("eth", "usdt", InstrumentKind::Spot)),
+            Market::new("binance", ("btc", "usdt", InstrumentKind::Spot)),
+        ];
+
+        let actual = portfolio.unrealised_profit_loss(markets.iter()).unwrap();
+
+        let expected = HashMap::from([(markets[0].clone(), Decimal::from(25))]);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn converted_equity_sums_positions_of_every_quote_currency_in_the_base_currency() {
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance::new(
+                Utc::now(),
+                Decimal::ONE_THOUSAND,
+                Decimal::from(800),
+            ))
+        });
+        mock_repository.get_open_positions = Some(|_, _| {
+            Ok(vec![
+                Position {
+                    unrealised_profit_loss: Decimal::TEN,
+                    ..position()
+                },
+                Position {
+                    instrument: Instrument::from(("eth", "eur", InstrumentKind::Spot)),
+                    unrealised_profit_loss: Decimal::TEN,
+                    current_value_gross: Decimal::ONE_HUNDRED,
+                    ..position()
+                },
+            ])
+        });
+        let mut portfolio = MetaPortfolio {
+            conversion_rates: Some(
+                ConversionRates::new("usdt").with_rate("eur", Decimal::new(11, 1)),
+            ),
+            ..new_mocked_portfolio(mock_repository).unwrap()
+        };
+        let markets = [
+            Market::new("binance", ("eth", "usdt", InstrumentKind::Spot)),
+            Market::new("binance", ("eth", "eur", InstrumentKind::Spot)),
+        ];
+
+        // 1000.0 usdt Balance + 10.0 usdt + (10.0 eur * 1.1)
+        let equity = portfolio.converted_equity(markets.iter()).unwrap().unwrap();
+        assert_eq!(equity.value, Decimal::from(1021));
+        assert!(!equity.is_partial());
+
+        let exposure = portfolio.exposure_report(markets.iter()).unwrap();
+        assert_eq!(exposure.total.gross, Decimal::from(210));
+        assert!(exposure.missing_rates.is_empty());
+
+        // Without a eur rate the eth_eur Position is excluded & flagged
+        portfolio.conversion_rates = Some(ConversionRates::new("usdt"));
+        let equity = portfolio.converted_equity(markets.iter()).unwrap().unwrap();
+        assert_eq!(equity.value, Decimal::from(1010));
+        assert!(equity.is_partial());
+        let exposure = portfolio.exposure_report(markets.iter()).unwrap();
+        assert_eq!(exposure.total.gross, Decimal::ONE_HUNDRED);
+        assert!(exposure.is_partial());
+        assert!(exposure.missing_rates.contains(&"eur".into()));
+    }
+
     #[test]
     fn update_from_market_with_long_position_increasing_in_value() {
         // Build Portfolio
@@ -733,11 +1430,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Buy;
-                input_position.quantity = 1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = Decimal::ONE;
+                input_position.enter_fees_total = Decimal::from(3);
+                input_position.current_symbol_price = Decimal::ONE_HUNDRED;
+                input_position.current_value_gross = Decimal::ONE_HUNDRED;
+                input_position.unrealised_profit_loss = Decimal::from(-3); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -760,17 +1457,23 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 200.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 200.0);
+        assert_eq!(
+            updated_position.current_symbol_price.unwrap(),
+            Decimal::from(200)
+        );
+        assert_eq!(
+            updated_position.current_value_gross.unwrap(),
+            Decimal::from(200)
+        );
 
         // Unreal PnL Long = current_value_gross - enter_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            200.0 - 100.0 - 6.0
+            Decimal::from(200 - 100 - 6)
         );
         assert_eq!(
             result_pos_update.unrealised_profit_loss,
-            200.0 - 100.0 - 6.0
+            Decimal::from(200 - 100 - 6)
         );
     }
 
@@ -782,11 +1485,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Buy;
-                input_position.quantity = 1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = Decimal::ONE;
+                input_position.enter_fees_total = Decimal::from(3);
+                input_position.current_symbol_price = Decimal::ONE_HUNDRED;
+                input_position.current_value_gross = Decimal::ONE_HUNDRED;
+                input_position.unrealised_profit_loss = Decimal::from(-3); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -808,14 +1511,23 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 50.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 50.0);
+        assert_eq!(
+            updated_position.current_symbol_price.unwrap(),
+            Decimal::from(50)
+        );
+        assert_eq!(
+            updated_position.current_value_gross.unwrap(),
+            Decimal::from(50)
+        );
         // Unreal PnL Long = current_value_gross - enter_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            50.0 - 100.0 - 6.0
+            Decimal::from(50 - 100 - 6)
+        );
+        assert_eq!(
+            result_pos_update.unrealised_profit_loss,
+            Decimal::from(50 - 100 - 6)
         );
-        assert_eq!(result_pos_update.unrealised_profit_loss, 50.0 - 100.0 - 6.0);
     }
 
     #[test]
@@ -826,11 +1538,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Sell;
-                input_position.quantity = -1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = Decimal::NEGATIVE_ONE;
+                input_position.enter_fees_total = Decimal::from(3);
+                input_position.current_symbol_price = Decimal::ONE_HUNDRED;
+                input_position.current_value_gross = Decimal::ONE_HUNDRED;
+                input_position.unrealised_profit_loss = Decimal::from(-3); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -853,14 +1565,23 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 50.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 50.0);
+        assert_eq!(
+            updated_position.current_symbol_price.unwrap(),
+            Decimal::from(50)
+        );
+        assert_eq!(
+            updated_position.current_value_gross.unwrap(),
+            Decimal::from(50)
+        );
         // Unreal PnL Short = enter_value_gross - current_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            100.0 - 50.0 - 6.0
+            Decimal::from(100 - 50 - 6)
+        );
+        assert_eq!(
+            result_pos_update.unrealised_profit_loss,
+            Decimal::from(100 - 50 - 6)
         );
-        assert_eq!(result_pos_update.unrealised_profit_loss, 100.0 - 50.0 - 6.0);
     }
 
     #[test]
@@ -871,11 +1592,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Sell;
-                input_position.quantity = -1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = Decimal::NEGATIVE_ONE;
+                input_position.enter_fees_total = Decimal::from(3);
+                input_position.current_symbol_price = Decimal::ONE_HUNDRED;
+                input_position.current_value_gross = Decimal::ONE_HUNDRED;
+                input_position.unrealised_profit_loss = Decimal::from(-3); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -898,16 +1619,22 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 200.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 200.0);
+        assert_eq!(
+            updated_position.current_symbol_price.unwrap(),
+            Decimal::from(200)
+        );
+        assert_eq!(
+            updated_position.current_value_gross.unwrap(),
+            Decimal::from(200)
+        );
         // Unreal PnL Short = enter_value_gross - current_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            100.0 - 200.0 - 6.0
+            Decimal::from(100 - 200 - 6)
         );
         assert_eq!(
             result_pos_update.unrealised_profit_loss,
-            100.0 - 200.0 - 6.0
+            Decimal::from(100 - 200 - 6)
         );
     }
 
@@ -919,8 +1646,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 0.0,
+                total: Decimal::ONE_HUNDRED,
+                available: Decimal::ZERO,
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -941,8 +1668,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 0.0,
+                total: Decimal::ONE_HUNDRED,
+                available: Decimal::ZERO,
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -963,8 +1690,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: Decimal::ONE_HUNDRED,
+                available: Decimal::ONE_HUNDRED,
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -988,8 +1715,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: Decimal::ONE_HUNDRED,
+                available: Decimal::ONE_HUNDRED,
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -1006,6 +1733,161 @@ pub mod tests {
         assert_eq!(actual.decision, Decision::Short)
     }
 
+    #[test]
+    fn generate_order_sized_by_position_sizer_from_the_portfolio_equity() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_position = Some(|_| Ok(None));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: Decimal::from(10_000),
+                available: Decimal::from(10_000),
+            })
+        });
+        let mut portfolio = MetaPortfolio {
+            position_sizer: Some(Box::new(PercentOfEquity { percent: 0.1 })),
+            ..new_mocked_portfolio(mock_repository).unwrap()
+        };
+
+        // Input SignalEvent at the default 100.0 close
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::Short, SignalStrength(0.5));
+
+        // 10% of 10_000.0 equity per unit of SignalStrength, at 100.0 per unit
+        let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        assert_eq!(actual.quantity, Decimal::new(-5, 0));
+    }
+
+    #[test]
+    fn generate_order_scaled_down_by_vol_targeter_as_realised_volatility_rises() {
+        // Build Portfolio targeting 1% volatility per minute over a window of 3 equity returns
+        let engine_id = Uuid::new_v4();
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![Market::new(signal().exchange, signal().instrument)])
+            .starting_cash(Decimal::from(10_000))
+            .repository(InMemoryRepository::<PnLReturnSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: Decimal::from(10_000),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .vol_targeter(
+                PortfolioVolTargeter::new(0.01, 3, chrono::Duration::minutes(1))
+                    .with_max_leverage(10.0),
+            )
+            .build_and_init()
+            .unwrap();
+
+        // Samples the equity at the provided minute, & any time within it is not sampled again
+        let start = Utc::now();
+        let sample = |portfolio: &mut MetaPortfolio<_, _, _, _>, minute: i64, equity| {
+            let balance = Balance {
+                time: Utc::now(),
+                total: equity,
+                available: equity,
+            };
+            portfolio.set_balance(engine_id, balance).unwrap();
+            for seconds in [0, 30] {
+                let mut market = market_event_trade(Side::Buy);
+                market.exchange_time = start + chrono::Duration::seconds(minute * 60 + seconds);
+                portfolio.update_from_market(&market).unwrap();
+            }
+        };
+
+        // Allocator sizes 10_000.0 / 100.0 = 100.0 units before scaling
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::Long, SignalStrength(1.0));
+
+        // Alternating +/-1% equity returns, with no order until a full window is sampled
+        for (minute, equity) in [
+            (0, Decimal::from(10000)),
+            (1, Decimal::from(10100)),
+            (2, Decimal::from(9999)),
+        ] {
+            sample(&mut portfolio, minute, equity);
+            assert!(portfolio.generate_order(&input_signal).unwrap().is_none());
+        }
+        sample(&mut portfolio, 3, Decimal::new(1009899, 2));
+        let calm_volatility = portfolio
+            .vol_targeter()
+            .unwrap()
+            .realised_volatility()
+            .unwrap();
+        let calm = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        assert_eq!(
+            calm.quantity,
+            (Decimal::ONE_HUNDRED * decimal_from_f64(0.01 / calm_volatility))
+                .round_dp_with_strategy(4, RoundingStrategy::ToZero)
+        );
+
+        // Alternating +/-4% equity returns quadruple the realised volatility
+        for (minute, equity) in [
+            (4, Decimal::new(96950304, 4)),
+            (5, Decimal::new(10082831616, 6)),
+            (6, Decimal::new(967951835136, 8)),
+        ] {
+            sample(&mut portfolio, minute, equity);
+        }
+        let volatile_volatility = portfolio
+            .vol_targeter()
+            .unwrap()
+            .realised_volatility()
+            .unwrap();
+        assert!((volatile_volatility / calm_volatility - 4.0).abs() < 1e-6);
+
+        // Subsequent order sizes shrink in proportion to the rise in realised volatility
+        let volatile = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        let ratio = decimal_to_f64(volatile.quantity) / decimal_to_f64(calm.quantity);
+        assert!((ratio - calm_volatility / volatile_volatility).abs() < 1e-3);
+    }
+
+    #[test]
+    fn generate_order_short_constrained_to_available_borrow() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_position = Some(|_| Ok(None));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: Decimal::from(10_000),
+                available: Decimal::from(10_000),
+            })
+        });
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::Short, SignalStrength(1.0));
+
+        // Short of 1.0 exceeding the available borrow is downsized
+        portfolio.short_constraints_mut().insert(
+            input_signal.instrument.clone(),
+            ShortConstraint {
+                shortable: true,
+                borrow_available: Decimal::new(25, 2),
+            },
+        );
+        let actual = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        assert_eq!(actual.quantity, Decimal::new(-25, 2));
+
+        // Short of a non-shortable instrument is refused
+        portfolio.short_constraints_mut().insert(
+            input_signal.instrument.clone(),
+            ShortConstraint {
+                shortable: false,
+                borrow_available: Decimal::new(25, 2),
+            },
+        );
+        assert!(portfolio.generate_order(&input_signal).unwrap().is_none());
+    }
+
     #[test]
     fn generate_order_close_long_with_long_position_and_input_net_close_long_signal() {
         // Build Portfolio
@@ -1020,8 +1902,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: Decimal::ONE_HUNDRED,
+                available: Decimal::ONE_HUNDRED,
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -1052,8 +1934,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: Decimal::ONE_HUNDRED,
+                available: Decimal::ONE_HUNDRED,
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -1078,7 +1960,7 @@ pub mod tests {
             Ok(Some({
                 let mut position = position();
                 position.side = Side::Buy;
-                position.quantity = 100.0;
+                position.quantity = Decimal::ONE_HUNDRED;
                 position
             }))
         });
@@ -1094,7 +1976,7 @@ pub mod tests {
             .unwrap();
 
         assert_eq!(actual.decision, Decision::CloseLong);
-        assert_eq!(actual.quantity, -100.0);
+        assert_eq!(actual.quantity, -Decimal::ONE_HUNDRED);
         assert_eq!(actual.order_type, OrderType::Market)
     }
 
@@ -1106,7 +1988,7 @@ pub mod tests {
             Ok(Some({
                 let mut position = position();
                 position.side = Side::Sell;
-                position.quantity = -100.0;
+                position.quantity = -Decimal::ONE_HUNDRED;
                 position
             }))
         });
@@ -1122,7 +2004,7 @@ pub mod tests {
             .unwrap();
 
         assert_eq!(actual.decision, Decision::CloseShort);
-        assert_eq!(actual.quantity, 100.0);
+        assert_eq!(actual.quantity, Decimal::ONE_HUNDRED);
         assert_eq!(actual.order_type, OrderType::Market)
     }
 
@@ -1148,8 +2030,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 200.0,
+                total: Decimal::from(200),
+                available: Decimal::from(200),
             })
         });
         mock_repository.remove_position = Some(|_| Ok(None));
@@ -1160,12 +2042,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = Decimal::ONE;
+        input_fill.fill_value_gross = Decimal::ONE_HUNDRED;
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1175,9 +2057,12 @@ pub mod tests {
 
         assert!(result.is_ok());
         assert_eq!(entered_position.side.unwrap(), Side::Buy);
-        assert_eq!(entered_position.enter_value_gross.unwrap(), 100.0);
-        assert_eq!(entered_position.enter_fees_total.unwrap(), 3.0);
-        assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
+        assert_eq!(
+            entered_position.enter_value_gross.unwrap(),
+            Decimal::ONE_HUNDRED
+        );
+        assert_eq!(entered_position.enter_fees_total.unwrap(), Decimal::from(3));
+        assert_eq!(updated_cash, Decimal::from(200 - 100 - 3)); // cash += enter_value_gross - enter_fees
     }
 
     #[test]
@@ -1187,8 +2072,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 200.0,
+                total: Decimal::from(200),
+                available: Decimal::from(200),
             })
         });
         mock_repository.remove_position = Some(|_| Ok(None));
@@ -1199,12 +2084,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = Decimal::NEGATIVE_ONE;
+        input_fill.fill_value_gross = Decimal::ONE_HUNDRED;
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1214,9 +2099,12 @@ pub mod tests {
 
         assert!(result.is_ok());
         assert_eq!(entered_position.side.unwrap(), Side::Sell);
-        assert_eq!(entered_position.enter_value_gross.unwrap(), 100.0);
-        assert_eq!(entered_position.enter_fees_total.unwrap(), 3.0);
-        assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
+        assert_eq!(
+            entered_position.enter_value_gross.unwrap(),
+            Decimal::ONE_HUNDRED
+        );
+        assert_eq!(entered_position.enter_fees_total.unwrap(), Decimal::from(3));
+        assert_eq!(updated_cash, Decimal::from(200 - 100 - 3)); // cash += enter_value_gross - enter_fees
     }
 
     #[test]
@@ -1226,8 +2114,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: Decimal::from(200),
+                available: Decimal::from(97),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1235,9 +2123,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Buy;
-                    input_position.quantity = 1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = Decimal::ONE;
+                    input_position.enter_fees_total = Decimal::from(3);
+                    input_position.enter_value_gross = Decimal::ONE_HUNDRED;
                     input_position
                 })
             })
@@ -1251,12 +2139,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.quantity = Decimal::NEGATIVE_ONE;
+        input_fill.fill_value_gross = Decimal::from(200);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1267,9 +2155,12 @@ pub mod tests {
         assert!(result.is_ok());
         // LONG result_profit_loss = exit_value_gross - enter_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (200.0 - 100.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            Decimal::from((97 + 100) + (200 - 100 - 6) + 3)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (200.0 - 100.0 - 6.0));
+        assert_eq!(updated_value, Decimal::from(200 + (200 - 100 - 6)));
     }
 
     #[test]
@@ -1279,8 +2170,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: Decimal::from(200),
+                available: Decimal::from(97),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1288,9 +2179,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Buy;
-                    input_position.quantity = 1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = Decimal::ONE;
+                    input_position.enter_fees_total = Decimal::from(3);
+                    input_position.enter_value_gross = Decimal::ONE_HUNDRED;
                     input_position
                 })
             })
@@ -1304,12 +2195,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.quantity = Decimal::NEGATIVE_ONE;
+        input_fill.fill_value_gross = Decimal::from(50);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1320,9 +2211,9 @@ pub mod tests {
         assert!(result.is_ok());
         // LONG result_profit_loss = exit_value_gross - enter_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (50.0 - 100.0 - 6.0) + 3.0);
+        assert_eq!(updated_cash, Decimal::from((97 + 100) + (50 - 100 - 6) + 3));
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (50.0 - 100.0 - 6.0));
+        assert_eq!(updated_value, Decimal::from(200 + (50 - 100 - 6)));
     }
 
     #[test]
@@ -1332,8 +2223,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: Decimal::from(200),
+                available: Decimal::from(97),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1341,9 +2232,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Sell;
-                    input_position.quantity = -1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = Decimal::NEGATIVE_ONE;
+                    input_position.enter_fees_total = Decimal::from(3);
+                    input_position.enter_value_gross = Decimal::ONE_HUNDRED;
                     input_position
                 })
             })
@@ -1357,12 +2248,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.quantity = Decimal::ONE;
+        input_fill.fill_value_gross = Decimal::from(50);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1373,9 +2264,9 @@ pub mod tests {
         assert!(result.is_ok());
         // SHORT result_profit_loss = enter_value_gross - exit_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (100.0 - 50.0 - 6.0) + 3.0);
+        assert_eq!(updated_cash, Decimal::from((97 + 100) + (100 - 50 - 6) + 3));
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (100.0 - 50.0 - 6.0));
+        assert_eq!(updated_value, Decimal::from(200 + (100 - 50 - 6)));
     }
 
     #[test]
@@ -1385,8 +2276,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: Decimal::from(200),
+                available: Decimal::from(97),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1394,9 +2285,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Sell;
-                    input_position.quantity = -1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = Decimal::NEGATIVE_ONE;
+                    input_position.enter_fees_total = Decimal::from(3);
+                    input_position.enter_value_gross = Decimal::ONE_HUNDRED;
                     input_position
                 })
             })
@@ -1410,12 +2301,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 150.0;
+        input_fill.quantity = Decimal::ONE;
+        input_fill.fill_value_gross = Decimal::from(150);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1426,9 +2317,291 @@ pub mod tests {
         assert!(result.is_ok());
         // SHORT result_profit_loss = enter_value_gross - exit_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (100.0 - 150.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            Decimal::from((97 + 100) + (100 - 150 - 6) + 3)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (100.0 - 150.0 - 6.0));
+        assert_eq!(updated_value, Decimal::from(200 + (100 - 150 - 6)));
+    }
+
+    #[test]
+    fn update_from_fill_accumulating_fractional_fills_should_close_position_to_exactly_zero() {
+        // Build Portfolio
+        let engine_id = Uuid::new_v4();
+        let market = Market::new(fill_event().exchange, fill_event().instrument);
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(Decimal::from(10_000))
+            .repository(InMemoryRepository::<PnLReturnSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: Decimal::ONE_HUNDRED,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .build_and_init()
+            .unwrap();
+
+        let fill = |decision, quantity: Decimal| FillEvent {
+            decision,
+            quantity,
+            fill_value_gross: Decimal::ONE_HUNDRED * quantity.abs(),
+            fees: Fees::default(),
+            ..fill_event()
+        };
+
+        // Enter a Position with 1000 partial fills of 0.001
+        let partial = Decimal::new(1, 3);
+        for _ in 0..1000 {
+            portfolio
+                .update_from_fill(&fill(Decision::Long, partial))
+                .unwrap();
+        }
+
+        // Summing the same partial fills as f64 accumulates rounding error
+        assert_ne!((0..1000).map(|_| 0.001_f64).sum::<f64>(), 1.0);
+
+        let position_id = determine_position_id(engine_id, &market.exchange, &market.instrument);
+        let open_position = portfolio.get_open_position(&position_id).unwrap().unwrap();
+        assert_eq!(open_position.quantity, Decimal::ONE);
+
+        // Close the Position with a single fill of the exact accumulated quantity
+        let close = fill(Decision::CloseLong, -open_position.quantity);
+        portfolio.update_from_fill(&close).unwrap();
+
+        let exited = portfolio.get_exited_positions(engine_id).unwrap();
+        assert_eq!(exited.len(), 1);
+        assert_eq!(exited[0].quantity + close.quantity, Decimal::ZERO);
+        assert!(portfolio.get_open_position(&position_id).unwrap().is_none());
+    }
+
+    #[test]
+    fn update_from_fill_partial_exit_realised_profit_loss_should_depend_on_cost_basis() {
+        let market = Market::new(fill_event().exchange, fill_event().instrument);
+        let fill = |decision, quantity: Decimal, price: i64| FillEvent {
+            decision,
+            quantity,
+            fill_value_gross: Decimal::from(price) * quantity.abs(),
+            fees: Fees {
+                exchange: Decimal::ONE,
+                ..Fees::default()
+            },
+            ..fill_event()
+        };
+
+        // Scale into a long of 2.0 & exit it in two halves
+        let fills = [
+            fill(Decision::Long, Decimal::ONE, 100),
+            fill(Decision::Long, Decimal::ONE, 120),
+            fill(Decision::CloseLong, Decimal::NEGATIVE_ONE, 130),
+            fill(Decision::CloseLong, Decimal::NEGATIVE_ONE, 130),
+        ];
+
+        // (CostBasis, realised profit & loss of the partial exit, enter price of the remainder)
+        let cases = [
+            (CostBasis::Fifo, 30 - 2, 120),
+            (CostBasis::Lifo, 10 - 2, 100),
+            (CostBasis::Average, 20 - 2, 110),
+        ];
+
+        for (cost_basis, partial_profit_loss, remaining_enter_price) in cases {
+            let engine_id = Uuid::new_v4();
+            let mut portfolio = MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![market.clone()])
+                .starting_cash(Decimal::from(10_000))
+                .repository(InMemoryRepository::<PnLReturnSummary>::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: Decimal::ONE_HUNDRED,
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(())
+                .cost_basis(cost_basis)
+                .build_and_init()
+                .unwrap();
+            let position_id =
+                determine_position_id(engine_id, &market.exchange, &market.instrument);
+
+            for fill in &fills[..3] {
+                portfolio.update_from_fill(fill).unwrap();
+            }
+
+            // Partial exit realises the P&L of the lot matched by the CostBasis
+            let open = portfolio.get_open_position(&position_id).unwrap().unwrap();
+            assert_eq!(open.quantity, Decimal::ONE, "{cost_basis:?}");
+            assert_eq!(
+                open.realised_profit_loss,
+                Decimal::from(partial_profit_loss),
+                "{cost_basis:?}"
+            );
+            assert_eq!(
+                open.enter_avg_price_gross,
+                Decimal::from(remaining_enter_price),
+                "{cost_basis:?}"
+            );
+            let balance = portfolio.get_balance(engine_id).unwrap();
+            assert_eq!(balance.total, Decimal::from(10_000 + partial_profit_loss));
+            assert_eq!(
+                balance.available,
+                Decimal::from(10_000 - 101 - 121 + 130 - 1)
+            );
+
+            // Every CostBasis realises the same total P&L once the Position is exited
+            portfolio.update_from_fill(&fills[3]).unwrap();
+            let exited = portfolio.get_exited_positions(engine_id).unwrap();
+            assert_eq!(exited.len(), 1);
+            assert_eq!(exited[0].realised_profit_loss, Decimal::from(260 - 220 - 4));
+            let balance = portfolio.get_balance(engine_id).unwrap();
+            assert_eq!(balance.total, Decimal::from(10_000 + 36));
+            assert_eq!(balance.available, Decimal::from(10_000 + 36));
+        }
+    }
+
+    #[test]
+    fn generate_manual_order_should_increase_and_partially_exit_an_open_position() {
+        let market = Market::new(fill_event().exchange, fill_event().instrument);
+        let engine_id = Uuid::new_v4();
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(Decimal::from(10_000))
+            .repository(InMemoryRepository::<PnLReturnSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: Decimal::ONE_HUNDRED,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .build_and_init()
+            .unwrap();
+        let position_id = determine_position_id(engine_id, &market.exchange, &market.instrument);
+        let market_meta = Some(MarketMeta {
+            close: Decimal::ONE_HUNDRED,
+            time: Utc::now(),
+        });
+        let request = |side, quantity: i64| ManualOrderRequest {
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            side,
+            quantity: Decimal::from(quantity),
+            notional: None,
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::default(),
+            trigger: None,
+            tags: OrderTags::default(),
+        };
+        let fill = |order: &OrderEvent| FillEvent {
+            decision: order.decision,
+            quantity: order.quantity,
+            fill_value_gross: order.quantity.abs() * Decimal::ONE_HUNDRED,
+            fees: Fees::default(),
+            ..fill_event()
+        };
+
+        // Enter a long of 2
+        let entry = portfolio
+            .generate_manual_order(request(Side::Buy, 2), market_meta)
+            .unwrap();
+        portfolio.update_from_fill(&fill(&entry)).unwrap();
+
+        // Same side request increases the open Position
+        let increase = portfolio
+            .generate_manual_order(request(Side::Buy, 1), market_meta)
+            .unwrap();
+        assert_eq!(increase.decision, Decision::Long);
+        assert_eq!(increase.quantity, Decimal::ONE);
+        portfolio.update_from_fill(&fill(&increase)).unwrap();
+        let open = portfolio.get_open_position(&position_id).unwrap().unwrap();
+        assert_eq!(open.quantity, Decimal::from(3));
+
+        // Opposite side request for less than the open quantity partially exits it
+        let decrease = portfolio
+            .generate_manual_order(request(Side::Sell, 2), market_meta)
+            .unwrap();
+        assert_eq!(decrease.decision, Decision::CloseLong);
+        assert_eq!(decrease.quantity, Decimal::from(-2));
+        portfolio.update_from_fill(&fill(&decrease)).unwrap();
+        let open = portfolio.get_open_position(&position_id).unwrap().unwrap();
+        assert_eq!(open.quantity, Decimal::ONE);
+
+        // Exits exceeding the open quantity are refused
+        assert!(matches!(
+            portfolio.generate_manual_order(request(Side::Sell, 2), market_meta),
+            Err(PortfolioError::ManualOrderRejected(_))
+        ));
+    }
+
+    #[test]
+    fn update_from_fill_flipping_long_to_short_should_exit_long_and_enter_short_remainder() {
+        let market = Market::new(fill_event().exchange, fill_event().instrument);
+        let engine_id = Uuid::new_v4();
+        let mut portfolio = MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(Decimal::from(10_000))
+            .repository(InMemoryRepository::<PnLReturnSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: Decimal::ONE_HUNDRED,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .build_and_init()
+            .unwrap();
+        let position_id = determine_position_id(engine_id, &market.exchange, &market.instrument);
+
+        // Long 1.0 @ 100 with 1.0 of fees
+        portfolio
+            .update_from_fill(&FillEvent {
+                decision: Decision::Long,
+                quantity: Decimal::ONE,
+                fill_value_gross: Decimal::ONE_HUNDRED,
+                fees: Fees {
+                    exchange: Decimal::ONE,
+                    ..Fees::default()
+                },
+                ..fill_event()
+            })
+            .unwrap();
+
+        // Single CloseLong fill of 3.0 @ 110 with 3.0 of fees flips the long to a short of 2.0
+        let events = portfolio
+            .update_from_fill(&FillEvent {
+                decision: Decision::CloseLong,
+                quantity: -Decimal::from(3),
+                fill_value_gross: Decimal::from(330),
+                fees: Fees {
+                    exchange: Decimal::from(3),
+                    ..Fees::default()
+                },
+                ..fill_event()
+            })
+            .unwrap();
+        assert!(matches!(events[0], Event::PositionExit(_)));
+        assert!(matches!(events[2], Event::PositionNew(_)));
+
+        // Long portion realises (110 - 100) - 1 entry fee - 1 pro-rated exit fee
+        let exited = portfolio.get_exited_positions(engine_id).unwrap();
+        assert_eq!(exited.len(), 1);
+        assert_eq!(exited[0].side, Side::Buy);
+        assert_eq!(exited[0].realised_profit_loss, Decimal::from(10 - 2));
+        assert_eq!(
+            portfolio.realised_profit_loss().unwrap(),
+            HashMap::from([(market.clone(), Decimal::from(8))])
+        );
+
+        // Short portion opens at the flip price with the remaining pro-rated fees
+        let short = portfolio.get_open_position(&position_id).unwrap().unwrap();
+        assert_eq!(short.side, Side::Sell);
+        assert_eq!(short.quantity, -Decimal::TWO);
+        assert_eq!(short.enter_avg_price_gross, Decimal::from(110));
+        assert_eq!(short.enter_value_gross, Decimal::from(220));
+        assert_eq!(short.enter_fees_total, Decimal::TWO);
+        assert_eq!(short.realised_profit_loss, Decimal::ZERO);
+
+        let balance = portfolio.get_balance(engine_id).unwrap();
+        assert_eq!(balance.total, Decimal::from(10_000 + 8));
+        assert_eq!(balance.available, Decimal::from(10_000 + 8 - 220 - 2));
     }
 
     #[test]
@@ -1,4 +1,5 @@
 use crate::{
+    data::MarketMeta,
     execution::{FeeAmount, Fees, FillEvent},
     portfolio::{error::PortfolioError, Balance},
     strategy::Decision,
@@ -6,6 +7,7 @@ use crate::{
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use uuid::Uuid;
@@ -30,6 +32,34 @@ pub trait PositionExiter {
     fn exit(&mut self, balance: Balance, fill: &FillEvent) -> Result<PositionExit, PortfolioError>;
 }
 
+/// Method used to match exit fills against the entry lots of a [`Position`] that was scaled
+/// into, determining the cost basis (and therefore the realised P&L) of each partial exit.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum CostBasis {
+    /// Exit fills close the oldest entry lot first.
+    #[default]
+    Fifo,
+    /// Exit fills close the most recent entry lot first.
+    Lifo,
+    /// Exit fills close quantity at the average price of every open entry lot.
+    Average,
+}
+
+/// Entry fill of a [`Position`], yet to be closed by an exit fill.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct EntryLot {
+    /// [`FillEvent`] timestamp of the entry.
+    pub time: DateTime<Utc>,
+    /// Entry price excluding the entry fees.
+    pub price: Decimal,
+    /// Absolute quantity of the entry yet to be closed.
+    pub quantity: Decimal,
+    /// Entry fees attributable to the open quantity.
+    pub fees: FeeAmount,
+}
+
 /// Communicates a String represents a unique [`Position`] identifier.
 pub type PositionId = String;
 
@@ -65,7 +95,7 @@ pub struct Position {
     pub side: Side,
 
     /// +ve or -ve quantity of symbol contracts opened.
-    pub quantity: f64,
+    pub quantity: Decimal,
 
     /// All fees types incurred from entering a [`Position`], and their associated [`FeeAmount`].
     pub enter_fees: Fees,
@@ -74,10 +104,10 @@ pub struct Position {
     pub enter_fees_total: FeeAmount,
 
     /// Enter average price excluding the entry_fees_total.
-    pub enter_avg_price_gross: f64,
+    pub enter_avg_price_gross: Decimal,
 
     /// abs(Quantity) * enter_avg_price_gross.
-    pub enter_value_gross: f64,
+    pub enter_value_gross: Decimal,
 
     /// All fees types incurred from exiting a [`Position`], and their associated [`FeeAmount`].
     pub exit_fees: Fees,
@@ -86,22 +116,28 @@ pub struct Position {
     pub exit_fees_total: FeeAmount,
 
     /// Exit average price excluding the exit_fees_total.
-    pub exit_avg_price_gross: f64,
+    pub exit_avg_price_gross: Decimal,
 
     /// abs(Quantity) * exit_avg_price_gross.
-    pub exit_value_gross: f64,
+    pub exit_value_gross: Decimal,
 
     /// Symbol current close price.
-    pub current_symbol_price: f64,
+    pub current_symbol_price: Decimal,
 
     /// abs(Quantity) * current_symbol_price.
-    pub current_value_gross: f64,
+    pub current_value_gross: Decimal,
 
     /// Unrealised P&L whilst the [`Position`] is open.
-    pub unrealised_profit_loss: f64,
+    pub unrealised_profit_loss: Decimal,
 
-    /// Realised P&L after the [`Position`] has closed.
-    pub realised_profit_loss: f64,
+    /// Realised P&L of the quantity exited so far, and of the whole [`Position`] once closed.
+    pub realised_profit_loss: Decimal,
+
+    /// Open [`EntryLot`]s, oldest first, matched against partial exits according to a
+    /// [`CostBasis`]. Positions without entry lots (eg/ persisted before lots were tracked)
+    /// are treated as a single lot at the [`Position::enter_avg_price_gross`].
+    #[serde(default)]
+    pub enter_lots: Vec<EntryLot>,
 }
 
 impl PositionEnterer for Position {
@@ -120,7 +156,7 @@ impl PositionEnterer for Position {
         let enter_avg_price_gross = Position::calculate_avg_price_gross(fill);
 
         // Unreal profit & loss
-        let unrealised_profit_loss = -enter_fees_total * 2.0;
+        let unrealised_profit_loss = -enter_fees_total * Decimal::TWO;
 
         Ok(Position {
             position_id: determine_position_id(engine_id, &fill.exchange, &fill.instrument),
@@ -134,13 +170,19 @@ impl PositionEnterer for Position {
             enter_avg_price_gross,
             enter_value_gross: fill.fill_value_gross,
             exit_fees: Fees::default(),
-            exit_fees_total: 0.0,
-            exit_avg_price_gross: 0.0,
-            exit_value_gross: 0.0,
+            exit_fees_total: Decimal::ZERO,
+            exit_avg_price_gross: Decimal::ZERO,
+            exit_value_gross: Decimal::ZERO,
             current_symbol_price: enter_avg_price_gross,
             current_value_gross: fill.fill_value_gross,
             unrealised_profit_loss,
-            realised_profit_loss: 0.0,
+            realised_profit_loss: Decimal::ZERO,
+            enter_lots: vec![EntryLot {
+                time: fill.time,
+                price: enter_avg_price_gross,
+                quantity: fill.quantity.abs(),
+                fees: enter_fees_total,
+            }],
         })
     }
 }
@@ -148,13 +190,7 @@ impl PositionEnterer for Position {
 impl PositionUpdater for Position {
     fn update(&mut self, market: &MarketEvent<DataKind>) -> Option<PositionUpdate> {
         // Determine close from MarketEvent
-        let close = match &market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
-            DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price(),
-            DataKind::OrderBook(book) => book.volume_weighed_mid_price()?,
-            DataKind::Liquidation(_) => return None,
-        };
+        let close = MarketMeta::from_market(market)?.close;
 
         self.meta.update_time = market.exchange_time;
 
@@ -189,12 +225,14 @@ impl PositionExiter for Position {
         self.exit_value_gross = fill.fill_value_gross;
         self.exit_avg_price_gross = Position::calculate_avg_price_gross(fill);
 
-        // Result profit & loss
-        self.realised_profit_loss = self.calculate_realised_profit_loss();
+        // Result profit & loss, including that realised by any previous partial exits
+        let profit_loss = self.calculate_realised_profit_loss();
+        self.realised_profit_loss += profit_loss;
         self.unrealised_profit_loss = self.realised_profit_loss;
+        self.enter_lots.clear();
 
         // Metadata
-        balance.total += self.realised_profit_loss;
+        balance.total += profit_loss;
         self.meta.update_time = fill.time;
         self.meta.exit_balance = Some(balance);
 
@@ -208,10 +246,194 @@ impl Position {
         PositionBuilder::new()
     }
 
+    /// Increases the quantity of this open [`Position`] by the input entry [`FillEvent`] of the
+    /// same [`Side`] (eg/ a subsequent partial fill of the entry order), returning a
+    /// [`PositionUpdate`] that communicates the open [`Position`]'s change in state. The
+    /// [`Position::enter_avg_price_gross`] becomes the average price of every entry fill.
+    pub fn increase(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError> {
+        if Position::parse_entry_side(fill)? != self.side {
+            return Err(PortfolioError::CannotIncreasePositionWithOppositeSideFill);
+        }
+
+        // Enter fees
+        self.enter_fees.exchange += fill.fees.exchange;
+        self.enter_fees.slippage += fill.fees.slippage;
+        self.enter_fees.network += fill.fees.network;
+        self.enter_fees_total += fill.fees.calculate_total_fees();
+
+        // Enter lots
+        let mut enter_lots = self.enter_lots();
+        enter_lots.push(EntryLot {
+            time: fill.time,
+            price: Position::calculate_avg_price_gross(fill),
+            quantity: fill.quantity.abs(),
+            fees: fill.fees.calculate_total_fees(),
+        });
+        self.enter_lots = enter_lots;
+
+        // Enter quantity, value & price
+        self.quantity += fill.quantity;
+        self.enter_value_gross += fill.fill_value_gross;
+        self.enter_avg_price_gross = self.enter_value_gross / self.quantity.abs();
+
+        // Market value gross & unreal profit & loss
+        self.current_value_gross = self.current_symbol_price * self.quantity.abs();
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+
+        self.meta.update_time = fill.time;
+
+        Ok(PositionUpdate::from(self))
+    }
+
+    /// Corrects the quantity of this open [`Position`] to the provided net quantity of the same
+    /// [`Side`] (eg/ the quantity held on the exchange after the [`Position`] drifted), returning
+    /// a [`PositionUpdate`] that communicates the open [`Position`]'s change in state. The
+    /// [`Position::enter_avg_price_gross`] is kept, with the enter value & fees scaled to the
+    /// corrected quantity.
+    pub fn reconcile(
+        &mut self,
+        quantity: Decimal,
+        time: DateTime<Utc>,
+    ) -> Result<PositionUpdate, PortfolioError> {
+        if quantity.is_zero() || quantity.is_sign_positive() != self.quantity.is_sign_positive() {
+            return Err(PortfolioError::CannotReconcilePositionToOppositeSide);
+        }
+
+        // Enter fees
+        let scale = quantity / self.quantity;
+        self.enter_fees.exchange *= scale;
+        self.enter_fees.slippage *= scale;
+        self.enter_fees.network *= scale;
+        self.enter_fees_total *= scale;
+
+        // Enter lots
+        for lot in &mut self.enter_lots {
+            lot.quantity *= scale;
+            lot.fees *= scale;
+        }
+
+        // Enter quantity & value
+        self.quantity = quantity;
+        self.enter_value_gross = self.enter_avg_price_gross * quantity.abs();
+
+        // Market value gross & unreal profit & loss
+        self.current_value_gross = self.current_symbol_price * quantity.abs();
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+
+        self.meta.update_time = time;
+
+        Ok(PositionUpdate::from(self))
+    }
+
+    /// Decreases the quantity of this open [`Position`] by the input exit [`FillEvent`] of a
+    /// smaller absolute quantity (eg/ a partial exit), returning a [`PositionUpdate`] that
+    /// communicates the open [`Position`]'s change in state.
+    ///
+    /// The exited quantity is matched against the [`EntryLot`]s according to the [`CostBasis`],
+    /// with the P&L of the exited quantity added to the [`Position::realised_profit_loss`]. The
+    /// remaining lots determine the enter price, value & fees of the open quantity.
+    pub fn decrease(
+        &mut self,
+        fill: &FillEvent,
+        cost_basis: CostBasis,
+    ) -> Result<PositionUpdate, PortfolioError> {
+        if fill.decision.is_entry() {
+            return Err(PortfolioError::CannotExitPositionWithEntryFill);
+        }
+        if fill.quantity.abs() >= self.quantity.abs() {
+            return Err(PortfolioError::CannotDecreasePositionByEntireQuantity);
+        }
+
+        let exit_price = Position::calculate_avg_price_gross(fill);
+        let direction = match self.side {
+            Side::Buy => Decimal::ONE,
+            Side::Sell => Decimal::NEGATIVE_ONE,
+        };
+
+        // Average cost matches against a single lot at the average enter price
+        let mut enter_lots = match cost_basis {
+            CostBasis::Average => vec![EntryLot {
+                time: self.meta.enter_time,
+                price: self.enter_avg_price_gross,
+                quantity: self.quantity.abs(),
+                fees: self.enter_fees_total,
+            }],
+            CostBasis::Fifo | CostBasis::Lifo => self.enter_lots(),
+        };
+
+        // Close the exited quantity from the lots in order of the CostBasis
+        let mut remaining = fill.quantity.abs();
+        let mut profit_loss = -fill.fees.calculate_total_fees();
+        while !remaining.is_zero() {
+            let lot = match cost_basis {
+                CostBasis::Lifo => enter_lots.last_mut(),
+                CostBasis::Fifo | CostBasis::Average => enter_lots.first_mut(),
+            };
+            let Some(lot) = lot else {
+                break;
+            };
+
+            let closed = lot.quantity.min(remaining);
+            let closed_fees = lot.fees * closed / lot.quantity;
+            profit_loss += (exit_price - lot.price) * closed * direction;
+            profit_loss -= closed_fees;
+
+            lot.fees -= closed_fees;
+            lot.quantity -= closed;
+            remaining -= closed;
+            enter_lots.retain(|lot| !lot.quantity.is_zero());
+        }
+
+        // Enter fees
+        let enter_fees_total = enter_lots.iter().map(|lot| lot.fees).sum::<Decimal>();
+        let scale = enter_fees_total
+            .checked_div(self.enter_fees_total)
+            .unwrap_or_default();
+        self.enter_fees.exchange *= scale;
+        self.enter_fees.slippage *= scale;
+        self.enter_fees.network *= scale;
+        self.enter_fees_total = enter_fees_total;
+
+        // Enter quantity, value & price
+        self.quantity += fill.quantity;
+        self.enter_value_gross = enter_lots.iter().map(|lot| lot.price * lot.quantity).sum();
+        self.enter_avg_price_gross = self.enter_value_gross / self.quantity.abs();
+        self.enter_lots = enter_lots;
+
+        // Realised profit & loss of the exited quantity
+        self.realised_profit_loss += profit_loss;
+
+        // Market value gross & unreal profit & loss
+        self.current_value_gross = self.current_symbol_price * self.quantity.abs();
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+
+        self.meta.update_time = fill.time;
+
+        Ok(PositionUpdate::from(self))
+    }
+
+    /// Returns the open [`EntryLot`]s of this [`Position`], or a single lot at the
+    /// [`Position::enter_avg_price_gross`] if no entry lots have been tracked.
+    fn enter_lots(&self) -> Vec<EntryLot> {
+        if self.enter_lots.is_empty() && !self.quantity.is_zero() {
+            vec![EntryLot {
+                time: self.meta.enter_time,
+                price: self.enter_avg_price_gross,
+                quantity: self.quantity.abs(),
+                fees: self.enter_fees_total,
+            }]
+        } else {
+            self.enter_lots.clone()
+        }
+    }
+
     /// Calculates the [`Position::enter_avg_price_gross`] or [`Position::exit_avg_price_gross`] of
     /// a [`FillEvent`].
-    pub fn calculate_avg_price_gross(fill: &FillEvent) -> f64 {
-        (fill.fill_value_gross / fill.quantity).abs()
+    pub fn calculate_avg_price_gross(fill: &FillEvent) -> Decimal {
+        fill.fill_value_gross
+            .checked_div(fill.quantity)
+            .unwrap_or_default()
+            .abs()
     }
 
     /// Determine the [`Position`] entry [`Side`] by analysing the input [`FillEvent`].
@@ -235,8 +457,8 @@ impl Position {
     }
 
     /// Calculate the approximate [`Position::unrealised_profit_loss`] of a [`Position`].
-    pub fn calculate_unrealised_profit_loss(&self) -> f64 {
-        let approx_total_fees = self.enter_fees_total * 2.0;
+    pub fn calculate_unrealised_profit_loss(&self) -> Decimal {
+        let approx_total_fees = self.enter_fees_total * Decimal::TWO;
 
         match self.side {
             Side::Buy => self.current_value_gross - self.enter_value_gross - approx_total_fees,
@@ -245,7 +467,7 @@ impl Position {
     }
 
     /// Calculate the exact [`Position::realised_profit_loss`] of a [`Position`].
-    pub fn calculate_realised_profit_loss(&self) -> f64 {
+    pub fn calculate_realised_profit_loss(&self) -> Decimal {
         let total_fees = self.enter_fees_total + self.exit_fees_total;
 
         match self.side {
@@ -256,8 +478,10 @@ impl Position {
 
     /// Calculate the PnL return of a closed [`Position`] - assumed [`Position::realised_profit_loss`] is
     /// appropriately calculated.
-    pub fn calculate_profit_loss_return(&self) -> f64 {
-        self.realised_profit_loss / self.enter_value_gross
+    pub fn calculate_profit_loss_return(&self) -> Decimal {
+        self.realised_profit_loss
+            .checked_div(self.enter_value_gross)
+            .unwrap_or_default()
     }
 }
 
@@ -269,19 +493,20 @@ pub struct PositionBuilder {
     pub instrument: Option<Instrument>,
     pub meta: Option<PositionMeta>,
     pub side: Option<Side>,
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     pub enter_fees: Option<Fees>,
     pub enter_fees_total: Option<FeeAmount>,
-    pub enter_avg_price_gross: Option<f64>,
-    pub enter_value_gross: Option<f64>,
+    pub enter_avg_price_gross: Option<Decimal>,
+    pub enter_value_gross: Option<Decimal>,
     pub exit_fees: Option<Fees>,
     pub exit_fees_total: Option<FeeAmount>,
-    pub exit_avg_price_gross: Option<f64>,
-    pub exit_value_gross: Option<f64>,
-    pub current_symbol_price: Option<f64>,
-    pub current_value_gross: Option<f64>,
-    pub unrealised_profit_loss: Option<f64>,
-    pub realised_profit_loss: Option<f64>,
+    pub exit_avg_price_gross: Option<Decimal>,
+    pub exit_value_gross: Option<Decimal>,
+    pub current_symbol_price: Option<Decimal>,
+    pub current_value_gross: Option<Decimal>,
+    pub unrealised_profit_loss: Option<Decimal>,
+    pub realised_profit_loss: Option<Decimal>,
+    pub enter_lots: Option<Vec<EntryLot>>,
 }
 
 impl PositionBuilder {
@@ -324,7 +549,7 @@ impl PositionBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Decimal) -> Self {
         Self {
             quantity: Some(value),
             ..self
@@ -345,14 +570,14 @@ impl PositionBuilder {
         }
     }
 
-    pub fn enter_avg_price_gross(self, value: f64) -> Self {
+    pub fn enter_avg_price_gross(self, value: Decimal) -> Self {
         Self {
             enter_avg_price_gross: Some(value),
             ..self
         }
     }
 
-    pub fn enter_value_gross(self, value: f64) -> Self {
+    pub fn enter_value_gross(self, value: Decimal) -> Self {
         Self {
             enter_value_gross: Some(value),
             ..self
@@ -373,48 +598,55 @@ impl PositionBuilder {
         }
     }
 
-    pub fn exit_avg_price_gross(self, value: f64) -> Self {
+    pub fn exit_avg_price_gross(self, value: Decimal) -> Self {
         Self {
             exit_avg_price_gross: Some(value),
             ..self
         }
     }
 
-    pub fn exit_value_gross(self, value: f64) -> Self {
+    pub fn exit_value_gross(self, value: Decimal) -> Self {
         Self {
             exit_value_gross: Some(value),
             ..self
         }
     }
 
-    pub fn current_symbol_price(self, value: f64) -> Self {
+    pub fn current_symbol_price(self, value: Decimal) -> Self {
         Self {
             current_symbol_price: Some(value),
             ..self
         }
     }
 
-    pub fn current_value_gross(self, value: f64) -> Self {
+    pub fn current_value_gross(self, value: Decimal) -> Self {
         Self {
             current_value_gross: Some(value),
             ..self
         }
     }
 
-    pub fn unrealised_profit_loss(self, value: f64) -> Self {
+    pub fn unrealised_profit_loss(self, value: Decimal) -> Self {
         Self {
             unrealised_profit_loss: Some(value),
             ..self
         }
     }
 
-    pub fn realised_profit_loss(self, value: f64) -> Self {
+    pub fn realised_profit_loss(self, value: Decimal) -> Self {
         Self {
             realised_profit_loss: Some(value),
             ..self
         }
     }
 
+    pub fn enter_lots(self, value: Vec<EntryLot>) -> Self {
+        Self {
+            enter_lots: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<Position, PortfolioError> {
         Ok(Position {
             position_id: self
@@ -467,6 +699,7 @@ impl PositionBuilder {
             realised_profit_loss: self
                 .realised_profit_loss
                 .ok_or(PortfolioError::BuilderIncomplete("realised_profit_loss"))?,
+            enter_lots: self.enter_lots.unwrap_or_default(),
         })
     }
 }
@@ -503,11 +736,11 @@ pub struct PositionUpdate {
     /// Event timestamp of the last event to trigger a [`Position`] update.
     pub update_time: DateTime<Utc>,
     /// Symbol current close price.
-    pub current_symbol_price: f64,
+    pub current_symbol_price: Decimal,
     /// abs(Quantity) * current_symbol_price.
-    pub current_value_gross: f64,
+    pub current_value_gross: Decimal,
     /// Unrealised P&L whilst the [`Position`] is open.
-    pub unrealised_profit_loss: f64,
+    pub unrealised_profit_loss: Decimal,
 }
 
 impl From<&mut Position> for PositionUpdate {
@@ -541,13 +774,13 @@ pub struct PositionExit {
     pub exit_fees_total: FeeAmount,
 
     /// Exit average price excluding the exit_fees_total.
-    pub exit_avg_price_gross: f64,
+    pub exit_avg_price_gross: Decimal,
 
     /// abs(Quantity) * exit_avg_price_gross.
-    pub exit_value_gross: f64,
+    pub exit_value_gross: Decimal,
 
     /// Realised P&L after the [`Position`] has closed.
-    pub realised_profit_loss: f64,
+    pub realised_profit_loss: Decimal,
 }
 
 impl TryFrom<&mut Position> for PositionExit {
@@ -573,26 +806,29 @@ impl TryFrom<&mut Position> for PositionExit {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::{fill_event, market_event_trade, position};
+    use crate::{
+        portfolio::decimal_from_f64,
+        test_util::{fill_event, market_event_trade, position},
+    };
     use barter_integration::model::Side;
 
     #[test]
     fn enter_new_position_with_long_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = Decimal::ONE;
+        input_fill.fill_value_gross = Decimal::ONE_HUNDRED;
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         let position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
 
         assert_eq!(position.side, Side::Buy);
         assert_eq!(position.quantity, input_fill.quantity);
-        assert_eq!(position.enter_fees_total, 3.0);
+        assert_eq!(position.enter_fees_total, Decimal::from(3));
         assert_eq!(position.enter_fees.exchange, input_fill.fees.exchange);
         assert_eq!(position.enter_fees.slippage, input_fill.fees.slippage);
         assert_eq!(position.enter_fees.network, input_fill.fees.network);
@@ -601,35 +837,35 @@ mod tests {
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.enter_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.exit_fees_total, 0.0);
-        assert_eq!(position.exit_avg_price_gross, 0.0);
-        assert_eq!(position.exit_value_gross, 0.0);
+        assert_eq!(position.exit_fees_total, Decimal::ZERO);
+        assert_eq!(position.exit_avg_price_gross, Decimal::ZERO);
+        assert_eq!(position.exit_value_gross, Decimal::ZERO);
         assert_eq!(
             position.current_symbol_price,
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.current_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.unrealised_profit_loss, -6.0); // -2 * enter_fees_total
-        assert_eq!(position.realised_profit_loss, 0.0);
+        assert_eq!(position.unrealised_profit_loss, Decimal::from(-6)); // -2 * enter_fees_total
+        assert_eq!(position.realised_profit_loss, Decimal::ZERO);
     }
 
     #[test]
     fn enter_new_position_with_short_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = Decimal::NEGATIVE_ONE;
+        input_fill.fill_value_gross = Decimal::ONE_HUNDRED;
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         let position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
 
         assert_eq!(position.side, Side::Sell);
         assert_eq!(position.quantity, input_fill.quantity);
-        assert_eq!(position.enter_fees_total, 3.0);
+        assert_eq!(position.enter_fees_total, Decimal::from(3));
         assert_eq!(position.enter_fees.exchange, input_fill.fees.exchange);
         assert_eq!(position.enter_fees.slippage, input_fill.fees.slippage);
         assert_eq!(position.enter_fees.network, input_fill.fees.network);
@@ -638,31 +874,31 @@ mod tests {
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.enter_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.exit_fees_total, 0.0);
-        assert_eq!(position.exit_avg_price_gross, 0.0);
-        assert_eq!(position.exit_value_gross, 0.0);
+        assert_eq!(position.exit_fees_total, Decimal::ZERO);
+        assert_eq!(position.exit_avg_price_gross, Decimal::ZERO);
+        assert_eq!(position.exit_value_gross, Decimal::ZERO);
         assert_eq!(
             position.current_symbol_price,
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.current_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.unrealised_profit_loss, -6.0); // -2 * enter_fees_total
-        assert_eq!(position.realised_profit_loss, 0.0);
+        assert_eq!(position.unrealised_profit_loss, Decimal::from(-6)); // -2 * enter_fees_total
+        assert_eq!(position.realised_profit_loss, Decimal::ZERO);
     }
 
     #[test]
     fn enter_new_position_and_return_err_with_close_long_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = Decimal::NEGATIVE_ONE;
+        input_fill.fill_value_gross = Decimal::ONE_HUNDRED;
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -676,15 +912,15 @@ mod tests {
     {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = Decimal::ONE;
+        input_fill.fill_value_gross = Decimal::ONE_HUNDRED;
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -698,15 +934,15 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = Decimal::NEGATIVE_ONE;
+        input_fill.fill_value_gross = Decimal::ONE_HUNDRED;
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -720,15 +956,15 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = Decimal::ONE;
+        input_fill.fill_value_gross = Decimal::ONE_HUNDRED;
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -742,18 +978,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = Decimal::ONE;
+        position.enter_fees_total = Decimal::from(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::ONE_HUNDRED;
+        position.current_symbol_price = Decimal::ONE_HUNDRED;
+        position.current_value_gross = Decimal::ONE_HUNDRED;
+        position.unrealised_profit_loss = position.enter_fees_total * -Decimal::TWO;
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Buy);
@@ -769,18 +1005,18 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, Decimal::ONE);
+        assert_eq!(position.enter_fees_total, Decimal::from(3));
+        assert_eq!(position.enter_fees.exchange, Decimal::ONE);
+        assert_eq!(position.enter_fees.slippage, Decimal::ONE);
+        assert_eq!(position.enter_fees.network, Decimal::ONE);
+        assert_eq!(position.enter_avg_price_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.enter_value_gross, Decimal::ONE_HUNDRED);
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Trade(trade) => decimal_from_f64(trade.price),
+            DataKind::Candle(candle) => decimal_from_f64(candle.close),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -790,7 +1026,10 @@ mod tests {
         );
 
         // current_value_gross - enter_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (200.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            Decimal::from(200 - 100 - 6)
+        );
     }
 
     #[test]
@@ -798,18 +1037,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = Decimal::ONE;
+        position.enter_fees_total = Decimal::from(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::ONE_HUNDRED;
+        position.current_symbol_price = Decimal::ONE_HUNDRED;
+        position.current_value_gross = Decimal::ONE_HUNDRED;
+        position.unrealised_profit_loss = position.enter_fees_total * -Decimal::TWO;
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Sell);
@@ -826,18 +1065,18 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, Decimal::ONE);
+        assert_eq!(position.enter_fees_total, Decimal::from(3));
+        assert_eq!(position.enter_fees.exchange, Decimal::ONE);
+        assert_eq!(position.enter_fees.slippage, Decimal::ONE);
+        assert_eq!(position.enter_fees.network, Decimal::ONE);
+        assert_eq!(position.enter_avg_price_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.enter_value_gross, Decimal::ONE_HUNDRED);
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Trade(trade) => decimal_from_f64(trade.price),
+            DataKind::Candle(candle) => decimal_from_f64(candle.close),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -847,7 +1086,7 @@ mod tests {
         );
 
         // current_value_gross - enter_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (50.0 - 100.0 - 6.0));
+        assert_eq!(position.unrealised_profit_loss, Decimal::from(50 - 100 - 6));
     }
 
     #[test]
@@ -855,18 +1094,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = Decimal::NEGATIVE_ONE;
+        position.enter_fees_total = Decimal::from(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::ONE_HUNDRED;
+        position.current_symbol_price = Decimal::ONE_HUNDRED;
+        position.current_value_gross = Decimal::ONE_HUNDRED;
+        position.unrealised_profit_loss = position.enter_fees_total * -Decimal::TWO;
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Buy);
@@ -883,18 +1122,18 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, Decimal::NEGATIVE_ONE);
+        assert_eq!(position.enter_fees_total, Decimal::from(3));
+        assert_eq!(position.enter_fees.exchange, Decimal::ONE);
+        assert_eq!(position.enter_fees.slippage, Decimal::ONE);
+        assert_eq!(position.enter_fees.network, Decimal::ONE);
+        assert_eq!(position.enter_avg_price_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.enter_value_gross, Decimal::ONE_HUNDRED);
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Trade(trade) => decimal_from_f64(trade.price),
+            DataKind::Candle(candle) => decimal_from_f64(candle.close),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -904,7 +1143,7 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 50.0 - 6.0));
+        assert_eq!(position.unrealised_profit_loss, Decimal::from(100 - 50 - 6));
     }
 
     #[test]
@@ -912,18 +1151,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = Decimal::NEGATIVE_ONE;
+        position.enter_fees_total = Decimal::from(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::ONE_HUNDRED;
+        position.current_symbol_price = Decimal::ONE_HUNDRED;
+        position.current_value_gross = Decimal::ONE_HUNDRED;
+        position.unrealised_profit_loss = position.enter_fees_total * -Decimal::TWO;
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Sell);
@@ -940,18 +1179,18 @@ mod tests {
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, Decimal::NEGATIVE_ONE);
+        assert_eq!(position.enter_fees_total, Decimal::from(3));
+        assert_eq!(position.enter_fees.exchange, Decimal::ONE);
+        assert_eq!(position.enter_fees.slippage, Decimal::ONE);
+        assert_eq!(position.enter_fees.network, Decimal::ONE);
+        assert_eq!(position.enter_avg_price_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.enter_value_gross, Decimal::ONE_HUNDRED);
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Trade(trade) => decimal_from_f64(trade.price),
+            DataKind::Candle(candle) => decimal_from_f64(candle.close),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -961,7 +1200,132 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 200.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            Decimal::from(100 - 200 - 6)
+        );
+    }
+
+    #[test]
+    fn increase_long_position_with_subsequent_entry_fill() {
+        // Initial Position
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = Decimal::ONE;
+        position.enter_fees_total = Decimal::from(3);
+        position.enter_fees = Fees {
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
+        };
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::ONE_HUNDRED;
+
+        // Input FillEvent of a subsequent partial fill at a higher price
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = Decimal::ONE;
+        input_fill.fill_value_gross = Decimal::from(200);
+        input_fill.fees = Fees {
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
+        };
+
+        position.increase(&input_fill).unwrap();
+
+        assert_eq!(position.quantity, Decimal::TWO);
+        assert_eq!(position.enter_fees_total, Decimal::from(6));
+        assert_eq!(position.enter_fees.exchange, Decimal::TWO);
+        assert_eq!(position.enter_value_gross, Decimal::from(300));
+        assert_eq!(position.enter_avg_price_gross, Decimal::from(150));
+    }
+
+    #[test]
+    fn increase_long_position_with_opposite_side_entry_fill_should_fail() {
+        let mut position = position();
+        position.side = Side::Buy;
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Short;
+        input_fill.quantity = Decimal::NEGATIVE_ONE;
+
+        assert!(matches!(
+            position.increase(&input_fill),
+            Err(PortfolioError::CannotIncreasePositionWithOppositeSideFill)
+        ));
+    }
+
+    #[test]
+    fn decrease_short_position_without_enter_lots_by_partial_exit_fill() {
+        // Short Position of 2.0 @ 100.0 without tracked enter lots
+        let mut position = position();
+        position.side = Side::Sell;
+        position.quantity = -Decimal::TWO;
+        position.enter_fees_total = Decimal::TWO;
+        position.enter_fees = Fees {
+            exchange: Decimal::TWO,
+            ..Fees::default()
+        };
+        position.enter_value_gross = Decimal::from(200);
+        position.current_symbol_price = Decimal::from(90);
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseShort;
+        input_fill.quantity = Decimal::ONE;
+        input_fill.fill_value_gross = Decimal::from(90);
+        input_fill.fees = Fees::default();
+
+        position.decrease(&input_fill, CostBasis::Lifo).unwrap();
+
+        // Exited quantity is matched against a single lot at the enter_avg_price_gross
+        assert_eq!(position.quantity, Decimal::NEGATIVE_ONE);
+        assert_eq!(position.realised_profit_loss, Decimal::from(10 - 1));
+        assert_eq!(position.enter_value_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.enter_fees_total, Decimal::ONE);
+        assert_eq!(position.enter_fees.exchange, Decimal::ONE);
+        assert_eq!(position.current_value_gross, Decimal::from(90));
+
+        // Exit fill for the entire remaining quantity must exit the Position instead
+        input_fill.quantity = Decimal::ONE;
+        assert!(matches!(
+            position.decrease(&input_fill, CostBasis::Lifo),
+            Err(PortfolioError::CannotDecreasePositionByEntireQuantity)
+        ));
+    }
+
+    #[test]
+    fn reconcile_long_position_to_drifted_quantity_keeps_enter_avg_price() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = Decimal::TWO;
+        position.enter_fees_total = Decimal::from(6);
+        position.enter_fees = Fees {
+            exchange: Decimal::TWO,
+            slippage: Decimal::TWO,
+            network: Decimal::TWO,
+        };
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::from(200);
+        position.current_symbol_price = Decimal::from(110);
+
+        position.reconcile(Decimal::ONE, Utc::now()).unwrap();
+
+        assert_eq!(position.quantity, Decimal::ONE);
+        assert_eq!(position.enter_avg_price_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.enter_value_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.enter_fees_total, Decimal::from(3));
+        assert_eq!(position.current_value_gross, Decimal::from(110));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            Decimal::from(110 - 100 - 6)
+        );
+
+        // Quantity of the opposite Side cannot be reconciled by rescaling
+        assert!(matches!(
+            position.reconcile(Decimal::NEGATIVE_ONE, Utc::now()),
+            Err(PortfolioError::CannotReconcilePositionToOppositeSide)
+        ));
     }
 
     #[test]
@@ -969,35 +1333,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = Decimal::ONE;
+        position.enter_fees_total = Decimal::from(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::ONE_HUNDRED;
+        position.current_symbol_price = Decimal::ONE_HUNDRED;
+        position.current_value_gross = Decimal::ONE_HUNDRED;
+        position.unrealised_profit_loss = position.enter_fees_total * -Decimal::TWO;
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: Decimal::from(10_000),
+            available: Decimal::from(10_000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = Decimal::from(200);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         // Exit Position
@@ -1005,19 +1369,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, Decimal::ONE);
+        assert_eq!(position.enter_fees_total, Decimal::from(3));
+        assert_eq!(position.enter_fees.exchange, Decimal::ONE);
+        assert_eq!(position.enter_fees.slippage, Decimal::ONE);
+        assert_eq!(position.enter_fees.network, Decimal::ONE);
+        assert_eq!(position.enter_avg_price_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.enter_value_gross, Decimal::ONE_HUNDRED);
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, Decimal::from(3));
+        assert_eq!(position.exit_fees.exchange, Decimal::ONE);
+        assert_eq!(position.exit_fees.slippage, Decimal::ONE);
+        assert_eq!(position.exit_fees.network, Decimal::ONE);
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1025,13 +1389,16 @@ mod tests {
         );
 
         // exit_value_gross - enter_value_gross - total_fees
-        assert_eq!(position.realised_profit_loss, (200.0 - 100.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (200.0 - 100.0 - 6.0));
+        assert_eq!(position.realised_profit_loss, Decimal::from(200 - 100 - 6));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            Decimal::from(200 - 100 - 6)
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (200.0 - 100.0 - 6.0)
+            current_balance.total + Decimal::from(200 - 100 - 6)
         )
     }
 
@@ -1040,35 +1407,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = Decimal::ONE;
+        position.enter_fees_total = Decimal::from(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::ONE_HUNDRED;
+        position.current_symbol_price = Decimal::ONE_HUNDRED;
+        position.current_value_gross = Decimal::ONE_HUNDRED;
+        position.unrealised_profit_loss = position.enter_fees_total * -Decimal::TWO;
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: Decimal::from(10_000),
+            available: Decimal::from(10_000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.fill_value_gross = Decimal::from(50);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         // Exit Position
@@ -1076,19 +1443,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, Decimal::ONE);
+        assert_eq!(position.enter_fees_total, Decimal::from(3));
+        assert_eq!(position.enter_fees.exchange, Decimal::ONE);
+        assert_eq!(position.enter_fees.slippage, Decimal::ONE);
+        assert_eq!(position.enter_fees.network, Decimal::ONE);
+        assert_eq!(position.enter_avg_price_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.enter_value_gross, Decimal::ONE_HUNDRED);
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, Decimal::from(3));
+        assert_eq!(position.exit_fees.exchange, Decimal::ONE);
+        assert_eq!(position.exit_fees.slippage, Decimal::ONE);
+        assert_eq!(position.exit_fees.network, Decimal::ONE);
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1096,13 +1463,13 @@ mod tests {
         );
 
         // exit_value_gross - enter_value_gross - total_fees
-        assert_eq!(position.realised_profit_loss, (50.0 - 100.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (50.0 - 100.0 - 6.0));
+        assert_eq!(position.realised_profit_loss, Decimal::from(50 - 100 - 6));
+        assert_eq!(position.unrealised_profit_loss, Decimal::from(50 - 100 - 6));
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (50.0 - 100.0 - 6.0)
+            current_balance.total + Decimal::from(50 - 100 - 6)
         )
     }
 
@@ -1111,35 +1478,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = Decimal::NEGATIVE_ONE;
+        position.enter_fees_total = Decimal::from(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::ONE_HUNDRED;
+        position.current_symbol_price = Decimal::ONE_HUNDRED;
+        position.current_value_gross = Decimal::ONE_HUNDRED;
+        position.unrealised_profit_loss = position.enter_fees_total * -Decimal::TWO;
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: Decimal::from(10_000),
+            available: Decimal::from(10_000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.fill_value_gross = Decimal::from(50);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         // Exit Position
@@ -1147,19 +1514,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, Decimal::NEGATIVE_ONE);
+        assert_eq!(position.enter_fees_total, Decimal::from(3));
+        assert_eq!(position.enter_fees.exchange, Decimal::ONE);
+        assert_eq!(position.enter_fees.slippage, Decimal::ONE);
+        assert_eq!(position.enter_fees.network, Decimal::ONE);
+        assert_eq!(position.enter_avg_price_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.enter_value_gross, Decimal::ONE_HUNDRED);
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, Decimal::from(3));
+        assert_eq!(position.exit_fees.exchange, Decimal::ONE);
+        assert_eq!(position.exit_fees.slippage, Decimal::ONE);
+        assert_eq!(position.exit_fees.network, Decimal::ONE);
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1167,13 +1534,13 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.realised_profit_loss, (100.0 - 50.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 50.0 - 6.0));
+        assert_eq!(position.realised_profit_loss, Decimal::from(100 - 50 - 6));
+        assert_eq!(position.unrealised_profit_loss, Decimal::from(100 - 50 - 6));
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (100.0 - 50.0 - 6.0)
+            current_balance.total + Decimal::from(100 - 50 - 6)
         )
     }
 
@@ -1182,35 +1549,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = Decimal::NEGATIVE_ONE;
+        position.enter_fees_total = Decimal::from(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::ONE_HUNDRED;
+        position.current_symbol_price = Decimal::ONE_HUNDRED;
+        position.current_value_gross = Decimal::ONE_HUNDRED;
+        position.unrealised_profit_loss = position.enter_fees_total * -Decimal::TWO;
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: Decimal::from(10_000),
+            available: Decimal::from(10_000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = Decimal::from(200);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         // Exit Position
@@ -1218,19 +1585,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, Decimal::NEGATIVE_ONE);
+        assert_eq!(position.enter_fees_total, Decimal::from(3));
+        assert_eq!(position.enter_fees.exchange, Decimal::ONE);
+        assert_eq!(position.enter_fees.slippage, Decimal::ONE);
+        assert_eq!(position.enter_fees.network, Decimal::ONE);
+        assert_eq!(position.enter_avg_price_gross, Decimal::ONE_HUNDRED);
+        assert_eq!(position.enter_value_gross, Decimal::ONE_HUNDRED);
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, Decimal::from(3));
+        assert_eq!(position.exit_fees.exchange, Decimal::ONE);
+        assert_eq!(position.exit_fees.slippage, Decimal::ONE);
+        assert_eq!(position.exit_fees.network, Decimal::ONE);
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1238,13 +1605,16 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.realised_profit_loss, (100.0 - 200.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 200.0 - 6.0));
+        assert_eq!(position.realised_profit_loss, Decimal::from(100 - 200 - 6));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            Decimal::from(100 - 200 - 6)
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (100.0 - 200.0 - 6.0)
+            current_balance.total + Decimal::from(100 - 200 - 6)
         )
     }
 
@@ -1253,39 +1623,39 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = Decimal::NEGATIVE_ONE;
+        position.enter_fees_total = Decimal::from(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::ONE_HUNDRED;
+        position.current_symbol_price = Decimal::ONE_HUNDRED;
+        position.current_value_gross = Decimal::ONE_HUNDRED;
+        position.unrealised_profit_loss = position.enter_fees_total * -Decimal::TWO;
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: Decimal::from(10_000),
+            available: Decimal::from(10_000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
         input_fill.quantity = position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = Decimal::from(200);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         // Exit Position
-        if let Err(_) = position.exit(current_balance, &input_fill) {
+        if position.exit(current_balance, &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1299,39 +1669,39 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = Decimal::NEGATIVE_ONE;
+        position.enter_fees_total = Decimal::from(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = Decimal::ONE_HUNDRED;
+        position.enter_value_gross = Decimal::ONE_HUNDRED;
+        position.current_symbol_price = Decimal::ONE_HUNDRED;
+        position.current_value_gross = Decimal::ONE_HUNDRED;
+        position.unrealised_profit_loss = position.enter_fees_total * -Decimal::TWO;
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: Decimal::from(10_000),
+            available: Decimal::from(10_000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = Decimal::from(200);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: Decimal::ONE,
+            slippage: Decimal::ONE,
+            network: Decimal::ONE,
         };
 
         // Exit Position
-        if let Err(_) = position.exit(current_balance, &input_fill) {
+        if position.exit(current_balance, &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1343,30 +1713,30 @@ mod tests {
     #[test]
     fn calculate_avg_price_gross_correctly_with_positive_quantity() {
         let mut input_fill = fill_event();
-        input_fill.fill_value_gross = 1000.0;
-        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = Decimal::ONE_THOUSAND;
+        input_fill.quantity = Decimal::ONE;
 
         let actual = Position::calculate_avg_price_gross(&input_fill);
 
-        assert_eq!(actual, 1000.0)
+        assert_eq!(actual, Decimal::ONE_THOUSAND)
     }
 
     #[test]
     fn calculate_avg_price_gross_correctly_with_negative_quantity() {
         let mut input_fill = fill_event();
-        input_fill.fill_value_gross = 1000.0;
-        input_fill.quantity = -1.0;
+        input_fill.fill_value_gross = Decimal::ONE_THOUSAND;
+        input_fill.quantity = Decimal::NEGATIVE_ONE;
 
         let actual = Position::calculate_avg_price_gross(&input_fill);
 
-        assert_eq!(actual, 1000.0)
+        assert_eq!(actual, Decimal::ONE_THOUSAND)
     }
 
     #[test]
     fn parse_entry_side_as_long_with_positive_quantity_long_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = Decimal::ONE;
 
         let actual = Position::parse_entry_side(&input_fill).unwrap();
 
@@ -1377,7 +1747,7 @@ mod tests {
     fn parse_entry_side_as_short_with_negative_quantity_short_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = Decimal::NEGATIVE_ONE;
 
         let actual = Position::parse_entry_side(&input_fill).unwrap();
 
@@ -1388,9 +1758,9 @@ mod tests {
     fn parse_entry_side_and_return_err_with_close_long_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = Decimal::NEGATIVE_ONE;
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1403,9 +1773,9 @@ mod tests {
     fn parse_entry_side_and_return_err_with_close_short_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = Decimal::ONE;
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1419,9 +1789,9 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = Decimal::NEGATIVE_ONE;
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1435,9 +1805,9 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = Decimal::ONE;
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1450,33 +1820,38 @@ mod tests {
     fn calculate_unreal_profit_loss() {
         let mut long_win = position(); // Expected PnL = +8.0
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.enter_fees_total = 1.0;
-        long_win.current_value_gross = 110.0;
+        long_win.enter_value_gross = Decimal::ONE_HUNDRED;
+        long_win.enter_fees_total = Decimal::ONE;
+        long_win.current_value_gross = Decimal::from(110);
 
         let mut long_lose = position(); // Expected PnL = -12.0
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.enter_fees_total = 1.0;
-        long_lose.current_value_gross = 90.0;
+        long_lose.enter_value_gross = Decimal::ONE_HUNDRED;
+        long_lose.enter_fees_total = Decimal::ONE;
+        long_lose.current_value_gross = Decimal::from(90);
 
         let mut short_win = position(); // Expected PnL = +8.0
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.enter_fees_total = 1.0;
-        short_win.current_value_gross = 90.0;
+        short_win.enter_value_gross = Decimal::ONE_HUNDRED;
+        short_win.enter_fees_total = Decimal::ONE;
+        short_win.current_value_gross = Decimal::from(90);
 
         let mut short_lose = position(); // Expected PnL = -12.0
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.enter_fees_total = 1.0;
-        short_lose.current_value_gross = 110.0;
+        short_lose.enter_value_gross = Decimal::ONE_HUNDRED;
+        short_lose.enter_fees_total = Decimal::ONE;
+        short_lose.current_value_gross = Decimal::from(110);
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
-        let expected_pnl = vec![8.0, -12.0, 8.0, -12.0];
+        let expected_pnl = vec![
+            Decimal::from(8),
+            Decimal::from(-12),
+            Decimal::from(8),
+            Decimal::from(-12),
+        ];
 
-        for (position, expected) in inputs.into_iter().zip(expected_pnl.into_iter()) {
+        for (position, expected) in inputs.into_iter().zip(expected_pnl) {
             let actual = position.calculate_unrealised_profit_loss();
             assert_eq!(actual, expected);
         }
@@ -1486,37 +1861,42 @@ mod tests {
     fn calculate_realised_profit_loss() {
         let mut long_win = position(); // Expected PnL = +18.0
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.enter_fees_total = 1.0;
-        long_win.exit_value_gross = 120.0;
-        long_win.exit_fees_total = 1.0;
+        long_win.enter_value_gross = Decimal::ONE_HUNDRED;
+        long_win.enter_fees_total = Decimal::ONE;
+        long_win.exit_value_gross = Decimal::from(120);
+        long_win.exit_fees_total = Decimal::ONE;
 
         let mut long_lose = position(); // Expected PnL = -22.0
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.enter_fees_total = 1.0;
-        long_lose.exit_value_gross = 80.0;
-        long_lose.exit_fees_total = 1.0;
+        long_lose.enter_value_gross = Decimal::ONE_HUNDRED;
+        long_lose.enter_fees_total = Decimal::ONE;
+        long_lose.exit_value_gross = Decimal::from(80);
+        long_lose.exit_fees_total = Decimal::ONE;
 
         let mut short_win = position(); // Expected PnL = +18.0
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.enter_fees_total = 1.0;
-        short_win.exit_value_gross = 80.0;
-        short_win.exit_fees_total = 1.0;
+        short_win.enter_value_gross = Decimal::ONE_HUNDRED;
+        short_win.enter_fees_total = Decimal::ONE;
+        short_win.exit_value_gross = Decimal::from(80);
+        short_win.exit_fees_total = Decimal::ONE;
 
         let mut short_lose = position(); // Expected PnL = -22.0
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.enter_fees_total = 1.0;
-        short_lose.exit_value_gross = 120.0;
-        short_lose.exit_fees_total = 1.0;
+        short_lose.enter_value_gross = Decimal::ONE_HUNDRED;
+        short_lose.enter_fees_total = Decimal::ONE;
+        short_lose.exit_value_gross = Decimal::from(120);
+        short_lose.exit_fees_total = Decimal::ONE;
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
-        let expected_pnl = vec![18.0, -22.0, 18.0, -22.0];
+        let expected_pnl = vec![
+            Decimal::from(18),
+            Decimal::from(-22),
+            Decimal::from(18),
+            Decimal::from(-22),
+        ];
 
-        for (position, expected) in inputs.into_iter().zip(expected_pnl.into_iter()) {
+        for (position, expected) in inputs.into_iter().zip(expected_pnl) {
             let actual = position.calculate_realised_profit_loss();
             assert_eq!(actual, expected);
         }
@@ -1526,29 +1906,34 @@ mod tests {
     fn calculate_profit_loss_return() {
         let mut long_win = position(); // Expected Return = 0.08
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.realised_profit_loss = 8.0;
+        long_win.enter_value_gross = Decimal::ONE_HUNDRED;
+        long_win.realised_profit_loss = Decimal::from(8);
 
         let mut long_lose = position(); // Expected Return = -0.12
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.realised_profit_loss = -12.0;
+        long_lose.enter_value_gross = Decimal::ONE_HUNDRED;
+        long_lose.realised_profit_loss = Decimal::from(-12);
 
         let mut short_win = position(); // Expected Return = 0.08
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.realised_profit_loss = 8.0;
+        short_win.enter_value_gross = Decimal::ONE_HUNDRED;
+        short_win.realised_profit_loss = Decimal::from(8);
 
         let mut short_lose = position(); // Expected Return = -0.12
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.realised_profit_loss = -12.0;
+        short_lose.enter_value_gross = Decimal::ONE_HUNDRED;
+        short_lose.realised_profit_loss = Decimal::from(-12);
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
-        let expected_return = vec![0.08, -0.12, 0.08, -0.12];
+        let expected_return = vec![
+            Decimal::new(8, 2),
+            Decimal::new(-12, 2),
+            Decimal::new(8, 2),
+            Decimal::new(-12, 2),
+        ];
 
-        for (position, expected) in inputs.into_iter().zip(expected_return.into_iter()) {
+        for (position, expected) in inputs.into_iter().zip(expected_return) {
             let actual = position.calculate_profit_loss_return();
             assert_eq!(actual, expected);
         }
@@ -1569,9 +1954,9 @@ mod tests {
     #[test]
     fn position_update_from_position() {
         let mut input_position = position();
-        input_position.current_symbol_price = 100.0;
-        input_position.current_value_gross = 200.0;
-        input_position.unrealised_profit_loss = 150.0;
+        input_position.current_symbol_price = Decimal::ONE_HUNDRED;
+        input_position.current_value_gross = Decimal::from(200);
+        input_position.unrealised_profit_loss = Decimal::from(150);
 
         let actual_update = PositionUpdate::from(&mut input_position);
 
@@ -1597,19 +1982,19 @@ mod tests {
         exited_position.meta.update_time = time;
         exited_position.meta.exit_balance = Some(Balance {
             time,
-            total: 0.0,
-            available: 0.0,
+            total: Decimal::ZERO,
+            available: Decimal::ZERO,
         });
 
         exited_position.exit_fees = Fees {
-            exchange: 0.0,
-            slippage: 0.0,
-            network: 0.0,
+            exchange: Decimal::ZERO,
+            slippage: Decimal::ZERO,
+            network: Decimal::ZERO,
         };
-        exited_position.exit_fees_total = 0.0;
-        exited_position.exit_avg_price_gross = 100.0;
-        exited_position.exit_value_gross = 100.0;
-        exited_position.realised_profit_loss = 100.0;
+        exited_position.exit_fees_total = Decimal::ZERO;
+        exited_position.exit_avg_price_gross = Decimal::ONE_HUNDRED;
+        exited_position.exit_value_gross = Decimal::ONE_HUNDRED;
+        exited_position.realised_profit_loss = Decimal::ONE_HUNDRED;
 
         let actual_exit = PositionExit::try_from(&mut exited_position).unwrap();
 
@@ -52,7 +52,7 @@ where
         &mut self,
         position_id: &PositionId,
     ) -> Result<Option<Position>, RepositoryError> {
-        let redis_position_value: Option<String> = self.conn.get(&position_id).ok();
+        let redis_position_value: Option<String> = self.conn.get(position_id).ok();
         match redis_position_value {
             Some(value) => Ok(Some(serde_json::from_str::<Position>(&value)?)),
             None => Ok(None),
@@ -83,7 +83,7 @@ where
         let position = self.get_open_position(position_id)?;
 
         self.conn
-            .del(position_id)
+            .del::<_, ()>(position_id)
             .map_err(|_| RepositoryError::DeleteError)?;
 
         Ok(position)
@@ -179,7 +179,7 @@ where
     pub fn new(connection: Connection) -> Self {
         Self {
             conn: connection,
-            _statistic_marker: PhantomData::<Statistic>::default(),
+            _statistic_marker: PhantomData::<Statistic>,
         }
     }
 
@@ -214,7 +214,7 @@ where
     pub fn new() -> Self {
         Self {
             conn: None,
-            _statistic_marker: PhantomData::<Statistic>::default(),
+            _statistic_marker: PhantomData::<Statistic>,
         }
     }
 
@@ -228,7 +228,7 @@ where
     pub fn build(self) -> Result<RedisRepository<Statistic>, PortfolioError> {
         Ok(RedisRepository {
             conn: self.conn.ok_or(PortfolioError::BuilderIncomplete("conn"))?,
-            _statistic_marker: PhantomData::<Statistic>::default(),
+            _statistic_marker: PhantomData::<Statistic>,
         })
     }
 }
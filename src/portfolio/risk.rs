@@ -1,6 +1,10 @@
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 
-use crate::portfolio::{OrderEvent, OrderType};
+use crate::{
+    portfolio::{decimal_from_f64, error::PortfolioError, OrderEvent, OrderType},
+    strategy::Decision,
+};
 
 /// Evaluates the risk associated with an [`OrderEvent`] to determine if it should be actioned. It
 /// can also amend the order (eg/ [`OrderType`]) to better fit the risk strategy required for
@@ -34,3 +38,280 @@ impl DefaultRisk {
         false
     }
 }
+
+/// Risk manager that implements [`OrderEvaluator`] by capping the notional value of every entry
+/// [`OrderEvent`] at `max_notional`. Over-sized entry orders are downsized rather than refused.
+/// Exit orders are never amended so an open Position can always be closed in full.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MaxNotionalRisk {
+    /// Maximum notional value (abs(quantity) * price) of an entry [`OrderEvent`].
+    pub max_notional: Decimal,
+}
+
+impl OrderEvaluator for MaxNotionalRisk {
+    const DEFAULT_ORDER_TYPE: OrderType = OrderType::Market;
+
+    fn evaluate_order(&self, mut order: OrderEvent) -> Option<OrderEvent> {
+        if order.decision.is_entry() {
+            let price = order.market_meta.close;
+            if price <= Decimal::ZERO || self.max_notional <= Decimal::ZERO {
+                return None;
+            }
+
+            // Downsize the order quantity if it exceeds the maximum notional value
+            if order.quantity.abs() * price > self.max_notional {
+                let quantity = self.max_notional / price;
+                order.quantity = if order.quantity.is_sign_negative() {
+                    -quantity
+                } else {
+                    quantity
+                };
+            }
+        }
+
+        order.order_type = MaxNotionalRisk::DEFAULT_ORDER_TYPE;
+        Some(order)
+    }
+}
+
+/// Short-selling constraints of an instrument that must be borrowed to be shorted (eg/ an
+/// equity). Checked before every sell [`OrderEvent`] that would open or extend a short
+/// Position. Sells that close an existing long Position are always allowed.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct ShortConstraint {
+    /// Whether the instrument can be shorted at all.
+    pub shortable: bool,
+    /// Quantity of the instrument currently available to borrow.
+    pub borrow_available: Decimal,
+}
+
+impl ShortConstraint {
+    /// Constrains the [`OrderEvent`] to the available borrow. Short entries exceeding the
+    /// available borrow are downsized to it, and refused if the instrument is not shortable or
+    /// no borrow is available. Every other [`OrderEvent`] is returned unchanged.
+    pub fn constrain_order(&self, mut order: OrderEvent) -> Result<OrderEvent, PortfolioError> {
+        if order.decision != Decision::Short {
+            return Ok(order);
+        }
+        if !self.shortable {
+            return Err(PortfolioError::ShortRefused("instrument is not shortable"));
+        }
+        if self.borrow_available <= Decimal::ZERO {
+            return Err(PortfolioError::ShortRefused("no borrow available"));
+        }
+
+        // Downsize the short quantity if it exceeds the available borrow
+        if order.quantity.abs() > self.borrow_available {
+            order.quantity = -self.borrow_available;
+        }
+        Ok(order)
+    }
+}
+
+/// Cash-balance guard preventing spot buy [`OrderEvent`]s from over-spending the available quote
+/// asset cash. Checked before every buy [`OrderEvent`] is sent for execution, against the
+/// available cash less the cash already committed to buy [`OrderEvent`]s awaiting execution
+/// (eg/ resting limit orders).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct CashGuard {
+    /// Estimated fees as a fraction of the order notional value (eg/ 0.001 for 0.1%).
+    pub fee_rate: f64,
+    /// Whether buys exceeding the available cash are downsized to it, rather than refused.
+    pub downsize: bool,
+}
+
+impl CashGuard {
+    /// Returns the cash required by the [`OrderEvent`]: it's notional value at the
+    /// [`MarketMeta`](crate::data::MarketMeta) close price plus the estimated fees. Sells
+    /// require no cash.
+    pub fn required_cash(&self, order: &OrderEvent) -> Decimal {
+        if order.quantity <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        order.quantity * self.unit_cash(order)
+    }
+
+    /// Returns the cash required per unit quantity of the [`OrderEvent`], including the
+    /// estimated fees.
+    fn unit_cash(&self, order: &OrderEvent) -> Decimal {
+        order.market_meta.close * (Decimal::ONE + decimal_from_f64(self.fee_rate))
+    }
+
+    /// Constrains the buy [`OrderEvent`] to the available cash. Buys requiring more cash are
+    /// downsized to it (rounded down to 4 decimal places) if configured, and refused otherwise
+    /// or if no quantity can be afforded. Sells are returned unchanged.
+    pub fn constrain_order(
+        &self,
+        mut order: OrderEvent,
+        available_cash: Decimal,
+    ) -> Result<OrderEvent, PortfolioError> {
+        let required = self.required_cash(&order);
+        if required <= available_cash {
+            return Ok(order);
+        }
+
+        let insufficient = PortfolioError::InsufficientCash {
+            required,
+            available: available_cash,
+        };
+        let unit_cash = self.unit_cash(&order);
+        if !self.downsize || available_cash <= Decimal::ZERO || unit_cash <= Decimal::ZERO {
+            return Err(insufficient);
+        }
+
+        let quantity = (available_cash / unit_cash)
+            .round_dp_with_strategy(4, RoundingStrategy::ToNegativeInfinity);
+        if quantity <= Decimal::ZERO {
+            return Err(insufficient);
+        }
+        order.quantity = quantity;
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::order_event;
+
+    #[test]
+    fn max_notional_risk_should_approve_order_within_limit() {
+        let risk = MaxNotionalRisk {
+            max_notional: Decimal::ONE_THOUSAND,
+        };
+
+        let mut input_order = order_event();
+        input_order.market_meta.close = Decimal::ONE_HUNDRED;
+        input_order.quantity = Decimal::from(5);
+
+        let actual = risk.evaluate_order(input_order.clone()).unwrap();
+
+        assert_eq!(actual, input_order);
+    }
+
+    #[test]
+    fn max_notional_risk_should_downsize_order_exceeding_limit() {
+        let risk = MaxNotionalRisk {
+            max_notional: Decimal::ONE_THOUSAND,
+        };
+
+        struct TestCase {
+            decision: Decision,
+            quantity: Decimal,
+            expected_quantity: Decimal,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                decision: Decision::Long,
+                quantity: Decimal::from(50),
+                expected_quantity: Decimal::TEN,
+            },
+            TestCase {
+                decision: Decision::Short,
+                quantity: -Decimal::from(50),
+                expected_quantity: -Decimal::TEN,
+            },
+        ];
+
+        for (index, test) in test_cases.into_iter().enumerate() {
+            let mut input_order = order_event();
+            input_order.market_meta.close = Decimal::ONE_HUNDRED;
+            input_order.decision = test.decision;
+            input_order.quantity = test.quantity;
+
+            let actual = risk.evaluate_order(input_order).unwrap();
+            assert_eq!(
+                actual.quantity, test.expected_quantity,
+                "TestCase {} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn max_notional_risk_should_not_downsize_exit_order() {
+        let risk = MaxNotionalRisk {
+            max_notional: Decimal::ONE_THOUSAND,
+        };
+
+        let mut input_order = order_event();
+        input_order.market_meta.close = Decimal::ONE_HUNDRED;
+        input_order.decision = Decision::CloseLong;
+        input_order.quantity = -Decimal::from(50);
+
+        let actual = risk.evaluate_order(input_order).unwrap();
+
+        assert_eq!(actual.quantity, -Decimal::from(50));
+    }
+
+    #[test]
+    fn max_notional_risk_should_refuse_entry_order_without_valid_price() {
+        let risk = MaxNotionalRisk {
+            max_notional: Decimal::ONE_THOUSAND,
+        };
+
+        let mut input_order = order_event();
+        input_order.market_meta.close = Decimal::ZERO;
+
+        assert!(risk.evaluate_order(input_order).is_none());
+    }
+
+    fn short_order(quantity: Decimal) -> OrderEvent {
+        OrderEvent {
+            decision: Decision::Short,
+            quantity,
+            ..order_event()
+        }
+    }
+
+    #[test]
+    fn short_constraint_should_refuse_short_of_non_shortable_instrument() {
+        let constraint = ShortConstraint {
+            shortable: false,
+            borrow_available: Decimal::from(100),
+        };
+
+        assert!(matches!(
+            constraint.constrain_order(short_order(-Decimal::ONE)),
+            Err(PortfolioError::ShortRefused(_))
+        ));
+    }
+
+    #[test]
+    fn short_constraint_should_downsize_short_to_available_borrow() {
+        let constraint = ShortConstraint {
+            shortable: true,
+            borrow_available: Decimal::from(3),
+        };
+
+        let actual = constraint
+            .constrain_order(short_order(-Decimal::TEN))
+            .unwrap();
+        assert_eq!(actual.quantity, -Decimal::from(3));
+
+        // Shorts within the available borrow are unchanged
+        let actual = constraint
+            .constrain_order(short_order(-Decimal::TWO))
+            .unwrap();
+        assert_eq!(actual.quantity, -Decimal::TWO);
+    }
+
+    #[test]
+    fn short_constraint_should_permit_sell_closing_a_long_regardless() {
+        let constraint = ShortConstraint {
+            shortable: false,
+            borrow_available: Decimal::ZERO,
+        };
+
+        let close_long = OrderEvent {
+            decision: Decision::CloseLong,
+            quantity: -Decimal::TEN,
+            ..order_event()
+        };
+        let actual = constraint.constrain_order(close_long.clone()).unwrap();
+        assert_eq!(actual, close_long);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::portfolio::{OrderEvent, OrderType};
+
+/// Result of netting two opposing [`OrderEvent`]s that would self-match on the exchange.
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub struct SelfMatch {
+    /// Both [`OrderEvent`]s, with the quantity of each that was crossed internally rather than
+    /// sent to the exchange.
+    pub crossed: [OrderEvent; 2],
+    /// Residual of the larger [`OrderEvent`] left to be sent to the exchange, if the
+    /// [`OrderEvent`]s were not of equal quantity.
+    pub residual: Option<OrderEvent>,
+}
+
+/// Determines if the two [`OrderEvent`]s would match against each other if both were sent to
+/// the exchange, ie/ they are of the same account & instrument, in opposing directions, and
+/// at crossing prices.
+///
+/// Market orders cross any price. Limit orders only cross if the buy limit price is at or above
+/// the sell limit price. Stop & bracket orders are never deemed to self-match, since they do not
+/// rest at a known price.
+pub fn is_self_match(order: &OrderEvent, other: &OrderEvent) -> bool {
+    if order.account != other.account
+        || order.exchange != other.exchange
+        || order.instrument != other.instrument
+        || order.quantity.is_sign_positive() == other.quantity.is_sign_positive()
+    {
+        return false;
+    }
+
+    let (buy, sell) = if order.quantity.is_sign_positive() {
+        (order, other)
+    } else {
+        (other, order)
+    };
+
+    match (buy.order_type, sell.order_type) {
+        (OrderType::Market, OrderType::Market | OrderType::Limit)
+        | (OrderType::Limit, OrderType::Market) => true,
+        (OrderType::Limit, OrderType::Limit) => buy.market_meta.close >= sell.market_meta.close,
+        _ => false,
+    }
+}
+
+/// Nets the two self-matching [`OrderEvent`]s (see [`is_self_match`]) against each other, so
+/// only the residual quantity of the larger [`OrderEvent`] reaches the exchange.
+pub fn net_self_match(order: OrderEvent, other: OrderEvent) -> SelfMatch {
+    let crossed = order.quantity.abs().min(other.quantity.abs());
+    let residual = order.quantity + other.quantity;
+
+    let residual = if residual.is_zero() {
+        None
+    } else if residual.is_sign_positive() == order.quantity.is_sign_positive() {
+        Some(OrderEvent {
+            quantity: residual,
+            ..order.clone()
+        })
+    } else {
+        Some(OrderEvent {
+            quantity: residual,
+            ..other.clone()
+        })
+    };
+
+    let with_crossed = |order: OrderEvent| OrderEvent {
+        quantity: if order.quantity.is_sign_positive() {
+            crossed
+        } else {
+            -crossed
+        },
+        ..order
+    };
+
+    SelfMatch {
+        crossed: [with_crossed(order), with_crossed(other)],
+        residual,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data::MarketMeta, strategy::Decision, test_util::order_event};
+    use rust_decimal::Decimal;
+
+    fn order(quantity: i64, order_type: OrderType, price: i64) -> OrderEvent {
+        OrderEvent {
+            decision: if quantity > 0 {
+                Decision::Long
+            } else {
+                Decision::Short
+            },
+            quantity: Decimal::from(quantity),
+            order_type,
+            market_meta: MarketMeta {
+                close: Decimal::from(price),
+                ..MarketMeta::default()
+            },
+            ..order_event()
+        }
+    }
+
+    #[test]
+    fn is_self_match_should_only_match_opposing_orders_at_crossing_prices() {
+        struct TestCase {
+            order: OrderEvent,
+            other: OrderEvent,
+            expected: bool,
+        }
+
+        let cases = vec![
+            // TC0: Opposing market orders
+            TestCase {
+                order: order(5, OrderType::Market, 100),
+                other: order(-3, OrderType::Market, 100),
+                expected: true,
+            },
+            // TC1: Market buy crosses any limit sell
+            TestCase {
+                order: order(5, OrderType::Market, 100),
+                other: order(-3, OrderType::Limit, 150),
+                expected: true,
+            },
+            // TC2: Limit buy at or above the limit sell price crosses
+            TestCase {
+                order: order(-3, OrderType::Limit, 100),
+                other: order(5, OrderType::Limit, 100),
+                expected: true,
+            },
+            // TC3: Limit buy below the limit sell price does not cross
+            TestCase {
+                order: order(5, OrderType::Limit, 99),
+                other: order(-3, OrderType::Limit, 100),
+                expected: false,
+            },
+            // TC4: Orders in the same direction
+            TestCase {
+                order: order(5, OrderType::Market, 100),
+                other: order(3, OrderType::Market, 100),
+                expected: false,
+            },
+            // TC5: Stop limit orders do not rest at a known price
+            TestCase {
+                order: order(5, OrderType::Market, 100),
+                other: order(-3, OrderType::StopLimit, 100),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            assert_eq!(
+                is_self_match(&test.order, &test.other),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn net_self_match_should_leave_residual_of_the_larger_order() {
+        let netted = net_self_match(
+            order(5, OrderType::Market, 100),
+            order(-3, OrderType::Market, 100),
+        );
+
+        let residual = netted.residual.unwrap();
+        assert_eq!(residual.quantity, Decimal::TWO);
+        assert_eq!(residual.decision, Decision::Long);
+        assert_eq!(netted.crossed[0].quantity, Decimal::from(3));
+        assert_eq!(netted.crossed[1].quantity, Decimal::from(-3));
+
+        let netted = net_self_match(
+            order(3, OrderType::Market, 100),
+            order(-3, OrderType::Market, 100),
+        );
+        assert!(netted.residual.is_none());
+    }
+}
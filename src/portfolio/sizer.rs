@@ -0,0 +1,374 @@
+use crate::{
+    data::MarketMeta,
+    portfolio::{decimal_from_f64, decimal_to_f64},
+    strategy::SignalStrength,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::Market;
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Debug,
+};
+
+/// Sizes the quantity of an entry [`OrderEvent`](super::OrderEvent) from the
+/// [`SignalStrength`], the Portfolio equity & the market price, so strategies need not compute
+/// quantities themselves.
+pub trait PositionSizer: Debug {
+    /// Updates any state the sizer derives from market data (eg/ realised volatility). Defaults
+    /// to a no-op for sizers that only use the latest price.
+    fn update_from_market(&mut self, _market: &MarketEvent<DataKind>) {}
+
+    /// Returns the absolute quantity of the [`Market`] to enter, or `None` if no size can be
+    /// determined (eg/ the sizer is still warming up).
+    fn size(
+        &self,
+        market: &Market,
+        signal_strength: SignalStrength,
+        equity: f64,
+        price: f64,
+    ) -> Option<Decimal>;
+}
+
+/// [`PositionSizer`] entering a fixed notional value per unit of [`SignalStrength`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct FixedNotional {
+    pub notional: f64,
+}
+
+impl PositionSizer for FixedNotional {
+    fn size(
+        &self,
+        _: &Market,
+        signal_strength: SignalStrength,
+        _: f64,
+        price: f64,
+    ) -> Option<Decimal> {
+        quantity_of_notional(self.notional * signal_strength.0, price)
+    }
+}
+
+/// [`PositionSizer`] entering a percentage of the Portfolio equity per unit of
+/// [`SignalStrength`], in decimal form (eg/ 0.1 for 10%).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct PercentOfEquity {
+    pub percent: f64,
+}
+
+impl PositionSizer for PercentOfEquity {
+    fn size(
+        &self,
+        _: &Market,
+        signal_strength: SignalStrength,
+        equity: f64,
+        price: f64,
+    ) -> Option<Decimal> {
+        quantity_of_notional(equity * self.percent * signal_strength.0, price)
+    }
+}
+
+/// [`PositionSizer`] sizing inversely to the recent realised volatility of each [`Market`], so
+/// every Position contributes a similar amount of risk.
+///
+/// The entered notional is the Portfolio equity scaled by the ratio of the target volatility to
+/// the realised volatility, per unit of [`SignalStrength`]. Realised volatility is the standard
+/// deviation of the returns between the prices of the latest `window` + 1 [`MarketEvent`]s, so
+/// the target volatility is per [`MarketEvent`] period (eg/ per candle). No size is produced
+/// until a [`Market`] has a full window of returns.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct VolatilityTargeted {
+    target_volatility: f64,
+    window: usize,
+    /// Latest price & rolling window of returns of each [`Market`].
+    #[serde(skip)]
+    markets: HashMap<Market, (f64, VecDeque<f64>)>,
+}
+
+impl VolatilityTargeted {
+    /// Constructs a new [`VolatilityTargeted`] sizer targeting the provided volatility per
+    /// [`MarketEvent`] period (eg/ 0.01 for 1%), measured over a rolling window of returns.
+    pub fn new(target_volatility: f64, window: usize) -> Self {
+        Self {
+            target_volatility,
+            window: window.max(2),
+            markets: HashMap::new(),
+        }
+    }
+
+    /// Returns the realised volatility of the [`Market`], or `None` if it is still warming up.
+    pub fn realised_volatility(&self, market: &Market) -> Option<f64> {
+        let (_, returns) = self.markets.get(market)?;
+        if returns.len() < self.window {
+            return None;
+        }
+
+        let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+        let variance = returns
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / (returns.len() - 1) as f64;
+        Some(variance.sqrt())
+    }
+}
+
+impl PositionSizer for VolatilityTargeted {
+    fn update_from_market(&mut self, market: &MarketEvent<DataKind>) {
+        let Some(MarketMeta { close, .. }) = MarketMeta::from_market(market) else {
+            return;
+        };
+        let close = decimal_to_f64(close);
+        if close <= 0.0 {
+            return;
+        }
+
+        let window = self.window;
+        let key = Market::new(market.exchange.clone(), market.instrument.clone());
+        match self.markets.get_mut(&key) {
+            Some((latest, returns)) => {
+                returns.push_back(close / *latest - 1.0);
+                if returns.len() > window {
+                    returns.pop_front();
+                }
+                *latest = close;
+            }
+            None => {
+                self.markets
+                    .insert(key, (close, VecDeque::with_capacity(window)));
+            }
+        }
+    }
+
+    fn size(
+        &self,
+        market: &Market,
+        signal_strength: SignalStrength,
+        equity: f64,
+        price: f64,
+    ) -> Option<Decimal> {
+        let volatility = self.realised_volatility(market)?;
+        if volatility <= 0.0 {
+            return None;
+        }
+
+        let notional = equity * (self.target_volatility / volatility) * signal_strength.0;
+        quantity_of_notional(notional, price)
+    }
+}
+
+/// Scales the quantity of every entry [`OrderEvent`](super::OrderEvent) of a Portfolio by the
+/// ratio of the target volatility to the realised volatility of it's equity curve, so the
+/// Portfolio as a whole targets a volatility regardless of how many Positions are open.
+///
+/// The Portfolio equity (the [`Balance`](super::Balance) total plus the unrealised P&L of the
+/// open Positions) is sampled from the first [`MarketEvent`] at least `interval` after the
+/// previous sample. Realised volatility is the standard deviation of the returns between the
+/// latest `window` + 1 equity samples, so the target volatility is per `interval`. The scale is
+/// clamped to the max leverage, & no scale is produced until a full window of returns has been
+/// sampled.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PortfolioVolTargeter {
+    target_volatility: f64,
+    window: usize,
+    interval: Duration,
+    max_leverage: f64,
+    /// [`Market`]s of every [`MarketEvent`] seen, any of which may have an open Position.
+    markets: HashSet<Market>,
+    /// Time & equity of the latest equity sample.
+    last_sample: Option<(DateTime<Utc>, f64)>,
+    /// Rolling window of returns between equity samples.
+    returns: VecDeque<f64>,
+}
+
+impl PortfolioVolTargeter {
+    /// Constructs a new [`PortfolioVolTargeter`] targeting the provided volatility per
+    /// `interval` (eg/ 0.01 for 1%), measured over a rolling window of equity returns sampled
+    /// every `interval`. The scale is clamped to a max leverage of 1.0 by default.
+    pub fn new(target_volatility: f64, window: usize, interval: Duration) -> Self {
+        Self {
+            target_volatility,
+            window: window.max(2),
+            interval,
+            max_leverage: 1.0,
+            markets: HashSet::new(),
+            last_sample: None,
+            returns: VecDeque::with_capacity(window.max(2)),
+        }
+    }
+
+    /// Replaces the max leverage the scale is clamped to (eg/ 2.0 to at most double sizes).
+    pub fn with_max_leverage(mut self, value: f64) -> Self {
+        self.max_leverage = value;
+        self
+    }
+
+    /// [`Market`]s of every [`MarketEvent`] seen, used to value the open Positions.
+    pub fn markets(&self) -> impl Iterator<Item = &Market> {
+        self.markets.iter()
+    }
+
+    /// Records the [`Market`] of the [`MarketEvent`], returning true if the Portfolio equity is
+    /// due to be sampled at it's time.
+    pub fn update_from_market(&mut self, market: &MarketEvent<DataKind>) -> bool {
+        self.markets.insert(Market::new(
+            market.exchange.clone(),
+            market.instrument.clone(),
+        ));
+
+        match self.last_sample {
+            Some((time, _)) => market.exchange_time >= time + self.interval,
+            None => true,
+        }
+    }
+
+    /// Records a sample of the Portfolio equity at the provided time.
+    pub fn sample(&mut self, time: DateTime<Utc>, equity: f64) {
+        if let Some((_, last_equity)) = self.last_sample {
+            if last_equity > 0.0 {
+                self.returns.push_back(equity / last_equity - 1.0);
+                if self.returns.len() > self.window {
+                    self.returns.pop_front();
+                }
+            }
+        }
+        self.last_sample = Some((time, equity));
+    }
+
+    /// Returns the realised volatility of the Portfolio equity, or `None` if it is still warming
+    /// up.
+    pub fn realised_volatility(&self) -> Option<f64> {
+        if self.returns.len() < self.window {
+            return None;
+        }
+
+        let mean = self.returns.iter().sum::<f64>() / self.returns.len() as f64;
+        let variance = self
+            .returns
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / (self.returns.len() - 1) as f64;
+        Some(variance.sqrt())
+    }
+
+    /// Returns the scale applied to entry quantities, ie/ the target volatility over the
+    /// realised volatility clamped to the max leverage, or `None` if it is still warming up.
+    pub fn scale(&self) -> Option<f64> {
+        let volatility = self.realised_volatility()?;
+        match volatility > 0.0 {
+            true => Some((self.target_volatility / volatility).min(self.max_leverage)),
+            false => Some(self.max_leverage),
+        }
+    }
+
+    /// Scales the signed quantity of an entry [`OrderEvent`](super::OrderEvent), rounded towards
+    /// zero to 4 decimal places. Returns `None` if still warming up or the scaled quantity is
+    /// zero.
+    pub fn scale_quantity(&self, quantity: Decimal) -> Option<Decimal> {
+        let scaled = (quantity * decimal_from_f64(self.scale()?))
+            .round_dp_with_strategy(4, RoundingStrategy::ToZero);
+        (!scaled.is_zero()).then_some(scaled)
+    }
+}
+
+/// Converts the notional value into a quantity at the price, rounded down to 4 decimal places
+/// like the [`DefaultAllocator`](super::allocator::DefaultAllocator). Returns `None` if the
+/// resulting quantity is not positive.
+fn quantity_of_notional(notional: f64, price: f64) -> Option<Decimal> {
+    if !notional.is_finite() || price <= 0.0 {
+        return None;
+    }
+
+    let quantity = decimal_from_f64(notional / price)
+        .round_dp_with_strategy(4, RoundingStrategy::ToNegativeInfinity);
+    (quantity > Decimal::ZERO).then_some(quantity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+
+    fn market() -> Market {
+        Market::new("binance", ("btc", "usdt", InstrumentKind::Spot))
+    }
+
+    #[test]
+    fn fixed_notional_should_size_notional_per_unit_of_signal_strength() {
+        let sizer = FixedNotional { notional: 1000.0 };
+
+        assert_eq!(
+            sizer.size(&market(), SignalStrength(1.0), 50_000.0, 200.0),
+            Some(Decimal::new(5, 0))
+        );
+        assert_eq!(
+            sizer.size(&market(), SignalStrength(0.5), 50_000.0, 200.0),
+            Some(Decimal::new(25, 1))
+        );
+        // Quantity below the rounding precision cannot be sized
+        assert_eq!(
+            sizer.size(&market(), SignalStrength(1.0), 50_000.0, 100_000_000.0),
+            None
+        );
+    }
+
+    #[test]
+    fn percent_of_equity_should_size_percentage_of_the_portfolio_equity() {
+        let sizer = PercentOfEquity { percent: 0.1 };
+
+        // 10% of 20_000.0 equity at 400.0 per unit
+        assert_eq!(
+            sizer.size(&market(), SignalStrength(1.0), 20_000.0, 400.0),
+            Some(Decimal::new(5, 0))
+        );
+        // Size grows with the equity
+        assert_eq!(
+            sizer.size(&market(), SignalStrength(1.0), 40_000.0, 400.0),
+            Some(Decimal::new(10, 0))
+        );
+    }
+
+    #[test]
+    fn volatility_targeted_should_only_size_once_warmed_up() {
+        let mut sizer = VolatilityTargeted::new(0.01, 3);
+        let priced = |price| {
+            let mut event = market_event_trade(Side::Buy);
+            event.exchange = market().exchange;
+            event.instrument = market().instrument;
+            if let DataKind::Trade(trade) = &mut event.kind {
+                trade.price = price;
+            }
+            event
+        };
+
+        // Three returns require four prices, alternating +/-2% returns
+        for price in [100.0, 102.0, 99.96, 101.9592] {
+            assert_eq!(
+                sizer.size(&market(), SignalStrength(1.0), 10_000.0, 100.0),
+                None
+            );
+            sizer.update_from_market(&priced(price));
+        }
+
+        let volatility = sizer.realised_volatility(&market()).unwrap();
+        assert!(volatility > 0.0);
+
+        // Notional is the equity scaled by the target over the realised volatility
+        let quantity = sizer
+            .size(&market(), SignalStrength(1.0), 10_000.0, 100.0)
+            .unwrap();
+        let expected = decimal_from_f64(10_000.0 * (0.01 / volatility) / 100.0)
+            .round_dp_with_strategy(4, RoundingStrategy::ToNegativeInfinity);
+        assert_eq!(quantity, expected);
+
+        // Markets without a full window of returns are still warming up
+        let other = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+        assert_eq!(
+            sizer.size(&other, SignalStrength(1.0), 10_000.0, 100.0),
+            None
+        );
+    }
+}
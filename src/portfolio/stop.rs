@@ -0,0 +1,225 @@
+use crate::portfolio::{decimal_from_f64, position::Position};
+use barter_integration::model::{Market, Side};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Distance of a stop-loss or take-profit trigger price from the entry price of a [`Position`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum StopOffset {
+    /// Absolute price distance from the entry price.
+    Absolute(Decimal),
+    /// Percentage distance from the entry price, in decimal form (eg/ 0.05 for 5%).
+    Percent(f64),
+}
+
+impl StopOffset {
+    /// Calculates the absolute price distance of this [`StopOffset`] from the entry price.
+    pub fn distance(&self, entry_price: Decimal) -> Decimal {
+        match self {
+            StopOffset::Absolute(distance) => distance.abs(),
+            StopOffset::Percent(pct) => (entry_price * decimal_from_f64(*pct)).abs(),
+        }
+    }
+}
+
+/// Stop-loss & take-profit configuration for the [`Position`]s of a [`Market`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct StopConfig {
+    /// [`StopOffset`] of the price at which a losing [`Position`] is exited.
+    pub stop_loss: Option<StopOffset>,
+    /// [`StopOffset`] of the price at which a winning [`Position`] is exited.
+    pub take_profit: Option<StopOffset>,
+}
+
+/// Communicates which threshold of a [`StopConfig`] was crossed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum StopTrigger {
+    StopLoss,
+    TakeProfit,
+}
+
+/// Trigger prices of a [`Position`], derived from its [`StopConfig`] & average entry price.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct StopPrices {
+    pub stop_loss: Option<Decimal>,
+    pub take_profit: Option<Decimal>,
+}
+
+/// Manages the stop-loss & take-profit thresholds of open [`Position`]s, configured per
+/// [`Market`]. Checked by the [`Trader`](crate::engine::trader::Trader) on every market price
+/// update, so strategies do not need to reimplement stops.
+///
+/// Trigger prices are derived from the current average entry price of the [`Position`] every time
+/// they are checked, so a [`Position`] that is re-averaged after entry has its triggers
+/// recomputed from the new average cost.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct StopManager {
+    stops: HashMap<Market, StopConfig>,
+}
+
+impl StopManager {
+    /// Constructs a new [`StopManager`] with no [`StopConfig`]s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the [`StopConfig`] used for the [`Position`]s of the provided [`Market`].
+    pub fn with_stop(mut self, market: Market, config: StopConfig) -> Self {
+        self.stops.insert(market, config);
+        self
+    }
+
+    /// Returns the [`StopConfig`] of the provided [`Market`], if one is configured.
+    pub fn config(&self, market: &Market) -> Option<&StopConfig> {
+        self.stops.get(market)
+    }
+
+    /// Calculates the [`StopPrices`] of the provided [`Position`] from its average entry price.
+    /// Returns `None` if no [`StopConfig`] is configured for the [`Position`] [`Market`].
+    pub fn stop_prices(&self, position: &Position) -> Option<StopPrices> {
+        let config = self.stops.get(&Market::new(
+            position.exchange.clone(),
+            position.instrument.clone(),
+        ))?;
+        let entry_price = position.enter_avg_price_gross;
+
+        // Losses occur below the entry price for a long Position, and above it for a short
+        let direction = match position.side {
+            Side::Buy => Decimal::ONE,
+            Side::Sell => Decimal::NEGATIVE_ONE,
+        };
+
+        Some(StopPrices {
+            stop_loss: config
+                .stop_loss
+                .map(|offset| entry_price - direction * offset.distance(entry_price)),
+            take_profit: config
+                .take_profit
+                .map(|offset| entry_price + direction * offset.distance(entry_price)),
+        })
+    }
+
+    /// Determines if the provided price crosses a threshold of the [`Position`], returning the
+    /// [`StopTrigger`] crossed. A stop-loss takes precedence if both are crossed.
+    pub fn check(&self, position: &Position, price: Decimal) -> Option<StopTrigger> {
+        let prices = self.stop_prices(position)?;
+
+        let (stop_loss_hit, take_profit_hit) = match position.side {
+            Side::Buy => (
+                prices.stop_loss.is_some_and(|stop| price <= stop),
+                prices.take_profit.is_some_and(|take| price >= take),
+            ),
+            Side::Sell => (
+                prices.stop_loss.is_some_and(|stop| price >= stop),
+                prices.take_profit.is_some_and(|take| price <= take),
+            ),
+        };
+
+        if stop_loss_hit {
+            Some(StopTrigger::StopLoss)
+        } else if take_profit_hit {
+            Some(StopTrigger::TakeProfit)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::position;
+
+    fn market() -> Market {
+        let position = position();
+        Market::new(position.exchange, position.instrument)
+    }
+
+    fn position_at(side: Side, enter_avg_price_gross: i64) -> Position {
+        Position {
+            side,
+            enter_avg_price_gross: Decimal::from(enter_avg_price_gross),
+            ..position()
+        }
+    }
+
+    #[test]
+    fn long_position_should_trigger_stop_loss_at_offset_below_entry() {
+        let stops = StopManager::new().with_stop(
+            market(),
+            StopConfig {
+                stop_loss: Some(StopOffset::Percent(0.05)),
+                take_profit: Some(StopOffset::Percent(0.10)),
+            },
+        );
+        let long = position_at(Side::Buy, 100);
+
+        assert_eq!(stops.check(&long, Decimal::from(96)), None);
+        assert_eq!(
+            stops.check(&long, Decimal::from(95)),
+            Some(StopTrigger::StopLoss)
+        );
+        assert_eq!(
+            stops.check(&long, Decimal::from(80)),
+            Some(StopTrigger::StopLoss)
+        );
+    }
+
+    #[test]
+    fn short_position_should_trigger_take_profit_at_offset_below_entry() {
+        let stops = StopManager::new().with_stop(
+            market(),
+            StopConfig {
+                stop_loss: Some(StopOffset::Absolute(Decimal::from(5))),
+                take_profit: Some(StopOffset::Absolute(Decimal::TEN)),
+            },
+        );
+        let short = position_at(Side::Sell, 100);
+
+        assert_eq!(stops.check(&short, Decimal::from(91)), None);
+        assert_eq!(
+            stops.check(&short, Decimal::from(90)),
+            Some(StopTrigger::TakeProfit)
+        );
+        assert_eq!(
+            stops.check(&short, Decimal::from(105)),
+            Some(StopTrigger::StopLoss)
+        );
+    }
+
+    #[test]
+    fn re_averaged_position_should_recompute_triggers_from_new_average_cost() {
+        let stops = StopManager::new().with_stop(
+            market(),
+            StopConfig {
+                stop_loss: Some(StopOffset::Percent(0.10)),
+                take_profit: None,
+            },
+        );
+
+        let mut long = position_at(Side::Buy, 100);
+        assert_eq!(
+            stops.stop_prices(&long).unwrap().stop_loss,
+            Some(Decimal::from(90))
+        );
+        assert_eq!(
+            stops.check(&long, Decimal::from(85)),
+            Some(StopTrigger::StopLoss)
+        );
+
+        // Adding to the Position at a lower price reduces the average cost
+        long.enter_avg_price_gross = Decimal::from(80);
+        assert_eq!(
+            stops.stop_prices(&long).unwrap().stop_loss,
+            Some(Decimal::from(72))
+        );
+        assert_eq!(stops.check(&long, Decimal::from(85)), None);
+    }
+
+    #[test]
+    fn position_without_stop_config_should_never_trigger() {
+        let long = position_at(Side::Buy, 100);
+        assert_eq!(StopManager::new().check(&long, Decimal::ZERO), None);
+    }
+}
@@ -0,0 +1,96 @@
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+/// Number of buckets in a [`LatencyHistogram`].
+pub const LATENCY_BUCKETS: usize = 16;
+
+/// Histogram of latencies (eg/ order round-trip latency) bucketed by powers of two milliseconds.
+///
+/// Bucket 0 counts latencies below 1ms, bucket `n` counts latencies in the range
+/// `[2^(n-1)ms, 2^n ms)`, and the final bucket counts every latency above that.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct LatencyHistogram {
+    /// Number of latencies recorded.
+    pub count: u64,
+    /// Sum of every latency recorded, in milliseconds.
+    pub total_ms: i64,
+    /// Smallest latency recorded, in milliseconds.
+    pub min_ms: Option<i64>,
+    /// Largest latency recorded, in milliseconds.
+    pub max_ms: Option<i64>,
+    /// Number of latencies recorded in each bucket.
+    pub buckets: [u64; LATENCY_BUCKETS],
+}
+
+impl LatencyHistogram {
+    /// Records the latency provided, returning false without recording it if it's negative
+    /// (eg/ due to the start & end timestamps being taken from different clocks).
+    pub fn record(&mut self, latency: Duration) -> bool {
+        let latency_ms = latency.num_milliseconds();
+        if latency_ms < 0 {
+            return false;
+        }
+
+        self.count += 1;
+        self.total_ms += latency_ms;
+        self.min_ms = Some(self.min_ms.map_or(latency_ms, |min| min.min(latency_ms)));
+        self.max_ms = Some(self.max_ms.map_or(latency_ms, |max| max.max(latency_ms)));
+        self.buckets[Self::bucket(latency_ms)] += 1;
+        true
+    }
+
+    /// Calculates the mean latency recorded, returning None if no latencies have been recorded.
+    pub fn mean(&self) -> Option<Duration> {
+        (self.count > 0).then(|| Duration::milliseconds(self.total_ms / self.count as i64))
+    }
+
+    /// Determines the index of the bucket the provided non-negative latency falls into.
+    fn bucket(latency_ms: i64) -> usize {
+        let bucket = (i64::BITS - latency_ms.leading_zeros()) as usize;
+        bucket.min(LATENCY_BUCKETS - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_record_latencies_into_power_of_two_millisecond_buckets() {
+        let mut histogram = LatencyHistogram::default();
+
+        for latency_ms in [0, 1, 3, 4, 1_000_000] {
+            assert!(histogram.record(Duration::milliseconds(latency_ms)));
+        }
+
+        let mut expected_buckets = [0; LATENCY_BUCKETS];
+        expected_buckets[0] = 1;
+        expected_buckets[1] = 1;
+        expected_buckets[2] = 1;
+        expected_buckets[3] = 1;
+        expected_buckets[LATENCY_BUCKETS - 1] = 1;
+
+        assert_eq!(histogram.count, 5);
+        assert_eq!(histogram.buckets, expected_buckets);
+        assert_eq!(histogram.min_ms, Some(0));
+        assert_eq!(histogram.max_ms, Some(1_000_000));
+    }
+
+    #[test]
+    fn should_reject_negative_latencies_without_recording_them() {
+        let mut histogram = LatencyHistogram::default();
+
+        assert!(!histogram.record(Duration::milliseconds(-5)));
+        assert_eq!(histogram, LatencyHistogram::default());
+    }
+
+    #[test]
+    fn should_calculate_mean_latency_only_if_latencies_recorded() {
+        let mut histogram = LatencyHistogram::default();
+        assert_eq!(histogram.mean(), None);
+
+        histogram.record(Duration::milliseconds(10));
+        histogram.record(Duration::milliseconds(30));
+        assert_eq!(histogram.mean(), Some(Duration::milliseconds(20)));
+    }
+}
@@ -1,11 +1,12 @@
 use crate::{
-    portfolio::{position::Position, Balance},
+    portfolio::{decimal_to_f64, position::Position, Balance},
     statistic::summary::PositionSummariser,
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 pub mod drawdown;
+pub mod latency;
 pub mod ratio;
 
 /// Total equity at a point in time - equates to [`Balance.total`](Balance).
@@ -28,7 +29,7 @@ impl From<Balance> for EquityPoint {
     fn from(balance: Balance) -> Self {
         Self {
             time: balance.time,
-            total: balance.total,
+            total: decimal_to_f64(balance.total),
         }
     }
 }
@@ -40,11 +41,11 @@ impl PositionSummariser for EquityPoint {
             None => {
                 // Position is not exited, so simulate
                 self.time = position.meta.update_time;
-                self.total += position.unrealised_profit_loss;
+                self.total += decimal_to_f64(position.unrealised_profit_loss);
             }
             Some(exit_balance) => {
                 self.time = exit_balance.time;
-                self.total += position.realised_profit_loss;
+                self.total += decimal_to_f64(position.realised_profit_loss);
             }
         }
     }
@@ -53,8 +54,9 @@ impl PositionSummariser for EquityPoint {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::position;
+    use crate::{portfolio::decimal_from_f64, test_util::position};
     use chrono::Duration;
+    use rust_decimal::Decimal;
     use std::ops::Add;
 
     #[test]
@@ -63,10 +65,10 @@ mod tests {
             let mut position = position();
             position.meta.exit_balance = Some(Balance {
                 time: exit_time,
-                total: 100.0,
-                available: 100.0,
+                total: Decimal::ONE_HUNDRED,
+                available: Decimal::ONE_HUNDRED,
             });
-            position.realised_profit_loss = result_pnl;
+            position.realised_profit_loss = decimal_from_f64(result_pnl);
             position
         }
 
@@ -77,7 +79,7 @@ mod tests {
             let mut position = position();
             position.meta.exit_balance = None;
             position.meta.update_time = last_update_time;
-            position.unrealised_profit_loss = unreal_pnl;
+            position.unrealised_profit_loss = decimal_from_f64(unreal_pnl);
             position
         }
 
@@ -3,13 +3,15 @@ use serde::{Deserialize, Serialize};
 
 pub trait Ratio {
     fn init(risk_free_return: f64) -> Self;
-    fn ratio(&self) -> f64;
+    fn ratio(&self) -> Option<f64>;
     fn trades_per_day(&self) -> f64;
-    fn daily(&self) -> f64 {
-        calculate_daily(self.ratio(), self.trades_per_day())
+    fn daily(&self) -> Option<f64> {
+        self.ratio()
+            .map(|ratio| calculate_daily(ratio, self.trades_per_day()))
     }
-    fn annual(&self, trading_days: u32) -> f64 {
-        calculate_annual(self.ratio(), self.trades_per_day(), trading_days)
+    fn annual(&self, trading_days: u32) -> Option<f64> {
+        self.ratio()
+            .map(|ratio| calculate_annual(ratio, self.trades_per_day(), trading_days))
     }
 }
 
@@ -17,19 +19,20 @@ pub trait Ratio {
 pub struct SharpeRatio {
     pub risk_free_return: f64,
     pub trades_per_day: f64,
-    pub sharpe_ratio_per_trade: f64,
+    /// `None` until there are at least two returns with a non-zero dispersion.
+    pub sharpe_ratio_per_trade: Option<f64>,
 }
 
 impl Ratio for SharpeRatio {
     fn init(risk_free_return: f64) -> Self {
         Self {
             risk_free_return,
-            sharpe_ratio_per_trade: 0.0,
+            sharpe_ratio_per_trade: None,
             trades_per_day: 0.0,
         }
     }
 
-    fn ratio(&self) -> f64 {
+    fn ratio(&self) -> Option<f64> {
         self.sharpe_ratio_per_trade
     }
 
@@ -43,14 +46,11 @@ impl SharpeRatio {
         // Update Trades Per Day
         self.trades_per_day = pnl_returns.trades_per_day;
 
-        // Calculate Sharpe Ratio Per Trade
-        self.sharpe_ratio_per_trade = match pnl_returns.total.dispersion.std_dev == 0.0 {
-            true => 0.0,
-            false => {
-                (pnl_returns.total.mean - self.risk_free_return)
-                    / pnl_returns.total.dispersion.std_dev
-            }
-        };
+        // Calculate Sharpe Ratio Per Trade, which is undefined for less than two returns or zero
+        // dispersion
+        let std_dev = pnl_returns.total.dispersion.std_dev;
+        self.sharpe_ratio_per_trade = (pnl_returns.total.count >= 2 && std_dev != 0.0)
+            .then(|| (pnl_returns.total.mean - self.risk_free_return) / std_dev);
     }
 }
 
@@ -70,8 +70,8 @@ impl Ratio for SortinoRatio {
         }
     }
 
-    fn ratio(&self) -> f64 {
-        self.sortino_ratio_per_trade
+    fn ratio(&self) -> Option<f64> {
+        Some(self.sortino_ratio_per_trade)
     }
 
     fn trades_per_day(&self) -> f64 {
@@ -111,8 +111,8 @@ impl Ratio for CalmarRatio {
         }
     }
 
-    fn ratio(&self) -> f64 {
-        self.calmar_ratio_per_trade
+    fn ratio(&self) -> Option<f64> {
+        Some(self.calmar_ratio_per_trade)
     }
 
     fn trades_per_day(&self) -> f64 {
@@ -175,7 +175,7 @@ mod tests {
 
         struct TestCase {
             input_return: PnLReturnSummary,
-            expected_sharpe: f64,
+            expected_sharpe: Option<f64>,
         }
 
         // Returns  = [0.1, 0.2, 0.3, 0.4, -0.4]
@@ -185,37 +185,53 @@ mod tests {
             TestCase {
                 // Test case 0: 1st trade, 10% profit
                 input_return: sharpe_ratio_input(1, 0.1, 0.0),
-                expected_sharpe: 0.0,
+                expected_sharpe: None,
             },
             TestCase {
                 // Test case 1: 2nd trade, 20% profit
                 input_return: sharpe_ratio_input(2, 0.15, 0.05),
-                expected_sharpe: 3.0,
+                expected_sharpe: Some(3.0),
             },
             TestCase {
                 // Test case 2: 3rd trade, 30% profit
                 input_return: sharpe_ratio_input(3, 0.2, (1.0_f64 / 150.0_f64).sqrt()),
-                expected_sharpe: 6.0_f64.sqrt(),
+                expected_sharpe: Some(6.0_f64.sqrt()),
             },
             TestCase {
                 // Test case 3: 4th trade, 40% profit
                 input_return: sharpe_ratio_input(4, 0.25, (0.0125_f64).sqrt()),
-                expected_sharpe: 5.0_f64.sqrt(),
+                expected_sharpe: Some(5.0_f64.sqrt()),
             },
             TestCase {
                 // Test case 4: 5th trade, -40% profit
                 input_return: sharpe_ratio_input(5, 0.12, (0.388_f64 / 5.0_f64).sqrt()),
-                expected_sharpe: ((3.0 * 194_f64.sqrt()) / 97.0),
+                expected_sharpe: Some((3.0 * 194_f64.sqrt()) / 97.0),
             },
         ];
 
         for (index, test) in test_cases.into_iter().enumerate() {
             sharpe.update(&test.input_return);
-            let sharpe_diff = sharpe.sharpe_ratio_per_trade - test.expected_sharpe;
-            assert!(sharpe_diff < 1e-10, "Test case: {:?}", index);
+            match (sharpe.sharpe_ratio_per_trade, test.expected_sharpe) {
+                (Some(actual), Some(expected)) => {
+                    assert!((actual - expected).abs() < 1e-10, "Test case: {:?}", index)
+                }
+                (actual, expected) => assert_eq!(actual, expected, "Test case: {:?}", index),
+            }
         }
     }
 
+    #[test]
+    fn sharpe_ratio_with_zero_dispersion_is_none() {
+        let mut sharpe = SharpeRatio::init(0.0);
+
+        // Returns = [0.1, 0.1]
+        sharpe.update(&sharpe_ratio_input(2, 0.1, 0.0));
+
+        assert_eq!(sharpe.ratio(), None);
+        assert_eq!(sharpe.daily(), None);
+        assert_eq!(sharpe.annual(365), None);
+    }
+
     #[test]
     fn sortino_ratio_update() {
         let mut sortino = SortinoRatio::init(0.0);
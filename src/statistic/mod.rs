@@ -6,6 +6,7 @@ pub mod dispersion;
 pub mod error;
 pub mod metric;
 pub mod summary;
+pub mod trade;
 
 /// Serialize a [`Duration`] into a `u64` representing the associated seconds.
 pub fn se_duration_as_secs<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
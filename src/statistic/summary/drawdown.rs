@@ -63,3 +63,46 @@ impl DrawdownSummary {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{portfolio::Balance, test_util::position};
+    use chrono::{DateTime, Duration, Utc};
+    use rust_decimal::Decimal;
+
+    fn closed_position(day: i64, total: i64) -> Position {
+        let mut position = position();
+        position.meta.exit_balance = Some(Balance {
+            time: DateTime::<Utc>::MIN_UTC + Duration::days(day),
+            total: Decimal::from(total),
+            available: Decimal::from(total),
+        });
+        position
+    }
+
+    #[test]
+    fn drawdown_summary_should_track_peaks_over_an_equity_curve_that_dips_and_recovers() {
+        let mut summary = DrawdownSummary::new(100.0);
+
+        // Equity    = [120, 90, 105, 132, 99, 66, 140]
+        // Peaks     = [120, 120, 120, 132, 132, 132, 140]
+        // Drawdowns = [-0.25 (120 -> 90, recovered on day 4), -0.5 (132 -> 66, recovered on day 7)]
+        for (day, total) in [120, 90, 105, 132, 99, 66, 140].into_iter().enumerate() {
+            summary.update(&closed_position(day as i64 + 1, total));
+        }
+
+        assert_eq!(summary.max_drawdown.drawdown.drawdown, -0.5);
+        assert_eq!(summary.max_drawdown.drawdown.equity_range.high, 132.0);
+        assert_eq!(summary.max_drawdown.drawdown.equity_range.low, 66.0);
+        assert_eq!(summary.max_drawdown.drawdown.duration, Duration::days(1));
+
+        assert_eq!(summary.avg_drawdown.count, 2);
+        assert_eq!(summary.avg_drawdown.mean_drawdown, -0.375);
+        assert_eq!(summary.avg_drawdown.mean_duration, Duration::days(1));
+
+        // Curve finished on a new peak, so no drawdown is in progress
+        assert!(summary.current_drawdown.is_waiting_for_peak());
+        assert_eq!(summary.current_drawdown.equity_range.high, 140.0);
+    }
+}
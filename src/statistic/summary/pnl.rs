@@ -1,5 +1,5 @@
 use crate::{
-    portfolio::position::Position,
+    portfolio::{decimal_to_f64, position::Position},
     statistic::{
         de_duration_from_secs, se_duration_as_secs,
         summary::{data::DataSummary, Initialiser, PositionSummariser, TableBuilder},
@@ -21,6 +21,8 @@ pub struct PnLReturnSummary {
     pub trades_per_day: f64,
     pub total: DataSummary,
     pub losses: DataSummary,
+    /// Number of trades with a positive return, break-even trades are neither wins nor losses.
+    pub wins: u64,
 }
 
 impl Initialiser for PnLReturnSummary {
@@ -39,6 +41,7 @@ impl Default for PnLReturnSummary {
             trades_per_day: 0.0,
             total: DataSummary::default(),
             losses: DataSummary::default(),
+            wins: 0,
         }
     }
 }
@@ -55,14 +58,16 @@ impl PositionSummariser for PnLReturnSummary {
         self.update_trades_per_day();
 
         // Calculate the Position PnL Return
-        let pnl_return = position.calculate_profit_loss_return();
+        let pnl_return = decimal_to_f64(position.calculate_profit_loss_return());
 
         // Update Total PnL Returns
         self.total.update(pnl_return);
 
-        // Update Loss PnL Returns if relevant
-        if pnl_return.is_sign_negative() {
+        // Update Loss PnL Returns or the wins if relevant
+        if pnl_return < 0.0 {
             self.losses.update(pnl_return);
+        } else if pnl_return > 0.0 {
+            self.wins += 1;
         }
     }
 }
@@ -84,10 +89,9 @@ impl TableBuilder for PnLReturnSummary {
     }
 
     fn row(&self) -> Row {
-        let wins = self.total.count - self.losses.count;
         row![
             self.total.count.to_string(),
-            wins,
+            self.wins,
             self.losses.count,
             self.duration.num_days().to_string(),
             format!("{:.3}", self.trades_per_day),
@@ -110,6 +114,7 @@ impl PnLReturnSummary {
             trades_per_day: 0.0,
             total: Default::default(),
             losses: Default::default(),
+            wins: 0,
         }
     }
 
@@ -127,6 +132,15 @@ impl PnLReturnSummary {
         self.trades_per_day = self.total.count as f64
             / (self.duration.num_seconds() as f64 / PnLReturnSummary::SECONDS_IN_DAY)
     }
+    /// Proportion of trades that were wins in decimal form (eg/ 0.6 for 60%), where break-even
+    /// trades count towards the total but not the wins. Returns `None` if no trades have been
+    /// summarised yet.
+    pub fn win_rate(&self) -> Option<f64> {
+        match self.total.count {
+            0 => None,
+            trades => Some(self.wins as f64 / trades as f64),
+        }
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
@@ -144,19 +158,19 @@ pub struct ProfitLossSummary {
 
 impl PositionSummariser for ProfitLossSummary {
     fn update(&mut self, position: &Position) {
-        self.total_contracts += position.quantity.abs();
-        self.total_pnl += position.realised_profit_loss;
+        self.total_contracts += decimal_to_f64(position.quantity.abs());
+        self.total_pnl += decimal_to_f64(position.realised_profit_loss);
         self.total_pnl_per_contract = self.total_pnl / self.total_contracts;
 
         match position.side {
             Side::Buy => {
-                self.long_contracts += position.quantity.abs();
-                self.long_pnl += position.realised_profit_loss;
+                self.long_contracts += decimal_to_f64(position.quantity.abs());
+                self.long_pnl += decimal_to_f64(position.realised_profit_loss);
                 self.long_pnl_per_contract = self.long_pnl / self.long_contracts;
             }
             Side::Sell => {
-                self.short_contracts += position.quantity.abs();
-                self.short_pnl += position.realised_profit_loss;
+                self.short_contracts += decimal_to_f64(position.quantity.abs());
+                self.short_pnl += decimal_to_f64(position.realised_profit_loss);
                 self.short_pnl_per_contract = self.short_pnl / self.short_contracts;
             }
         }
@@ -204,12 +218,48 @@ mod tests {
     use super::*;
     use crate::{portfolio::Balance, test_util::position};
     use chrono::{Duration, Utc};
+    use rust_decimal::Decimal;
 
     #[test]
     fn update_pnl_return_summary() {
         // Todo:
     }
 
+    #[test]
+    fn win_rate_with_no_trades_is_none() {
+        let pnl_return_view = PnLReturnSummary::new();
+
+        assert_eq!(pnl_return_view.win_rate(), None);
+    }
+
+    #[test]
+    fn win_rate_with_winning_and_losing_trades() {
+        let mut pnl_return_view = PnLReturnSummary::new();
+
+        for realised_profit_loss in [10, -5, 20, -1] {
+            let mut input_position = position();
+            input_position.realised_profit_loss = Decimal::from(realised_profit_loss);
+            pnl_return_view.update(&input_position);
+        }
+
+        assert_eq!(pnl_return_view.win_rate(), Some(0.5));
+    }
+
+    #[test]
+    fn win_rate_should_not_count_break_even_trades_as_wins() {
+        let mut pnl_return_view = PnLReturnSummary::new();
+
+        for realised_profit_loss in [10, 0, 0, -1] {
+            let mut input_position = position();
+            input_position.realised_profit_loss = Decimal::from(realised_profit_loss);
+            pnl_return_view.update(&input_position);
+        }
+
+        assert_eq!(pnl_return_view.wins, 1);
+        assert_eq!(pnl_return_view.losses.count, 1);
+        assert_eq!(pnl_return_view.win_rate(), Some(0.25));
+    }
+
     #[test]
     fn update_trading_session_duration_with_non_exited_position() {
         let base_time = Utc::now();
@@ -238,8 +288,8 @@ mod tests {
         let mut input_position = position();
         input_position.meta.exit_balance = Some(Balance {
             time: base_time.checked_add_signed(Duration::days(15)).unwrap(),
-            total: 0.0,
-            available: 0.0,
+            total: Decimal::ZERO,
+            available: Decimal::ZERO,
         });
 
         pnl_return_view.update_trading_session_duration(&input_position);
@@ -116,13 +116,18 @@ impl TableBuilder for TearSheet {
 
     fn row(&self) -> Row {
         row![
-            format!("{:.3}", self.sharpe_ratio.daily()),
-            format!("{:.3}", self.sortino_ratio.daily()),
-            format!("{:.3}", self.calmar_ratio.daily()),
+            format_ratio(self.sharpe_ratio.daily()),
+            format_ratio(self.sortino_ratio.daily()),
+            format_ratio(self.calmar_ratio.daily()),
         ]
     }
 }
 
+/// Formats an undefined ratio (eg/ a Sharpe ratio of a single return) as "N/A".
+fn format_ratio(ratio: Option<f64>) -> String {
+    ratio.map_or_else(|| "N/A".to_owned(), |ratio| format!("{ratio:.3}"))
+}
+
 pub fn calculate_trading_duration(start_time: &DateTime<Utc>, position: &Position) -> Duration {
     match position.meta.exit_balance {
         None => {
@@ -0,0 +1,415 @@
+use crate::{
+    execution::FillEvent,
+    portfolio::{position::CostBasis, OrderTags},
+    statistic::{de_duration_from_secs, se_duration_as_secs},
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Completed round-trip trade, pairing (part of) an entry fill with the exit fill that closed
+/// it.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Trade {
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    /// Buy for a long trade, Sell for a short trade.
+    pub side: Side,
+    pub entry_time: DateTime<Utc>,
+    pub exit_time: DateTime<Utc>,
+    pub entry_price: Decimal,
+    pub exit_price: Decimal,
+    /// Absolute quantity closed by the [`Trade`].
+    pub quantity: Decimal,
+    /// Entry & exit fees attributable to the closed quantity.
+    pub fees: Decimal,
+    /// Realised profit & loss of the closed quantity, net of fees.
+    pub profit_loss: Decimal,
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub holding_period: Duration,
+    /// [`OrderTags`] of the entry fill that opened the [`Trade`] (eg/ the strategy or signal
+    /// it is attributed to).
+    #[serde(default)]
+    pub tags: OrderTags,
+}
+
+/// Open entry lot of an [`Instrument`], yet to be closed by an exit fill.
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+struct Lot {
+    time: DateTime<Utc>,
+    price: Decimal,
+    /// +ve for a long lot, -ve for a short lot.
+    quantity: Decimal,
+    /// Entry fees attributable to the open quantity.
+    fees: Decimal,
+    /// [`OrderTags`] of the entry fill that opened the lot.
+    tags: OrderTags,
+}
+
+/// Ledger pairing entry & exit [`FillEvent`]s into completed [`Trade`] records, matched
+/// according to a [`CostBasis`].
+///
+/// [`CostBasis::Fifo`] & [`CostBasis::Lifo`] close the oldest or most recent open entry lot
+/// first, producing a [`Trade`] per entry lot. [`CostBasis::Average`] merges entry fills into
+/// one lot at their quantity weighted average price, so every exit fill produces a single
+/// [`Trade`] tagged with the [`OrderTags`] of the entry fill that opened the lot.
+///
+/// Partial exits produce [`Trade`]s for the closed quantity only, leaving the remainder of the
+/// entry lot open. A fill that reverses the position closes every open lot before opening a
+/// new lot with the remaining quantity. Fees are attributed pro-rata to the quantity closed.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TradeLedger {
+    cost_basis: CostBasis,
+    lots: HashMap<(Exchange, Instrument), VecDeque<Lot>>,
+    trades: Vec<Trade>,
+}
+
+impl TradeLedger {
+    /// Constructs a new empty [`TradeLedger`] matching fills using the provided [`CostBasis`].
+    pub fn new(cost_basis: CostBasis) -> Self {
+        Self {
+            cost_basis,
+            lots: HashMap::new(),
+            trades: Vec::new(),
+        }
+    }
+
+    /// Records the [`FillEvent`], returning the [`Trade`]s it completed by closing open entry
+    /// lots (empty if it only entered or scaled into a position).
+    pub fn record(&mut self, fill: &FillEvent) -> Vec<Trade> {
+        if fill.quantity.is_zero() {
+            return Vec::new();
+        }
+
+        let price = fill.fill_value_gross / fill.quantity.abs();
+        let lots = self
+            .lots
+            .entry((fill.exchange.clone(), fill.instrument.clone()))
+            .or_default();
+
+        let mut remaining = fill.quantity;
+        let mut exit_fees = fill.fees.calculate_total_fees();
+        let mut trades = Vec::new();
+
+        // Close open lots in the opposite direction to the fill, in order of the CostBasis
+        while !remaining.is_zero() {
+            let lot = match self.cost_basis {
+                CostBasis::Lifo => lots.back_mut(),
+                CostBasis::Fifo | CostBasis::Average => lots.front_mut(),
+            };
+            let Some(lot) = lot else {
+                break;
+            };
+            if lot.quantity.is_sign_positive() == remaining.is_sign_positive() {
+                break;
+            }
+
+            let closed = lot.quantity.abs().min(remaining.abs());
+            let entry_fees = lot.fees * closed / lot.quantity.abs();
+            let closed_exit_fees = exit_fees * closed / remaining.abs();
+            let fees = entry_fees + closed_exit_fees;
+
+            let (side, direction) = if lot.quantity.is_sign_positive() {
+                (Side::Buy, Decimal::ONE)
+            } else {
+                (Side::Sell, Decimal::NEGATIVE_ONE)
+            };
+
+            trades.push(Trade {
+                exchange: fill.exchange.clone(),
+                instrument: fill.instrument.clone(),
+                side,
+                entry_time: lot.time,
+                exit_time: fill.time,
+                entry_price: lot.price,
+                exit_price: price,
+                quantity: closed,
+                fees,
+                profit_loss: (price - lot.price) * closed * direction - fees,
+                holding_period: fill.time.signed_duration_since(lot.time),
+                tags: lot.tags.clone(),
+            });
+
+            lot.fees -= entry_fees;
+            exit_fees -= closed_exit_fees;
+            if lot.quantity.is_sign_positive() {
+                lot.quantity -= closed;
+                remaining += closed;
+            } else {
+                lot.quantity += closed;
+                remaining -= closed;
+            }
+            if lot.quantity.is_zero() {
+                match self.cost_basis {
+                    CostBasis::Lifo => lots.pop_back(),
+                    CostBasis::Fifo | CostBasis::Average => lots.pop_front(),
+                };
+            }
+        }
+
+        // Open (or scale into) a lot with any quantity left after closing opposing lots
+        if !remaining.is_zero() {
+            let lot = Lot {
+                time: fill.time,
+                price,
+                quantity: remaining,
+                fees: exit_fees,
+                tags: fill.tags.clone(),
+            };
+
+            match (self.cost_basis, lots.back_mut()) {
+                (CostBasis::Average, Some(open)) => {
+                    let open_quantity = open.quantity.abs();
+                    let lot_quantity = lot.quantity.abs();
+                    open.price = (open.price * open_quantity + lot.price * lot_quantity)
+                        / (open_quantity + lot_quantity);
+                    open.quantity += lot.quantity;
+                    open.fees += lot.fees;
+                }
+                _ => lots.push_back(lot),
+            }
+        }
+
+        self.trades.extend(trades.iter().cloned());
+        trades
+    }
+
+    /// Returns every completed [`Trade`], in the order they were closed.
+    pub fn trades(&self) -> &[Trade] {
+        &self.trades
+    }
+
+    /// Returns the absolute quantity of the open entry lots of the [`Instrument`] on the
+    /// [`Exchange`], yet to be closed by an exit fill.
+    pub fn open_quantity(&self, exchange: &Exchange, instrument: &Instrument) -> Decimal {
+        self.lots
+            .get(&(exchange.clone(), instrument.clone()))
+            .map_or(Decimal::ZERO, |lots| {
+                lots.iter().map(|lot| lot.quantity.abs()).sum()
+            })
+    }
+
+    /// Proportion of completed [`Trade`]s with a positive profit & loss, in decimal form (eg/
+    /// 0.6 for 60%). Returns `None` if no [`Trade`]s have been completed.
+    pub fn win_rate(&self) -> Option<f64> {
+        match self.trades.len() {
+            0 => None,
+            trades => Some(self.wins().count() as f64 / trades as f64),
+        }
+    }
+
+    /// Mean profit & loss of the winning [`Trade`]s, or `None` if there are none.
+    pub fn average_win(&self) -> Option<Decimal> {
+        mean(self.wins().map(|trade| trade.profit_loss))
+    }
+
+    /// Mean profit & loss of the losing [`Trade`]s (a -ve value), or `None` if there are none.
+    pub fn average_loss(&self) -> Option<Decimal> {
+        mean(
+            self.trades
+                .iter()
+                .filter(|trade| trade.profit_loss <= Decimal::ZERO)
+                .map(|trade| trade.profit_loss),
+        )
+    }
+
+    fn wins(&self) -> impl Iterator<Item = &Trade> {
+        self.trades
+            .iter()
+            .filter(|trade| trade.profit_loss > Decimal::ZERO)
+    }
+}
+
+/// [`OrderTags`] key naming the strategy a [`Trade`] is attributed to.
+pub const STRATEGY_TAG: &str = "strategy";
+
+/// Strategy [`Trade`]s without a [`STRATEGY_TAG`] are attributed to.
+pub const UNTAGGED_STRATEGY: &str = "untagged";
+
+/// Aggregate statistics of a set of completed [`Trade`]s.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct TradeStats {
+    /// Sum of the realised profit & loss of the [`Trade`]s, net of fees.
+    pub profit_loss: Decimal,
+    /// Number of [`Trade`]s.
+    pub trades: u64,
+    /// Number of [`Trade`]s with a positive profit & loss.
+    pub wins: u64,
+    /// Sum of the entry & exit fees of the [`Trade`]s.
+    pub fees: Decimal,
+}
+
+/// [`TradeStats`] of the [`Trade`]s of a single [`Instrument`].
+pub type InstrumentStats = TradeStats;
+
+/// [`TradeStats`] of the [`Trade`]s attributed to a single strategy.
+pub type StrategyStats = TradeStats;
+
+impl TradeStats {
+    /// Folds the completed [`Trade`] into the [`TradeStats`].
+    pub fn update(&mut self, trade: &Trade) {
+        self.profit_loss += trade.profit_loss;
+        self.trades += 1;
+        if trade.profit_loss > Decimal::ZERO {
+            self.wins += 1;
+        }
+        self.fees += trade.fees;
+    }
+
+    /// Proportion of [`Trade`]s with a positive profit & loss, in decimal form (eg/ 0.6 for
+    /// 60%). Returns `None` if there are no [`Trade`]s.
+    pub fn win_rate(&self) -> Option<f64> {
+        (self.trades > 0).then(|| self.wins as f64 / self.trades as f64)
+    }
+}
+
+impl Trade {
+    /// Returns the strategy the [`Trade`] is attributed to via it's [`STRATEGY_TAG`], or
+    /// [`UNTAGGED_STRATEGY`] if it is untagged.
+    pub fn strategy(&self) -> &str {
+        self.tags
+            .get(STRATEGY_TAG)
+            .map_or(UNTAGGED_STRATEGY, String::as_str)
+    }
+}
+
+/// Calculates the mean of the provided values, or `None` if there are none.
+fn mean(values: impl Iterator<Item = Decimal>) -> Option<Decimal> {
+    let (count, sum) = values.fold((0_u64, Decimal::ZERO), |(count, sum), value| {
+        (count + 1, sum + value)
+    });
+    (count > 0).then(|| sum / Decimal::from(count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{execution::Fees, strategy::Decision, test_util::fill_event};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn fill(seconds: i64, quantity: Decimal, price: i64, fees: i64) -> FillEvent {
+        FillEvent {
+            time: DateTime::<Utc>::MIN_UTC + Duration::seconds(seconds),
+            decision: if quantity.is_sign_positive() {
+                Decision::Long
+            } else {
+                Decision::CloseLong
+            },
+            quantity,
+            fill_value_gross: Decimal::from(price) * quantity.abs(),
+            fees: Fees {
+                exchange: Decimal::from(fees),
+                ..Fees::default()
+            },
+            ..fill_event()
+        }
+    }
+
+    #[test]
+    fn trade_ledger_should_pair_entry_and_exit_fills_of_a_round_trip() {
+        let mut ledger = TradeLedger::new(CostBasis::Fifo);
+
+        assert!(ledger.record(&fill(0, Decimal::ONE, 100, 1)).is_empty());
+        let trades = ledger.record(&fill(60, -Decimal::ONE, 110, 1));
+
+        assert_eq!(
+            trades,
+            vec![Trade {
+                exchange: Exchange::from("binance"),
+                instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+                side: Side::Buy,
+                entry_time: DateTime::<Utc>::MIN_UTC,
+                exit_time: DateTime::<Utc>::MIN_UTC + Duration::seconds(60),
+                entry_price: Decimal::ONE_HUNDRED,
+                exit_price: Decimal::from(110),
+                quantity: Decimal::ONE,
+                fees: Decimal::TWO,
+                profit_loss: Decimal::from(8),
+                holding_period: Duration::seconds(60),
+                tags: OrderTags::default(),
+            }]
+        );
+        assert_eq!(ledger.win_rate(), Some(1.0));
+        assert_eq!(ledger.average_win(), Some(Decimal::from(8)));
+        assert_eq!(ledger.average_loss(), None);
+    }
+
+    #[test]
+    fn trade_ledger_should_match_scale_in_then_full_exit_by_cost_basis() {
+        let fills = [
+            fill(0, Decimal::ONE, 100, 0),
+            fill(10, Decimal::ONE, 120, 0),
+            fill(20, -Decimal::TWO, 110, 0),
+        ];
+
+        // FIFO closes each entry lot with a Trade of it's own
+        let mut ledger = TradeLedger::new(CostBasis::Fifo);
+        let trades = fills
+            .iter()
+            .flat_map(|fill| ledger.record(fill))
+            .collect::<Vec<_>>();
+        assert_eq!(trades.len(), 2);
+        assert_eq!(
+            (trades[0].entry_price, trades[0].profit_loss),
+            (Decimal::ONE_HUNDRED, Decimal::TEN)
+        );
+        assert_eq!(
+            (trades[1].entry_price, trades[1].profit_loss),
+            (Decimal::from(120), -Decimal::TEN)
+        );
+        assert_eq!(trades[1].holding_period, Duration::seconds(10));
+        assert_eq!(ledger.win_rate(), Some(0.5));
+        assert_eq!(ledger.average_loss(), Some(-Decimal::TEN));
+
+        // Average cost closes one lot at the weighted average entry price
+        let mut ledger = TradeLedger::new(CostBasis::Average);
+        let trades = fills
+            .iter()
+            .flat_map(|fill| ledger.record(fill))
+            .collect::<Vec<_>>();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].quantity, Decimal::TWO);
+        assert_eq!(trades[0].entry_price, Decimal::from(110));
+        assert_eq!(trades[0].profit_loss, Decimal::ZERO);
+
+        // LIFO closes the most recent entry lot first
+        let mut ledger = TradeLedger::new(CostBasis::Lifo);
+        let trades = fills
+            .iter()
+            .flat_map(|fill| ledger.record(fill))
+            .collect::<Vec<_>>();
+        assert_eq!(trades.len(), 2);
+        assert_eq!(
+            (trades[0].entry_price, trades[0].profit_loss),
+            (Decimal::from(120), -Decimal::TEN)
+        );
+        assert_eq!(
+            (trades[1].entry_price, trades[1].profit_loss),
+            (Decimal::ONE_HUNDRED, Decimal::TEN)
+        );
+    }
+
+    #[test]
+    fn trade_ledger_should_record_partial_trade_for_a_partial_exit() {
+        let mut ledger = TradeLedger::new(CostBasis::Fifo);
+        ledger.record(&fill(0, Decimal::TWO, 100, 2));
+
+        let trades = ledger.record(&fill(30, -Decimal::ONE, 90, 1));
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].quantity, Decimal::ONE);
+        // Half of the entry fees & all of the exit fees are attributed to the closed quantity
+        assert_eq!(trades[0].fees, Decimal::TWO);
+        assert_eq!(trades[0].profit_loss, Decimal::from(-12));
+
+        let fill = fill_event();
+        assert_eq!(
+            ledger.open_quantity(&fill.exchange, &fill.instrument),
+            Decimal::ONE
+        );
+    }
+}
@@ -0,0 +1,656 @@
+use super::{Decision, Signal, SignalGenerator, SignalStrength};
+use crate::{
+    data::MarketMeta,
+    execution::{Fees, FillEvent},
+    portfolio::{decimal_from_f64, decimal_to_f64},
+    statistic::trade::STRATEGY_TAG,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{Debug, Formatter},
+};
+
+/// Performance metric an [`AdaptiveAllocator`] allocates capital across it's child strategies
+/// by, evaluated over the returns of each child in the lookback window.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum AllocationMetric {
+    /// Sum of the returns in the lookback window.
+    #[default]
+    Return,
+    /// Mean return divided by the standard deviation of the returns in the lookback window. A
+    /// child with constant returns is scored as if it's volatility were [`f64::EPSILON`].
+    Sharpe,
+}
+
+/// Meta-strategy [`SignalGenerator`] that allocates capital across several child strategies by
+/// their recent performance, according to an [`AllocationMetric`].
+///
+/// Each child is identified by a name its [`Signal`]s are tagged with (see
+/// [`STRATEGY_TAG`]), & has it's own virtual equity tracked from the [`FillEvent`]s of it's
+/// tagged orders, marked to the latest price of every [`Instrument`] it holds. Every
+/// `rebalance_interval` [`MarketEvent`]s the return of each child since the last reallocation
+/// is sampled, relative to the capital it was allocated, & the allocations are recomputed:
+/// every child receives the floor allocation, & the remainder is shared in proportion to each
+/// positive [`AllocationMetric`] score (or equally if no child scores positively).
+///
+/// Every child is run on every [`MarketEvent`] of an [`Instrument`] it is interested in, & the
+/// [`Signal`]s of every child are combined into the single [`Signal`] the [`MarketEvent`] yields:
+/// the [`SignalStrength`] of each [`Decision`] is the sum of the child strengths, each scaled by
+/// the allocation of the child. The combined [`Signal`] is tagged with the child contributing the
+/// most strength.
+///
+/// Entry fills are attributed to the children whose [`Signal`]s were combined, in proportion to
+/// their scaled strength. Exit fills are attributed to the children holding the [`Instrument`],
+/// in proportion to their holdings, whichever child (if any) advised the exit.
+pub struct AdaptiveAllocator {
+    children: Vec<Child>,
+    metric: AllocationMetric,
+    capital: f64,
+    rebalance_interval: usize,
+    floor: f64,
+    lookback: usize,
+    market_events: usize,
+    prices: HashMap<Instrument, f64>,
+    /// Scaled [`SignalStrength`] each child contributed to the latest combined [`Signal`] of
+    /// every [`Instrument`] & [`Decision`].
+    contributions: HashMap<(Instrument, Decision), Vec<(usize, f64)>>,
+}
+
+/// Child strategy of an [`AdaptiveAllocator`], alongside it's virtual equity & allocation.
+struct Child {
+    name: String,
+    strategy: Box<dyn SignalGenerator + Send>,
+    instruments: Option<HashSet<Instrument>>,
+    allocation: f64,
+    /// Net cash flow of the fills of the child, including fees.
+    cash: f64,
+    /// Net quantity of every [`Instrument`] held by the child.
+    holdings: HashMap<Instrument, Decimal>,
+    /// Virtual equity of the child at the last reallocation.
+    last_equity: f64,
+    /// Returns of the child between reallocations, most recent last.
+    returns: VecDeque<f64>,
+}
+
+impl Child {
+    /// Determines if the child is interested in the provided [`Instrument`].
+    fn is_interested(&self, instrument: &Instrument) -> bool {
+        self.instruments
+            .as_ref()
+            .is_none_or(|instruments| instruments.contains(instrument))
+    }
+
+    /// Virtual equity of the child, with every holding valued at the latest known price.
+    fn equity(&self, capital: f64, prices: &HashMap<Instrument, f64>) -> f64 {
+        let holdings = self
+            .holdings
+            .iter()
+            .map(|(instrument, quantity)| {
+                decimal_to_f64(*quantity) * prices.get(instrument).copied().unwrap_or_default()
+            })
+            .sum::<f64>();
+        capital + self.cash + holdings
+    }
+
+    /// Applies the [`FillEvent`] attributed to the child to it's virtual equity & strategy.
+    fn apply_fill(&mut self, fill: &FillEvent) {
+        // Buying costs the fill value, selling earns it
+        let direction = match fill.quantity.is_sign_negative() {
+            true => -1.0,
+            false => 1.0,
+        };
+        self.cash -= direction * decimal_to_f64(fill.fill_value_gross)
+            + decimal_to_f64(fill.fees.calculate_total_fees());
+        *self
+            .holdings
+            .entry(fill.instrument.clone())
+            .or_insert(Decimal::ZERO) += fill.quantity;
+
+        self.strategy.on_fill(fill);
+    }
+
+    /// Scores the returns of the child in the lookback window by the provided
+    /// [`AllocationMetric`].
+    fn score(&self, metric: AllocationMetric) -> f64 {
+        match metric {
+            AllocationMetric::Return => self.returns.iter().sum(),
+            AllocationMetric::Sharpe => {
+                if self.returns.len() < 2 {
+                    return 0.0;
+                }
+                let count = self.returns.len() as f64;
+                let mean = self.returns.iter().sum::<f64>() / count;
+                let variance = self
+                    .returns
+                    .iter()
+                    .map(|ret| (ret - mean).powi(2))
+                    .sum::<f64>()
+                    / (count - 1.0);
+                mean / variance.sqrt().max(f64::EPSILON)
+            }
+        }
+    }
+}
+
+impl SignalGenerator for AdaptiveAllocator {
+    fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+        if let Some(market_meta) = MarketMeta::from_market(market) {
+            self.prices
+                .insert(market.instrument.clone(), decimal_to_f64(market_meta.close));
+        }
+
+        self.market_events += 1;
+        if self.market_events.is_multiple_of(self.rebalance_interval) {
+            self.reallocate();
+        }
+
+        // Run every interested child, combining their Signals scaled by allocation
+        let mut combined: Option<Signal> = None;
+        let mut contributions = HashMap::<Decision, Vec<(usize, f64)>>::new();
+        let mut strength_per_child = vec![0.0; self.children.len()];
+        for (index, child) in self.children.iter_mut().enumerate() {
+            if !child.is_interested(&market.instrument) {
+                continue;
+            }
+            let Some(signal) = child.strategy.generate_signal(market) else {
+                continue;
+            };
+
+            let combined = combined.get_or_insert_with(|| Signal {
+                signals: HashMap::new(),
+                ..signal.clone()
+            });
+            for (decision, strength) in signal.signals {
+                let scaled = strength.0 * child.allocation;
+                combined
+                    .signals
+                    .entry(decision)
+                    .or_insert(SignalStrength(0.0))
+                    .0 += scaled;
+                contributions
+                    .entry(decision)
+                    .or_default()
+                    .push((index, scaled));
+                strength_per_child[index] += scaled;
+            }
+        }
+        let mut signal = combined?;
+
+        for (decision, contribution) in contributions {
+            self.contributions
+                .insert((market.instrument.clone(), decision), contribution);
+        }
+
+        let lead = strength_per_child
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index)?;
+        signal
+            .tags
+            .insert(STRATEGY_TAG.to_owned(), self.children[lead].name.clone());
+        Some(signal)
+    }
+
+    fn on_fill(&mut self, fill: &FillEvent) {
+        let shares = match fill.decision.is_entry() {
+            true => self.entry_shares(fill),
+            false => self.exit_shares(fill),
+        };
+        let shares = match shares.is_empty() {
+            // Fall back to the child that tagged the filled order
+            true => fill
+                .tags
+                .get(STRATEGY_TAG)
+                .and_then(|name| self.children.iter().position(|child| child.name == *name))
+                .map(|index| vec![(index, Decimal::ONE)])
+                .unwrap_or_default(),
+            false => shares,
+        };
+
+        for (index, share) in shares {
+            self.children[index].apply_fill(&pro_rate_fill(fill, share));
+        }
+    }
+
+    fn instruments_of_interest(&self) -> Option<HashSet<Instrument>> {
+        // Interested in every Instrument if any child is
+        let mut union = HashSet::new();
+        for child in &self.children {
+            union.extend(child.instruments.clone()?);
+        }
+        Some(union)
+    }
+}
+
+impl Debug for AdaptiveAllocator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdaptiveAllocator")
+            .field("allocations", &self.allocations())
+            .field("metric", &self.metric)
+            .field("rebalance_interval", &self.rebalance_interval)
+            .finish()
+    }
+}
+
+impl AdaptiveAllocator {
+    /// Default allocation every child receives, & a newly added child starts at.
+    pub const DEFAULT_FLOOR: f64 = 0.05;
+
+    /// Default number of returns per child the [`AllocationMetric`] is evaluated over.
+    pub const DEFAULT_LOOKBACK: usize = 20;
+
+    /// Constructs a new [`AdaptiveAllocator`] with no child strategies, allocating the provided
+    /// virtual capital by the provided [`AllocationMetric`] every `rebalance_interval`
+    /// [`MarketEvent`]s.
+    pub fn new(capital: f64, metric: AllocationMetric, rebalance_interval: usize) -> Self {
+        Self {
+            children: Vec::new(),
+            metric,
+            capital,
+            rebalance_interval: rebalance_interval.max(1),
+            floor: Self::DEFAULT_FLOOR,
+            lookback: Self::DEFAULT_LOOKBACK,
+            market_events: 0,
+            prices: HashMap::new(),
+            contributions: HashMap::new(),
+        }
+    }
+
+    /// Replaces the floor allocation, [`AdaptiveAllocator::DEFAULT_FLOOR`] by default. Clamped so
+    /// the floors of every child never exceed the whole capital.
+    pub fn with_floor(mut self, value: f64) -> Self {
+        self.floor = value.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Replaces the number of returns per child the [`AllocationMetric`] is evaluated over,
+    /// [`AdaptiveAllocator::DEFAULT_LOOKBACK`] by default.
+    pub fn with_lookback(mut self, value: usize) -> Self {
+        self.lookback = value.max(1);
+        self
+    }
+
+    /// Adds a named child strategy to the [`AdaptiveAllocator`], see
+    /// [`AdaptiveAllocator::add_child`].
+    pub fn with_child<Strategy>(mut self, name: impl Into<String>, strategy: Strategy) -> Self
+    where
+        Strategy: SignalGenerator + Send + 'static,
+    {
+        self.add_child(name, strategy);
+        self
+    }
+
+    /// Adds a named child strategy to the [`AdaptiveAllocator`] at the floor allocation (or the
+    /// whole capital if it is the first child), scaling down the allocations of the existing
+    /// children to make room. The name must be unique, since it attributes fills to the child.
+    pub fn add_child<Strategy>(&mut self, name: impl Into<String>, strategy: Strategy)
+    where
+        Strategy: SignalGenerator + Send + 'static,
+    {
+        let allocation = match self.children.is_empty() {
+            true => 1.0,
+            false => self.floor,
+        };
+        let existing = self
+            .children
+            .iter()
+            .map(|child| child.allocation)
+            .sum::<f64>();
+        if existing > 0.0 {
+            let scale = (1.0 - allocation) / existing;
+            self.children
+                .iter_mut()
+                .for_each(|child| child.allocation *= scale);
+        }
+
+        self.children.push(Child {
+            name: name.into(),
+            instruments: strategy.instruments_of_interest(),
+            strategy: Box::new(strategy),
+            allocation,
+            cash: 0.0,
+            holdings: HashMap::new(),
+            last_equity: self.capital,
+            returns: VecDeque::new(),
+        });
+    }
+
+    /// Returns the [`AllocationMetric`] capital is allocated by.
+    pub fn metric(&self) -> AllocationMetric {
+        self.metric
+    }
+
+    /// Returns the current allocation of the named child strategy, if it exists.
+    pub fn allocation(&self, name: &str) -> Option<f64> {
+        self.children
+            .iter()
+            .find(|child| child.name == name)
+            .map(|child| child.allocation)
+    }
+
+    /// Returns the current allocation of every child strategy, in insertion order.
+    pub fn allocations(&self) -> Vec<(&str, f64)> {
+        self.children
+            .iter()
+            .map(|child| (child.name.as_str(), child.allocation))
+            .collect()
+    }
+
+    /// Returns the virtual equity of the named child strategy, if it exists.
+    pub fn equity(&self, name: &str) -> Option<f64> {
+        self.children
+            .iter()
+            .find(|child| child.name == name)
+            .map(|child| child.equity(self.capital, &self.prices))
+    }
+
+    /// Returns the share of the entry [`FillEvent`] attributable to each child, in proportion to
+    /// the scaled [`SignalStrength`] it contributed to the combined [`Signal`] of the filled
+    /// [`Decision`].
+    fn entry_shares(&self, fill: &FillEvent) -> Vec<(usize, Decimal)> {
+        let Some(contributions) = self
+            .contributions
+            .get(&(fill.instrument.clone(), fill.decision))
+        else {
+            return Vec::new();
+        };
+        let total = contributions
+            .iter()
+            .map(|(_, strength)| strength)
+            .sum::<f64>();
+        if total <= 0.0 {
+            return Vec::new();
+        }
+
+        contributions
+            .iter()
+            .map(|(index, strength)| (*index, decimal_from_f64(strength / total)))
+            .collect()
+    }
+
+    /// Returns the share of the exit [`FillEvent`] attributable to each child, in proportion to
+    /// the quantity of the [`Instrument`] it holds in the direction being exited.
+    fn exit_shares(&self, fill: &FillEvent) -> Vec<(usize, Decimal)> {
+        let holdings = self
+            .children
+            .iter()
+            .enumerate()
+            .filter_map(|(index, child)| {
+                let held = child.holdings.get(&fill.instrument).copied()?;
+                (!held.is_zero() && held.is_sign_negative() != fill.quantity.is_sign_negative())
+                    .then_some((index, held.abs()))
+            })
+            .collect::<Vec<_>>();
+        let total = holdings.iter().map(|(_, held)| *held).sum::<Decimal>();
+
+        holdings
+            .into_iter()
+            .filter_map(|(index, held)| Some((index, held.checked_div(total)?)))
+            .collect()
+    }
+
+    /// Samples the return of every child since the last reallocation & recomputes the
+    /// allocations from their [`AllocationMetric`] scores.
+    fn reallocate(&mut self) {
+        if self.children.is_empty() {
+            return;
+        }
+
+        for child in &mut self.children {
+            let equity = child.equity(self.capital, &self.prices);
+            let allocated = self.capital * child.allocation;
+            if allocated > 0.0 {
+                child
+                    .returns
+                    .push_back((equity - child.last_equity) / allocated);
+                if child.returns.len() > self.lookback {
+                    child.returns.pop_front();
+                }
+            }
+            child.last_equity = equity;
+        }
+
+        let floor = self.floor.min(1.0 / self.children.len() as f64);
+        let remainder = 1.0 - floor * self.children.len() as f64;
+        let scores = self
+            .children
+            .iter()
+            .map(|child| child.score(self.metric).max(0.0))
+            .collect::<Vec<_>>();
+        let total = scores.iter().sum::<f64>();
+
+        let equal = 1.0 / self.children.len() as f64;
+        for (child, score) in self.children.iter_mut().zip(scores) {
+            child.allocation = match total > 0.0 {
+                true => floor + remainder * score / total,
+                false => equal,
+            };
+        }
+    }
+}
+
+/// Returns the provided share of the [`FillEvent`], pro-rating the quantity, fill value & fees.
+fn pro_rate_fill(fill: &FillEvent, share: Decimal) -> FillEvent {
+    if share == Decimal::ONE {
+        return fill.clone();
+    }
+    FillEvent {
+        quantity: fill.quantity * share,
+        fill_value_gross: fill.fill_value_gross * share,
+        fees: Fees {
+            exchange: fill.fees.exchange * share,
+            slippage: fill.fees.slippage * share,
+            network: fill.fees.network * share,
+        },
+        ..fill.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{fill_event, market_event_trade, signal};
+    use barter_data::subscription::trade::PublicTrade;
+    use barter_integration::model::Side;
+
+    /// [`SignalGenerator`] that advises entering a long Position on every [`MarketEvent`].
+    struct AlwaysLong;
+
+    impl SignalGenerator for AlwaysLong {
+        fn generate_signal(&mut self, _: &MarketEvent<DataKind>) -> Option<Signal> {
+            Some(Signal {
+                signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+                ..signal()
+            })
+        }
+    }
+
+    /// [`SignalGenerator`] that advises the next scripted [`Decision`] (if any) on every
+    /// [`MarketEvent`].
+    struct Scripted {
+        decisions: VecDeque<Option<Decision>>,
+    }
+
+    impl SignalGenerator for Scripted {
+        fn generate_signal(&mut self, _: &MarketEvent<DataKind>) -> Option<Signal> {
+            let decision = self.decisions.pop_front()??;
+            Some(Signal {
+                signals: HashMap::from([(decision, SignalStrength(1.0))]),
+                ..signal()
+            })
+        }
+    }
+
+    /// [`SignalGenerator`] that never advises a [`Signal`].
+    struct Flat;
+
+    impl SignalGenerator for Flat {
+        fn generate_signal(&mut self, _: &MarketEvent<DataKind>) -> Option<Signal> {
+            None
+        }
+    }
+
+    fn trade(price: f64) -> MarketEvent<DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.kind = DataKind::Trade(PublicTrade {
+            id: "trade_id".to_owned(),
+            price,
+            amount: 1.0,
+            side: Side::Buy,
+        });
+        market
+    }
+
+    fn fill(signal: &Signal, decision: Decision, quantity: i64, price: i64) -> FillEvent {
+        FillEvent {
+            instrument: signal.instrument.clone(),
+            decision,
+            quantity: Decimal::from(quantity),
+            fill_value_gross: Decimal::from(price * quantity.abs()),
+            tags: signal.tags.clone(),
+            ..fill_event()
+        }
+    }
+
+    #[test]
+    fn adaptive_allocator_should_reallocate_towards_the_consistently_profitable_child() {
+        let mut allocator = AdaptiveAllocator::new(10_000.0, AllocationMetric::Return, 2)
+            .with_child("flat", Flat)
+            .with_child("winner", AlwaysLong);
+
+        // Newly added child starts at the floor allocation
+        assert_eq!(allocator.allocation("flat"), Some(0.95));
+        assert_eq!(allocator.allocation("winner"), Some(0.05));
+
+        // Enter at 100 & exit at 110 between every reallocation
+        for _ in 0..4 {
+            let entry = allocator.generate_signal(&trade(100.0)).unwrap();
+            assert_eq!(entry.tags[STRATEGY_TAG], "winner");
+            allocator.on_fill(&fill(&entry, Decision::Long, 1, 100));
+            allocator.generate_signal(&trade(110.0));
+            allocator.on_fill(&fill(&entry, Decision::CloseLong, -1, 110));
+        }
+
+        assert_eq!(allocator.equity("winner"), Some(10_040.0));
+        assert_eq!(allocator.equity("flat"), Some(10_000.0));
+
+        // Profitable child holds everything above the floor of the flat child
+        let winner = allocator.allocation("winner").unwrap();
+        let flat = allocator.allocation("flat").unwrap();
+        assert!(winner > flat);
+        assert!((winner - 0.95).abs() < 1e-9);
+        assert!((flat - 0.05).abs() < 1e-9);
+
+        // Child Signals are scaled by their allocation
+        let entry = allocator.generate_signal(&trade(100.0)).unwrap();
+        let strength = entry.signals[&Decision::Long].0;
+        assert!((strength - 0.95).abs() < 1e-9);
+    }
+
+    #[test]
+    fn adaptive_allocator_should_combine_the_scaled_signals_of_every_child() {
+        let mut allocator = AdaptiveAllocator::new(10_000.0, AllocationMetric::Return, 100)
+            .with_child("first", AlwaysLong)
+            .with_child("second", AlwaysLong);
+
+        // Both children advise a long, so the combined strength is the sum of their allocations
+        let entry = allocator.generate_signal(&trade(100.0)).unwrap();
+        assert_eq!(entry.signals.len(), 1);
+        assert!((entry.signals[&Decision::Long].0 - 1.0).abs() < 1e-9);
+        assert_eq!(entry.tags[STRATEGY_TAG], "first");
+
+        // Entry fill is attributed to each child in proportion to it's scaled strength
+        allocator.on_fill(&fill(&entry, Decision::Long, 100, 100));
+        allocator.generate_signal(&trade(110.0));
+        assert_eq!(allocator.equity("first"), Some(10_950.0));
+        assert_eq!(allocator.equity("second"), Some(10_050.0));
+    }
+
+    #[test]
+    fn adaptive_allocator_should_attribute_exit_fills_to_the_child_that_opened_the_position() {
+        let mut allocator = AdaptiveAllocator::new(10_000.0, AllocationMetric::Return, 100)
+            .with_child("opener", AlwaysLong)
+            .with_child("flat", Flat);
+
+        let entry = allocator.generate_signal(&trade(100.0)).unwrap();
+        allocator.on_fill(&fill(&entry, Decision::Long, 1, 100));
+
+        // Exit fill of an untagged order (eg/ a stop-loss) is attributed to the opener
+        let exit = FillEvent {
+            tags: Default::default(),
+            ..fill(&entry, Decision::CloseLong, -1, 110)
+        };
+        allocator.on_fill(&exit);
+
+        assert_eq!(allocator.equity("opener"), Some(10_010.0));
+        assert_eq!(allocator.equity("flat"), Some(10_000.0));
+    }
+
+    #[test]
+    fn adaptive_allocator_should_reallocate_towards_a_lower_ranked_child_that_recovers() {
+        // Each round the "early" child enters & exits, then the "late" child does, whilst the
+        // "early" child keeps advising a CloseLong that must not crowd out the "late" entry
+        let rounds = 6;
+        let early = (0..rounds).flat_map(|_| {
+            [
+                Some(Decision::Long),
+                Some(Decision::CloseLong),
+                Some(Decision::CloseLong),
+                Some(Decision::CloseLong),
+            ]
+        });
+        let late =
+            (0..rounds).flat_map(|_| [None, None, Some(Decision::Long), Some(Decision::CloseLong)]);
+        let mut allocator = AdaptiveAllocator::new(10_000.0, AllocationMetric::Return, 4)
+            .with_child(
+                "early",
+                Scripted {
+                    decisions: early.collect(),
+                },
+            )
+            .with_child(
+                "late",
+                Scripted {
+                    decisions: late.collect(),
+                },
+            );
+
+        let round = |allocator: &mut AdaptiveAllocator, early_exit: i64, late_exit: i64| {
+            for (entry_price, exit_price) in [(100, early_exit), (100, late_exit)] {
+                let entry = allocator
+                    .generate_signal(&trade(entry_price as f64))
+                    .unwrap();
+                allocator.on_fill(&fill(&entry, Decision::Long, 1, entry_price));
+                let exit = allocator
+                    .generate_signal(&trade(exit_price as f64))
+                    .unwrap();
+                allocator.on_fill(&fill(&exit, Decision::CloseLong, -1, exit_price));
+            }
+        };
+
+        // Early child profits whilst the late child is flat
+        for _ in 0..2 {
+            round(&mut allocator, 110, 100);
+        }
+        assert!(allocator.allocation("early").unwrap() > allocator.allocation("late").unwrap());
+
+        // Early child loses whilst the late child profits, so the late child recovers
+        for _ in 2..rounds {
+            round(&mut allocator, 90, 110);
+        }
+        assert_eq!(allocator.equity("early"), Some(10_000.0 + 20.0 - 40.0));
+        assert_eq!(allocator.equity("late"), Some(10_000.0 + 40.0));
+        let late = allocator.allocation("late").unwrap();
+        let early = allocator.allocation("early").unwrap();
+        assert!(late > early);
+        assert!((late - 0.95).abs() < 1e-9);
+        assert!((early - 0.05).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,207 @@
+use super::{Signal, SignalGenerator};
+use crate::execution::FillEvent;
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Formatter},
+    future::Future,
+    time::Duration,
+};
+use tokio::runtime::{Builder, Runtime};
+use tracing::warn;
+
+/// May generate an advisory [`Signal`] as a result of asynchronously analysing an input
+/// [`MarketEvent`], eg/ by querying an inference endpoint or a database.
+///
+/// Every [`SignalGenerator`] is an [`AsyncSignalGenerator`] via a blanket implementation. Use a
+/// [`BlockingSignalGenerator`] to drive an [`AsyncSignalGenerator`] from a
+/// [`Trader`](crate::engine::trader::Trader).
+pub trait AsyncSignalGenerator {
+    /// Optionally return a [`Signal`] given input [`MarketEvent`].
+    fn generate_signal_async(
+        &mut self,
+        market: &MarketEvent<DataKind>,
+    ) -> impl Future<Output = Option<Signal>>;
+
+    /// Notifies the strategy of an applied [`FillEvent`]. See [`SignalGenerator::on_fill`].
+    fn on_fill(&mut self, _fill: &FillEvent) {}
+
+    /// Returns the [`Instrument`]s this strategy is interested in, or `None` if it is interested
+    /// in every [`Instrument`] (the default). See [`SignalGenerator::instruments_of_interest`].
+    fn instruments_of_interest(&self) -> Option<HashSet<Instrument>> {
+        None
+    }
+}
+
+impl<Strategy> AsyncSignalGenerator for Strategy
+where
+    Strategy: SignalGenerator,
+{
+    fn generate_signal_async(
+        &mut self,
+        market: &MarketEvent<DataKind>,
+    ) -> impl Future<Output = Option<Signal>> {
+        std::future::ready(self.generate_signal(market))
+    }
+
+    fn on_fill(&mut self, fill: &FillEvent) {
+        SignalGenerator::on_fill(self, fill)
+    }
+
+    fn instruments_of_interest(&self) -> Option<HashSet<Instrument>> {
+        SignalGenerator::instruments_of_interest(self)
+    }
+}
+
+/// [`SignalGenerator`] adapter that drives an [`AsyncSignalGenerator`] to completion on a
+/// dedicated single threaded runtime, so it can be used by the synchronous
+/// [`Trader`](crate::engine::trader::Trader) event loop.
+///
+/// If the [`AsyncSignalGenerator`] does not complete within the configured timeout, no
+/// [`Signal`] is generated for that [`MarketEvent`] and a warning is logged.
+///
+/// Must not be used from within an asynchronous context (eg/ a tokio task), since it blocks
+/// the current thread.
+pub struct BlockingSignalGenerator<Strategy> {
+    strategy: Strategy,
+    timeout: Duration,
+    runtime: Runtime,
+}
+
+impl<Strategy> SignalGenerator for BlockingSignalGenerator<Strategy>
+where
+    Strategy: AsyncSignalGenerator,
+{
+    fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+        let signal = self.strategy.generate_signal_async(market);
+
+        match self
+            .runtime
+            .block_on(async { tokio::time::timeout(self.timeout, signal).await })
+        {
+            Ok(signal) => signal,
+            Err(_) => {
+                warn!(
+                    exchange = %market.exchange,
+                    instrument = %market.instrument,
+                    timeout = ?self.timeout,
+                    "AsyncSignalGenerator timed out, treating as no Signal"
+                );
+                None
+            }
+        }
+    }
+
+    fn on_fill(&mut self, fill: &FillEvent) {
+        self.strategy.on_fill(fill)
+    }
+
+    fn instruments_of_interest(&self) -> Option<HashSet<Instrument>> {
+        self.strategy.instruments_of_interest()
+    }
+}
+
+impl<Strategy> Debug for BlockingSignalGenerator<Strategy>
+where
+    Strategy: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockingSignalGenerator")
+            .field("strategy", &self.strategy)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl<Strategy> BlockingSignalGenerator<Strategy>
+where
+    Strategy: AsyncSignalGenerator,
+{
+    /// Constructs a new [`BlockingSignalGenerator`] that waits at most `timeout` for each
+    /// [`Signal`] of the provided [`AsyncSignalGenerator`].
+    pub fn new(strategy: Strategy, timeout: Duration) -> std::io::Result<Self> {
+        Ok(Self {
+            strategy,
+            timeout,
+            runtime: Builder::new_current_thread().enable_all().build()?,
+        })
+    }
+
+    /// Returns a reference to the wrapped [`AsyncSignalGenerator`].
+    pub fn strategy(&self) -> &Strategy {
+        &self.strategy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        strategy::buy_and_hold::BuyAndHold,
+        test_util::{market_event_trade, signal},
+    };
+    use barter_integration::model::Side;
+
+    /// [`AsyncSignalGenerator`] that waits `delay` before advising the provided [`Signal`].
+    struct DelayedStrategy {
+        delay: Duration,
+        signal: Signal,
+    }
+
+    impl AsyncSignalGenerator for DelayedStrategy {
+        async fn generate_signal_async(&mut self, _: &MarketEvent<DataKind>) -> Option<Signal> {
+            tokio::time::sleep(self.delay).await;
+            Some(self.signal.clone())
+        }
+    }
+
+    #[test]
+    fn blocking_signal_generator_should_use_signal_completed_within_timeout() {
+        let expected = signal();
+        let mut strategy = BlockingSignalGenerator::new(
+            DelayedStrategy {
+                delay: Duration::from_millis(1),
+                signal: expected.clone(),
+            },
+            Duration::from_secs(5),
+        )
+        .unwrap();
+
+        assert_eq!(
+            strategy.generate_signal(&market_event_trade(Side::Buy)),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn blocking_signal_generator_should_treat_hung_strategy_as_no_signal() {
+        let mut strategy = BlockingSignalGenerator::new(
+            DelayedStrategy {
+                delay: Duration::from_secs(3600),
+                signal: signal(),
+            },
+            Duration::from_millis(10),
+        )
+        .unwrap();
+
+        assert_eq!(
+            strategy.generate_signal(&market_event_trade(Side::Buy)),
+            None
+        );
+    }
+
+    #[test]
+    fn sync_signal_generator_should_be_usable_as_async_signal_generator() {
+        let market = market_event_trade(Side::Buy);
+        let expected = BuyAndHold::new().generate_signal(&market).unwrap();
+
+        let mut strategy =
+            BlockingSignalGenerator::new(BuyAndHold::new(), Duration::from_secs(5)).unwrap();
+        let actual = strategy.generate_signal(&market).unwrap();
+
+        assert_eq!(actual.signals, expected.signals);
+        assert_eq!(actual.market_meta, expected.market_meta);
+        assert_eq!(strategy.generate_signal(&market), None);
+    }
+}
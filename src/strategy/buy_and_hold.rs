@@ -0,0 +1,100 @@
+use super::{error::SnapshotError, Decision, Signal, SignalGenerator, SignalStrength};
+use crate::data::MarketMeta;
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::Market;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Reference [`SignalGenerator`] implementation that advises entering a long Position on the
+/// first priced [`MarketEvent`] of every [`Market`], and then holds it indefinitely.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct BuyAndHold {
+    /// [`Market`]s a [`Decision::Long`] [`Signal`] has already been generated for.
+    entered: HashSet<Market>,
+}
+
+impl SignalGenerator for BuyAndHold {
+    fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+        // Ignore MarketEvents that do not communicate a price (eg/ Liquidations)
+        let market_meta = MarketMeta::from_market(market)?;
+
+        // Only advise entering each Market once
+        if !self.entered.insert(Market::new(
+            market.exchange.clone(),
+            market.instrument.clone(),
+        )) {
+            return None;
+        }
+
+        Some(Signal {
+            time: Utc::now(),
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+            market_meta,
+            tags: Default::default(),
+        })
+    }
+
+    fn snapshot(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("BuyAndHold contains only serialisable values")
+    }
+
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), SnapshotError> {
+        *self = BuyAndHold::deserialize(snapshot)
+            .map_err(|error| SnapshotError::Deserialise(error.to_string()))?;
+        Ok(())
+    }
+}
+
+impl BuyAndHold {
+    /// Constructs a new [`BuyAndHold`] component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{market_event_candle, market_event_trade};
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Side,
+    };
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn buy_and_hold_should_generate_long_signal_for_first_market_event() {
+        let mut strategy = BuyAndHold::new();
+        let market = market_event_trade(Side::Buy);
+
+        let signal = strategy
+            .generate_signal(&market)
+            .expect("BuyAndHold did not generate a Signal");
+
+        assert_eq!(signal.exchange, market.exchange);
+        assert_eq!(signal.instrument, market.instrument);
+        assert_eq!(
+            signal.signals,
+            HashMap::from([(Decision::Long, SignalStrength(1.0))])
+        );
+        assert_eq!(signal.market_meta.close, Decimal::ONE_THOUSAND);
+    }
+
+    #[test]
+    fn buy_and_hold_should_generate_one_signal_per_market() {
+        let mut strategy = BuyAndHold::new();
+
+        assert!(strategy
+            .generate_signal(&market_event_trade(Side::Buy))
+            .is_some());
+        assert!(strategy.generate_signal(&market_event_candle()).is_none());
+
+        let mut other_market = market_event_trade(Side::Buy);
+        other_market.instrument = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        assert!(strategy.generate_signal(&other_market).is_some());
+        assert!(strategy.generate_signal(&other_market).is_none());
+    }
+}
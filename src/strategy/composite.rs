@@ -0,0 +1,360 @@
+use super::{error::SnapshotError, Decision, Signal, SignalGenerator, SignalStrength};
+use crate::execution::FillEvent;
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Debug, Formatter},
+};
+
+/// Policy used by a [`CompositeStrategy`] to combine the [`Signal`]s of its sub-strategies.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum AggregationPolicy {
+    /// Sums the [`SignalStrength`] of each [`Decision`] across every sub-strategy. Opposing entry
+    /// [`Decision`]s (Long vs Short) net against each other, and cancel out if equal.
+    #[default]
+    Sum,
+    /// Keeps each [`Decision`] advised by more than half of the sub-strategies, at the mean
+    /// [`SignalStrength`] of the sub-strategies that advised it.
+    MajorityVote,
+    /// Uses the [`Signal`] of the first sub-strategy (in insertion order) that advises a
+    /// [`Decision`] with a non-zero [`SignalStrength`].
+    FirstNonZero,
+}
+
+/// [`SignalGenerator`] that combines several sub-strategies trading the same [`Market`]s into a
+/// single [`Signal`] per [`MarketEvent`], according to an [`AggregationPolicy`].
+///
+/// Every sub-strategy is run on every [`MarketEvent`] of an [`Instrument`] it is interested in
+/// (even if its [`Signal`] is not used), so stateful indicators stay up to date. The
+/// [`CompositeStrategy`] is interested in the union of the sub-strategy
+/// [`SignalGenerator::instruments_of_interest`].
+///
+/// [`Market`]: barter_integration::model::Market
+pub struct CompositeStrategy {
+    strategies: Vec<SubStrategy>,
+    policy: AggregationPolicy,
+}
+
+/// Sub-strategy of a [`CompositeStrategy`], alongside the [`Instrument`]s it is interested in.
+struct SubStrategy {
+    strategy: Box<dyn SignalGenerator + Send>,
+    instruments: Option<HashSet<Instrument>>,
+}
+
+impl SubStrategy {
+    /// Determines if the sub-strategy is interested in the provided [`Instrument`].
+    fn is_interested(&self, instrument: &Instrument) -> bool {
+        self.instruments
+            .as_ref()
+            .is_none_or(|instruments| instruments.contains(instrument))
+    }
+}
+
+impl SignalGenerator for CompositeStrategy {
+    fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+        let mut interested = 0;
+        let signals = self
+            .strategies
+            .iter_mut()
+            .filter(|sub| sub.is_interested(&market.instrument))
+            .filter_map(|sub| {
+                interested += 1;
+                sub.strategy.generate_signal(market)
+            })
+            .collect::<Vec<_>>();
+
+        let combined = match self.policy {
+            AggregationPolicy::Sum => net_entries(sum(&signals)),
+            AggregationPolicy::MajorityVote => net_entries(majority_vote(&signals, interested)),
+            AggregationPolicy::FirstNonZero => signals
+                .iter()
+                .find(|signal| signal.signals.values().any(|strength| strength.0 != 0.0))
+                .map(|signal| signal.signals.clone())
+                .unwrap_or_default(),
+        };
+
+        if combined.is_empty() {
+            return None;
+        }
+
+        // Every sub-strategy Signal originates from the same MarketEvent
+        let first = signals.into_iter().next()?;
+        Some(Signal {
+            signals: combined,
+            ..first
+        })
+    }
+
+    fn on_fill(&mut self, fill: &FillEvent) {
+        self.strategies
+            .iter_mut()
+            .filter(|sub| sub.is_interested(&fill.instrument))
+            .for_each(|sub| sub.strategy.on_fill(fill));
+    }
+
+    fn instruments_of_interest(&self) -> Option<HashSet<Instrument>> {
+        // Interested in every Instrument if any sub-strategy is
+        let mut union = HashSet::new();
+        for sub in &self.strategies {
+            union.extend(sub.instruments.clone()?);
+        }
+        Some(union)
+    }
+
+    fn snapshot(&self) -> serde_json::Value {
+        // Snapshot of each sub-strategy, in insertion order
+        self.strategies
+            .iter()
+            .map(|sub| sub.strategy.snapshot())
+            .collect()
+    }
+
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), SnapshotError> {
+        let snapshots = match snapshot {
+            serde_json::Value::Array(snapshots) if snapshots.len() == self.strategies.len() => {
+                snapshots
+            }
+            _ => {
+                return Err(SnapshotError::Deserialise(format!(
+                    "expected an array of {} sub-strategy snapshots",
+                    self.strategies.len()
+                )))
+            }
+        };
+
+        // Roll back the sub-strategies already restored if a later sub-strategy fails
+        let previous = self.snapshot();
+        for (index, snapshot) in snapshots.into_iter().enumerate() {
+            if let Err(error) = self.strategies[index].strategy.restore(snapshot) {
+                if let serde_json::Value::Array(previous) = previous {
+                    for (sub, snapshot) in self.strategies.iter_mut().zip(previous).take(index) {
+                        let _ = sub.strategy.restore(snapshot);
+                    }
+                }
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Debug for CompositeStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompositeStrategy")
+            .field("strategies", &self.strategies.len())
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl CompositeStrategy {
+    /// Constructs a new [`CompositeStrategy`] with no sub-strategies, that combines
+    /// [`Signal`]s using the provided [`AggregationPolicy`].
+    pub fn new(policy: AggregationPolicy) -> Self {
+        Self {
+            strategies: Vec::new(),
+            policy,
+        }
+    }
+
+    /// Adds a sub-strategy to the [`CompositeStrategy`].
+    pub fn with_strategy<Strategy>(mut self, strategy: Strategy) -> Self
+    where
+        Strategy: SignalGenerator + Send + 'static,
+    {
+        self.strategies.push(SubStrategy {
+            instruments: strategy.instruments_of_interest(),
+            strategy: Box::new(strategy),
+        });
+        self
+    }
+
+    /// Returns the [`AggregationPolicy`] used to combine sub-strategy [`Signal`]s.
+    pub fn policy(&self) -> AggregationPolicy {
+        self.policy
+    }
+}
+
+/// Sums the [`SignalStrength`] of each [`Decision`] across the provided [`Signal`]s.
+fn sum(signals: &[Signal]) -> HashMap<Decision, SignalStrength> {
+    signals
+        .iter()
+        .flat_map(|signal| signal.signals.iter())
+        .fold(HashMap::new(), |mut combined, (decision, strength)| {
+            combined.entry(*decision).or_insert(SignalStrength(0.0)).0 += strength.0;
+            combined
+        })
+}
+
+/// Keeps each [`Decision`] advised by more than half of the sub-strategies, at the mean
+/// [`SignalStrength`] of the sub-strategies that advised it.
+fn majority_vote(signals: &[Signal], num_strategies: usize) -> HashMap<Decision, SignalStrength> {
+    let mut votes = HashMap::<Decision, (usize, f64)>::new();
+    for (decision, strength) in signals.iter().flat_map(|signal| signal.signals.iter()) {
+        let (count, total) = votes.entry(*decision).or_default();
+        *count += 1;
+        *total += strength.0;
+    }
+
+    votes
+        .into_iter()
+        .filter(|(_, (count, _))| *count * 2 > num_strategies)
+        .map(|(decision, (count, total))| (decision, SignalStrength(total / count as f64)))
+        .collect()
+}
+
+/// Nets opposing entry [`Decision`]s so a combined [`Signal`] never advises entering both Long
+/// & Short. The stronger [`Decision`] is kept at the difference in [`SignalStrength`], and both
+/// are removed if equal. [`Decision`]s with zero [`SignalStrength`] are removed.
+fn net_entries(
+    mut signals: HashMap<Decision, SignalStrength>,
+) -> HashMap<Decision, SignalStrength> {
+    if let (Some(long), Some(short)) = (
+        signals.get(&Decision::Long).copied(),
+        signals.get(&Decision::Short).copied(),
+    ) {
+        signals.remove(&Decision::Long);
+        signals.remove(&Decision::Short);
+
+        let net = long.0 - short.0;
+        if net > 0.0 {
+            signals.insert(Decision::Long, SignalStrength(net));
+        } else if net < 0.0 {
+            signals.insert(Decision::Short, SignalStrength(-net));
+        }
+    }
+
+    signals.retain(|_, strength| strength.0 != 0.0);
+    signals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{market_event_trade, signal};
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+
+    /// [`SignalGenerator`] that advises the same [`Decision`]s on every [`MarketEvent`].
+    struct FixedStrategy(HashMap<Decision, SignalStrength>);
+
+    impl SignalGenerator for FixedStrategy {
+        fn generate_signal(&mut self, _: &MarketEvent<DataKind>) -> Option<Signal> {
+            Some(Signal {
+                signals: self.0.clone(),
+                ..signal()
+            })
+        }
+    }
+
+    /// [`FixedStrategy`] that is only interested in the provided [`Instrument`]s.
+    struct InterestedStrategy(FixedStrategy, HashSet<Instrument>);
+
+    impl SignalGenerator for InterestedStrategy {
+        fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+            self.0.generate_signal(market)
+        }
+
+        fn instruments_of_interest(&self) -> Option<HashSet<Instrument>> {
+            Some(self.1.clone())
+        }
+    }
+
+    fn advising(decision: Decision, strength: f64) -> FixedStrategy {
+        FixedStrategy(HashMap::from([(decision, SignalStrength(strength))]))
+    }
+
+    fn combine(strategy: &mut CompositeStrategy) -> Option<HashMap<Decision, SignalStrength>> {
+        strategy
+            .generate_signal(&market_event_trade(Side::Buy))
+            .map(|signal| signal.signals)
+    }
+
+    #[test]
+    fn sum_policy_should_net_opposing_equal_strength_signals_to_no_signal() {
+        let mut strategy = CompositeStrategy::new(AggregationPolicy::Sum)
+            .with_strategy(advising(Decision::Long, 1.0))
+            .with_strategy(advising(Decision::Short, 1.0));
+
+        assert_eq!(combine(&mut strategy), None);
+    }
+
+    #[test]
+    fn sum_policy_should_keep_stronger_entry_at_net_strength() {
+        let mut strategy = CompositeStrategy::new(AggregationPolicy::Sum)
+            .with_strategy(advising(Decision::Long, 1.0))
+            .with_strategy(advising(Decision::Long, 0.5))
+            .with_strategy(advising(Decision::Short, 0.75));
+
+        assert_eq!(
+            combine(&mut strategy),
+            Some(HashMap::from([(Decision::Long, SignalStrength(0.75))]))
+        );
+    }
+
+    #[test]
+    fn majority_vote_policy_should_keep_decisions_advised_by_most_strategies() {
+        let mut strategy = CompositeStrategy::new(AggregationPolicy::MajorityVote)
+            .with_strategy(advising(Decision::Short, 1.0))
+            .with_strategy(advising(Decision::Short, 0.5))
+            .with_strategy(advising(Decision::Long, 1.0));
+
+        assert_eq!(
+            combine(&mut strategy),
+            Some(HashMap::from([(Decision::Short, SignalStrength(0.75))]))
+        );
+
+        // A tied vote is not a majority
+        let mut tied = CompositeStrategy::new(AggregationPolicy::MajorityVote)
+            .with_strategy(advising(Decision::Short, 1.0))
+            .with_strategy(advising(Decision::Long, 1.0));
+        assert_eq!(combine(&mut tied), None);
+    }
+
+    #[test]
+    fn first_non_zero_policy_should_use_first_strategy_advising_a_decision() {
+        let mut strategy = CompositeStrategy::new(AggregationPolicy::FirstNonZero)
+            .with_strategy(advising(Decision::Long, 0.0))
+            .with_strategy(advising(Decision::Short, 0.5))
+            .with_strategy(advising(Decision::Long, 1.0));
+
+        assert_eq!(
+            combine(&mut strategy),
+            Some(HashMap::from([(Decision::Short, SignalStrength(0.5))]))
+        );
+    }
+
+    #[test]
+    fn composite_strategy_should_only_run_sub_strategies_interested_in_the_instrument() {
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        let mut strategy = CompositeStrategy::new(AggregationPolicy::MajorityVote)
+            .with_strategy(InterestedStrategy(
+                advising(Decision::Long, 1.0),
+                HashSet::from([btc.clone()]),
+            ))
+            .with_strategy(InterestedStrategy(
+                advising(Decision::Short, 1.0),
+                HashSet::from([eth.clone()]),
+            ));
+
+        // Union of the sub-strategy interests applies
+        assert_eq!(
+            strategy.instruments_of_interest(),
+            Some(HashSet::from([btc, eth]))
+        );
+
+        // Only the btc sub-strategy votes on the btc MarketEvent, so holds the majority
+        assert_eq!(
+            combine(&mut strategy),
+            Some(HashMap::from([(Decision::Long, SignalStrength(1.0))]))
+        );
+
+        // Any sub-strategy interested in every Instrument makes the union every Instrument
+        let strategy = strategy.with_strategy(advising(Decision::Long, 1.0));
+        assert_eq!(strategy.instruments_of_interest(), None);
+    }
+}
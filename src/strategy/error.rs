@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+/// Errors generated when applying updated parameters to a
+/// [`SignalGenerator`](super::SignalGenerator) strategy.
+#[derive(Error, Clone, Eq, PartialEq, Debug)]
+pub enum ParamError {
+    #[error("Strategy does not support updating it's parameters")]
+    Unsupported,
+
+    #[error("Failed to deserialise strategy parameters: {0}")]
+    Deserialise(String),
+
+    #[error("Invalid strategy parameters: {0}")]
+    Invalid(&'static str),
+}
+
+/// Errors generated when restoring a [`SignalGenerator`](super::SignalGenerator) strategy from
+/// a snapshot of it's state.
+#[derive(Error, Clone, Eq, PartialEq, Debug)]
+pub enum SnapshotError {
+    #[error("Strategy does not support restoring it's state from a snapshot")]
+    Unsupported,
+
+    #[error("Failed to deserialise strategy snapshot: {0}")]
+    Deserialise(String),
+}
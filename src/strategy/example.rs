@@ -1,5 +1,8 @@
-use super::{Decision, Signal, SignalGenerator, SignalStrength};
-use crate::data::MarketMeta;
+use super::{
+    error::{ParamError, SnapshotError},
+    Decision, Signal, SignalGenerator, SignalStrength,
+};
+use crate::{data::MarketMeta, portfolio::decimal_from_f64};
 use barter_data::event::{DataKind, MarketEvent};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
@@ -12,10 +15,31 @@ pub struct Config {
     pub rsi_period: usize,
 }
 
-#[derive(Clone, Debug)]
+/// Tunable RSI thresholds of a [`RSIStrategy`], which may be updated live via
+/// [`SignalGenerator::apply_params`] with a JSON object of any of it's fields (eg/
+/// `{"oversold": 30.0}`), leaving omitted thresholds unchanged.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Params {
+    /// RSI value below which the market is deemed oversold, advising a long entry.
+    pub oversold: f64,
+    /// RSI value above which the market is deemed overbought, advising a short entry.
+    pub overbought: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            oversold: 40.0,
+            overbought: 60.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 /// Example RSI based strategy that implements [`SignalGenerator`].
 pub struct RSIStrategy {
     rsi: RelativeStrengthIndex,
+    params: Params,
 }
 
 impl SignalGenerator for RSIStrategy {
@@ -30,7 +54,7 @@ impl SignalGenerator for RSIStrategy {
         let rsi = self.rsi.next(candle_close);
 
         // Generate advisory signals map
-        let signals = RSIStrategy::generate_signals_map(rsi);
+        let signals = self.generate_signals_map(rsi);
 
         // If signals map is empty, return no SignalEvent
         if signals.is_empty() {
@@ -42,12 +66,54 @@ impl SignalGenerator for RSIStrategy {
             exchange: market.exchange.clone(),
             instrument: market.instrument.clone(),
             market_meta: MarketMeta {
-                close: candle_close,
+                close: decimal_from_f64(candle_close),
                 time: market.exchange_time,
             },
             signals,
+            tags: Default::default(),
         })
     }
+
+    fn apply_params(&mut self, params: &serde_json::Value) -> Result<(), ParamError> {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Update {
+            oversold: Option<f64>,
+            overbought: Option<f64>,
+        }
+
+        let update = Update::deserialize(params)
+            .map_err(|error| ParamError::Deserialise(error.to_string()))?;
+        let params = Params {
+            oversold: update.oversold.unwrap_or(self.params.oversold),
+            overbought: update.overbought.unwrap_or(self.params.overbought),
+        };
+
+        if !(0.0..=100.0).contains(&params.oversold) || !(0.0..=100.0).contains(&params.overbought)
+        {
+            return Err(ParamError::Invalid(
+                "RSI thresholds must be within 0.0..=100.0",
+            ));
+        }
+        if params.oversold >= params.overbought {
+            return Err(ParamError::Invalid(
+                "oversold threshold must be below the overbought threshold",
+            ));
+        }
+
+        self.params = params;
+        Ok(())
+    }
+
+    fn snapshot(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("RSIStrategy contains only serialisable values")
+    }
+
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), SnapshotError> {
+        *self = RSIStrategy::deserialize(snapshot)
+            .map_err(|error| SnapshotError::Deserialise(error.to_string()))?;
+        Ok(())
+    }
 }
 
 impl RSIStrategy {
@@ -56,26 +122,39 @@ impl RSIStrategy {
         let rsi_indicator = RelativeStrengthIndex::new(config.rsi_period)
             .expect("Failed to construct RSI indicator");
 
-        Self { rsi: rsi_indicator }
+        Self {
+            rsi: rsi_indicator,
+            params: Params::default(),
+        }
+    }
+
+    /// Returns the current RSI threshold [`Params`] of the [`RSIStrategy`].
+    pub fn params(&self) -> Params {
+        self.params
     }
 
     /// Given the latest RSI value for a symbol, generates a map containing the [`SignalStrength`] for
     /// [`Decision`] under consideration.
-    fn generate_signals_map(rsi: f64) -> HashMap<Decision, SignalStrength> {
+    fn generate_signals_map(&self, rsi: f64) -> HashMap<Decision, SignalStrength> {
+        let Params {
+            oversold,
+            overbought,
+        } = self.params;
+
         let mut signals = HashMap::with_capacity(4);
-        if rsi < 40.0 {
+        if rsi < oversold {
             signals.insert(Decision::Long, RSIStrategy::calculate_signal_strength());
         }
-        if rsi > 60.0 {
+        if rsi > overbought {
             signals.insert(
                 Decision::CloseLong,
                 RSIStrategy::calculate_signal_strength(),
             );
         }
-        if rsi > 60.0 {
+        if rsi > overbought {
             signals.insert(Decision::Short, RSIStrategy::calculate_signal_strength());
         }
-        if rsi < 40.0 {
+        if rsi < oversold {
             signals.insert(
                 Decision::CloseShort,
                 RSIStrategy::calculate_signal_strength(),
@@ -89,3 +168,37 @@ impl RSIStrategy {
         SignalStrength(1.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn rsi_strategy_should_only_apply_valid_params() {
+        let mut strategy = RSIStrategy::new(Config { rsi_period: 14 });
+
+        // Omitted thresholds are left unchanged
+        strategy.apply_params(&json!({ "oversold": 30.0 })).unwrap();
+        assert_eq!(
+            strategy.params(),
+            Params {
+                oversold: 30.0,
+                overbought: 60.0
+            }
+        );
+
+        // Rejected params leave every threshold unchanged
+        assert_eq!(
+            strategy.apply_params(&json!({ "oversold": 30.0, "overbought": 20.0 })),
+            Err(ParamError::Invalid(
+                "oversold threshold must be below the overbought threshold"
+            ))
+        );
+        assert!(matches!(
+            strategy.apply_params(&json!({ "period": 7 })),
+            Err(ParamError::Deserialise(_))
+        ));
+        assert_eq!(strategy.params().oversold, 30.0);
+    }
+}
@@ -0,0 +1,314 @@
+use super::{error::SnapshotError, Decision, Signal, SignalGenerator, SignalStrength};
+use crate::{data::MarketMeta, execution::FillEvent, portfolio::decimal_to_f64};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::Side;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for constructing a [`GridStrategy`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Price the grid is centred around.
+    pub center: f64,
+    /// Price interval between adjacent grid levels.
+    pub spacing: f64,
+    /// Number of grid levels either side of the center.
+    pub levels: usize,
+}
+
+/// State of a [`GridLevel`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum LevelState {
+    /// Enters a Position once the price crosses the level.
+    Armed,
+    /// Crossed, awaiting the [`FillEvent`] of it's entry.
+    Pending,
+    /// Entry filled, exiting once the price crosses one spacing back towards the center.
+    Filled,
+}
+
+/// Price level of a [`GridStrategy`] ladder.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GridLevel {
+    /// Price of the level.
+    pub price: f64,
+    /// Side of the entry at the level, buying below the center & selling above it.
+    pub side: Side,
+    /// State of the level.
+    pub state: LevelState,
+}
+
+impl GridLevel {
+    /// Price at which the entry of the level is exited, one spacing back towards the center.
+    fn exit_price(&self, spacing: f64) -> f64 {
+        match self.side {
+            Side::Buy => self.price + spacing,
+            Side::Sell => self.price - spacing,
+        }
+    }
+}
+
+/// Reference grid trading [`SignalGenerator`] implementation, laddering buy levels below & sell
+/// levels above a center price at a configurable interval.
+///
+/// Once the price crosses armed levels whilst flat, a [`Decision::Long`] (buy levels) or
+/// [`Decision::Short`] (sell levels) [`Signal`] is advised with a [`SignalStrength`] of the
+/// number of levels crossed, so a price gapping through several levels between
+/// [`MarketEvent`]s enters every crossed level at once. The opposite side of each level is
+/// re-armed by it's [`FillEvent`] (see [`SignalGenerator::on_fill`]): filled entries are exited
+/// once the price crosses one spacing back towards the center, after which their levels are
+/// armed again.
+///
+/// A Portfolio holds a single Position per Market that is exited in full, so crossing the exit
+/// of any filled level exits every filled level, and levels crossed whilst a Position is open
+/// are left armed.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GridStrategy {
+    spacing: f64,
+    levels: Vec<GridLevel>,
+    last_price: Option<f64>,
+    /// Whether an exit has been advised that is yet to be filled.
+    exiting: bool,
+}
+
+impl SignalGenerator for GridStrategy {
+    fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+        // Ignore MarketEvents that do not communicate a price (eg/ Liquidations)
+        let market_meta = MarketMeta::from_market(market)?;
+        let price = decimal_to_f64(market_meta.close);
+        let last_price = self.last_price.replace(price)?;
+
+        let (decision, strength) = self.cross(last_price, price)?;
+        Some(Signal {
+            time: Utc::now(),
+            exchange: market.exchange.clone(),
+            instrument: market.instrument.clone(),
+            signals: HashMap::from([(decision, SignalStrength(strength))]),
+            market_meta,
+            tags: Default::default(),
+        })
+    }
+
+    fn on_fill(&mut self, fill: &FillEvent) {
+        match fill.decision {
+            Decision::Long | Decision::Short => {
+                let side = match fill.decision {
+                    Decision::Long => Side::Buy,
+                    _ => Side::Sell,
+                };
+                self.levels
+                    .iter_mut()
+                    .filter(|level| level.side == side && level.state == LevelState::Pending)
+                    .for_each(|level| level.state = LevelState::Filled);
+            }
+            Decision::CloseLong | Decision::CloseShort => {
+                self.exiting = false;
+                self.levels
+                    .iter_mut()
+                    .filter(|level| level.state == LevelState::Filled)
+                    .for_each(|level| level.state = LevelState::Armed);
+            }
+        }
+    }
+
+    fn snapshot(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("GridStrategy contains only serialisable values")
+    }
+
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), SnapshotError> {
+        *self = GridStrategy::deserialize(snapshot)
+            .map_err(|error| SnapshotError::Deserialise(error.to_string()))?;
+        Ok(())
+    }
+}
+
+impl GridStrategy {
+    /// Constructs a new [`GridStrategy`] component using the provided configuration struct, with
+    /// every level armed.
+    pub fn new(config: Config) -> Self {
+        let spacing = config.spacing.abs();
+        let offset = |index: usize| spacing * (index + 1) as f64;
+        let buys = (0..config.levels).map(|index| GridLevel {
+            price: config.center - offset(index),
+            side: Side::Buy,
+            state: LevelState::Armed,
+        });
+        let sells = (0..config.levels).map(|index| GridLevel {
+            price: config.center + offset(index),
+            side: Side::Sell,
+            state: LevelState::Armed,
+        });
+
+        Self {
+            spacing,
+            levels: buys.chain(sells).collect(),
+            last_price: None,
+            exiting: false,
+        }
+    }
+
+    /// Returns the [`GridLevel`]s of the ladder, buy levels first.
+    pub fn levels(&self) -> &[GridLevel] {
+        &self.levels
+    }
+
+    /// Determines the [`Decision`] & strength advised by the price moving from `from` to `to`,
+    /// updating the state of every crossed level.
+    fn cross(&mut self, from: f64, to: f64) -> Option<(Decision, f64)> {
+        let crossed = |price: f64| (from > price && to <= price) || (from < price && to >= price);
+
+        // Exit every filled level once the price crosses the exit of any of them
+        let holding = self
+            .levels
+            .iter()
+            .find(|level| matches!(level.state, LevelState::Pending | LevelState::Filled));
+        if let Some(held) = holding {
+            let side = held.side;
+            let exit = self.levels.iter().any(|level| {
+                level.state == LevelState::Filled && crossed(level.exit_price(self.spacing))
+            });
+            if !exit || self.exiting {
+                return None;
+            }
+            self.exiting = true;
+            return Some(match side {
+                Side::Buy => (Decision::CloseLong, 1.0),
+                Side::Sell => (Decision::CloseShort, 1.0),
+            });
+        }
+
+        // Flat, so enter every armed level crossed in the direction of the price movement
+        let side = match to < from {
+            true => Side::Buy,
+            false => Side::Sell,
+        };
+        let mut entered = 0;
+        for level in self
+            .levels
+            .iter_mut()
+            .filter(|level| level.side == side && level.state == LevelState::Armed)
+        {
+            if crossed(level.price) {
+                level.state = LevelState::Pending;
+                entered += 1;
+            }
+        }
+
+        match (entered, side) {
+            (0, _) => None,
+            (entered, Side::Buy) => Some((Decision::Long, entered as f64)),
+            (entered, Side::Sell) => Some((Decision::Short, entered as f64)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{fill_event, market_event_trade};
+    use barter_data::subscription::trade::PublicTrade;
+
+    fn grid() -> GridStrategy {
+        GridStrategy::new(Config {
+            center: 100.0,
+            spacing: 10.0,
+            levels: 3,
+        })
+    }
+
+    fn trade(price: f64) -> MarketEvent<DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.kind = DataKind::Trade(PublicTrade {
+            id: "trade_id".to_owned(),
+            price,
+            amount: 1.0,
+            side: Side::Buy,
+        });
+        market
+    }
+
+    fn fill(decision: Decision) -> FillEvent {
+        FillEvent {
+            decision,
+            ..fill_event()
+        }
+    }
+
+    /// Returns the single [`Decision`] & strength advised by the [`GridStrategy`] at the price.
+    fn advise(strategy: &mut GridStrategy, price: f64) -> Option<(Decision, f64)> {
+        strategy.generate_signal(&trade(price)).map(|signal| {
+            let (decision, strength) = signal.signals.into_iter().next().unwrap();
+            (decision, strength.0)
+        })
+    }
+
+    fn states(strategy: &GridStrategy, side: Side) -> Vec<LevelState> {
+        strategy
+            .levels()
+            .iter()
+            .filter(|level| level.side == side)
+            .map(|level| level.state)
+            .collect()
+    }
+
+    #[test]
+    fn grid_strategy_should_re_arm_opposite_side_once_level_fills() {
+        use LevelState::*;
+        let mut strategy = grid();
+
+        // First MarketEvent only seeds the last price
+        assert_eq!(advise(&mut strategy, 95.0), None);
+
+        // Crossing the 90 buy level enters a single level
+        assert_eq!(advise(&mut strategy, 89.0), Some((Decision::Long, 1.0)));
+        assert_eq!(states(&strategy, Side::Buy), vec![Pending, Armed, Armed]);
+
+        // Exit of the 90 level is only armed once it's entry fills
+        assert_eq!(advise(&mut strategy, 101.0), None);
+        assert_eq!(advise(&mut strategy, 89.0), None);
+        strategy.on_fill(&fill(Decision::Long));
+        assert_eq!(states(&strategy, Side::Buy), vec![Filled, Armed, Armed]);
+
+        // Crossing the 100 exit of the filled level advises a single exit
+        assert_eq!(
+            advise(&mut strategy, 100.0),
+            Some((Decision::CloseLong, 1.0))
+        );
+        assert_eq!(advise(&mut strategy, 89.0), None);
+        assert_eq!(advise(&mut strategy, 100.0), None);
+
+        // Exit fill re-arms the 90 level, which enters again once crossed
+        strategy.on_fill(&fill(Decision::CloseLong));
+        assert_eq!(states(&strategy, Side::Buy), vec![Armed, Armed, Armed]);
+        assert_eq!(advise(&mut strategy, 90.0), Some((Decision::Long, 1.0)));
+    }
+
+    #[test]
+    fn grid_strategy_should_enter_every_level_a_price_gap_crosses() {
+        use LevelState::*;
+        let mut strategy = grid();
+        assert_eq!(advise(&mut strategy, 100.0), None);
+
+        // Gapping up through the 110 & 120 sell levels enters both at once
+        assert_eq!(advise(&mut strategy, 125.0), Some((Decision::Short, 2.0)));
+        assert_eq!(states(&strategy, Side::Sell), vec![Pending, Pending, Armed]);
+        assert_eq!(states(&strategy, Side::Buy), vec![Armed, Armed, Armed]);
+        strategy.on_fill(&fill(Decision::Short));
+        assert_eq!(states(&strategy, Side::Sell), vec![Filled, Filled, Armed]);
+
+        // Crossing the 110 exit of the 120 level exits every filled level
+        assert_eq!(
+            advise(&mut strategy, 105.0),
+            Some((Decision::CloseShort, 1.0))
+        );
+        strategy.on_fill(&fill(Decision::CloseShort));
+
+        // Gapping down through every buy level enters all three
+        assert_eq!(advise(&mut strategy, 65.0), Some((Decision::Long, 3.0)));
+        assert_eq!(
+            states(&strategy, Side::Buy),
+            vec![Pending, Pending, Pending]
+        );
+    }
+}
@@ -1,17 +1,83 @@
-use crate::data::MarketMeta;
+use self::error::{ParamError, SnapshotError};
+use crate::{data::MarketMeta, execution::FillEvent, portfolio::OrderTags};
 use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::Instrument, Exchange, Market};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// Barter strategy module specific errors.
+pub mod error;
 
 /// Barter example RSI strategy [`SignalGenerator`] implementation.
 pub mod example;
 
+/// Reference buy & hold strategy [`SignalGenerator`] implementation.
+pub mod buy_and_hold;
+
+/// [`SignalGenerator`] combining the [`Signal`]s of several sub-strategies.
+pub mod composite;
+
+/// Reference grid trading strategy [`SignalGenerator`] implementation.
+pub mod grid;
+
+/// Meta-strategy [`SignalGenerator`] allocating capital across child strategies by their recent
+/// performance.
+pub mod adaptive;
+
+/// Asynchronous [`SignalGenerator`] variant for strategies that call external services.
+pub mod asynchronous;
+
 /// May generate an advisory [`Signal`] as a result of analysing an input [`MarketEvent`].
 pub trait SignalGenerator {
     /// Optionally return a [`Signal`] given input [`MarketEvent`].
     fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal>;
+
+    /// Returns the [`Instrument`]s this strategy is interested in, or `None` if it is interested
+    /// in every [`Instrument`] (the default). A [`Trader`](crate::engine::trader::Trader) skips
+    /// generating a [`Signal`] from any [`MarketEvent`] of an [`Instrument`] outside this set,
+    /// whilst still updating the Portfolio from it.
+    ///
+    /// Queried once when the strategy is given to a
+    /// [`Trader`](crate::engine::trader::Trader) or [`CompositeStrategy`](composite::CompositeStrategy).
+    fn instruments_of_interest(&self) -> Option<HashSet<Instrument>> {
+        None
+    }
+
+    /// Notifies the strategy of a [`FillEvent`] of it's own orders, so stateful strategies can
+    /// react to executions (eg/ re-arm a grid level, update stop levels, flip state) without
+    /// tracking fills externally. Fills are ignored by default.
+    ///
+    /// Invoked by a [`Trader`](crate::engine::trader::Trader) for every [`FillEvent`] of it's
+    /// account, once de-duplicated, but before it is applied to the Portfolio.
+    fn on_fill(&mut self, _fill: &FillEvent) {}
+
+    /// Applies updated strategy parameters (eg/ thresholds, sizes) received via a
+    /// [`Command::UpdateStrategyParams`](crate::engine::Command::UpdateStrategyParams), without
+    /// restarting the strategy. Invalid parameters must be rejected with the existing parameters
+    /// left unchanged. Strategies do not support updating their parameters by default.
+    fn apply_params(&mut self, _params: &serde_json::Value) -> Result<(), ParamError> {
+        Err(ParamError::Unsupported)
+    }
+
+    /// Returns a snapshot of the internal strategy state (eg/ rolling indicators, memory of
+    /// entered positions), persisted in a [`TraderCheckpoint`](crate::engine::checkpoint::TraderCheckpoint)
+    /// so a restarted strategy resumes without a fresh warm-up. Defaults to
+    /// [`serde_json::Value::Null`] for strategies without state to persist.
+    fn snapshot(&self) -> serde_json::Value {
+        serde_json::Value::Null
+    }
+
+    /// Restores the internal strategy state from a snapshot previously returned by
+    /// [`SignalGenerator::snapshot`]. Invalid snapshots must be rejected with the existing state
+    /// left unchanged. Strategies only support restoring a [`serde_json::Value::Null`] snapshot
+    /// by default.
+    fn restore(&mut self, snapshot: serde_json::Value) -> Result<(), SnapshotError> {
+        match snapshot {
+            serde_json::Value::Null => Ok(()),
+            _ => Err(SnapshotError::Unsupported),
+        }
+    }
 }
 
 /// Advisory [`Signal`] for a [`Market`] detailing the [`SignalStrength`] associated with each
@@ -24,23 +90,25 @@ pub struct Signal {
     pub signals: HashMap<Decision, SignalStrength>,
     /// Metadata propagated from the [`MarketEvent`] that yielded this [`Signal`].
     pub market_meta: MarketMeta,
+    /// [`OrderTags`] the [`OrderEvent`](crate::portfolio::OrderEvent) generated from this
+    /// [`Signal`] is tagged with (eg/ the [`STRATEGY_TAG`](crate::statistic::trade::STRATEGY_TAG)
+    /// of the strategy that advised it).
+    #[serde(default)]
+    pub tags: OrderTags,
 }
 
 /// Describes the type of advisory signal the strategy is endorsing.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
 pub enum Decision {
+    #[default]
     Long,
     CloseLong,
     Short,
     CloseShort,
 }
 
-impl Default for Decision {
-    fn default() -> Self {
-        Self::Long
-    }
-}
-
 impl Decision {
     /// Determines if a [`Decision`] is Long.
     pub fn is_long(&self) -> bool {
@@ -110,48 +178,48 @@ mod tests {
     #[test]
     fn should_return_decision_is_long() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_long(), true)
+        assert!(decision.is_long())
     }
 
     #[test]
     fn should_return_decision_is_not_long() {
         let decision = Decision::Short;
-        assert_eq!(decision.is_long(), false)
+        assert!(!decision.is_long())
     }
 
     #[test]
     fn should_return_decision_is_short() {
         let decision = Decision::Short;
-        assert_eq!(decision.is_short(), true)
+        assert!(decision.is_short())
     }
 
     #[test]
     fn should_return_decision_is_not_short() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_short(), false)
+        assert!(!decision.is_short())
     }
 
     #[test]
     fn should_return_decision_is_entry() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_entry(), true)
+        assert!(decision.is_entry())
     }
 
     #[test]
     fn should_return_decision_is_not_entry() {
         let decision = Decision::CloseLong;
-        assert_eq!(decision.is_entry(), false)
+        assert!(!decision.is_entry())
     }
 
     #[test]
     fn should_return_decision_is_exit() {
         let decision = Decision::CloseShort;
-        assert_eq!(decision.is_exit(), true)
+        assert!(decision.is_exit())
     }
 
     #[test]
     fn should_return_decision_is_not_exit() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_exit(), false)
+        assert!(!decision.is_exit())
     }
 }
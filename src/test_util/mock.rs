@@ -0,0 +1,324 @@
+use crate::{
+    data::{Feed, MarketGenerator, MarketMeta},
+    execution::{
+        error::ExecutionError, order_id::ClientOrderId, ExecutionClient, Fees, FillEvent,
+        OrderRejection, RejectReason,
+    },
+    portfolio::OrderEvent,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::Instrument;
+use chrono::Utc;
+use parking_lot::{Condvar, Mutex};
+use rust_decimal::Decimal;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// [`MarketGenerator`] that yields the [`MarketEvent`]s pushed to it by a test, so a test can
+/// drive a [`Trader`](crate::engine::trader::Trader) running on another thread one
+/// [`MarketEvent`] at a time.
+///
+/// [`MarketGenerator::next`] blocks until a [`MarketEvent`] is pushed, and yields
+/// [`Feed::Finished`] once every pushed [`MarketEvent`] is consumed after the [`MockFeed`] is
+/// marked exhausted. Cloning a [`MockFeed`] returns a handle to the same shared feed.
+#[derive(Clone, Debug, Default)]
+pub struct MockFeed {
+    state: Arc<(Mutex<MockFeedState>, Condvar)>,
+}
+
+#[derive(Debug, Default)]
+struct MockFeedState {
+    events: VecDeque<MarketEvent<DataKind>>,
+    exhausted: bool,
+}
+
+impl MockFeed {
+    /// Constructs a new empty [`MockFeed`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes a [`MarketEvent`] to be yielded after every [`MarketEvent`] pushed before it.
+    pub fn push(&self, event: MarketEvent<DataKind>) {
+        let (state, pushed) = &*self.state;
+        state.lock().events.push_back(event);
+        pushed.notify_all();
+    }
+
+    /// Marks the [`MockFeed`] exhausted, so it finishes once every pushed [`MarketEvent`] is
+    /// consumed.
+    pub fn exhaust(&self) {
+        let (state, pushed) = &*self.state;
+        state.lock().exhausted = true;
+        pushed.notify_all();
+    }
+}
+
+impl MarketGenerator<MarketEvent<DataKind>> for MockFeed {
+    fn next(&mut self) -> Feed<MarketEvent<DataKind>> {
+        let (state, pushed) = &*self.state;
+        let mut state = state.lock();
+        loop {
+            if let Some(event) = state.events.pop_front() {
+                return Feed::Next(event);
+            }
+            if state.exhausted {
+                return Feed::Finished;
+            }
+            pushed.wait(&mut state);
+        }
+    }
+}
+
+/// [`ExecutionClient`] that captures every [`OrderEvent`] sent for execution, leaving it resting
+/// until a test injects a [`FillEvent`] for it, or rejecting it if a test requested so via
+/// [`MockExecution::reject_next`]. Resting [`OrderEvent`]s are amended & cancelled in place.
+///
+/// Injected [`FillEvent`]s are returned to the [`Trader`](crate::engine::trader::Trader) by
+/// [`ExecutionClient::fill_resting_orders`], ie/ with the next [`MarketEvent`] it consumes.
+/// Cloning a [`MockExecution`] returns a handle to the same shared state.
+#[derive(Clone, Debug, Default)]
+pub struct MockExecution {
+    state: Arc<(Mutex<MockExecutionState>, Condvar)>,
+}
+
+#[derive(Debug, Default)]
+struct MockExecutionState {
+    sent: Vec<OrderEvent>,
+    open: HashMap<ClientOrderId, OrderEvent>,
+    fills: VecDeque<FillEvent>,
+    rejects: VecDeque<RejectReason>,
+    rejected: Vec<OrderRejection>,
+    exchange_positions: HashMap<Instrument, Decimal>,
+}
+
+impl MockExecution {
+    /// Constructs a new [`MockExecution`] that has been sent no [`OrderEvent`]s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns every [`OrderEvent`] sent for execution, in the order they were sent.
+    pub fn orders(&self) -> Vec<OrderEvent> {
+        self.state.0.lock().sent.clone()
+    }
+
+    /// Blocks until at least `count` [`OrderEvent`]s have been sent for execution, or the timeout
+    /// elapses, returning every [`OrderEvent`] sent.
+    pub fn wait_for_orders(&self, count: usize, timeout: Duration) -> Vec<OrderEvent> {
+        let deadline = Instant::now() + timeout;
+        let (state, sent) = &*self.state;
+        let mut state = state.lock();
+        while state.sent.len() < count && !sent.wait_until(&mut state, deadline).timed_out() {}
+        state.sent.clone()
+    }
+
+    /// Injects a [`FillEvent`] to be returned with the next [`MarketEvent`], closing the resting
+    /// [`OrderEvent`] it fills.
+    pub fn inject_fill(&self, fill: FillEvent) {
+        let mut state = self.state.0.lock();
+        state.open.remove(&fill.cid);
+        state.fills.push_back(fill);
+    }
+
+    /// Injects a [`FillEvent`] filling the full quantity of the provided [`OrderEvent`] at the
+    /// provided price, without fees.
+    pub fn fill_order(&self, order: &OrderEvent, price: Decimal) {
+        self.inject_fill(FillEvent {
+            time: Utc::now(),
+            cid: order.cid,
+            account: order.account.clone(),
+            exchange: order.exchange.clone(),
+            instrument: order.instrument.clone(),
+            market_meta: MarketMeta {
+                close: price,
+                time: Utc::now(),
+            },
+            decision: order.decision,
+            quantity: order.quantity,
+            fill_value_gross: price * order.quantity.abs(),
+            fees: Fees::default(),
+            fill_id: None,
+            tags: order.tags.clone(),
+        });
+    }
+
+    /// Rejects the next [`OrderEvent`] sent for execution with the provided [`RejectReason`],
+    /// after any earlier rejections are used up.
+    pub fn reject_next(&self, reason: RejectReason) {
+        self.state.0.lock().rejects.push_back(reason);
+    }
+
+    /// Sets the net signed quantity of the [`Instrument`] position held on the exchange,
+    /// returned by [`ExecutionClient::exchange_position`].
+    pub fn set_exchange_position(&self, instrument: Instrument, quantity: Decimal) {
+        self.state
+            .0
+            .lock()
+            .exchange_positions
+            .insert(instrument, quantity);
+    }
+}
+
+impl ExecutionClient for MockExecution {
+    fn generate_fill(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        let (state, sent) = &*self.state;
+        let mut state = state.lock();
+        state.sent.push(order.clone());
+        match state.rejects.pop_front() {
+            Some(reason) => state.rejected.push(OrderRejection {
+                time: Utc::now(),
+                order: order.clone(),
+                reason,
+            }),
+            None => {
+                state.open.insert(order.cid, order.clone());
+            }
+        }
+        sent.notify_all();
+        Ok(None)
+    }
+
+    fn fill_resting_orders(&mut self, _: &MarketEvent<DataKind>) -> Vec<FillEvent> {
+        self.state.0.lock().fills.drain(..).collect()
+    }
+
+    fn cancel_order(&mut self, cid: &ClientOrderId) -> Option<OrderEvent> {
+        self.state.0.lock().open.remove(cid)
+    }
+
+    fn amend_order(&mut self, order: &OrderEvent) -> Option<OrderEvent> {
+        let mut state = self.state.0.lock();
+        let open = state.open.get_mut(&order.cid)?;
+        *open = order.clone();
+        Some(order.clone())
+    }
+
+    fn restore_order(&mut self, order: &OrderEvent) {
+        self.state.0.lock().open.insert(order.cid, order.clone());
+    }
+
+    fn rejected_orders(&mut self) -> Vec<OrderRejection> {
+        self.state.0.lock().rejected.drain(..).collect()
+    }
+
+    fn exchange_position(
+        &mut self,
+        instrument: &Instrument,
+    ) -> Result<Option<Decimal>, ExecutionError> {
+        Ok(self
+            .state
+            .0
+            .lock()
+            .exchange_positions
+            .get(instrument)
+            .copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::trader::Trader,
+        event::EventTx,
+        portfolio::{
+            allocator::DefaultAllocator,
+            portfolio::MetaPortfolio,
+            position::determine_position_id,
+            repository::{in_memory::InMemoryRepository, PositionHandler},
+            risk::DefaultRisk,
+        },
+        statistic::summary::pnl::PnLReturnSummary,
+        strategy::{Decision, Signal, SignalGenerator, SignalStrength},
+        test_util::market_event_trade,
+    };
+    use barter_integration::model::{Market, Side};
+    use tokio::sync::mpsc;
+    use uuid::Uuid;
+
+    /// Strategy that advises entering a long Position on every [`MarketEvent`].
+    #[derive(Debug)]
+    struct AlwaysLongStrategy;
+
+    impl SignalGenerator for AlwaysLongStrategy {
+        fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
+            Some(Signal {
+                time: Utc::now(),
+                exchange: market.exchange.clone(),
+                instrument: market.instrument.clone(),
+                signals: HashMap::from([(Decision::Long, SignalStrength(1.0))]),
+                market_meta: MarketMeta::from_market(market)?,
+                tags: Default::default(),
+            })
+        }
+    }
+
+    #[test]
+    fn mock_feed_and_execution_should_drive_an_order_round_trip_through_a_trader() {
+        let engine_id = Uuid::new_v4();
+        let market = Market::new(
+            market_event_trade(Side::Buy).exchange,
+            market_event_trade(Side::Buy).instrument,
+        );
+        let portfolio = Arc::new(Mutex::new(
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![market.clone()])
+                .starting_cash(Decimal::from(10_000))
+                .repository(InMemoryRepository::<PnLReturnSummary>::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: Decimal::ONE_HUNDRED,
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(())
+                .build_and_init()
+                .unwrap(),
+        ));
+
+        let feed = MockFeed::new();
+        let execution = MockExecution::new();
+        let (_command_tx, command_rx) = mpsc::channel(10);
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+        let trader: Trader<EventTx, PnLReturnSummary, _, _, _, _> = Trader::builder()
+            .engine_id(engine_id)
+            .market(market.clone())
+            .command_rx(command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(&portfolio))
+            .data(feed.clone())
+            .strategy(AlwaysLongStrategy)
+            .execution(execution.clone())
+            .build()
+            .unwrap();
+        let trader = std::thread::spawn(move || trader.run());
+
+        // Feed a MarketEvent & assert the Trader sends an OrderEvent for execution
+        feed.push(market_event_trade(Side::Buy));
+        let orders = execution.wait_for_orders(1, Duration::from_secs(5));
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].decision, Decision::Long);
+
+        // Inject the FillEvent, which is returned with the next MarketEvent
+        execution.fill_order(&orders[0], Decimal::ONE_THOUSAND);
+        feed.push(market_event_trade(Side::Buy));
+        feed.exhaust();
+        trader.join().unwrap().unwrap();
+
+        // Assert the Portfolio entered a Position of the filled quantity
+        let position_id = determine_position_id(engine_id, &market.exchange, &market.instrument);
+        let position = portfolio
+            .lock()
+            .get_open_position(&position_id)
+            .unwrap()
+            .expect("Portfolio did not enter a Position from the injected FillEvent");
+        assert_eq!(position.quantity, orders[0].quantity);
+
+        // The open Position is not entered again on the second MarketEvent
+        assert_eq!(execution.orders().len(), 1);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::strategy::{Decision, Signal, SignalGenerator};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::trade::PublicTrade,
+};
+use barter_integration::model::Side;
+use chrono::{Duration, Utc};
+use std::collections::HashSet;
+
+/// Rig that drives a [`SignalGenerator`] strategy through a scripted sequence of
+/// [`MarketEvent`]s in isolation, without any Portfolio or Execution wiring, so a test can assert
+/// the sequence of [`Signal`]s it emits.
+///
+/// Like a [`Trader`](crate::engine::trader::Trader), the strategy is only invoked for
+/// [`MarketEvent`]s of it's [`SignalGenerator::instruments_of_interest`]. The strategy state
+/// carries over between runs unless the rig is [`reset`](StrategyTestRig::reset).
+#[derive(Debug)]
+pub struct StrategyTestRig<Strategy>
+where
+    Strategy: SignalGenerator + Clone,
+{
+    initial: Strategy,
+    strategy: Strategy,
+}
+
+impl<Strategy> StrategyTestRig<Strategy>
+where
+    Strategy: SignalGenerator + Clone,
+{
+    /// Constructs a new [`StrategyTestRig`] for the provided strategy, which is also the state
+    /// the strategy is [`reset`](StrategyTestRig::reset) to.
+    pub fn new(strategy: Strategy) -> Self {
+        Self {
+            initial: strategy.clone(),
+            strategy,
+        }
+    }
+
+    /// Feeds every [`MarketEvent`] to the strategy in order, returning the [`Signal`]s emitted
+    /// for each [`MarketEvent`] (empty if it emitted none).
+    pub fn run<Events>(&mut self, market_events: Events) -> Vec<Vec<Signal>>
+    where
+        Events: IntoIterator<Item = MarketEvent<DataKind>>,
+    {
+        let instruments_of_interest = self.strategy.instruments_of_interest();
+
+        market_events
+            .into_iter()
+            .map(|market| {
+                let interested = instruments_of_interest
+                    .as_ref()
+                    .is_none_or(|instruments| instruments.contains(&market.instrument));
+                if !interested {
+                    return Vec::new();
+                }
+
+                self.strategy.generate_signal(&market).into_iter().collect()
+            })
+            .collect()
+    }
+
+    /// Resets the strategy to the state it was provided to [`StrategyTestRig::new`] with,
+    /// discarding any internal state (eg/ indicator history) accumulated by previous runs.
+    pub fn reset(&mut self) {
+        self.strategy = self.initial.clone();
+    }
+
+    /// Returns the strategy being driven by this [`StrategyTestRig`].
+    pub fn strategy(&self) -> &Strategy {
+        &self.strategy
+    }
+}
+
+/// Build a scripted price path of [`MarketEvent`]s of [`DataKind::Trade`](DataKind), one for
+/// each price provided, with exchange times one second apart. See
+/// [`market_event_trade`](super::market_event_trade) for the [`Market`] they are of.
+///
+/// [`Market`]: barter_integration::model::Market
+pub fn price_path<Prices>(prices: Prices) -> Vec<MarketEvent<DataKind>>
+where
+    Prices: IntoIterator<Item = f64>,
+{
+    let start = Utc::now();
+
+    prices
+        .into_iter()
+        .zip(0..)
+        .map(|(price, second)| {
+            let mut market = super::market_event_trade(Side::Buy);
+            market.exchange_time = start + Duration::seconds(second);
+            market.received_time = market.exchange_time;
+            if let DataKind::Trade(PublicTrade { price: trade, .. }) = &mut market.kind {
+                *trade = price;
+            }
+            market
+        })
+        .collect()
+}
+
+/// Returns the distinct [`Decision`]s advised by each set of [`Signal`]s emitted by a
+/// [`StrategyTestRig`] run, for concise assertions.
+pub fn decisions(signals: &[Vec<Signal>]) -> Vec<HashSet<Decision>> {
+    signals
+        .iter()
+        .map(|signals| {
+            signals
+                .iter()
+                .flat_map(|signal| signal.signals.keys().copied())
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::buy_and_hold::BuyAndHold;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn strategy_test_rig_should_return_signal_sequence_of_buy_and_hold_over_price_path() {
+        let mut rig = StrategyTestRig::new(BuyAndHold::new());
+
+        let signals = rig.run(price_path([1000.0, 990.0, 1010.0]));
+        assert_eq!(
+            decisions(&signals),
+            vec![
+                HashSet::from([Decision::Long]),
+                HashSet::new(),
+                HashSet::new()
+            ]
+        );
+        assert_eq!(signals[0][0].market_meta.close, Decimal::ONE_THOUSAND);
+
+        // BuyAndHold remembers the entered Market between runs, until the rig is reset
+        assert!(rig.run(price_path([1020.0])).concat().is_empty());
+
+        rig.reset();
+        let signals = rig.run(price_path([1020.0]));
+        assert_eq!(decisions(&signals), vec![HashSet::from([Decision::Long])]);
+        assert_eq!(signals[0][0].market_meta.close, Decimal::from(1020));
+    }
+}
@@ -1,7 +1,10 @@
 use barter::{
-    data::historical,
-    engine::{trader::Trader, Engine},
-    event::EventTx,
+    data::{
+        historical,
+        live::{self, MarketSubscriptions},
+    },
+    engine::{error::EngineError, trader::Trader, Command, Engine, EngineBuilder},
+    event::{Event, EventTx},
     execution::{
         simulated::{Config as ExecutionConfig, SimulatedExecution},
         Fees,
@@ -17,8 +20,10 @@ use barter::{
     strategy::example::{Config as StrategyConfig, RSIStrategy},
     test_util::market_event_trade,
 };
+use barter_data::event::{DataKind, MarketEvent};
 use barter_integration::model::{instrument::kind::InstrumentKind, Market, Side};
 use parking_lot::Mutex;
+use rust_decimal::Decimal;
 use std::{collections::HashMap, sync::Arc, time::Duration};
 use tokio::sync::mpsc;
 use uuid::Uuid;
@@ -43,10 +48,10 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         MetaPortfolio::builder()
             .engine_id(engine_id)
             .markets(vec![market.clone()])
-            .starting_cash(10_000.0)
+            .starting_cash(Decimal::from(10_000))
             .repository(InMemoryRepository::new())
             .allocation_manager(DefaultAllocator {
-                default_order_value: 100.0,
+                default_order_value: Decimal::ONE_HUNDRED,
             })
             .risk_manager(DefaultRisk {})
             .statistic_config(StatisticConfig {
@@ -77,9 +82,9 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
             .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
             .execution(SimulatedExecution::new(ExecutionConfig {
                 simulated_fees_pct: Fees {
-                    exchange: 0.1,
-                    slippage: 0.05,
-                    network: 0.0,
+                    exchange: Decimal::new(1, 1),
+                    slippage: Decimal::new(5, 2),
+                    network: Decimal::ZERO,
                 },
             }))
             .build()
@@ -114,5 +119,291 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
     assert!(
         actual.is_ok(),
         "failed because Engine's command_rx.await is blocking the Engine from stopping"
-    )
+    );
+    assert!(actual.unwrap().is_ok(), "Engine terminated with an error");
+}
+
+/// [`Engine`] whose Traders consume endless [`MarketEvent`] feeds, and so never stop organically.
+type EndlessEngine = Engine<
+    EventTx,
+    TradingSummary,
+    MetaPortfolio<
+        InMemoryRepository<TradingSummary>,
+        DefaultAllocator,
+        DefaultRisk,
+        TradingSummary,
+    >,
+    historical::MarketFeed<
+        Box<dyn Iterator<Item = MarketEvent<DataKind>> + Send>,
+        MarketEvent<DataKind>,
+    >,
+    RSIStrategy,
+    SimulatedExecution,
+>;
+
+fn engine_with_endless_feeds(markets: &[Market]) -> (EndlessEngine, mpsc::Sender<Command>) {
+    // Create channel to distribute Commands to the Engine & it's Traders (eg/ Command::Terminate)
+    let (command_tx, command_rx) = mpsc::channel(20);
+
+    // Drop the Event receiver since the Engine Events are not inspected
+    let (event_tx, _) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    // Generate unique identifier to associate an Engine's components
+    let engine_id = Uuid::new_v4();
+
+    // Build global shared-state MetaPortfolio (1-to-1 relationship with an Engine)
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(markets.to_vec())
+            .starting_cash(Decimal::from(10_000))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: Decimal::ONE_HUNDRED,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Build a Trader for each Market, each with it's own Command channel & endless MarketEvent feed
+    let mut traders = Vec::new();
+    let mut trader_command_txs = HashMap::new();
+    for market in markets.iter() {
+        let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+        trader_command_txs.insert(market.clone(), trader_command_tx);
+
+        let feed_market = market.clone();
+        let market_events: Box<dyn Iterator<Item = MarketEvent<DataKind>> + Send> =
+            Box::new(std::iter::repeat_with(move || {
+                let mut event = market_event_trade(Side::Buy);
+                event.exchange = feed_market.exchange.clone();
+                event.instrument = feed_market.instrument.clone();
+                event
+            }));
+
+        traders.push(
+            Trader::builder()
+                .engine_id(engine_id)
+                .market(market.clone())
+                .command_rx(trader_command_rx)
+                .event_tx(event_tx.clone())
+                .portfolio(Arc::clone(&portfolio))
+                .data(historical::MarketFeed::new(market_events))
+                .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+                .execution(SimulatedExecution::new(ExecutionConfig::default()))
+                .build()
+                .expect("failed to build trader"),
+        );
+    }
+
+    // Build Engine (1-to-many relationship with Traders)
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(traders)
+        .trader_command_txs(trader_command_txs)
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 1000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .build()
+        .expect("failed to build engine");
+
+    (engine, command_tx)
+}
+
+#[tokio::test]
+async fn engine_with_multiple_traders_returns_summary_of_each_trader_after_terminate() {
+    // Create the disjoint Markets to be traded on (1-to-1 relationship with a Trader)
+    let markets = vec![
+        Market::new("binance", ("btc", "usdt", InstrumentKind::Spot)),
+        Market::new("binance", ("eth", "usdt", InstrumentKind::Spot)),
+    ];
+
+    let (engine, command_tx) = engine_with_endless_feeds(&markets);
+
+    // Traders never stop organically, so the Engine must distribute Command::Terminate to each
+    // '--> sent once the Traders have had time to consume MarketEvents, since Traders without
+    //      open Positions are terminated immediately
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        command_tx
+            .send(Command::Terminate("integration test finished".to_owned()))
+            .await
+            .unwrap();
+    });
+
+    let summaries = tokio::time::timeout(Duration::from_secs(5), engine.run())
+        .await
+        .expect("Engine failed to terminate every Trader")
+        .expect("Engine terminated with an error");
+
+    assert_eq!(summaries.len(), 2);
+    for market in markets {
+        let summary = summaries
+            .iter()
+            .find(|summary| summary.market == market)
+            .expect("missing SessionSummary for Trader");
+        assert!(summary.market_events > 0);
+        assert!(summary.statistics.is_some());
+    }
+}
+
+#[tokio::test]
+async fn engine_run_until_shutdown_signal_terminates_traders_cleanly() {
+    let markets = vec![Market::new("binance", ("btc", "usdt", InstrumentKind::Spot))];
+
+    let (engine, _command_tx) = engine_with_endless_feeds(&markets);
+
+    // Simulate a shutdown signal (eg/ SIGINT) being received shortly after the Engine starts
+    let shutdown_signal = tokio::time::sleep(Duration::from_millis(10));
+
+    let summaries = tokio::time::timeout(Duration::from_secs(5), engine.run_until(shutdown_signal))
+        .await
+        .expect("Engine failed to stop after receiving shutdown signal")
+        .expect("Engine terminated with an error");
+
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0].market, markets[0]);
+    assert!(summaries[0].market_events > 0);
+}
+
+/// [`EngineBuilder`] whose Traders consume live [`MarketEvent`] feeds.
+type LiveEngineBuilder = EngineBuilder<
+    EventTx,
+    TradingSummary,
+    MetaPortfolio<
+        InMemoryRepository<TradingSummary>,
+        DefaultAllocator,
+        DefaultRisk,
+        TradingSummary,
+    >,
+    live::MarketFeed<MarketEvent<DataKind>>,
+    RSIStrategy,
+    SimulatedExecution,
+>;
+
+fn engine_builder_with_live_feeds(
+    markets: &[Market],
+) -> (LiveEngineBuilder, mpsc::UnboundedReceiver<Event>) {
+    // Keep the Event receiver so the MarketEvents consumed by the Traders can be inspected
+    let (event_tx, event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+
+    // Generate unique identifier to associate an Engine's components
+    let engine_id = Uuid::new_v4();
+
+    // Build global shared-state MetaPortfolio (1-to-1 relationship with an Engine)
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(markets.to_vec())
+            .starting_cash(Decimal::from(10_000))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: Decimal::ONE_HUNDRED,
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: 10_000.0,
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Build a Trader for each Market with a placeholder live MarketFeed, subscribed by the Engine
+    let mut traders = Vec::new();
+    let mut trader_command_txs = HashMap::new();
+    for market in markets.iter() {
+        let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+        trader_command_txs.insert(market.clone(), trader_command_tx);
+
+        traders.push(
+            Trader::builder()
+                .engine_id(engine_id)
+                .market(market.clone())
+                .command_rx(trader_command_rx)
+                .event_tx(event_tx.clone())
+                .portfolio(Arc::clone(&portfolio))
+                .data(live::MarketFeed::default())
+                .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
+                .execution(SimulatedExecution::new(ExecutionConfig::default()))
+                .build()
+                .expect("failed to build trader"),
+        );
+    }
+
+    let builder = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(mpsc::channel(20).1)
+        .portfolio(portfolio)
+        .traders(traders)
+        .trader_command_txs(trader_command_txs)
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: 1000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }));
+
+    (builder, event_rx)
+}
+
+#[tokio::test]
+async fn engine_with_market_streams_feeds_subscribed_market_events_to_traders() {
+    let market = Market::new("binance_spot", ("btc", "usdt", InstrumentKind::Spot));
+    let (builder, mut event_rx) = engine_builder_with_live_feeds(std::slice::from_ref(&market));
+
+    // Mock Barter-Data stream that yields one MarketEvent before disconnecting
+    let market_event = market_event_trade(Side::Buy);
+    let (stream_tx, stream_rx) = mpsc::unbounded_channel();
+    stream_tx.send(market_event.clone()).unwrap();
+    drop(stream_tx);
+
+    let engine = builder
+        .with_market_streams(MarketSubscriptions::new().stream([market], stream_rx))
+        .await
+        .expect("failed to subscribe to market streams")
+        .build()
+        .expect("failed to build engine");
+
+    // Trader stops once the market stream has ended, so the Engine stops organically
+    let summaries = tokio::time::timeout(Duration::from_secs(5), engine.run())
+        .await
+        .expect("Engine failed to stop after market stream ended")
+        .expect("Engine terminated with an error");
+    assert_eq!(summaries[0].market_events, 1);
+
+    let mut consumed = Vec::new();
+    while let Ok(event) = event_rx.try_recv() {
+        if let Event::Market(market_event) = event {
+            consumed.push(market_event);
+        }
+    }
+    assert_eq!(consumed, vec![market_event]);
+}
+
+#[tokio::test]
+async fn engine_with_market_streams_of_untraded_market_is_err() {
+    let traded = Market::new("binance_spot", ("btc", "usdt", InstrumentKind::Spot));
+    let untraded = Market::new("binance_spot", ("eth", "usdt", InstrumentKind::Spot));
+    let (builder, _event_rx) = engine_builder_with_live_feeds(&[traded]);
+
+    let result = builder
+        .with_market_streams(
+            MarketSubscriptions::new().stream([untraded], mpsc::unbounded_channel().1),
+        )
+        .await;
+
+    assert!(matches!(result, Err(EngineError::InvalidMarkets(_))));
 }